@@ -112,9 +112,7 @@ fn main() {
         println!("Configuring bls12-381 MSM...");
         let mut msm_results = DeviceVec::<curve::G1Projective>::cuda_malloc(1).unwrap();
         let stream = CudaStream::create().unwrap();
-        let mut cfg = msm::MSMConfig::default();
-        cfg.ctx.stream = &stream;
-        cfg.is_async = true;
+        let cfg = msm::MSMConfig::builder().stream(&stream).is_async(true).build().unwrap();
 
         println!("Executing bls12-381 MSM on device...");
         msm::msm(scalars, points, &cfg, &mut msm_results[..]).unwrap();
@@ -100,7 +100,7 @@ pub fn bls12_381_g1_msm(
     points: &[BlsG1point],
     config: Option<msm::MSMConfig>,
 ) -> Result<BlsG1point, IcicleError> {
-    let mut cfg = config.unwrap_or(msm::MSMConfig::default());
+    let cfg = config.unwrap_or(msm::MSMConfig::default());
 
     let convert_scalars = scalars.iter()
             .map(|scalar| ToIcicle::to_icicle_scalar(scalar))
@@ -116,8 +116,7 @@ pub fn bls12_381_g1_msm(
 
     let mut msm_results = DeviceVec::<curve::G1Projective>::cuda_malloc(1).unwrap();
     let stream = CudaStream::create().unwrap();
-    cfg.ctx.stream = &stream;
-    cfg.is_async = true;
+    let cfg = cfg.into_builder().stream(&stream).is_async(true).build().unwrap();
     msm::msm(icicle_scalars, icicle_points, &cfg, &mut msm_results[..]).unwrap();
 
     let mut msm_host_result = vec![curve::G1Projective::zero(); 1];
@@ -64,10 +64,11 @@ fn main() {
 
     println!("Configuring bn254 NTT...");
     let stream = CudaStream::create().unwrap();
-    let mut cfg = ntt::NTTConfig::<'_, ScalarField>::default();
-    cfg.ctx
-        .stream = &stream;
-    cfg.is_async = true;
+    let cfg = ntt::NTTConfig::<'_, ScalarField>::builder()
+        .stream(&stream)
+        .is_async(true)
+        .build()
+        .unwrap();
 
     println!("Setting up bls12377 Domain...");
     let icicle_omega = <Bls12377Fr as FftField>::get_root_of_unity(
@@ -80,11 +81,11 @@ fn main() {
 
     println!("Configuring bls12377 NTT...");
     let stream_bls12377 = CudaStream::create().unwrap();
-    let mut cfg_bls12377 = ntt::NTTConfig::<'_, BLS12377ScalarField>::default();
-    cfg_bls12377
-        .ctx
-        .stream = &stream_bls12377;
-    cfg_bls12377.is_async = true;
+    let cfg_bls12377 = ntt::NTTConfig::<'_, BLS12377ScalarField>::builder()
+        .stream(&stream_bls12377)
+        .is_async(true)
+        .build()
+        .unwrap();
 
     println!("Executing bn254 NTT on device...");
     #[cfg(feature = "profile")]
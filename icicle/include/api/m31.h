@@ -13,6 +13,42 @@
 #include "matrix/matrix.cuh"
 #include "fields/stark_fields/m31.cuh"
 #include "vec_ops/vec_ops.cuh"
+#include "poseidon2/poseidon2.cuh"
+
+extern "C" cudaError_t m31_poseidon2_create_cuda(
+  poseidon2::Poseidon2<m31::scalar_t>** poseidon,
+  unsigned int width,
+  unsigned int rate,
+  unsigned int alpha,
+  unsigned int internal_rounds,
+  unsigned int external_rounds,
+  const m31::scalar_t* round_constants,
+  const m31::scalar_t* internal_matrix_diag,
+  poseidon2::MdsType mds_type,
+  poseidon2::DiffusionStrategy diffusion,
+  device_context::DeviceContext& ctx
+);
+
+extern "C" cudaError_t m31_poseidon2_load_cuda(
+  poseidon2::Poseidon2<m31::scalar_t>** poseidon,
+  unsigned int width,
+  unsigned int rate,
+  poseidon2::MdsType mds_type,
+  poseidon2::DiffusionStrategy diffusion,
+  device_context::DeviceContext& ctx
+);
+
+extern "C" cudaError_t m31_poseidon2_hash_many_cuda(
+  const poseidon2::Poseidon2<m31::scalar_t>* poseidon,
+  const m31::scalar_t* inputs,
+  m31::scalar_t* output,
+  unsigned int number_of_states,
+  unsigned int input_block_len,
+  unsigned int output_len,
+  hash::HashConfig& cfg);
+
+extern "C" cudaError_t
+  m31_poseidon2_delete_cuda(poseidon2::Poseidon2<m31::scalar_t>* poseidon, device_context::DeviceContext& ctx);
 
 extern "C" cudaError_t m31_build_merkle_tree(
   const m31::scalar_t* leaves,
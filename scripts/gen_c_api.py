@@ -69,7 +69,6 @@ FIELDS_CONFIG = {
         "ntt_ext.h",
         "ntt.h",
         "poseidon.h",
-        "poseidon2.h",
     }
 }
 
@@ -1,6 +1,7 @@
+use crate::error::{IcicleError, IcicleResult};
 #[cfg(feature = "arkworks")]
 use crate::traits::ArkConvertible;
-use crate::traits::{FieldImpl, MontgomeryConvertible};
+use crate::traits::{FieldArithmetic, FieldImpl, MontgomeryConvertible};
 #[cfg(feature = "arkworks")]
 use ark_ec::models::CurveConfig as ArkCurveConfig;
 #[cfg(feature = "arkworks")]
@@ -12,11 +13,15 @@ use icicle_cuda_runtime::device_context::DeviceContext;
 use icicle_cuda_runtime::error::CudaError;
 use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice};
 use std::fmt::Debug;
+use std::ops::{Add, Neg, Sub};
 
 pub trait Curve: Debug + PartialEq + Copy + Clone {
     type BaseField: FieldImpl;
     type ScalarField: FieldImpl;
 
+    /// Name of the curve in the C++ library, `bn254` or `bn254_g2` for instance.
+    const NAME: &'static str;
+
     #[doc(hidden)]
     fn eq_proj(point1: *const Projective<Self>, point2: *const Projective<Self>) -> bool;
     #[doc(hidden)]
@@ -88,6 +93,34 @@ impl<C: Curve> Affine<C> {
             z: C::BaseField::one(),
         }
     }
+
+    /// Canonical encoding of the point: `x` then `y`, both little-endian. The zero point encodes to zeros.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self
+            .x
+            .to_bytes_le();
+        bytes.extend(
+            self.y
+                .to_bytes_le(),
+        );
+        bytes
+    }
+
+    /// Point from its [to_bytes_le](Self::to_bytes_le) encoding. G2 points encode the same way, each `Fp2`
+    /// coordinate being `c0` then `c1`. Fails when the size isn't the one of two coordinates.
+    pub fn from_bytes_le(bytes: &[u8]) -> IcicleResult<Self> {
+        let coordinate_size = std::mem::size_of::<C::BaseField>();
+        if bytes.len() != 2 * coordinate_size {
+            return Err(IcicleError::invalid_argument(
+                "Point encoding doesn't have the size of two coordinates",
+            ));
+        }
+        let (x, y) = bytes.split_at(coordinate_size);
+        Ok(Affine {
+            x: C::BaseField::from_bytes_le(x),
+            y: C::BaseField::from_bytes_le(y),
+        })
+    }
 }
 
 impl<C: Curve> From<Affine<C>> for Projective<C> {
@@ -139,6 +172,207 @@ impl<C: Curve> From<Projective<C>> for Affine<C> {
     }
 }
 
+/// Host-side group law of curves `y² = x³ + b`, for base fields with [FieldArithmetic]. The formulas are the
+/// `add-1998-cmo-2`, `madd-1998-cmo` and `dbl-2007-bl` ones of the explicit-formulas database.
+impl<C: Curve> Projective<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    pub fn is_zero(&self) -> bool {
+        self.z == C::BaseField::zero()
+    }
+
+    pub fn double(&self) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+        let xx = self
+            .x
+            .sqr();
+        let w = xx + xx + xx;
+        let s = self.y * self.z;
+        let s = s + s;
+        let sss = s.sqr() * s;
+        let r = self.y * s;
+        let rr = r.sqr();
+        let b = (self.x + r).sqr() - xx - rr;
+        let h = w.sqr() - b - b;
+        Self {
+            x: h * s,
+            y: w * (b - h) - rr - rr,
+            z: sss,
+        }
+    }
+
+    /// Sum with an affine point, the zero [Affine] point standing for the point at infinity.
+    pub fn add_affine(&self, other: &Affine<C>) -> Self {
+        if *other == Affine::zero() {
+            return *self;
+        }
+        if self.is_zero() {
+            return other.to_projective();
+        }
+        let u = other.y * self.z - self.y;
+        let v = other.x * self.z - self.x;
+        if v == C::BaseField::zero() {
+            return if u == C::BaseField::zero() {
+                self.double()
+            } else {
+                Self::zero()
+            };
+        }
+        let vv = v.sqr();
+        let vvv = v * vv;
+        let r = vv * self.x;
+        let a = u.sqr() * self.z - vvv - r - r;
+        Self {
+            x: v * a,
+            y: u * (r - a) - vvv * self.y,
+            z: vvv * self.z,
+        }
+    }
+
+    /// Scalar multiplication by the canonical form of `scalar`, double-and-add from the top bit.
+    pub fn mul_scalar(&self, scalar: &C::ScalarField) -> Self {
+        let mut res = Self::zero();
+        for byte in scalar
+            .to_bytes_le()
+            .iter()
+            .rev()
+        {
+            for bit in (0..8).rev() {
+                res = res.double();
+                if (byte >> bit) & 1 == 1 {
+                    res = res + *self;
+                }
+            }
+        }
+        res
+    }
+
+    /// Host version of the conversion to [Affine], which needs an inversion.
+    pub fn normalize(&self) -> Affine<C> {
+        match self
+            .z
+            .inv()
+        {
+            Some(z_inv) => Affine {
+                x: self.x * z_inv,
+                y: self.y * z_inv,
+            },
+            None => Affine::zero(),
+        }
+    }
+
+    /// [normalize](Self::normalize) of many points with a single inversion.
+    pub fn batch_normalize(points: &[Self]) -> Vec<Affine<C>> {
+        let mut products = Vec::with_capacity(points.len());
+        let mut acc = C::BaseField::one();
+        for point in points {
+            products.push(acc);
+            if !point.is_zero() {
+                acc = acc * point.z;
+            }
+        }
+        // The product of non-zero elements is invertible
+        let mut acc_inv = acc
+            .inv()
+            .unwrap();
+        let mut affine = vec![Affine::zero(); points.len()];
+        for ((point, product), res) in points
+            .iter()
+            .zip(products)
+            .zip(affine.iter_mut())
+            .rev()
+        {
+            if point.is_zero() {
+                continue;
+            }
+            let z_inv = acc_inv * product;
+            acc_inv = acc_inv * point.z;
+            *res = Affine {
+                x: point.x * z_inv,
+                y: point.y * z_inv,
+            };
+        }
+        affine
+    }
+}
+
+impl<C: Curve> Add for Projective<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let y1z2 = self.y * other.z;
+        let x1z2 = self.x * other.z;
+        let z1z2 = self.z * other.z;
+        let u = other.y * self.z - y1z2;
+        let v = other.x * self.z - x1z2;
+        if v == C::BaseField::zero() {
+            return if u == C::BaseField::zero() {
+                self.double()
+            } else {
+                Self::zero()
+            };
+        }
+        let vv = v.sqr();
+        let vvv = v * vv;
+        let r = vv * x1z2;
+        let a = u.sqr() * z1z2 - vvv - r - r;
+        Self {
+            x: v * a,
+            y: u * (r - a) - vvv * y1z2,
+            z: vvv * z1z2,
+        }
+    }
+}
+
+impl<C: Curve> Neg for Projective<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<C: Curve> Sub for Projective<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<C: Curve> Neg for Affine<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { x: self.x, y: -self.y }
+    }
+}
+
 impl<'a, C: Curve> MontgomeryConvertible<'a> for Affine<C> {
     fn to_mont(values: &mut DeviceSlice<Self>, ctx: &DeviceContext<'a>) -> CudaError {
         check_device(ctx.device_id);
@@ -307,6 +541,8 @@ macro_rules! impl_curve {
             type BaseField = $base_field;
             type ScalarField = $scalar_field;
 
+            const NAME: &'static str = $curve_prefix;
+
             fn eq_proj(point1: *const $projective_type, point2: *const $projective_type) -> bool {
                 unsafe { $curve_prefix_ident::eq(point1, point2) }
             }
@@ -237,7 +237,6 @@ macro_rules! impl_ecntt_bench {
                     let points = HostSlice::from_slice(&points);
                     let mut batch_ntt_result = vec![Projective::<C>::zero(); full_size];
                     let batch_ntt_result = HostSlice::from_mut_slice(&mut batch_ntt_result);
-                    let mut config = NTTConfig::default();
                     for is_inverse in [NTTDir::kInverse, NTTDir::kForward] {
                         for ordering in [
                             Ordering::kNN,
@@ -247,10 +246,13 @@ macro_rules! impl_ecntt_bench {
                             // Ordering::kNM, // no mixed radix ecntt
                             // Ordering::kMN,
                         ] {
-                            config.ordering = ordering;
                             for alg in [NttAlgorithm::Radix2] {
-                                config.batch_size = batch_size as i32;
-                                config.ntt_algorithm = alg;
+                                let mut config = NTTConfig::builder()
+                                    .ordering(ordering)
+                                    .batch_size(batch_size as i32)
+                                    .ntt_algorithm(alg)
+                                    .build()
+                                    .unwrap();
                                 let bench_descr = format!(
                                     "{:?} {:?} {:?} {} x {}",
                                     alg, ordering, is_inverse, test_size, batch_size
@@ -46,6 +46,11 @@ impl IcicleError {
         }
     }
 
+    /// Error for arguments the call can't be made with, described by `reason`.
+    pub fn invalid_argument(reason: &'static str) -> Self {
+        Self::from_code_and_reason(IcicleErrorCode::InvalidArgument, reason)
+    }
+
     pub fn get_icicle_error_code(&self) -> IcicleErrorCode {
         self.icicle_error_code
     }
@@ -1,6 +1,6 @@
 #[cfg(feature = "arkworks")]
 use crate::traits::ArkConvertible;
-use crate::traits::{FieldConfig, FieldImpl, MontgomeryConvertible};
+use crate::traits::{FieldArithmetic, FieldConfig, FieldImpl, MontgomeryConvertible, PrimeFieldConfig};
 #[cfg(feature = "arkworks")]
 use ark_ff::{BigInteger, Field as ArkField, PrimeField};
 use hex::FromHex;
@@ -9,6 +9,9 @@ use icicle_cuda_runtime::error::CudaError;
 use icicle_cuda_runtime::memory::DeviceSlice;
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+pub(crate) mod utils;
 
 #[derive(PartialEq, Copy, Clone)]
 #[repr(C)]
@@ -104,6 +107,238 @@ impl<const NUM_LIMBS: usize, F: FieldConfig> FieldImpl for Field<NUM_LIMBS, F> {
     }
 }
 
+const fn modulus_limbs<const NUM_LIMBS: usize>(modulus: &[u32]) -> [u32; NUM_LIMBS] {
+    assert!(
+        modulus.len() == NUM_LIMBS,
+        "Modulus doesn't have the same number of limbs as the field"
+    );
+    let mut limbs = [0u32; NUM_LIMBS];
+    let mut i = 0;
+    while i < NUM_LIMBS {
+        limbs[i] = modulus[i];
+        i += 1;
+    }
+    limbs
+}
+
+const fn add_limbs<const NUM_LIMBS: usize>(a: &[u32; NUM_LIMBS], b: &[u32; NUM_LIMBS]) -> ([u32; NUM_LIMBS], bool) {
+    let mut res = [0u32; NUM_LIMBS];
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < NUM_LIMBS {
+        let sum = a[i] as u64 + b[i] as u64 + carry;
+        res[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    (res, carry != 0)
+}
+
+const fn sub_limbs<const NUM_LIMBS: usize>(a: &[u32; NUM_LIMBS], b: &[u32; NUM_LIMBS]) -> ([u32; NUM_LIMBS], bool) {
+    let mut res = [0u32; NUM_LIMBS];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < NUM_LIMBS {
+        let diff = (a[i] as u64).wrapping_sub(b[i] as u64 + borrow);
+        res[i] = diff as u32;
+        borrow = (diff >> 63) & 1;
+        i += 1;
+    }
+    (res, borrow != 0)
+}
+
+const fn less_than<const NUM_LIMBS: usize>(a: &[u32; NUM_LIMBS], b: &[u32; NUM_LIMBS]) -> bool {
+    let mut i = NUM_LIMBS;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+const fn add_mod<const NUM_LIMBS: usize>(
+    a: &[u32; NUM_LIMBS],
+    b: &[u32; NUM_LIMBS],
+    modulus: &[u32; NUM_LIMBS],
+) -> [u32; NUM_LIMBS] {
+    let (res, carry) = add_limbs(a, b);
+    if carry || !less_than(&res, modulus) {
+        sub_limbs(&res, modulus).0
+    } else {
+        res
+    }
+}
+
+// -modulus^-1 mod 2^32, each Newton iteration doubles the number of correct low bits
+const fn montgomery_inv(modulus_low_limb: u32) -> u32 {
+    let mut inv = 1u32;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(modulus_low_limb.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+// R^2 mod modulus for R = 2^(32 * NUM_LIMBS), computed by doubling 1 until it reaches 2^(64 * NUM_LIMBS)
+const fn montgomery_r2<const NUM_LIMBS: usize>(modulus: &[u32; NUM_LIMBS]) -> [u32; NUM_LIMBS] {
+    let mut res = [0u32; NUM_LIMBS];
+    res[0] = 1;
+    let mut i = 0;
+    while i < 64 * NUM_LIMBS {
+        res = add_mod(&res, &res, modulus);
+        i += 1;
+    }
+    res
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Field<NUM_LIMBS, F> {
+    const MODULUS: [u32; NUM_LIMBS] = modulus_limbs(F::MODULUS);
+    const MODULUS_INV: u32 = montgomery_inv(Self::MODULUS[0]);
+    const R2: [u32; NUM_LIMBS] = montgomery_r2(&Self::MODULUS);
+
+    // Montgomery product a * b * R^-1 (CIOS), the result is fully reduced
+    fn mont_mul(&self, other: &Self) -> Self {
+        let (a, b, modulus) = (&self.limbs, &other.limbs, &Self::MODULUS);
+        let mut t = [0u32; NUM_LIMBS];
+        let mut t_high = 0u32;
+        for i in 0..NUM_LIMBS {
+            let mut carry = 0u64;
+            for j in 0..NUM_LIMBS {
+                let x = t[j] as u64 + a[j] as u64 * b[i] as u64 + carry;
+                t[j] = x as u32;
+                carry = x >> 32;
+            }
+            let x = t_high as u64 + carry;
+            t_high = x as u32;
+            let t_overflow = (x >> 32) as u32;
+
+            let k = t[0].wrapping_mul(Self::MODULUS_INV);
+            let mut carry = (t[0] as u64 + k as u64 * modulus[0] as u64) >> 32;
+            for j in 1..NUM_LIMBS {
+                let x = t[j] as u64 + k as u64 * modulus[j] as u64 + carry;
+                t[j - 1] = x as u32;
+                carry = x >> 32;
+            }
+            let x = t_high as u64 + carry;
+            t[NUM_LIMBS - 1] = x as u32;
+            t_high = t_overflow + (x >> 32) as u32;
+        }
+        if t_high != 0 || !less_than(&t, modulus) {
+            t = sub_limbs(&t, modulus).0;
+        }
+        Self::from(t)
+    }
+
+    // Power by little-endian exponent limbs, carried out in the Montgomery domain
+    fn pow_limbs(&self, exp: &[u64]) -> Self {
+        let base = Montgomery(self.mont_mul(&Self::from(Self::R2)));
+        let one = Montgomery(Self::one().mont_mul(&Self::from(Self::R2)));
+        utils::pow_limbs(base, one, exp)
+            .0
+            .mont_mul(&Self::one())
+    }
+}
+
+// Element in the Montgomery domain, multiplied without converting back
+#[derive(Copy, Clone)]
+struct Montgomery<const NUM_LIMBS: usize, F: FieldConfig>(Field<NUM_LIMBS, F>);
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Mul for Montgomery<NUM_LIMBS, F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Montgomery(
+            self.0
+                .mont_mul(&other.0),
+        )
+    }
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Add for Field<NUM_LIMBS, F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from(add_mod(&self.limbs, &other.limbs, &Self::MODULUS))
+    }
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Sub for Field<NUM_LIMBS, F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let (res, borrow) = sub_limbs(&self.limbs, &other.limbs);
+        if borrow {
+            Self::from(add_limbs(&res, &Self::MODULUS).0)
+        } else {
+            Self::from(res)
+        }
+    }
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Mul for Field<NUM_LIMBS, F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mont_mul(&other)
+            .mont_mul(&Self::from(Self::R2))
+    }
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> Neg for Field<NUM_LIMBS, F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> FieldArithmetic for Field<NUM_LIMBS, F> {
+    fn sqr(&self) -> Self {
+        *self * *self
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        self.pow_limbs(&[exp])
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if *self == Self::zero() {
+            return None;
+        }
+        let mut two = [0u32; NUM_LIMBS];
+        two[0] = 2;
+        let exp: Vec<u8> = sub_limbs(&Self::MODULUS, &two)
+            .0
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        Some(self.pow_limbs(&utils::limbs_from_bytes_le(&exp)))
+    }
+
+    fn to_montgomery(&self) -> Self {
+        if F::MONTGOMERY_FORM {
+            self.mont_mul(&Self::from(Self::R2))
+        } else {
+            *self
+        }
+    }
+
+    fn from_montgomery(&self) -> Self {
+        if F::MONTGOMERY_FORM {
+            self.mont_mul(&Self::one())
+        } else {
+            *self
+        }
+    }
+
+    fn modulus_bytes_le() -> Vec<u8> {
+        Self::from(Self::MODULUS).to_bytes_le()
+    }
+}
+
 #[doc(hidden)]
 pub trait MontgomeryConvertibleField<'a, F: FieldImpl> {
     fn to_mont(values: &mut DeviceSlice<F>, ctx: &DeviceContext<'a>) -> CudaError;
@@ -259,3 +494,20 @@ macro_rules! impl_field_tests {
         }
     };
 }
+
+#[macro_export]
+macro_rules! impl_field_arithmetic_tests {
+    (
+        $field_name:ident
+    ) => {
+        #[test]
+        fn test_field_arithmetic() {
+            check_field_arithmetic::<$field_name>()
+        }
+
+        #[test]
+        fn test_field_montgomery_host() {
+            check_field_montgomery_host::<$field_name>()
+        }
+    };
+}
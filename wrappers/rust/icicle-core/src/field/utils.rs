@@ -0,0 +1,49 @@
+use std::ops::Mul;
+
+use crate::traits::FieldArithmetic;
+
+// Square-and-multiply over little-endian exponent limbs
+pub(crate) fn pow_limbs<T: Copy + Mul<Output = T>>(base: T, one: T, exp: &[u64]) -> T {
+    let mut res = one;
+    for limb in exp
+        .iter()
+        .rev()
+    {
+        for bit in (0..64).rev() {
+            res = res * res;
+            if (limb >> bit) & 1 == 1 {
+                res = res * base;
+            }
+        }
+    }
+    res
+}
+
+// Little-endian `u64` limbs of little-endian bytes, the last limb padded with zeros
+pub(crate) fn limbs_from_bytes_le(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect()
+}
+
+// `(p - 1) / divisor` as little-endian limbs, and the remainder
+pub(crate) fn modulus_minus_one_over<F: FieldArithmetic>(divisor: u64) -> (Vec<u64>, u64) {
+    let mut limbs = limbs_from_bytes_le(&F::modulus_bytes_le());
+    // The modulus is odd, the subtraction doesn't borrow
+    limbs[0] -= 1;
+    let mut remainder = 0u128;
+    for limb in limbs
+        .iter_mut()
+        .rev()
+    {
+        let value = remainder << 64 | *limb as u128;
+        *limb = (value / divisor as u128) as u64;
+        remainder = value % divisor as u128;
+    }
+    (limbs, remainder as u64)
+}
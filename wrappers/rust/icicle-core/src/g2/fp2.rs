@@ -0,0 +1,269 @@
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::field::Field;
+#[cfg(feature = "arkworks")]
+use crate::traits::ArkConvertible;
+use crate::traits::{FieldArithmetic, FieldConfig, FieldImpl, Fp2Config};
+use hex::FromHex;
+
+/// Prime fields with host arithmetic in their quadratic extension [Fp2], which is every [Field] whose config is a
+/// [Fp2Config].
+pub trait Fp2BaseField: FieldArithmetic {
+    /// `β` of `Fp2 = Fp[u] / (u² - β)`.
+    fn fp2_non_residue() -> Self;
+
+    #[cfg(feature = "arkworks")]
+    #[doc(hidden)]
+    type ArkFp2Config: ark_ff::Fp2Config<Fp = <Self::Config as FieldConfig>::ArkField>;
+}
+
+impl<const NUM_LIMBS: usize, F: Fp2Config> Fp2BaseField for Field<NUM_LIMBS, F> {
+    fn fp2_non_residue() -> Self {
+        let beta = Self::from_u32(F::NON_RESIDUE.unsigned_abs());
+        if F::NON_RESIDUE < 0 {
+            -beta
+        } else {
+            beta
+        }
+    }
+
+    #[cfg(feature = "arkworks")]
+    type ArkFp2Config = F::ArkFp2Config;
+}
+
+/// Element `c0 + c1 u` of the quadratic extension `Fp2 = Fp[u] / (u² - β)` of the prime field `F`, the base field of
+/// G2 points.
+///
+/// The layout is the one of the device: `c0` then `c1`, so `Fp2` coordinates are passed to the C++ library as they
+/// are, and the byte encoding is `c0` then `c1`, both little-endian.
+#[derive(PartialEq, Copy, Clone)]
+#[repr(C)]
+pub struct Fp2<F> {
+    pub c0: F,
+    pub c1: F,
+}
+
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Fp2Cfg<F>(PhantomData<F>);
+
+impl<F: Fp2BaseField> FieldConfig for Fp2Cfg<F> {
+    #[cfg(feature = "arkworks")]
+    type ArkField = ark_ff::Fp2<F::ArkFp2Config>;
+}
+
+impl<F: FieldImpl> Fp2<F> {
+    pub fn new(c0: F, c1: F) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Element of the prime field.
+    pub fn from_fp(c0: F) -> Self {
+        Self::new(c0, F::zero())
+    }
+}
+
+impl<F: Fp2BaseField> Fp2<F> {
+    pub fn mul_by_fp(&self, other: &F) -> Self {
+        Self::new(self.c0 * *other, self.c1 * *other)
+    }
+
+    /// `c0 - c1 u`, which is also the Frobenius map.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// `c0² - β c1²`, the product of the element and its conjugate.
+    pub fn norm(&self) -> F {
+        self.c0
+            .sqr()
+            - F::fp2_non_residue()
+                * self
+                    .c1
+                    .sqr()
+    }
+}
+
+impl<F: Display> Display for Fp2<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{} + {} * u", self.c0, self.c1)
+    }
+}
+
+impl<F: Display> Debug for Fp2<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl<F: FieldImpl> From<Fp2<F>> for [F::Repr; 2] {
+    fn from(fp2: Fp2<F>) -> Self {
+        [
+            fp2.c0
+                .into(),
+            fp2.c1
+                .into(),
+        ]
+    }
+}
+
+impl<F: FieldImpl> From<[F::Repr; 2]> for Fp2<F> {
+    fn from([c0, c1]: [F::Repr; 2]) -> Self {
+        Self::new(F::from(c0), F::from(c1))
+    }
+}
+
+impl<F: Fp2BaseField> FieldImpl for Fp2<F> {
+    type Config = Fp2Cfg<F>;
+    type Repr = [F::Repr; 2];
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self
+            .c0
+            .to_bytes_le();
+        bytes.extend(
+            self.c1
+                .to_bytes_le(),
+        );
+        bytes
+    }
+
+    // Like prime fields, zero-pads when there are not enough bytes and ignores the extra ones
+    fn from_bytes_le(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(
+            bytes
+                .len()
+                .min(std::mem::size_of::<F>()),
+        );
+        Self::new(F::from_bytes_le(c0), F::from_bytes_le(c1))
+    }
+
+    fn from_hex(s: &str) -> Self {
+        let mut bytes = Vec::from_hex(
+            s.strip_prefix("0x")
+                .unwrap_or(s),
+        )
+        .expect("Invalid hex string");
+        bytes.reverse();
+        Self::from_bytes_le(&bytes)
+    }
+
+    fn zero() -> Self {
+        Self::from_fp(F::zero())
+    }
+
+    fn one() -> Self {
+        Self::from_fp(F::one())
+    }
+
+    fn from_u32(val: u32) -> Self {
+        Self::from_fp(F::from_u32(val))
+    }
+}
+
+impl<F: Fp2BaseField> Add for Fp2<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1)
+    }
+}
+
+impl<F: Fp2BaseField> Sub for Fp2<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1)
+    }
+}
+
+impl<F: Fp2BaseField> Mul for Fp2<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        Self::new(v0 + F::fp2_non_residue() * v1, c1)
+    }
+}
+
+impl<F: Fp2BaseField> Neg for Fp2<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl<F: Fp2BaseField> FieldArithmetic for Fp2<F> {
+    fn sqr(&self) -> Self {
+        *self * *self
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        let mut res = Self::one();
+        for bit in (0..64).rev() {
+            res = res.sqr();
+            if (exp >> bit) & 1 == 1 {
+                res = res * *self;
+            }
+        }
+        res
+    }
+
+    fn inv(&self) -> Option<Self> {
+        self.norm()
+            .inv()
+            .map(|norm_inv| {
+                self.conjugate()
+                    .mul_by_fp(&norm_inv)
+            })
+    }
+
+    // The device converts both coefficients
+    fn to_montgomery(&self) -> Self {
+        Self::new(
+            self.c0
+                .to_montgomery(),
+            self.c1
+                .to_montgomery(),
+        )
+    }
+
+    fn from_montgomery(&self) -> Self {
+        Self::new(
+            self.c0
+                .from_montgomery(),
+            self.c1
+                .from_montgomery(),
+        )
+    }
+
+    fn modulus_bytes_le() -> Vec<u8> {
+        F::modulus_bytes_le()
+    }
+}
+
+#[cfg(feature = "arkworks")]
+impl<F> ArkConvertible for Fp2<F>
+where
+    F: Fp2BaseField + ArkConvertible<ArkEquivalent = <F::Config as FieldConfig>::ArkField>,
+{
+    type ArkEquivalent = ark_ff::Fp2<F::ArkFp2Config>;
+
+    fn to_ark(&self) -> Self::ArkEquivalent {
+        ark_ff::Fp2::new(
+            self.c0
+                .to_ark(),
+            self.c1
+                .to_ark(),
+        )
+    }
+
+    fn from_ark(ark: Self::ArkEquivalent) -> Self {
+        Self::new(F::from_ark(ark.c0), F::from_ark(ark.c1))
+    }
+}
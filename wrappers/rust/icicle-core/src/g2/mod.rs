@@ -0,0 +1,40 @@
+//! G2 of pairing-friendly curves, whose points have coordinates in the quadratic extension [Fp2] of the base field.
+//!
+//! Curve crates define their G2 base field as `Fp2<BaseField>`, so G2 points get the host group law of
+//! [Projective](crate::curve::Projective), the encoding of [Affine::to_bytes_le](crate::curve::Affine::to_bytes_le)
+//! and arkworks conversions through `Fq2`, while keeping the layout the C++ library expects.
+
+mod fp2;
+pub use fp2::{Fp2, Fp2BaseField, Fp2Cfg};
+
+#[cfg(feature = "arkworks")]
+#[doc(hidden)]
+pub mod tests;
+
+#[macro_export]
+macro_rules! impl_g2_tests {
+    (
+      $base_field:ident,
+      $curve:ident
+    ) => {
+        #[test]
+        fn test_g2_point_equality() {
+            check_g2_point_equality::<$base_field, $curve>()
+        }
+
+        #[test]
+        fn test_fp2_arithmetic() {
+            check_fp2_arithmetic::<$base_field>()
+        }
+
+        #[test]
+        fn test_g2_group_ops() {
+            check_g2_group_ops::<$base_field, $curve>()
+        }
+
+        #[test]
+        fn test_g2_serialization() {
+            check_g2_serialization::<$base_field, $curve>()
+        }
+    };
+}
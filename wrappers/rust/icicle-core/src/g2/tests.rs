@@ -0,0 +1,212 @@
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::IcicleErrorCode;
+use crate::g2::{Fp2, Fp2BaseField};
+use crate::traits::{ArkConvertible, FieldArithmetic, FieldConfig, FieldImpl};
+use ark_ec::models::CurveConfig as ArkCurveConfig;
+use ark_ec::{short_weierstrass::Affine as ArkAffine, AffineRepr, CurveGroup};
+use ark_ff::Field as ArkField;
+use ark_std::{test_rng, UniformRand};
+
+type ArkFp2<F> = ark_ff::Fp2<<F as Fp2BaseField>::ArkFp2Config>;
+type ArkScalar<C> = <<C as Curve>::ArkSWConfig as ArkCurveConfig>::ScalarField;
+
+fn random_points<C: Curve>(size: usize) -> Vec<ArkAffine<C::ArkSWConfig>> {
+    let rng = &mut test_rng();
+    (0..size)
+        .map(|_| (ArkAffine::<C::ArkSWConfig>::generator() * ArkScalar::<C>::rand(rng)).into_affine())
+        .collect()
+}
+
+// Element of `F` whose limbs are all `limb`, like the `[limb; LIMBS]` representations of the other curve tests
+fn repeated_limbs<F: FieldImpl>(limb: u32) -> F {
+    F::from_bytes_le(
+        &limb
+            .to_le_bytes()
+            .repeat(std::mem::size_of::<F>() / 4),
+    )
+}
+
+pub fn check_g2_point_equality<F: Fp2BaseField, C>()
+where
+    C: Curve<BaseField = Fp2<F>>,
+{
+    let twos = Fp2::new(repeated_limbs::<F>(2), repeated_limbs::<F>(2));
+    let fours = Fp2::new(repeated_limbs::<F>(4), repeated_limbs::<F>(4));
+    let left = Projective::<C>::zero();
+    let right = Projective::<C>::zero();
+    assert_eq!(left, right);
+    let right = Projective::<C> {
+        x: Fp2::zero(),
+        y: twos,
+        z: Fp2::zero(),
+    };
+    assert_eq!(left, right);
+    let right = Projective::<C> {
+        x: Fp2::zero(),
+        y: fours,
+        z: Fp2::from_u32(2),
+    };
+    assert_ne!(left, right);
+    let left = Projective::<C> {
+        x: Fp2::zero(),
+        y: twos,
+        z: Fp2::one(),
+    };
+    assert_eq!(left, right);
+}
+
+pub fn check_fp2_arithmetic<F>()
+where
+    F: Fp2BaseField + ArkConvertible<ArkEquivalent = <F::Config as FieldConfig>::ArkField>,
+{
+    let rng = &mut test_rng();
+    for _ in 0..64 {
+        let (a_ark, b_ark) = (ArkFp2::<F>::rand(rng), ArkFp2::<F>::rand(rng));
+        let (a, b) = (Fp2::<F>::from_ark(a_ark), Fp2::<F>::from_ark(b_ark));
+        assert_eq!(a.to_ark(), a_ark);
+        assert_eq!((a + b).to_ark(), a_ark + b_ark);
+        assert_eq!((a - b).to_ark(), a_ark - b_ark);
+        assert_eq!((a * b).to_ark(), a_ark * b_ark);
+        assert_eq!((-a).to_ark(), -a_ark);
+        assert_eq!(
+            a.sqr()
+                .to_ark(),
+            a_ark.square()
+        );
+        assert_eq!(
+            a.pow(5)
+                .to_ark(),
+            a_ark.pow([5u64])
+        );
+        assert_eq!(
+            a.inv()
+                .map(|inv| inv.to_ark()),
+            a_ark.inverse()
+        );
+        let mut frobenius = a_ark;
+        frobenius.frobenius_map_in_place(1);
+        assert_eq!(
+            a.conjugate()
+                .to_ark(),
+            frobenius
+        );
+        assert_eq!(
+            a.norm()
+                .to_ark(),
+            a_ark.norm()
+        );
+
+        // Both coefficients go to Montgomery form, and the encoding is `c0` then `c1`
+        let a_mont = a.to_montgomery();
+        assert_eq!(a_mont.c0, a.c0.to_montgomery());
+        assert_eq!(a_mont.c1, a.c1.to_montgomery());
+        assert_eq!(a_mont.from_montgomery(), a);
+        assert_eq!(Fp2::<F>::from_bytes_le(&a.to_bytes_le()), a);
+        assert_eq!(a.to_bytes_le()[..std::mem::size_of::<F>()], a.c0.to_bytes_le());
+    }
+    let u = Fp2::new(F::zero(), F::one());
+    assert_eq!(u.sqr(), Fp2::from_fp(F::fp2_non_residue()));
+    assert_eq!(Fp2::<F>::zero().inv(), None);
+    assert_eq!(Fp2::<F>::from_u32(2) * Fp2::from_u32(3), Fp2::from_u32(6));
+    assert_eq!(Fp2::<F>::modulus_bytes_le(), F::modulus_bytes_le());
+}
+
+pub fn check_g2_group_ops<F: Fp2BaseField, C>()
+where
+    C: Curve<BaseField = Fp2<F>>,
+    C::ScalarField: ArkConvertible<ArkEquivalent = ArkScalar<C>>,
+    Fp2<F>: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField>,
+{
+    let rng = &mut test_rng();
+    let points_ark = random_points::<C>(16);
+    let mut sums = Vec::new();
+    let mut sums_ark = Vec::new();
+    for pair in points_ark.chunks(2) {
+        let (p_ark, q_ark) = (pair[0].into_group(), pair[1].into_group());
+        let (p, q) = (Affine::<C>::from_ark(pair[0]), Affine::<C>::from_ark(pair[1]));
+        let (p_proj, q_proj) = (p.to_projective(), q.to_projective());
+        assert_eq!((p_proj + q_proj).to_ark(), p_ark + q_ark);
+        assert_eq!((p_proj - q_proj).to_ark(), p_ark - q_ark);
+        assert_eq!((-p_proj).to_ark(), -p_ark);
+        assert_eq!((-p).to_ark(), -pair[0]);
+        assert_eq!(
+            p_proj
+                .double()
+                .to_ark(),
+            p_ark + p_ark
+        );
+        assert_eq!(
+            p_proj
+                .add_affine(&q)
+                .to_ark(),
+            p_ark + q_ark
+        );
+        assert_eq!(
+            (p_proj + q_proj)
+                .normalize()
+                .to_ark(),
+            (p_ark + q_ark).into_affine()
+        );
+        let scalar_ark = ArkScalar::<C>::rand(rng);
+        assert_eq!(
+            p_proj
+                .mul_scalar(&C::ScalarField::from_ark(scalar_ark))
+                .to_ark(),
+            p_ark * scalar_ark
+        );
+        assert!((p_proj - p.to_projective()).is_zero());
+        assert_eq!(
+            p_proj
+                .add_affine(&Affine::zero())
+                .to_ark(),
+            p_ark
+        );
+        sums.push(p_proj + q_proj);
+        sums_ark.push(p_ark + q_ark);
+    }
+    sums.push(Projective::zero());
+    sums_ark.push(ArkAffine::<C::ArkSWConfig>::zero().into_group());
+    for (affine, expected) in Projective::batch_normalize(&sums)
+        .iter()
+        .zip(CurveGroup::normalize_batch(&sums_ark))
+    {
+        assert_eq!(affine.to_ark(), expected);
+    }
+}
+
+pub fn check_g2_serialization<F: Fp2BaseField, C>()
+where
+    C: Curve<BaseField = Fp2<F>>,
+    Fp2<F>: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField>,
+{
+    // The device layout is four prime field elements: `x.c0`, `x.c1`, `y.c0` and `y.c1`
+    let coefficient_size = std::mem::size_of::<F>();
+    assert_eq!(std::mem::size_of::<Affine<C>>(), 4 * coefficient_size);
+
+    let mut points: Vec<Affine<C>> = random_points::<C>(8)
+        .into_iter()
+        .map(Affine::from_ark)
+        .collect();
+    points.push(Affine::zero());
+    for point in points {
+        let bytes = point.to_bytes_le();
+        assert_eq!(bytes.len(), 4 * coefficient_size);
+        assert_eq!(
+            bytes[coefficient_size..2 * coefficient_size],
+            point
+                .x
+                .c1
+                .to_bytes_le()
+        );
+        assert_eq!(
+            bytes[2 * coefficient_size..3 * coefficient_size],
+            point
+                .y
+                .c0
+                .to_bytes_le()
+        );
+        assert_eq!(Affine::<C>::from_bytes_le(&bytes).unwrap(), point);
+        let err = Affine::<C>::from_bytes_le(&bytes[1..]).unwrap_err();
+        assert_eq!(err.get_icicle_error_code(), IcicleErrorCode::InvalidArgument);
+    }
+}
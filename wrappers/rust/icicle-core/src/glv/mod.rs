@@ -0,0 +1,262 @@
+use crate::curve::{Affine, Curve};
+use crate::error::{IcicleError, IcicleResult};
+use crate::field::utils::limbs_from_bytes_le;
+use crate::msm::MSMConfig;
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+#[cfg(feature = "arkworks")]
+#[doc(hidden)]
+pub mod tests;
+
+/// Curves `y² = x³ + b` with the endomorphism `φ(x, y) = (β x, y)`, `β` being a cube root of unity of the base field,
+/// which acts on the prime order subgroup as the multiplication by a cube root of unity `λ` of the scalar field.
+///
+/// With a short basis of the lattice `{(a, b) : a + b λ = 0 mod r}`, any scalar `k` splits into `k1 + k2 λ` with
+/// `k1` and `k2` of about half the bits of `r`, so `k P = k1 P + k2 φ(P)` takes half the doublings.
+pub trait GlvCurve: Curve {
+    /// `β`, big-endian hex.
+    const BETA: &'static str;
+    /// `λ`, big-endian hex.
+    const LAMBDA: &'static str;
+    /// Short basis `[(a1, b1), (a2, b2)]` of the lattice, ordered so that `a1 b2 - a2 b1 = r`. Each coordinate is its
+    /// sign, `true` for negative, and its absolute value.
+    const BASIS: [[(bool, u128); 2]; 2];
+}
+
+// `a b` for little-endian limbs `a`
+fn mul_u128(a: &[u64], b: u128) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + 2];
+    for (j, b_limb) in [b as u64, (b >> 64) as u64]
+        .into_iter()
+        .enumerate()
+    {
+        let mut carry = 0u128;
+        for (i, a_limb) in a
+            .iter()
+            .enumerate()
+        {
+            let t = *a_limb as u128 * b_limb as u128 + product[i + j] as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        for limb in product[a.len() + j..].iter_mut() {
+            let t = *limb as u128 + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+    }
+    product
+}
+
+fn greater_or_equal(a: &[u64], b: &[u64]) -> bool {
+    let len = a
+        .len()
+        .max(b.len());
+    for i in (0..len).rev() {
+        let (a_limb, b_limb) = (
+            a.get(i)
+                .copied()
+                .unwrap_or(0),
+            b.get(i)
+                .copied()
+                .unwrap_or(0),
+        );
+        if a_limb != b_limb {
+            return a_limb > b_limb;
+        }
+    }
+    true
+}
+
+// `a - b` in place, `a` being at least `b`
+fn sub_assign(a: &mut [u64], b: &[u64]) {
+    let mut borrow = false;
+    for (i, limb) in a
+        .iter_mut()
+        .enumerate()
+    {
+        let (diff, borrow1) = limb.overflowing_sub(
+            b.get(i)
+                .copied()
+                .unwrap_or(0),
+        );
+        let (diff, borrow2) = diff.overflowing_sub(borrow as u64);
+        *limb = diff;
+        borrow = borrow1 || borrow2;
+    }
+}
+
+fn shl1(a: &mut [u64], bit: u64) {
+    let mut carry = bit;
+    for limb in a.iter_mut() {
+        let next = *limb >> 63;
+        *limb = (*limb << 1) | carry;
+        carry = next;
+    }
+}
+
+// `num / den` rounded to the nearest integer by binary long division, the quotient being smaller than 2^128
+fn round_div(num: &[u64], den: &[u64]) -> u128 {
+    let mut rem = vec![0u64; den.len() + 1];
+    let mut quotient = 0u128;
+    for bit in (0..64 * num.len()).rev() {
+        shl1(&mut rem, (num[bit / 64] >> (bit % 64)) & 1);
+        quotient <<= 1;
+        if greater_or_equal(&rem, den) {
+            sub_assign(&mut rem, den);
+            quotient |= 1;
+        }
+    }
+    shl1(&mut rem, 0);
+    quotient + greater_or_equal(&rem, den) as u128
+}
+
+pub(crate) fn bit_len<F: FieldImpl>(x: &F) -> usize {
+    let bytes = x.to_bytes_le();
+    match bytes
+        .iter()
+        .rposition(|byte| *byte != 0)
+    {
+        Some(top) => 8 * top + 8 - bytes[top].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+fn from_signed<F: FieldArithmetic>((negative, magnitude): (bool, u128)) -> F {
+    let x = F::from_bytes_le(&magnitude.to_le_bytes());
+    if negative {
+        -x
+    } else {
+        x
+    }
+}
+
+/// Number of bits bounding the absolute values of the halves of [decompose]: `|k1|` is at most `(|a1| + |a2|) / 2`
+/// and `|k2|` at most `(|b1| + |b2|) / 2`.
+pub fn glv_bitsize<C: GlvCurve>() -> usize {
+    let [[(_, a1), (_, b1)], [(_, a2), (_, b2)]] = C::BASIS;
+    let bits = |sum: Option<u128>| sum.map_or(129, |sum| 128 - sum.leading_zeros() as usize);
+    bits(a1.checked_add(a2)).max(bits(b1.checked_add(b2)))
+}
+
+/// Splits `k`, in normal form, into `k = k1 + k2 λ mod r`.
+///
+/// Returns the absolute values of `k1` and `k2`, smaller than `2^glv_bitsize()`, and their signs, `true` for
+/// negative. `(k1, k2)` is `(k, 0)` minus the closest lattice point, found by rounding the coordinates of `(k, 0)` in
+/// the basis of [GlvCurve::BASIS].
+pub fn decompose<C: GlvCurve>(k: &C::ScalarField) -> (C::ScalarField, C::ScalarField, (bool, bool))
+where
+    C::ScalarField: FieldArithmetic,
+{
+    let [[a1, (b1_negative, b1)], [a2, (b2_negative, b2)]] = C::BASIS;
+    let r = limbs_from_bytes_le(&C::ScalarField::modulus_bytes_le());
+    let k_limbs = limbs_from_bytes_le(&k.to_bytes_le());
+    // Coordinates `(k b2, -k b1) / r` of `(k, 0)`
+    let c1: C::ScalarField = from_signed((b2_negative, round_div(&mul_u128(&k_limbs, b2), &r)));
+    let c2: C::ScalarField = from_signed((!b1_negative, round_div(&mul_u128(&k_limbs, b1), &r)));
+    let k1 = *k - c1 * from_signed(a1) - c2 * from_signed(a2);
+    let k2 = -(c1 * from_signed((b1_negative, b1)) + c2 * from_signed((b2_negative, b2)));
+
+    let abs = |x: C::ScalarField| {
+        let minus_x = -x;
+        if bit_len(&minus_x) < bit_len(&x) {
+            (minus_x, true)
+        } else {
+            (x, false)
+        }
+    };
+    let (k1, k1_negative) = abs(k1);
+    let (k2, k2_negative) = abs(k2);
+    (k1, k2, (k1_negative, k2_negative))
+}
+
+/// `φ(P) = (β x, y)`, which is `λ P` for points of the prime order subgroup. The zero point stays zero, and since
+/// `φ` is linear in `x` it maps points in Montgomery form to points in Montgomery form.
+pub fn endomorphism<C: GlvCurve>(point: &Affine<C>) -> Affine<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    endomorphism_with(point, C::BaseField::from_hex(C::BETA))
+}
+
+pub(crate) fn endomorphism_with<C: Curve>(point: &Affine<C>, beta: C::BaseField) -> Affine<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    if *point == Affine::zero() {
+        return *point;
+    }
+    Affine {
+        x: point.x * beta,
+        y: point.y,
+    }
+}
+
+/// Scalars and bases of the MSM of a [glv_split].
+pub type GlvSplit<C> = (Vec<<C as Curve>::ScalarField>, Vec<Affine<C>>);
+
+/// Splits an MSM of `n` scalars into an MSM of `2 n` scalars of [glv_bitsize] bits, to be passed to
+/// [msm](crate::msm::msm) with `cfg` and `bitsize` set to [glv_bitsize].
+///
+/// The scalars are `k1` of every scalar followed by `k2` of every scalar, and the bases are `P` followed by `φ(P)`,
+/// negated where the half of the scalar is negative. Scalars and points are read and returned in the forms of `cfg`.
+pub fn glv_split<C: GlvCurve>(
+    scalars: &HostSlice<C::ScalarField>,
+    points: &HostSlice<Affine<C>>,
+    cfg: &MSMConfig,
+) -> IcicleResult<GlvSplit<C>>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if scalars.len() != points.len() {
+        return Err(IcicleError::invalid_argument(
+            "GLV split needs as many points as scalars",
+        ));
+    }
+    let beta = C::BaseField::from_hex(C::BETA);
+    let len = scalars.len();
+    let mut split_scalars = vec![C::ScalarField::zero(); 2 * len];
+    let mut split_points = vec![Affine::<C>::zero(); 2 * len];
+    for (i, (scalar, point)) in scalars
+        .as_slice()
+        .iter()
+        .zip(points.as_slice())
+        .enumerate()
+    {
+        let scalar = if cfg.are_scalars_montgomery_form {
+            scalar.from_montgomery()
+        } else {
+            *scalar
+        };
+        let (k1, k2, (k1_negative, k2_negative)) = decompose::<C>(&scalar);
+        let endo_point = endomorphism_with(point, beta);
+        for (index, k, negative, point) in [(i, k1, k1_negative, *point), (len + i, k2, k2_negative, endo_point)] {
+            split_scalars[index] = if cfg.are_scalars_montgomery_form {
+                k.to_montgomery()
+            } else {
+                k
+            };
+            split_points[index] = if negative { -point } else { point };
+        }
+    }
+    Ok((split_scalars, split_points))
+}
+
+#[macro_export]
+macro_rules! impl_glv_tests {
+    (
+      $curve:ident
+    ) => {
+        #[test]
+        fn test_glv_decomposition() {
+            check_glv_decomposition::<$curve>()
+        }
+
+        #[test]
+        fn test_glv_msm() {
+            check_glv_msm::<$curve>()
+        }
+    };
+}
@@ -0,0 +1,143 @@
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::IcicleErrorCode;
+use crate::glv::{bit_len, decompose, endomorphism, glv_bitsize, glv_split, GlvCurve};
+use crate::msm::tests::ark_points_and_scalars;
+use crate::msm::{glv_msm_host, msm_batch_host, MSMConfig, MsmJob};
+use crate::traits::{ArkConvertible, FieldArithmetic, FieldImpl};
+use ark_ec::models::CurveConfig as ArkCurveConfig;
+use ark_ec::{CurveGroup, VariableBaseMSM};
+use icicle_cuda_runtime::memory::HostSlice;
+
+type ArkProjective<C> = ark_ec::models::short_weierstrass::Projective<<C as Curve>::ArkSWConfig>;
+
+pub fn check_glv_decomposition<C: GlvCurve>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField> + FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    let lambda = C::ScalarField::from_hex(C::LAMBDA);
+    let (points_ark, scalars_ark) = ark_points_and_scalars::<C>(64);
+    let mut scalars: Vec<C::ScalarField> = scalars_ark
+        .iter()
+        .map(|scalar| C::ScalarField::from_ark(*scalar))
+        .collect();
+    scalars.extend([
+        C::ScalarField::zero(),
+        C::ScalarField::one(),
+        -C::ScalarField::one(),
+        lambda,
+        -lambda,
+        lambda * lambda,
+    ]);
+    for k in scalars {
+        let (k1, k2, (k1_negative, k2_negative)) = decompose::<C>(&k);
+        assert!(bit_len(&k1) <= glv_bitsize::<C>());
+        assert!(bit_len(&k2) <= glv_bitsize::<C>());
+        let k1 = if k1_negative { -k1 } else { k1 };
+        let k2 = if k2_negative { -k2 } else { k2 };
+        assert_eq!(k1 + k2 * lambda, k);
+    }
+
+    // `φ` is the multiplication by `λ` on the prime order subgroup
+    let lambda_ark = lambda.to_ark();
+    for point_ark in points_ark {
+        let point = Affine::<C>::from_ark(point_ark);
+        assert_eq!(endomorphism(&point).to_ark(), (point_ark * lambda_ark).into_affine());
+    }
+}
+
+pub fn check_glv_msm<C: GlvCurve>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField> + FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    // Three MSMs re-using the points
+    let (msm_size, batch_size) = (32, 3);
+    let (points_ark, scalars_ark) = ark_points_and_scalars::<C>(msm_size * batch_size);
+    let points_ark = &points_ark[..msm_size];
+    let points: Vec<Affine<C>> = points_ark
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let scalars: Vec<C::ScalarField> = scalars_ark
+        .iter()
+        .map(|scalar| C::ScalarField::from_ark(*scalar))
+        .collect();
+    let expected: Vec<_> = scalars_ark
+        .chunks(msm_size)
+        .map(|chunk| ArkProjective::<C>::msm(points_ark, chunk).unwrap())
+        .collect();
+
+    // Windows chosen from the size, and windows of 4 bits over scalars and points in Montgomery form
+    let scalars_mont: Vec<C::ScalarField> = scalars
+        .iter()
+        .map(|scalar| scalar.to_montgomery())
+        .collect();
+    let points_mont: Vec<Affine<C>> = points
+        .iter()
+        .map(|point| Affine {
+            x: point
+                .x
+                .to_montgomery(),
+            y: point
+                .y
+                .to_montgomery(),
+        })
+        .collect();
+    let mut results = vec![Projective::<C>::zero(); batch_size];
+    for (scalars, points, cfg) in [
+        (&scalars, &points, MSMConfig::default()),
+        (
+            &scalars_mont,
+            &points_mont,
+            MSMConfig::builder()
+                .c(4)
+                .scalars_montgomery_form(true)
+                .points_montgomery_form(true)
+                .build()
+                .unwrap(),
+        ),
+    ] {
+        glv_msm_host(
+            HostSlice::from_slice(scalars),
+            HostSlice::from_slice(points),
+            &cfg,
+            HostSlice::from_mut_slice(&mut results),
+        )
+        .unwrap();
+        for (result, expected) in results
+            .iter()
+            .zip(&expected)
+        {
+            assert_eq!(result.to_ark(), *expected);
+        }
+    }
+
+    // The split doubles the bases and feeds a plain MSM with the bitsize of the halves
+    let (split_scalars, split_points) = glv_split(
+        HostSlice::from_slice(&scalars[..msm_size]),
+        HostSlice::from_slice(&points),
+        &MSMConfig::default(),
+    )
+    .unwrap();
+    assert_eq!(split_scalars.len(), 2 * msm_size);
+    let cfg = MSMConfig::builder()
+        .bitsize(glv_bitsize::<C>() as i32)
+        .build()
+        .unwrap();
+    let job = MsmJob {
+        scalars: HostSlice::from_slice(&split_scalars),
+        points: HostSlice::from_slice(&split_points),
+    };
+    let mut result = vec![Projective::<C>::zero(); 1];
+    msm_batch_host(&[job], &cfg, HostSlice::from_mut_slice(&mut result)).unwrap();
+    assert_eq!(result[0].to_ark(), expected[0]);
+
+    let err = glv_split(
+        HostSlice::from_slice(&scalars),
+        HostSlice::from_slice(&points),
+        &MSMConfig::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.get_icicle_error_code(), IcicleErrorCode::InvalidArgument);
+}
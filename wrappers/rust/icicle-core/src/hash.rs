@@ -4,21 +4,20 @@ use icicle_cuda_runtime::{
     device::check_device,
     device_context::{DeviceContext, DEFAULT_DEVICE_ID},
     memory::HostOrDeviceSlice,
+    stream::CudaStream,
 };
 
-use crate::ntt::IcicleResult;
+use crate::{error::IcicleError, ntt::IcicleResult};
 
-/// Struct that encodes Sponge hash parameters.
+/// Struct that encodes Sponge hash parameters. Built with [HashConfig::builder] or the `default_config` of a hasher,
+/// the parameters can be read back through the accessors.
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct HashConfig<'a> {
-    /// Details related to the device such as its id and stream id. See [DeviceContext](@ref device_context::DeviceContext).
-    pub ctx: DeviceContext<'a>,
-    pub are_inputs_on_device: bool,
-    pub are_outputs_on_device: bool,
-    /// Whether to run the sponge operations asynchronously. If set to `true`, the functions will be non-blocking and you'd need to synchronize
-    /// it explicitly by running `stream.synchronize()`. If set to false, the functions will block the current CPU thread.
-    pub is_async: bool,
+    pub(crate) ctx: DeviceContext<'a>,
+    are_inputs_on_device: bool,
+    are_outputs_on_device: bool,
+    pub(crate) is_async: bool,
 }
 
 impl<'a> Default for HashConfig<'a> {
@@ -28,6 +27,29 @@ impl<'a> Default for HashConfig<'a> {
 }
 
 impl<'a> HashConfig<'a> {
+    /// Details related to the device such as its id and stream id. See [DeviceContext](@ref device_context::DeviceContext).
+    pub fn ctx(&self) -> &DeviceContext<'a> {
+        &self.ctx
+    }
+
+    /// Whether to run the sponge operations asynchronously. If set to `true`, the functions will be non-blocking and you'd need to synchronize
+    /// it explicitly by running `stream.synchronize()`. If set to false, the functions will block the current CPU thread.
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// Copy of the config for hashing `inputs` into `outputs`, recording whether each of them is on the device.
+    pub fn for_slices<I, O>(
+        &self,
+        inputs: &(impl HostOrDeviceSlice<I> + ?Sized),
+        outputs: &(impl HostOrDeviceSlice<O> + ?Sized),
+    ) -> Self {
+        let mut cfg = self.clone();
+        cfg.are_inputs_on_device = inputs.is_on_device();
+        cfg.are_outputs_on_device = outputs.is_on_device();
+        cfg
+    }
+
     pub(crate) fn default_for_device(device_id: usize) -> Self {
         HashConfig {
             ctx: DeviceContext::default_for_device(device_id),
@@ -36,6 +58,54 @@ impl<'a> HashConfig<'a> {
             is_async: false,
         }
     }
+
+    /// Starts a [HashConfigBuilder] from the default config of the default device.
+    pub fn builder() -> HashConfigBuilder<'a> {
+        Self::builder_for_device(DEFAULT_DEVICE_ID)
+    }
+
+    /// Starts a [HashConfigBuilder] from the default config of device `device_id`.
+    pub fn builder_for_device(device_id: usize) -> HashConfigBuilder<'a> {
+        HashConfigBuilder {
+            cfg: Self::default_for_device(device_id),
+        }
+    }
+}
+
+/// Builder of [HashConfig]. Parameters that aren't set keep their default values, and whether inputs and outputs
+/// are on the device is taken from the slices passed to the hash functions.
+#[derive(Debug, Clone)]
+pub struct HashConfigBuilder<'a> {
+    cfg: HashConfig<'a>,
+}
+
+impl<'a> HashConfigBuilder<'a> {
+    /// Device context to run the hash in, see [HashConfig::ctx].
+    pub fn ctx(mut self, ctx: DeviceContext<'a>) -> Self {
+        self.cfg
+            .ctx = ctx;
+        self
+    }
+
+    /// Stream of the device context to run the hash on.
+    pub fn stream(mut self, stream: &'a CudaStream) -> Self {
+        self.cfg
+            .ctx
+            .stream = stream;
+        self
+    }
+
+    /// See [HashConfig::is_async].
+    pub fn is_async(mut self, is_async: bool) -> Self {
+        self.cfg
+            .is_async = is_async;
+        self
+    }
+
+    /// Returns the config, none of its parameters can be out of range.
+    pub fn build(self) -> HashConfig<'a> {
+        self.cfg
+    }
 }
 
 pub trait SpongeHash<PreImage, Image> {
@@ -118,3 +188,44 @@ pub(crate) fn sponge_check_outputs<T>(
     }
     check_device(ctx_device_id);
 }
+
+/// Views the arguments of a host sponge as plain slices. Sizes are checked the same way as in
+/// [sponge_check_input] and [sponge_check_outputs], device memory is rejected.
+pub(crate) fn sponge_host_slices<'a, 'b, PreImage, Image>(
+    inputs: &'a (impl HostOrDeviceSlice<PreImage> + ?Sized),
+    output: &'b mut (impl HostOrDeviceSlice<Image> + ?Sized),
+    number_of_states: usize,
+    input_block_len: usize,
+    input_rate: usize,
+    output_len: usize,
+) -> IcicleResult<(&'a [PreImage], &'b mut [Image])> {
+    if inputs.is_on_device() || output.is_on_device() {
+        return Err(IcicleError::invalid_argument(
+            "Host sponges only accept inputs and outputs in host memory",
+        ));
+    }
+    if input_block_len > input_rate {
+        panic!(
+            "input block len ({}) can't be greater than input rate ({})",
+            input_block_len, input_rate
+        );
+    }
+    if inputs.len() < input_block_len * number_of_states {
+        panic!(
+            "inputs len is {}; but needs to be at least {}",
+            inputs.len(),
+            input_block_len * number_of_states,
+        );
+    }
+    if output.len() < output_len * number_of_states {
+        panic!(
+            "outputs len is {}; but needs to be at least {}",
+            output.len(),
+            output_len * number_of_states,
+        );
+    }
+
+    let inputs = unsafe { std::slice::from_raw_parts(inputs.as_ptr(), input_block_len * number_of_states) };
+    let output = unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr(), output_len * number_of_states) };
+    Ok((inputs, output))
+}
@@ -0,0 +1,536 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::curve::{Affine, Curve};
+use crate::error::{IcicleError, IcicleResult};
+use crate::field::utils::pow_limbs;
+use crate::field::Field;
+use crate::g2::{Fp2, Fp2BaseField};
+use crate::traits::{FieldArithmetic, FieldImpl, PrimeFieldConfig};
+
+#[cfg(feature = "arkworks")]
+#[doc(hidden)]
+pub mod tests;
+
+/// Hash function of `expand_message_xmd`, SHA-256 for the suites of RFC 9380.
+pub trait XmdHash {
+    /// `b_in_bytes`, the digest size.
+    const OUTPUT_LEN: usize;
+    /// `s_in_bytes`, the input block size.
+    const BLOCK_LEN: usize;
+
+    fn hash(data: &[u8]) -> Vec<u8>;
+}
+
+/// `expand_message_xmd` of RFC 9380 (section 5.3.1): `len` pseudo-random bytes from the message and the domain
+/// separation tag. Tags longer than 255 bytes are first hashed as described in section 5.3.3.
+pub fn expand_message_xmd<H: XmdHash>(msg: &[u8], dst: &[u8], len: usize) -> IcicleResult<Vec<u8>> {
+    let ell = len.div_ceil(H::OUTPUT_LEN);
+    if ell > 255 || len > 65535 {
+        return Err(IcicleError::invalid_argument("expand_message_xmd output is too long"));
+    }
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        long_dst = H::hash(&[b"H2C-OVERSIZE-DST-", dst].concat());
+        &long_dst[..]
+    } else {
+        dst
+    };
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let mut msg_prime = vec![0u8; H::BLOCK_LEN];
+    msg_prime.extend_from_slice(msg);
+    msg_prime.extend_from_slice(&(len as u16).to_be_bytes());
+    msg_prime.push(0);
+    msg_prime.extend_from_slice(&dst_prime);
+    let b0 = H::hash(&msg_prime);
+
+    let mut uniform_bytes = Vec::with_capacity(ell * H::OUTPUT_LEN);
+    let mut b = H::hash(&[&b0[..], &[1], &dst_prime].concat());
+    for i in 2..=ell {
+        uniform_bytes.extend_from_slice(&b);
+        let xor: Vec<u8> = b0
+            .iter()
+            .zip(&b)
+            .map(|(x, y)| x ^ y)
+            .collect();
+        b = H::hash(&[&xor[..], &[i as u8], &dst_prime].concat());
+    }
+    uniform_bytes.extend_from_slice(&b);
+    uniform_bytes.truncate(len);
+    Ok(uniform_bytes)
+}
+
+/// Field arithmetic of the maps to curves, for prime fields with `p = 3 mod 4` and their quadratic extensions.
+pub trait MapToCurveField:
+    Debug + PartialEq + Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    /// `m`, the degree of the field over the prime field.
+    const DEGREE: usize;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    /// Returns `None` for zero.
+    fn inverse(&self) -> Option<Self>;
+    /// A square root, `None` for non-squares.
+    fn sqrt(&self) -> Option<Self>;
+    /// The sign of the element, as defined in section 4.1 of RFC 9380.
+    fn sgn0(&self) -> bool;
+    /// The element from `m` big-endian integers of equal size, each reduced modulo `p`.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+    /// Little-endian encoding, the coordinates of `Affine` points for `m > 1`.
+    fn to_bytes_le(&self) -> Vec<u8>;
+}
+
+// `(p + 1) / 4` as little-endian limbs
+fn sqrt_exponent<F: FieldArithmetic>() -> Vec<u64> {
+    let mut bytes = F::modulus_bytes_le();
+    bytes.resize(
+        bytes
+            .len()
+            .next_multiple_of(8)
+            + 8,
+        0,
+    );
+    let mut limbs: Vec<u64> = bytes
+        .chunks(8)
+        .map(|chunk| {
+            u64::from_le_bytes(
+                chunk
+                    .try_into()
+                    .unwrap(),
+            )
+        })
+        .collect();
+    let mut carry = true;
+    for limb in limbs.iter_mut() {
+        (*limb, carry) = limb.overflowing_add(carry as u64);
+    }
+    limbs
+        .windows(2)
+        .map(|pair| pair[0] >> 2 | pair[1] << 62)
+        .collect()
+}
+
+impl<const NUM_LIMBS: usize, F: PrimeFieldConfig> MapToCurveField for Field<NUM_LIMBS, F> {
+    const DEGREE: usize = 1;
+
+    fn zero() -> Self {
+        FieldImpl::zero()
+    }
+
+    fn one() -> Self {
+        FieldImpl::one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.inv()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        let root = pow_limbs(*self, FieldImpl::one(), &sqrt_exponent::<Self>());
+        (root * root == *self).then_some(root)
+    }
+
+    fn sgn0(&self) -> bool {
+        FieldImpl::to_bytes_le(self)[0] & 1 == 1
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let base = Self::from_u32(256);
+        bytes
+            .iter()
+            .fold(FieldImpl::zero(), |acc, byte| acc * base + Self::from_u32(*byte as u32))
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        FieldImpl::to_bytes_le(self)
+    }
+}
+
+impl<F: Fp2BaseField + MapToCurveField> MapToCurveField for Fp2<F> {
+    const DEGREE: usize = 2 * F::DEGREE;
+
+    fn zero() -> Self {
+        FieldImpl::zero()
+    }
+
+    fn one() -> Self {
+        FieldImpl::one()
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.inv()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        let beta = F::fp2_non_residue();
+        let one = <F as MapToCurveField>::one();
+        let two_inv = (one + one)
+            .inverse()
+            .unwrap();
+        let root = if self.c1 == MapToCurveField::zero() {
+            // Either c0 is a square, or c0 / β is
+            match self
+                .c0
+                .sqrt()
+            {
+                Some(root) => Fp2::from_fp(root),
+                None => Fp2::new(
+                    MapToCurveField::zero(),
+                    (self.c0
+                        * beta
+                            .inverse()
+                            .unwrap())
+                    .sqrt()?,
+                ),
+            }
+        } else {
+            // (x0 + x1 u)² = a0 + a1 u with x0² = (a0 ± α) / 2, α² = a0² - β a1², and x1 = a1 / 2 x0
+            let alpha = (self.c0 * self.c0 - beta * self.c1 * self.c1).sqrt()?;
+            let x0 = match ((self.c0 + alpha) * two_inv).sqrt() {
+                Some(x0) => x0,
+                None => ((self.c0 - alpha) * two_inv).sqrt()?,
+            };
+            let x1 = self.c1
+                * (x0 + x0)
+                    .inverse()
+                    .unwrap();
+            Fp2::new(x0, x1)
+        };
+        (root * root == *self).then_some(root)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.c0
+            .sgn0()
+            || (self.c0 == MapToCurveField::zero()
+                && self
+                    .c1
+                    .sgn0())
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(bytes.len() / 2);
+        Fp2::new(F::from_uniform_bytes(c0), F::from_uniform_bytes(c1))
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        FieldImpl::to_bytes_le(self)
+    }
+}
+
+// `x³ + a x + b`
+fn curve_rhs<F: MapToCurveField>(x: F, a: F, b: F) -> F {
+    x * x * x + a * x + b
+}
+
+// Sets the sign of `y` to the one of `u`
+fn with_sign_of<F: MapToCurveField>(y: F, u: &F) -> F {
+    if y.sgn0() == u.sgn0() {
+        y
+    } else {
+        -y
+    }
+}
+
+/// Simplified Shallue-van de Woestijne-Ulas map (RFC 9380, section 6.6.2) of `u` to `y² = x³ + a x + b`, with
+/// `a b != 0` and `z` the non-square constant of the suite.
+pub fn map_to_curve_sswu<F: MapToCurveField>(u: &F, a: F, b: F, z: F) -> (F, F) {
+    let u2 = *u * *u;
+    let x1 = match (z * z * u2 * u2 + z * u2).inverse() {
+        Some(tv1) => {
+            -b * a
+                .inverse()
+                .unwrap()
+                * (F::one() + tv1)
+        }
+        None => {
+            b * (z * a)
+                .inverse()
+                .unwrap()
+        }
+    };
+    let (x, y) = match curve_rhs(x1, a, b).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z * u2 * x1;
+            (
+                x2,
+                curve_rhs(x2, a, b)
+                    .sqrt()
+                    .unwrap(),
+            )
+        }
+    };
+    (x, with_sign_of(y, u))
+}
+
+/// Shallue-van de Woestijne map (RFC 9380, section 6.6.1) of `u` to `y² = x³ + a x + b`, for any curve and the
+/// constant `z` of the suite.
+pub fn map_to_curve_svdw<F: MapToCurveField>(u: &F, a: F, b: F, z: F) -> (F, F) {
+    let two = F::one() + F::one();
+    let three = two + F::one();
+    let four = two + two;
+    let gz = curve_rhs(z, a, b);
+    let c1 = gz;
+    let c2 = -z
+        * two
+            .inverse()
+            .unwrap();
+    let h = three * z * z + four * a;
+    let c3 = (-gz * h)
+        .sqrt()
+        .unwrap();
+    let c3 = if c3.sgn0() { -c3 } else { c3 };
+    let c4 = -four
+        * gz
+        * h.inverse()
+            .unwrap();
+
+    let tv1 = *u * *u * c1;
+    let tv2 = F::one() + tv1;
+    let tv1 = F::one() - tv1;
+    let tv3 = (tv1 * tv2)
+        .inverse()
+        .unwrap_or(F::zero());
+    let tv4 = *u * tv1 * tv3 * c3;
+    let x1 = c2 - tv4;
+    let x2 = c2 + tv4;
+    let x3 = {
+        let tv = tv2 * tv2 * tv3;
+        tv * tv * c4 + z
+    };
+    let (x, y) = [x1, x2, x3]
+        .into_iter()
+        .find_map(|x| {
+            curve_rhs(x, a, b)
+                .sqrt()
+                .map(|y| (x, y))
+        })
+        .unwrap();
+    (x, with_sign_of(y, u))
+}
+
+/// Rational map `(x_num(x) / x_den(x), y y_num(x) / y_den(x))` from an isogenous curve, coefficients from the
+/// constant term up.
+pub struct IsogenyMap<F> {
+    pub x_numerator: Vec<F>,
+    pub x_denominator: Vec<F>,
+    pub y_numerator: Vec<F>,
+    pub y_denominator: Vec<F>,
+}
+
+impl<F: MapToCurveField> IsogenyMap<F> {
+    /// Image of the point, `None` for the point at infinity.
+    pub fn apply(&self, (x, y): (F, F)) -> Option<(F, F)> {
+        let evaluate = |coefficients: &[F]| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(F::zero(), |acc, coefficient| acc * x + *coefficient)
+        };
+        let x_den_inv = evaluate(&self.x_denominator).inverse()?;
+        let y_den_inv = evaluate(&self.y_denominator).inverse()?;
+        Some((
+            evaluate(&self.x_numerator) * x_den_inv,
+            y * evaluate(&self.y_numerator) * y_den_inv,
+        ))
+    }
+}
+
+// Point of a curve with `a = 0` in Jacobian coordinates, `z = 0` at infinity
+#[derive(Clone, Copy)]
+struct Jacobian<F> {
+    x: F,
+    y: F,
+    z: F,
+}
+
+impl<F: MapToCurveField> Jacobian<F> {
+    fn infinity() -> Self {
+        Self {
+            x: F::one(),
+            y: F::one(),
+            z: F::zero(),
+        }
+    }
+
+    fn from_affine(point: Option<(F, F)>) -> Self {
+        match point {
+            Some((x, y)) => Self { x, y, z: F::one() },
+            None => Self::infinity(),
+        }
+    }
+
+    fn to_affine(self) -> Option<(F, F)> {
+        let z_inv = self
+            .z
+            .inverse()?;
+        let z_inv2 = z_inv * z_inv;
+        Some((self.x * z_inv2, self.y * z_inv2 * z_inv))
+    }
+
+    // dbl-2009-l
+    fn double(&self) -> Self {
+        let a = self.x * self.x;
+        let b = self.y * self.y;
+        let c = b * b;
+        let d = (self.x + b) * (self.x + b) - a - c;
+        let d = d + d;
+        let e = a + a + a;
+        let x = e * e - d - d;
+        let c8 = c + c;
+        let c8 = c8 + c8;
+        let c8 = c8 + c8;
+        let z = self.y * self.z;
+        Self {
+            x,
+            y: e * (d - x) - c8,
+            z: z + z,
+        }
+    }
+
+    // add-2007-bl
+    fn add(&self, other: &Self) -> Self {
+        if self.z == F::zero() {
+            return *other;
+        }
+        if other.z == F::zero() {
+            return *self;
+        }
+        let z1z1 = self.z * self.z;
+        let z2z2 = other.z * other.z;
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        if u1 == u2 {
+            return if s1 == s2 { self.double() } else { Self::infinity() };
+        }
+        let h = u2 - u1;
+        let i = (h + h) * (h + h);
+        let j = h * i;
+        let r = (s2 - s1) + (s2 - s1);
+        let v = u1 * i;
+        let x = r * r - j - v - v;
+        let s1j = s1 * j;
+        let z = (self.z + other.z) * (self.z + other.z) - z1z1 - z2z2;
+        Self {
+            x,
+            y: r * (v - x) - s1j - s1j,
+            z: z * h,
+        }
+    }
+
+    fn mul(&self, scalar: &[u64]) -> Self {
+        let mut res = Self::infinity();
+        for limb in scalar
+            .iter()
+            .rev()
+        {
+            for bit in (0..64).rev() {
+                res = res.double();
+                if (limb >> bit) & 1 == 1 {
+                    res = res.add(self);
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Hash to curve suite of RFC 9380 for a curve with `a = 0`: the field of its coordinates, the map to curve and the
+/// cofactor clearing.
+pub trait HashToCurve: Curve {
+    /// The base field of the curve, in which the coordinates of points are.
+    type Field: MapToCurveField;
+
+    /// `L`, the number of uniform bytes reduced into each prime field element.
+    const L: usize;
+    /// `h_eff`, the scalar clearing the cofactor, little-endian.
+    const H_EFF: &'static [u64];
+
+    /// `map_to_curve` of the suite, `None` for the point at infinity.
+    fn map_to_curve(u: &Self::Field) -> Option<(Self::Field, Self::Field)>;
+}
+
+/// `hash_to_field` of RFC 9380 (section 5.2) with `expand_message_xmd`: `count` elements of `F`, each reduced from
+/// `m * len_per_element` uniform bytes.
+pub fn hash_to_field<F: MapToCurveField, H: XmdHash>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    len_per_element: usize,
+) -> IcicleResult<Vec<F>> {
+    let element_len = F::DEGREE * len_per_element;
+    Ok(expand_message_xmd::<H>(msg, dst, count * element_len)?
+        .chunks(element_len)
+        .map(F::from_uniform_bytes)
+        .collect())
+}
+
+fn to_affine<C: HashToCurve>(point: Option<(C::Field, C::Field)>) -> Affine<C> {
+    match point {
+        Some((x, y)) => Affine {
+            x: C::BaseField::from_bytes_le(&x.to_bytes_le()),
+            y: C::BaseField::from_bytes_le(&y.to_bytes_le()),
+        },
+        None => Affine::zero(),
+    }
+}
+
+/// `map_to_curve` of the suite, without clearing the cofactor.
+pub fn map_to_curve<C: HashToCurve>(u: &C::Field) -> Affine<C> {
+    to_affine::<C>(C::map_to_curve(u))
+}
+
+fn clear_cofactor<C: HashToCurve>(point: &Jacobian<C::Field>) -> Affine<C> {
+    to_affine::<C>(
+        point
+            .mul(C::H_EFF)
+            .to_affine(),
+    )
+}
+
+/// `encode_to_curve` of RFC 9380: a point of the prime order subgroup from a single field element, not uniformly
+/// distributed.
+pub fn encode_to_curve<C: HashToCurve, H: XmdHash>(msg: &[u8], dst: &[u8]) -> IcicleResult<Affine<C>> {
+    let u = hash_to_field::<C::Field, H>(msg, dst, 1, C::L)?;
+    Ok(clear_cofactor::<C>(&Jacobian::from_affine(C::map_to_curve(&u[0]))))
+}
+
+/// `hash_to_curve` of RFC 9380: a uniformly distributed point of the prime order subgroup, the sum of the maps of
+/// two field elements.
+pub fn hash_to_curve<C: HashToCurve, H: XmdHash>(msg: &[u8], dst: &[u8]) -> IcicleResult<Affine<C>> {
+    let u = hash_to_field::<C::Field, H>(msg, dst, 2, C::L)?;
+    let q0 = Jacobian::from_affine(C::map_to_curve(&u[0]));
+    let q1 = Jacobian::from_affine(C::map_to_curve(&u[1]));
+    Ok(clear_cofactor::<C>(&q0.add(&q1)))
+}
+
+/// [hash_to_curve] of many messages in parallel on the host, the points being in the layout [msm](crate::msm::msm)
+/// takes its bases in, for instance to commit against generators nobody knows the discrete logarithms of.
+pub fn hash_to_curve_batch<C: HashToCurve, H: XmdHash>(msgs: &[&[u8]], dst: &[u8]) -> IcicleResult<Vec<Affine<C>>>
+where
+    C::Field: Send + Sync,
+{
+    msgs.par_iter()
+        .map(|msg| hash_to_curve::<C, H>(msg, dst))
+        .collect()
+}
+
+#[macro_export]
+macro_rules! impl_hash_to_curve_tests {
+    (
+      $curve:ident,
+      $hash:ty
+    ) => {
+        #[test]
+        fn test_hash_to_curve() {
+            check_hash_to_curve::<$curve, $hash>()
+        }
+    };
+}
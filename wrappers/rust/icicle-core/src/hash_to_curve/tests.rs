@@ -0,0 +1,103 @@
+use crate::curve::{Affine, Curve};
+use crate::hash_to_curve::{
+    encode_to_curve, hash_to_curve, hash_to_curve_batch, hash_to_field, map_to_curve, HashToCurve, MapToCurveField,
+    XmdHash,
+};
+use crate::traits::ArkConvertible;
+use ark_ec::models::CurveConfig as ArkCurveConfig;
+use ark_ec::short_weierstrass::Affine as ArkAffine;
+
+const DST: &[u8] = b"ICICLE-V01-CS01-with-hash-to-curve-tests";
+
+fn check_in_subgroup<C: Curve>(point: &Affine<C>)
+where
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField>,
+{
+    let ark_point: ArkAffine<C::ArkSWConfig> = point.to_ark();
+    assert_ne!(*point, Affine::zero());
+    assert!(ark_point.is_on_curve());
+    assert!(ark_point.is_in_correct_subgroup_assuming_on_curve());
+}
+
+pub fn check_hash_to_curve<C: HashToCurve, H: XmdHash>()
+where
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField>,
+    C::Field: Send + Sync,
+{
+    let msgs: Vec<Vec<u8>> = (0..4u8)
+        .map(|i| vec![i; 3 * i as usize])
+        .collect();
+    let msgs: Vec<&[u8]> = msgs
+        .iter()
+        .map(|msg| &msg[..])
+        .collect();
+    let points = hash_to_curve_batch::<C, H>(&msgs, DST).unwrap();
+    for (msg, point) in msgs
+        .iter()
+        .zip(&points)
+    {
+        check_in_subgroup(point);
+        assert_eq!(*point, hash_to_curve::<C, H>(msg, DST).unwrap());
+        let encoded = encode_to_curve::<C, H>(msg, DST).unwrap();
+        check_in_subgroup(&encoded);
+        assert_ne!(encoded, *point);
+    }
+    assert_ne!(points[0], points[1]);
+    assert_ne!(points[0], hash_to_curve::<C, H>(msgs[0], b"another tag").unwrap());
+
+    // The maps land on the curve before the cofactor is cleared, including on their exceptional input zero
+    let zero_map = map_to_curve::<C>(&<C::Field as MapToCurveField>::zero());
+    let ark_point: ArkAffine<C::ArkSWConfig> = zero_map.to_ark();
+    assert!(ark_point.is_on_curve());
+}
+
+/// Vector of a random oracle suite: the message, `u0` and `u1`, and the coordinates of `Q0`, `Q1` and `P`.
+pub type HashToCurveVector = (&'static str, [&'static str; 2], [[&'static str; 2]; 3]);
+/// Vector of a nonuniform suite: the message, `u0`, and the coordinates of `Q0` and `P`.
+pub type EncodeToCurveVector = (&'static str, &'static str, [[&'static str; 2]; 2]);
+
+/// Checks `hash_to_field`, `map_to_curve` and `hash_to_curve` against vectors of a random oracle suite, as in RFC 9380
+/// appendix J.
+pub fn check_hash_to_curve_vectors<C: HashToCurve, H: XmdHash>(
+    dst: &[u8],
+    vectors: &[HashToCurveVector],
+    field: impl Fn(&str) -> C::Field,
+    coordinate: impl Fn(&str) -> C::BaseField,
+) {
+    for (msg, u, points) in vectors {
+        let [q0, q1, p] = points.map(|[x, y]| Affine::<C> {
+            x: coordinate(x),
+            y: coordinate(y),
+        });
+        let expected_u = u.map(&field);
+        assert_eq!(
+            hash_to_field::<C::Field, H>(msg.as_bytes(), dst, 2, C::L).unwrap(),
+            expected_u
+        );
+        assert_eq!(map_to_curve::<C>(&expected_u[0]), q0);
+        assert_eq!(map_to_curve::<C>(&expected_u[1]), q1);
+        assert_eq!(hash_to_curve::<C, H>(msg.as_bytes(), dst).unwrap(), p);
+    }
+}
+
+/// Checks `hash_to_field`, `map_to_curve` and `encode_to_curve` against vectors of a nonuniform suite.
+pub fn check_encode_to_curve_vectors<C: HashToCurve, H: XmdHash>(
+    dst: &[u8],
+    vectors: &[EncodeToCurveVector],
+    field: impl Fn(&str) -> C::Field,
+    coordinate: impl Fn(&str) -> C::BaseField,
+) {
+    for (msg, u, points) in vectors {
+        let [q, p] = points.map(|[x, y]| Affine::<C> {
+            x: coordinate(x),
+            y: coordinate(y),
+        });
+        let expected_u = field(u);
+        assert_eq!(
+            hash_to_field::<C::Field, H>(msg.as_bytes(), dst, 1, C::L).unwrap(),
+            [expected_u]
+        );
+        assert_eq!(map_to_curve::<C>(&expected_u), q);
+        assert_eq!(encode_to_curve::<C, H>(msg.as_bytes(), dst).unwrap(), p);
+    }
+}
@@ -4,15 +4,21 @@ pub mod curve;
 pub mod ecntt;
 pub mod error;
 pub mod field;
+pub mod g2;
+pub mod glv;
 pub mod hash;
+pub mod hash_to_curve;
 pub mod msm;
 pub mod ntt;
+pub mod pairing;
 pub mod polynomials;
 pub mod poseidon;
 pub mod poseidon2;
+pub mod sponge;
 #[doc(hidden)]
 pub mod tests;
 pub mod traits;
+pub mod transcript;
 pub mod tree;
 pub mod vec_ops;
 
@@ -0,0 +1,81 @@
+use std::io::{Read, Write};
+
+use crate::curve::Curve;
+use crate::error::{IcicleError, IcicleErrorCode, IcicleResult};
+use crate::traits::FieldArithmetic;
+
+const MAGIC: &[u8; 8] = b"ICICLEMB";
+const VERSION: u32 = 1;
+
+/// Bases stored in a file, recorded after the version so that each kind is only read back as itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub(super) enum BasesKind {
+    Precomputed = 1,
+    FixedBaseTables = 2,
+}
+
+pub(super) fn io_error<T>(result: std::io::Result<T>) -> IcicleResult<T> {
+    result.map_err(|_| IcicleError::from_code_and_reason(IcicleErrorCode::UndefinedError, "MSM bases file I/O failed"))
+}
+
+pub(super) fn read_array<const N: usize>(reader: &mut impl Read) -> IcicleResult<[u8; N]> {
+    let mut bytes = [0u8; N];
+    io_error(reader.read_exact(&mut bytes))?;
+    Ok(bytes)
+}
+
+// The curve is identified by the moduli of its base and scalar fields, each preceded by its length
+fn curve_moduli<C: Curve>() -> [Vec<u8>; 2]
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    [C::BaseField::modulus_bytes_le(), C::ScalarField::modulus_bytes_le()]
+}
+
+/// Writes the magic, the version, the kind of bases and the curve they are on.
+pub(super) fn write_header<C: Curve>(writer: &mut impl Write, kind: BasesKind) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend(VERSION.to_le_bytes());
+    bytes.extend((kind as u32).to_le_bytes());
+    for modulus in curve_moduli::<C>() {
+        bytes.extend((modulus.len() as u64).to_le_bytes());
+        bytes.extend(modulus);
+    }
+    io_error(writer.write_all(&bytes))
+}
+
+/// Reads a header written by [write_header], failing when it isn't one of the current version for bases of `kind`
+/// on the curve `C`.
+pub(super) fn read_header<C: Curve>(reader: &mut impl Read, kind: BasesKind) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if &read_array::<8>(reader)? != MAGIC || u32::from_le_bytes(read_array(reader)?) != VERSION {
+        return Err(IcicleError::invalid_argument(
+            "Not an MSM bases file of a supported version",
+        ));
+    }
+    if u32::from_le_bytes(read_array(reader)?) != kind as u32 {
+        return Err(IcicleError::invalid_argument(
+            "MSM bases file holds another kind of bases",
+        ));
+    }
+    for expected in curve_moduli::<C>() {
+        if u64::from_le_bytes(read_array(reader)?) != expected.len() as u64 {
+            return Err(IcicleError::invalid_argument("MSM bases are for another curve"));
+        }
+        let mut modulus = vec![0u8; expected.len()];
+        io_error(reader.read_exact(&mut modulus))?;
+        if modulus != expected {
+            return Err(IcicleError::invalid_argument("MSM bases are for another curve"));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::{IcicleError, IcicleResult};
+use crate::msm::host::host_msm;
+use crate::msm::{msm, MSMConfig, MSM};
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+/// One MSM of a [msm_batch]: `scalars[0]*points[0] + ... + scalars[n-1]*points[n-1]`, with its own bases.
+#[derive(Debug, Clone, Copy)]
+pub struct MsmJob<'a, C: Curve> {
+    pub scalars: &'a HostSlice<C::ScalarField>,
+    pub points: &'a HostSlice<Affine<C>>,
+}
+
+fn check_jobs<C: Curve>(jobs: &[MsmJob<C>], cfg: &MSMConfig, results_len: usize) -> IcicleResult<()> {
+    if jobs.len() != results_len {
+        return Err(IcicleError::invalid_argument(
+            "Number of results isn't the number of MSM jobs",
+        ));
+    }
+    if cfg.precompute_factor != 1 {
+        return Err(IcicleError::invalid_argument(
+            "MSM jobs take their bases as they are, precompute_factor must be 1",
+        ));
+    }
+    if jobs
+        .iter()
+        .any(|job| {
+            job.scalars
+                .len()
+                != job
+                    .points
+                    .len()
+        })
+    {
+        return Err(IcicleError::invalid_argument(
+            "MSM job with a different number of scalars and points",
+        ));
+    }
+    Ok(())
+}
+
+/// Computes MSMs of different sizes, each with its own bases, writing the sum of job `i` to `results[i]`.
+///
+/// Jobs whose sizes round up to the same power of two are packed into a single batched [msm] on the stream and
+/// device of `cfg`: each is padded with zero scalars and zero points to the largest size of its class, so padding at
+/// most doubles the work and the results are the ones of separate [msm] calls. The call is blocking whatever
+/// `cfg.is_async`, since the packed inputs live on the host until the MSMs are done.
+///
+/// `cfg.precompute_factor` must be 1, the other fields of `cfg` apply to every job. [msm_batch_host] computes the
+/// same results without a device.
+pub fn msm_batch<C: Curve + MSM<C>>(
+    jobs: &[MsmJob<C>],
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()> {
+    check_jobs(jobs, cfg, results.len())?;
+    let mut classes = BTreeMap::<usize, Vec<usize>>::new();
+    for (index, job) in jobs
+        .iter()
+        .enumerate()
+    {
+        match job
+            .scalars
+            .len()
+        {
+            0 => results[index] = Projective::zero(),
+            size => classes
+                .entry(size.next_power_of_two())
+                .or_default()
+                .push(index),
+        }
+    }
+
+    let mut local_cfg = cfg.clone();
+    local_cfg.is_async = false;
+    for indices in classes.values() {
+        let size = indices
+            .iter()
+            .map(|index| {
+                jobs[*index]
+                    .scalars
+                    .len()
+            })
+            .max()
+            .unwrap_or(0);
+        let mut scalars = vec![C::ScalarField::zero(); size * indices.len()];
+        let mut points = vec![Affine::<C>::zero(); size * indices.len()];
+        for (slot, index) in indices
+            .iter()
+            .enumerate()
+        {
+            let job = &jobs[*index];
+            let len = job
+                .scalars
+                .len();
+            scalars[slot * size..slot * size + len].copy_from_slice(
+                job.scalars
+                    .as_slice(),
+            );
+            points[slot * size..slot * size + len].copy_from_slice(
+                job.points
+                    .as_slice(),
+            );
+        }
+        let mut sums = vec![Projective::<C>::zero(); indices.len()];
+        msm(
+            HostSlice::from_slice(&scalars),
+            HostSlice::from_slice(&points),
+            &local_cfg,
+            HostSlice::from_mut_slice(&mut sums),
+        )?;
+        for (index, sum) in indices
+            .iter()
+            .zip(sums)
+        {
+            results[*index] = sum;
+        }
+    }
+    Ok(())
+}
+
+/// Host version of [msm_batch], for machines without a device.
+///
+/// Each job is computed with the bucket method on the host, honoring `cfg.c`, `cfg.bitsize` and the Montgomery
+/// form of scalars and points. It fails when a scalar is wider than `cfg.bitsize`.
+pub fn msm_batch_host<C: Curve>(
+    jobs: &[MsmJob<C>],
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    check_jobs(jobs, cfg, results.len())?;
+    let sums = jobs
+        .iter()
+        .map(|job| {
+            host_msm(
+                job.scalars
+                    .as_slice(),
+                job.points
+                    .as_slice(),
+                cfg,
+            )
+        })
+        .collect::<IcicleResult<Vec<_>>>()?;
+    results
+        .as_mut_slice()
+        .copy_from_slice(&sums);
+    Ok(())
+}
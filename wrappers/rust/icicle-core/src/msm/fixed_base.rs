@@ -0,0 +1,416 @@
+use std::io::{Read, Write};
+use std::mem::size_of;
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::slice::ParallelSlice;
+
+use super::bases_file::{io_error, read_array, read_header, write_header, BasesKind};
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::{IcicleError, IcicleErrorCode, IcicleResult};
+use crate::msm::{msm, MSMConfig, MSM};
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{DeviceVec, HostOrDeviceSlice, HostSlice};
+
+// Largest window chosen when `MSMConfig::c` is 0, beyond it building the tables costs more than most provers save
+const MAX_AUTO_WINDOW_BITS: usize = 8;
+const MAX_WINDOW_BITS: usize = 31;
+
+// Number of `c` bit signed digits of scalars of `bitsize` bits: one more bit than the scalars for the last carry
+pub(super) fn num_windows(bitsize: usize, c: usize) -> usize {
+    (bitsize + 1).div_ceil(c)
+}
+
+// Number of points in the tables of a single base
+fn points_per_base(bitsize: usize, c: usize) -> usize {
+    num_windows(bitsize, c) << (c - 1)
+}
+
+/// Precomputed tables of a fixed set of MSM bases, for [fixed_base_msm] and [fixed_base_msm_host].
+///
+/// For each base `P` and window `j` the tables hold `k 2^(c j) P` for `k` from 1 to `2^(c-1)`. On the host, an MSM
+/// then takes a single mixed addition per base and window of signed `c` bit digits, and no doubling, which pays off
+/// when many commitments are made to the same SRS. The tables take `msm_size * ⌈(bitsize + 1) / c⌉ * 2^(c-1)` affine
+/// points, `c` being chosen to fit the memory budget the handle is built with.
+///
+/// The device MSM takes the shifted copies `2^(c j) P` of the tables instead, in the layout of
+/// [precompute_points](crate::msm::precompute_points), once they are loaded with
+/// [load_to_device](Self::load_to_device).
+///
+/// Handles are built on the host, in normal form, and can be written to disk so that a prover restart doesn't have
+/// to build them again.
+pub struct MsmBasesHandle<C: Curve> {
+    c: usize,
+    bitsize: usize,
+    msm_size: usize,
+    tables: Vec<Affine<C>>,
+    device_bases: Option<DeviceVec<Affine<C>>>,
+}
+
+impl<C: Curve> std::fmt::Debug for MsmBasesHandle<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MsmBasesHandle")
+            .field("c", &self.c)
+            .field("bitsize", &self.bitsize)
+            .field("msm_size", &self.msm_size)
+            .field(
+                "device_bases",
+                &self
+                    .device_bases
+                    .as_ref()
+                    .map(|bases| bases.len()),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+// The device copy of the bases is derived from the tables
+impl<C: Curve> PartialEq for MsmBasesHandle<C> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.c, self.bitsize, self.msm_size) == (other.c, other.bitsize, other.msm_size) && self.tables == other.tables
+    }
+}
+
+impl<C: Curve> MsmBasesHandle<C>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    /// Builds the tables of `points` with at most `memory_budget` bytes.
+    ///
+    /// `cfg.c` fixes the window size when it isn't 0, otherwise the largest window up to 8 bits fitting in the budget
+    /// is taken. `cfg.bitsize` bounds the scalars the same way as for [msm](crate::msm::msm), and
+    /// `cfg.are_points_montgomery_form` tells the form of `points`.
+    pub fn new(points: &HostSlice<Affine<C>>, cfg: &MSMConfig, memory_budget: usize) -> IcicleResult<Self> {
+        if points.is_empty() {
+            return Err(IcicleError::invalid_argument("Fixed-base MSM needs at least one base"));
+        }
+        let bitsize = if cfg.bitsize > 0 {
+            cfg.bitsize as usize
+        } else {
+            scalar_bitsize::<C>()
+        };
+        let table_size = |c: usize| {
+            points
+                .len()
+                .checked_mul(points_per_base(bitsize, c))
+                .and_then(|num_points| num_points.checked_mul(size_of::<Affine<C>>()))
+                .filter(|size| *size <= memory_budget)
+        };
+        let c = match cfg.c {
+            c if c < 0 || c as usize > MAX_WINDOW_BITS => {
+                return Err(IcicleError::invalid_argument("Invalid window size for fixed-base MSM"))
+            }
+            0 => (1..=MAX_AUTO_WINDOW_BITS)
+                .rev()
+                .find(|c| table_size(*c).is_some()),
+            c => table_size(c as usize).map(|_| c as usize),
+        }
+        .ok_or_else(|| {
+            IcicleError::from_code_and_reason(
+                IcicleErrorCode::MemoryAllocationError,
+                "Fixed-base tables don't fit in the memory budget",
+            )
+        })?;
+
+        let are_points_montgomery_form = cfg.are_points_montgomery_form;
+        let tables = points
+            .as_slice()
+            .par_iter()
+            .flat_map_iter(|point| {
+                let point = if are_points_montgomery_form {
+                    Affine {
+                        x: point
+                            .x
+                            .from_montgomery(),
+                        y: point
+                            .y
+                            .from_montgomery(),
+                    }
+                } else {
+                    *point
+                };
+                base_tables(&point, bitsize, c)
+            })
+            .collect();
+        Ok(Self {
+            c,
+            bitsize,
+            msm_size: points.len(),
+            tables,
+            device_bases: None,
+        })
+    }
+
+    /// The window size `c` of the tables.
+    pub fn window_bits(&self) -> usize {
+        self.c
+    }
+
+    /// The largest number of bits of the scalars the tables can multiply.
+    pub fn bitsize(&self) -> usize {
+        self.bitsize
+    }
+
+    /// The number of bases, which is the size of each MSM.
+    pub fn msm_size(&self) -> usize {
+        self.msm_size
+    }
+
+    /// Memory taken by the tables, in bytes.
+    pub fn memory_size(&self) -> usize {
+        self.tables
+            .len()
+            * size_of::<Affine<C>>()
+    }
+
+    /// Copies to the current device the bases shifted by each of `precompute_factor` groups of windows, for
+    /// [fixed_base_msm].
+    ///
+    /// `precompute_factor` is at most the number of windows `⌈bitsize / c⌉`, at which the device MSM doubles no
+    /// point. The copies are a subset of the tables and take `precompute_factor * msm_size` points of device memory.
+    /// Each copy is shifted by `⌈windows / precompute_factor⌉` windows from the previous one, so factors whose last
+    /// copy would start past the last window, like 9 for 10 windows, are rejected.
+    pub fn load_to_device(&mut self, precompute_factor: usize) -> IcicleResult<()> {
+        let windows = self
+            .bitsize
+            .div_ceil(self.c);
+        if precompute_factor == 0 || precompute_factor > windows {
+            return Err(IcicleError::invalid_argument(
+                "Precompute factor of fixed-base bases must be between 1 and the number of windows",
+            ));
+        }
+        // The device MSM reads the digits of window `j` from the copy `j / stride`, shifted by `c * stride` bits
+        let stride = windows.div_ceil(precompute_factor);
+        if (precompute_factor - 1) * stride >= windows {
+            return Err(IcicleError::invalid_argument(
+                "Precompute factor of fixed-base bases shifts its last copy past the last window",
+            ));
+        }
+        let per_base = points_per_base(self.bitsize, self.c);
+        let half = 1usize << (self.c - 1);
+        let shifted: Vec<Affine<C>> = (0..precompute_factor)
+            .flat_map(|copy| {
+                self.tables
+                    .iter()
+                    .skip(copy * stride * half)
+                    .step_by(per_base)
+                    .copied()
+            })
+            .collect();
+
+        let mut device_bases = DeviceVec::cuda_malloc(shifted.len()).map_err(IcicleError::from_cuda_error)?;
+        device_bases
+            .copy_from_host(HostSlice::from_slice(&shifted))
+            .map_err(IcicleError::from_cuda_error)?;
+        self.device_bases = Some(device_bases);
+        Ok(())
+    }
+
+    /// The precompute factor of the bases loaded on the device, `None` until [load_to_device](Self::load_to_device)
+    /// is called.
+    pub fn precompute_factor(&self) -> Option<usize> {
+        self.device_bases
+            .as_ref()
+            .map(|bases| bases.len() / self.msm_size)
+    }
+
+    /// Writes the tables with a header recording the curve, the window size and the number of bases.
+    pub fn write_to(&self, writer: &mut impl Write) -> IcicleResult<()> {
+        write_header::<C>(writer, BasesKind::FixedBaseTables)?;
+        for value in [self.c, self.bitsize, self.msm_size] {
+            io_error(writer.write_all(&(value as u64).to_le_bytes()))?;
+        }
+        for point in self
+            .tables
+            .iter()
+        {
+            io_error(writer.write_all(&point.to_bytes_le()))?;
+        }
+        io_error(writer.flush())
+    }
+
+    /// Reads tables written by [write_to](Self::write_to), failing when they were built for another curve.
+    pub fn read_from(reader: &mut impl Read) -> IcicleResult<Self> {
+        read_header::<C>(reader, BasesKind::FixedBaseTables)?;
+        let c = u64::from_le_bytes(read_array(reader)?) as usize;
+        let bitsize = u64::from_le_bytes(read_array(reader)?) as usize;
+        let msm_size = u64::from_le_bytes(read_array(reader)?) as usize;
+        if c == 0 || c > MAX_WINDOW_BITS || bitsize == 0 {
+            return Err(IcicleError::invalid_argument("Malformed fixed-base tables header"));
+        }
+
+        let coordinate_len = C::BaseField::zero()
+            .to_bytes_le()
+            .len();
+        let num_points = msm_size
+            .checked_mul(points_per_base(bitsize, c))
+            .ok_or_else(|| IcicleError::invalid_argument("Malformed fixed-base tables header"))?;
+        let mut tables = Vec::new();
+        let mut bytes = vec![0u8; 2 * coordinate_len];
+        for _ in 0..num_points {
+            io_error(reader.read_exact(&mut bytes))?;
+            let (x, y) = bytes.split_at(coordinate_len);
+            tables.push(Affine {
+                x: C::BaseField::from_bytes_le(x),
+                y: C::BaseField::from_bytes_le(y),
+            });
+        }
+        Ok(Self {
+            c,
+            bitsize,
+            msm_size,
+            tables,
+            device_bases: None,
+        })
+    }
+}
+
+pub(super) fn scalar_bitsize<C: Curve>() -> usize
+where
+    C::ScalarField: FieldArithmetic,
+{
+    let modulus = C::ScalarField::modulus_bytes_le();
+    let top = modulus
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .unwrap_or(0);
+    8 * top + (8 - modulus[top].leading_zeros() as usize)
+}
+
+// `k 2^(c j) P` for each window `j` and `k` from 1 to `2^(c-1)`
+fn base_tables<C: Curve>(point: &Affine<C>, bitsize: usize, c: usize) -> Vec<Affine<C>>
+where
+    C::BaseField: FieldArithmetic,
+{
+    let half = 1usize << (c - 1);
+    let mut multiples = Vec::with_capacity(points_per_base(bitsize, c));
+    let mut window_base = point.to_projective();
+    for _ in 0..num_windows(bitsize, c) {
+        let mut multiple = window_base;
+        for _ in 0..half {
+            multiples.push(multiple);
+            multiple = multiple + window_base;
+        }
+        for _ in 0..c {
+            window_base = window_base.double();
+        }
+    }
+    Projective::batch_normalize(&multiples)
+}
+
+// Signed `c` bit digits of the scalar in `(-2^(c-1), 2^(c-1)]`, `None` when it doesn't fit in the windows
+pub(super) fn signed_digits(scalar_bytes: &[u8], c: usize, num_windows: usize) -> Option<Vec<i64>> {
+    let bit = |i: usize| {
+        scalar_bytes
+            .get(i / 8)
+            .map_or(0, |byte| (byte >> (i % 8)) & 1) as i64
+    };
+    let mut digits = Vec::with_capacity(num_windows);
+    let mut carry = 0;
+    for window in 0..num_windows {
+        let mut digit = carry;
+        for i in 0..c {
+            digit += bit(window * c + i) << i;
+        }
+        carry = (digit > 1 << (c - 1)) as i64;
+        digits.push(digit - (carry << c));
+    }
+    let all_bits_read = (num_windows * c..8 * scalar_bytes.len()).all(|i| bit(i) == 0);
+    (carry == 0 && all_bits_read).then_some(digits)
+}
+
+/// Computes MSMs of `scalars` with the fixed bases of `bases` on the device, which the bases must have been loaded
+/// to with [MsmBasesHandle::load_to_device].
+///
+/// `scalars` holds `results.len()` MSMs of `bases.msm_size()` scalars each, of at most `bases.bitsize()` bits: the
+/// bits above are ignored. The window size, `bitsize`, precompute factor and form of the points of `cfg` are fixed by
+/// the bases, its other fields apply as for [msm].
+pub fn fixed_base_msm<C: Curve + MSM<C>>(
+    scalars: &(impl HostOrDeviceSlice<C::ScalarField> + ?Sized),
+    bases: &MsmBasesHandle<C>,
+    cfg: &MSMConfig,
+    results: &mut (impl HostOrDeviceSlice<Projective<C>> + ?Sized),
+) -> IcicleResult<()> {
+    if scalars.len() != bases.msm_size * results.len() {
+        return Err(IcicleError::invalid_argument(
+            "Number of scalars isn't the number of results times the size of the fixed-base MSM",
+        ));
+    }
+    let Some(device_bases) = &bases.device_bases else {
+        return Err(IcicleError::invalid_argument(
+            "Fixed-base bases aren't loaded on the device",
+        ));
+    };
+    let mut local_cfg = cfg.clone();
+    local_cfg.c = bases.c as i32;
+    local_cfg.bitsize = bases.bitsize as i32;
+    local_cfg.precompute_factor = (device_bases.len() / bases.msm_size) as i32;
+    local_cfg.are_points_montgomery_form = false;
+    msm(scalars, &device_bases[..], &local_cfg, results)
+}
+
+/// Computes MSMs of `scalars` with the fixed bases of `bases` on the host, the reference of [fixed_base_msm].
+///
+/// `scalars` holds `results.len()` MSMs of `bases.msm_size()` scalars each, of at most `bases.bitsize()` bits.
+/// `cfg.are_scalars_montgomery_form` tells their form, the other fields of `cfg` are fixed by the tables.
+pub fn fixed_base_msm_host<C: Curve>(
+    scalars: &HostSlice<C::ScalarField>,
+    bases: &MsmBasesHandle<C>,
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if scalars.len() != bases.msm_size * results.len() {
+        return Err(IcicleError::invalid_argument(
+            "Number of scalars isn't the number of results times the size of the fixed-base MSM",
+        ));
+    }
+    let c = bases.c;
+    let windows = num_windows(bases.bitsize, c);
+    let half = 1usize << (c - 1);
+    let per_base = windows * half;
+    let are_scalars_montgomery_form = cfg.are_scalars_montgomery_form;
+
+    let sums: Vec<IcicleResult<Projective<C>>> = scalars
+        .as_slice()
+        .par_chunks(bases.msm_size)
+        .map(|batch| {
+            batch
+                .into_par_iter()
+                .zip(
+                    bases
+                        .tables
+                        .par_chunks(per_base),
+                )
+                .try_fold(Projective::<C>::zero, |acc, (scalar, table)| {
+                    let scalar = if are_scalars_montgomery_form {
+                        scalar.from_montgomery()
+                    } else {
+                        *scalar
+                    };
+                    let digits = signed_digits(&scalar.to_bytes_le(), c, windows)
+                        .ok_or_else(|| IcicleError::invalid_argument("Scalar is wider than the fixed-base tables"))?;
+                    Ok(digits
+                        .iter()
+                        .enumerate()
+                        .fold(acc, |acc, (window, digit)| match digit.unsigned_abs() as usize {
+                            0 => acc,
+                            k if *digit < 0 => acc.add_affine(&-table[window * half + k - 1]),
+                            k => acc.add_affine(&table[window * half + k - 1]),
+                        }))
+                })
+                .try_reduce(Projective::<C>::zero, |a, b| Ok(a + b))
+        })
+        .collect();
+    for (result, sum) in results
+        .as_mut_slice()
+        .iter_mut()
+        .zip(sums)
+    {
+        *result = sum?;
+    }
+    Ok(())
+}
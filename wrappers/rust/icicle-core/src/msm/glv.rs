@@ -0,0 +1,111 @@
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::curve::{Affine, Projective};
+use crate::error::{IcicleError, IcicleResult};
+use crate::glv::{decompose, endomorphism_with, glv_bitsize, GlvCurve};
+use crate::msm::fixed_base::{num_windows, signed_digits};
+use crate::msm::host::{bucket_msm, default_window_bits, normal_points, periodic_points};
+use crate::msm::MSMConfig;
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+/// Host MSM of curves with an efficient endomorphism, which splits every scalar with
+/// [decompose](crate::glv::decompose) and adds `k1 P + k2 φ(P)`, so the bucket method runs over half the windows.
+///
+/// Sizes are the ones of [msm](crate::msm::msm) without precomputation. `cfg.c` is the window size, chosen from the
+/// MSM size when 0, and `cfg.are_scalars_montgomery_form` and `cfg.are_points_montgomery_form` tell the forms of the
+/// inputs. `cfg.bitsize` isn't used, the halves having [glv_bitsize](crate::glv::glv_bitsize) bits whatever the
+/// scalars.
+pub fn glv_msm_host<C: GlvCurve>(
+    scalars: &HostSlice<C::ScalarField>,
+    points: &HostSlice<Affine<C>>,
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if cfg.precompute_factor != 1 {
+        return Err(IcicleError::invalid_argument(
+            "GLV host MSM doesn't take precomputed bases",
+        ));
+    }
+    if scalars.is_empty() || results.is_empty() || scalars.len() % results.len() != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Number of results doesn't divide the number of scalars",
+        ));
+    }
+    if points.is_empty() || scalars.len() % points.len() != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Number of points doesn't divide the number of scalars",
+        ));
+    }
+    let msm_size = scalars.len() / results.len();
+    let c = if cfg.c > 0 {
+        cfg.c as usize
+    } else {
+        default_window_bits(2 * msm_size)
+    };
+    let windows = num_windows(glv_bitsize::<C>(), c);
+
+    let are_scalars_montgomery_form = cfg.are_scalars_montgomery_form;
+    // Digits of `k1` and `k2` of every scalar, with the signs of the halves
+    let digits = scalars
+        .as_slice()
+        .par_iter()
+        .map(|scalar| {
+            let scalar = if are_scalars_montgomery_form {
+                scalar.from_montgomery()
+            } else {
+                *scalar
+            };
+            let (k1, k2, signs) = decompose::<C>(&scalar);
+            let digits = |k: C::ScalarField, negative: bool| {
+                let digits = signed_digits(&k.to_bytes_le(), c, windows)
+                    .ok_or_else(|| IcicleError::invalid_argument("GLV half of the scalar is wider than its windows"))?;
+                Ok(match negative {
+                    true => digits
+                        .into_iter()
+                        .map(|digit| -digit)
+                        .collect(),
+                    false => digits,
+                })
+            };
+            Ok([digits(k1, signs.0)?, digits(k2, signs.1)?])
+        })
+        .collect::<IcicleResult<Vec<[Vec<i64>; 2]>>>()?;
+
+    let points = normal_points(points.as_slice(), cfg.are_points_montgomery_form);
+    let beta = C::BaseField::from_hex(C::BETA);
+    let endo_points: Vec<Affine<C>> = points
+        .iter()
+        .map(|point| endomorphism_with(point, beta))
+        .collect();
+    for (index, (digits, result)) in digits
+        .chunks(msm_size)
+        .zip(
+            results
+                .as_mut_slice()
+                .iter_mut(),
+        )
+        .enumerate()
+    {
+        let split_digits: Vec<Vec<i64>> = digits
+            .iter()
+            .map(|[k1, _]| k1.clone())
+            .chain(
+                digits
+                    .iter()
+                    .map(|[_, k2]| k2.clone()),
+            )
+            .collect();
+        let split_points = [
+            periodic_points(&points, index, msm_size),
+            periodic_points(&endo_points, index, msm_size),
+        ]
+        .concat();
+        *result = bucket_msm(&split_digits, &split_points, c);
+    }
+    Ok(())
+}
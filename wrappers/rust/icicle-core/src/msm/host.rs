@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::{IcicleError, IcicleResult};
+use crate::msm::fixed_base::{num_windows, scalar_bitsize, signed_digits};
+use crate::msm::MSMConfig;
+use crate::traits::{FieldArithmetic, FieldImpl};
+
+// Window size of the bucket method when `MSMConfig::c` is 0, about `ln(size) + 2` like most CPU implementations
+pub(super) fn default_window_bits(size: usize) -> usize {
+    let log2_size = (usize::BITS - size.leading_zeros()) as usize;
+    (log2_size * 69 / 100 + 2).clamp(2, 16)
+}
+
+// Bucket method over signed digits in `(-2^(c-1), 2^(c-1)]`: `digits[i][j]` is the digit of window `j` of the scalar
+// of `points[i]`, every scalar having the same number of windows
+pub(super) fn bucket_msm<C: Curve>(digits: &[Vec<i64>], points: &[Affine<C>], c: usize) -> Projective<C>
+where
+    C::BaseField: FieldArithmetic,
+{
+    let windows = digits
+        .first()
+        .map_or(0, Vec::len);
+    let window_sums: Vec<Projective<C>> = (0..windows)
+        .into_par_iter()
+        .map(|window| {
+            let mut buckets = vec![Projective::<C>::zero(); 1 << (c - 1)];
+            for (scalar_digits, point) in digits
+                .iter()
+                .zip(points)
+            {
+                let digit = scalar_digits[window];
+                if digit != 0 {
+                    let bucket = &mut buckets[digit.unsigned_abs() as usize - 1];
+                    *bucket = if digit < 0 {
+                        bucket.add_affine(&-*point)
+                    } else {
+                        bucket.add_affine(point)
+                    };
+                }
+            }
+            // Sum of `k` times bucket `k`, with running sums from the top bucket
+            let mut running = Projective::<C>::zero();
+            let mut sum = Projective::<C>::zero();
+            for bucket in buckets
+                .into_iter()
+                .rev()
+            {
+                running = running + bucket;
+                sum = sum + running;
+            }
+            sum
+        })
+        .collect();
+    window_sums
+        .into_iter()
+        .rev()
+        .fold(Projective::<C>::zero(), |mut acc, window_sum| {
+            for _ in 0..c {
+                acc = acc.double();
+            }
+            acc + window_sum
+        })
+}
+
+// MSM of `scalars` and `points` on the host, in the forms and with the window size and bitsize of `cfg`
+pub(super) fn host_msm<C: Curve>(
+    scalars: &[C::ScalarField],
+    points: &[Affine<C>],
+    cfg: &MSMConfig,
+) -> IcicleResult<Projective<C>>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    let bitsize = if cfg.bitsize > 0 {
+        cfg.bitsize as usize
+    } else {
+        scalar_bitsize::<C>()
+    };
+    let c = if cfg.c > 0 {
+        cfg.c as usize
+    } else {
+        default_window_bits(scalars.len())
+    };
+    let windows = num_windows(bitsize, c);
+    let are_scalars_montgomery_form = cfg.are_scalars_montgomery_form;
+    let digits = scalars
+        .par_iter()
+        .map(|scalar| {
+            let scalar = if are_scalars_montgomery_form {
+                scalar.from_montgomery()
+            } else {
+                *scalar
+            };
+            signed_digits(&scalar.to_bytes_le(), c, windows)
+                .ok_or_else(|| IcicleError::invalid_argument("Scalar is wider than the MSM bitsize"))
+        })
+        .collect::<IcicleResult<Vec<_>>>()?;
+    let points = normal_points(points, cfg.are_points_montgomery_form);
+    Ok(bucket_msm(&digits, &points, c))
+}
+
+// Points in normal form, converted from Montgomery form when needed
+pub(super) fn normal_points<C: Curve>(points: &[Affine<C>], are_points_montgomery_form: bool) -> Cow<'_, [Affine<C>]>
+where
+    C::BaseField: FieldArithmetic,
+{
+    if !are_points_montgomery_form {
+        return Cow::Borrowed(points);
+    }
+    Cow::Owned(
+        points
+            .iter()
+            .map(|point| Affine {
+                x: point
+                    .x
+                    .from_montgomery(),
+                y: point
+                    .y
+                    .from_montgomery(),
+            })
+            .collect(),
+    )
+}
+
+// Points of the MSM of index `index` of a batch of MSMs of `msm_size` points, re-used periodically like in `msm`
+pub(super) fn periodic_points<C: Curve>(points: &[Affine<C>], index: usize, msm_size: usize) -> Cow<'_, [Affine<C>]> {
+    let start = index * msm_size % points.len();
+    match points.get(start..start + msm_size) {
+        Some(msm_points) => Cow::Borrowed(msm_points),
+        None => Cow::Owned(
+            (start..start + msm_size)
+                .map(|i| points[i % points.len()])
+                .collect(),
+        ),
+    }
+}
@@ -1,8 +1,22 @@
 use crate::curve::{Affine, Curve, Projective};
-use crate::error::IcicleResult;
+use crate::error::{IcicleError, IcicleResult};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::{DeviceContext, DEFAULT_DEVICE_ID};
 use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice};
+use icicle_cuda_runtime::stream::CudaStream;
+
+mod bases_file;
+mod batch;
+mod fixed_base;
+mod glv;
+mod host;
+mod precomputed;
+mod small;
+pub use batch::{msm_batch, msm_batch_host, MsmJob};
+pub use fixed_base::{fixed_base_msm, fixed_base_msm_host, MsmBasesHandle};
+pub use glv::glv_msm_host;
+pub use precomputed::{load_precomputed_points, save_precomputed_points};
+pub use small::{msm_small, msm_small_host, SmallScalar};
 
 #[cfg(feature = "arkworks")]
 #[doc(hidden)]
@@ -12,63 +26,87 @@ pub mod tests;
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct MSMConfig<'a> {
-    /// Details related to the device such as its id and stream.
-    pub ctx: DeviceContext<'a>,
-
+    pub(crate) ctx: DeviceContext<'a>,
     points_size: i32,
+    pub(crate) precompute_factor: i32,
+    pub(crate) c: i32,
+    pub(crate) bitsize: i32,
+    pub(crate) large_bucket_factor: i32,
+    batch_size: i32,
+    are_scalars_on_device: bool,
+    pub(crate) are_scalars_montgomery_form: bool,
+    are_points_on_device: bool,
+    pub(crate) are_points_montgomery_form: bool,
+    are_results_on_device: bool,
+    pub(crate) is_big_triangle: bool,
+    pub(crate) is_async: bool,
+}
+
+impl<'a> Default for MSMConfig<'a> {
+    fn default() -> Self {
+        Self::default_for_device(DEFAULT_DEVICE_ID)
+    }
+}
+
+impl<'a> MSMConfig<'a> {
+    /// Details related to the device such as its id and stream.
+    pub fn ctx(&self) -> &DeviceContext<'a> {
+        &self.ctx
+    }
 
     /// The number of extra points to pre-compute for each point. See the `precompute_bases` function, `precompute_factor` passed
     /// there needs to be equal to the one used here. Larger values decrease the number of computations
     /// to make, on-line memory footprint, but increase the static memory footprint. Default value: 1 (i.e. don't pre-compute).
-    pub precompute_factor: i32,
+    pub fn precompute_factor(&self) -> i32 {
+        self.precompute_factor
+    }
 
     /// `c` value, or "window bitsize" which is the main parameter of the "bucket method"
     /// that we use to solve the MSM problem. As a rule of thumb, larger value means more on-line memory
     /// footprint but also more parallelism and less computational complexity (up to a certain point).
     /// Currently pre-computation is independent of `c`, however in the future value of `c` here and the one passed into the
-    /// `precompute_bases` function will need to be identical. Default value: 0 (the optimal value of `c` is chosen automatically).
-    pub c: i32,
+    /// `precompute_bases` function will need to be identical. Bases saved with [save_precomputed_points] record `c`
+    /// and can only be loaded with the same value. Default value: 0 (the optimal value of `c` is chosen automatically).
+    pub fn c(&self) -> i32 {
+        self.c
+    }
 
     /// Number of bits of the largest scalar. Typically equals the bitsize of scalar field, but if a different
     /// (better) upper bound is known, it should be reflected in this variable. Default value: 0 (set to the bitsize of scalar field).
-    pub bitsize: i32,
+    pub fn bitsize(&self) -> i32 {
+        self.bitsize
+    }
 
     /// Variable that controls how sensitive the algorithm is to the buckets that occur very frequently.
     /// Useful for efficient treatment of non-uniform distributions of scalars and "top windows" with few bits.
     /// Can be set to 0 to disable separate treatment of large buckets altogether. Default value: 10.
-    pub large_bucket_factor: i32,
-
-    batch_size: i32,
-
-    are_scalars_on_device: bool,
+    pub fn large_bucket_factor(&self) -> i32 {
+        self.large_bucket_factor
+    }
 
     /// True if scalars are in Montgomery form and false otherwise. Default value: true.
-    pub are_scalars_montgomery_form: bool,
-
-    are_points_on_device: bool,
+    pub fn are_scalars_montgomery_form(&self) -> bool {
+        self.are_scalars_montgomery_form
+    }
 
     /// True if coordinates of points are in Montgomery form and false otherwise. Default value: true.
-    pub are_points_montgomery_form: bool,
-
-    are_results_on_device: bool,
+    pub fn are_points_montgomery_form(&self) -> bool {
+        self.are_points_montgomery_form
+    }
 
     /// Whether to do "bucket accumulation" serially. Decreases computational complexity, but also greatly
     /// decreases parallelism, so only suitable for large batches of MSMs. Default value: false.
-    pub is_big_triangle: bool,
+    pub fn is_big_triangle(&self) -> bool {
+        self.is_big_triangle
+    }
 
     /// Whether to run the MSM asynchronously. If set to `true`, the MSM function will be non-blocking
     /// and you'd need to synchronize it explicitly by running `cudaStreamSynchronize` or `cudaDeviceSynchronize`.
     /// If set to `false`, the MSM function will block the current CPU thread.
-    pub is_async: bool,
-}
-
-impl<'a> Default for MSMConfig<'a> {
-    fn default() -> Self {
-        Self::default_for_device(DEFAULT_DEVICE_ID)
+    pub fn is_async(&self) -> bool {
+        self.is_async
     }
-}
 
-impl<'a> MSMConfig<'a> {
     pub fn default_for_device(device_id: usize) -> Self {
         Self {
             ctx: DeviceContext::default_for_device(device_id),
@@ -87,6 +125,131 @@ impl<'a> MSMConfig<'a> {
             is_async: false,
         }
     }
+
+    /// Starts an [MSMConfigBuilder] from the default config of the default device.
+    pub fn builder() -> MSMConfigBuilder<'a> {
+        Self::builder_for_device(DEFAULT_DEVICE_ID)
+    }
+
+    /// Starts an [MSMConfigBuilder] from the default config of device `device_id`.
+    pub fn builder_for_device(device_id: usize) -> MSMConfigBuilder<'a> {
+        MSMConfigBuilder {
+            cfg: Self::default_for_device(device_id),
+        }
+    }
+
+    /// Starts an [MSMConfigBuilder] from this config, to change some of its parameters.
+    pub fn into_builder(self) -> MSMConfigBuilder<'a> {
+        MSMConfigBuilder { cfg: self }
+    }
+}
+
+/// Builder of [MSMConfig] which checks the parameters on [build](MSMConfigBuilder::build) instead of leaving
+/// invalid combinations to the device code, for instance
+/// `MSMConfig::builder().c(16).bitsize(253).big_triangle(true).build()?`.
+///
+/// Parameters that aren't set keep the values of [MSMConfig::default_for_device].
+#[derive(Debug, Clone)]
+pub struct MSMConfigBuilder<'a> {
+    cfg: MSMConfig<'a>,
+}
+
+impl<'a> MSMConfigBuilder<'a> {
+    /// Device context to run the MSM in, see [MSMConfig::ctx].
+    pub fn ctx(mut self, ctx: DeviceContext<'a>) -> Self {
+        self.cfg
+            .ctx = ctx;
+        self
+    }
+
+    /// Stream of the device context to run the MSM on.
+    pub fn stream(mut self, stream: &'a CudaStream) -> Self {
+        self.cfg
+            .ctx
+            .stream = stream;
+        self
+    }
+
+    /// See [MSMConfig::precompute_factor], must be at least 1.
+    pub fn precompute_factor(mut self, precompute_factor: i32) -> Self {
+        self.cfg
+            .precompute_factor = precompute_factor;
+        self
+    }
+
+    /// See [MSMConfig::c], must be between 0 (chosen automatically) and 31 and at most `bitsize`.
+    pub fn c(mut self, c: i32) -> Self {
+        self.cfg
+            .c = c;
+        self
+    }
+
+    /// See [MSMConfig::bitsize], 0 meaning the bitsize of the scalar field.
+    pub fn bitsize(mut self, bitsize: i32) -> Self {
+        self.cfg
+            .bitsize = bitsize;
+        self
+    }
+
+    /// See [MSMConfig::large_bucket_factor], can't be negative.
+    pub fn large_bucket_factor(mut self, large_bucket_factor: i32) -> Self {
+        self.cfg
+            .large_bucket_factor = large_bucket_factor;
+        self
+    }
+
+    /// See [MSMConfig::are_scalars_montgomery_form].
+    pub fn scalars_montgomery_form(mut self, are_scalars_montgomery_form: bool) -> Self {
+        self.cfg
+            .are_scalars_montgomery_form = are_scalars_montgomery_form;
+        self
+    }
+
+    /// See [MSMConfig::are_points_montgomery_form].
+    pub fn points_montgomery_form(mut self, are_points_montgomery_form: bool) -> Self {
+        self.cfg
+            .are_points_montgomery_form = are_points_montgomery_form;
+        self
+    }
+
+    /// See [MSMConfig::is_big_triangle].
+    pub fn big_triangle(mut self, is_big_triangle: bool) -> Self {
+        self.cfg
+            .is_big_triangle = is_big_triangle;
+        self
+    }
+
+    /// See [MSMConfig::is_async].
+    pub fn is_async(mut self, is_async: bool) -> Self {
+        self.cfg
+            .is_async = is_async;
+        self
+    }
+
+    /// Returns the config, or an `InvalidArgument` error naming the first parameter out of its range.
+    pub fn build(self) -> IcicleResult<MSMConfig<'a>> {
+        let cfg = self.cfg;
+        if cfg.precompute_factor < 1 {
+            return Err(IcicleError::invalid_argument(
+                "MSM precompute_factor must be at least 1",
+            ));
+        }
+        if cfg.bitsize < 0 {
+            return Err(IcicleError::invalid_argument("MSM bitsize can't be negative"));
+        }
+        if !(0..=31).contains(&cfg.c) {
+            return Err(IcicleError::invalid_argument("MSM c must be between 0 and 31"));
+        }
+        if cfg.bitsize != 0 && cfg.c > cfg.bitsize {
+            return Err(IcicleError::invalid_argument("MSM c can't be larger than bitsize"));
+        }
+        if cfg.large_bucket_factor < 0 {
+            return Err(IcicleError::invalid_argument(
+                "MSM large_bucket_factor can't be negative",
+            ));
+        }
+        Ok(cfg)
+    }
 }
 
 #[doc(hidden)]
@@ -242,6 +405,7 @@ pub fn precompute_bases<C: Curve + MSM<C>>(
 /// and potentially the c value inside this config. This config should be the same config used in msm.
 ///
 /// * `output_bases` - Device-allocated buffer of size `bases_size` * `precompute_factor` for the extended bases.
+/// They can be kept across runs with [save_precomputed_points] and [load_precomputed_points].
 ///
 /// Returns `Ok(())` if no errors occurred or a `CudaError` otherwise.
 pub fn precompute_points<C: Curve + MSM<C>>(
@@ -393,10 +557,57 @@ macro_rules! impl_msm_tests {
             check_msm_batch::<$curve>()
         }
 
+        #[test]
+        fn test_msm_batch_jobs() {
+            check_msm_batch_jobs::<$curve>()
+        }
+
+        #[test]
+        fn test_msm_small() {
+            check_msm_small::<$curve>()
+        }
+
         #[test]
         fn test_msm_skewed_distributions() {
             check_msm_skewed_distributions::<$curve>()
         }
+
+        #[test]
+        fn test_fixed_base_msm() {
+            check_fixed_base_msm::<$curve>()
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_msm_host_tests {
+    (
+      $curve:ident
+    ) => {
+        #[test]
+        fn test_fixed_base_msm_host() {
+            check_fixed_base_msm_host::<$curve>()
+        }
+
+        #[test]
+        fn test_msm_batch_host() {
+            check_msm_batch_host::<$curve>()
+        }
+
+        #[test]
+        fn test_msm_small_host() {
+            check_msm_small_host::<$curve>()
+        }
+
+        #[test]
+        fn test_precomputed_points_file() {
+            check_precomputed_points_file::<$curve>()
+        }
+
+        #[test]
+        fn test_msm_config_builder() {
+            check_msm_config_builder()
+        }
     };
 }
 
@@ -463,13 +674,6 @@ macro_rules! impl_msm_bench {
                 .unwrap_or(MAX_LOG2);
 
             let stream = CudaStream::create().unwrap();
-            let mut cfg = MSMConfig::default();
-            cfg.ctx
-                .stream = &stream;
-            cfg.is_async = true;
-            cfg.large_bucket_factor = 5;
-            cfg.c = 4;
-
             warmup(&stream).unwrap();
 
             for test_size_log2 in (min_log2..=max_log2) {
@@ -478,7 +682,14 @@ macro_rules! impl_msm_bench {
                 let points = generate_random_affine_points_with_zeroes(test_size, 10);
                 for precompute_factor in [1, 4, 8] {
                     let mut precomputed_points_d = DeviceVec::cuda_malloc(precompute_factor * test_size).unwrap();
-                    cfg.precompute_factor = precompute_factor as i32;
+                    let cfg = MSMConfig::builder()
+                        .stream(&stream)
+                        .is_async(true)
+                        .large_bucket_factor(5)
+                        .c(4)
+                        .precompute_factor(precompute_factor as i32)
+                        .build()
+                        .unwrap();
                     precompute_points(
                         HostSlice::from_slice(&points),
                         test_size as i32,
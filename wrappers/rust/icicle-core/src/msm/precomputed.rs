@@ -0,0 +1,181 @@
+use std::io::{Read, Write};
+
+use super::bases_file::{io_error, read_array, read_header, write_header, BasesKind};
+use crate::curve::{Affine, Curve};
+use crate::error::{IcicleError, IcicleResult};
+use crate::msm::MSMConfig;
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice, HostSlice};
+
+// Everything the layout of extended bases depends on, besides the curve recorded by the common header
+#[derive(Debug, PartialEq, Eq)]
+struct Header {
+    precompute_factor: i32,
+    c: i32,
+    msm_size: i32,
+    points_size: u64,
+    are_points_montgomery_form: bool,
+}
+
+impl Header {
+    fn new(points_size: usize, msm_size: i32, cfg: &MSMConfig) -> Self {
+        Self {
+            precompute_factor: cfg.precompute_factor,
+            c: cfg.c,
+            msm_size,
+            points_size: points_size as u64,
+            are_points_montgomery_form: cfg.are_points_montgomery_form,
+        }
+    }
+
+    fn write_to(&self, writer: &mut impl Write) -> IcicleResult<()> {
+        let mut bytes = Vec::new();
+        for value in [self.precompute_factor, self.c, self.msm_size] {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes.extend(
+            self.points_size
+                .to_le_bytes(),
+        );
+        bytes.push(self.are_points_montgomery_form as u8);
+        io_error(writer.write_all(&bytes))
+    }
+
+    fn read_from(reader: &mut impl Read) -> IcicleResult<Self> {
+        Ok(Self {
+            precompute_factor: i32::from_le_bytes(read_array(reader)?),
+            c: i32::from_le_bytes(read_array(reader)?),
+            msm_size: i32::from_le_bytes(read_array(reader)?),
+            points_size: u64::from_le_bytes(read_array(reader)?),
+            are_points_montgomery_form: read_array::<1>(reader)?[0] != 0,
+        })
+    }
+
+    // Fails with the first field the bases were precomputed with which differs from the expected ones
+    fn check(&self, expected: &Self) -> IcicleResult<()> {
+        if self.precompute_factor != expected.precompute_factor {
+            return Err(IcicleError::invalid_argument(
+                "Precomputed bases have another precompute_factor than the MSM config",
+            ));
+        }
+        if self.c != expected.c {
+            return Err(IcicleError::invalid_argument(
+                "Precomputed bases have another c than the MSM config",
+            ));
+        }
+        if self.msm_size != expected.msm_size {
+            return Err(IcicleError::invalid_argument(
+                "Precomputed bases are for another MSM size",
+            ));
+        }
+        if self.are_points_montgomery_form != expected.are_points_montgomery_form {
+            return Err(IcicleError::invalid_argument(
+                "Precomputed bases are in another form than are_points_montgomery_form of the MSM config",
+            ));
+        }
+        if self.points_size != expected.points_size {
+            return Err(IcicleError::invalid_argument(
+                "Precomputed bases don't fit the output buffer",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Writes bases extended by [precompute_points](crate::msm::precompute_points) with the parameters they depend on:
+/// the curve, `precompute_factor`, `c`, `msm_size` and whether the points are in Montgomery form.
+///
+/// * `bases` - The extended bases, on the host or on the device, `precompute_factor` times the original bases.
+///
+/// * `msm_size` and `cfg` - The arguments `bases` were precomputed with.
+pub fn save_precomputed_points<C: Curve>(
+    writer: &mut impl Write,
+    bases: &(impl HostOrDeviceSlice<Affine<C>> + ?Sized),
+    msm_size: i32,
+    cfg: &MSMConfig,
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if cfg.precompute_factor < 1 || bases.len() % cfg.precompute_factor as usize != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Precompute factor doesn't divide the number of extended bases",
+        ));
+    }
+    let mut host_bases = vec![Affine::<C>::zero(); bases.len()];
+    if bases.is_on_device() {
+        let device_bases = unsafe { DeviceSlice::from_slice(std::slice::from_raw_parts(bases.as_ptr(), bases.len())) };
+        device_bases
+            .copy_to_host(HostSlice::from_mut_slice(&mut host_bases))
+            .map_err(IcicleError::from_cuda_error)?;
+    } else {
+        host_bases.copy_from_slice(unsafe { std::slice::from_raw_parts(bases.as_ptr(), bases.len()) });
+    }
+
+    write_header::<C>(writer, BasesKind::Precomputed)?;
+    Header::new(bases.len() / cfg.precompute_factor as usize, msm_size, cfg).write_to(writer)?;
+    for point in host_bases.iter() {
+        io_error(writer.write_all(&point.to_bytes_le()))?;
+    }
+    io_error(writer.flush())
+}
+
+/// Reads bases written by [save_precomputed_points] into `output_bases`, to be passed to [msm](crate::msm::msm)
+/// with `cfg`.
+///
+/// Fails without touching `output_bases` when the file was written for another curve, another `precompute_factor`,
+/// `c`, `msm_size` or form of the points than the ones of `cfg`, or for another number of bases than
+/// `output_bases` holds.
+pub fn load_precomputed_points<C: Curve>(
+    reader: &mut impl Read,
+    msm_size: i32,
+    cfg: &MSMConfig,
+    output_bases: &mut (impl HostOrDeviceSlice<Affine<C>> + ?Sized),
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+    C::ScalarField: FieldArithmetic,
+{
+    if cfg.precompute_factor < 1 || output_bases.len() % cfg.precompute_factor as usize != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Precompute factor doesn't divide the number of extended bases",
+        ));
+    }
+    read_header::<C>(reader, BasesKind::Precomputed)?;
+    Header::read_from(reader)?.check(&Header::new(
+        output_bases.len() / cfg.precompute_factor as usize,
+        msm_size,
+        cfg,
+    ))?;
+
+    let coordinate_len = C::BaseField::zero()
+        .to_bytes_le()
+        .len();
+    let mut bytes = vec![0u8; 2 * coordinate_len];
+    let mut host_bases = Vec::with_capacity(output_bases.len());
+    for _ in 0..output_bases.len() {
+        io_error(reader.read_exact(&mut bytes))?;
+        let (x, y) = bytes.split_at(coordinate_len);
+        host_bases.push(Affine {
+            x: C::BaseField::from_bytes_le(x),
+            y: C::BaseField::from_bytes_le(y),
+        });
+    }
+
+    if output_bases.is_on_device() {
+        let device_bases = unsafe {
+            DeviceSlice::from_mut_slice(std::slice::from_raw_parts_mut(
+                output_bases.as_mut_ptr(),
+                output_bases.len(),
+            ))
+        };
+        device_bases
+            .copy_from_host(HostSlice::from_slice(&host_bases))
+            .map_err(IcicleError::from_cuda_error)
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(output_bases.as_mut_ptr(), output_bases.len()) }
+            .copy_from_slice(&host_bases);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::curve::{Affine, Curve, Projective};
+use crate::error::{IcicleError, IcicleResult};
+use crate::msm::fixed_base::{num_windows, signed_digits};
+use crate::msm::host::{bucket_msm, default_window_bits, normal_points, periodic_points};
+use crate::msm::{msm, MSMConfig, MSM};
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+/// Machine integers used as MSM scalars by [msm_small] and [msm_small_host], instead of full field elements.
+pub trait SmallScalar: Copy + Send + Sync {
+    /// Number of bits of the largest absolute value, which bounds the number of windows of the MSM.
+    const BITS: usize;
+
+    /// Whether the integer is negative, and its absolute value.
+    fn sign_and_magnitude(self) -> (bool, u64);
+}
+
+macro_rules! impl_small_scalar {
+    (unsigned $($int:ty),*) => {
+        $(
+            impl SmallScalar for $int {
+                const BITS: usize = <$int>::BITS as usize;
+
+                fn sign_and_magnitude(self) -> (bool, u64) {
+                    (false, self as u64)
+                }
+            }
+        )*
+    };
+    (signed $($int:ty),*) => {
+        $(
+            impl SmallScalar for $int {
+                const BITS: usize = <$int>::BITS as usize;
+
+                fn sign_and_magnitude(self) -> (bool, u64) {
+                    (self < 0, self.unsigned_abs() as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_small_scalar!(unsigned u8, u16, u32, u64);
+impl_small_scalar!(signed i8, i16, i32, i64);
+
+// Same sizes as `msm` accepts, but as errors: `results.len()` MSMs whose points are re-used periodically
+fn check_sizes(scalars_len: usize, points_len: usize, results_len: usize) -> IcicleResult<usize> {
+    if scalars_len == 0 {
+        return Err(IcicleError::invalid_argument("MSM without scalars"));
+    }
+    if results_len == 0 || scalars_len % results_len != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Number of results doesn't divide the number of scalars",
+        ));
+    }
+    if points_len == 0 || scalars_len % points_len != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Number of points doesn't divide the number of scalars",
+        ));
+    }
+    Ok(scalars_len / results_len)
+}
+
+/// Computes MSMs of small or signed integer scalars on the device, equal to [msm] of the scalars widened to
+/// `C::ScalarField`, negative integers standing for their opposites in the field.
+///
+/// Sizes and `cfg` are the ones of [msm]. The library has no kernel reading integers, so they are widened on the host
+/// before the transfer. Non-negative integers and the magnitudes of negative ones go through separate MSMs whose
+/// `cfg.bitsize` is the width `T::BITS` of the integers and whose `cfg.c` is at most `T::BITS`, so the device only
+/// goes through the windows of the integer width. The MSM of the magnitudes is negated, which makes it the MSM over
+/// the negated points, and is skipped when no integer is negative. Bases precomputed with a `cfg.precompute_factor`
+/// above 1 are spaced for the full scalar field, so they keep `cfg.bitsize`. The call is blocking whatever
+/// `cfg.is_async`.
+pub fn msm_small<C: Curve + MSM<C>, T: SmallScalar>(
+    scalars: &HostSlice<T>,
+    points: &(impl HostOrDeviceSlice<Affine<C>> + ?Sized),
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()>
+where
+    C::ScalarField: FieldArithmetic,
+    C::BaseField: FieldArithmetic,
+{
+    let precompute_factor = cfg
+        .precompute_factor
+        .max(1) as usize;
+    check_sizes(scalars.len(), points.len() / precompute_factor, results.len())?;
+    let (non_negative, negative): (Vec<C::ScalarField>, Vec<C::ScalarField>) = scalars
+        .as_slice()
+        .par_iter()
+        .map(|scalar| {
+            let (negative, magnitude) = scalar.sign_and_magnitude();
+            let wide = C::ScalarField::from_bytes_le(&magnitude.to_le_bytes());
+            match negative {
+                true => (C::ScalarField::zero(), wide),
+                false => (wide, C::ScalarField::zero()),
+            }
+        })
+        .unzip();
+    let mut local_cfg = cfg.clone();
+    if precompute_factor == 1 {
+        local_cfg.bitsize = T::BITS as i32;
+        local_cfg.c = cfg
+            .c
+            .min(T::BITS as i32);
+    }
+    local_cfg.are_scalars_montgomery_form = false;
+    local_cfg.is_async = false;
+    msm(HostSlice::from_slice(&non_negative), points, &local_cfg, results)?;
+
+    let zero = C::ScalarField::zero();
+    if negative
+        .iter()
+        .all(|scalar| *scalar == zero)
+    {
+        return Ok(());
+    }
+    let mut negative_results = vec![Projective::<C>::zero(); results.len()];
+    msm(
+        HostSlice::from_slice(&negative),
+        points,
+        &local_cfg,
+        HostSlice::from_mut_slice(&mut negative_results),
+    )?;
+    for (result, negative_result) in results
+        .as_mut_slice()
+        .iter_mut()
+        .zip(negative_results)
+    {
+        *result = *result - negative_result;
+    }
+    Ok(())
+}
+
+/// Host version of [msm_small], which reads the integers directly.
+///
+/// The bucket method runs over `⌈(T::BITS + 1) / c⌉` windows of signed digits, `c` being `cfg.c` when it's set and
+/// chosen from the MSM size otherwise, and at most `T::BITS`. `cfg.are_points_montgomery_form` tells the form of
+/// `points`, `cfg.precompute_factor` must be 1.
+pub fn msm_small_host<C: Curve, T: SmallScalar>(
+    scalars: &HostSlice<T>,
+    points: &HostSlice<Affine<C>>,
+    cfg: &MSMConfig,
+    results: &mut HostSlice<Projective<C>>,
+) -> IcicleResult<()>
+where
+    C::BaseField: FieldArithmetic,
+{
+    if cfg.precompute_factor != 1 {
+        return Err(IcicleError::invalid_argument(
+            "Host MSM of small scalars doesn't take precomputed bases",
+        ));
+    }
+    let msm_size = check_sizes(scalars.len(), points.len(), results.len())?;
+    let c = if cfg.c > 0 {
+        cfg.c as usize
+    } else {
+        default_window_bits(msm_size)
+    }
+    .min(T::BITS);
+    let windows = num_windows(T::BITS, c);
+    let digits = scalars
+        .as_slice()
+        .par_iter()
+        .map(|scalar| {
+            let (negative, magnitude) = scalar.sign_and_magnitude();
+            let digits = signed_digits(&magnitude.to_le_bytes(), c, windows)
+                .ok_or_else(|| IcicleError::invalid_argument("Small scalar is wider than its windows"))?;
+            Ok(match negative {
+                true => digits
+                    .into_iter()
+                    .map(|digit| -digit)
+                    .collect(),
+                false => digits,
+            })
+        })
+        .collect::<IcicleResult<Vec<Vec<i64>>>>()?;
+
+    let points = normal_points(points.as_slice(), cfg.are_points_montgomery_form);
+    for (index, (digits, result)) in digits
+        .chunks(msm_size)
+        .zip(
+            results
+                .as_mut_slice()
+                .iter_mut(),
+        )
+        .enumerate()
+    {
+        *result = bucket_msm(digits, &periodic_points(&points, index, msm_size), c);
+    }
+    Ok(())
+}
@@ -1,8 +1,12 @@
 use crate::curve::{Affine, Curve, Projective};
-use crate::msm::{msm, precompute_points, MSMConfig, MSM};
-use crate::traits::{FieldImpl, GenerateRandom};
+use crate::error::IcicleErrorCode;
+use crate::msm::{
+    fixed_base_msm, fixed_base_msm_host, load_precomputed_points, msm, msm_batch, msm_batch_host, msm_small,
+    msm_small_host, precompute_points, save_precomputed_points, MSMConfig, MsmBasesHandle, MsmJob, SmallScalar, MSM,
+};
+use crate::traits::{FieldArithmetic, FieldImpl, GenerateRandom};
 use icicle_cuda_runtime::device::{get_device_count, set_device, warmup};
-use icicle_cuda_runtime::memory::{DeviceVec, HostSlice};
+use icicle_cuda_runtime::memory::{DeviceVec, HostOrDeviceSlice, HostSlice};
 use icicle_cuda_runtime::stream::CudaStream;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -11,7 +15,7 @@ use crate::traits::ArkConvertible;
 #[cfg(feature = "arkworks")]
 use ark_ec::models::CurveConfig as ArkCurveConfig;
 #[cfg(feature = "arkworks")]
-use ark_ec::VariableBaseMSM;
+use ark_ec::{short_weierstrass::Affine as ArkAffine, AffineRepr, CurveGroup, VariableBaseMSM};
 #[cfg(feature = "arkworks")]
 use ark_std::{rand::Rng, test_rng, UniformRand};
 
@@ -190,6 +194,116 @@ where
         .unwrap();
 }
 
+pub fn check_msm_batch_jobs<C: Curve + MSM<C>>()
+where
+    <C::ScalarField as FieldImpl>::Config: GenerateRandom<C::ScalarField>,
+{
+    // Sizes sharing a power of two class, an empty job and a class of its own
+    let sizes = [100, 0, 128, 1000, 1, 97, 129, 300];
+    let points = generate_random_affine_points_with_zeroes(
+        sizes
+            .iter()
+            .sum(),
+        10,
+    );
+    let scalars = <C::ScalarField as FieldImpl>::Config::generate_random(
+        sizes
+            .iter()
+            .sum(),
+    );
+    let mut jobs = Vec::new();
+    let mut offset = 0;
+    for size in sizes {
+        jobs.push(MsmJob {
+            scalars: HostSlice::from_slice(&scalars[offset..offset + size]),
+            points: HostSlice::from_slice(&points[offset..offset + size]),
+        });
+        offset += size;
+    }
+
+    let cfg = MSMConfig::default();
+    let mut results = vec![Projective::<C>::zero(); jobs.len()];
+    msm_batch(&jobs, &cfg, HostSlice::from_mut_slice(&mut results)).unwrap();
+    for (job, result) in jobs
+        .iter()
+        .zip(&results)
+    {
+        let mut expected = vec![Projective::<C>::zero()];
+        if !job
+            .scalars
+            .is_empty()
+        {
+            msm(job.scalars, job.points, &cfg, HostSlice::from_mut_slice(&mut expected)).unwrap();
+        }
+        assert_eq!(*result, expected[0]);
+    }
+}
+
+// Widens integers to field elements without going through their bytes, for comparisons with `msm_small`
+fn widen_small<F: FieldArithmetic, T: SmallScalar>(scalar: T) -> F {
+    let (negative, magnitude) = scalar.sign_and_magnitude();
+    let wide = F::from_u32((magnitude >> 32) as u32) * F::from_u32(1 << 16).sqr() + F::from_u32(magnitude as u32);
+    if negative {
+        -wide
+    } else {
+        wide
+    }
+}
+
+fn check_msm_small_scalars<C: Curve + MSM<C>, T: SmallScalar>(scalars: &[T], points: &[Affine<C>], batch_size: usize)
+where
+    C::ScalarField: FieldArithmetic,
+    C::BaseField: FieldArithmetic,
+{
+    let widened: Vec<C::ScalarField> = scalars
+        .iter()
+        .map(|scalar| widen_small(*scalar))
+        .collect();
+    let cfg = MSMConfig::default();
+    let mut expected = vec![Projective::<C>::zero(); batch_size];
+    msm(
+        HostSlice::from_slice(&widened),
+        HostSlice::from_slice(points),
+        &cfg,
+        HostSlice::from_mut_slice(&mut expected),
+    )
+    .unwrap();
+    let mut results = vec![Projective::<C>::zero(); batch_size];
+    msm_small(
+        HostSlice::from_slice(scalars),
+        HostSlice::from_slice(points),
+        &cfg,
+        HostSlice::from_mut_slice(&mut results),
+    )
+    .unwrap();
+    assert_eq!(results, expected);
+}
+
+pub fn check_msm_small<C: Curve + MSM<C>>()
+where
+    C::ScalarField: FieldArithmetic,
+    C::BaseField: FieldArithmetic,
+{
+    let rng = &mut test_rng();
+    let (msm_size, batch_size) = (1000, 3);
+    let points = generate_random_affine_points_with_zeroes(msm_size, 10);
+    let len = msm_size * batch_size;
+    let bytes: Vec<u8> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    check_msm_small_scalars::<C, _>(&bytes, &points, batch_size);
+    let unsigned: Vec<u64> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    check_msm_small_scalars::<C, _>(&unsigned, &points, batch_size);
+    let mut signed: Vec<i64> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    signed[0] = i64::MIN;
+    signed[1] = i64::MAX;
+    check_msm_small_scalars::<C, _>(&signed, &points, batch_size);
+}
+
 pub fn check_msm_skewed_distributions<C: Curve + MSM<C>>()
 where
     <C::ScalarField as FieldImpl>::Config: GenerateRandom<C::ScalarField>,
@@ -247,3 +361,582 @@ where
         }
     }
 }
+
+pub(crate) type ArkScalar<C> = <<C as Curve>::ArkSWConfig as ArkCurveConfig>::ScalarField;
+
+// Random points and scalars from arkworks, for the host implementations which don't need a device
+pub(crate) fn ark_points_and_scalars<C: Curve>(size: usize) -> (Vec<ArkAffine<C::ArkSWConfig>>, Vec<ArkScalar<C>>) {
+    let rng = &mut test_rng();
+    let mut points: Vec<_> = (0..size)
+        .map(|_| (ArkAffine::<C::ArkSWConfig>::generator() * ArkScalar::<C>::rand(rng)).into_affine())
+        .collect();
+    points[size / 2] = ArkAffine::<C::ArkSWConfig>::zero();
+    let scalars = (0..size)
+        .map(|_| ArkScalar::<C>::rand(rng))
+        .collect();
+    (points, scalars)
+}
+
+pub fn check_fixed_base_msm<C: Curve + MSM<C>>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField> + FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    let (msm_size, batch_size) = (1 << 10, 3);
+    let (points_ark, scalars_ark) = ark_points_and_scalars::<C>(msm_size * batch_size);
+    let points: Vec<Affine<C>> = points_ark[..msm_size]
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let scalars: Vec<C::ScalarField> = scalars_ark
+        .iter()
+        .map(|scalar| C::ScalarField::from_ark(*scalar))
+        .collect();
+
+    let mut cfg = MSMConfig::default();
+    let mut results = vec![Projective::<C>::zero(); batch_size];
+    let mut host_results = vec![Projective::<C>::zero(); batch_size];
+    for c in [4, 0] {
+        cfg.c = c;
+        let mut bases = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, 1 << 30).unwrap();
+        fixed_base_msm_host(
+            HostSlice::from_slice(&scalars),
+            &bases,
+            &cfg,
+            HostSlice::from_mut_slice(&mut host_results),
+        )
+        .unwrap();
+        // From no precomputation to one copy of the bases per window
+        let windows = bases
+            .bitsize()
+            .div_ceil(bases.window_bits());
+        for precompute_factor in [1, 3, windows] {
+            bases
+                .load_to_device(precompute_factor)
+                .unwrap();
+            assert_eq!(bases.precompute_factor(), Some(precompute_factor));
+            fixed_base_msm(
+                HostSlice::from_slice(&scalars),
+                &bases,
+                &cfg,
+                HostSlice::from_mut_slice(&mut results),
+            )
+            .unwrap();
+            assert_eq!(results, host_results);
+        }
+    }
+
+    // Scalars in Montgomery form, on the device
+    cfg.are_scalars_montgomery_form = true;
+    let scalars_mont: Vec<C::ScalarField> = scalars
+        .iter()
+        .map(|scalar| scalar.to_montgomery())
+        .collect();
+    let mut scalars_d = DeviceVec::cuda_malloc(scalars.len()).unwrap();
+    scalars_d
+        .copy_from_host(HostSlice::from_slice(&scalars_mont))
+        .unwrap();
+    let mut bases = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, 1 << 30).unwrap();
+    bases
+        .load_to_device(2)
+        .unwrap();
+    fixed_base_msm(&scalars_d[..], &bases, &cfg, HostSlice::from_mut_slice(&mut results)).unwrap();
+    assert_eq!(
+        results[1].to_ark(),
+        ark_ec::models::short_weierstrass::Projective::<C::ArkSWConfig>::msm(
+            &points_ark[..msm_size],
+            &scalars_ark[msm_size..2 * msm_size]
+        )
+        .unwrap()
+    );
+}
+
+pub fn check_fixed_base_msm_host<C: Curve + MSM<C>>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField> + FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    let (msm_size, batch_size) = (32, 3);
+    let (points_ark, scalars_ark) = ark_points_and_scalars::<C>(msm_size * batch_size);
+    let points_ark = &points_ark[..msm_size];
+    let points: Vec<Affine<C>> = points_ark
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let scalars: Vec<C::ScalarField> = scalars_ark
+        .iter()
+        .map(|scalar| C::ScalarField::from_ark(*scalar))
+        .collect();
+    let expected: Vec<_> = scalars_ark
+        .chunks(msm_size)
+        .map(|chunk| ark_ec::models::short_weierstrass::Projective::<C::ArkSWConfig>::msm(points_ark, chunk).unwrap())
+        .collect();
+
+    let mut cfg = MSMConfig::default();
+    let mut results = vec![Projective::<C>::zero(); batch_size];
+    let point_size = std::mem::size_of::<Affine<C>>();
+    // A thousand points per base fit windows of 4 or 5 bits, a few hundred only fit the smallest windows
+    for (c, budget) in [
+        (0, 1000 * msm_size * point_size),
+        (3, 1 << 30),
+        (0, 400 * msm_size * point_size),
+    ] {
+        cfg.c = c;
+        let bases = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, budget).unwrap();
+        assert!(bases.memory_size() <= budget);
+        assert_eq!(bases.msm_size(), msm_size);
+        if c != 0 {
+            assert_eq!(bases.window_bits(), c as usize);
+        }
+        fixed_base_msm_host(
+            HostSlice::from_slice(&scalars),
+            &bases,
+            &cfg,
+            HostSlice::from_mut_slice(&mut results),
+        )
+        .unwrap();
+        for (result, expected) in results
+            .iter()
+            .zip(&expected)
+        {
+            assert_eq!(result.to_ark(), *expected);
+        }
+    }
+
+    // Points and scalars in Montgomery form
+    cfg.c = 4;
+    cfg.are_points_montgomery_form = true;
+    cfg.are_scalars_montgomery_form = true;
+    let points_mont: Vec<Affine<C>> = points
+        .iter()
+        .map(|point| Affine {
+            x: point
+                .x
+                .to_montgomery(),
+            y: point
+                .y
+                .to_montgomery(),
+        })
+        .collect();
+    let scalars_mont: Vec<C::ScalarField> = scalars
+        .iter()
+        .map(|scalar| scalar.to_montgomery())
+        .collect();
+    let bases = MsmBasesHandle::new(HostSlice::from_slice(&points_mont), &cfg, 1 << 30).unwrap();
+    fixed_base_msm_host(
+        HostSlice::from_slice(&scalars_mont),
+        &bases,
+        &cfg,
+        HostSlice::from_mut_slice(&mut results),
+    )
+    .unwrap();
+    assert_eq!(results[2].to_ark(), expected[2]);
+
+    // Tables survive a round trip through their file format
+    let mut file = Vec::new();
+    bases
+        .write_to(&mut file)
+        .unwrap();
+    assert_eq!(MsmBasesHandle::<C>::read_from(&mut &file[..]).unwrap(), bases);
+    let mut precomputed = vec![Affine::<C>::zero(); msm_size];
+    assert!(load_precomputed_points(
+        &mut &file[..],
+        msm_size as i32,
+        &MSMConfig::default(),
+        HostSlice::from_mut_slice(&mut precomputed)
+    )
+    .is_err());
+    file[0] ^= 1;
+    assert!(MsmBasesHandle::<C>::read_from(&mut &file[..]).is_err());
+
+    // Small scalars with a small `bitsize`, and scalars too wide for it
+    cfg = MSMConfig::default();
+    cfg.bitsize = 16;
+    let bases = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, 1 << 30).unwrap();
+    let small_scalars: Vec<C::ScalarField> = (0..msm_size as u32)
+        .map(|i| C::ScalarField::from_u32(i * 641 % (1 << 16)))
+        .collect();
+    let small_scalars_ark: Vec<_> = small_scalars
+        .iter()
+        .map(|scalar| scalar.to_ark())
+        .collect();
+    fixed_base_msm_host(
+        HostSlice::from_slice(&small_scalars),
+        &bases,
+        &cfg,
+        HostSlice::from_mut_slice(&mut results[..1]),
+    )
+    .unwrap();
+    assert_eq!(
+        results[0].to_ark(),
+        ark_ec::models::short_weierstrass::Projective::<C::ArkSWConfig>::msm(points_ark, &small_scalars_ark).unwrap()
+    );
+    assert!(fixed_base_msm_host(
+        HostSlice::from_slice(&scalars[..msm_size]),
+        &bases,
+        &cfg,
+        HostSlice::from_mut_slice(&mut results[..1]),
+    )
+    .is_err());
+
+    // The device MSM needs the bases on the device, with at most one copy per window
+    assert!(fixed_base_msm(
+        HostSlice::from_slice(&small_scalars),
+        &bases,
+        &cfg,
+        HostSlice::from_mut_slice(&mut results[..1]),
+    )
+    .is_err());
+    let mut bases = bases;
+    assert_eq!(bases.precompute_factor(), None);
+    assert!(bases
+        .load_to_device(0)
+        .is_err());
+    assert!(bases
+        .load_to_device(17)
+        .is_err());
+
+    let too_small = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, 16).unwrap_err();
+    assert_eq!(
+        too_small.get_icicle_error_code(),
+        IcicleErrorCode::MemoryAllocationError
+    );
+
+    // With 16 windows of 1 bit, 9 copies 2 windows apart would start the last one past the last window
+    cfg.c = 1;
+    let mut bases = MsmBasesHandle::new(HostSlice::from_slice(&points), &cfg, 1 << 30).unwrap();
+    assert!(bases
+        .load_to_device(9)
+        .is_err());
+    assert_eq!(bases.precompute_factor(), None);
+}
+
+pub fn check_msm_batch_host<C: Curve>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField> + FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    let sizes = [17, 0, 1, 40, 16, 5];
+    let (points_ark, scalars_ark) = ark_points_and_scalars::<C>(
+        sizes
+            .iter()
+            .sum(),
+    );
+    let points: Vec<Affine<C>> = points_ark
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let scalars: Vec<C::ScalarField> = scalars_ark
+        .iter()
+        .map(|scalar| C::ScalarField::from_ark(*scalar))
+        .collect();
+    let points_mont: Vec<Affine<C>> = points
+        .iter()
+        .map(|point| Affine {
+            x: point
+                .x
+                .to_montgomery(),
+            y: point
+                .y
+                .to_montgomery(),
+        })
+        .collect();
+    let scalars_mont: Vec<C::ScalarField> = scalars
+        .iter()
+        .map(|scalar| scalar.to_montgomery())
+        .collect();
+    let mut offset = 0;
+    let ranges: Vec<_> = sizes
+        .iter()
+        .map(|size| {
+            offset += size;
+            (offset - size, offset)
+        })
+        .collect();
+    let expected: Vec<_> = ranges
+        .iter()
+        .map(|(start, end)| {
+            ark_ec::models::short_weierstrass::Projective::<C::ArkSWConfig>::msm(
+                &points_ark[*start..*end],
+                &scalars_ark[*start..*end],
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let mut results = vec![Projective::<C>::zero(); sizes.len()];
+    for (scalars, points, cfg) in [
+        (&scalars, &points, MSMConfig::default()),
+        (
+            &scalars_mont,
+            &points_mont,
+            MSMConfig::builder()
+                .c(5)
+                .scalars_montgomery_form(true)
+                .points_montgomery_form(true)
+                .build()
+                .unwrap(),
+        ),
+    ] {
+        let jobs: Vec<_> = ranges
+            .iter()
+            .map(|(start, end)| MsmJob {
+                scalars: HostSlice::from_slice(&scalars[*start..*end]),
+                points: HostSlice::from_slice(&points[*start..*end]),
+            })
+            .collect();
+        msm_batch_host(&jobs, &cfg, HostSlice::from_mut_slice(&mut results)).unwrap();
+        for (result, expected) in results
+            .iter()
+            .zip(&expected)
+        {
+            assert_eq!(result.to_ark(), *expected);
+        }
+    }
+
+    let job = MsmJob {
+        scalars: HostSlice::from_slice(&scalars[..4]),
+        points: HostSlice::from_slice(&points[..4]),
+    };
+    let mut result = vec![Projective::<C>::zero()];
+    let run = |jobs: &[MsmJob<C>], cfg: &MSMConfig, results: &mut [Projective<C>]| {
+        msm_batch_host(jobs, cfg, HostSlice::from_mut_slice(results))
+            .unwrap_err()
+            .get_icicle_error_code()
+    };
+    // Scalars and points of different lengths, missing results, precomputed bases and too wide scalars
+    let uneven = MsmJob {
+        scalars: HostSlice::from_slice(&scalars[..4]),
+        points: HostSlice::from_slice(&points[..3]),
+    };
+    let cfg = MSMConfig::default();
+    assert_eq!(run(&[uneven], &cfg, &mut result), IcicleErrorCode::InvalidArgument);
+    assert_eq!(run(&[job, job], &cfg, &mut result), IcicleErrorCode::InvalidArgument);
+    let precomputed = MSMConfig::builder()
+        .precompute_factor(2)
+        .build()
+        .unwrap();
+    assert_eq!(run(&[job], &precomputed, &mut result), IcicleErrorCode::InvalidArgument);
+    let narrow = MSMConfig::builder()
+        .bitsize(16)
+        .build()
+        .unwrap();
+    assert_eq!(run(&[job], &narrow, &mut result), IcicleErrorCode::InvalidArgument);
+}
+
+fn check_msm_small_host_scalars<C: Curve, T: SmallScalar>(scalars: &[T], points_ark: &[ArkAffine<C::ArkSWConfig>])
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField>,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    let points: Vec<Affine<C>> = points_ark
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let points_mont: Vec<Affine<C>> = points
+        .iter()
+        .map(|point| Affine {
+            x: point
+                .x
+                .to_montgomery(),
+            y: point
+                .y
+                .to_montgomery(),
+        })
+        .collect();
+    let expected: Vec<_> = scalars
+        .chunks(points.len())
+        .map(|chunk| {
+            let chunk: Vec<ArkScalar<C>> = chunk
+                .iter()
+                .map(|scalar| match scalar.sign_and_magnitude() {
+                    (true, magnitude) => -ArkScalar::<C>::from(magnitude),
+                    (false, magnitude) => ArkScalar::<C>::from(magnitude),
+                })
+                .collect();
+            ark_ec::models::short_weierstrass::Projective::<C::ArkSWConfig>::msm(points_ark, &chunk).unwrap()
+        })
+        .collect();
+
+    // Windows chosen from the size, and windows of 3 bits over points in Montgomery form
+    let mut results = vec![Projective::<C>::zero(); expected.len()];
+    for (points, cfg) in [
+        (&points, MSMConfig::default()),
+        (
+            &points_mont,
+            MSMConfig::builder()
+                .c(3)
+                .points_montgomery_form(true)
+                .build()
+                .unwrap(),
+        ),
+    ] {
+        msm_small_host(
+            HostSlice::from_slice(scalars),
+            HostSlice::from_slice(points),
+            &cfg,
+            HostSlice::from_mut_slice(&mut results),
+        )
+        .unwrap();
+        for (result, expected) in results
+            .iter()
+            .zip(&expected)
+        {
+            assert_eq!(result.to_ark(), *expected);
+        }
+    }
+}
+
+pub fn check_msm_small_host<C: Curve>()
+where
+    C::ScalarField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::ScalarField>,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    // Two MSMs re-using the points
+    let (msm_size, batch_size) = (24, 2);
+    let (points_ark, _) = ark_points_and_scalars::<C>(msm_size);
+    let rng = &mut test_rng();
+    let len = msm_size * batch_size;
+    let bytes: Vec<u8> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    check_msm_small_host_scalars::<C, _>(&bytes, &points_ark);
+    let unsigned: Vec<u32> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    check_msm_small_host_scalars::<C, _>(&unsigned, &points_ark);
+    let mut signed: Vec<i64> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    signed[0] = i64::MIN;
+    signed[1] = i64::MAX;
+    signed[2] = -1;
+    check_msm_small_host_scalars::<C, _>(&signed, &points_ark);
+    let small_signed: Vec<i16> = (0..len)
+        .map(|_| rng.gen())
+        .collect();
+    check_msm_small_host_scalars::<C, _>(&small_signed, &points_ark);
+
+    let points = vec![Affine::<C>::zero(); 5];
+    let mut results = vec![Projective::<C>::zero(); 2];
+    let err = msm_small_host(
+        HostSlice::from_slice(&bytes[..12]),
+        HostSlice::from_slice(&points),
+        &MSMConfig::default(),
+        HostSlice::from_mut_slice(&mut results),
+    )
+    .unwrap_err();
+    assert_eq!(err.get_icicle_error_code(), IcicleErrorCode::InvalidArgument);
+}
+
+pub fn check_precomputed_points_file<C: Curve>()
+where
+    C::ScalarField: FieldArithmetic,
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ArkCurveConfig>::BaseField> + FieldArithmetic,
+{
+    // The file format doesn't look at the points, any will do for extended bases
+    let (points_ark, _) = ark_points_and_scalars::<C>(24);
+    let bases: Vec<Affine<C>> = points_ark
+        .iter()
+        .map(|point| Affine::from_ark(*point))
+        .collect();
+    let msm_size = 8;
+    let cfg = MSMConfig::builder()
+        .precompute_factor(3)
+        .c(11)
+        .points_montgomery_form(true)
+        .build()
+        .unwrap();
+
+    let mut file = Vec::new();
+    save_precomputed_points(&mut file, HostSlice::from_slice(&bases), msm_size, &cfg).unwrap();
+    let mut loaded = vec![Affine::<C>::zero(); bases.len()];
+    load_precomputed_points(&mut &file[..], msm_size, &cfg, HostSlice::from_mut_slice(&mut loaded)).unwrap();
+    assert_eq!(loaded, bases);
+
+    // Any difference in the parameters of the MSM fails the load
+    let load = |file: &[u8], msm_size: i32, cfg: &MSMConfig, len: usize| {
+        let mut output = vec![Affine::<C>::zero(); len];
+        let res = load_precomputed_points(&mut &file[..], msm_size, cfg, HostSlice::from_mut_slice(&mut output));
+        assert!(output
+            .iter()
+            .all(|point| *point == Affine::zero()));
+        res
+    };
+    assert!(load(&file, msm_size + 1, &cfg, bases.len()).is_err());
+    assert!(load(&file, msm_size, &cfg, bases.len() - 3).is_err());
+    for change in 0..3 {
+        let mut other_cfg = cfg.clone();
+        match change {
+            0 => other_cfg.precompute_factor = 2,
+            1 => other_cfg.c = 0,
+            _ => other_cfg.are_points_montgomery_form = false,
+        }
+        assert!(load(&file, msm_size, &other_cfg, bases.len()).is_err());
+    }
+
+    // As does another curve, another kind of bases or another version of the format
+    let curve_offset = 8 + 4 + 4 + 8;
+    file[curve_offset] ^= 1;
+    assert!(load(&file, msm_size, &cfg, bases.len()).is_err());
+    file[curve_offset] ^= 1;
+    file[12] = 2;
+    assert!(load(&file, msm_size, &cfg, bases.len()).is_err());
+    file[12] = 1;
+    file[8] = 2;
+    assert!(load(&file, msm_size, &cfg, bases.len()).is_err());
+}
+
+pub fn check_msm_config_builder() {
+    let cfg = MSMConfig::builder()
+        .c(16)
+        .bitsize(253)
+        .big_triangle(true)
+        .build()
+        .unwrap();
+    assert_eq!(cfg.c, 16);
+    assert_eq!(cfg.bitsize, 253);
+    assert!(cfg.is_big_triangle);
+    // Parameters which aren't set keep their default values
+    assert_eq!(cfg.precompute_factor, 1);
+    assert_eq!(cfg.large_bucket_factor, 10);
+    assert!(!cfg.are_scalars_montgomery_form);
+
+    let cfg = MSMConfig::builder_for_device(1)
+        .precompute_factor(4)
+        .scalars_montgomery_form(true)
+        .is_async(true)
+        .build()
+        .unwrap();
+    assert_eq!(
+        cfg.ctx
+            .device_id,
+        1
+    );
+    assert_eq!(cfg.precompute_factor, 4);
+    assert!(cfg.are_scalars_montgomery_form && cfg.is_async);
+
+    for builder in [
+        MSMConfig::builder().precompute_factor(0),
+        MSMConfig::builder().c(-1),
+        MSMConfig::builder().c(32),
+        MSMConfig::builder()
+            .c(17)
+            .bitsize(16),
+        MSMConfig::builder().bitsize(-1),
+        MSMConfig::builder().large_bucket_factor(-1),
+    ] {
+        assert_eq!(
+            builder
+                .build()
+                .unwrap_err()
+                .get_icicle_error_code(),
+            IcicleErrorCode::InvalidArgument
+        );
+    }
+    // c only has to fit in bitsize when bitsize isn't left to the scalar field
+    assert!(MSMConfig::builder()
+        .c(20)
+        .build()
+        .is_ok());
+}
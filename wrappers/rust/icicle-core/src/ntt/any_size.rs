@@ -0,0 +1,521 @@
+use crate::error::{IcicleError, IcicleResult};
+use crate::field::utils::{modulus_minus_one_over, pow_limbs};
+use crate::ntt::{get_root_of_unity, ntt_inplace, NTTConfig, NTTDir, Ordering, NTT};
+use crate::traits::{FieldArithmetic, FieldImpl};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+fn field_from_u64<F: FieldImpl>(value: u64) -> F {
+    F::from_bytes_le(&value.to_le_bytes())
+}
+
+// Distinct prime factors of `n`
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n % factor == 0 {
+            factors.push(factor);
+            while n % factor == 0 {
+                n /= factor;
+            }
+        }
+        factor += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Primitive root of unity of order `order`, which must divide `p - 1`. It generates the domain of [ntt_any_size]:
+/// `g^((p - 1) / order)` for the smallest integer `g > 1` that gives an element of order exactly `order`.
+///
+/// The root is computed on the host, so it doesn't depend on the initialized NTT domain. For power of two orders it
+/// isn't necessarily the root of [get_root_of_unity](crate::ntt::get_root_of_unity).
+pub fn root_of_unity_any_size<F: FieldArithmetic>(order: u64) -> IcicleResult<F> {
+    if order == 0 {
+        return Err(IcicleError::invalid_argument("Root of unity order must be at least 1"));
+    }
+    let (exponent, remainder) = modulus_minus_one_over::<F>(order);
+    if remainder != 0 {
+        return Err(IcicleError::invalid_argument(
+            "Field has no root of unity of this order, it must divide p - 1",
+        ));
+    }
+    let factors = prime_factors(order);
+    (2..)
+        .map(|g| pow_limbs(F::from_u32(g), F::one(), &exponent))
+        .find(|root| {
+            factors
+                .iter()
+                .all(|factor| root.pow(order / factor) != F::one())
+        })
+        .ok_or_else(|| IcicleError::invalid_argument("No root of unity found"))
+}
+
+// `1, q, q², ...` up to the largest power of the prime `q` that divides `p - 1`
+fn prime_powers_dividing<F: FieldArithmetic>(prime: u64) -> Vec<u64> {
+    let mut powers = vec![1u64];
+    while let Some(power) = powers
+        .last()
+        .unwrap()
+        .checked_mul(prime)
+    {
+        if modulus_minus_one_over::<F>(power).1 != 0 {
+            break;
+        }
+        powers.push(power);
+    }
+    powers
+}
+
+/// Smallest size from `min_size` on that [ntt_any_size] computes with mixed radix: the smallest `2^a 3^b 5^c`
+/// dividing `p - 1` that is at least `min_size`, built from the powers of 2, 3 and 5 in the factorization of `p - 1`.
+/// Polynomial products of `n` coefficients computed over such a domain are padded to this size instead of the next
+/// power of two.
+pub fn ntt_size_at_least<F: FieldArithmetic>(min_size: u64) -> IcicleResult<u64> {
+    let twos = prime_powers_dividing::<F>(2);
+    let threes = prime_powers_dividing::<F>(3);
+    let fives = prime_powers_dividing::<F>(5);
+    // For each odd part, the smallest power of two that brings it to `min_size`
+    fives
+        .iter()
+        .flat_map(|five| {
+            threes
+                .iter()
+                .filter_map(move |three| five.checked_mul(*three))
+        })
+        .filter_map(|odd| {
+            twos.iter()
+                .filter_map(|two| odd.checked_mul(*two))
+                .find(|size| *size >= min_size)
+        })
+        .min()
+        .ok_or_else(|| {
+            IcicleError::invalid_argument("No NTT size of the form 2^a 3^b 5^c that divides p - 1 is large enough")
+        })
+}
+
+// `(n1, n2)` with `size = n1 n2`, `n2` the largest power of two dividing `size` and `n1` made of 3s and 5s, for the
+// sizes that run with mixed radix
+fn mixed_radix_split(size: usize) -> Option<(usize, usize)> {
+    let n2 = 1 << size.trailing_zeros();
+    let mut odd = size / n2;
+    for radix in [3, 5] {
+        while odd % radix == 0 {
+            odd /= radix;
+        }
+    }
+    (odd == 1).then_some((size / n2, n2))
+}
+
+// `w^(t (t - 1) / 2)` for `t < len`, built with one multiplication per step as `w^C(t + 1, 2) = w^C(t, 2) w^t`
+fn chirp_powers<F: FieldArithmetic>(w: F, len: usize) -> Vec<F> {
+    let mut chirp = Vec::with_capacity(len);
+    let (mut value, mut step) = (F::one(), F::one());
+    for _ in 0..len {
+        chirp.push(value);
+        value = value * step;
+        step = step * w;
+    }
+    chirp
+}
+
+// Size of the NTTs of the batch, after checking the config and the slices like `ntt` does
+fn check_any_size<F: FieldImpl>(input_len: usize, output_len: usize, cfg: &NTTConfig<F>) -> IcicleResult<usize> {
+    if input_len != output_len {
+        return Err(IcicleError::invalid_argument(
+            "Input and output lengths of ntt_any_size don't match",
+        ));
+    }
+    if cfg.batch_size < 1 || input_len == 0 || input_len % cfg.batch_size as usize != 0 {
+        return Err(IcicleError::invalid_argument(
+            "ntt_any_size input length must be a non-zero multiple of batch_size",
+        ));
+    }
+    if cfg.ordering != Ordering::kNN || cfg.columns_batch {
+        return Err(IcicleError::invalid_argument(
+            "ntt_any_size only takes natural order inputs and outputs, batched by rows",
+        ));
+    }
+    if cfg.coset_gen == F::zero() {
+        return Err(IcicleError::invalid_argument("NTT coset_gen can't be zero"));
+    }
+    Ok(input_len / cfg.batch_size as usize)
+}
+
+// Bluestein's algorithm: with `jk = C(j + k, 2) - C(j, 2) - C(k, 2)`, the sums `X_k = Σ a_j w^(jk)` are
+// `w^-C(k, 2) Σ (a_j w^-C(j, 2)) w^C(j + k, 2)`, a correlation with the chirp that is computed as a cyclic
+// convolution of power of two size by `transform`. `transform(rows, dir, batch_size)` runs a batch of NTTs of that
+// size in place, with any ordering of the evaluations as long as the inverse undoes the forward one.
+fn bluestein<F: FieldArithmetic>(
+    input: &[F],
+    size: usize,
+    dir: NTTDir,
+    coset_gen: F,
+    output: &mut [F],
+    mut transform: impl FnMut(&mut [F], NTTDir, usize) -> IcicleResult<()>,
+) -> IcicleResult<()> {
+    let root = root_of_unity_any_size::<F>(size as u64)?;
+    let root_inv = root
+        .inv()
+        .unwrap();
+    let (w, w_inv) = match dir {
+        NTTDir::kForward => (root, root_inv),
+        NTTDir::kInverse => (root_inv, root),
+    };
+    let chirp = chirp_powers(w, 2 * size - 1);
+    let inv_chirp = chirp_powers(w_inv, size);
+    // Forward transforms evaluate over the coset, `X_k = Σ (x_j g^j) w^(jk)`, and inverse ones undo it with
+    // `x_j = g^-j Σ X_k w^(jk) / size`
+    let mut pre = inv_chirp.clone();
+    let mut post = inv_chirp;
+    match dir {
+        NTTDir::kForward => scale_by_powers(&mut pre, coset_gen, F::one()),
+        NTTDir::kInverse => scale_by_powers(
+            &mut post,
+            coset_gen
+                .inv()
+                .unwrap(),
+            field_from_u64::<F>(size as u64)
+                .inv()
+                .unwrap(),
+        ),
+    }
+
+    // The correlation is the middle of the convolution of the reversed inputs with the chirp, whose tail wraps
+    // around below index `size - 1` when the convolution size is at least `2 * size - 1`
+    let conv_size = (2 * size - 1).next_power_of_two();
+    let batch_size = input.len() / size;
+    let mut rows = vec![F::zero(); (batch_size + 1) * conv_size];
+    for (row, values) in rows
+        .chunks_mut(conv_size)
+        .zip(input.chunks(size))
+    {
+        for (j, value) in values
+            .iter()
+            .enumerate()
+        {
+            row[size - 1 - j] = *value * pre[j];
+        }
+    }
+    rows[batch_size * conv_size..batch_size * conv_size + chirp.len()].copy_from_slice(&chirp);
+    transform(&mut rows, NTTDir::kForward, batch_size + 1)?;
+    let (rows, kernel) = rows.split_at_mut(batch_size * conv_size);
+    for row in rows.chunks_mut(conv_size) {
+        for (value, factor) in row
+            .iter_mut()
+            .zip(kernel.iter())
+        {
+            *value = *value * *factor;
+        }
+    }
+    transform(rows, NTTDir::kInverse, batch_size)?;
+    for (values, row) in output
+        .chunks_mut(size)
+        .zip(rows.chunks(conv_size))
+    {
+        for (k, value) in values
+            .iter_mut()
+            .enumerate()
+        {
+            *value = row[k + size - 1] * post[k];
+        }
+    }
+    Ok(())
+}
+
+// Multiplies `values[i]` by `scale * base^i`
+fn scale_by_powers<F: FieldArithmetic>(values: &mut [F], base: F, scale: F) {
+    let mut factor = scale;
+    for value in values.iter_mut() {
+        *value = *value * factor;
+        factor = factor * base;
+    }
+}
+
+// In-place radix-2 NTT of a power of two size with natural order inputs and outputs
+fn host_radix2_ntt<F: FieldArithmetic>(values: &mut [F], root: F) {
+    let size = values.len();
+    let log_size = size.trailing_zeros();
+    for i in 0..size {
+        let j = i
+            .reverse_bits()
+            .checked_shr(usize::BITS - log_size)
+            .unwrap_or(0);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < size {
+        let step = root.pow((size / (2 * half)) as u64);
+        for block in values.chunks_mut(2 * half) {
+            let mut twiddle = F::one();
+            for i in 0..half {
+                let odd = block[i + half] * twiddle;
+                block[i + half] = block[i] - odd;
+                block[i] = block[i] + odd;
+                twiddle = twiddle * step;
+            }
+        }
+        half *= 2;
+    }
+}
+
+// DFT of `values` with `root` of order `values.len()`, decimating in time by the smallest prime factor of the length.
+// It runs the small odd DFTs of the mixed radix NTTs, whose lengths are made of 3s and 5s.
+fn host_mixed_radix_dft<F: FieldArithmetic>(values: &[F], root: F) -> Vec<F> {
+    let size = values.len();
+    if size == 1 {
+        return values.to_vec();
+    }
+    let radix = prime_factors(size as u64)[0] as usize;
+    let sub_size = size / radix;
+    let sub_root = root.pow(radix as u64);
+    let sub_dfts: Vec<Vec<F>> = (0..radix)
+        .map(|offset| {
+            let sub_values: Vec<F> = values[offset..]
+                .iter()
+                .step_by(radix)
+                .copied()
+                .collect();
+            host_mixed_radix_dft(&sub_values, sub_root)
+        })
+        .collect();
+    // `X_k = Σ_s root^(sk) S_s[k mod sub_size]` for the DFTs `S_s` of the inputs `x[radix j + s]`
+    let mut output = Vec::with_capacity(size);
+    let mut root_power = F::one();
+    for k in 0..size {
+        let (mut sum, mut twiddle) = (F::zero(), F::one());
+        for sub_dft in sub_dfts.iter() {
+            sum = sum + sub_dft[k % sub_size] * twiddle;
+            twiddle = twiddle * root_power;
+        }
+        output.push(sum);
+        root_power = root_power * root;
+    }
+    output
+}
+
+// Four-step mixed radix NTT of size `n = n1 n2`: with `j = n2 j1 + j2` and `k = k1 + n1 k2`,
+// `w^(jk) = (w^n2)^(j1 k1) w^(j2 k1) (w^n1)^(j2 k2)`, so the NTT is `n2` DFTs of size `n1` over the strided inputs, a
+// twiddle by `w^(j2 k1)` and `n1` power of two NTTs of size `n2` with root `w^n1`. `transform(rows, row_size, root)`
+// runs the latter in place on a batch of rows, with natural order inputs and outputs.
+fn mixed_radix<F: FieldArithmetic>(
+    input: &[F],
+    (n1, n2): (usize, usize),
+    dir: NTTDir,
+    coset_gen: F,
+    output: &mut [F],
+    mut transform: impl FnMut(&mut [F], usize, F) -> IcicleResult<()>,
+) -> IcicleResult<()> {
+    let size = n1 * n2;
+    let root = root_of_unity_any_size::<F>(size as u64)?;
+    let w = match dir {
+        NTTDir::kForward => root,
+        NTTDir::kInverse => root
+            .inv()
+            .unwrap(),
+    };
+    let w_n2 = w.pow(n2 as u64);
+    let mut rows = vec![F::zero(); input.len()];
+    for (values, rows) in input
+        .chunks(size)
+        .zip(rows.chunks_mut(size))
+    {
+        let mut values = values.to_vec();
+        if dir == NTTDir::kForward {
+            scale_by_powers(&mut values, coset_gen, F::one());
+        }
+        let mut twiddle_step = F::one();
+        for j2 in 0..n2 {
+            let column: Vec<F> = values[j2..]
+                .iter()
+                .step_by(n2)
+                .copied()
+                .collect();
+            let mut twiddle = F::one();
+            for (k1, value) in host_mixed_radix_dft(&column, w_n2)
+                .into_iter()
+                .enumerate()
+            {
+                rows[k1 * n2 + j2] = value * twiddle;
+                twiddle = twiddle * twiddle_step;
+            }
+            twiddle_step = twiddle_step * w;
+        }
+    }
+    transform(&mut rows, n2, w.pow(n1 as u64))?;
+    for (values, rows) in output
+        .chunks_mut(size)
+        .zip(rows.chunks(size))
+    {
+        for (k1, row) in rows
+            .chunks(n2)
+            .enumerate()
+        {
+            for (k2, value) in row
+                .iter()
+                .enumerate()
+            {
+                values[k1 + n1 * k2] = *value;
+            }
+        }
+        if dir == NTTDir::kInverse {
+            scale_by_powers(
+                values,
+                coset_gen
+                    .inv()
+                    .unwrap(),
+                field_from_u64::<F>(size as u64)
+                    .inv()
+                    .unwrap(),
+            );
+        }
+    }
+    Ok(())
+}
+
+// `t` with `root = generator^t`, both of order `size`, a power of two, found bit by bit: once the lower bits are
+// taken out, `root generator^-t` is in the subgroup of order `size >> bit`, and its power `size >> (bit + 1)` is `-1`
+// exactly when the next bit is set
+fn two_adic_log<F: FieldArithmetic>(generator: F, root: F, size: usize) -> usize {
+    let mut t = 0;
+    let mut rest = root;
+    let mut step = generator
+        .inv()
+        .unwrap();
+    for bit in 0..size.trailing_zeros() {
+        if rest.pow((size >> (bit + 1)) as u64) != F::one() {
+            t |= 1 << bit;
+            rest = rest * step;
+        }
+        step = step * step;
+    }
+    t
+}
+
+/// Computes the NTT, or a batch of several NTTs, of any size `n` dividing `p - 1` over the domain generated by
+/// [root_of_unity_any_size]: forward NTTs evaluate at `g ω^k` for `k < n` and the coset generator `g` of `cfg`, and
+/// inverse NTTs interpolate from these evaluations.
+///
+/// The size is `input.len() / cfg.batch_size`, like [ntt](crate::ntt::ntt), and [ntt_size_at_least] gives the
+/// smallest size from a given one that runs with mixed radix. Sizes `n = 3^a 5^b 2^k` run the radix-3/5 DFTs of
+/// size `3^a 5^b` on the host and a batch of power of two NTTs of size `2^k` on the stream and device of `cfg`, so the
+/// NTT domain must be initialized from [get_root_of_unity] for `2^k`. Other sizes fall back to Bluestein's algorithm,
+/// two batched power of two NTTs of size `(2n - 1).next_power_of_two()` for which the domain must be initialized.
+/// Inputs and outputs are in natural order, batched by rows, and the call is blocking whatever `cfg.is_async`.
+/// [ntt_any_size_host] computes the same NTTs without a device.
+pub fn ntt_any_size<F>(
+    input: &HostSlice<F>,
+    dir: NTTDir,
+    cfg: &NTTConfig<F>,
+    output: &mut HostSlice<F>,
+) -> IcicleResult<()>
+where
+    F: FieldArithmetic,
+    <F as FieldImpl>::Config: NTT<F, F>,
+{
+    let size = check_any_size(input.len(), output.len(), cfg)?;
+    let mut local_cfg = cfg.clone();
+    local_cfg.coset_gen = F::one();
+    local_cfg.is_async = false;
+    if let Some(split) = mixed_radix_split(size) {
+        return mixed_radix(
+            input.as_slice(),
+            split,
+            dir,
+            cfg.coset_gen,
+            output.as_mut_slice(),
+            |rows, row_size, root| {
+                if row_size == 1 {
+                    return Ok(());
+                }
+                local_cfg.batch_size = (rows.len() / row_size) as i32;
+                ntt_inplace(HostSlice::from_mut_slice(rows), NTTDir::kForward, &local_cfg)?;
+                // The device evaluates at the powers of its own root `ω`, and `root^k = ω^(tk)`
+                let t = two_adic_log(get_root_of_unity::<F>(row_size as u64), root, row_size);
+                for row in rows.chunks_mut(row_size) {
+                    let evals = row.to_vec();
+                    let mut index = 0;
+                    for value in row.iter_mut() {
+                        *value = evals[index];
+                        index = (index + t) % row_size;
+                    }
+                }
+                Ok(())
+            },
+        );
+    }
+    bluestein(
+        input.as_slice(),
+        size,
+        dir,
+        cfg.coset_gen,
+        output.as_mut_slice(),
+        |rows, dir, batch_size| {
+            // The convolution doesn't depend on the order of the evaluations, mixed order is the cheapest
+            local_cfg.batch_size = batch_size as i32;
+            local_cfg.ordering = match dir {
+                NTTDir::kForward => Ordering::kNM,
+                NTTDir::kInverse => Ordering::kMN,
+            };
+            ntt_inplace(HostSlice::from_mut_slice(rows), dir, &local_cfg)
+        },
+    )
+}
+
+/// Host version of [ntt_any_size], for machines without a device. It gives the same outputs with the same mixed radix
+/// and Bluestein paths, only `cfg.batch_size`, `cfg.coset_gen`, `cfg.ordering` and `cfg.columns_batch` are read.
+pub fn ntt_any_size_host<F: FieldArithmetic>(
+    input: &HostSlice<F>,
+    dir: NTTDir,
+    cfg: &NTTConfig<F>,
+    output: &mut HostSlice<F>,
+) -> IcicleResult<()> {
+    let size = check_any_size(input.len(), output.len(), cfg)?;
+    if let Some(split) = mixed_radix_split(size) {
+        return mixed_radix(
+            input.as_slice(),
+            split,
+            dir,
+            cfg.coset_gen,
+            output.as_mut_slice(),
+            |rows, row_size, root| {
+                for row in rows.chunks_mut(row_size) {
+                    host_radix2_ntt(row, root);
+                }
+                Ok(())
+            },
+        );
+    }
+    let conv_size = (2 * size - 1).next_power_of_two();
+    let root = root_of_unity_any_size::<F>(conv_size as u64)?;
+    let (root_inv, size_inv) = (
+        root.inv()
+            .unwrap(),
+        field_from_u64::<F>(conv_size as u64)
+            .inv()
+            .unwrap(),
+    );
+    bluestein(
+        input.as_slice(),
+        size,
+        dir,
+        cfg.coset_gen,
+        output.as_mut_slice(),
+        |rows, dir, _| {
+            for row in rows.chunks_mut(conv_size) {
+                match dir {
+                    NTTDir::kForward => host_radix2_ntt(row, root),
+                    NTTDir::kInverse => {
+                        host_radix2_ntt(row, root_inv);
+                        scale_by_powers(row, F::one(), size_inv);
+                    }
+                }
+            }
+            Ok(())
+        },
+    )
+}
@@ -1,13 +1,18 @@
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::{DeviceContext, DEFAULT_DEVICE_ID};
 use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+use icicle_cuda_runtime::stream::CudaStream;
 
+use crate::error::IcicleError;
 pub use crate::{error::IcicleResult, traits::FieldImpl};
 
+mod any_size;
 #[cfg(feature = "arkworks")]
 #[doc(hidden)]
 pub mod tests;
 
+pub use any_size::{ntt_any_size, ntt_any_size_host, ntt_size_at_least, root_of_unity_any_size};
+
 /// Whether to perform normal forward NTT, or inverse NTT (iNTT). Mathematically, forward NTT computes polynomial
 /// evaluations from coefficients while inverse NTT computes coefficients from evaluations.
 #[allow(non_camel_case_types)]
@@ -68,37 +73,66 @@ pub enum NttAlgorithm {
     MixedRadix,
 }
 
-/// Struct that encodes NTT parameters to be passed into the [ntt](ntt) function.
+/// Struct that encodes NTT parameters to be passed into the [ntt](ntt) function. Built with [NTTConfig::builder] or
+/// [NTTConfig::default_for_device], the parameters can be read back through the accessors.
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct NTTConfig<'a, S> {
+    pub(crate) ctx: DeviceContext<'a>,
+    pub(crate) coset_gen: S,
+    pub(crate) batch_size: i32,
+    pub(crate) columns_batch: bool,
+    pub(crate) ordering: Ordering,
+    are_inputs_on_device: bool,
+    are_outputs_on_device: bool,
+    pub(crate) is_async: bool,
+    pub(crate) ntt_algorithm: NttAlgorithm,
+}
+
+impl<'a, S: FieldImpl> Default for NTTConfig<'a, S> {
+    fn default() -> Self {
+        Self::default_for_device(DEFAULT_DEVICE_ID)
+    }
+}
+
+impl<'a, S: FieldImpl> NTTConfig<'a, S> {
     /// Details related to the device such as its id and stream id. See [DeviceContext](DeviceContext).
-    pub ctx: DeviceContext<'a>,
+    pub fn ctx(&self) -> &DeviceContext<'a> {
+        &self.ctx
+    }
+
     /// Coset generator. Used to perform coset (i)NTTs. Default value: `S::one()` (corresponding to no coset being used).
-    pub coset_gen: S,
+    pub fn coset_gen(&self) -> S {
+        self.coset_gen
+    }
+
     /// The number of NTTs to compute. Default value: 1.
-    pub batch_size: i32,
+    pub fn batch_size(&self) -> i32 {
+        self.batch_size
+    }
+
     /// If true the function will compute the NTTs over the columns of the input matrix and not over the rows.
-    pub columns_batch: bool,
+    pub fn columns_batch(&self) -> bool {
+        self.columns_batch
+    }
+
     /// Ordering of inputs and outputs. See [Ordering](Ordering). Default value: `Ordering::kNN`.
-    pub ordering: Ordering,
-    pub are_inputs_on_device: bool,
-    pub are_outputs_on_device: bool,
+    pub fn ordering(&self) -> Ordering {
+        self.ordering
+    }
+
     /// Whether to run the NTT asynchronously. If set to `true`, the NTT function will be non-blocking and you'd need to synchronize
     /// it explicitly by running `stream.synchronize()`. If set to false, the NTT function will block the current CPU thread.
-    pub is_async: bool,
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
     /// Explicitly select the NTT algorithm. Default value: Auto (the implementation selects radix-2 or mixed-radix algorithm based
     /// on heuristics).
-    pub ntt_algorithm: NttAlgorithm,
-}
-
-impl<'a, S: FieldImpl> Default for NTTConfig<'a, S> {
-    fn default() -> Self {
-        Self::default_for_device(DEFAULT_DEVICE_ID)
+    pub fn ntt_algorithm(&self) -> NttAlgorithm {
+        self.ntt_algorithm
     }
-}
 
-impl<'a, S: FieldImpl> NTTConfig<'a, S> {
     pub fn default_for_device(device_id: usize) -> Self {
         NTTConfig {
             ctx: DeviceContext::default_for_device(device_id),
@@ -112,6 +146,99 @@ impl<'a, S: FieldImpl> NTTConfig<'a, S> {
             ntt_algorithm: NttAlgorithm::Auto,
         }
     }
+
+    /// Starts an [NTTConfigBuilder] from the default config of the default device.
+    pub fn builder() -> NTTConfigBuilder<'a, S> {
+        Self::builder_for_device(DEFAULT_DEVICE_ID)
+    }
+
+    /// Starts an [NTTConfigBuilder] from the default config of device `device_id`.
+    pub fn builder_for_device(device_id: usize) -> NTTConfigBuilder<'a, S> {
+        NTTConfigBuilder {
+            cfg: Self::default_for_device(device_id),
+        }
+    }
+}
+
+/// Builder of [NTTConfig] which checks the parameters on [build](NTTConfigBuilder::build), for instance
+/// `NTTConfig::<F>::builder().batch_size(4).ordering(Ordering::kNM).build()?`.
+///
+/// Parameters that aren't set keep the values of [NTTConfig::default_for_device]. Whether inputs and outputs are
+/// on the device is taken from the slices passed to [ntt], so it isn't set here.
+#[derive(Debug, Clone)]
+pub struct NTTConfigBuilder<'a, S> {
+    cfg: NTTConfig<'a, S>,
+}
+
+impl<'a, S: FieldImpl> NTTConfigBuilder<'a, S> {
+    /// Device context to run the NTT in, see [NTTConfig::ctx].
+    pub fn ctx(mut self, ctx: DeviceContext<'a>) -> Self {
+        self.cfg
+            .ctx = ctx;
+        self
+    }
+
+    /// Stream of the device context to run the NTT on.
+    pub fn stream(mut self, stream: &'a CudaStream) -> Self {
+        self.cfg
+            .ctx
+            .stream = stream;
+        self
+    }
+
+    /// See [NTTConfig::coset_gen], can't be zero.
+    pub fn coset_gen(mut self, coset_gen: S) -> Self {
+        self.cfg
+            .coset_gen = coset_gen;
+        self
+    }
+
+    /// See [NTTConfig::batch_size], must be at least 1.
+    pub fn batch_size(mut self, batch_size: i32) -> Self {
+        self.cfg
+            .batch_size = batch_size;
+        self
+    }
+
+    /// See [NTTConfig::columns_batch].
+    pub fn columns_batch(mut self, columns_batch: bool) -> Self {
+        self.cfg
+            .columns_batch = columns_batch;
+        self
+    }
+
+    /// See [NTTConfig::ordering].
+    pub fn ordering(mut self, ordering: Ordering) -> Self {
+        self.cfg
+            .ordering = ordering;
+        self
+    }
+
+    /// See [NTTConfig::ntt_algorithm].
+    pub fn ntt_algorithm(mut self, ntt_algorithm: NttAlgorithm) -> Self {
+        self.cfg
+            .ntt_algorithm = ntt_algorithm;
+        self
+    }
+
+    /// See [NTTConfig::is_async].
+    pub fn is_async(mut self, is_async: bool) -> Self {
+        self.cfg
+            .is_async = is_async;
+        self
+    }
+
+    /// Returns the config, or an `InvalidArgument` error naming the first parameter out of its range.
+    pub fn build(self) -> IcicleResult<NTTConfig<'a, S>> {
+        let cfg = self.cfg;
+        if cfg.batch_size < 1 {
+            return Err(IcicleError::invalid_argument("NTT batch_size must be at least 1"));
+        }
+        if cfg.coset_gen == S::zero() {
+            return Err(IcicleError::invalid_argument("NTT coset_gen can't be zero"));
+        }
+        Ok(cfg)
+    }
 }
 
 #[doc(hidden)]
@@ -276,7 +403,7 @@ macro_rules! impl_ntt_without_domain {
                 unsafe {
                     ntt_cuda(
                         input.as_ptr(),
-                        (input.len() / (cfg.batch_size as usize)) as i32,
+                        (input.len() / (cfg.batch_size() as usize)) as i32,
                         dir,
                         cfg,
                         output.as_mut_ptr(),
@@ -293,7 +420,7 @@ macro_rules! impl_ntt_without_domain {
                 unsafe {
                     ntt_cuda(
                         inout.as_mut_ptr(),
-                        (inout.len() / (cfg.batch_size as usize)) as i32,
+                        (inout.len() / (cfg.batch_size() as usize)) as i32,
                         dir,
                         cfg,
                         inout.as_mut_ptr(),
@@ -399,6 +526,11 @@ macro_rules! impl_ntt_tests {
             check_ntt_batch::<$field>()
         }
 
+        #[test]
+        fn test_ntt_config_builder() {
+            check_ntt_config_builder::<$field>()
+        }
+
         #[test]
         #[parallel]
         fn test_ntt_device_async() {
@@ -406,6 +538,18 @@ macro_rules! impl_ntt_tests {
             check_ntt_device_async::<$field>()
         }
 
+        #[test]
+        #[parallel]
+        fn test_ntt_any_size() {
+            INIT.get_or_init(move || init_domain::<$field>(MAX_SIZE, DEFAULT_DEVICE_ID, FAST_TWIDDLES_MODE));
+            check_ntt_any_size::<$field>()
+        }
+
+        #[test]
+        fn test_ntt_any_size_host() {
+            check_ntt_any_size_host::<$field>()
+        }
+
         #[test]
         #[serial]
         fn test_ntt_release_domain() {
@@ -501,7 +645,6 @@ macro_rules! impl_ntt_bench {
 
                     let mut batch_ntt_result = vec![F::zero(); batch_size * test_size];
                     let batch_ntt_result = HostSlice::from_mut_slice(&mut batch_ntt_result);
-                    let mut config = NTTConfig::default();
                     for is_inverse in [NTTDir::kInverse, NTTDir::kForward] {
                         for ordering in [
                             Ordering::kNN,
@@ -511,10 +654,13 @@ macro_rules! impl_ntt_bench {
                             Ordering::kNM,
                             Ordering::kMN,
                         ] {
-                            config.ordering = ordering;
                             // for alg in [NttAlgorithm::Radix2, NttAlgorithm::MixedRadix] {
-                            config.batch_size = batch_size as i32;
-                            // config.ntt_algorithm = alg;
+                            let mut config = NTTConfig::builder()
+                                .ordering(ordering)
+                                .batch_size(batch_size as i32)
+                                // .ntt_algorithm(alg)
+                                .build()
+                                .unwrap();
                             let bench_descr = format!(
                                 "{:?} {:?} {} x {}",
                                 ordering, is_inverse, test_size, batch_size
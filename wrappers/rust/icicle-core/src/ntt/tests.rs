@@ -6,12 +6,14 @@ use icicle_cuda_runtime::device_context::DeviceContext;
 use icicle_cuda_runtime::memory::{DeviceVec, HostSlice};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use crate::error::IcicleResult;
+use crate::error::{IcicleErrorCode, IcicleResult};
+use crate::field::utils::modulus_minus_one_over;
 use crate::{
     ntt::{
-        initialize_domain, ntt, ntt_inplace, release_domain, NTTConfig, NTTDir, NTTDomain, NttAlgorithm, Ordering, NTT,
+        initialize_domain, ntt, ntt_any_size, ntt_any_size_host, ntt_inplace, ntt_size_at_least, release_domain,
+        root_of_unity_any_size, NTTConfig, NTTDir, NTTDomain, NttAlgorithm, Ordering, NTT,
     },
-    traits::{ArkConvertible, FieldImpl, GenerateRandom},
+    traits::{ArkConvertible, FieldArithmetic, FieldImpl, GenerateRandom},
     vec_ops::{transpose_matrix, VecOps},
 };
 
@@ -478,3 +480,184 @@ where
     let err = rel_domain::<F>(&config.ctx);
     assert!(err.is_ok())
 }
+
+pub fn check_ntt_config_builder<F: FieldImpl>() {
+    let coset_gen = F::from_u32(5);
+    let cfg = NTTConfig::<F>::builder()
+        .coset_gen(coset_gen)
+        .batch_size(4)
+        .columns_batch(true)
+        .ordering(Ordering::kNM)
+        .ntt_algorithm(NttAlgorithm::MixedRadix)
+        .build()
+        .unwrap();
+    assert_eq!(cfg.coset_gen, coset_gen);
+    assert_eq!(cfg.batch_size, 4);
+    assert!(cfg.columns_batch);
+    assert_eq!(cfg.ordering, Ordering::kNM);
+    assert_eq!(cfg.ntt_algorithm, NttAlgorithm::MixedRadix);
+    assert!(!cfg.is_async);
+
+    for builder in [
+        NTTConfig::<F>::builder().batch_size(0),
+        NTTConfig::<F>::builder().coset_gen(F::zero()),
+    ] {
+        assert_eq!(
+            builder
+                .build()
+                .unwrap_err()
+                .get_icicle_error_code(),
+            IcicleErrorCode::InvalidArgument
+        );
+    }
+}
+
+// Evaluations of the polynomial with coefficients `values` at `coset_gen * root^k`
+fn direct_ntt<F: FieldArithmetic>(values: &[F], root: F, coset_gen: F) -> Vec<F> {
+    (0..values.len())
+        .map(|k| {
+            let x = coset_gen * root.pow(k as u64);
+            values
+                .iter()
+                .rev()
+                .fold(F::zero(), |acc, value| acc * x + *value)
+        })
+        .collect()
+}
+
+// Whether `size` divides `p - 1`, and whether it is made of 2s, 3s and 5s
+fn any_size_kind<F: FieldArithmetic>(size: u64) -> (bool, bool) {
+    let mut rest = size;
+    for radix in [2, 3, 5] {
+        while rest % radix == 0 {
+            rest /= radix;
+        }
+    }
+    (modulus_minus_one_over::<F>(size).1 == 0, rest == 1)
+}
+
+pub fn check_ntt_any_size_host<F: FieldArithmetic>() {
+    for min_size in [1, 2, 3, 7, 10, 33, 100, 1000] {
+        let size = ntt_size_at_least::<F>(min_size).unwrap();
+        assert!(size >= min_size);
+        assert_eq!(any_size_kind::<F>(size), (true, true));
+        assert!((min_size..size).all(|smaller| any_size_kind::<F>(smaller) != (true, true)));
+    }
+
+    // Every size dividing `p - 1`, with mixed radix or Bluestein's algorithm
+    let batch_size = 3;
+    let sizes: Vec<u64> = (1..=40)
+        .filter(|size| any_size_kind::<F>(*size).0)
+        .chain([ntt_size_at_least::<F>(100).unwrap()])
+        .collect();
+    for size in sizes {
+        let root = root_of_unity_any_size::<F>(size).unwrap();
+        assert_eq!(root.pow(size), F::one());
+        assert!((1..size).all(|k| root.pow(k) != F::one()));
+
+        let coset_gen = F::from_u32(7)
+            .inv()
+            .unwrap();
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(batch_size)
+            .coset_gen(coset_gen)
+            .build()
+            .unwrap();
+        let coeffs: Vec<F> = (0..size * batch_size as u64)
+            .map(|i| F::from_u32(i as u32 + 1).pow(7))
+            .collect();
+        let mut evals = vec![F::zero(); coeffs.len()];
+        ntt_any_size_host(
+            HostSlice::from_slice(&coeffs),
+            NTTDir::kForward,
+            &cfg,
+            HostSlice::from_mut_slice(&mut evals),
+        )
+        .unwrap();
+        for (row, row_evals) in coeffs
+            .chunks(size as usize)
+            .zip(evals.chunks(size as usize))
+        {
+            assert_eq!(row_evals, direct_ntt(row, root, coset_gen));
+        }
+
+        let mut interpolated = vec![F::zero(); coeffs.len()];
+        ntt_any_size_host(
+            HostSlice::from_slice(&evals),
+            NTTDir::kInverse,
+            &cfg,
+            HostSlice::from_mut_slice(&mut interpolated),
+        )
+        .unwrap();
+        assert_eq!(interpolated, coeffs);
+    }
+
+    // Sizes that don't divide `p - 1` have no domain
+    let missing_size = (2..)
+        .find(|size| !any_size_kind::<F>(*size).0)
+        .unwrap();
+    assert!(root_of_unity_any_size::<F>(missing_size).is_err());
+    let values = vec![F::one(); missing_size as usize];
+    let mut output = values.clone();
+    let cfg = NTTConfig::<F>::default();
+    let mut bad_ordering = cfg.clone();
+    bad_ordering.ordering = Ordering::kNR;
+    for (input, cfg) in [(&values[..], &cfg), (&values[..1], &bad_ordering)] {
+        let output = &mut output[..input.len()];
+        let err = ntt_any_size_host(
+            HostSlice::from_slice(input),
+            NTTDir::kForward,
+            cfg,
+            HostSlice::from_mut_slice(output),
+        )
+        .unwrap_err();
+        assert_eq!(err.get_icicle_error_code(), IcicleErrorCode::InvalidArgument);
+    }
+    assert!(ntt_any_size_host(
+        HostSlice::from_slice(&values),
+        NTTDir::kForward,
+        &cfg,
+        HostSlice::from_mut_slice(&mut output[1..]),
+    )
+    .is_err());
+}
+
+/// Needs the NTT domain of `F` to be initialized from [get_root_of_unity](crate::ntt::get_root_of_unity) for sizes up to 512.
+pub fn check_ntt_any_size<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: NTT<F, F> + GenerateRandom<F>,
+{
+    // Mixed radix sizes, `3 * 2^k` among them when 3 divides `p - 1`, and Bluestein's sizes
+    let batch_size = 2;
+    let sizes = (2..=64)
+        .filter(|size| any_size_kind::<F>(*size).0)
+        .chain([ntt_size_at_least::<F>(200).unwrap()]);
+    for size in sizes {
+        let size = size as usize;
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(batch_size)
+            .coset_gen(F::Config::generate_random(1)[0])
+            .build()
+            .unwrap();
+        let inputs = F::Config::generate_random(size * batch_size as usize);
+        for dir in [NTTDir::kForward, NTTDir::kInverse] {
+            let mut outputs = vec![F::zero(); inputs.len()];
+            ntt_any_size(
+                HostSlice::from_slice(&inputs),
+                dir,
+                &cfg,
+                HostSlice::from_mut_slice(&mut outputs),
+            )
+            .unwrap();
+            let mut host_outputs = vec![F::zero(); inputs.len()];
+            ntt_any_size_host(
+                HostSlice::from_slice(&inputs),
+                dir,
+                &cfg,
+                HostSlice::from_mut_slice(&mut host_outputs),
+            )
+            .unwrap();
+            assert_eq!(outputs, host_outputs);
+        }
+    }
+}
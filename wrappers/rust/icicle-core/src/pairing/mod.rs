@@ -0,0 +1,259 @@
+use crate::curve::{Affine, Curve};
+use crate::traits::{FieldArithmetic, FieldImpl};
+
+mod tower;
+pub use crate::g2::Fp2;
+pub use tower::{Fp12, Fp6, TowerConfig};
+
+#[cfg(feature = "arkworks")]
+#[doc(hidden)]
+pub mod tests;
+
+/// Family of the curve, which fixes the Miller loop length and the final exponentiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairingFamily {
+    /// Barreto-Naehrig: Miller loop over `6x + 2` followed by two Frobenius lines.
+    Bn,
+    /// Barreto-Lynn-Scott of embedding degree 12: Miller loop over `x`.
+    Bls12,
+}
+
+/// How the G2 points on the sextic twist map to the curve over [Fp12].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwistType {
+    /// Divisive twist `y² = x³ + b / ξ`: `(x, y)` maps to `(x w², y w³)`.
+    D,
+    /// Multiplicative twist `y² = x³ + b ξ`: `(x, y)` maps to `(x w⁻², y w⁻³)`.
+    M,
+}
+
+/// Optimal ate pairing `e: G1 × G2 → Fp12` computed on the host.
+///
+/// The Miller loop works on affine points and the final exponentiation follows arkworks, so pairings are equal to
+/// the ones of `ark_ec::pairing::Pairing` for the same curve.
+pub trait Pairing: TowerConfig {
+    type G1: Curve<BaseField = Self::Fp>;
+    type G2: Curve<BaseField = Fp2<Self::Fp>, ScalarField = <Self::G1 as Curve>::ScalarField>;
+
+    const FAMILY: PairingFamily;
+    const TWIST: TwistType;
+    /// Absolute value of the curve parameter `x`.
+    const X: u64;
+    const X_IS_NEGATIVE: bool;
+
+    #[cfg(feature = "arkworks")]
+    type ArkEquivalent: ark_ec::pairing::Pairing;
+
+    /// Product of the Miller loops of the pairs, with a single accumulator squared once per iteration. Pairs with a
+    /// point at infinity contribute one.
+    fn multi_miller_loop(pairs: &[PairingInput<Self>]) -> Fp12<Self> {
+        multi_miller_loop::<Self>(pairs)
+    }
+
+    /// Raises the output of a Miller loop to `(p^12 - 1) / r`, `None` when it is zero.
+    fn final_exponentiation(f: &Fp12<Self>) -> Option<Fp12<Self>> {
+        final_exponentiation::<Self>(f)
+    }
+
+    fn pairing(p: &Affine<Self::G1>, q: &Affine<Self::G2>) -> Fp12<Self> {
+        Self::multi_pairing(&[(*p, *q)])
+    }
+
+    /// `Π e(P_i, Q_i)`, sharing the Miller loop and the final exponentiation between the pairs.
+    fn multi_pairing(pairs: &[PairingInput<Self>]) -> Fp12<Self> {
+        // Miller loops of valid points are never zero
+        Self::final_exponentiation(&Self::multi_miller_loop(pairs)).unwrap()
+    }
+}
+
+/// A point of G1 and a point of G2 to pair.
+pub type PairingInput<P> = (Affine<<P as Pairing>::G1>, Affine<<P as Pairing>::G2>);
+
+// Affine point of the twist
+type TwistPoint<P> = (Fp2<<P as TowerConfig>::Fp>, Fp2<<P as TowerConfig>::Fp>);
+
+// Multiplies `f` by the line through `t` and `q` (the tangent when they are equal) evaluated at `p`, and moves `t` to
+// `t + q`. Vertical lines are left out: they lie in `Fp6`, which the final exponentiation maps to one.
+fn add_step<P: Pairing>(f: &mut Fp12<P>, t: &mut Option<TwistPoint<P>>, q: &TwistPoint<P>, p: &Affine<P::G1>) {
+    let Some((xt, yt)) = *t else {
+        *t = Some(*q);
+        return;
+    };
+    let (xq, yq) = *q;
+    let slope = if xt != xq {
+        (yq - yt)
+            * (xq - xt)
+                .inv()
+                .unwrap()
+    } else if yt == yq && yt != Fp2::zero() {
+        let x_squared = xt.sqr();
+        (x_squared + x_squared + x_squared)
+            * (yt + yt)
+                .inv()
+                .unwrap()
+    } else {
+        *t = None;
+        return;
+    };
+    let x = slope.sqr() - xt - xq;
+    *t = Some((x, slope * (xt - x) - yt));
+
+    // The line `y - yt - slope (x - xt)` through the images of the points on the curve over Fp12, times `w³` for the
+    // M-twist, which is a factor of `Fp4` erased by the final exponentiation as well
+    let zero = Fp2::zero();
+    let constant = slope * xt - yt;
+    let x_term = -slope.mul_by_fp(&p.x);
+    let y_term = Fp2::from_fp(p.y);
+    *f = *f
+        * match P::TWIST {
+            TwistType::D => Fp12::from_w_coefficients([y_term, x_term, zero, constant, zero, zero]),
+            TwistType::M => Fp12::from_w_coefficients([constant, zero, x_term, y_term, zero, zero]),
+        };
+}
+
+// `π(Q)`: the Frobenius endomorphism of the curve over Fp12 brought back to the twist
+fn twist_frobenius<P: Pairing>(q: &TwistPoint<P>) -> TwistPoint<P> {
+    let gamma = tower::frobenius_gamma::<P>();
+    let gamma = match P::TWIST {
+        TwistType::D => gamma,
+        TwistType::M => gamma
+            .inv()
+            .unwrap(),
+    };
+    let gamma_squared = gamma.sqr();
+    (q.0.conjugate() * gamma_squared, q.1.conjugate() * gamma_squared * gamma)
+}
+
+fn multi_miller_loop<P: Pairing>(pairs: &[PairingInput<P>]) -> Fp12<P> {
+    let pairs: Vec<_> = pairs
+        .iter()
+        .filter(|(p, q)| *p != Affine::zero() && *q != Affine::zero())
+        .map(|(p, q)| (*p, (q.x, q.y)))
+        .collect();
+    let loop_count = match P::FAMILY {
+        PairingFamily::Bn => 6 * P::X as u128 + 2,
+        PairingFamily::Bls12 => P::X as u128,
+    };
+    let mut ts: Vec<_> = pairs
+        .iter()
+        .map(|(_, q)| Some(*q))
+        .collect();
+
+    let mut f = Fp12::one();
+    for bit in (0..127 - loop_count.leading_zeros()).rev() {
+        f = f.square();
+        for ((p, q), t) in pairs
+            .iter()
+            .zip(ts.iter_mut())
+        {
+            if let Some(doubled) = *t {
+                add_step(&mut f, t, &doubled, p);
+            }
+            if (loop_count >> bit) & 1 == 1 {
+                add_step(&mut f, t, q, p);
+            }
+        }
+    }
+    if P::X_IS_NEGATIVE {
+        f = f.conjugate();
+    }
+
+    if P::FAMILY == PairingFamily::Bn {
+        for ((p, q), t) in pairs
+            .iter()
+            .zip(ts.iter_mut())
+        {
+            let q1 = twist_frobenius::<P>(q);
+            let (x2, y2) = twist_frobenius::<P>(&q1);
+            if P::X_IS_NEGATIVE {
+                *t = t.map(|(x, y)| (x, -y));
+            }
+            add_step(&mut f, t, &q1, p);
+            add_step(&mut f, t, &(x2, -y2), p);
+        }
+    }
+    f
+}
+
+fn final_exponentiation<P: Pairing>(f: &Fp12<P>) -> Option<Fp12<P>> {
+    // Easy part: f^((p^6 - 1)(p^2 + 1))
+    let r = f.conjugate() * f.inverse()?;
+    let r = r.frobenius_map(2) * r;
+
+    Some(match P::FAMILY {
+        PairingFamily::Bn => bn_hard_part(r),
+        PairingFamily::Bls12 => bls12_hard_part(r),
+    })
+}
+
+// Fuentes-Castañeda et al. "Faster hashing to G2": `r^(2z (6z² + 3z + 1) (p⁴ - p² + 1) / r)`
+fn bn_hard_part<P: Pairing>(r: Fp12<P>) -> Fp12<P> {
+    let exp_by_neg_x = |f: Fp12<P>| {
+        let f = f.pow(&[P::X]);
+        if P::X_IS_NEGATIVE {
+            f
+        } else {
+            f.conjugate()
+        }
+    };
+    let y0 = exp_by_neg_x(r);
+    let y1 = y0.square();
+    let y2 = y1.square();
+    let y3 = y2 * y1;
+    let y4 = exp_by_neg_x(y3);
+    let y5 = y4.square();
+    let y6 = exp_by_neg_x(y5);
+    let y3 = y3.conjugate();
+    let y6 = y6.conjugate();
+    let y7 = y6 * y4;
+    let y8 = y7 * y3;
+    let y9 = y8 * y1;
+    let y10 = y8 * y4;
+    let y11 = y10 * r;
+    let y12 = y9.frobenius_map(1);
+    let y13 = y12 * y11;
+    let y14 = y8.frobenius_map(2) * y13;
+    let y15 = (r.conjugate() * y9).frobenius_map(3);
+    y15 * y14
+}
+
+// Hayashida, Hayasaka and Teruya, eprint 2020/875
+fn bls12_hard_part<P: Pairing>(r: Fp12<P>) -> Fp12<P> {
+    let exp_by_x = |f: Fp12<P>| {
+        let f = f.pow(&[P::X]);
+        if P::X_IS_NEGATIVE {
+            f.conjugate()
+        } else {
+            f
+        }
+    };
+    let y0 = r.square();
+    let y1 = exp_by_x(r) * r.conjugate();
+    let y2 = exp_by_x(y1);
+    let y1 = y1.conjugate() * y2;
+    let y2 = exp_by_x(y1);
+    let y1 = y1.frobenius_map(1) * y2;
+    let r = r * y0;
+    let y0 = exp_by_x(y1);
+    let y2 = exp_by_x(y0);
+    let y0 = y1.frobenius_map(2);
+    let y1 = y1.conjugate() * y2 * y0;
+    r * y1
+}
+
+#[macro_export]
+macro_rules! impl_pairing_tests {
+    (
+      $pairing:ident
+    ) => {
+        #[test]
+        fn test_pairing() {
+            check_pairing::<$pairing>()
+        }
+
+        #[test]
+        fn test_pairing_tower() {
+            check_tower::<$pairing>()
+        }
+    };
+}
@@ -0,0 +1,106 @@
+use crate::curve::{Affine, Curve};
+use crate::g2::Fp2;
+use crate::pairing::{Fp12, Fp6, Pairing};
+use crate::traits::{ArkConvertible, FieldImpl};
+use ark_ec::models::CurveConfig as ArkCurveConfig;
+use ark_ec::pairing::Pairing as ArkPairing;
+use ark_ec::short_weierstrass::Affine as ArkAffine;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, Field as ArkField, PrimeField};
+use ark_std::{test_rng, UniformRand};
+
+type ArkG1<P> = ArkAffine<<<P as Pairing>::G1 as Curve>::ArkSWConfig>;
+type ArkG2<P> = ArkAffine<<<P as Pairing>::G2 as Curve>::ArkSWConfig>;
+
+fn random_points<C: Curve>(size: usize) -> Vec<ArkAffine<C::ArkSWConfig>> {
+    let rng = &mut test_rng();
+    (0..size)
+        .map(|_| {
+            (ArkAffine::<C::ArkSWConfig>::generator() * <C::ArkSWConfig as ArkCurveConfig>::ScalarField::rand(rng))
+                .into_affine()
+        })
+        .collect()
+}
+
+fn from_ark_target<P: Pairing>(f: <P::ArkEquivalent as ArkPairing>::TargetField) -> Fp12<P> {
+    let elements: Vec<_> = f
+        .to_base_prime_field_elements()
+        .map(|element| {
+            P::Fp::from_bytes_le(
+                &element
+                    .into_bigint()
+                    .to_bytes_le(),
+            )
+        })
+        .collect();
+    let fp2: Vec<_> = elements
+        .chunks(2)
+        .map(|c| Fp2::new(c[0], c[1]))
+        .collect();
+    Fp12::new(Fp6::new(fp2[0], fp2[1], fp2[2]), Fp6::new(fp2[3], fp2[4], fp2[5]))
+}
+
+pub fn check_pairing<P: Pairing>()
+where
+    P::ArkEquivalent: ArkPairing<G1Affine = ArkG1<P>, G2Affine = ArkG2<P>>,
+    <P::G1 as Curve>::BaseField:
+        ArkConvertible<ArkEquivalent = <<P::G1 as Curve>::ArkSWConfig as ArkCurveConfig>::BaseField>,
+    <P::G2 as Curve>::BaseField:
+        ArkConvertible<ArkEquivalent = <<P::G2 as Curve>::ArkSWConfig as ArkCurveConfig>::BaseField>,
+{
+    let ark_g1 = random_points::<P::G1>(2);
+    let ark_g2 = random_points::<P::G2>(2);
+    let g1: Vec<_> = ark_g1
+        .iter()
+        .map(|point| Affine::<P::G1>::from_ark(*point))
+        .collect();
+    let g2: Vec<_> = ark_g2
+        .iter()
+        .map(|point| Affine::<P::G2>::from_ark(*point))
+        .collect();
+
+    let pairing = P::pairing(&g1[0], &g2[0]);
+    let ark_pairing = P::ArkEquivalent::pairing(ark_g1[0], ark_g2[0]);
+    assert_eq!(pairing, from_ark_target::<P>(ark_pairing.0));
+    assert_ne!(pairing, Fp12::one());
+
+    let pairs = [(g1[0], g2[0]), (g1[1], g2[1])];
+    let ark_multi_pairing = P::ArkEquivalent::multi_pairing(&ark_g1, &ark_g2);
+    assert_eq!(P::multi_pairing(&pairs), from_ark_target::<P>(ark_multi_pairing.0));
+
+    // e(P, Q) e(-P, Q) = 1, and points at infinity don't contribute
+    let neg_g1 = Affine::<P::G1>::from_ark(-ark_g1[0]);
+    assert_eq!(P::multi_pairing(&[(g1[0], g2[0]), (neg_g1, g2[0])]), Fp12::one());
+    assert_eq!(
+        P::multi_pairing(&[(g1[0], g2[0]), (Affine::zero(), g2[1]), (g1[1], Affine::zero())]),
+        pairing
+    );
+}
+
+/// Arithmetic of the tower against the `Fp12` of arkworks.
+pub fn check_tower<P: Pairing>() {
+    let rng = &mut test_rng();
+    let ark_a = <P::ArkEquivalent as ArkPairing>::TargetField::rand(rng);
+    let ark_b = <P::ArkEquivalent as ArkPairing>::TargetField::rand(rng);
+    let (a, b) = (from_ark_target::<P>(ark_a), from_ark_target::<P>(ark_b));
+
+    assert_eq!(a * b, from_ark_target::<P>(ark_a * ark_b));
+    assert_eq!(a.square(), from_ark_target::<P>(ark_a.square()));
+    assert_eq!(
+        a.inverse()
+            .unwrap(),
+        from_ark_target::<P>(
+            ark_a
+                .inverse()
+                .unwrap()
+        )
+    );
+    assert_eq!(Fp12::<P>::zero().inverse(), None);
+    for power in 1..4 {
+        let mut ark_frobenius = ark_a;
+        ark_frobenius.frobenius_map_in_place(power);
+        assert_eq!(a.frobenius_map(power), from_ark_target::<P>(ark_frobenius));
+    }
+    assert_eq!(a.frobenius_map(6), a.conjugate());
+    assert_eq!(a.pow(&[P::X, 1]), from_ark_target::<P>(ark_a.pow([P::X, 1])));
+}
@@ -0,0 +1,182 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::field::utils::{modulus_minus_one_over, pow_limbs};
+use crate::g2::{Fp2, Fp2BaseField};
+use crate::traits::{FieldArithmetic, FieldImpl};
+
+/// Non-residues of the extension tower `Fp2 = Fp[u] / (u² - β)`, `Fp6 = Fp2[v] / (v³ - ξ)` and
+/// `Fp12 = Fp6[w] / (w² - v)` of a pairing-friendly curve, the same towers as arkworks. `β` is the one of the
+/// [Fp2] of G2 coordinates.
+pub trait TowerConfig: Debug + PartialEq + Copy + Clone {
+    type Fp: Fp2BaseField;
+
+    /// `ξ`, neither a square nor a cube in `Fp2`.
+    fn fp6_non_residue() -> Fp2<Self::Fp>;
+}
+
+/// `γ = ξ^((p - 1) / 6)`, so that `w^p = γ w`.
+pub(super) fn frobenius_gamma<P: TowerConfig>() -> Fp2<P::Fp> {
+    let (exponent, remainder) = modulus_minus_one_over::<P::Fp>(6);
+    debug_assert_eq!(remainder, 0);
+    pow_limbs(P::fp6_non_residue(), Fp2::one(), &exponent)
+}
+
+/// Element `c0 + c1 v + c2 v²` of the cubic extension of [Fp2].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fp6<P: TowerConfig> {
+    pub c0: Fp2<P::Fp>,
+    pub c1: Fp2<P::Fp>,
+    pub c2: Fp2<P::Fp>,
+}
+
+impl<P: TowerConfig> Fp6<P> {
+    pub fn new(c0: Fp2<P::Fp>, c1: Fp2<P::Fp>, c2: Fp2<P::Fp>) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(Fp2::zero(), Fp2::zero(), Fp2::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::new(Fp2::one(), Fp2::zero(), Fp2::zero())
+    }
+
+    /// Multiplication by `v`.
+    pub fn mul_by_non_residue(&self) -> Self {
+        Self::new(P::fp6_non_residue() * self.c2, self.c0, self.c1)
+    }
+
+    /// Returns `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let xi = P::fp6_non_residue();
+        let t0 = self.c0 * self.c0 - xi * self.c1 * self.c2;
+        let t1 = xi * self.c2 * self.c2 - self.c0 * self.c1;
+        let t2 = self.c1 * self.c1 - self.c0 * self.c2;
+        let norm = self.c0 * t0 + xi * (self.c2 * t1 + self.c1 * t2);
+        norm.inv()
+            .map(|norm_inv| Self::new(t0 * norm_inv, t1 * norm_inv, t2 * norm_inv))
+    }
+}
+
+impl<P: TowerConfig> Add for Fp6<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1, self.c2 + other.c2)
+    }
+}
+
+impl<P: TowerConfig> Sub for Fp6<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1, self.c2 - other.c2)
+    }
+}
+
+impl<P: TowerConfig> Mul for Fp6<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let xi = P::fp6_non_residue();
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (other.c0, other.c1, other.c2);
+        Self::new(
+            a0 * b0 + xi * (a1 * b2 + a2 * b1),
+            a0 * b1 + a1 * b0 + xi * (a2 * b2),
+            a0 * b2 + a1 * b1 + a2 * b0,
+        )
+    }
+}
+
+impl<P: TowerConfig> Neg for Fp6<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+/// Element `c0 + c1 w` of the quadratic extension of [Fp6], where pairings take their values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fp12<P: TowerConfig> {
+    pub c0: Fp6<P>,
+    pub c1: Fp6<P>,
+}
+
+impl<P: TowerConfig> Fp12<P> {
+    pub fn new(c0: Fp6<P>, c1: Fp6<P>) -> Self {
+        Self { c0, c1 }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(Fp6::zero(), Fp6::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::new(Fp6::one(), Fp6::zero())
+    }
+
+    /// Element `Σ g_i w^i` from its coefficients over [Fp2].
+    pub fn from_w_coefficients(g: [Fp2<P::Fp>; 6]) -> Self {
+        Self::new(Fp6::new(g[0], g[2], g[4]), Fp6::new(g[1], g[3], g[5]))
+    }
+
+    /// Coefficients `g_i` over [Fp2] of `Σ g_i w^i`.
+    pub fn w_coefficients(&self) -> [Fp2<P::Fp>; 6] {
+        let (c0, c1) = (&self.c0, &self.c1);
+        [c0.c0, c1.c0, c0.c1, c1.c1, c0.c2, c1.c2]
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// `c0 - c1 w`, the `p^6` power, which is also the inverse in the cyclotomic subgroup.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// Returns `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let norm = self.c0 * self.c0 - (self.c1 * self.c1).mul_by_non_residue();
+        norm.inverse()
+            .map(|norm_inv| Self::new(self.c0 * norm_inv, -(self.c1 * norm_inv)))
+    }
+
+    pub fn pow(&self, exp: &[u64]) -> Self {
+        pow_limbs(*self, Self::one(), exp)
+    }
+
+    /// The `p^power` Frobenius map: `Σ g_i w^i` goes to `Σ conj(g_i) γ^i w^i` for each power of `p`.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        let gamma = frobenius_gamma::<P>();
+        let mut gamma_powers = [Fp2::one(); 6];
+        for i in 1..6 {
+            gamma_powers[i] = gamma_powers[i - 1] * gamma;
+        }
+        let mut g = self.w_coefficients();
+        for _ in 0..power {
+            for (coefficient, gamma_power) in g
+                .iter_mut()
+                .zip(gamma_powers)
+            {
+                *coefficient = coefficient.conjugate() * gamma_power;
+            }
+        }
+        Self::from_w_coefficients(g)
+    }
+}
+
+impl<P: TowerConfig> Mul for Fp12<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let t0 = self.c0 * other.c0;
+        let t1 = self.c1 * other.c1;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - t0 - t1;
+        Self::new(t0 + t1.mul_by_non_residue(), c1)
+    }
+}
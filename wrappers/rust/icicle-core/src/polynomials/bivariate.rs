@@ -0,0 +1,235 @@
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    ntt::{ntt, ntt_inplace, NTTConfig, NTTDir, NTT},
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+/// Polynomial in `X` and `Y` with at most `x_size` coefficients in `X` and `y_size` in `Y`, kept on the host.
+///
+/// The coefficients are stored row by row: the coefficient of `X^i Y^j` is at `j * x_size + i`, so each row holds
+/// the univariate polynomial in `X` multiplying `Y^j`. Evaluations over the product of two roots of unity domains
+/// use the same layout, the evaluation at `(ω_x^i, ω_y^j)` being at `j * x_size + i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariatePolynomial<F: FieldImpl> {
+    coeffs: Vec<F>,
+    x_size: usize,
+    y_size: usize,
+}
+
+impl<F: FieldImpl> BivariatePolynomial<F> {
+    pub fn from_coeffs(coeffs: Vec<F>, x_size: usize, y_size: usize) -> IcicleResult<Self> {
+        if x_size == 0 || y_size == 0 || coeffs.len() != x_size * y_size {
+            return Err(IcicleError::invalid_argument(
+                "Bivariate polynomial needs x_size * y_size coefficients",
+            ));
+        }
+        Ok(Self { coeffs, x_size, y_size })
+    }
+
+    pub fn zero(x_size: usize, y_size: usize) -> Self {
+        Self {
+            coeffs: vec![F::zero(); x_size * y_size],
+            x_size,
+            y_size,
+        }
+    }
+
+    pub fn x_size(&self) -> usize {
+        self.x_size
+    }
+
+    pub fn y_size(&self) -> usize {
+        self.y_size
+    }
+
+    /// All the coefficients, in the row by row layout.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// The coefficient of `X^x_power Y^y_power`.
+    pub fn get_coeff(&self, x_power: usize, y_power: usize) -> F {
+        self.coeffs[y_power * self.x_size + x_power]
+    }
+
+    /// The coefficients of the polynomial in `X` multiplying `Y^y_power`.
+    pub fn row(&self, y_power: usize) -> &[F] {
+        &self.coeffs[y_power * self.x_size..(y_power + 1) * self.x_size]
+    }
+}
+
+// Horner evaluation of coefficients given lowest degree first
+fn eval_univariate<F: FieldArithmetic>(coeffs: impl DoubleEndedIterator<Item = F>, x: &F) -> F {
+    coeffs
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * *x + coeff)
+}
+
+// Quotient of a univariate polynomial by `X - a`, with the same number of coefficients, and the remainder
+fn divide_univariate<F: FieldArithmetic>(coeffs: &[F], a: &F) -> (Vec<F>, F) {
+    let mut quotient = vec![F::zero(); coeffs.len()];
+    let mut carry = F::zero();
+    for i in (1..coeffs.len()).rev() {
+        carry = coeffs[i] + carry * *a;
+        quotient[i - 1] = carry;
+    }
+    (quotient, coeffs[0] + carry * *a)
+}
+
+impl<F: FieldArithmetic> BivariatePolynomial<F> {
+    pub fn eval(&self, x: &F, y: &F) -> F {
+        eval_univariate(
+            self.eval_x(x)
+                .into_iter(),
+            y,
+        )
+    }
+
+    /// Partial evaluation at `X = x`: the coefficients of `P(x, Y)`.
+    pub fn eval_x(&self, x: &F) -> Vec<F> {
+        self.coeffs
+            .chunks(self.x_size)
+            .map(|row| {
+                eval_univariate(
+                    row.iter()
+                        .copied(),
+                    x,
+                )
+            })
+            .collect()
+    }
+
+    /// Partial evaluation at `Y = y`: the coefficients of `P(X, y)`.
+    pub fn eval_y(&self, y: &F) -> Vec<F> {
+        (0..self.x_size)
+            .map(|i| {
+                eval_univariate(
+                    self.coeffs
+                        .iter()
+                        .skip(i)
+                        .step_by(self.x_size)
+                        .copied(),
+                    y,
+                )
+            })
+            .collect()
+    }
+
+    /// Divides by `X - a`: returns `Q` and `R` with `P(X, Y) = (X - a) Q(X, Y) + R(Y)`, where `R(Y) = P(a, Y)`.
+    ///
+    /// The quotient keeps the sizes of the polynomial, its highest coefficients in `X` being zero.
+    pub fn divide_by_x(&self, a: &F) -> (Self, Vec<F>) {
+        let mut quotient = Vec::with_capacity(
+            self.coeffs
+                .len(),
+        );
+        let mut remainder = Vec::with_capacity(self.y_size);
+        for row in self
+            .coeffs
+            .chunks(self.x_size)
+        {
+            let (row_quotient, row_remainder) = divide_univariate(row, a);
+            quotient.extend(row_quotient);
+            remainder.push(row_remainder);
+        }
+        (
+            Self {
+                coeffs: quotient,
+                x_size: self.x_size,
+                y_size: self.y_size,
+            },
+            remainder,
+        )
+    }
+
+    /// Divides by `Y - b`: returns `Q` and `R` with `P(X, Y) = (Y - b) Q(X, Y) + R(X)`, where `R(X) = P(X, b)`.
+    ///
+    /// The quotient keeps the sizes of the polynomial, its highest row being zero.
+    pub fn divide_by_y(&self, b: &F) -> (Self, Vec<F>) {
+        // Same as dividing each column by `X - b`, done on whole rows
+        let mut quotient = vec![
+            F::zero();
+            self.coeffs
+                .len()
+        ];
+        let mut carry = vec![F::zero(); self.x_size];
+        for j in (1..self.y_size).rev() {
+            for (i, carried) in carry
+                .iter_mut()
+                .enumerate()
+            {
+                *carried = self.get_coeff(i, j) + *carried * *b;
+            }
+            quotient[(j - 1) * self.x_size..j * self.x_size].copy_from_slice(&carry);
+        }
+        let remainder = self
+            .row(0)
+            .iter()
+            .zip(carry)
+            .map(|(coeff, carried)| *coeff + carried * *b)
+            .collect();
+        (
+            Self {
+                coeffs: quotient,
+                x_size: self.x_size,
+                y_size: self.y_size,
+            },
+            remainder,
+        )
+    }
+}
+
+impl<F: FieldImpl> BivariatePolynomial<F>
+where
+    <F as FieldImpl>::Config: NTT<F, F>,
+{
+    fn check_domain(x_size: usize, y_size: usize, len: usize) -> IcicleResult<()> {
+        if !x_size.is_power_of_two() || !y_size.is_power_of_two() || len != x_size * y_size {
+            return Err(IcicleError::invalid_argument(
+                "Bivariate NTTs need power of two sizes and x_size * y_size evaluations",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Interpolates the evaluations over the product of the roots of unity of orders `x_size` and `y_size`, laid out
+    /// like the coefficients. The NTT domain must be initialized for the larger of the two sizes.
+    pub fn from_rou_evals(
+        evals: &(impl HostOrDeviceSlice<F> + ?Sized),
+        x_size: usize,
+        y_size: usize,
+    ) -> IcicleResult<Self> {
+        Self::check_domain(x_size, y_size, evals.len())?;
+        let mut coeffs = vec![F::zero(); evals.len()];
+        // The columns are interleaved: element `j` of column `i` is at `j * x_size + i`
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(x_size as i32)
+            .columns_batch(true)
+            .build()?;
+        ntt(evals, NTTDir::kInverse, &cfg, HostSlice::from_mut_slice(&mut coeffs))?;
+
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(y_size as i32)
+            .build()?;
+        ntt_inplace(HostSlice::from_mut_slice(&mut coeffs), NTTDir::kInverse, &cfg)?;
+        Self::from_coeffs(coeffs, x_size, y_size)
+    }
+
+    /// Evaluates the polynomial over the product of the roots of unity of orders `x_size` and `y_size`, with one
+    /// batched NTT over the rows and one over the columns. The NTT domain must be initialized for the larger size.
+    pub fn eval_on_rou_domain(&self, evals: &mut (impl HostOrDeviceSlice<F> + ?Sized)) -> IcicleResult<()> {
+        Self::check_domain(self.x_size, self.y_size, evals.len())?;
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(self.y_size as i32)
+            .build()?;
+        ntt(HostSlice::from_slice(&self.coeffs), NTTDir::kForward, &cfg, evals)?;
+
+        let cfg = NTTConfig::<F>::builder()
+            .batch_size(self.x_size as i32)
+            .columns_batch(true)
+            .build()?;
+        ntt_inplace(evals, NTTDir::kForward, &cfg)
+    }
+}
@@ -1,6 +1,12 @@
 use crate::traits::{FieldConfig, FieldImpl};
 use icicle_cuda_runtime::memory::HostOrDeviceSlice;
 
+pub mod bivariate;
+#[doc(hidden)]
+pub mod tests;
+
+pub use bivariate::BivariatePolynomial;
+
 pub trait UnivariatePolynomial
 where
     Self::Field: FieldImpl,
@@ -10,6 +16,9 @@ where
     type FieldConfig;
 
     fn from_coeffs<S: HostOrDeviceSlice<Self::Field> + ?Sized>(coeffs: &S, size: usize) -> Self;
+    /// Interpolates evaluations on the power of two domain of `size` roots of unity in the polynomial backend. Other
+    /// domains can't go through the backend, whose NTTs are radix 2 only, see `from_rou_evals_any_size` of the field
+    /// crates.
     fn from_rou_evals<S: HostOrDeviceSlice<Self::Field> + ?Sized>(evals: &S, size: usize) -> Self;
     fn divide(&self, denominator: &Self) -> (Self, Self)
     where
@@ -27,6 +36,9 @@ where
         domain: &D,
         evals: &mut E,
     );
+    /// Evaluates the polynomial on the `2^domain_log_size` roots of unity in the polynomial backend. The domain is a
+    /// power of two by its signature, on which mixed radix gives the same radix 2 NTT, so it isn't routed through
+    /// [ntt_any_size](crate::ntt::ntt_any_size); see `eval_on_rou_domain_any_size` of the field crates for other sizes.
     fn eval_on_rou_domain<E: HostOrDeviceSlice<Self::Field> + ?Sized>(&self, domain_log_size: u64, evals: &mut E);
     fn get_nof_coeffs(&self) -> u64;
     fn get_coeff(&self, idx: u64) -> Self::Field;
@@ -154,6 +166,116 @@ macro_rules! impl_univariate_polynomial_api {
                     DeviceSlice::from_mut_slice(s)
                 }
             }
+
+            /// Product of the polynomials through mixed radix NTTs of the smallest size `2^k 3^a 5^b` dividing
+            /// `p - 1` that holds it, given by [ntt_size_at_least](icicle_core::ntt::ntt_size_at_least), instead of the
+            /// next power of two. The NTT domain must be initialized for the `2^k` of that size.
+            ///
+            /// Unlike `*`, which multiplies in the polynomial backend without leaving device memory, this copies the
+            /// coefficients through the host and reports a missing NTT domain as an error, which `Mul` can't return.
+            pub fn mul_any_size(&self, rhs: &Self) -> icicle_core::error::IcicleResult<Self> {
+                use icicle_core::ntt::{ntt_any_size, ntt_size_at_least, NTTConfig, NTTDir};
+                use icicle_cuda_runtime::memory::HostSlice;
+
+                let (lhs_degree, rhs_degree) = (self.degree(), rhs.degree());
+                if lhs_degree < 0 || rhs_degree < 0 {
+                    return Ok(Self::from_coeffs(HostSlice::from_slice(&[$field::zero()]), 1));
+                }
+                let len = (lhs_degree + rhs_degree + 1) as usize;
+                let size = ntt_size_at_least::<$field>(len as u64)? as usize;
+                let mut coeffs = vec![$field::zero(); 2 * size];
+                self.copy_coeffs(
+                    0,
+                    HostSlice::from_mut_slice(&mut coeffs[..lhs_degree as usize + 1]),
+                );
+                rhs.copy_coeffs(
+                    0,
+                    HostSlice::from_mut_slice(&mut coeffs[size..size + rhs_degree as usize + 1]),
+                );
+
+                let cfg = NTTConfig::<$field>::builder()
+                    .batch_size(2)
+                    .build()?;
+                let mut evals = vec![$field::zero(); 2 * size];
+                ntt_any_size(
+                    HostSlice::from_slice(&coeffs),
+                    NTTDir::kForward,
+                    &cfg,
+                    HostSlice::from_mut_slice(&mut evals),
+                )?;
+                let (lhs_evals, rhs_evals) = evals.split_at(size);
+                let product_evals: Vec<$field> = lhs_evals
+                    .iter()
+                    .zip(rhs_evals)
+                    .map(|(lhs, rhs)| *lhs * *rhs)
+                    .collect();
+                ntt_any_size(
+                    HostSlice::from_slice(&product_evals),
+                    NTTDir::kInverse,
+                    &NTTConfig::default(),
+                    HostSlice::from_mut_slice(&mut coeffs[..size]),
+                )?;
+                Ok(Self::from_coeffs(HostSlice::from_slice(&coeffs[..len]), len))
+            }
+
+            /// Evaluates the polynomial at the `domain_size` powers of
+            /// [root_of_unity_any_size](icicle_core::ntt::root_of_unity_any_size), for any `domain_size` dividing
+            /// `p - 1`, writing them to the first `domain_size` evaluations. Domains of size `2^k 3^a 5^b` run with
+            /// mixed radix and need the NTT domain initialized for `2^k`, other sizes run with Bluestein's algorithm
+            /// and need it for `(2 * domain_size - 1).next_power_of_two()`, see
+            /// [ntt_any_size](icicle_core::ntt::ntt_any_size).
+            pub fn eval_on_rou_domain_any_size(
+                &self,
+                domain_size: u64,
+                evals: &mut icicle_cuda_runtime::memory::HostSlice<$field>,
+            ) -> icicle_core::error::IcicleResult<()> {
+                use icicle_core::ntt::{ntt_any_size, NTTConfig, NTTDir};
+                use icicle_cuda_runtime::memory::HostSlice;
+
+                let size = domain_size as usize;
+                if size == 0 || evals.len() < size {
+                    return Err(icicle_core::error::IcicleError::invalid_argument(
+                        "eval_on_rou_domain_any_size(): eval size must not be smaller than domain",
+                    ));
+                }
+                // Coefficients wrap around modulo `X^size - 1`, which vanishes on the domain
+                let mut coeffs = vec![$field::zero(); (self.degree() + 1) as usize];
+                if !coeffs.is_empty() {
+                    self.copy_coeffs(0, HostSlice::from_mut_slice(&mut coeffs));
+                }
+                let mut folded = vec![$field::zero(); size];
+                for (i, coeff) in coeffs
+                    .into_iter()
+                    .enumerate()
+                {
+                    folded[i % size] = folded[i % size] + coeff;
+                }
+                ntt_any_size(
+                    HostSlice::from_slice(&folded),
+                    NTTDir::kForward,
+                    &NTTConfig::default(),
+                    &mut evals[..size],
+                )
+            }
+
+            /// Interpolates the evaluations at the powers of
+            /// [root_of_unity_any_size](icicle_core::ntt::root_of_unity_any_size) of order `evals.len()`, the
+            /// inverse of [eval_on_rou_domain_any_size](Self::eval_on_rou_domain_any_size).
+            pub fn from_rou_evals_any_size(
+                evals: &icicle_cuda_runtime::memory::HostSlice<$field>,
+            ) -> icicle_core::error::IcicleResult<Self> {
+                use icicle_core::ntt::{ntt_any_size, NTTConfig, NTTDir};
+                use icicle_cuda_runtime::memory::HostSlice;
+
+                let mut coeffs = vec![$field::zero(); evals.len()];
+                ntt_any_size(
+                    evals,
+                    NTTDir::kInverse,
+                    &NTTConfig::default(),
+                    HostSlice::from_mut_slice(&mut coeffs),
+                )?;
+                Ok(Self::from_coeffs(HostSlice::from_slice(&coeffs), coeffs.len()))
+            }
         }
 
         impl UnivariatePolynomial for DensePolynomial {
@@ -353,6 +475,10 @@ macro_rules! impl_univariate_polynomial_api {
         impl Mul for &DensePolynomial {
             type Output = DensePolynomial;
 
+            /// Multiplies in the polynomial backend with radix 2 NTTs of the next power of two size, on the device
+            /// coefficients. It isn't routed through [ntt_size_at_least](icicle_core::ntt::ntt_size_at_least) because
+            /// the backend only evaluates on power of two domains and `Mul` can't fail; use
+            /// [mul_any_size](DensePolynomial::mul_any_size) for the smaller mixed radix domain.
             fn mul(self: Self, rhs: Self) -> Self::Output {
                 unsafe {
                     DensePolynomial {
@@ -805,7 +931,47 @@ macro_rules! impl_polynomial_tests {
             assert_eq!(expected_odd, oddx);
         }
 
-        use icicle_core::ntt::{ntt, ntt_inplace, NTTConfig, NTTDir, Ordering};
+        #[test]
+        #[ignore]
+        fn test_poly_any_size() {
+            setup();
+
+            // product of 150 coefficients, over a mixed radix domain smaller than 256
+            let f = randomize_poly(100);
+            let g = randomize_poly(51);
+            assert!(ntt_size_at_least::<$field>(150).unwrap() < 256);
+            let fg = f
+                .mul_any_size(&g)
+                .unwrap();
+            assert_eq!(fg.degree(), f.degree() + g.degree());
+            let x = rand();
+            assert_eq!(fg.eval(&x), mul(&f.eval(&x), &g.eval(&x)));
+            let diff = &fg - &(&f * &g);
+            assert_eq!(diff.degree(), -1);
+
+            // domains smaller and larger than the polynomial
+            for min_size in [50, 300] {
+                let domain_size = ntt_size_at_least::<$field>(min_size).unwrap();
+                let root = root_of_unity_any_size::<$field>(domain_size).unwrap();
+                let mut evals = vec![$field::zero(); domain_size as usize];
+                f.eval_on_rou_domain_any_size(domain_size, HostSlice::from_mut_slice(&mut evals))
+                    .unwrap();
+                let mut x = $field::one();
+                for eval in evals.iter() {
+                    assert_eq!(*eval, f.eval(&x));
+                    x = mul(&x, &root);
+                }
+                if domain_size > 100 {
+                    let interpolated = Poly::from_rou_evals_any_size(HostSlice::from_slice(&evals)).unwrap();
+                    let diff = &f - &interpolated;
+                    assert_eq!(diff.degree(), -1);
+                }
+            }
+        }
+
+        use icicle_core::ntt::{
+            ntt, ntt_inplace, ntt_size_at_least, root_of_unity_any_size, NTTConfig, NTTDir, Ordering,
+        };
 
         #[test]
         #[ignore]
@@ -831,7 +997,7 @@ macro_rules! impl_polynomial_tests {
             assert_eq!(coeffs_copied_from_slice, coeffs);
 
             // or can use the memory directly
-            let mut config: NTTConfig<'_, $field> = NTTConfig::default();
+            let config: NTTConfig<'_, $field> = NTTConfig::default();
             let mut ntt_result = vec![$field::zero(); coeffs_slice_dev.len()];
             ntt(
                 coeffs_slice_dev,
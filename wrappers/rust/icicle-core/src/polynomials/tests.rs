@@ -0,0 +1,103 @@
+use icicle_cuda_runtime::memory::HostSlice;
+
+use crate::{
+    ntt::{get_root_of_unity, NTT},
+    polynomials::BivariatePolynomial,
+    traits::{FieldArithmetic, GenerateRandom},
+};
+
+fn random_bivariate<F: FieldArithmetic>(x_size: usize, y_size: usize) -> BivariatePolynomial<F>
+where
+    F::Config: GenerateRandom<F>,
+{
+    BivariatePolynomial::from_coeffs(F::Config::generate_random(x_size * y_size), x_size, y_size).unwrap()
+}
+
+pub fn check_bivariate_polynomial<F: FieldArithmetic>() {
+    assert!(BivariatePolynomial::from_coeffs(vec![F::one(); 5], 2, 2).is_err());
+
+    // 3 + X + 2XY + X^2 Y + 4XY^2
+    let coeffs = [3, 1, 0, 0, 2, 1, 0, 4, 0]
+        .map(F::from_u32)
+        .to_vec();
+    let p = BivariatePolynomial::from_coeffs(coeffs, 3, 3).unwrap();
+    assert_eq!(p.get_coeff(2, 1), F::from_u32(1));
+    assert_eq!(p.row(2), &[0, 4, 0].map(F::from_u32));
+    assert_eq!(p.eval(&F::from_u32(2), &F::from_u32(3)), F::from_u32(101));
+    assert_eq!(p.eval_x(&F::from_u32(2)), [5, 8, 8].map(F::from_u32));
+    assert_eq!(p.eval_y(&F::from_u32(3)), [3, 43, 3].map(F::from_u32));
+
+    let (x_size, y_size) = (8, 4);
+    let coeffs = (0..x_size * y_size)
+        .map(|i| F::from_u32(i as u32 + 1).pow(7))
+        .collect();
+    let p = BivariatePolynomial::from_coeffs(coeffs, x_size, y_size).unwrap();
+    let (a, b, x, y) = (
+        F::from_u32(5)
+            .inv()
+            .unwrap(),
+        F::from_u32(11).pow(9),
+        -F::from_u32(3),
+        F::from_u32(7)
+            .inv()
+            .unwrap(),
+    );
+    let value = p.eval(&x, &y);
+    assert_eq!(
+        value,
+        p.eval_y(&y)
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, coeff| acc * x + *coeff)
+    );
+
+    let (quotient, remainder) = p.divide_by_x(&a);
+    assert_eq!(remainder, p.eval_x(&a));
+    assert!((0..y_size).all(|j| quotient.get_coeff(x_size - 1, j) == F::zero()));
+    let remainder_at_y = remainder
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * y + *coeff);
+    assert_eq!(value, (x - a) * quotient.eval(&x, &y) + remainder_at_y);
+
+    let (quotient, remainder) = p.divide_by_y(&b);
+    assert_eq!(remainder, p.eval_y(&b));
+    assert!(quotient
+        .row(y_size - 1)
+        .iter()
+        .all(|coeff| *coeff == F::zero()));
+    let remainder_at_x = remainder
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + *coeff);
+    assert_eq!(value, (y - b) * quotient.eval(&x, &y) + remainder_at_x);
+}
+
+/// Needs the NTT domain of `F` to be initialized for sizes up to 16.
+pub fn check_bivariate_rou_domain<F: FieldArithmetic>()
+where
+    F::Config: GenerateRandom<F> + NTT<F, F>,
+{
+    let (x_size, y_size) = (16, 4);
+    let p = random_bivariate::<F>(x_size, y_size);
+    let mut evals = vec![F::zero(); x_size * y_size];
+    p.eval_on_rou_domain(HostSlice::from_mut_slice(&mut evals))
+        .unwrap();
+
+    let (x_root, y_root) = (
+        get_root_of_unity::<F>(x_size as u64),
+        get_root_of_unity::<F>(y_size as u64),
+    );
+    for j in 0..y_size {
+        for i in 0..x_size {
+            assert_eq!(
+                evals[j * x_size + i],
+                p.eval(&x_root.pow(i as u64), &y_root.pow(j as u64))
+            );
+        }
+    }
+
+    let interpolated = BivariatePolynomial::from_rou_evals(HostSlice::from_slice(&evals), x_size, y_size).unwrap();
+    assert_eq!(interpolated, p);
+    assert!(BivariatePolynomial::from_rou_evals(HostSlice::from_slice(&evals[1..]), x_size, y_size).is_err());
+}
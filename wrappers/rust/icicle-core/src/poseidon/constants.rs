@@ -0,0 +1,189 @@
+use crate::{
+    error::{IcicleError, IcicleResult},
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+use super::{
+    grain::{round_numbers, GrainLfsr, Modulus},
+    matrix::{self, Matrix},
+};
+
+// The constants behind `Poseidon::load` were generated with the S-box bits of the Grain seed set to 1
+const SBOX_SEED: u32 = 1;
+
+/// Constants of the optimized Poseidon permutation, in the layout taken by [Poseidon::new](super::Poseidon::new).
+#[derive(Debug, Clone)]
+pub struct PoseidonConstants<F: FieldImpl> {
+    pub arity: usize,
+    pub alpha: u32,
+    pub full_rounds_half: u32,
+    pub partial_rounds: u32,
+    pub round_constants: Vec<F>,
+    pub mds_matrix: Vec<F>,
+    pub non_sparse_matrix: Vec<F>,
+    pub sparse_matrices: Vec<F>,
+    /// Defaults to `2^arity - 1`, the Merkle tree domain tag used by `Poseidon::load`.
+    pub domain_tag: F,
+}
+
+impl<F: FieldArithmetic> PoseidonConstants<F> {
+    /// Derives the constants of the `x^alpha` permutation over `arity + 1` elements at `security_level` bits.
+    ///
+    /// The number of rounds is the cheapest one found by the reference parameter script, security margin included.
+    /// For the curves and arities supported by `Poseidon::load` this reproduces the loaded constants.
+    pub fn generate(arity: usize, alpha: u32, security_level: u32) -> IcicleResult<Self> {
+        let modulus = Modulus::of::<F>();
+        modulus.check_alpha(alpha)?;
+        let (full_rounds, partial_rounds) = round_numbers(&modulus, arity + 1, alpha, security_level);
+        Self::generate_with_rounds(arity, alpha, (full_rounds / 2) as u32, partial_rounds as u32)
+    }
+
+    /// Same as [generate](Self::generate) with a given number of rounds.
+    ///
+    /// The round constants are drawn from the Grain LFSR and the MDS matrix is the Cauchy matrix
+    /// `1 / (i + j + width)`. Should that matrix admit an infinitely long subspace trail, Cauchy matrices are sampled
+    /// from the LFSR like in the reference until a secure one comes up. The constants are then folded into the layout
+    /// of the optimized kernels, with all partial rounds but one S-box input moved to the full rounds and the MDS
+    /// matrix of the partial rounds factorized into sparse matrices.
+    pub fn generate_with_rounds(
+        arity: usize,
+        alpha: u32,
+        full_rounds_half: u32,
+        partial_rounds: u32,
+    ) -> IcicleResult<Self> {
+        let (round_constants, mds) =
+            reference_constants::<F>(arity, alpha, full_rounds_half as usize, partial_rounds as usize)?;
+        let width = arity + 1;
+        let (full_rounds_half, partial_rounds) = (full_rounds_half as usize, partial_rounds as usize);
+
+        // The kernels multiply the state as a row vector
+        let mds = matrix::transpose(&mds);
+        let mds_inv = matrix::inverse(&mds).ok_or(singular_matrix())?;
+        let round = |r: usize| &round_constants[r * width..(r + 1) * width];
+
+        // Constants added after the S-boxes of a full round are the ones of the next round moved across the MDS
+        let mut optimized = round(0).to_vec();
+        for r in 1..full_rounds_half {
+            optimized.extend(matrix::vec_mul(round(r), &mds_inv));
+        }
+        // Going backwards through the partial rounds only the constant of the S-box input has to stay in place
+        let mut partial = Vec::with_capacity(partial_rounds);
+        let mut acc = round(full_rounds_half + partial_rounds).to_vec();
+        for r in (full_rounds_half..full_rounds_half + partial_rounds).rev() {
+            let mut moved = matrix::vec_mul(&acc, &mds_inv);
+            partial.push(moved[0]);
+            moved[0] = F::zero();
+            acc = round(r)
+                .iter()
+                .zip(moved)
+                .map(|(a, b)| *a + b)
+                .collect();
+        }
+        optimized.extend(matrix::vec_mul(&acc, &mds_inv));
+        optimized.extend(
+            partial
+                .iter()
+                .rev(),
+        );
+        for r in full_rounds_half + partial_rounds + 1..2 * full_rounds_half + partial_rounds {
+            optimized.extend(matrix::vec_mul(round(r), &mds_inv));
+        }
+
+        // mds = m' * m'' where m' only mixes the elements that skip the S-box, so it can move to the previous round
+        let mut current = mds.clone();
+        let mut sparse = Vec::with_capacity(partial_rounds);
+        for _ in 0..partial_rounds {
+            let mut m_prime = matrix::identity(width);
+            for i in 1..width {
+                m_prime[i][1..].copy_from_slice(&current[i][1..]);
+            }
+            let m_prime_inv = matrix::inverse(&m_prime).ok_or(singular_matrix())?;
+            sparse.push(matrix::mul(&m_prime_inv, &current));
+            current = matrix::mul(&mds, &m_prime);
+        }
+        // Each sparse matrix is stored as its first column followed by the rest of its first row
+        let sparse_matrices = sparse
+            .iter()
+            .rev()
+            .flat_map(|m| {
+                m.iter()
+                    .map(|row| row[0])
+                    .chain(
+                        m[0][1..]
+                            .iter()
+                            .copied(),
+                    )
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Ok(Self {
+            arity,
+            alpha,
+            full_rounds_half: full_rounds_half as u32,
+            partial_rounds: partial_rounds as u32,
+            round_constants: optimized,
+            mds_matrix: mds.concat(),
+            non_sparse_matrix: current.concat(),
+            sparse_matrices,
+            domain_tag: F::from_u32(2).pow(arity as u64) - F::one(),
+        })
+    }
+}
+
+fn singular_matrix() -> IcicleError {
+    IcicleError::invalid_argument("Poseidon MDS matrix is not invertible")
+}
+
+/// Round constants of every round and the MDS matrix (acting on column vectors) of the unoptimized permutation.
+pub(crate) fn reference_constants<F: FieldArithmetic>(
+    arity: usize,
+    alpha: u32,
+    full_rounds_half: usize,
+    partial_rounds: usize,
+) -> IcicleResult<(Vec<F>, Matrix<F>)> {
+    if arity == 0 || full_rounds_half == 0 {
+        return Err(IcicleError::invalid_argument(
+            "Poseidon needs a positive arity and at least one full round in each half",
+        ));
+    }
+    let modulus = Modulus::of::<F>();
+    modulus.check_alpha(alpha)?;
+
+    let width = arity + 1;
+    let mut lfsr = GrainLfsr::new(SBOX_SEED, modulus.bits(), width, 2 * full_rounds_half, partial_rounds)?;
+    let round_constants = (0..width * (2 * full_rounds_half + partial_rounds))
+        .map(|_| lfsr.next_field_element(&modulus))
+        .collect();
+
+    let cauchy = |xs: &[F], ys: &[F]| -> Option<Matrix<F>> {
+        xs.iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| (*x + *y).inv())
+                    .collect()
+            })
+            .collect()
+    };
+    let indices: Vec<F> = (0..2 * width as u32)
+        .map(F::from_u32)
+        .collect();
+    if let Some(mds) = cauchy(&indices[..width], &indices[width..]).filter(matrix::is_secure) {
+        return Ok((round_constants, mds));
+    }
+    loop {
+        let points: Vec<F> = (0..2 * width)
+            .map(|_| lfsr.next_field_element_reduced(&modulus))
+            .collect();
+        let distinct = points
+            .iter()
+            .enumerate()
+            .all(|(i, x)| !points[..i].contains(x));
+        if !distinct {
+            continue;
+        }
+        if let Some(mds) = cauchy(&points[..width], &points[width..]).filter(matrix::is_secure) {
+            return Ok((round_constants, mds));
+        }
+    }
+}
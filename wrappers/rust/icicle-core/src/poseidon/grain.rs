@@ -0,0 +1,248 @@
+use crate::{
+    error::{IcicleError, IcicleResult},
+    traits::FieldArithmetic,
+};
+
+/// The field modulus as a plain integer, used to sample field elements from random bits and to pick round numbers.
+pub(crate) struct Modulus {
+    bytes: Vec<u8>,
+    bits: u32,
+}
+
+impl Modulus {
+    pub(crate) fn of<F: FieldArithmetic>() -> Self {
+        let bytes = F::modulus_bytes_le();
+        let bits = bytes
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |i| 8 * i as u32 + 8 - bytes[i].leading_zeros());
+        Self { bytes, bits }
+    }
+
+    pub(crate) fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Little-endian bytes of the modulus, the exponent of the Frobenius map.
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn log2(&self) -> f64 {
+        self.bytes
+            .iter()
+            .rev()
+            .fold(0f64, |acc, byte| acc * 256.0 + *byte as f64)
+            .log2()
+    }
+
+    fn rem(&self, divisor: u32) -> u32 {
+        self.bytes
+            .iter()
+            .rev()
+            .fold(0u64, |acc, byte| ((acc << 8) + *byte as u64) % divisor as u64) as u32
+    }
+
+    // Whether the little-endian integer is smaller than the modulus
+    fn is_reduced(&self, value: &[u8]) -> bool {
+        for (a, b) in value
+            .iter()
+            .zip(&self.bytes)
+            .rev()
+        {
+            if a != b {
+                return a < b;
+            }
+        }
+        false
+    }
+
+    fn reduce(&self, value: &mut [u8]) {
+        if self.is_reduced(value) {
+            return;
+        }
+        let mut borrow = 0i16;
+        for (a, b) in value
+            .iter_mut()
+            .zip(&self.bytes)
+        {
+            let diff = *a as i16 - *b as i16 - borrow;
+            *a = diff.rem_euclid(256) as u8;
+            borrow = (diff < 0) as i16;
+        }
+    }
+
+    /// The S-box `x^alpha` is only a permutation if `alpha` is coprime to `p - 1`.
+    pub(crate) fn check_alpha(&self, alpha: u32) -> IcicleResult<()> {
+        let gcd = |mut a: u32, mut b: u32| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        if alpha < 3 || gcd(alpha, (self.rem(alpha) + alpha - 1) % alpha) != 1 {
+            return Err(IcicleError::invalid_argument(
+                "alpha must be at least 3 and coprime to p - 1",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Grain LFSR in self-shrinking mode, the source of randomness of the Poseidon reference implementation.
+pub(crate) struct GrainLfsr {
+    // Bit i of the register is the i-th bit of the sequence
+    state: u128,
+}
+
+impl GrainLfsr {
+    /// Seeds the register with the 80 bits describing the instance and discards the first 160 output bits.
+    pub(crate) fn new(
+        sbox: u32,
+        field_bits: u32,
+        width: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> IcicleResult<Self> {
+        if width >= 1 << 12 || full_rounds >= 1 << 10 || partial_rounds >= 1 << 10 {
+            return Err(IcicleError::invalid_argument(
+                "The width or the number of rounds doesn't fit the Grain LFSR seed",
+            ));
+        }
+
+        // Prime field, S-box, field size, width, full rounds, partial rounds and 30 set bits, most significant bit first
+        let seed = [
+            (1, 2),
+            (sbox as u128, 4),
+            (field_bits as u128, 12),
+            (width as u128, 12),
+            (full_rounds as u128, 10),
+            (partial_rounds as u128, 10),
+            ((1 << 30) - 1, 30),
+        ];
+        let mut state = 0u128;
+        let mut position = 0;
+        for (value, len) in seed {
+            for i in (0..len).rev() {
+                state |= ((value >> i) & 1) << position;
+                position += 1;
+            }
+        }
+
+        let mut lfsr = Self { state };
+        for _ in 0..160 {
+            lfsr.update();
+        }
+        Ok(lfsr)
+    }
+
+    // b_{i+80} = b_{i+62} ^ b_{i+51} ^ b_{i+38} ^ b_{i+23} ^ b_{i+13} ^ b_i
+    fn update(&mut self) -> bool {
+        let s = self.state;
+        let bit = ((s >> 62) ^ (s >> 51) ^ (s >> 38) ^ (s >> 23) ^ (s >> 13) ^ s) & 1;
+        self.state = (s >> 1) | (bit << 79);
+        bit == 1
+    }
+
+    // Bits are evaluated in pairs, the second one is output only if the first one is set
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.update();
+            let bit = self.update();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    // The reference reads the bits as an integer starting from the most significant one
+    fn next_integer(&mut self, modulus: &Modulus) -> Vec<u8> {
+        let mut value = vec![
+            0u8;
+            modulus
+                .bytes
+                .len()
+        ];
+        for i in (0..modulus.bits as usize).rev() {
+            if self.next_bit() {
+                value[i / 8] |= 1 << (i % 8);
+            }
+        }
+        value
+    }
+
+    /// Samples an element the way round constants are sampled: integers outside the field are skipped.
+    pub(crate) fn next_field_element<F: FieldArithmetic>(&mut self, modulus: &Modulus) -> F {
+        loop {
+            let value = self.next_integer(modulus);
+            if modulus.is_reduced(&value) {
+                return F::from_bytes_le(&value);
+            }
+        }
+    }
+
+    /// Samples an element the way matrix entries are sampled: the integer is reduced modulo p.
+    pub(crate) fn next_field_element_reduced<F: FieldArithmetic>(&mut self, modulus: &Modulus) -> F {
+        let mut value = self.next_integer(modulus);
+        modulus.reduce(&mut value);
+        F::from_bytes_le(&value)
+    }
+}
+
+/// Round numbers `(full_rounds, partial_rounds)` of the reference parameter script for `x^alpha` S-boxes.
+///
+/// The search picks the cheapest pair that resists the statistical, interpolation and Groebner basis attacks at the
+/// given security level (including the bound of <https://eprint.iacr.org/2023/537>), then adds the security margin
+/// of two full rounds and 7.5% more partial rounds.
+pub(crate) fn round_numbers(modulus: &Modulus, width: usize, alpha: u32, security_level: u32) -> (usize, usize) {
+    let (t, m, alpha_f) = (width as f64, security_level as f64, alpha as f64);
+    let log2_p = modulus.log2();
+    let log_alpha = |x: f64| x.ln() / alpha_f.ln();
+
+    let is_secure = |full_rounds: usize, partial_rounds: usize| {
+        let (rf, rp) = (full_rounds as f64, partial_rounds as f64);
+        let statistical = if m <= ((log2_p - (alpha_f - 1.0) / 2.0).floor()) * (t + 1.0) {
+            6.0
+        } else {
+            10.0
+        };
+        let interpolation = 1.0 + (log_alpha(2.0) * m.min(modulus.bits as f64)).ceil() + log_alpha(t).ceil() - rp;
+        let groebner_1 = log_alpha(2.0) * m.min(log2_p) - rp;
+        let groebner_2 = t - 1.0 + log_alpha(2.0) * (m / (t + 1.0)).min(log2_p / 2.0) - rp;
+        let groebner_3 = (t - 2.0 + m / (2.0 * alpha_f.log2()) - rp) / (t - 1.0);
+        let min_full_rounds = [statistical, interpolation, groebner_1, groebner_2, groebner_3]
+            .iter()
+            .map(|bound| bound.ceil())
+            .fold(f64::MIN, f64::max);
+        if rf < min_full_rounds {
+            return false;
+        }
+
+        let r = (t / 3.0).floor();
+        let over = (rf - 1.0) * t + rp + r + r * (rf / 2.0) + rp + alpha_f;
+        let under = r * (rf / 2.0) + rp + alpha_f;
+        let binomial_log2: f64 = (1..=under as u64)
+            .map(|k| ((over - under + k as f64) / k as f64).log2())
+            .sum();
+        (2.0 * binomial_log2).ceil() >= m
+    };
+
+    let mut best = (0, 0);
+    let mut min_cost = usize::MAX;
+    for partial_rounds in 1..500 {
+        // The cost grows with the number of full rounds, only the first secure one matters
+        if let Some(full_rounds) = (4..100)
+            .step_by(2)
+            .find(|full_rounds| is_secure(*full_rounds, partial_rounds))
+        {
+            let full_rounds = full_rounds + 2;
+            let partial_rounds = (partial_rounds as f64 * 1.075).ceil() as usize;
+            let cost = width * full_rounds + partial_rounds;
+            if cost < min_cost || (cost == min_cost && full_rounds < best.0) {
+                best = (full_rounds, partial_rounds);
+                min_cost = cost;
+            }
+        }
+    }
+    best
+}
@@ -0,0 +1,240 @@
+use std::ffi::c_void;
+
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    hash::{sponge_host_slices, HashConfig, SpongeHash},
+    sponge::{check_permutation_state, Permutation},
+    traits::FieldArithmetic,
+};
+
+use super::constants::PoseidonConstants;
+
+/// Host implementation of the optimized Poseidon permutation used by [Poseidon](super::Poseidon).
+///
+/// It takes the same constants as [Poseidon::new](super::Poseidon::new) and produces the same digests, so a verifier
+/// can recompute hashes without the CUDA library.
+#[derive(Debug, Clone)]
+pub struct HostPoseidon<F: FieldArithmetic> {
+    width: usize,
+    alpha: u32,
+    full_rounds_half: usize,
+    partial_rounds: usize,
+    round_constants: Vec<F>,
+    mds_matrix: Vec<F>,
+    non_sparse_matrix: Vec<F>,
+    sparse_matrices: Vec<F>,
+    domain_tag: F,
+}
+
+impl<F: FieldArithmetic> HostPoseidon<F> {
+    pub fn new(
+        arity: usize,
+        alpha: u32,
+        full_rounds_half: u32,
+        partial_rounds: u32,
+        round_constants: &[F],
+        mds_matrix: &[F],
+        non_sparse_matrix: &[F],
+        sparse_matrices: &[F],
+        domain_tag: F,
+    ) -> IcicleResult<Self> {
+        let width = arity + 1;
+        let full_rounds_half = full_rounds_half as usize;
+        let partial_rounds = partial_rounds as usize;
+        if arity == 0 || full_rounds_half == 0 {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon needs a positive arity and at least one full round in each half",
+            ));
+        }
+        if round_constants.len() != width * full_rounds_half * 2 + partial_rounds
+            || mds_matrix.len() != width * width
+            || non_sparse_matrix.len() != width * width
+            || sparse_matrices.len() != (width * 2 - 1) * partial_rounds
+        {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon constants don't match the arity and the number of rounds",
+            ));
+        }
+
+        Ok(Self {
+            width,
+            alpha,
+            full_rounds_half,
+            partial_rounds,
+            round_constants: round_constants.to_vec(),
+            mds_matrix: mds_matrix.to_vec(),
+            non_sparse_matrix: non_sparse_matrix.to_vec(),
+            sparse_matrices: sparse_matrices.to_vec(),
+            domain_tag,
+        })
+    }
+
+    pub fn from_constants(constants: &PoseidonConstants<F>) -> IcicleResult<Self> {
+        Self::new(
+            constants.arity,
+            constants.alpha,
+            constants.full_rounds_half,
+            constants.partial_rounds,
+            &constants.round_constants,
+            &constants.mds_matrix,
+            &constants.non_sparse_matrix,
+            &constants.sparse_matrices,
+            constants.domain_tag,
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn sbox(&self, state: &mut [F]) {
+        for element in state.iter_mut() {
+            *element = element.pow(self.alpha as u64);
+        }
+    }
+
+    fn add_round_constants(state: &mut [F], round_constants: &[F]) {
+        for (element, rc) in state
+            .iter_mut()
+            .zip(round_constants)
+        {
+            *element = *element + *rc;
+        }
+    }
+
+    // The state is a row vector multiplied by a row-major width x width matrix
+    fn mul_matrix(&self, state: &mut [F], matrix: &[F]) {
+        let result: Vec<F> = (0..self.width)
+            .map(|column| {
+                state
+                    .iter()
+                    .enumerate()
+                    .fold(F::zero(), |acc, (row, element)| {
+                        acc + *element * matrix[row * self.width + column]
+                    })
+            })
+            .collect();
+        state.copy_from_slice(&result);
+    }
+
+    /// Applies the permutation to a full state of `arity + 1` elements.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), self.width, "state len must be equal to the width");
+        let width = self.width;
+        let mut rc_offset = 0;
+
+        // The first full round also adds a set of constants before the S-box
+        Self::add_round_constants(state, &self.round_constants[..width]);
+        rc_offset += width;
+        for round in 0..self.full_rounds_half {
+            self.sbox(state);
+            Self::add_round_constants(state, &self.round_constants[rc_offset..rc_offset + width]);
+            rc_offset += width;
+            // The last round before the partial ones uses the dense part of the factorized MDS
+            if round < self.full_rounds_half - 1 {
+                self.mul_matrix(state, &self.mds_matrix);
+            } else {
+                self.mul_matrix(state, &self.non_sparse_matrix);
+            }
+        }
+
+        for round in 0..self.partial_rounds {
+            let element = state[0].pow(self.alpha as u64) + self.round_constants[rc_offset];
+            rc_offset += 1;
+
+            let sparse_matrix = &self.sparse_matrices[(width * 2 - 1) * round..(width * 2 - 1) * (round + 1)];
+            let first = state[1..]
+                .iter()
+                .zip(&sparse_matrix[1..width])
+                .fold(element * sparse_matrix[0], |acc, (s, m)| acc + *s * *m);
+            for (s, m) in state[1..]
+                .iter_mut()
+                .zip(&sparse_matrix[width..])
+            {
+                *s = *s + element * *m;
+            }
+            state[0] = first;
+        }
+
+        for round in 0..self.full_rounds_half {
+            self.sbox(state);
+            if round < self.full_rounds_half - 1 {
+                Self::add_round_constants(state, &self.round_constants[rc_offset..rc_offset + width]);
+                rc_offset += width;
+            }
+            self.mul_matrix(state, &self.mds_matrix);
+        }
+    }
+
+    /// Hashes a single preimage of at most `arity` elements: the domain tag goes first, the input is zero-padded and
+    /// the output is read after the domain tag. Preimages of exactly `arity` elements hash like each state of
+    /// `hash_many`, shorter ones have no device equivalent since the device reads the states `arity` elements apart.
+    pub fn hash(&self, input: &[F], output: &mut [F]) {
+        assert!(input.len() < self.width, "input len must not exceed the arity");
+        assert!(output.len() < self.width, "output len must not exceed the arity");
+
+        let mut state = vec![F::zero(); self.width];
+        state[0] = self.domain_tag;
+        state[1..input.len() + 1].copy_from_slice(input);
+        self.permute(&mut state);
+        output.copy_from_slice(&state[1..output.len() + 1]);
+    }
+}
+
+impl<F: FieldArithmetic> SpongeHash<F, F> for HostPoseidon<F> {
+    /// Host sponges have no device handle, so they can't be passed to the device tree builders.
+    fn get_handle(&self) -> *const c_void {
+        std::ptr::null()
+    }
+
+    fn hash_many(
+        &self,
+        inputs: &(impl HostOrDeviceSlice<F> + ?Sized),
+        output: &mut (impl HostOrDeviceSlice<F> + ?Sized),
+        number_of_states: usize,
+        input_block_len: usize,
+        output_len: usize,
+        _cfg: &HashConfig,
+    ) -> IcicleResult<()> {
+        // The device reads state `i` at `i * arity` whatever the block length, only full blocks match it
+        if input_block_len != self.width - 1 {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon hash_many input block len must be the arity",
+            ));
+        }
+        let (inputs, output) = sponge_host_slices(
+            inputs,
+            output,
+            number_of_states,
+            input_block_len,
+            self.width - 1,
+            output_len,
+        )?;
+
+        for i in 0..number_of_states {
+            self.hash(
+                &inputs[i * input_block_len..(i + 1) * input_block_len],
+                &mut output[i * output_len..(i + 1) * output_len],
+            );
+        }
+        Ok(())
+    }
+
+    fn default_config<'a>(&self) -> HashConfig<'a> {
+        HashConfig::default()
+    }
+}
+
+impl<F: FieldArithmetic> Permutation<F> for HostPoseidon<F> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn permute(&self, state: &mut [F]) -> IcicleResult<()> {
+        check_permutation_state(state, self.width)?;
+        HostPoseidon::permute(self, state);
+        Ok(())
+    }
+}
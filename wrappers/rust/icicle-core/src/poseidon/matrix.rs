@@ -0,0 +1,487 @@
+//! Dense linear algebra over the field, used to build and vet the linear layers of Poseidon and Poseidon2.
+//!
+//! Matrices act on column vectors here, like in the reference scripts.
+
+use crate::traits::FieldArithmetic;
+
+use super::grain::Modulus;
+
+pub(crate) type Matrix<F> = Vec<Vec<F>>;
+
+pub(crate) fn identity<F: FieldArithmetic>(size: usize) -> Matrix<F> {
+    (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| if i == j { F::one() } else { F::zero() })
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn transpose<F: FieldArithmetic>(m: &Matrix<F>) -> Matrix<F> {
+    (0..m.len())
+        .map(|j| {
+            m.iter()
+                .map(|row| row[j])
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn mul<F: FieldArithmetic>(a: &Matrix<F>, b: &Matrix<F>) -> Matrix<F> {
+    a.iter()
+        .map(|row| vec_mul(row, b))
+        .collect()
+}
+
+/// Row vector times matrix.
+pub(crate) fn vec_mul<F: FieldArithmetic>(v: &[F], m: &Matrix<F>) -> Vec<F> {
+    (0..m[0].len())
+        .map(|j| {
+            v.iter()
+                .zip(m)
+                .fold(F::zero(), |acc, (x, row)| acc + *x * row[j])
+        })
+        .collect()
+}
+
+/// Matrix times column vector.
+pub(crate) fn mul_vec<F: FieldArithmetic>(m: &Matrix<F>, v: &[F]) -> Vec<F> {
+    m.iter()
+        .map(|row| dot(row, v))
+        .collect()
+}
+
+fn dot<F: FieldArithmetic>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b)
+        .fold(F::zero(), |acc, (x, y)| acc + *x * *y)
+}
+
+/// Gauss-Jordan inversion, `None` for singular matrices.
+pub(crate) fn inverse<F: FieldArithmetic>(m: &Matrix<F>) -> Option<Matrix<F>> {
+    let size = m.len();
+    let mut a = m.clone();
+    let mut inv = identity(size);
+    for col in 0..size {
+        let pivot = (col..size).find(|row| a[*row][col] != F::zero())?;
+        a.swap(col, pivot);
+        inv.swap(col, pivot);
+        let scale = a[col][col]
+            .inv()
+            .unwrap();
+        for j in 0..size {
+            a[col][j] = a[col][j] * scale;
+            inv[col][j] = inv[col][j] * scale;
+        }
+        for row in 0..size {
+            let factor = a[row][col];
+            if row == col || factor == F::zero() {
+                continue;
+            }
+            for j in 0..size {
+                a[row][j] = a[row][j] - factor * a[col][j];
+                inv[row][j] = inv[row][j] - factor * inv[col][j];
+            }
+        }
+    }
+    Some(inv)
+}
+
+/// Row echelon basis of a growing subspace.
+struct Subspace<F> {
+    // Each vector has a one at its pivot and zeros at the pivots of the other vectors
+    basis: Vec<(usize, Vec<F>)>,
+}
+
+impl<F: FieldArithmetic> Subspace<F> {
+    fn new() -> Self {
+        Self { basis: Vec::new() }
+    }
+
+    fn dimension(&self) -> usize {
+        self.basis
+            .len()
+    }
+
+    fn reduce(&self, v: &[F]) -> Vec<F> {
+        let mut v = v.to_vec();
+        for (pivot, b) in &self.basis {
+            let factor = v[*pivot];
+            if factor != F::zero() {
+                for (x, y) in v
+                    .iter_mut()
+                    .zip(b)
+                {
+                    *x = *x - factor * *y;
+                }
+            }
+        }
+        v
+    }
+
+    /// Adds a vector to the subspace, returns whether the dimension grew.
+    fn insert(&mut self, v: &[F]) -> bool {
+        let mut v = self.reduce(v);
+        let Some(pivot) = v
+            .iter()
+            .position(|x| *x != F::zero())
+        else {
+            return false;
+        };
+        let scale = v[pivot]
+            .inv()
+            .unwrap();
+        for x in v.iter_mut() {
+            *x = *x * scale;
+        }
+        for (_, b) in self
+            .basis
+            .iter_mut()
+        {
+            let factor = b[pivot];
+            if factor != F::zero() {
+                for (x, y) in b
+                    .iter_mut()
+                    .zip(&v)
+                {
+                    *x = *x - factor * *y;
+                }
+            }
+        }
+        self.basis
+            .push((pivot, v));
+        true
+    }
+}
+
+/// Basis of the vectors orthogonal to all `rows`.
+fn kernel<F: FieldArithmetic>(rows: &[Vec<F>], size: usize) -> Vec<Vec<F>> {
+    let mut span = Subspace::new();
+    for row in rows {
+        span.insert(row);
+    }
+    let pivots: Vec<usize> = span
+        .basis
+        .iter()
+        .map(|(pivot, _)| *pivot)
+        .collect();
+    (0..size)
+        .filter(|free| !pivots.contains(free))
+        .map(|free| {
+            let mut v = vec![F::zero(); size];
+            v[free] = F::one();
+            for (pivot, b) in &span.basis {
+                v[*pivot] = -b[free];
+            }
+            v
+        })
+        .collect()
+}
+
+// First row of every power of `m`, the linear forms that feed the single S-box of a partial round
+fn first_rows<F: FieldArithmetic>(m: &Matrix<F>, count: usize) -> Vec<Vec<F>> {
+    let mut rows = Vec::with_capacity(count);
+    let mut row: Vec<F> = identity(m.len()).swap_remove(0);
+    for _ in 0..count {
+        let next = vec_mul(&row, m);
+        rows.push(row);
+        row = next;
+    }
+    rows
+}
+
+/// Checks the linear layer for infinitely long subspace trails, following algorithms 1 to 3 of the reference script
+/// with one S-box per partial round.
+///
+/// Algorithm 1 rejects a matrix if the subspace of differences that avoid the S-box for `i` rounds contains an
+/// eigenvector of `m^i`. Here any non-trivial invariant subspace that never reaches the S-box is rejected, which covers
+/// all of those eigenvectors.
+pub(crate) fn is_secure<F: FieldArithmetic>(m: &Matrix<F>) -> bool {
+    let size = m.len();
+    let rows = first_rows(m, size);
+    if !kernel(&rows[..size], size).is_empty() {
+        return false;
+    }
+
+    let mut power = identity(size);
+    let mut powers = Vec::with_capacity(size);
+    for _ in 1..size {
+        power = mul(&power, m);
+        powers.push(power.clone());
+    }
+    for (i, power) in (1..size).zip(&powers) {
+        let scalar = power[0][0];
+        let is_scalar = power
+            .iter()
+            .enumerate()
+            .all(|(r, row)| {
+                row.iter()
+                    .enumerate()
+                    .all(|(c, x)| *x == if r == c { scalar } else { F::zero() })
+            });
+        if is_scalar {
+            return false;
+        }
+
+        // Differences that stay away from the S-box for i rounds
+        let avoiding = kernel(&rows[..i], size);
+        for power in &powers[..i] {
+            let invariant = avoiding
+                .iter()
+                .all(|v| {
+                    let image = mul_vec(power, v);
+                    rows[..i]
+                        .iter()
+                        .all(|row| dot(row, &image) == F::zero())
+                });
+            if invariant {
+                return false;
+            }
+        }
+    }
+
+    // Algorithms 2 and 3, the S-box output must spread to the whole state under m, m^2, ..., m^(4t)
+    let mut power = m.clone();
+    for _ in 0..4 * size {
+        if !is_full_krylov_space(&power) {
+            return false;
+        }
+        power = mul(&power, m);
+    }
+    true
+}
+
+// Whether e_0, m * e_0, m^2 * e_0, ... span the whole space
+fn is_full_krylov_space<F: FieldArithmetic>(m: &Matrix<F>) -> bool {
+    let size = m.len();
+    let mut space = Subspace::new();
+    let mut v: Vec<F> = identity(size).swap_remove(0);
+    space.insert(&v);
+    while space.dimension() < size {
+        v = mul_vec(m, &v);
+        if !space.insert(&v) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether the minimal polynomials of `m, m^2, ..., m^(2t)` are irreducible of degree `t`, the condition the reference
+/// script uses to pick the Poseidon2 internal matrix.
+pub(crate) fn satisfies_minpoly_condition<F: FieldArithmetic>(m: &Matrix<F>, modulus: &Modulus) -> bool {
+    // An irreducible characteristic polynomial is also the minimal one
+    let mut power = m.clone();
+    for _ in 0..2 * m.len() {
+        if !is_irreducible(&charpoly(&power), modulus) {
+            return false;
+        }
+        power = mul(&power, m);
+    }
+    true
+}
+
+// Polynomials are little-endian coefficient vectors without trailing zeros
+
+fn trim<F: FieldArithmetic>(mut a: Vec<F>) -> Vec<F> {
+    while a.last() == Some(&F::zero()) {
+        a.pop();
+    }
+    a
+}
+
+fn poly_sub<F: FieldArithmetic>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut res = vec![
+        F::zero();
+        a.len()
+            .max(b.len())
+    ];
+    for (i, x) in a
+        .iter()
+        .enumerate()
+    {
+        res[i] = *x;
+    }
+    for (i, x) in b
+        .iter()
+        .enumerate()
+    {
+        res[i] = res[i] - *x;
+    }
+    trim(res)
+}
+
+fn poly_rem<F: FieldArithmetic>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut a = trim(a.to_vec());
+    let lead_inv = b
+        .last()
+        .unwrap()
+        .inv()
+        .unwrap();
+    while a.len() >= b.len() {
+        let factor = *a
+            .last()
+            .unwrap()
+            * lead_inv;
+        let shift = a.len() - b.len();
+        for (i, x) in b
+            .iter()
+            .enumerate()
+        {
+            a[shift + i] = a[shift + i] - factor * *x;
+        }
+        a = trim(a);
+    }
+    a
+}
+
+fn poly_mul_mod<F: FieldArithmetic>(a: &[F], b: &[F], modulus: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a
+        .iter()
+        .enumerate()
+    {
+        for (j, y) in b
+            .iter()
+            .enumerate()
+        {
+            res[i + j] = res[i + j] + *x * *y;
+        }
+    }
+    poly_rem(&res, modulus)
+}
+
+fn poly_gcd<F: FieldArithmetic>(a: &[F], b: &[F]) -> Vec<F> {
+    let (mut a, mut b) = (trim(a.to_vec()), trim(b.to_vec()));
+    while !b.is_empty() {
+        let r = poly_rem(&a, &b);
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Characteristic polynomial through a reduction to upper Hessenberg form.
+fn charpoly<F: FieldArithmetic>(m: &Matrix<F>) -> Vec<F> {
+    let size = m.len();
+    let mut h = m.clone();
+    for col in 0..size.saturating_sub(2) {
+        let Some(pivot) = (col + 1..size).find(|row| h[*row][col] != F::zero()) else {
+            continue;
+        };
+        if pivot != col + 1 {
+            h.swap(pivot, col + 1);
+            for row in h.iter_mut() {
+                row.swap(pivot, col + 1);
+            }
+        }
+        let pivot_inv = h[col + 1][col]
+            .inv()
+            .unwrap();
+        for row in col + 2..size {
+            let factor = h[row][col] * pivot_inv;
+            if factor == F::zero() {
+                continue;
+            }
+            // Row operation and its inverse on the columns keep the matrix similar
+            let pivot_row = h[col + 1].clone();
+            for (a, b) in h[row]
+                .iter_mut()
+                .zip(pivot_row)
+            {
+                *a = *a - factor * b;
+            }
+            for r in h.iter_mut() {
+                r[col + 1] = r[col + 1] + factor * r[row];
+            }
+        }
+    }
+
+    // charpolys[k] is the characteristic polynomial of the leading k x k block
+    let mut charpolys: Vec<Vec<F>> = vec![vec![F::one()]];
+    for k in 1..=size {
+        let shifted: Vec<F> = std::iter::once(F::zero())
+            .chain(
+                charpolys[k - 1]
+                    .iter()
+                    .copied(),
+            )
+            .collect();
+        let mut p = poly_sub(&shifted, &poly_scale(&charpolys[k - 1], h[k - 1][k - 1]));
+        let mut product = F::one();
+        for i in 1..k {
+            product = product * h[k - i][k - i - 1];
+            let coeff = product * h[k - i - 1][k - 1];
+            p = poly_sub(&p, &poly_scale(&charpolys[k - i - 1], coeff));
+        }
+        charpolys.push(p);
+    }
+    charpolys.swap_remove(size)
+}
+
+fn poly_scale<F: FieldArithmetic>(a: &[F], factor: F) -> Vec<F> {
+    trim(
+        a.iter()
+            .map(|x| *x * factor)
+            .collect(),
+    )
+}
+
+/// Rabin's test for a monic polynomial of degree `d`: `x^(p^d) = x` modulo `f` and `x^(p^(d/q)) - x` is coprime to `f`
+/// for every prime `q` dividing `d`.
+fn is_irreducible<F: FieldArithmetic>(f: &[F], modulus: &Modulus) -> bool {
+    let degree = f.len() - 1;
+    if degree <= 1 {
+        return true;
+    }
+    let x = vec![F::zero(), F::one()];
+
+    // x^p by square-and-multiply, then the Frobenius map g -> g^p = g(x^p) as a linear map on the residues
+    let mut x_p = vec![F::one()];
+    for byte in modulus
+        .bytes()
+        .iter()
+        .rev()
+    {
+        for bit in (0..8).rev() {
+            x_p = poly_mul_mod(&x_p, &x_p, f);
+            if (byte >> bit) & 1 == 1 {
+                x_p = poly_mul_mod(&x_p, &x, f);
+            }
+        }
+    }
+    let mut frobenius = vec![vec![F::one()]];
+    for i in 1..degree {
+        frobenius.push(poly_mul_mod(&frobenius[i - 1], &x_p, f));
+    }
+    let apply_frobenius = |g: &[F]| {
+        let mut res = vec![F::zero(); degree];
+        for (coeff, power) in g
+            .iter()
+            .zip(&frobenius)
+        {
+            for (r, y) in res
+                .iter_mut()
+                .zip(power)
+            {
+                *r = *r + *coeff * *y;
+            }
+        }
+        trim(res)
+    };
+
+    // x^(p^k) for k = 0..=degree
+    let mut iterates = vec![x.clone()];
+    for k in 1..=degree {
+        iterates.push(apply_frobenius(&iterates[k - 1]));
+    }
+    if iterates[degree] != x {
+        return false;
+    }
+    (2..=degree)
+        .filter(|q| degree % q == 0 && (2..*q).all(|d| q % d != 0))
+        .all(|q| poly_gcd(&poly_sub(&iterates[degree / q], &x), f).len() == 1)
+}
@@ -1,3 +1,7 @@
+pub mod constants;
+pub(crate) mod grain;
+pub mod host;
+pub(crate) mod matrix;
 #[doc(hidden)]
 pub mod tests;
 
@@ -11,6 +15,8 @@ use crate::{
     traits::FieldImpl,
 };
 
+use self::constants::PoseidonConstants;
+
 pub type PoseidonHandle = *const c_void;
 pub struct Poseidon<F>
 where
@@ -69,6 +75,21 @@ where
             })
         })
     }
+
+    pub fn from_constants(constants: &PoseidonConstants<F>, ctx: &DeviceContext) -> IcicleResult<Self> {
+        Self::new(
+            constants.arity,
+            constants.alpha,
+            constants.full_rounds_half,
+            constants.partial_rounds,
+            &constants.round_constants,
+            &constants.mds_matrix,
+            &constants.non_sparse_matrix,
+            &constants.sparse_matrices,
+            constants.domain_tag,
+            ctx,
+        )
+    }
 }
 
 impl<F> SpongeHash<F, F> for Poseidon<F>
@@ -92,9 +113,7 @@ where
         sponge_check_input(inputs, number_of_states, input_block_len, self.width - 1, &cfg.ctx);
         sponge_check_outputs(output, number_of_states, output_len, self.width, false, &cfg.ctx);
 
-        let mut local_cfg = cfg.clone();
-        local_cfg.are_inputs_on_device = inputs.is_on_device();
-        local_cfg.are_outputs_on_device = output.is_on_device();
+        let local_cfg = cfg.for_slices(inputs, output);
 
         <<F as FieldImpl>::Config as PoseidonImpl<F>>::hash_many(
             inputs,
@@ -167,13 +186,13 @@ macro_rules! impl_poseidon {
                     poseidon: *mut PoseidonHandle,
                     arity: u32,
                     alpha: u32,
-                    full_rounds_half: u32,
                     partial_rounds: u32,
+                    full_rounds_half: u32,
                     round_constants: *const $field,
                     mds_matrix: *const $field,
                     non_sparse_matrix: *const $field,
                     sparse_matrices: *const $field,
-                    domain_tag: $field,
+                    domain_tag: &$field,
                     ctx: &DeviceContext,
                 ) -> CudaError;
 
@@ -215,13 +234,13 @@ macro_rules! impl_poseidon {
                         poseidon.as_mut_ptr(),
                         arity,
                         alpha,
-                        full_rounds_half,
                         partial_rounds,
+                        full_rounds_half,
                         round_constants as *const _ as *const $field,
                         mds_matrix as *const _ as *const $field,
                         non_sparse_matrix as *const _ as *const $field,
                         sparse_matrices as *const _ as *const $field,
-                        domain_tag,
+                        &domain_tag,
                         ctx,
                     )
                     .wrap()
@@ -277,5 +296,25 @@ macro_rules! impl_poseidon_tests {
         fn test_poseidon_hash_many() {
             check_poseidon_hash_many::<$field>()
         }
+
+        #[test]
+        fn test_poseidon_host() {
+            check_poseidon_host::<$field>()
+        }
+
+        #[test]
+        fn test_poseidon_host_block_len() {
+            check_poseidon_host_block_len::<$field>()
+        }
+
+        #[test]
+        fn test_poseidon_create_arguments() {
+            check_poseidon_create_arguments::<$field>()
+        }
+
+        #[test]
+        fn test_poseidon_constants() {
+            check_poseidon_constants::<$field>()
+        }
     };
 }
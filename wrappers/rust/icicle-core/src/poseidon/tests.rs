@@ -1,9 +1,14 @@
+use crate::error::IcicleErrorCode;
 use crate::hash::SpongeHash;
-use crate::traits::FieldImpl;
+use crate::traits::{FieldArithmetic, FieldImpl, GenerateRandom};
 use icicle_cuda_runtime::device_context::DeviceContext;
 use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
 
-use super::{Poseidon, PoseidonImpl};
+use super::{
+    constants::{reference_constants, PoseidonConstants},
+    host::HostPoseidon,
+    matrix, Poseidon, PoseidonImpl,
+};
 
 pub fn init_poseidon<F: FieldImpl>(arity: usize) -> Poseidon<F>
 where
@@ -46,3 +51,263 @@ where
         _check_poseidon_hash_many(poseidon);
     }
 }
+
+pub fn check_poseidon_host<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: PoseidonImpl<F> + GenerateRandom<F>,
+{
+    let test_size = 1 << 8;
+    let (alpha, full_rounds_half, partial_rounds) = (5, 4, 56);
+    let ctx = DeviceContext::default();
+    for arity in [2, 4, 8, 11] {
+        let width = arity + 1;
+        // The kernels don't rely on any relation between the constants, random ones exercise every step
+        let round_constants = F::Config::generate_random(width * full_rounds_half * 2 + partial_rounds);
+        let mds_matrix = F::Config::generate_random(width * width);
+        let non_sparse_matrix = F::Config::generate_random(width * width);
+        let sparse_matrices = F::Config::generate_random((width * 2 - 1) * partial_rounds);
+        let domain_tag = F::Config::generate_random(1)[0];
+
+        let poseidon = Poseidon::<F>::new(
+            arity,
+            alpha,
+            full_rounds_half as u32,
+            partial_rounds as u32,
+            &round_constants,
+            &mds_matrix,
+            &non_sparse_matrix,
+            &sparse_matrices,
+            domain_tag,
+            &ctx,
+        )
+        .unwrap();
+        let host_poseidon = HostPoseidon::<F>::new(
+            arity,
+            alpha,
+            full_rounds_half as u32,
+            partial_rounds as u32,
+            &round_constants,
+            &mds_matrix,
+            &non_sparse_matrix,
+            &sparse_matrices,
+            domain_tag,
+        )
+        .unwrap();
+
+        let inputs = F::Config::generate_random(test_size * arity);
+        for output_len in [1, arity] {
+            let mut outputs = vec![F::zero(); test_size * output_len];
+            let mut host_outputs = vec![F::zero(); test_size * output_len];
+
+            let cfg = poseidon.default_config();
+            poseidon
+                .hash_many(
+                    HostSlice::from_slice(&inputs),
+                    HostSlice::from_mut_slice(&mut outputs),
+                    test_size,
+                    arity,
+                    output_len,
+                    &cfg,
+                )
+                .unwrap();
+            host_poseidon
+                .hash_many(
+                    HostSlice::from_slice(&inputs),
+                    HostSlice::from_mut_slice(&mut host_outputs),
+                    test_size,
+                    arity,
+                    output_len,
+                    &cfg,
+                )
+                .unwrap();
+
+            assert_eq!(outputs, host_outputs);
+        }
+    }
+}
+
+pub fn check_poseidon_constants<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: PoseidonImpl<F> + GenerateRandom<F>,
+{
+    let test_size = 1 << 4;
+    let ctx = DeviceContext::default();
+    for arity in [2, 4] {
+        // x^5 is not a permutation of every field
+        let constants = [5, 7, 11, 13, 17]
+            .into_iter()
+            .find_map(|alpha| PoseidonConstants::<F>::generate(arity, alpha, 128).ok())
+            .unwrap();
+        let full_rounds_half = constants.full_rounds_half as usize;
+        let partial_rounds = constants.partial_rounds as usize;
+        let (round_constants, mds) =
+            reference_constants::<F>(arity, constants.alpha, full_rounds_half, partial_rounds).unwrap();
+        let host_poseidon = HostPoseidon::from_constants(&constants).unwrap();
+
+        // The optimized constants must describe the same permutation as the plain rounds
+        let inputs = F::Config::generate_random(test_size * arity);
+        for input in inputs.chunks(arity) {
+            let mut state = [&[constants.domain_tag], input].concat();
+            for (round, round_constants) in round_constants
+                .chunks(arity + 1)
+                .enumerate()
+            {
+                let is_full_round = round < full_rounds_half || round >= full_rounds_half + partial_rounds;
+                for (i, (element, rc)) in state
+                    .iter_mut()
+                    .zip(round_constants)
+                    .enumerate()
+                {
+                    *element = *element + *rc;
+                    if i == 0 || is_full_round {
+                        *element = element.pow(constants.alpha as u64);
+                    }
+                }
+                state = matrix::mul_vec(&mds, &state);
+            }
+
+            let mut output = vec![F::zero(); arity];
+            host_poseidon.hash(input, &mut output);
+            assert_eq!(output, state[1..]);
+        }
+
+        let poseidon = Poseidon::from_constants(&constants, &ctx).unwrap();
+        let mut outputs = vec![F::zero(); test_size];
+        let mut host_outputs = vec![F::zero(); test_size];
+        let cfg = poseidon.default_config();
+        poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut outputs),
+                test_size,
+                arity,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        host_poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut host_outputs),
+                test_size,
+                arity,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        assert_eq!(outputs, host_outputs);
+    }
+}
+
+/// Host `hash_many` only takes blocks of `arity` elements, the device would read shorter ones `arity` elements apart.
+pub fn check_poseidon_host_block_len<F: FieldArithmetic>() {
+    let arity = 4;
+    let constants = [5, 7, 11, 13, 17]
+        .into_iter()
+        .find_map(|alpha| PoseidonConstants::<F>::generate(arity, alpha, 128).ok())
+        .unwrap();
+    let host_poseidon = HostPoseidon::from_constants(&constants).unwrap();
+    let inputs = vec![F::one(); 2 * arity];
+    let mut outputs = vec![F::zero(); 2];
+    let cfg = host_poseidon.default_config();
+    for input_block_len in [1, arity - 1] {
+        let err = host_poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut outputs),
+                2,
+                input_block_len,
+                1,
+                &cfg,
+            )
+            .unwrap_err();
+        assert_eq!(err.get_icicle_error_code(), IcicleErrorCode::InvalidArgument);
+    }
+    host_poseidon
+        .hash_many(
+            HostSlice::from_slice(&inputs),
+            HostSlice::from_mut_slice(&mut outputs),
+            2,
+            arity,
+            1,
+            &cfg,
+        )
+        .unwrap();
+    let mut output = [F::zero()];
+    host_poseidon.hash(&inputs[arity..], &mut output);
+    assert_eq!(outputs[1], output[0]);
+}
+
+/// Regression test for the arguments of the device `create`: the C++ API takes `partial_rounds` before
+/// `full_rounds_half` and the domain tag by reference, so swapped round counts or a tag passed by value give a
+/// permutation that differs from the host one.
+pub fn check_poseidon_create_arguments<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: PoseidonImpl<F> + GenerateRandom<F>,
+{
+    let test_size = 1 << 4;
+    let (arity, alpha, full_rounds_half, partial_rounds) = (2, 5, 4, 56);
+    let width = arity + 1;
+    let ctx = DeviceContext::default();
+    let round_constants = F::Config::generate_random(width * full_rounds_half * 2 + partial_rounds);
+    let mds_matrix = F::Config::generate_random(width * width);
+    let non_sparse_matrix = F::Config::generate_random(width * width);
+    let sparse_matrices = F::Config::generate_random((width * 2 - 1) * partial_rounds);
+    let inputs = F::Config::generate_random(test_size * arity);
+
+    let mut outputs_per_tag = Vec::new();
+    for domain_tag in [F::zero(), F::from_u32(3)] {
+        let poseidon = Poseidon::<F>::new(
+            arity,
+            alpha,
+            full_rounds_half as u32,
+            partial_rounds as u32,
+            &round_constants,
+            &mds_matrix,
+            &non_sparse_matrix,
+            &sparse_matrices,
+            domain_tag,
+            &ctx,
+        )
+        .unwrap();
+        let host_poseidon = HostPoseidon::<F>::new(
+            arity,
+            alpha,
+            full_rounds_half as u32,
+            partial_rounds as u32,
+            &round_constants,
+            &mds_matrix,
+            &non_sparse_matrix,
+            &sparse_matrices,
+            domain_tag,
+        )
+        .unwrap();
+
+        let mut outputs = vec![F::zero(); test_size];
+        let mut host_outputs = vec![F::zero(); test_size];
+        let cfg = poseidon.default_config();
+        poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut outputs),
+                test_size,
+                arity,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        host_poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut host_outputs),
+                test_size,
+                arity,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        assert_eq!(outputs, host_outputs);
+        outputs_per_tag.push(outputs);
+    }
+    assert_ne!(outputs_per_tag[0], outputs_per_tag[1]);
+}
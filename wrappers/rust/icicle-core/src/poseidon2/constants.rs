@@ -0,0 +1,107 @@
+use crate::{
+    error::{IcicleError, IcicleResult},
+    poseidon::{
+        grain::{round_numbers, GrainLfsr, Modulus},
+        matrix,
+    },
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+/// Constants of the Poseidon2 permutation, in the layout taken by [Poseidon2::new](super::Poseidon2::new) with
+/// [MdsType::Default](super::MdsType::Default) and [DiffusionStrategy::Default](super::DiffusionStrategy::Default).
+#[derive(Debug, Clone)]
+pub struct Poseidon2Constants<F: FieldImpl> {
+    pub width: usize,
+    pub alpha: u32,
+    pub internal_rounds: u32,
+    pub external_rounds: u32,
+    pub round_constants: Vec<F>,
+    /// Diagonal of the internal matrix minus one.
+    pub internal_matrix_diag: Vec<F>,
+}
+
+impl<F: FieldArithmetic> Poseidon2Constants<F> {
+    /// Derives the constants of the `x^alpha` permutation over `width` elements at `security_level` bits.
+    ///
+    /// The number of rounds is the cheapest one found by the reference parameter script, security margin included.
+    pub fn generate(width: usize, alpha: u32, security_level: u32) -> IcicleResult<Self> {
+        let modulus = Modulus::of::<F>();
+        modulus.check_alpha(alpha)?;
+        let (external_rounds, internal_rounds) = round_numbers(&modulus, width, alpha, security_level);
+        Self::generate_with_rounds(width, alpha, internal_rounds as u32, external_rounds as u32)
+    }
+
+    /// Same as [generate](Self::generate) with a given number of rounds.
+    ///
+    /// The round constants are drawn from the Grain LFSR, one per element in external rounds and one in internal
+    /// rounds. For widths of at least 4 the internal matrix is `J - I + D` with `D` a diagonal sampled from the LFSR
+    /// until the minimal polynomials of its first `2 * width` powers are irreducible, as in the reference script.
+    pub fn generate_with_rounds(
+        width: usize,
+        alpha: u32,
+        internal_rounds: u32,
+        external_rounds: u32,
+    ) -> IcicleResult<Self> {
+        if !(width == 2 || width == 3 || (width % 4 == 0 && width <= 24)) {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 width must be one of [2, 3, 4, 8, 12, 16, 20, 24]",
+            ));
+        }
+        if external_rounds == 0 || external_rounds % 2 != 0 {
+            return Err(IcicleError::invalid_argument("Invalid external rounds"));
+        }
+        let modulus = Modulus::of::<F>();
+        modulus.check_alpha(alpha)?;
+
+        let mut lfsr = GrainLfsr::new(
+            0,
+            modulus.bits(),
+            width,
+            external_rounds as usize,
+            internal_rounds as usize,
+        )?;
+        let round_constants = (0..width * external_rounds as usize + internal_rounds as usize)
+            .map(|_| lfsr.next_field_element(&modulus))
+            .collect();
+
+        let internal_matrix_diag: Vec<F> = match width {
+            // [2, 1], [1, 3]
+            2 => vec![F::one(), F::from_u32(2)],
+            // [2, 1, 1], [1, 2, 1], [1, 1, 3]
+            3 => vec![F::one(), F::one(), F::from_u32(2)],
+            _ => loop {
+                let diag: Vec<F> = (0..width)
+                    .map(|_| lfsr.next_field_element_reduced::<F>(&modulus) - F::one())
+                    .collect();
+                if matrix::satisfies_minpoly_condition(&internal_matrix(&diag), &modulus) {
+                    break diag;
+                }
+            },
+        };
+        if !matrix::is_secure(&internal_matrix(&internal_matrix_diag)) {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 internal matrix admits an infinitely long subspace trail",
+            ));
+        }
+
+        Ok(Self {
+            width,
+            alpha,
+            internal_rounds,
+            external_rounds,
+            round_constants,
+            internal_matrix_diag,
+        })
+    }
+}
+
+// J + diag(diag), the internal matrix given the diagonal minus one
+fn internal_matrix<F: FieldArithmetic>(diag: &[F]) -> matrix::Matrix<F> {
+    (0..diag.len())
+        .map(|i| {
+            (0..diag.len())
+                .map(|j| if i == j { diag[i] + F::one() } else { F::one() })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,275 @@
+use std::ffi::c_void;
+
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    hash::{sponge_host_slices, HashConfig, SpongeHash},
+    sponge::{check_permutation_state, Permutation},
+    traits::FieldArithmetic,
+};
+
+use super::{constants::Poseidon2Constants, DiffusionStrategy, MdsType};
+
+/// Host implementation of the Poseidon2 permutation used by [Poseidon2](super::Poseidon2).
+///
+/// It takes the same constants as [Poseidon2::new](super::Poseidon2::new) and produces the same digests, so a
+/// verifier can recompute hashes without the CUDA library.
+#[derive(Debug, Clone)]
+pub struct HostPoseidon2<F: FieldArithmetic> {
+    width: usize,
+    rate: usize,
+    alpha: u32,
+    internal_rounds: usize,
+    external_rounds: usize,
+    round_constants: Vec<F>,
+    internal_matrix_diag: Vec<F>,
+    mds_type: MdsType,
+    diffusion: DiffusionStrategy,
+}
+
+impl<F: FieldArithmetic> HostPoseidon2<F> {
+    pub fn new(
+        width: usize,
+        rate: usize,
+        alpha: u32,
+        internal_rounds: u32,
+        external_rounds: u32,
+        round_constants: &[F],
+        internal_matrix_diag: &[F],
+        mds_type: MdsType,
+        diffusion: DiffusionStrategy,
+    ) -> IcicleResult<Self> {
+        let internal_rounds = internal_rounds as usize;
+        let external_rounds = external_rounds as usize;
+        if !(width == 2 || width == 3 || (width % 4 == 0 && width <= 24)) {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 width must be one of [2, 3, 4, 8, 12, 16, 20, 24]",
+            ));
+        }
+        if rate == 0 || rate > width {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 rate must be positive and not greater than the width",
+            ));
+        }
+        if external_rounds % 2 != 0 {
+            return Err(IcicleError::invalid_argument("Invalid external rounds"));
+        }
+        if round_constants.len() != width * external_rounds + internal_rounds || internal_matrix_diag.len() != width {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 constants don't match the width and the number of rounds",
+            ));
+        }
+
+        Ok(Self {
+            width,
+            rate,
+            alpha,
+            internal_rounds,
+            external_rounds,
+            round_constants: round_constants.to_vec(),
+            internal_matrix_diag: internal_matrix_diag.to_vec(),
+            mds_type,
+            diffusion,
+        })
+    }
+
+    pub fn from_constants(constants: &Poseidon2Constants<F>, rate: usize) -> IcicleResult<Self> {
+        Self::new(
+            constants.width,
+            rate,
+            constants.alpha,
+            constants.internal_rounds,
+            constants.external_rounds,
+            &constants.round_constants,
+            &constants.internal_matrix_diag,
+            MdsType::Default,
+            DiffusionStrategy::Default,
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    // [5 7 1 3; 4 6 1 1; 1 3 5 7; 1 1 4 6]
+    fn mds_light_4x4(s: &mut [F]) {
+        let t0 = s[0] + s[1];
+        let t1 = s[2] + s[3];
+        let t2 = s[1] + s[1] + t1;
+        let t3 = s[3] + s[3] + t0;
+        let t4 = t1 + t1 + t1 + t1 + t3;
+        let t5 = t0 + t0 + t0 + t0 + t2;
+        s[0] = t3 + t5;
+        s[1] = t5;
+        s[2] = t2 + t4;
+        s[3] = t4;
+    }
+
+    // [2 3 1 1; 1 2 3 1; 1 1 2 3; 3 1 1 2]
+    fn mds_light_plonky_4x4(s: &mut [F]) {
+        let t01 = s[0] + s[1];
+        let t23 = s[2] + s[3];
+        let t0123 = t01 + t23;
+        let t01123 = t0123 + s[1];
+        let t01233 = t0123 + s[3];
+        s[3] = t01233 + s[0] + s[0];
+        s[1] = t01123 + s[2] + s[2];
+        s[0] = t01123 + t01;
+        s[2] = t01233 + t23;
+    }
+
+    fn mds_light(&self, state: &mut [F]) {
+        if self.width < 4 {
+            let sum = state
+                .iter()
+                .fold(F::zero(), |acc, s| acc + *s);
+            for s in state.iter_mut() {
+                *s = *s + sum;
+            }
+            return;
+        }
+
+        for block in state.chunks_mut(4) {
+            match self.mds_type {
+                MdsType::Default => Self::mds_light_4x4(block),
+                MdsType::Plonky => Self::mds_light_plonky_4x4(block),
+            }
+        }
+        let mut sums = [F::zero(); 4];
+        for (i, s) in state
+            .iter()
+            .enumerate()
+        {
+            sums[i % 4] = sums[i % 4] + *s;
+        }
+        for (i, s) in state
+            .iter_mut()
+            .enumerate()
+        {
+            *s = *s + sums[i % 4];
+        }
+    }
+
+    fn external_round(&self, state: &mut [F], round_constants: &[F]) {
+        for (s, rc) in state
+            .iter_mut()
+            .zip(round_constants)
+        {
+            *s = (*s + *rc).pow(self.alpha as u64);
+        }
+        self.mds_light(state);
+    }
+
+    fn internal_round(&self, state: &mut [F], round_constant: F) {
+        state[0] = (state[0] + round_constant).pow(self.alpha as u64);
+        let sum = state
+            .iter()
+            .fold(F::zero(), |acc, s| acc + *s);
+        match self.width {
+            // [2, 1], [1, 3]
+            2 => {
+                state[0] = state[0] + sum;
+                state[1] = state[1] + state[1] + sum;
+            }
+            // [2, 1, 1], [1, 2, 1], [1, 1, 3]
+            3 => {
+                state[0] = state[0] + sum;
+                state[1] = state[1] + sum;
+                state[2] = state[2] + state[2] + sum;
+            }
+            _ => {
+                for (s, diag) in state
+                    .iter_mut()
+                    .zip(&self.internal_matrix_diag)
+                {
+                    *s = *s * *diag + sum;
+                    if let DiffusionStrategy::Montgomery = self.diffusion {
+                        *s = s.from_montgomery();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Applies the permutation to a full state of `width` elements.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), self.width, "state len must be equal to the width");
+
+        let (first_external, rest) = self
+            .round_constants
+            .split_at(self.external_rounds / 2 * self.width);
+        let (internal, last_external) = rest.split_at(self.internal_rounds);
+
+        self.mds_light(state);
+        for round_constants in first_external.chunks(self.width) {
+            self.external_round(state, round_constants);
+        }
+        for round_constant in internal {
+            self.internal_round(state, *round_constant);
+        }
+        for round_constants in last_external.chunks(self.width) {
+            self.external_round(state, round_constants);
+        }
+    }
+
+    /// Hashes a single preimage of at most `rate` elements the way `hash_many` does for each state:
+    /// the input is zero-padded to the width and the output is read from the start of the state.
+    pub fn hash(&self, input: &[F], output: &mut [F]) {
+        assert!(input.len() <= self.rate, "input len must not exceed the rate");
+        assert!(output.len() <= self.width, "output len must not exceed the width");
+
+        let mut state = vec![F::zero(); self.width];
+        state[..input.len()].copy_from_slice(input);
+        self.permute(&mut state);
+        output.copy_from_slice(&state[..output.len()]);
+    }
+}
+
+impl<F: FieldArithmetic> SpongeHash<F, F> for HostPoseidon2<F> {
+    /// Host sponges have no device handle, so they can't be passed to the device tree builders.
+    fn get_handle(&self) -> *const c_void {
+        std::ptr::null()
+    }
+
+    fn hash_many(
+        &self,
+        inputs: &(impl HostOrDeviceSlice<F> + ?Sized),
+        output: &mut (impl HostOrDeviceSlice<F> + ?Sized),
+        number_of_states: usize,
+        input_block_len: usize,
+        output_len: usize,
+        _cfg: &HashConfig,
+    ) -> IcicleResult<()> {
+        let (inputs, output) =
+            sponge_host_slices(inputs, output, number_of_states, input_block_len, self.rate, output_len)?;
+
+        for i in 0..number_of_states {
+            self.hash(
+                &inputs[i * input_block_len..(i + 1) * input_block_len],
+                &mut output[i * output_len..(i + 1) * output_len],
+            );
+        }
+        Ok(())
+    }
+
+    fn default_config<'a>(&self) -> HashConfig<'a> {
+        HashConfig::default()
+    }
+}
+
+impl<F: FieldArithmetic> Permutation<F> for HostPoseidon2<F> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn permute(&self, state: &mut [F]) -> IcicleResult<()> {
+        check_permutation_state(state, self.width)?;
+        HostPoseidon2::permute(self, state);
+        Ok(())
+    }
+}
@@ -1,16 +1,24 @@
+pub mod constants;
+pub mod host;
 #[doc(hidden)]
 pub mod tests;
 
 use std::{ffi::c_void, marker::PhantomData};
 
-use icicle_cuda_runtime::{device_context::DeviceContext, memory::HostOrDeviceSlice};
+use icicle_cuda_runtime::{
+    device_context::DeviceContext,
+    memory::{HostOrDeviceSlice, HostSlice},
+};
 
 use crate::{
-    error::IcicleResult,
+    error::{IcicleError, IcicleResult},
     hash::{sponge_check_input, sponge_check_outputs, HashConfig, SpongeHash},
+    sponge::{check_permutation_state, Permutation},
     traits::FieldImpl,
 };
 
+use self::constants::Poseidon2Constants;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub enum DiffusionStrategy {
@@ -66,8 +74,8 @@ where
         alpha: u32,
         internal_rounds: u32,
         external_rounds: u32,
-        round_constants: &mut [F],
-        internal_matrix_diag: &mut [F],
+        round_constants: &[F],
+        internal_matrix_diag: &[F],
         mds_type: MdsType,
         diffusion: DiffusionStrategy,
         ctx: &DeviceContext,
@@ -93,6 +101,21 @@ where
             })
         })
     }
+
+    pub fn from_constants(constants: &Poseidon2Constants<F>, rate: usize, ctx: &DeviceContext) -> IcicleResult<Self> {
+        Self::new(
+            constants.width,
+            rate,
+            constants.alpha,
+            constants.internal_rounds,
+            constants.external_rounds,
+            &constants.round_constants,
+            &constants.internal_matrix_diag,
+            MdsType::Default,
+            DiffusionStrategy::Default,
+            ctx,
+        )
+    }
 }
 
 impl<F> SpongeHash<F, F> for Poseidon2<F>
@@ -116,9 +139,7 @@ where
         sponge_check_input(inputs, number_of_states, input_block_len, self.rate, &cfg.ctx);
         sponge_check_outputs(output, number_of_states, output_len, self.width, false, &cfg.ctx);
 
-        let mut local_cfg = cfg.clone();
-        local_cfg.are_inputs_on_device = inputs.is_on_device();
-        local_cfg.are_outputs_on_device = output.is_on_device();
+        let local_cfg = cfg.for_slices(inputs, output);
 
         <<F as FieldImpl>::Config as Poseidon2Impl<F>>::hash_many(
             inputs,
@@ -136,6 +157,38 @@ where
     }
 }
 
+/// A single state is hashed with `hash_many`, so the handle must be created with a rate equal to its width for the
+/// whole state to be taken as input. The kernels zero the capacity part of their states, so handles with a smaller rate
+/// can't permute a sponge state and [permute](Permutation::permute) fails on them. Sponges with a capacity still work
+/// over such a full-rate handle, the rate of the sponge is independent of the one of the handle.
+impl<F> Permutation<F> for Poseidon2<F>
+where
+    F: FieldImpl,
+    <F as FieldImpl>::Config: Poseidon2Impl<F>,
+{
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn permute(&self, state: &mut [F]) -> IcicleResult<()> {
+        if self.rate != self.width {
+            return Err(IcicleError::invalid_argument(
+                "Poseidon2 permutation needs a rate equal to the width",
+            ));
+        }
+        check_permutation_state(state, self.width)?;
+        let input = state.to_vec();
+        self.hash_many(
+            HostSlice::from_slice(&input),
+            HostSlice::from_mut_slice(state),
+            1,
+            self.width,
+            self.width,
+            &self.default_config(),
+        )
+    }
+}
+
 impl<F> Drop for Poseidon2<F>
 where
     F: FieldImpl,
@@ -324,6 +377,21 @@ macro_rules! impl_poseidon2_tests {
         fn test_poseidon2_hash_many() {
             check_poseidon_hash_many::<$field>()
         }
+
+        #[test]
+        fn test_poseidon2_host() {
+            check_poseidon_host::<$field>()
+        }
+
+        #[test]
+        fn test_poseidon2_constants() {
+            check_poseidon2_constants::<$field>()
+        }
+
+        #[test]
+        fn test_poseidon2_duplex_sponge() {
+            check_poseidon2_duplex_sponge::<$field>()
+        }
     };
 }
 
@@ -1,9 +1,10 @@
 use crate::hash::SpongeHash;
-use crate::traits::FieldImpl;
+use crate::sponge::{DuplexSponge, Permutation, SpongePadding};
+use crate::traits::{FieldArithmetic, FieldImpl, GenerateRandom};
 use icicle_cuda_runtime::device_context::DeviceContext;
 use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
 
-use super::{DiffusionStrategy, MdsType, Poseidon2, Poseidon2Impl};
+use super::{constants::Poseidon2Constants, host::HostPoseidon2, DiffusionStrategy, MdsType, Poseidon2, Poseidon2Impl};
 
 pub fn init_poseidon<F: FieldImpl>(width: usize, mds_type: MdsType, diffusion: DiffusionStrategy) -> Poseidon2<F>
 where
@@ -85,3 +86,269 @@ where
         assert_eq!(*val, kats[i % width]);
     }
 }
+
+pub fn check_poseidon_host<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: Poseidon2Impl<F> + GenerateRandom<F>,
+{
+    let test_size = 1 << 8;
+    let (alpha, internal_rounds, external_rounds) = (5, 13, 8);
+    let ctx = DeviceContext::default();
+    for width in [2, 3, 4, 8, 12, 16, 20, 24] {
+        for (mds_type, diffusion) in [
+            (MdsType::Default, DiffusionStrategy::Default),
+            (MdsType::Plonky, DiffusionStrategy::Montgomery),
+        ] {
+            let rate = width / 2 + 1;
+            let round_constants = F::Config::generate_random(width * external_rounds + internal_rounds);
+            let internal_matrix_diag = F::Config::generate_random(width);
+
+            let host_poseidon = HostPoseidon2::<F>::new(
+                width,
+                rate,
+                alpha,
+                internal_rounds as u32,
+                external_rounds as u32,
+                &round_constants,
+                &internal_matrix_diag,
+                mds_type,
+                diffusion,
+            )
+            .unwrap();
+            let poseidon = Poseidon2::<F>::new(
+                width,
+                rate,
+                alpha,
+                internal_rounds as u32,
+                external_rounds as u32,
+                &round_constants,
+                &internal_matrix_diag,
+                mds_type,
+                diffusion,
+                &ctx,
+            )
+            .unwrap();
+
+            let inputs = F::Config::generate_random(test_size * rate);
+            for (input_block_len, output_len) in [(rate, width), (rate - 1, 1)] {
+                let mut outputs = vec![F::zero(); test_size * output_len];
+                let mut host_outputs = vec![F::zero(); test_size * output_len];
+
+                let cfg = poseidon.default_config();
+                poseidon
+                    .hash_many(
+                        HostSlice::from_slice(&inputs),
+                        HostSlice::from_mut_slice(&mut outputs),
+                        test_size,
+                        input_block_len,
+                        output_len,
+                        &cfg,
+                    )
+                    .unwrap();
+                host_poseidon
+                    .hash_many(
+                        HostSlice::from_slice(&inputs),
+                        HostSlice::from_mut_slice(&mut host_outputs),
+                        test_size,
+                        input_block_len,
+                        output_len,
+                        &cfg,
+                    )
+                    .unwrap();
+
+                assert_eq!(outputs, host_outputs);
+            }
+        }
+    }
+}
+
+pub fn check_poseidon2_constants<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: Poseidon2Impl<F> + GenerateRandom<F>,
+{
+    let test_size = 1 << 4;
+    let ctx = DeviceContext::default();
+    for width in [2, 3, 4] {
+        // x^5 is not a permutation of every field
+        let constants = [5, 7, 11, 13, 17]
+            .into_iter()
+            .find_map(|alpha| Poseidon2Constants::<F>::generate(width, alpha, 128).ok())
+            .unwrap();
+        assert_eq!(
+            constants
+                .round_constants
+                .len(),
+            width * constants.external_rounds as usize + constants.internal_rounds as usize
+        );
+        assert_eq!(
+            constants
+                .internal_matrix_diag
+                .len(),
+            width
+        );
+
+        let rate = width / 2 + 1;
+        let host_poseidon = HostPoseidon2::from_constants(&constants, rate).unwrap();
+        let poseidon = Poseidon2::from_constants(&constants, rate, &ctx).unwrap();
+
+        let inputs = F::Config::generate_random(test_size * rate);
+        let mut outputs = vec![F::zero(); test_size];
+        let mut host_outputs = vec![F::zero(); test_size];
+        let cfg = poseidon.default_config();
+        poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut outputs),
+                test_size,
+                rate,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        host_poseidon
+            .hash_many(
+                HostSlice::from_slice(&inputs),
+                HostSlice::from_mut_slice(&mut host_outputs),
+                test_size,
+                rate,
+                1,
+                &cfg,
+            )
+            .unwrap();
+        assert_eq!(outputs, host_outputs);
+    }
+}
+
+pub fn check_poseidon2_duplex_sponge<F: FieldArithmetic>()
+where
+    <F as FieldImpl>::Config: Poseidon2Impl<F>,
+{
+    let (width, rate) = (3, 2);
+    let constants = [5, 7, 11, 13, 17]
+        .into_iter()
+        .find_map(|alpha| Poseidon2Constants::<F>::generate(width, alpha, 128).ok())
+        .unwrap();
+    let host_poseidon = HostPoseidon2::from_constants(&constants, width).unwrap();
+    let domain_separator = F::from_u32(7);
+    let input: Vec<F> = (1..6)
+        .map(F::from_u32)
+        .collect();
+
+    let mut sponge = DuplexSponge::new(&host_poseidon, rate, SpongePadding::Pad10, domain_separator).unwrap();
+    sponge
+        .absorb(&input)
+        .unwrap();
+    let output = sponge
+        .squeeze(3)
+        .unwrap();
+
+    // Five elements and the padding make three blocks, the outputs span two
+    let mut state = vec![F::zero(); width];
+    state[rate] = domain_separator;
+    let mut padded = input.clone();
+    padded.push(F::one());
+    for block in padded.chunks(rate) {
+        for (s, x) in state
+            .iter_mut()
+            .zip(block)
+        {
+            *s = *s + *x;
+        }
+        host_poseidon.permute(&mut state);
+    }
+    let mut expected = state[..rate].to_vec();
+    host_poseidon.permute(&mut state);
+    expected.push(state[0]);
+    assert_eq!(output, expected);
+    assert_eq!(sponge.state(), state);
+    assert!(sponge.is_squeezing());
+    assert_eq!(sponge.position(), 1);
+
+    // Absorbing and squeezing in pieces doesn't change the outputs, and clones carry on independently
+    let mut sponge = DuplexSponge::new(&host_poseidon, rate, SpongePadding::Pad10, domain_separator).unwrap();
+    sponge
+        .absorb(&input[..1])
+        .unwrap();
+    sponge
+        .absorb(&input[1..])
+        .unwrap();
+    let mut cloned = sponge.clone();
+    assert_eq!(
+        sponge
+            .squeeze(1)
+            .unwrap(),
+        output[..1]
+    );
+    assert_eq!(
+        sponge
+            .squeeze(2)
+            .unwrap(),
+        output[1..]
+    );
+    assert_eq!(
+        cloned
+            .squeeze(3)
+            .unwrap(),
+        output
+    );
+
+    let squeeze_after = |input: &[F], padding: SpongePadding, domain_separator: F| {
+        let mut sponge = DuplexSponge::new(&host_poseidon, rate, padding, domain_separator).unwrap();
+        sponge
+            .absorb(input)
+            .unwrap();
+        sponge
+            .squeeze(1)
+            .unwrap()
+    };
+    let mut zero_extended = input.clone();
+    zero_extended.push(F::zero());
+    assert_ne!(
+        squeeze_after(&input, SpongePadding::Pad10, domain_separator),
+        squeeze_after(&zero_extended, SpongePadding::Pad10, domain_separator)
+    );
+    assert_eq!(
+        squeeze_after(&input, SpongePadding::None, domain_separator),
+        squeeze_after(&zero_extended, SpongePadding::None, domain_separator)
+    );
+    assert_ne!(
+        squeeze_after(&input, SpongePadding::None, domain_separator),
+        squeeze_after(&input, SpongePadding::None, F::zero())
+    );
+    assert!(DuplexSponge::new(&host_poseidon, width, SpongePadding::Pad10, domain_separator).is_err());
+
+    // The device permutation drives the same sponge
+    let ctx = DeviceContext::default();
+    let poseidon = Poseidon2::from_constants(&constants, width, &ctx).unwrap();
+    let mut host_sponge = DuplexSponge::new(&host_poseidon, rate, SpongePadding::Pad10, domain_separator).unwrap();
+    let mut sponge = DuplexSponge::new(&poseidon, rate, SpongePadding::Pad10, domain_separator).unwrap();
+    fn interleave<F: FieldArithmetic, P: Permutation<F>>(sponge: &mut DuplexSponge<F, P>, input: &[F]) {
+        sponge
+            .absorb(input)
+            .unwrap();
+        sponge
+            .squeeze(3)
+            .unwrap();
+        sponge
+            .absorb(&input[..3])
+            .unwrap();
+    }
+    interleave(&mut sponge, &input);
+    interleave(&mut host_sponge, &input);
+    assert_eq!(
+        sponge
+            .squeeze(4)
+            .unwrap(),
+        host_sponge
+            .squeeze(4)
+            .unwrap()
+    );
+    assert_eq!(sponge.state(), host_sponge.state());
+
+    // Handles whose kernels zero part of the state and states of the wrong length are rejected
+    let partial_rate = Poseidon2::from_constants(&constants, rate, &ctx).unwrap();
+    let mut state = vec![F::one(); width];
+    assert!(Permutation::permute(&partial_rate, &mut state).is_err());
+    assert!(Permutation::permute(&poseidon, &mut state[..rate]).is_err());
+    assert!(Permutation::permute(&host_poseidon, &mut state[..rate]).is_err());
+}
@@ -0,0 +1,177 @@
+use crate::{
+    error::{IcicleError, IcicleResult},
+    traits::FieldArithmetic,
+};
+
+/// A permutation of a state of `width` field elements, the building block of [DuplexSponge].
+///
+/// It's implemented by the host sponges [HostPoseidon](crate::poseidon::host::HostPoseidon) and
+/// [HostPoseidon2](crate::poseidon2::host::HostPoseidon2), which take any rate and capacity, and by device
+/// [Poseidon2](crate::poseidon2::Poseidon2) handles created with a rate equal to their width. Device
+/// [Poseidon](crate::poseidon::Poseidon) handles can't permute an arbitrary state, their kernels put the domain tag
+/// in the first element, so sponges over Poseidon run on the host.
+pub trait Permutation<F> {
+    fn width(&self) -> usize;
+
+    /// Permutes `state` in place, it must have `width` elements.
+    fn permute(&self, state: &mut [F]) -> IcicleResult<()>;
+}
+
+pub(crate) fn check_permutation_state<F>(state: &[F], width: usize) -> IcicleResult<()> {
+    if state.len() != width {
+        return Err(IcicleError::invalid_argument(
+            "Permutation state length must be equal to the width",
+        ));
+    }
+    Ok(())
+}
+
+/// How the absorbed input is padded before the first squeeze.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpongePadding {
+    /// The last block is implicitly padded with zeros. Only sound for inputs of a fixed length, or when the length is
+    /// bound by the domain separator.
+    None,
+    /// A one is appended to the input, followed by zeros up to the end of the block.
+    Pad10,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpongeMode {
+    Absorbing,
+    Squeezing,
+}
+
+/// Stateful sponge in duplex mode: inputs of any length can be absorbed and any number of elements can be squeezed,
+/// in any order.
+///
+/// The first `rate` elements of the state are the outer part that inputs are added to and outputs are read from, the
+/// remaining `capacity` elements are never exposed. The domain separator is put in the first capacity element before
+/// anything is absorbed.
+pub struct DuplexSponge<'a, F, P: Permutation<F>> {
+    permutation: &'a P,
+    rate: usize,
+    padding: SpongePadding,
+    state: Vec<F>,
+    mode: SpongeMode,
+    // Next element of the outer part to be absorbed into or squeezed from
+    position: usize,
+}
+
+impl<'a, F, P> DuplexSponge<'a, F, P>
+where
+    F: FieldArithmetic,
+    P: Permutation<F>,
+{
+    pub fn new(permutation: &'a P, rate: usize, padding: SpongePadding, domain_separator: F) -> IcicleResult<Self> {
+        let width = permutation.width();
+        if rate == 0 || rate >= width {
+            return Err(IcicleError::invalid_argument(
+                "Sponge rate must be positive and leave at least one element of capacity",
+            ));
+        }
+
+        let mut state = vec![F::zero(); width];
+        state[rate] = domain_separator;
+        Ok(Self {
+            permutation,
+            rate,
+            padding,
+            state,
+            mode: SpongeMode::Absorbing,
+            position: 0,
+        })
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.state
+            .len()
+            - self.rate
+    }
+
+    /// The current state, rate part first. Inputs absorbed since the last permutation are already added to it.
+    pub fn state(&self) -> &[F] {
+        &self.state
+    }
+
+    /// Whether the sponge is squeezing, in which case the next absorbed element starts a new block.
+    pub fn is_squeezing(&self) -> bool {
+        self.mode == SpongeMode::Squeezing
+    }
+
+    /// Position in the rate part of the next element to be absorbed or squeezed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Adds the input to the rate part of the state, permuting it whenever a block is full. The permutation of the
+    /// last block is deferred until more input comes or output is squeezed.
+    pub fn absorb(&mut self, input: &[F]) -> IcicleResult<()> {
+        if self.mode == SpongeMode::Squeezing {
+            self.mode = SpongeMode::Absorbing;
+            self.position = 0;
+        }
+        for element in input {
+            if self.position == self.rate {
+                self.permute()?;
+            }
+            self.state[self.position] = self.state[self.position] + *element;
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    /// Squeezes `output.len()` elements out of the sponge, padding and permuting the absorbed input first.
+    pub fn squeeze_into(&mut self, output: &mut [F]) -> IcicleResult<()> {
+        if self.mode == SpongeMode::Absorbing {
+            if self.padding == SpongePadding::Pad10 {
+                if self.position == self.rate {
+                    self.permute()?;
+                }
+                self.state[self.position] = self.state[self.position] + F::one();
+            }
+            self.permute()?;
+            self.mode = SpongeMode::Squeezing;
+        }
+        for element in output.iter_mut() {
+            if self.position == self.rate {
+                self.permute()?;
+            }
+            *element = self.state[self.position];
+            self.position += 1;
+        }
+        Ok(())
+    }
+
+    pub fn squeeze(&mut self, len: usize) -> IcicleResult<Vec<F>> {
+        let mut output = vec![F::zero(); len];
+        self.squeeze_into(&mut output)?;
+        Ok(output)
+    }
+
+    fn permute(&mut self) -> IcicleResult<()> {
+        self.permutation
+            .permute(&mut self.state)?;
+        self.position = 0;
+        Ok(())
+    }
+}
+
+impl<'a, F: Clone, P: Permutation<F>> Clone for DuplexSponge<'a, F, P> {
+    fn clone(&self) -> Self {
+        Self {
+            permutation: self.permutation,
+            rate: self.rate,
+            padding: self.padding,
+            state: self
+                .state
+                .clone(),
+            mode: self.mode,
+            position: self.position,
+        }
+    }
+}
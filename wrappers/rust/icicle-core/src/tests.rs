@@ -3,7 +3,7 @@ use crate::traits::ArkConvertible;
 use crate::{
     curve::{Affine, Curve, Projective},
     field::Field,
-    traits::{FieldConfig, FieldImpl, GenerateRandom, MontgomeryConvertible},
+    traits::{FieldArithmetic, FieldConfig, FieldImpl, GenerateRandom, MontgomeryConvertible},
 };
 #[cfg(feature = "arkworks")]
 use ark_ec::short_weierstrass::{Affine as ArkAffine, Projective as ArkProjective};
@@ -21,6 +21,71 @@ pub fn check_field_equality<F: FieldImpl>() {
     assert_eq!(left, right);
 }
 
+pub fn check_field_arithmetic<F: FieldArithmetic>()
+where
+    F::Config: GenerateRandom<F>,
+{
+    let size = 1 << 10;
+    let a = F::Config::generate_random(size);
+    let b = F::Config::generate_random(size);
+    let c = F::Config::generate_random(size);
+    for ((&a, &b), &c) in a
+        .iter()
+        .zip(b.iter())
+        .zip(c.iter())
+    {
+        assert_eq!(a + b - b, a);
+        assert_eq!(a - b + b, a);
+        assert_eq!(a + (-a), F::zero());
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * (b + c), a * b + a * c);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * F::one(), a);
+        assert_eq!(a.sqr(), a * a);
+        assert_eq!(a.pow(5), a.sqr() * a.sqr() * a);
+        assert_eq!(
+            a * a
+                .inv()
+                .unwrap(),
+            F::one()
+        );
+    }
+    assert_eq!(F::zero().inv(), None);
+    assert_eq!(-F::zero(), F::zero());
+    assert_eq!(F::from_u32(2) * F::from_u32(3), F::from_u32(6));
+    assert_eq!(F::from_u32(2).pow(10), F::from_u32(1024));
+}
+
+pub fn check_field_montgomery_host<F>()
+where
+    F: FieldArithmetic + MontgomeryConvertible<'static>,
+    F::Config: GenerateRandom<F>,
+{
+    let size = 1 << 10;
+    let scalars = F::Config::generate_random(size);
+    let device_ctx = DeviceContext::default();
+
+    let mut d_scalars = DeviceVec::cuda_malloc(size).unwrap();
+    d_scalars
+        .copy_from_host(HostSlice::from_slice(&scalars))
+        .unwrap();
+    F::to_mont(&mut d_scalars, &device_ctx)
+        .wrap()
+        .unwrap();
+    let mut mont_scalars = vec![F::zero(); size];
+    d_scalars
+        .copy_to_host(HostSlice::from_mut_slice(&mut mont_scalars))
+        .unwrap();
+
+    for (scalar, mont_scalar) in scalars
+        .iter()
+        .zip(mont_scalars.iter())
+    {
+        assert_eq!(scalar.to_montgomery(), *mont_scalar);
+        assert_eq!(mont_scalar.from_montgomery(), *scalar);
+    }
+}
+
 pub fn check_affine_projective_convert<C: Curve>() {
     let size = 1 << 10;
     let affine_points = C::generate_random_affine_points(size);
@@ -37,18 +102,35 @@ pub fn check_affine_projective_convert<C: Curve>() {
 
 pub fn check_point_equality<const BASE_LIMBS: usize, F: FieldConfig, C>()
 where
-    C: Curve<BaseField = Field<BASE_LIMBS, F>>,
+    C: Curve,
+    C::BaseField: FieldImpl<Config = F>,
 {
+    // `BASE_LIMBS` counts the limbs of every coordinate of extension fields, like the ones of G2
+    let from_limbs = |limbs: [u32; BASE_LIMBS]| {
+        let bytes: Vec<u8> = limbs
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        C::BaseField::from_bytes_le(&bytes)
+    };
+    let point = |x, y, z| Projective::<C> {
+        x: from_limbs(x),
+        y: from_limbs(y),
+        z: from_limbs(z),
+    };
     let left = Projective::<C>::zero();
     let right = Projective::<C>::zero();
     assert_eq!(left, right);
-    let right = Projective::<C>::from_limbs([0; BASE_LIMBS], [2; BASE_LIMBS], [0; BASE_LIMBS]);
+    let right = point([0; BASE_LIMBS], [2; BASE_LIMBS], [0; BASE_LIMBS]);
     assert_eq!(left, right);
     let mut z = [0; BASE_LIMBS];
     z[0] = 2;
-    let right = Projective::<C>::from_limbs([0; BASE_LIMBS], [4; BASE_LIMBS], z);
+    let right = point([0; BASE_LIMBS], [4; BASE_LIMBS], z);
     assert_ne!(left, right);
-    let left = Projective::<C>::from_limbs([0; BASE_LIMBS], [2; BASE_LIMBS], C::BaseField::one().into());
+    let left = Projective::<C> {
+        z: C::BaseField::one(),
+        ..point([0; BASE_LIMBS], [2; BASE_LIMBS], [0; BASE_LIMBS])
+    };
     assert_eq!(left, right);
 }
 
@@ -5,6 +5,7 @@ use icicle_cuda_runtime::{device_context::DeviceContext, error::CudaError, memor
 use std::{
     fmt::{Debug, Display},
     mem::MaybeUninit,
+    ops::{Add, Mul, Neg, Sub},
 };
 
 #[doc(hidden)]
@@ -18,6 +19,26 @@ pub trait FieldConfig: Debug + PartialEq + Copy + Clone {
     type ArkField: ArkField;
 }
 
+/// Parameters of a prime field that are needed for arithmetic on the host.
+#[doc(hidden)]
+pub trait PrimeFieldConfig: FieldConfig {
+    /// The field modulus as little-endian 32-bit limbs, the same limbs as in the C++ field config.
+    const MODULUS: &'static [u32];
+    /// Whether the device keeps elements in Montgomery form with `R = 2^(32 * limbs)`.
+    /// Fields like M31 reduce directly and have no Montgomery form.
+    const MONTGOMERY_FORM: bool = true;
+}
+
+/// Prime fields with a quadratic extension `Fp2 = Fp[u] / (u² - β)`, the base field of G2 on pairing-friendly curves.
+#[doc(hidden)]
+pub trait Fp2Config: PrimeFieldConfig {
+    /// `β`, a quadratic non-residue small enough for an `i32`, like `-1` for BN254 and BLS12-381.
+    const NON_RESIDUE: i32;
+    /// The arkworks config of the extension, for conversions through `Fq2`.
+    #[cfg(feature = "arkworks")]
+    type ArkFp2Config: ark_ff::Fp2Config<Fp = Self::ArkField>;
+}
+
 pub trait FieldImpl:
     Display + Debug + PartialEq + Copy + Clone + Into<Self::Repr> + From<Self::Repr> + Send + Sync
 {
@@ -33,6 +54,24 @@ pub trait FieldImpl:
     fn from_u32(val: u32) -> Self;
 }
 
+/// Host-side arithmetic for prime fields and their quadratic extensions.
+///
+/// Every operation expects canonical inputs (smaller than the modulus) and returns canonical outputs.
+pub trait FieldArithmetic:
+    FieldImpl + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn sqr(&self) -> Self;
+    fn pow(&self, exp: u64) -> Self;
+    /// Returns `None` for zero.
+    fn inv(&self) -> Option<Self>;
+    /// Host version of the device `to_montgomery`, identity for fields without Montgomery form.
+    fn to_montgomery(&self) -> Self;
+    /// Host version of the device `from_montgomery`, identity for fields without Montgomery form.
+    fn from_montgomery(&self) -> Self;
+    /// The modulus of the prime field as little-endian bytes, which is the characteristic for extensions.
+    fn modulus_bytes_le() -> Vec<u8>;
+}
+
 #[cfg(feature = "arkworks")]
 pub trait ArkConvertible {
     type ArkEquivalent;
@@ -0,0 +1,160 @@
+#[doc(hidden)]
+pub mod tests;
+
+use crate::{
+    curve::{Affine, Curve},
+    error::{IcicleError, IcicleResult},
+    poseidon::grain::Modulus,
+    sponge::{DuplexSponge, Permutation, SpongePadding},
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+/// Fiat-Shamir transcript: the prover and the verifier append the same labelled messages and draw the same challenges.
+///
+/// Labels separate the messages of a protocol from each other, every message and challenge goes with one.
+pub trait Transcript<F: FieldImpl> {
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) -> IcicleResult<()>;
+
+    fn append_scalar(&mut self, label: &[u8], scalar: &F) -> IcicleResult<()>;
+
+    /// Appends the point in its canonical encoding, see [Affine::to_bytes_le].
+    fn append_point<C: Curve>(&mut self, label: &[u8], point: &Affine<C>) -> IcicleResult<()> {
+        self.append_bytes(label, &point.to_bytes_le())
+    }
+
+    fn challenge_scalars(&mut self, label: &[u8], len: usize) -> IcicleResult<Vec<F>>;
+
+    fn challenge_scalar(&mut self, label: &[u8]) -> IcicleResult<F> {
+        Ok(self.challenge_scalars(label, 1)?[0])
+    }
+
+    /// Draws a challenge in an extension of `F` whose elements are encoded as their coefficients over `F`, like the
+    /// extension fields of the small fields in this repo. Each coefficient is a challenge scalar.
+    fn challenge_ext<E: FieldImpl>(&mut self, label: &[u8]) -> IcicleResult<E> {
+        let base_len = F::zero()
+            .to_bytes_le()
+            .len();
+        let ext_len = E::zero()
+            .to_bytes_le()
+            .len();
+        if ext_len < base_len || ext_len % base_len != 0 {
+            return Err(IcicleError::invalid_argument(
+                "Extension field elements must be made of base field elements",
+            ));
+        }
+
+        let coefficients: Vec<u8> = self
+            .challenge_scalars(label, ext_len / base_len)?
+            .iter()
+            .flat_map(|coefficient| coefficient.to_bytes_le())
+            .collect();
+        Ok(E::from_bytes_le(&coefficients))
+    }
+}
+
+/// Reduces a little-endian integer of any size modulo the field order. Byte-oriented transcripts draw twice as many
+/// bytes as the field size so that the result is close to uniform.
+pub fn reduce_bytes_le<F: FieldArithmetic>(bytes: &[u8]) -> F {
+    let base = F::from_u32(256);
+    bytes
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, byte| acc * base + F::from_u32(*byte as u32))
+}
+
+/// Field-native transcript over a duplex sponge, typically with a Poseidon2 permutation.
+///
+/// Labels and byte messages are absorbed as their length followed by their bytes, packed into field elements a few
+/// bytes short of the field size. Scalars are absorbed as they are and challenges are squeezed.
+pub struct SpongeTranscript<'a, F, P: Permutation<F>> {
+    sponge: DuplexSponge<'a, F, P>,
+    // Bytes packed in each absorbed element
+    chunk_len: usize,
+}
+
+impl<'a, F, P> SpongeTranscript<'a, F, P>
+where
+    F: FieldArithmetic,
+    P: Permutation<F>,
+{
+    pub fn new(permutation: &'a P, rate: usize, domain_label: &[u8]) -> IcicleResult<Self> {
+        let chunk_len = (Modulus::of::<F>().bits() as usize - 1) / 8;
+        if chunk_len == 0 {
+            return Err(IcicleError::invalid_argument("Field is too small to pack bytes"));
+        }
+
+        let mut transcript = Self {
+            sponge: DuplexSponge::new(permutation, rate, SpongePadding::Pad10, F::zero())?,
+            chunk_len,
+        };
+        transcript.absorb_bytes(domain_label)?;
+        Ok(transcript)
+    }
+
+    /// The underlying sponge, to cross-check its state with other implementations.
+    pub fn sponge(&self) -> &DuplexSponge<'a, F, P> {
+        &self.sponge
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) -> IcicleResult<()> {
+        let mut elements = vec![F::from_u32(bytes.len() as u32)];
+        elements.extend(
+            bytes
+                .chunks(self.chunk_len)
+                .map(F::from_bytes_le),
+        );
+        self.sponge
+            .absorb(&elements)
+    }
+}
+
+impl<'a, F, P> Transcript<F> for SpongeTranscript<'a, F, P>
+where
+    F: FieldArithmetic,
+    P: Permutation<F>,
+{
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) -> IcicleResult<()> {
+        self.absorb_bytes(label)?;
+        self.absorb_bytes(bytes)
+    }
+
+    fn append_scalar(&mut self, label: &[u8], scalar: &F) -> IcicleResult<()> {
+        self.absorb_bytes(label)?;
+        self.sponge
+            .absorb(&[*scalar])
+    }
+
+    fn challenge_scalars(&mut self, label: &[u8], len: usize) -> IcicleResult<Vec<F>> {
+        self.absorb_bytes(label)?;
+        self.sponge
+            .squeeze(len)
+    }
+}
+
+impl<'a, F: Clone, P: Permutation<F>> Clone for SpongeTranscript<'a, F, P> {
+    fn clone(&self) -> Self {
+        Self {
+            sponge: self
+                .sponge
+                .clone(),
+            chunk_len: self.chunk_len,
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! impl_transcript_tests {
+    (
+      $field:ident
+    ) => {
+        #[test]
+        fn test_sponge_transcript() {
+            check_sponge_transcript::<$field>()
+        }
+
+        #[test]
+        fn test_reduce_bytes_le() {
+            check_reduce_bytes_le::<$field>()
+        }
+    };
+}
@@ -0,0 +1,90 @@
+use crate::poseidon2::{constants::Poseidon2Constants, host::HostPoseidon2};
+use crate::traits::FieldArithmetic;
+
+use super::{reduce_bytes_le, SpongeTranscript, Transcript};
+
+pub fn check_sponge_transcript<F: FieldArithmetic>() {
+    let (width, rate) = (3, 2);
+    let constants = [5, 7, 11, 13, 17]
+        .into_iter()
+        .find_map(|alpha| Poseidon2Constants::<F>::generate(width, alpha, 128).ok())
+        .unwrap();
+    let poseidon = HostPoseidon2::from_constants(&constants, width).unwrap();
+    let new_transcript = || SpongeTranscript::new(&poseidon, rate, b"icicle transcript test").unwrap();
+    let run = |transcript: &mut SpongeTranscript<F, HostPoseidon2<F>>, label: &[u8], message: &[u8]| {
+        transcript
+            .append_bytes(label, message)
+            .unwrap();
+        transcript
+            .append_scalar(b"scalar", &F::from_u32(42))
+            .unwrap();
+        transcript
+            .challenge_scalar(b"challenge")
+            .unwrap()
+    };
+
+    let mut transcript = new_transcript();
+    let challenge = run(&mut transcript, b"message", b"hello");
+    let mut cloned = transcript.clone();
+    assert_eq!(challenge, run(&mut new_transcript(), b"message", b"hello"));
+    assert_ne!(challenge, run(&mut new_transcript(), b"messag", b"ehello"));
+    assert_ne!(challenge, run(&mut new_transcript(), b"message", b"hello\0"));
+    assert_ne!(
+        challenge,
+        run(
+            &mut SpongeTranscript::new(&poseidon, rate, b"another protocol").unwrap(),
+            b"message",
+            b"hello"
+        )
+    );
+
+    // Successive challenges differ and clones keep up with the original
+    let challenges = transcript
+        .challenge_scalars(b"challenge", 3)
+        .unwrap();
+    assert_ne!(challenges[0], challenge);
+    assert_ne!(challenges[0], challenges[1]);
+    assert_eq!(
+        challenges,
+        cloned
+            .challenge_scalars(b"challenge", 3)
+            .unwrap()
+    );
+
+    // An extension of degree one is the base field itself
+    assert_eq!(
+        transcript
+            .challenge_ext::<F>(b"ext")
+            .unwrap(),
+        cloned
+            .challenge_scalar(b"ext")
+            .unwrap()
+    );
+    assert_eq!(
+        transcript
+            .sponge()
+            .state(),
+        cloned
+            .sponge()
+            .state()
+    );
+}
+
+pub fn check_reduce_bytes_le<F: FieldArithmetic>() {
+    let modulus = F::modulus_bytes_le();
+    assert_eq!(reduce_bytes_le::<F>(&modulus), F::zero());
+    assert_eq!(reduce_bytes_le::<F>(&[7]), F::from_u32(7));
+
+    // Multiples of the modulus vanish, whatever the length
+    let low = [0xab; 8];
+    let high = [0xcd; 8];
+    let zeros = vec![0; modulus.len()];
+    assert_eq!(
+        reduce_bytes_le::<F>(&[modulus.as_slice(), &low].concat()),
+        reduce_bytes_le::<F>(&[zeros.as_slice(), &low].concat())
+    );
+    assert_eq!(
+        reduce_bytes_le::<F>(&[low, high].concat()),
+        reduce_bytes_le::<F>(&low) + reduce_bytes_le::<F>(&high) * F::from_u32(256).pow(8)
+    );
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeSet;
+
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    hash::SpongeHash,
+    traits::FieldImpl,
+};
+
+use super::{merkle_tree_digests_len, TreeBuilderConfig};
+
+/// Merkle tree kept on the host that can be updated without rebuilding it.
+///
+/// The tree is the one [build_merkle_tree](super::FieldTreeBuilder::build_merkle_tree) computes with all rows kept:
+/// each leaf of `input_block_len` elements is hashed by the sponge into `digest_elements` elements, each node
+/// compresses the digests of its `arity` children, and the digests are stored row by row from the leaves up to the
+/// root. A tree with fewer leaves than `arity^height` is padded with zero leaves, which [append](Self::append) fills.
+///
+/// Updates only recompute the ancestors of the changed leaves: one `hash_many` call for the leaves and one per level
+/// for all the dirty nodes of that level.
+pub struct MerkleTree<'a, F, Sponge, Compression> {
+    sponge: &'a Sponge,
+    compression: &'a Compression,
+    height: usize,
+    arity: usize,
+    digest_elements: usize,
+    input_block_len: usize,
+    // Number of leaves that are not padding
+    len: usize,
+    digests: Vec<F>,
+    // Start of each row in the digests, leaves first
+    row_offsets: Vec<usize>,
+}
+
+impl<'a, F, Sponge, Compression> MerkleTree<'a, F, Sponge, Compression>
+where
+    F: FieldImpl,
+    Sponge: SpongeHash<F, F>,
+    Compression: SpongeHash<F, F>,
+{
+    /// Builds a tree of height `height` over the leaves, which may be fewer than `arity^height`. Only the arity and
+    /// the digest elements of the config are used, all rows are kept.
+    pub fn new(
+        leaves: &(impl HostOrDeviceSlice<F> + ?Sized),
+        height: usize,
+        input_block_len: usize,
+        sponge: &'a Sponge,
+        compression: &'a Compression,
+        config: &TreeBuilderConfig,
+    ) -> IcicleResult<Self> {
+        let mut tree = Self::empty(height, input_block_len, sponge, compression, config)?;
+        let number_of_leaves = tree.check_appended(leaves)?;
+
+        let mut leaf_digests = tree.hash_leaves(leaves, number_of_leaves)?;
+        if number_of_leaves < tree.capacity() {
+            let zero_leaf = vec![F::zero(); input_block_len];
+            let zero_digest = tree.hash_leaves(HostSlice::from_slice(&zero_leaf), 1)?;
+            for _ in number_of_leaves..tree.capacity() {
+                leaf_digests.extend_from_slice(&zero_digest);
+            }
+        }
+        tree.digests[..leaf_digests.len()].copy_from_slice(&leaf_digests);
+        tree.len = number_of_leaves;
+
+        let all_leaves: Vec<usize> = (0..tree.capacity()).collect();
+        tree.update_ancestors(&all_leaves)?;
+        Ok(tree)
+    }
+
+    /// Wraps the digests of a tree built by [build_merkle_tree](super::FieldTreeBuilder::build_merkle_tree) with
+    /// `keep_rows` set to 0, of which the first `number_of_leaves` leaves are not padding.
+    pub fn from_digests(
+        digests: Vec<F>,
+        number_of_leaves: usize,
+        height: usize,
+        input_block_len: usize,
+        sponge: &'a Sponge,
+        compression: &'a Compression,
+        config: &TreeBuilderConfig,
+    ) -> IcicleResult<Self> {
+        let mut tree = Self::empty(height, input_block_len, sponge, compression, config)?;
+        if digests.len()
+            != tree
+                .digests
+                .len()
+            || number_of_leaves > tree.capacity()
+        {
+            return Err(IcicleError::invalid_argument(
+                "Merkle tree digests don't match the height, arity and digest elements",
+            ));
+        }
+        tree.digests = digests;
+        tree.len = number_of_leaves;
+        Ok(tree)
+    }
+
+    fn empty(
+        height: usize,
+        input_block_len: usize,
+        sponge: &'a Sponge,
+        compression: &'a Compression,
+        config: &TreeBuilderConfig,
+    ) -> IcicleResult<Self> {
+        let (arity, digest_elements) = (config.arity as usize, config.digest_elements as usize);
+        if arity < 2 || digest_elements == 0 || input_block_len == 0 || config.keep_rows != 0 {
+            return Err(IcicleError::invalid_argument(
+                "Incremental Merkle trees need an arity of at least 2, non-empty digests and leaves, and all rows",
+            ));
+        }
+
+        let mut row_offsets = vec![0];
+        let mut row_len = arity.pow(height as u32) * digest_elements;
+        for _ in 0..height {
+            row_offsets.push(row_offsets[row_offsets.len() - 1] + row_len);
+            row_len /= arity;
+        }
+        Ok(Self {
+            sponge,
+            compression,
+            height,
+            arity,
+            digest_elements,
+            input_block_len,
+            len: 0,
+            digests: vec![F::zero(); merkle_tree_digests_len(height as u32, arity as u32, digest_elements as u32)],
+            row_offsets,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of leaves, not counting the padding.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of leaves the tree can hold, `arity^height`.
+    pub fn capacity(&self) -> usize {
+        self.arity
+            .pow(self.height as u32)
+    }
+
+    pub fn root(&self) -> &[F] {
+        &self.digests[self.row_offsets[self.height]..]
+    }
+
+    /// All the digests, in the layout of [build_merkle_tree](super::FieldTreeBuilder::build_merkle_tree).
+    pub fn digests(&self) -> &[F] {
+        &self.digests
+    }
+
+    /// The digests of one row, row 0 being the digests of the leaves and row `height` the root.
+    pub fn row(&self, row: usize) -> &[F] {
+        let end = self
+            .row_offsets
+            .get(row + 1)
+            .copied()
+            .unwrap_or(
+                self.digests
+                    .len(),
+            );
+        &self.digests[self.row_offsets[row]..end]
+    }
+
+    /// Replaces the leaves at `indices` with the consecutive leaves of `new_leaves`. When an index is repeated the
+    /// last leaf wins.
+    pub fn update_leaves(
+        &mut self,
+        indices: &[usize],
+        new_leaves: &(impl HostOrDeviceSlice<F> + ?Sized),
+    ) -> IcicleResult<()> {
+        if new_leaves.len() != indices.len() * self.input_block_len {
+            return Err(IcicleError::invalid_argument(
+                "There must be one new leaf of input_block_len elements per index",
+            ));
+        }
+        if indices
+            .iter()
+            .any(|index| *index >= self.len)
+        {
+            return Err(IcicleError::invalid_argument("Updated leaf index is out of the tree"));
+        }
+        self.set_leaves(indices, new_leaves)
+    }
+
+    /// Fills the next padding leaves with `leaves`.
+    pub fn append(&mut self, leaves: &(impl HostOrDeviceSlice<F> + ?Sized)) -> IcicleResult<()> {
+        let number_of_leaves = self.check_appended(leaves)?;
+        let indices: Vec<usize> = (self.len..self.len + number_of_leaves).collect();
+        self.set_leaves(&indices, leaves)?;
+        self.len += number_of_leaves;
+        Ok(())
+    }
+
+    fn check_appended(&self, leaves: &(impl HostOrDeviceSlice<F> + ?Sized)) -> IcicleResult<usize> {
+        let number_of_leaves = leaves.len() / self.input_block_len;
+        if leaves.len() % self.input_block_len != 0 || self.len + number_of_leaves > self.capacity() {
+            return Err(IcicleError::invalid_argument(
+                "Appended leaves must be made of input_block_len elements and fit in the tree",
+            ));
+        }
+        Ok(number_of_leaves)
+    }
+
+    fn set_leaves(&mut self, indices: &[usize], leaves: &(impl HostOrDeviceSlice<F> + ?Sized)) -> IcicleResult<()> {
+        if indices.is_empty() {
+            return Ok(());
+        }
+        let leaf_digests = self.hash_leaves(leaves, indices.len())?;
+        for (index, digest) in indices
+            .iter()
+            .zip(leaf_digests.chunks(self.digest_elements))
+        {
+            self.digests[index * self.digest_elements..(index + 1) * self.digest_elements].copy_from_slice(digest);
+        }
+        self.update_ancestors(indices)
+    }
+
+    // Recomputes the ancestors of the given leaves level by level
+    fn update_ancestors(&mut self, leaf_indices: &[usize]) -> IcicleResult<()> {
+        let node_len = self.digest_elements;
+        let mut dirty: BTreeSet<usize> = leaf_indices
+            .iter()
+            .copied()
+            .collect();
+        for row in 0..self.height {
+            let parents: Vec<usize> = dirty
+                .iter()
+                .map(|index| index / self.arity)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            // The children of a node are next to each other in their row
+            let children_len = self.arity * node_len;
+            let row_start = self.row_offsets[row];
+            let mut children = Vec::with_capacity(parents.len() * children_len);
+            for parent in &parents {
+                let start = row_start + parent * children_len;
+                children.extend_from_slice(&self.digests[start..start + children_len]);
+            }
+
+            let mut parent_digests = vec![F::zero(); parents.len() * node_len];
+            self.compression
+                .hash_many(
+                    HostSlice::from_slice(&children),
+                    HostSlice::from_mut_slice(&mut parent_digests),
+                    parents.len(),
+                    children_len,
+                    node_len,
+                    &self
+                        .compression
+                        .default_config(),
+                )?;
+
+            let parent_row_start = self.row_offsets[row + 1];
+            for (parent, digest) in parents
+                .iter()
+                .zip(parent_digests.chunks(node_len))
+            {
+                let start = parent_row_start + parent * node_len;
+                self.digests[start..start + node_len].copy_from_slice(digest);
+            }
+            dirty = parents
+                .into_iter()
+                .collect();
+        }
+        Ok(())
+    }
+
+    fn hash_leaves(
+        &self,
+        leaves: &(impl HostOrDeviceSlice<F> + ?Sized),
+        number_of_leaves: usize,
+    ) -> IcicleResult<Vec<F>> {
+        let mut digests = vec![F::zero(); number_of_leaves * self.digest_elements];
+        if number_of_leaves == 0 {
+            return Ok(digests);
+        }
+        self.sponge
+            .hash_many(
+                leaves,
+                HostSlice::from_mut_slice(&mut digests),
+                number_of_leaves,
+                self.input_block_len,
+                self.digest_elements,
+                &self
+                    .sponge
+                    .default_config(),
+            )?;
+        Ok(digests)
+    }
+}
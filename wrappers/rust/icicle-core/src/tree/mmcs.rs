@@ -1,9 +1,12 @@
 use icicle_cuda_runtime::memory::HostSlice;
 
+use crate::error::IcicleError;
+use crate::poseidon2::host::HostPoseidon2;
+use crate::traits::FieldArithmetic;
 use crate::{error::IcicleResult, ntt::FieldImpl};
 use crate::{hash::SpongeHash, Matrix};
 
-use super::TreeBuilderConfig;
+use super::{multiproof::MultiProof, TreeBuilderConfig};
 
 pub trait FieldMmcs<F, Compression, Hasher>
 where
@@ -18,6 +21,471 @@ where
         compression: &Compression,
         config: &TreeBuilderConfig,
     ) -> IcicleResult<()>;
+
+    /// Opens the rows at `indices` of the tallest matrices of a commitment computed by
+    /// [mmcs_commit](Self::mmcs_commit) with all rows kept, see [MmcsMultiProof::new].
+    fn mmcs_open_multi(
+        leaves: &[Matrix],
+        digests: &[F],
+        config: &TreeBuilderConfig,
+        indices: &[usize],
+    ) -> IcicleResult<MmcsMultiProof<F>> {
+        MmcsMultiProof::new(leaves, digests, config, indices)
+    }
+
+    /// Host verification of a proof of [mmcs_open_multi](Self::mmcs_open_multi), see [MmcsMultiProof::verify].
+    fn mmcs_verify_multi(
+        proof: &MmcsMultiProof<F>,
+        root: &[F],
+        dims: &[MatrixDims],
+        hasher: &HostPoseidon2<F>,
+        compression: &HostPoseidon2<F>,
+    ) -> IcicleResult<bool>
+    where
+        F: FieldArithmetic,
+    {
+        proof.verify(root, dims, hasher, compression)
+    }
+}
+
+/// Width and height of a matrix committed by [FieldMmcs::mmcs_commit], which is all a verifier knows of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatrixDims {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl From<&Matrix> for MatrixDims {
+    fn from(matrix: &Matrix) -> Self {
+        Self {
+            width: matrix.width,
+            height: matrix.height,
+        }
+    }
+}
+
+fn malformed<T>() -> IcicleResult<T> {
+    Err(IcicleError::invalid_argument("Malformed MMCS multi-proof"))
+}
+
+// Height of the binary tree over matrices of `dims`, after checking them like `mmcs_commit` does
+fn mmcs_tree_height(dims: &[MatrixDims]) -> IcicleResult<usize> {
+    if dims.is_empty()
+        || dims
+            .iter()
+            .any(|dims| dims.width == 0 || dims.height == 0)
+    {
+        return Err(IcicleError::invalid_argument(
+            "MMCS commits to at least one matrix, and matrices can't be empty",
+        ));
+    }
+    for (i, left) in dims
+        .iter()
+        .enumerate()
+    {
+        if dims[i + 1..]
+            .iter()
+            .any(|right| {
+                left.height
+                    .next_power_of_two()
+                    == right
+                        .height
+                        .next_power_of_two()
+                    && left.height != right.height
+            })
+        {
+            return Err(IcicleError::invalid_argument(
+                "Matrix heights that round up to the same power of two must be equal",
+            ));
+        }
+    }
+    let max_height = dims
+        .iter()
+        .map(|dims| dims.height)
+        .max()
+        .unwrap();
+    Ok(max_height
+        .next_power_of_two()
+        .trailing_zeros() as usize)
+}
+
+fn check_sponges<F: FieldArithmetic>(
+    hasher: &HostPoseidon2<F>,
+    compression: &HostPoseidon2<F>,
+    digest_elements: usize,
+) -> IcicleResult<()> {
+    // Levels without injected matrices read `width` elements of the previous level per parent on the device
+    if digest_elements == 0 || hasher.width() < digest_elements || compression.width() != 2 * digest_elements {
+        return Err(IcicleError::invalid_argument(
+            "MMCS needs a compression of width 2 * digest_elements and a hasher at least as wide",
+        ));
+    }
+    Ok(())
+}
+
+// Matrices whose rows are hashed at `level` of a tree of height `tree_height`, the leaves being level 0
+fn injected_at(dims: &[MatrixDims], tree_height: usize, level: usize) -> Vec<usize> {
+    (0..dims.len())
+        .filter(|matrix| {
+            dims[*matrix]
+                .height
+                .next_power_of_two()
+                == 1 << (tree_height - level)
+        })
+        .collect()
+}
+
+// Rows of `matrix` on the paths of the leaves at sorted `indices`, without the padding rows past its height
+fn opened_rows(indices: &[usize], dims: &[MatrixDims], tree_height: usize, matrix: usize) -> Vec<usize> {
+    let shift = tree_height
+        - dims[matrix]
+            .height
+            .next_power_of_two()
+            .trailing_zeros() as usize;
+    let mut rows: Vec<usize> = indices
+        .iter()
+        .map(|index| index >> shift)
+        .filter(|row| *row < dims[matrix].height)
+        .collect();
+    rows.dedup();
+    rows
+}
+
+fn matrix_values<F>(matrix: &Matrix) -> &[F] {
+    unsafe { std::slice::from_raw_parts(matrix.values as *const F, matrix.width * matrix.height) }
+}
+
+// Hashes rows like the device `absorb_2d_state`: the elements of the rows overwrite the rate part of a zero state,
+// which is permuted whenever it's full and after the last partial block
+fn hash_rows<F: FieldArithmetic>(sponge: &HostPoseidon2<F>, rows: &[&[F]], digest_elements: usize) -> Vec<F> {
+    let mut state = vec![F::zero(); sponge.width()];
+    let mut index = 0;
+    for element in rows
+        .iter()
+        .flat_map(|row| row.iter())
+    {
+        state[index] = *element;
+        index += 1;
+        if index == sponge.rate() {
+            sponge.permute(&mut state);
+            index = 0;
+        }
+    }
+    if index != 0 {
+        sponge.permute(&mut state);
+    }
+    state.truncate(digest_elements);
+    state
+}
+
+// Digest of a parent from the digests of its two children. At levels with injected matrices the compressed digest
+// is permuted again next to the hash of the parent's row of these matrices, or next to zeros past their height.
+fn parent_digest<F: FieldArithmetic>(
+    compression: &HostPoseidon2<F>,
+    children: &[F],
+    digest_elements: usize,
+    injected_rows: Option<Option<Vec<&[F]>>>,
+) -> Vec<F> {
+    let mut state = vec![F::zero(); compression.width()];
+    state[..children.len()].copy_from_slice(children);
+    compression.permute(&mut state);
+    if let Some(rows) = injected_rows {
+        state[digest_elements..].fill(F::zero());
+        if let Some(rows) = rows {
+            let row_hash = hash_rows(compression, &rows, digest_elements);
+            state[digest_elements..2 * digest_elements].copy_from_slice(&row_hash);
+        }
+        compression.permute(&mut state);
+    }
+    state.truncate(digest_elements);
+    state
+}
+
+/// Host version of [FieldMmcs::mmcs_commit] with Poseidon2 sponges, returning the digests of all the rows of the tree
+/// from the leaves up, like `mmcs_commit` with all rows kept.
+///
+/// The rows of the tallest matrices are hashed together by `hasher` into the leaves, rows past their height being zero
+/// digests. Each parent compresses its two children with one permutation of `compression`, and at the levels of the
+/// shorter matrices the result is permuted again with the hash of their rows, absorbed at the rate of `compression`.
+/// Matrices of the same height are absorbed in the order of `leaves`. The device sorts them with an unstable sort, so
+/// device commitments are only reproduced with at most one matrix per height; matrices of one height can be merged
+/// into one whose rows are the concatenations of their rows, which absorbs the same elements.
+pub fn mmcs_commit_host<F: FieldArithmetic>(
+    leaves: &[Matrix],
+    digest_elements: usize,
+    hasher: &HostPoseidon2<F>,
+    compression: &HostPoseidon2<F>,
+) -> IcicleResult<Vec<F>> {
+    let dims: Vec<MatrixDims> = leaves
+        .iter()
+        .map(MatrixDims::from)
+        .collect();
+    let tree_height = mmcs_tree_height(&dims)?;
+    check_sponges(hasher, compression, digest_elements)?;
+    let values: Vec<&[F]> = leaves
+        .iter()
+        .map(matrix_values)
+        .collect();
+    let rows_of = |matrices: &[usize], row: usize| -> Vec<&[F]> {
+        matrices
+            .iter()
+            .map(|matrix| {
+                let width = dims[*matrix].width;
+                &values[*matrix][row * width..(row + 1) * width]
+            })
+            .collect()
+    };
+
+    let tallest = injected_at(&dims, tree_height, 0);
+    let mut layer = vec![F::zero(); digest_elements << tree_height];
+    for row in 0..dims[tallest[0]].height {
+        layer[row * digest_elements..(row + 1) * digest_elements].copy_from_slice(&hash_rows(
+            hasher,
+            &rows_of(&tallest, row),
+            digest_elements,
+        ));
+    }
+    let mut digests = layer.clone();
+    for level in 1..=tree_height {
+        let injected = injected_at(&dims, tree_height, level);
+        layer = layer
+            .chunks(2 * digest_elements)
+            .enumerate()
+            .flat_map(|(node, children)| {
+                let injected_rows =
+                    (!injected.is_empty()).then(|| (node < dims[injected[0]].height).then(|| rows_of(&injected, node)));
+                parent_digest(compression, children, digest_elements, injected_rows)
+            })
+            .collect();
+        digests.extend_from_slice(&layer);
+    }
+    Ok(digests)
+}
+
+/// Opening of the same leaves of all matrices of an MMCS commitment: the rows of the tallest matrices at the opened
+/// indices, the rows of the shorter matrices injected on their paths, and a [MultiProof] of the siblings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MmcsMultiProof<F: FieldImpl> {
+    tree: MultiProof<F>,
+    // Opened rows of each matrix in the order of the commitment, by increasing row index
+    rows: Vec<Vec<F>>,
+}
+
+impl<F: FieldImpl> MmcsMultiProof<F> {
+    /// Opens the rows at `indices` of the tallest of `leaves`, which must be rows of these matrices and not padding.
+    /// `digests` are the digests of [mmcs_commit](FieldMmcs::mmcs_commit) with all rows kept, or of
+    /// [mmcs_commit_host].
+    pub fn new(leaves: &[Matrix], digests: &[F], config: &TreeBuilderConfig, indices: &[usize]) -> IcicleResult<Self> {
+        let dims: Vec<MatrixDims> = leaves
+            .iter()
+            .map(MatrixDims::from)
+            .collect();
+        let tree_height = mmcs_tree_height(&dims)?;
+        if config.arity != 2 {
+            return Err(IcicleError::invalid_argument("MMCS trees are binary"));
+        }
+        let tree = MultiProof::new(digests, tree_height, config, indices)?;
+        let max_height = dims[injected_at(&dims, tree_height, 0)[0]].height;
+        if tree.indices()[tree
+            .indices()
+            .len()
+            - 1]
+            >= max_height
+        {
+            return Err(IcicleError::invalid_argument(
+                "Opened MMCS leaves must be rows of the tallest matrices",
+            ));
+        }
+
+        let rows = leaves
+            .iter()
+            .enumerate()
+            .map(|(matrix, leaf)| {
+                let values = matrix_values::<F>(leaf);
+                opened_rows(tree.indices(), &dims, tree_height, matrix)
+                    .into_iter()
+                    .flat_map(|row| values[row * leaf.width..(row + 1) * leaf.width].to_vec())
+                    .collect()
+            })
+            .collect();
+        Ok(Self { tree, rows })
+    }
+
+    /// The siblings of the opened leaves, whose indices are the rows opened in the tallest matrices.
+    pub fn tree(&self) -> &MultiProof<F> {
+        &self.tree
+    }
+
+    /// Opened rows of the matrix at position `matrix` of the commitment, concatenated by increasing row index. The
+    /// rows of a matrix of padded height `h` on the path of leaf `i` are `i >> (height - log2(h))`, when below its
+    /// height.
+    pub fn rows(&self, matrix: usize) -> &[F] {
+        &self.rows[matrix]
+    }
+
+    /// Serializes the proof as the little-endian `u32` number of matrices, then for each matrix the `u32` number of
+    /// opened elements followed by the elements in their canonical encoding, then the [MultiProof::to_bytes] of the
+    /// siblings.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(
+            &(self
+                .rows
+                .len() as u32)
+                .to_le_bytes(),
+        );
+        for rows in &self.rows {
+            bytes.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+            for value in rows {
+                bytes.extend(value.to_bytes_le());
+            }
+        }
+        bytes.extend(
+            self.tree
+                .to_bytes(),
+        );
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> IcicleResult<Self> {
+        let element_len = F::zero()
+            .to_bytes_le()
+            .len();
+        let read_u32 = |bytes: &[u8]| -> IcicleResult<usize> {
+            match bytes.get(..4) {
+                Some(word) => Ok(u32::from_le_bytes(
+                    word.try_into()
+                        .unwrap(),
+                ) as usize),
+                None => malformed(),
+            }
+        };
+
+        let number_of_matrices = read_u32(bytes)?;
+        let mut rest = &bytes[4..];
+        let mut rows = Vec::new();
+        for _ in 0..number_of_matrices {
+            let rows_len = read_u32(rest)?;
+            let values_len = rows_len
+                .checked_mul(element_len)
+                .filter(|len| *len <= rest.len() - 4);
+            let Some(values_len) = values_len else {
+                return malformed();
+            };
+            let (values, tail) = rest[4..].split_at(values_len);
+            rows.push(
+                values
+                    .chunks(element_len)
+                    .map(F::from_bytes_le)
+                    .collect(),
+            );
+            rest = tail;
+        }
+        if rows.is_empty() {
+            return malformed();
+        }
+        let tree = MultiProof::from_bytes(rest)?;
+        Ok(Self { tree, rows })
+    }
+
+    /// Checks the opened rows against the root of a commitment to matrices of `dims`, in the order they were committed,
+    /// with the host sponges of the device ones that computed it, see [mmcs_commit_host].
+    ///
+    /// Malformed proofs are rejected with an error, proofs that don't lead to the root return `false`.
+    pub fn verify(
+        &self,
+        root: &[F],
+        dims: &[MatrixDims],
+        hasher: &HostPoseidon2<F>,
+        compression: &HostPoseidon2<F>,
+    ) -> IcicleResult<bool>
+    where
+        F: FieldArithmetic,
+    {
+        // The shape of the tree comes from the verifier's dimensions and sponges, not from the proof
+        let tree_height = mmcs_tree_height(dims)?;
+        let digest_elements = compression.width() / 2;
+        check_sponges(hasher, compression, digest_elements)?;
+        let config = TreeBuilderConfig::builder()
+            .digest_elements(digest_elements as u32)
+            .build()?;
+        if self
+            .tree
+            .height()
+            != tree_height
+            || self
+                .tree
+                .arity()
+                != 2
+            || self
+                .rows
+                .len()
+                != dims.len()
+        {
+            return malformed();
+        }
+        let row_indices: Vec<Vec<usize>> = (0..dims.len())
+            .map(|matrix| {
+                opened_rows(
+                    self.tree
+                        .indices(),
+                    dims,
+                    tree_height,
+                    matrix,
+                )
+            })
+            .collect();
+        for (matrix, rows) in row_indices
+            .iter()
+            .enumerate()
+        {
+            if self.rows[matrix].len() != rows.len() * dims[matrix].width {
+                return malformed();
+            }
+        }
+        let rows_of = |matrices: &[usize], row: usize| -> IcicleResult<Vec<&[F]>> {
+            matrices
+                .iter()
+                .map(|matrix| {
+                    let position = row_indices[*matrix]
+                        .binary_search(&row)
+                        .or_else(|_| malformed())?;
+                    let width = dims[*matrix].width;
+                    Ok(&self.rows[*matrix][position * width..(position + 1) * width])
+                })
+                .collect()
+        };
+
+        let tallest = injected_at(dims, tree_height, 0);
+        let mut leaf_digests = Vec::new();
+        for index in self
+            .tree
+            .indices()
+        {
+            leaf_digests.extend(hash_rows(hasher, &rows_of(&tallest, *index)?, digest_elements));
+        }
+        let computed_root = self
+            .tree
+            .compute_root_with(tree_height, &config, &leaf_digests, |level, parents, children| {
+                let injected = injected_at(dims, tree_height, level);
+                let mut digests = Vec::with_capacity(parents.len() * digest_elements);
+                for (node, children) in parents
+                    .iter()
+                    .zip(children.chunks(2 * digest_elements))
+                {
+                    let injected_rows = if injected.is_empty() {
+                        None
+                    } else if *node < dims[injected[0]].height {
+                        Some(Some(rows_of(&injected, *node)?))
+                    } else {
+                        Some(None)
+                    };
+                    digests.extend(parent_digest(compression, children, digest_elements, injected_rows));
+                }
+                Ok(digests)
+            })?;
+        Ok(computed_root == root)
+    }
 }
 
 #[macro_export]
@@ -67,8 +535,8 @@ macro_rules! impl_mmcs {
                             .as_ptr(),
                         leaves.len() as u32,
                         digests.as_mut_ptr(),
-                        compression.get_handle(),
                         hasher.get_handle(),
+                        compression.get_handle(),
                         config,
                     )
                     .wrap()
@@ -1,12 +1,17 @@
 use icicle_cuda_runtime::{
     device_context::{DeviceContext, DEFAULT_DEVICE_ID},
     memory::HostOrDeviceSlice,
+    stream::CudaStream,
 };
 
+use crate::error::IcicleError;
 use crate::hash::SpongeHash;
 use crate::{error::IcicleResult, ntt::FieldImpl};
 
+pub mod incremental;
 pub mod mmcs;
+pub mod multiproof;
+pub mod sparse;
 #[doc(hidden)]
 pub mod tests;
 
@@ -14,36 +19,63 @@ pub mod tests;
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct TreeBuilderConfig<'a> {
+    pub(crate) ctx: DeviceContext<'a>,
+    pub(crate) arity: u32,
+    pub(crate) keep_rows: u32,
+    pub(crate) digest_elements: u32,
+    are_inputs_on_device: bool,
+    are_outputs_on_device: bool,
+    pub(crate) is_async: bool,
+}
+
+impl<'a> Default for TreeBuilderConfig<'a> {
+    fn default() -> Self {
+        Self::default_for_device(DEFAULT_DEVICE_ID)
+    }
+}
+
+impl<'a> TreeBuilderConfig<'a> {
     /// Details related to the device such as its id and stream id. See [DeviceContext](@ref device_context::DeviceContext).
-    pub ctx: DeviceContext<'a>,
+    pub fn ctx(&self) -> &DeviceContext<'a> {
+        &self.ctx
+    }
 
     /// Airty of the tree
-    pub arity: u32,
+    pub fn arity(&self) -> u32 {
+        self.arity
+    }
 
     /// How many rows of the Merkle tree rows should be written to output. '0' means all of them
-    pub keep_rows: u32,
+    pub fn keep_rows(&self) -> u32 {
+        self.keep_rows
+    }
 
     /// The size of the output for the bottom layer hash and compression.
     /// Will also be equal to the size of the root of the tree. Default value 1
-    pub digest_elements: u32,
-
-    are_inputs_on_device: bool,
-
-    are_outputs_on_device: bool,
+    pub fn digest_elements(&self) -> u32 {
+        self.digest_elements
+    }
 
     /// Whether to run build_merkle_tree asynchronously. If set to `true`, TreeBuilder will be non-blocking
     /// and you'd need to synchronize it explicitly by running `cudaStreamSynchronize` or `cudaDeviceSynchronize`.
     /// If set to `false`, build_merkle_tree will block the current CPU thread.
-    pub is_async: bool,
-}
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
 
-impl<'a> Default for TreeBuilderConfig<'a> {
-    fn default() -> Self {
-        Self::default_for_device(DEFAULT_DEVICE_ID)
+    /// Copy of the config for building a tree of `leaves` into `digests`, recording whether each of them is on the
+    /// device.
+    pub fn for_slices<F>(
+        &self,
+        leaves: &(impl HostOrDeviceSlice<F> + ?Sized),
+        digests: &(impl HostOrDeviceSlice<F> + ?Sized),
+    ) -> Self {
+        let mut cfg = self.clone();
+        cfg.are_inputs_on_device = leaves.is_on_device();
+        cfg.are_outputs_on_device = digests.is_on_device();
+        cfg
     }
-}
 
-impl<'a> TreeBuilderConfig<'a> {
     fn default_for_device(device_id: usize) -> Self {
         Self {
             ctx: DeviceContext::default_for_device(device_id),
@@ -55,6 +87,85 @@ impl<'a> TreeBuilderConfig<'a> {
             is_async: false,
         }
     }
+
+    /// Starts a [TreeBuilderConfigBuilder] from the default config of the default device.
+    pub fn builder() -> TreeBuilderConfigBuilder<'a> {
+        Self::builder_for_device(DEFAULT_DEVICE_ID)
+    }
+
+    /// Starts a [TreeBuilderConfigBuilder] from the default config of device `device_id`.
+    pub fn builder_for_device(device_id: usize) -> TreeBuilderConfigBuilder<'a> {
+        TreeBuilderConfigBuilder {
+            cfg: Self::default_for_device(device_id),
+        }
+    }
+}
+
+/// Builder of [TreeBuilderConfig] which checks the parameters on [build](TreeBuilderConfigBuilder::build), for
+/// instance `TreeBuilderConfig::builder().arity(4).keep_rows(3).build()?`.
+///
+/// Parameters that aren't set keep their default values, and whether inputs and outputs are on the device is taken
+/// from the slices passed to [build_merkle_tree](FieldTreeBuilder::build_merkle_tree).
+#[derive(Debug, Clone)]
+pub struct TreeBuilderConfigBuilder<'a> {
+    cfg: TreeBuilderConfig<'a>,
+}
+
+impl<'a> TreeBuilderConfigBuilder<'a> {
+    /// Device context to run the tree builder in, see [TreeBuilderConfig::ctx].
+    pub fn ctx(mut self, ctx: DeviceContext<'a>) -> Self {
+        self.cfg
+            .ctx = ctx;
+        self
+    }
+
+    /// Stream of the device context to run the tree builder on.
+    pub fn stream(mut self, stream: &'a CudaStream) -> Self {
+        self.cfg
+            .ctx
+            .stream = stream;
+        self
+    }
+
+    /// See [TreeBuilderConfig::arity], must be at least 2.
+    pub fn arity(mut self, arity: u32) -> Self {
+        self.cfg
+            .arity = arity;
+        self
+    }
+
+    /// See [TreeBuilderConfig::keep_rows].
+    pub fn keep_rows(mut self, keep_rows: u32) -> Self {
+        self.cfg
+            .keep_rows = keep_rows;
+        self
+    }
+
+    /// See [TreeBuilderConfig::digest_elements], must be at least 1.
+    pub fn digest_elements(mut self, digest_elements: u32) -> Self {
+        self.cfg
+            .digest_elements = digest_elements;
+        self
+    }
+
+    /// See [TreeBuilderConfig::is_async].
+    pub fn is_async(mut self, is_async: bool) -> Self {
+        self.cfg
+            .is_async = is_async;
+        self
+    }
+
+    /// Returns the config, or an `InvalidArgument` error naming the first parameter out of its range.
+    pub fn build(self) -> IcicleResult<TreeBuilderConfig<'a>> {
+        let cfg = self.cfg;
+        if cfg.arity < 2 {
+            return Err(IcicleError::invalid_argument("Tree arity must be at least 2"));
+        }
+        if cfg.digest_elements < 1 {
+            return Err(IcicleError::invalid_argument("Tree digest_elements must be at least 1"));
+        }
+        Ok(cfg)
+    }
 }
 
 pub fn merkle_tree_digests_len(height: u32, arity: u32, digest_elements: u32) -> usize {
@@ -127,6 +238,7 @@ macro_rules! impl_field_tree_builder {
                 sponge: &Sponge,
                 config: &TreeBuilderConfig,
             ) -> IcicleResult<()> {
+                let local_cfg = config.for_slices(leaves, digests);
                 unsafe {
                     $field_prefix_ident::build_merkle_tree(
                         leaves.as_ptr(),
@@ -135,7 +247,7 @@ macro_rules! impl_field_tree_builder {
                         input_block_len as u32,
                         compression.get_handle(),
                         sponge.get_handle(),
-                        config,
+                        &local_cfg,
                     )
                     .wrap()
                 }
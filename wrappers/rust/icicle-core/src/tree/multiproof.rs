@@ -0,0 +1,356 @@
+use std::collections::BTreeMap;
+
+use icicle_cuda_runtime::memory::HostSlice;
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    hash::SpongeHash,
+    traits::FieldImpl,
+};
+
+use super::{merkle_tree_digests_len, TreeBuilderConfig};
+
+fn invalid_proof() -> IcicleError {
+    IcicleError::invalid_argument("Malformed Merkle multi-proof")
+}
+
+/// Opening of several leaves of one Merkle tree, where the nodes shared by their paths are sent once.
+///
+/// Level by level from the leaves, the proof holds the digests of the children of the opened nodes' parents that
+/// can't be computed from the opened nodes, in increasing order. The tree is the one of
+/// [build_merkle_tree](super::FieldTreeBuilder::build_merkle_tree) or [mmcs_commit](super::mmcs::FieldMmcs::mmcs_commit),
+/// of any arity and with `digest_elements` elements per node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiProof<F: FieldImpl> {
+    height: usize,
+    arity: usize,
+    digest_elements: usize,
+    // Opened leaves, sorted and without duplicates
+    indices: Vec<usize>,
+    siblings: Vec<F>,
+}
+
+impl<F: FieldImpl> MultiProof<F> {
+    /// Opens the leaves at `indices` of a tree whose digests were computed with all rows kept.
+    pub fn new(digests: &[F], height: usize, config: &TreeBuilderConfig, indices: &[usize]) -> IcicleResult<Self> {
+        let (arity, digest_elements) = (config.arity as usize, config.digest_elements as usize);
+        if arity < 2
+            || digest_elements == 0
+            || digests.len() != merkle_tree_digests_len(height as u32, arity as u32, digest_elements as u32)
+        {
+            return Err(IcicleError::invalid_argument(
+                "Merkle tree digests don't match the height, arity and digest elements",
+            ));
+        }
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        if indices.is_empty() || indices[indices.len() - 1] >= arity.pow(height as u32) {
+            return Err(IcicleError::invalid_argument(
+                "Multi-proofs open at least one leaf and only leaves of the tree",
+            ));
+        }
+
+        let mut siblings = Vec::new();
+        let mut known = indices.clone();
+        let (mut row_start, mut row_len) = (0, arity.pow(height as u32));
+        for _ in 0..height {
+            let parents = parents_of(&known, arity);
+            for parent in &parents {
+                for child in parent * arity..(parent + 1) * arity {
+                    if known
+                        .binary_search(&child)
+                        .is_err()
+                    {
+                        let start = row_start + child * digest_elements;
+                        siblings.extend_from_slice(&digests[start..start + digest_elements]);
+                    }
+                }
+            }
+            known = parents;
+            row_start += row_len * digest_elements;
+            row_len /= arity;
+        }
+
+        Ok(Self {
+            height,
+            arity,
+            digest_elements,
+            indices,
+            siblings,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn digest_elements(&self) -> usize {
+        self.digest_elements
+    }
+
+    /// The opened leaves in the order their values are expected by [verify_multi].
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Sibling digests of all levels, `digest_elements` elements each.
+    pub fn siblings(&self) -> &[F] {
+        &self.siblings
+    }
+
+    /// Recomputes the root from the digests of the opened leaves, in the order of [indices](Self::indices).
+    ///
+    /// The height of the tree and the arity and digest elements of `config` come from the verifier, proofs of another
+    /// shape are rejected: a deserialized proof is untrusted, and its own shape could describe a subtree.
+    ///
+    /// `compress` is called once per level, leaves first, with the row of the parents, their indices and the
+    /// concatenated digests of their children, and returns the parents' digests. This is where trees that do more
+    /// than compress children, like MMCS with injected matrices, plug their node hash.
+    pub fn compute_root_with(
+        &self,
+        height: usize,
+        config: &TreeBuilderConfig,
+        leaf_digests: &[F],
+        mut compress: impl FnMut(usize, &[usize], &[F]) -> IcicleResult<Vec<F>>,
+    ) -> IcicleResult<Vec<F>> {
+        let (arity, digest_elements) = (self.arity, self.digest_elements);
+        if self.height != height || arity != config.arity as usize || digest_elements != config.digest_elements as usize
+        {
+            return Err(IcicleError::invalid_argument(
+                "Merkle multi-proof doesn't match the verifier's height, arity and digest elements",
+            ));
+        }
+        if leaf_digests.len()
+            != self
+                .indices
+                .len()
+                * digest_elements
+        {
+            return Err(IcicleError::invalid_argument(
+                "There must be one leaf digest per opened leaf",
+            ));
+        }
+
+        let mut known: BTreeMap<usize, Vec<F>> = self
+            .indices
+            .iter()
+            .copied()
+            .zip(
+                leaf_digests
+                    .chunks(digest_elements)
+                    .map(<[F]>::to_vec),
+            )
+            .collect();
+        let mut siblings = self
+            .siblings
+            .chunks(digest_elements);
+        for row in 1..=self.height {
+            let parents = parents_of(
+                &known
+                    .keys()
+                    .copied()
+                    .collect::<Vec<_>>(),
+                arity,
+            );
+            let mut children = Vec::with_capacity(parents.len() * arity * digest_elements);
+            for parent in &parents {
+                for child in parent * arity..(parent + 1) * arity {
+                    let digest = match known.get(&child) {
+                        Some(digest) => digest,
+                        None => siblings
+                            .next()
+                            .ok_or_else(invalid_proof)?,
+                    };
+                    children.extend_from_slice(digest);
+                }
+            }
+
+            let parent_digests = compress(row, &parents, &children)?;
+            if parent_digests.len() != parents.len() * digest_elements {
+                return Err(invalid_proof());
+            }
+            known = parents
+                .into_iter()
+                .zip(
+                    parent_digests
+                        .chunks(digest_elements)
+                        .map(<[F]>::to_vec),
+                )
+                .collect();
+        }
+        if siblings
+            .next()
+            .is_some()
+        {
+            return Err(invalid_proof());
+        }
+
+        Ok(known
+            .into_values()
+            .flatten()
+            .collect())
+    }
+
+    /// Recomputes the root from the digests of the opened leaves with a compression of the children of each node, see
+    /// [compute_root_with](Self::compute_root_with).
+    pub fn compute_root<C: SpongeHash<F, F>>(
+        &self,
+        height: usize,
+        config: &TreeBuilderConfig,
+        leaf_digests: &[F],
+        compression: &C,
+    ) -> IcicleResult<Vec<F>> {
+        let (arity, digest_elements) = (self.arity, self.digest_elements);
+        self.compute_root_with(height, config, leaf_digests, |_, parents, children| {
+            let mut digests = vec![F::zero(); parents.len() * digest_elements];
+            compression.hash_many(
+                HostSlice::from_slice(children),
+                HostSlice::from_mut_slice(&mut digests),
+                parents.len(),
+                arity * digest_elements,
+                digest_elements,
+                &compression.default_config(),
+            )?;
+            Ok(digests)
+        })
+    }
+
+    /// Serializes the proof as little-endian `u32` height, arity, digest elements and number of opened leaves, the
+    /// opened leaves as `u64`, then the sibling elements in their canonical encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for value in [
+            self.height,
+            self.arity,
+            self.digest_elements,
+            self.indices
+                .len(),
+        ] {
+            bytes.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        for index in &self.indices {
+            bytes.extend_from_slice(&(*index as u64).to_le_bytes());
+        }
+        for sibling in &self.siblings {
+            bytes.extend(sibling.to_bytes_le());
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> IcicleResult<Self> {
+        if bytes.len() < 16 {
+            return Err(invalid_proof());
+        }
+        let header: Vec<usize> = bytes[..16]
+            .chunks(4)
+            .map(|word| {
+                u32::from_le_bytes(
+                    word.try_into()
+                        .unwrap(),
+                ) as usize
+            })
+            .collect();
+        let (height, arity, digest_elements, number_of_indices) = (header[0], header[1], header[2], header[3]);
+        let indices_len = number_of_indices
+            .checked_mul(8)
+            .ok_or_else(invalid_proof)?;
+        if arity < 2 || digest_elements == 0 || number_of_indices == 0 || bytes.len() - 16 < indices_len {
+            return Err(invalid_proof());
+        }
+
+        let (indices_bytes, siblings_bytes) = bytes[16..].split_at(indices_len);
+        let indices: Vec<usize> = indices_bytes
+            .chunks(8)
+            .map(|word| {
+                u64::from_le_bytes(
+                    word.try_into()
+                        .unwrap(),
+                ) as usize
+            })
+            .collect();
+        let sorted = indices
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]);
+        let in_tree = arity
+            .checked_pow(height as u32)
+            .is_some_and(|number_of_leaves| indices[number_of_indices - 1] < number_of_leaves);
+
+        let element_len = F::zero()
+            .to_bytes_le()
+            .len();
+        if !sorted || !in_tree || siblings_bytes.len() % (element_len * digest_elements) != 0 {
+            return Err(invalid_proof());
+        }
+        let siblings = siblings_bytes
+            .chunks(element_len)
+            .map(F::from_bytes_le)
+            .collect();
+        Ok(Self {
+            height,
+            arity,
+            digest_elements,
+            indices,
+            siblings,
+        })
+    }
+}
+
+/// Host verification of a multi-proof of a tree built by [build_merkle_tree](super::FieldTreeBuilder::build_merkle_tree):
+/// `leaves` holds the opened leaves of `input_block_len` elements in the order of [MultiProof::indices], which are
+/// hashed by the sponge, and nodes are checked up to the root with the compression. `height` and `config` describe the
+/// verifier's tree, like in [MultiProof::new].
+///
+/// Malformed proofs and proofs of another shape are rejected with an error, proofs that don't lead to the root return
+/// `false`.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_multi<F, S, C>(
+    proof: &MultiProof<F>,
+    root: &[F],
+    leaves: &[F],
+    input_block_len: usize,
+    height: usize,
+    config: &TreeBuilderConfig,
+    sponge: &S,
+    compression: &C,
+) -> IcicleResult<bool>
+where
+    F: FieldImpl,
+    S: SpongeHash<F, F>,
+    C: SpongeHash<F, F>,
+{
+    let number_of_leaves = proof
+        .indices
+        .len();
+    if leaves.len() != number_of_leaves * input_block_len {
+        return Err(IcicleError::invalid_argument(
+            "There must be one leaf of input_block_len elements per opened leaf",
+        ));
+    }
+
+    let digest_elements = config.digest_elements as usize;
+    let mut leaf_digests = vec![F::zero(); number_of_leaves * digest_elements];
+    sponge.hash_many(
+        HostSlice::from_slice(leaves),
+        HostSlice::from_mut_slice(&mut leaf_digests),
+        number_of_leaves,
+        input_block_len,
+        digest_elements,
+        &sponge.default_config(),
+    )?;
+    Ok(proof.compute_root(height, config, &leaf_digests, compression)? == root)
+}
+
+// Parents of sorted nodes, sorted and without duplicates
+fn parents_of(nodes: &[usize], arity: usize) -> Vec<usize> {
+    let mut parents: Vec<usize> = nodes
+        .iter()
+        .map(|node| node / arity)
+        .collect();
+    parents.dedup();
+    parents
+}
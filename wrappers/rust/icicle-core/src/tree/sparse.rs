@@ -0,0 +1,361 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use icicle_cuda_runtime::memory::HostSlice;
+
+use crate::{
+    error::{IcicleError, IcicleResult},
+    hash::SpongeHash,
+    traits::FieldImpl,
+};
+
+pub const SPARSE_MERKLE_TREE_MAX_DEPTH: usize = 256;
+
+/// Position of a leaf in a [SparseMerkleTree]: a 256-bit integer whose bit `i` picks the child at height `i + 1`
+/// on the path from the root, 1 for the right one. Keys must be smaller than `2^depth`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SparseKey([u64; 4]);
+
+impl SparseKey {
+    pub fn from_u64(key: u64) -> Self {
+        Self([key, 0, 0, 0])
+    }
+
+    /// Reads a little-endian integer of at most 32 bytes.
+    pub fn from_bytes_le(bytes: &[u8]) -> Self {
+        assert!(bytes.len() <= 32, "sparse keys have at most 32 bytes");
+        let mut limbs = [0u64; 4];
+        for (i, byte) in bytes
+            .iter()
+            .enumerate()
+        {
+            limbs[i / 8] |= (*byte as u64) << (8 * (i % 8));
+        }
+        Self(limbs)
+    }
+
+    pub fn to_bytes_le(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes
+            .chunks_mut(8)
+            .zip(self.0)
+        {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn bit(&self, i: usize) -> bool {
+        i < 256 && (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    // Index of the ancestor `shift` levels up, nodes of a level being numbered from the left
+    fn shr(&self, shift: usize) -> Self {
+        let mut limbs = [0u64; 4];
+        let (limb_shift, bit_shift) = (shift / 64, shift % 64);
+        for i in limb_shift..4 {
+            limbs[i - limb_shift] |= self.0[i] >> bit_shift;
+            if bit_shift != 0 && i + 1 < 4 {
+                limbs[i - limb_shift] |= self.0[i + 1] << (64 - bit_shift);
+            }
+        }
+        Self(limbs)
+    }
+
+    // Left child of the node at this index, one level down
+    fn left_child(&self) -> Self {
+        let mut limbs = self
+            .0
+            .map(|limb| limb << 1);
+        for (limb, lower) in limbs[1..]
+            .iter_mut()
+            .zip(self.0)
+        {
+            *limb |= lower >> 63;
+        }
+        Self(limbs)
+    }
+
+    fn sibling(&self) -> Self {
+        let mut limbs = self.0;
+        limbs[0] ^= 1;
+        Self(limbs)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+}
+
+/// Opening of a key of a [SparseMerkleTree]. A proof with no value shows that the key is not in the tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMerkleProof<F: FieldImpl> {
+    pub key: SparseKey,
+    pub value: Option<F>,
+    /// Siblings of the path from the leaf to the root, the leaf's sibling first.
+    pub siblings: Vec<F>,
+}
+
+impl<F: FieldImpl> SparseMerkleProof<F> {
+    pub fn is_membership(&self) -> bool {
+        self.value
+            .is_some()
+    }
+
+    /// Recomputes the root from the leaf and its path with the compression of the tree.
+    pub fn compute_root<H: SpongeHash<F, F>>(&self, compression: &H) -> IcicleResult<F> {
+        let mut node = self
+            .value
+            .unwrap_or(F::zero());
+        for (height, sibling) in self
+            .siblings
+            .iter()
+            .enumerate()
+        {
+            node = if self
+                .key
+                .bit(height)
+            {
+                compress(compression, &[*sibling, node])?[0]
+            } else {
+                compress(compression, &[node, *sibling])?[0]
+            };
+        }
+        Ok(node)
+    }
+
+    /// Checks the proof against the `root` of a tree of the given `depth`. The depth must come from the verifier: a
+    /// proof cut short by `k` siblings, with an internal node as its value, would otherwise verify too.
+    pub fn verify<H: SpongeHash<F, F>>(&self, compression: &H, root: &F, depth: usize) -> IcicleResult<bool> {
+        if self
+            .siblings
+            .len()
+            != depth
+            || self.value == Some(F::zero())
+            || !self
+                .key
+                .shr(depth)
+                .is_zero()
+        {
+            return Ok(false);
+        }
+        Ok(self.compute_root(compression)? == *root)
+    }
+}
+
+// Hashes consecutive pairs of nodes into their parents
+fn compress<F: FieldImpl, H: SpongeHash<F, F>>(compression: &H, children: &[F]) -> IcicleResult<Vec<F>> {
+    let number_of_parents = children.len() / 2;
+    let mut parents = vec![F::zero(); number_of_parents];
+    compression.hash_many(
+        HostSlice::from_slice(children),
+        HostSlice::from_mut_slice(&mut parents),
+        number_of_parents,
+        2,
+        1,
+        &compression.default_config(),
+    )?;
+    Ok(parents)
+}
+
+/// Binary Merkle tree with `2^depth` leaves that only stores the nodes above non-empty leaves.
+///
+/// A leaf holds its value and an empty leaf is zero, so zero can't be stored. A node is the compression of its two
+/// children into one element. The nodes of fully empty subtrees are the same at each height and are computed once.
+/// Updates only recompute the paths of the updated leaves, one `hash_many` call per level for a whole batch.
+pub struct SparseMerkleTree<'a, F: FieldImpl, H: SpongeHash<F, F>> {
+    compression: &'a H,
+    depth: usize,
+    // Root of an empty subtree of each height
+    default_nodes: Vec<F>,
+    // Non-default nodes of each height, leaves first
+    nodes: Vec<HashMap<SparseKey, F>>,
+}
+
+impl<'a, F, H> SparseMerkleTree<'a, F, H>
+where
+    F: FieldImpl,
+    H: SpongeHash<F, F>,
+{
+    pub fn new(compression: &'a H, depth: usize) -> IcicleResult<Self> {
+        if depth == 0 || depth > SPARSE_MERKLE_TREE_MAX_DEPTH {
+            return Err(IcicleError::invalid_argument(
+                "Sparse Merkle tree depth must be between 1 and 256",
+            ));
+        }
+
+        let mut default_nodes = vec![F::zero()];
+        for height in 0..depth {
+            let child = default_nodes[height];
+            default_nodes.push(compress(compression, &[child, child])?[0]);
+        }
+        Ok(Self {
+            compression,
+            depth,
+            default_nodes,
+            nodes: vec![HashMap::new(); depth + 1],
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn root(&self) -> F {
+        self.node(self.depth, &SparseKey::default())
+    }
+
+    /// Root of an empty subtree of the given height, the root of an empty tree being at height `depth`.
+    pub fn default_node(&self, height: usize) -> F {
+        self.default_nodes[height]
+    }
+
+    /// Number of keys in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].is_empty()
+    }
+
+    pub fn get(&self, key: &SparseKey) -> Option<F> {
+        self.nodes[0]
+            .get(key)
+            .copied()
+    }
+
+    pub fn contains(&self, key: &SparseKey) -> bool {
+        self.nodes[0].contains_key(key)
+    }
+
+    /// Adds a key that is not in the tree yet.
+    pub fn insert(&mut self, key: SparseKey, value: F) -> IcicleResult<()> {
+        self.check_key(&key)?;
+        if self.contains(&key) {
+            return Err(IcicleError::invalid_argument(
+                "Inserted key is already in the sparse Merkle tree",
+            ));
+        }
+        self.update_batch(&[(key, Some(value))])
+    }
+
+    /// Changes the value of a key of the tree and returns the previous one.
+    pub fn update(&mut self, key: SparseKey, value: F) -> IcicleResult<F> {
+        let previous = self.existing(&key)?;
+        self.update_batch(&[(key, Some(value))])?;
+        Ok(previous)
+    }
+
+    /// Removes a key of the tree and returns its value.
+    pub fn delete(&mut self, key: SparseKey) -> IcicleResult<F> {
+        let previous = self.existing(&key)?;
+        self.update_batch(&[(key, None)])?;
+        Ok(previous)
+    }
+
+    /// Sets or removes (with `None`) the values of many keys at once, whether they are in the tree or not. When a key
+    /// appears more than once the last update wins.
+    ///
+    /// The nodes to recompute are gathered level by level, so that each level takes a single `hash_many` call. An empty
+    /// batch leaves the tree as it is.
+    pub fn update_batch(&mut self, updates: &[(SparseKey, Option<F>)]) -> IcicleResult<()> {
+        if updates.is_empty() {
+            return Ok(());
+        }
+        let mut leaves = BTreeMap::new();
+        for (key, value) in updates {
+            self.check_key(key)?;
+            if *value == Some(F::zero()) {
+                return Err(IcicleError::invalid_argument(
+                    "Zero marks empty leaves and can't be stored in a sparse Merkle tree",
+                ));
+            }
+            leaves.insert(*key, value.unwrap_or(F::zero()));
+        }
+
+        let mut dirty = BTreeSet::new();
+        for (key, value) in leaves {
+            self.set_node(0, key, value);
+            dirty.insert(key);
+        }
+        let mut children = Vec::new();
+        for height in 0..self.depth {
+            let parents: BTreeSet<SparseKey> = dirty
+                .iter()
+                .map(|index| index.shr(1))
+                .collect();
+            children.clear();
+            for parent in &parents {
+                let left = parent.left_child();
+                children.push(self.node(height, &left));
+                children.push(self.node(height, &left.sibling()));
+            }
+            let digests = compress(self.compression, &children)?;
+            for (parent, digest) in parents
+                .iter()
+                .zip(digests)
+            {
+                self.set_node(height + 1, *parent, digest);
+            }
+            dirty = parents;
+        }
+        Ok(())
+    }
+
+    /// Opens a key, proving its value if it is in the tree and its absence otherwise.
+    pub fn prove(&self, key: &SparseKey) -> IcicleResult<SparseMerkleProof<F>> {
+        self.check_key(key)?;
+        let siblings = (0..self.depth)
+            .map(|height| {
+                self.node(
+                    height,
+                    &key.shr(height)
+                        .sibling(),
+                )
+            })
+            .collect();
+        Ok(SparseMerkleProof {
+            key: *key,
+            value: self.get(key),
+            siblings,
+        })
+    }
+
+    /// Checks a proof against the current root of the tree.
+    pub fn verify(&self, proof: &SparseMerkleProof<F>) -> IcicleResult<bool> {
+        proof.verify(self.compression, &self.root(), self.depth)
+    }
+
+    fn node(&self, height: usize, index: &SparseKey) -> F {
+        self.nodes[height]
+            .get(index)
+            .copied()
+            .unwrap_or(self.default_nodes[height])
+    }
+
+    fn set_node(&mut self, height: usize, index: SparseKey, node: F) {
+        if node == self.default_nodes[height] {
+            self.nodes[height].remove(&index);
+        } else {
+            self.nodes[height].insert(index, node);
+        }
+    }
+
+    fn check_key(&self, key: &SparseKey) -> IcicleResult<()> {
+        if !key
+            .shr(self.depth)
+            .is_zero()
+        {
+            return Err(IcicleError::invalid_argument(
+                "Sparse Merkle tree key is out of the tree",
+            ));
+        }
+        Ok(())
+    }
+
+    fn existing(&self, key: &SparseKey) -> IcicleResult<F> {
+        self.check_key(key)?;
+        self.get(key)
+            .ok_or(IcicleError::invalid_argument("Key is not in the sparse Merkle tree"))
+    }
+}
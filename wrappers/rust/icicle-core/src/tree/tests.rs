@@ -1,9 +1,21 @@
+use std::ffi::c_void;
+
 use icicle_cuda_runtime::memory::HostSlice;
 
 use crate::{
+    error::IcicleErrorCode,
     hash::SpongeHash,
-    traits::FieldImpl,
-    tree::{merkle_tree_digests_len, TreeBuilderConfig},
+    poseidon2::host::HostPoseidon2,
+    traits::{FieldArithmetic, FieldImpl},
+    tree::{
+        incremental::MerkleTree,
+        merkle_tree_digests_len,
+        mmcs::{mmcs_commit_host, MatrixDims, MmcsMultiProof},
+        multiproof::{verify_multi, MultiProof},
+        sparse::{SparseKey, SparseMerkleProof, SparseMerkleTree},
+        TreeBuilderConfig,
+    },
+    Matrix,
 };
 
 use super::FieldTreeBuilder;
@@ -40,3 +52,702 @@ pub fn check_build_field_merkle_tree<F, H, T>(
     .unwrap();
     println!("Root: {:?}", digests_slice[0]);
 }
+
+pub fn check_tree_builder_config() {
+    let config = TreeBuilderConfig::builder()
+        .arity(4)
+        .keep_rows(3)
+        .digest_elements(2)
+        .build()
+        .unwrap();
+    assert_eq!(
+        (config.arity(), config.keep_rows(), config.digest_elements()),
+        (4, 3, 2)
+    );
+    assert!(!config.is_async());
+
+    // Building a tree takes whether leaves and digests are on the device from the slices
+    let leaves = [0u32; 8];
+    let mut digests = [0u32; 4];
+    let local_config = config.for_slices(HostSlice::from_slice(&leaves), HostSlice::from_mut_slice(&mut digests));
+    assert!(!local_config.are_inputs_on_device && !local_config.are_outputs_on_device);
+    assert_eq!(
+        (
+            local_config.arity(),
+            local_config.keep_rows(),
+            local_config.digest_elements()
+        ),
+        (4, 3, 2)
+    );
+
+    for builder in [
+        TreeBuilderConfig::builder().arity(0),
+        TreeBuilderConfig::builder().arity(1),
+        TreeBuilderConfig::builder().digest_elements(0),
+    ] {
+        assert_eq!(
+            builder
+                .build()
+                .unwrap_err()
+                .get_icicle_error_code(),
+            IcicleErrorCode::InvalidArgument
+        );
+    }
+}
+
+pub fn check_sparse_merkle_tree<F, H>(compression: &H)
+where
+    F: FieldImpl,
+    H: SpongeHash<F, F>,
+{
+    let compress = |left: F, right: F| {
+        let mut parent = [F::zero()];
+        compression
+            .hash_many(
+                HostSlice::from_slice(&[left, right]),
+                HostSlice::from_mut_slice(&mut parent),
+                1,
+                2,
+                1,
+                &compression.default_config(),
+            )
+            .unwrap();
+        parent[0]
+    };
+
+    // A small tree against the dense tree of the same leaves
+    let depth = 4;
+    let mut tree = SparseMerkleTree::new(compression, depth).unwrap();
+    let empty_root = tree.root();
+    let mut dense = vec![F::zero(); 1 << depth];
+    for (key, value) in [(3u64, 10u32), (4, 20), (15, 30)] {
+        tree.insert(SparseKey::from_u64(key), F::from_u32(value))
+            .unwrap();
+        dense[key as usize] = F::from_u32(value);
+    }
+    while dense.len() > 1 {
+        dense = dense
+            .chunks(2)
+            .map(|pair| compress(pair[0], pair[1]))
+            .collect();
+    }
+    assert_eq!(tree.root(), dense[0]);
+    assert_eq!(tree.len(), 3);
+
+    assert!(tree
+        .insert(SparseKey::from_u64(3), F::one())
+        .is_err());
+    assert!(tree
+        .insert(SparseKey::from_u64(16), F::one())
+        .is_err());
+    assert!(tree
+        .insert(SparseKey::from_u64(5), F::zero())
+        .is_err());
+    assert!(tree
+        .update(SparseKey::from_u64(5), F::one())
+        .is_err());
+    assert!(tree
+        .delete(SparseKey::from_u64(5))
+        .is_err());
+
+    let root = tree.root();
+    assert_eq!(
+        tree.update(SparseKey::from_u64(4), F::from_u32(21))
+            .unwrap(),
+        F::from_u32(20)
+    );
+    assert_ne!(tree.root(), root);
+    tree.update(SparseKey::from_u64(4), F::from_u32(20))
+        .unwrap();
+    assert_eq!(tree.root(), root);
+    for key in [3, 4, 15] {
+        tree.delete(SparseKey::from_u64(key))
+            .unwrap();
+    }
+    assert_eq!(tree.root(), empty_root);
+    assert!(tree.is_empty());
+
+    // A deep tree with keys using the high bits, updated one by one and all at once
+    let depth = 160;
+    let keys: Vec<SparseKey> = (0..32u8)
+        .map(|i| {
+            let mut bytes = [0u8; 20];
+            bytes[0] = i;
+            bytes[19] = i.wrapping_mul(37);
+            bytes[10] = 0x80 | i;
+            SparseKey::from_bytes_le(&bytes)
+        })
+        .collect();
+    let mut tree = SparseMerkleTree::new(compression, depth).unwrap();
+    let mut batch_tree = SparseMerkleTree::new(compression, depth).unwrap();
+    assert_eq!(tree.root(), tree.default_node(depth));
+    for (i, key) in keys
+        .iter()
+        .enumerate()
+    {
+        tree.insert(*key, F::from_u32(i as u32 + 1))
+            .unwrap();
+    }
+    let updates: Vec<(SparseKey, Option<F>)> = keys
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, key)| (*key, Some(F::from_u32(i as u32 + 1))))
+        .collect();
+    batch_tree
+        .update_batch(&updates)
+        .unwrap();
+    assert_eq!(batch_tree.root(), tree.root());
+
+    // Later updates of a key win, removing keys in a batch
+    batch_tree
+        .update_batch(&[(keys[0], Some(F::from_u32(100))), (keys[1], None), (keys[0], None)])
+        .unwrap();
+    tree.delete(keys[0])
+        .unwrap();
+    tree.delete(keys[1])
+        .unwrap();
+    assert_eq!(batch_tree.root(), tree.root());
+
+    // Empty batches don't hash anything
+    batch_tree
+        .update_batch(&[])
+        .unwrap();
+    assert_eq!(batch_tree.root(), tree.root());
+
+    // Membership and non-membership proofs
+    for key in &keys[1..4] {
+        let proof = tree
+            .prove(key)
+            .unwrap();
+        assert_eq!(proof.is_membership(), tree.contains(key));
+        assert_eq!(
+            proof
+                .siblings
+                .len(),
+            depth
+        );
+        assert!(tree
+            .verify(&proof)
+            .unwrap());
+
+        let mut forged = proof.clone();
+        forged.value = match proof.value {
+            Some(_) => None,
+            None => Some(F::one()),
+        };
+        assert!(!tree
+            .verify(&forged)
+            .unwrap());
+
+        let mut forged = proof.clone();
+        forged.key = keys[5];
+        assert!(!tree
+            .verify(&forged)
+            .unwrap());
+    }
+    let absent = tree
+        .prove(&SparseKey::from_u64(7))
+        .unwrap();
+    assert!(!absent.is_membership());
+    assert!(absent
+        .verify(compression, &tree.root(), depth)
+        .unwrap());
+    assert!(!absent
+        .verify(compression, &empty_root, depth)
+        .unwrap());
+
+    // A proof one level short, whose value is the parent of the absent leaf, gives the same root
+    let truncated = SparseMerkleProof {
+        key: SparseKey::from_u64(3),
+        value: Some(compress(absent.siblings[0], F::zero())),
+        siblings: absent.siblings[1..].to_vec(),
+    };
+    assert_eq!(
+        truncated
+            .compute_root(compression)
+            .unwrap(),
+        tree.root()
+    );
+    assert!(!truncated
+        .verify(compression, &tree.root(), depth)
+        .unwrap());
+    assert!(!tree
+        .verify(&truncated)
+        .unwrap());
+}
+
+/// Digests of a tree built from `leaves` the way `build_merkle_tree` does with all rows kept, hashing one row at a time
+/// with host sponges. It is the reference the tree builders and the incremental trees are checked against.
+pub fn host_merkle_digests<F, S, C>(
+    leaves: &[F],
+    input_block_len: usize,
+    digest_elements: usize,
+    arity: usize,
+    sponge: &S,
+    compression: &C,
+) -> Vec<F>
+where
+    F: FieldImpl,
+    S: SpongeHash<F, F>,
+    C: SpongeHash<F, F>,
+{
+    let number_of_leaves = leaves.len() / input_block_len;
+    let mut layer = vec![F::zero(); number_of_leaves * digest_elements];
+    sponge
+        .hash_many(
+            HostSlice::from_slice(leaves),
+            HostSlice::from_mut_slice(&mut layer),
+            number_of_leaves,
+            input_block_len,
+            digest_elements,
+            &sponge.default_config(),
+        )
+        .unwrap();
+    let mut digests = layer.clone();
+    while layer.len() > digest_elements {
+        let number_of_nodes = layer.len() / (arity * digest_elements);
+        let mut next_layer = vec![F::zero(); number_of_nodes * digest_elements];
+        compression
+            .hash_many(
+                HostSlice::from_slice(&layer),
+                HostSlice::from_mut_slice(&mut next_layer),
+                number_of_nodes,
+                arity * digest_elements,
+                digest_elements,
+                &compression.default_config(),
+            )
+            .unwrap();
+        digests.extend_from_slice(&next_layer);
+        layer = next_layer;
+    }
+    digests
+}
+
+pub fn check_incremental_merkle_tree<F, S, C>(
+    height: usize,
+    input_block_len: usize,
+    sponge: &S,
+    compression: &C,
+    config: &TreeBuilderConfig,
+) where
+    F: FieldImpl,
+    S: SpongeHash<F, F>,
+    C: SpongeHash<F, F>,
+{
+    let arity = config.arity as usize;
+    let capacity = arity.pow(height as u32);
+    let mut leaves: Vec<F> = (0..(capacity * input_block_len) as u32)
+        .map(F::from_u32)
+        .collect();
+    let rebuild = |leaves: &[F]| {
+        MerkleTree::new(
+            HostSlice::from_slice(leaves),
+            height,
+            input_block_len,
+            sponge,
+            compression,
+            config,
+        )
+        .unwrap()
+    };
+    // Row by row hashing, which doesn't go through the ancestor updates of the trees
+    let reference = |leaves: &[F]| {
+        host_merkle_digests(
+            leaves,
+            input_block_len,
+            config.digest_elements as usize,
+            arity,
+            sponge,
+            compression,
+        )
+    };
+
+    let mut tree = rebuild(&leaves);
+    assert_eq!(tree.digests(), reference(&leaves));
+    assert_eq!(
+        tree.digests()
+            .len(),
+        merkle_tree_digests_len(height as u32, arity as u32, config.digest_elements)
+    );
+    assert_eq!(
+        tree.root()
+            .len(),
+        config.digest_elements as usize
+    );
+
+    // Updates of scattered leaves, one of them twice
+    let indices = [1, capacity - 1, capacity / 2, 1];
+    let new_leaves: Vec<F> = (0..(indices.len() * input_block_len) as u32)
+        .map(|i| F::from_u32(1000 + i))
+        .collect();
+    tree.update_leaves(&indices, HostSlice::from_slice(&new_leaves))
+        .unwrap();
+    for (index, leaf) in indices
+        .iter()
+        .zip(new_leaves.chunks(input_block_len))
+    {
+        leaves[index * input_block_len..(index + 1) * input_block_len].copy_from_slice(leaf);
+    }
+    assert_eq!(tree.digests(), reference(&leaves));
+    assert!(tree
+        .update_leaves(&[capacity], HostSlice::from_slice(&new_leaves[..input_block_len]))
+        .is_err());
+    assert!(tree
+        .update_leaves(&[0, 1], HostSlice::from_slice(&new_leaves[..input_block_len]))
+        .is_err());
+
+    // The same updates on a tree wrapping the digests of a full build
+    let mut wrapped = MerkleTree::from_digests(
+        reference(&leaves),
+        capacity,
+        height,
+        input_block_len,
+        sponge,
+        compression,
+        config,
+    )
+    .unwrap();
+    wrapped
+        .update_leaves(&[0], HostSlice::from_slice(&new_leaves[..input_block_len]))
+        .unwrap();
+    leaves[..input_block_len].copy_from_slice(&new_leaves[..input_block_len]);
+    assert_eq!(wrapped.digests(), reference(&leaves));
+
+    // Appending in pieces matches a build over the leaves padded with zeros
+    let mut log = rebuild(&leaves[..3 * input_block_len]);
+    assert_eq!(log.len(), 3);
+    let mut appended = 3;
+    for count in [1, 0, 2, capacity - 6] {
+        log.append(HostSlice::from_slice(
+            &leaves[appended * input_block_len..(appended + count) * input_block_len],
+        ))
+        .unwrap();
+        appended += count;
+
+        let mut padded = leaves[..appended * input_block_len].to_vec();
+        padded.resize(capacity * input_block_len, F::zero());
+        assert_eq!(log.digests(), reference(&padded));
+        assert_eq!(log.len(), appended);
+    }
+    assert_eq!(log.root(), wrapped.root());
+    assert!(log
+        .append(HostSlice::from_slice(&leaves[..input_block_len]))
+        .is_err());
+}
+
+pub fn check_multi_proof<F, S, C>(
+    height: usize,
+    input_block_len: usize,
+    sponge: &S,
+    compression: &C,
+    config: &TreeBuilderConfig,
+) where
+    F: FieldImpl,
+    S: SpongeHash<F, F>,
+    C: SpongeHash<F, F>,
+{
+    let arity = config.arity as usize;
+    let digest_elements = config.digest_elements as usize;
+    let capacity = arity.pow(height as u32);
+    let leaves: Vec<F> = (0..(capacity * input_block_len) as u32)
+        .map(F::from_u32)
+        .collect();
+    let tree = MerkleTree::new(
+        HostSlice::from_slice(&leaves),
+        height,
+        input_block_len,
+        sponge,
+        compression,
+        config,
+    )
+    .unwrap();
+    let root = tree.root();
+    let opened_leaves = |proof: &MultiProof<F>| -> Vec<F> {
+        proof
+            .indices()
+            .iter()
+            .flat_map(|index| leaves[index * input_block_len..(index + 1) * input_block_len].to_vec())
+            .collect()
+    };
+
+    // Neighbouring and repeated queries share their siblings
+    let queries = [capacity - 1, 0, 1, capacity / 2, 1, capacity / 2 + 1];
+    let proof = MultiProof::new(tree.digests(), height, config, &queries).unwrap();
+    assert_eq!(proof.indices(), [0, 1, capacity / 2, capacity / 2 + 1, capacity - 1]);
+    let separate_siblings = 5 * height * (arity - 1) * digest_elements;
+    assert!(
+        proof
+            .siblings()
+            .len()
+            < separate_siblings
+    );
+    assert!(verify_multi(
+        &proof,
+        root,
+        &opened_leaves(&proof),
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .unwrap());
+
+    // Single openings and the hook used for MMCS trees
+    for index in [0, capacity - 1] {
+        let single = MultiProof::new(tree.digests(), height, config, &[index]).unwrap();
+        assert_eq!(
+            single
+                .siblings()
+                .len(),
+            height * (arity - 1) * digest_elements
+        );
+        let leaf_digest = tree.row(0)[index * digest_elements..(index + 1) * digest_elements].to_vec();
+        let mut rows = Vec::new();
+        let computed_root = single
+            .compute_root_with(height, config, &leaf_digest, |row, parents, children| {
+                rows.push(row);
+                let mut digests = vec![F::zero(); parents.len() * digest_elements];
+                compression.hash_many(
+                    HostSlice::from_slice(children),
+                    HostSlice::from_mut_slice(&mut digests),
+                    parents.len(),
+                    arity * digest_elements,
+                    digest_elements,
+                    &compression.default_config(),
+                )?;
+                Ok(digests)
+            })
+            .unwrap();
+        assert_eq!(computed_root, root);
+        assert_eq!(rows, (1..=height).collect::<Vec<_>>());
+    }
+
+    // Serialization
+    let bytes = proof.to_bytes();
+    assert_eq!(MultiProof::<F>::from_bytes(&bytes).unwrap(), proof);
+    assert!(MultiProof::<F>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(MultiProof::<F>::from_bytes(&bytes[..10]).is_err());
+
+    // Tampering
+    let mut wrong_leaves = opened_leaves(&proof);
+    wrong_leaves[0] = F::from_u32(12345);
+    assert!(!verify_multi(
+        &proof,
+        root,
+        &wrong_leaves,
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .unwrap());
+    let element_len = F::zero()
+        .to_bytes_le()
+        .len();
+    let mut wrong_sibling = bytes.clone();
+    let last = wrong_sibling.len() - element_len;
+    wrong_sibling[last] ^= 1;
+    let wrong_sibling = MultiProof::<F>::from_bytes(&wrong_sibling).unwrap();
+    assert!(!verify_multi(
+        &wrong_sibling,
+        root,
+        &opened_leaves(&proof),
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .unwrap());
+    let mut short = bytes.clone();
+    short.truncate(bytes.len() - element_len * digest_elements);
+    let short = MultiProof::<F>::from_bytes(&short).unwrap();
+    assert!(verify_multi(
+        &short,
+        root,
+        &opened_leaves(&proof),
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .is_err());
+
+    // Proofs of another shape than the verifier's tree are rejected: without its top siblings and with one level
+    // less, the opening of leaf 0 is a valid proof of the first child of the root
+    let single_bytes = MultiProof::new(tree.digests(), height, config, &[0])
+        .unwrap()
+        .to_bytes();
+    let mut lower = single_bytes.clone();
+    lower[..4].copy_from_slice(&(height as u32 - 1).to_le_bytes());
+    lower.truncate(single_bytes.len() - (arity - 1) * digest_elements * element_len);
+    let lower = MultiProof::<F>::from_bytes(&lower).unwrap();
+    assert_eq!(
+        lower
+            .compute_root(height - 1, config, &tree.row(0)[..digest_elements], compression)
+            .unwrap(),
+        tree.row(height - 1)[..digest_elements]
+    );
+    assert!(verify_multi(
+        &lower,
+        root,
+        &leaves[..input_block_len],
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .is_err());
+    let mut wider = single_bytes.clone();
+    wider[4..8].copy_from_slice(&(arity as u32 + 1).to_le_bytes());
+    let wider = MultiProof::<F>::from_bytes(&wider).unwrap();
+    assert!(verify_multi(
+        &wider,
+        root,
+        &leaves[..input_block_len],
+        input_block_len,
+        height,
+        config,
+        sponge,
+        compression
+    )
+    .is_err());
+
+    assert!(MultiProof::new(tree.digests(), height, config, &[capacity]).is_err());
+    assert!(MultiProof::new(tree.digests(), height, config, &[]).is_err());
+}
+
+pub fn check_mmcs_multi_proof<F: FieldArithmetic>(
+    hasher: &HostPoseidon2<F>,
+    compression: &HostPoseidon2<F>,
+    digest_elements: usize,
+) {
+    // The tallest matrix is padded to 16 rows, the others are injected at heights 8, 4 (with a padding row) and 1
+    let dims = [(3, 13), (5, 8), (2, 3), (4, 1)].map(|(width, height)| MatrixDims { width, height });
+    let values: Vec<Vec<F>> = dims
+        .iter()
+        .enumerate()
+        .map(|(matrix, dims)| {
+            (0..(dims.width * dims.height) as u32)
+                .map(|i| F::from_u32(1000 * matrix as u32 + i))
+                .collect()
+        })
+        .collect();
+    let matrices = |values: &[Vec<F>]| -> Vec<Matrix> {
+        values
+            .iter()
+            .zip(dims)
+            .map(|(values, dims)| Matrix {
+                values: values.as_ptr() as *const c_void,
+                width: dims.width,
+                height: dims.height,
+            })
+            .collect()
+    };
+    let leaves = matrices(&values);
+    let digests = mmcs_commit_host(&leaves, digest_elements, hasher, compression).unwrap();
+    assert_eq!(digests.len(), merkle_tree_digests_len(4, 2, digest_elements as u32));
+    let root = &digests[digests.len() - digest_elements..];
+    let config = TreeBuilderConfig::builder()
+        .digest_elements(digest_elements as u32)
+        .build()
+        .unwrap();
+
+    let proof = MmcsMultiProof::new(&leaves, &digests, &config, &[12, 0, 5, 4, 0]).unwrap();
+    assert_eq!(
+        proof
+            .tree()
+            .indices(),
+        [0, 4, 5, 12]
+    );
+    // Rows 0, 2 and 6 of the matrix of height 8, row 3 of the one of height 3 is past its height
+    assert_eq!(
+        proof
+            .rows(1)
+            .len(),
+        3 * 5
+    );
+    assert_eq!(
+        proof
+            .rows(2)
+            .len(),
+        2 * 2
+    );
+    assert!(proof
+        .verify(root, &dims, hasher, compression)
+        .unwrap());
+
+    let bytes = proof.to_bytes();
+    assert_eq!(MmcsMultiProof::<F>::from_bytes(&bytes).unwrap(), proof);
+    assert!(MmcsMultiProof::<F>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(MmcsMultiProof::<F>::from_bytes(&bytes[..10]).is_err());
+    assert!(MmcsMultiProof::<F>::from_bytes(&0u32.to_le_bytes()).is_err());
+
+    // Each opened leaf on its own gives a subset of the rows and siblings, which are shared by the multi-proof
+    let mut separate_siblings = 0;
+    for index in proof
+        .tree()
+        .indices()
+    {
+        let single = MmcsMultiProof::new(&leaves, &digests, &config, &[*index]).unwrap();
+        assert!(single
+            .verify(root, &dims, hasher, compression)
+            .unwrap());
+        for (matrix, dims) in dims
+            .iter()
+            .enumerate()
+        {
+            for row in single
+                .rows(matrix)
+                .chunks(dims.width)
+            {
+                assert!(proof
+                    .rows(matrix)
+                    .chunks(dims.width)
+                    .any(|opened| opened == row));
+            }
+        }
+        separate_siblings += single
+            .tree()
+            .siblings()
+            .len();
+    }
+    assert!(
+        proof
+            .tree()
+            .siblings()
+            .len()
+            < separate_siblings
+    );
+
+    // A change in an injected row on the opened paths changes the root
+    let mut changed_values = values.clone();
+    changed_values[2][1] = F::from_u32(7);
+    let changed_digests = mmcs_commit_host(&matrices(&changed_values), digest_elements, hasher, compression).unwrap();
+    let changed_root = &changed_digests[changed_digests.len() - digest_elements..];
+    assert_ne!(changed_root, root);
+    assert!(!proof
+        .verify(changed_root, &dims, hasher, compression)
+        .unwrap());
+
+    let mut wrong_dims = dims;
+    wrong_dims[1].width = 4;
+    assert!(proof
+        .verify(root, &wrong_dims, hasher, compression)
+        .is_err());
+    assert!(proof
+        .verify(root, &dims[..3], hasher, compression)
+        .is_err());
+    assert!(MmcsMultiProof::new(&leaves, &digests, &config, &[13]).is_err());
+    let config = TreeBuilderConfig::builder()
+        .arity(4)
+        .digest_elements(digest_elements as u32)
+        .build()
+        .unwrap();
+    assert!(MmcsMultiProof::new(&leaves, &digests, &config, &[0]).is_err());
+}
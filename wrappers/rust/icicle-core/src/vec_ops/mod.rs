@@ -2,6 +2,7 @@ use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::{
     device_context::{DeviceContext, DEFAULT_DEVICE_ID},
     memory::HostOrDeviceSlice,
+    stream::CudaStream,
 };
 
 use crate::{error::IcicleResult, traits::FieldImpl};
@@ -12,14 +13,11 @@ pub mod tests;
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct VecOpsConfig<'a> {
-    /// Details related to the device such as its id and stream id. See [DeviceContext](@ref device_context::DeviceContext).
-    pub ctx: DeviceContext<'a>,
+    pub(crate) ctx: DeviceContext<'a>,
     is_a_on_device: bool,
     is_b_on_device: bool,
     is_result_on_device: bool,
-    /// Whether to run the vector operations asynchronously. If set to `true`, the functions will be non-blocking and you'd need to synchronize
-    /// it explicitly by running `stream.synchronize()`. If set to false, the functions will block the current CPU thread.
-    pub is_async: bool,
+    pub(crate) is_async: bool,
 }
 
 impl<'a> Default for VecOpsConfig<'a> {
@@ -29,6 +27,17 @@ impl<'a> Default for VecOpsConfig<'a> {
 }
 
 impl<'a> VecOpsConfig<'a> {
+    /// Details related to the device such as its id and stream id. See [DeviceContext](@ref device_context::DeviceContext).
+    pub fn ctx(&self) -> &DeviceContext<'a> {
+        &self.ctx
+    }
+
+    /// Whether to run the vector operations asynchronously. If set to `true`, the functions will be non-blocking and you'd need to synchronize
+    /// it explicitly by running `stream.synchronize()`. If set to false, the functions will block the current CPU thread.
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
     pub fn default_for_device(device_id: usize) -> Self {
         VecOpsConfig {
             ctx: DeviceContext::default_for_device(device_id),
@@ -38,6 +47,54 @@ impl<'a> VecOpsConfig<'a> {
             is_async: false,
         }
     }
+
+    /// Starts a [VecOpsConfigBuilder] from the default config of the default device.
+    pub fn builder() -> VecOpsConfigBuilder<'a> {
+        Self::builder_for_device(DEFAULT_DEVICE_ID)
+    }
+
+    /// Starts a [VecOpsConfigBuilder] from the default config of device `device_id`.
+    pub fn builder_for_device(device_id: usize) -> VecOpsConfigBuilder<'a> {
+        VecOpsConfigBuilder {
+            cfg: Self::default_for_device(device_id),
+        }
+    }
+}
+
+/// Builder of [VecOpsConfig]. Parameters that aren't set keep the values of [VecOpsConfig::default_for_device],
+/// and whether the vectors are on the device is taken from the slices passed to the vector operations.
+#[derive(Debug, Clone)]
+pub struct VecOpsConfigBuilder<'a> {
+    cfg: VecOpsConfig<'a>,
+}
+
+impl<'a> VecOpsConfigBuilder<'a> {
+    /// Device context to run the vector operations in, see [VecOpsConfig::ctx].
+    pub fn ctx(mut self, ctx: DeviceContext<'a>) -> Self {
+        self.cfg
+            .ctx = ctx;
+        self
+    }
+
+    /// Stream of the device context to run the vector operations on.
+    pub fn stream(mut self, stream: &'a CudaStream) -> Self {
+        self.cfg
+            .ctx
+            .stream = stream;
+        self
+    }
+
+    /// See [VecOpsConfig::is_async].
+    pub fn is_async(mut self, is_async: bool) -> Self {
+        self.cfg
+            .is_async = is_async;
+        self
+    }
+
+    /// Returns the config, none of its parameters can be out of range.
+    pub fn build(self) -> VecOpsConfig<'a> {
+        self.cfg
+    }
 }
 
 #[repr(C)]
@@ -1,10 +1,12 @@
-#[cfg(feature = "arkworks")]
-use ark_bls12_377::{g1::Config as ArkG1Config, Fq, Fr};
 #[cfg(all(feature = "arkworks", feature = "g2"))]
-use ark_bls12_377::{g2::Config as ArkG2Config, Fq2};
+use ark_bls12_377::g2::Config as ArkG2Config;
+#[cfg(feature = "arkworks")]
+use ark_bls12_377::{g1::Config as ArkG1Config, Fq, Fq2Config, Fr};
 use icicle_core::curve::{Affine, Curve, Projective};
 use icicle_core::field::{Field, MontgomeryConvertibleField};
-use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom};
+#[cfg(feature = "g2")]
+use icicle_core::g2::Fp2;
+use icicle_core::traits::{FieldConfig, FieldImpl, Fp2Config, GenerateRandom, PrimeFieldConfig};
 use icicle_core::{impl_curve, impl_field, impl_scalar_field};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::DeviceContext;
@@ -13,8 +15,6 @@ use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice};
 
 pub(crate) const SCALAR_LIMBS: usize = 8;
 pub(crate) const BASE_LIMBS: usize = 12;
-#[cfg(feature = "g2")]
-pub(crate) const G2_BASE_LIMBS: usize = 24;
 
 impl_scalar_field!("bls12_377", bls12_377_sf, SCALAR_LIMBS, ScalarField, ScalarCfg, Fr);
 #[cfg(feature = "bw6-761")]
@@ -22,7 +22,27 @@ impl_scalar_field!("bw6_761", bw6_761_sf, BASE_LIMBS, BaseField, BaseCfg, Fq);
 #[cfg(not(feature = "bw6-761"))]
 impl_field!(BASE_LIMBS, BaseField, BaseCfg, Fq);
 #[cfg(feature = "g2")]
-impl_field!(G2_BASE_LIMBS, G2BaseField, G2BaseCfg, Fq2);
+pub type G2BaseField = Fp2<BaseField>;
+
+impl PrimeFieldConfig for ScalarCfg {
+    const MODULUS: &'static [u32] = &[
+        0x00000001, 0x0a118000, 0xd0000001, 0x59aa76fe, 0x5c37b001, 0x60b44d1e, 0x9a2ca556, 0x12ab655e,
+    ];
+}
+
+impl PrimeFieldConfig for BaseCfg {
+    const MODULUS: &'static [u32] = &[
+        0x00000001, 0x8508c000, 0x30000000, 0x170b5d44, 0xba094800, 0x1ef3622f, 0x00f5138f, 0x1a22d9f3, 0x6ca1493b,
+        0xc63b05c0, 0x17c510ea, 0x01ae3a46,
+    ];
+}
+
+impl Fp2Config for BaseCfg {
+    const NON_RESIDUE: i32 = -5;
+    #[cfg(feature = "arkworks")]
+    type ArkFp2Config = Fq2Config;
+}
+
 impl_curve!(
     "bls12_377",
     bls12_377,
@@ -47,19 +67,27 @@ impl_curve!(
 
 #[cfg(test)]
 mod tests {
-    use super::{CurveCfg, ScalarField, BASE_LIMBS};
     #[cfg(feature = "g2")]
-    use super::{G2CurveCfg, G2_BASE_LIMBS};
+    use super::{BaseField, G2CurveCfg};
+    use super::{CurveCfg, ScalarField, BASE_LIMBS};
     use icicle_core::curve::Curve;
     use icicle_core::tests::*;
     use icicle_core::traits::FieldImpl;
-    use icicle_core::{impl_curve_tests, impl_field_tests};
+    use icicle_core::{impl_curve_tests, impl_field_arithmetic_tests, impl_field_tests};
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
     impl_curve_tests!(BASE_LIMBS, CurveCfg);
     #[cfg(feature = "g2")]
     mod g2 {
         use super::*;
+        use icicle_core::g2::tests::*;
+        use icicle_core::impl_g2_tests;
+
+        // Both coordinates of Fp2
+        const G2_BASE_LIMBS: usize = 2 * BASE_LIMBS;
+
         impl_curve_tests!(G2_BASE_LIMBS, G2CurveCfg);
+        impl_g2_tests!(BaseField, G2CurveCfg);
     }
 }
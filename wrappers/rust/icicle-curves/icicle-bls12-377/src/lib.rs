@@ -2,6 +2,8 @@ pub mod curve;
 pub mod ecntt;
 pub mod msm;
 pub mod ntt;
+#[cfg(feature = "g2")]
+pub mod pairing;
 pub mod polynomials;
 pub mod poseidon;
 pub mod tree;
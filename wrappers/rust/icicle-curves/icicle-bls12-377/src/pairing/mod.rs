@@ -0,0 +1,38 @@
+use crate::curve::{BaseField, CurveCfg, G2CurveCfg};
+use icicle_core::g2::Fp2;
+use icicle_core::pairing::{Pairing, PairingFamily, TowerConfig, TwistType};
+use icicle_core::traits::FieldImpl;
+
+/// Host optimal ate pairing of BLS12-377.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Bls12_377;
+
+impl TowerConfig for Bls12_377 {
+    type Fp = BaseField;
+
+    fn fp6_non_residue() -> Fp2<BaseField> {
+        Fp2::new(BaseField::zero(), BaseField::one())
+    }
+}
+
+impl Pairing for Bls12_377 {
+    type G1 = CurveCfg;
+    type G2 = G2CurveCfg;
+
+    const FAMILY: PairingFamily = PairingFamily::Bls12;
+    const TWIST: TwistType = TwistType::D;
+    const X: u64 = 0x8508c00000000001;
+    const X_IS_NEGATIVE: bool = false;
+
+    #[cfg(feature = "arkworks")]
+    type ArkEquivalent = ark_bls12_377::Bls12_377;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Bls12_377;
+    use icicle_core::impl_pairing_tests;
+    use icicle_core::pairing::tests::*;
+
+    impl_pairing_tests!(Bls12_377);
+}
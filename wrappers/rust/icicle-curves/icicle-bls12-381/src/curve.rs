@@ -1,10 +1,12 @@
-#[cfg(feature = "arkworks")]
-use ark_bls12_381::{g1::Config as ArkG1Config, Fq, Fr};
 #[cfg(all(feature = "arkworks", feature = "g2"))]
-use ark_bls12_381::{g2::Config as ArkG2Config, Fq2};
+use ark_bls12_381::g2::Config as ArkG2Config;
+#[cfg(feature = "arkworks")]
+use ark_bls12_381::{g1::Config as ArkG1Config, Fq, Fq2Config, Fr};
 use icicle_core::curve::{Affine, Curve, Projective};
 use icicle_core::field::{Field, MontgomeryConvertibleField};
-use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom};
+#[cfg(feature = "g2")]
+use icicle_core::g2::Fp2;
+use icicle_core::traits::{FieldConfig, FieldImpl, Fp2Config, GenerateRandom, PrimeFieldConfig};
 use icicle_core::{impl_curve, impl_field, impl_scalar_field};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::DeviceContext;
@@ -13,13 +15,31 @@ use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice};
 
 pub(crate) const SCALAR_LIMBS: usize = 8;
 pub(crate) const BASE_LIMBS: usize = 12;
-#[cfg(feature = "g2")]
-pub(crate) const G2_BASE_LIMBS: usize = 24;
 
 impl_scalar_field!("bls12_381", bls12_381_sf, SCALAR_LIMBS, ScalarField, ScalarCfg, Fr);
 impl_field!(BASE_LIMBS, BaseField, BaseCfg, Fq);
 #[cfg(feature = "g2")]
-impl_field!(G2_BASE_LIMBS, G2BaseField, G2BaseCfg, Fq2);
+pub type G2BaseField = Fp2<BaseField>;
+
+impl PrimeFieldConfig for ScalarCfg {
+    const MODULUS: &'static [u32] = &[
+        0x00000001, 0xffffffff, 0xfffe5bfe, 0x53bda402, 0x09a1d805, 0x3339d808, 0x299d7d48, 0x73eda753,
+    ];
+}
+
+impl PrimeFieldConfig for BaseCfg {
+    const MODULUS: &'static [u32] = &[
+        0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ];
+}
+
+impl Fp2Config for BaseCfg {
+    const NON_RESIDUE: i32 = -1;
+    #[cfg(feature = "arkworks")]
+    type ArkFp2Config = Fq2Config;
+}
+
 impl_curve!(
     "bls12_381",
     bls12_381,
@@ -44,19 +64,27 @@ impl_curve!(
 
 #[cfg(test)]
 mod tests {
-    use super::{CurveCfg, ScalarField, BASE_LIMBS};
     #[cfg(feature = "g2")]
-    use super::{G2CurveCfg, G2_BASE_LIMBS};
+    use super::{BaseField, G2CurveCfg};
+    use super::{CurveCfg, ScalarField, BASE_LIMBS};
     use icicle_core::curve::Curve;
     use icicle_core::tests::*;
     use icicle_core::traits::FieldImpl;
-    use icicle_core::{impl_curve_tests, impl_field_tests};
+    use icicle_core::{impl_curve_tests, impl_field_arithmetic_tests, impl_field_tests};
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
     impl_curve_tests!(BASE_LIMBS, CurveCfg);
     #[cfg(feature = "g2")]
     mod g2 {
         use super::*;
+        use icicle_core::g2::tests::*;
+        use icicle_core::impl_g2_tests;
+
+        // Both coordinates of Fp2
+        const G2_BASE_LIMBS: usize = 2 * BASE_LIMBS;
+
         impl_curve_tests!(G2_BASE_LIMBS, G2CurveCfg);
+        impl_g2_tests!(BaseField, G2CurveCfg);
     }
 }
@@ -0,0 +1,22 @@
+use icicle_core::glv::GlvCurve;
+
+use crate::curve::CurveCfg;
+
+impl GlvCurve for CurveCfg {
+    const BETA: &'static str =
+        "0x1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaac";
+    const LAMBDA: &'static str = "0xac45a4010001a40200000000ffffffff";
+    const BASIS: [[(bool, u128); 2]; 2] = [
+        [(false, 0xac45a4010001a40200000000ffffffff), (true, 0x1)],
+        [(false, 0x1), (false, 0xac45a4010001a4020000000100000000)],
+    ];
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::curve::CurveCfg;
+    use icicle_core::glv::tests::*;
+    use icicle_core::impl_glv_tests;
+
+    impl_glv_tests!(CurveCfg);
+}
@@ -0,0 +1,38 @@
+use crate::curve::{BaseField, CurveCfg, G2CurveCfg};
+use icicle_core::g2::Fp2;
+use icicle_core::pairing::{Pairing, PairingFamily, TowerConfig, TwistType};
+use icicle_core::traits::FieldImpl;
+
+/// Host optimal ate pairing of BLS12-381.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Bls12_381;
+
+impl TowerConfig for Bls12_381 {
+    type Fp = BaseField;
+
+    fn fp6_non_residue() -> Fp2<BaseField> {
+        Fp2::new(BaseField::one(), BaseField::one())
+    }
+}
+
+impl Pairing for Bls12_381 {
+    type G1 = CurveCfg;
+    type G2 = G2CurveCfg;
+
+    const FAMILY: PairingFamily = PairingFamily::Bls12;
+    const TWIST: TwistType = TwistType::M;
+    const X: u64 = 0xd201000000010000;
+    const X_IS_NEGATIVE: bool = true;
+
+    #[cfg(feature = "arkworks")]
+    type ArkEquivalent = ark_bls12_381::Bls12_381;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Bls12_381;
+    use icicle_core::impl_pairing_tests;
+    use icicle_core::pairing::tests::*;
+
+    impl_pairing_tests!(Bls12_381);
+}
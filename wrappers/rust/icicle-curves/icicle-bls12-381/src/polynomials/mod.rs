@@ -6,5 +6,18 @@ impl_univariate_polynomial_api!("bls12_381", bls12_381, ScalarField, ScalarCfg);
 #[cfg(test)]
 mod tests {
     use icicle_core::impl_polynomial_tests;
+    use icicle_core::polynomials::tests::{check_bivariate_polynomial, check_bivariate_rou_domain};
     impl_polynomial_tests!(bls12_381, ScalarField);
+
+    #[test]
+    fn test_bivariate_polynomial() {
+        check_bivariate_polynomial::<ScalarField>();
+    }
+
+    #[test]
+    #[ignore]
+    fn test_bivariate_rou_domain() {
+        setup();
+        check_bivariate_rou_domain::<ScalarField>();
+    }
 }
@@ -0,0 +1,21 @@
+use icicle_core::glv::GlvCurve;
+
+use crate::curve::CurveCfg;
+
+impl GlvCurve for CurveCfg {
+    const BETA: &'static str = "0x59e26bcea0d48bacd4f263f1acdb5c4f5763473177fffffe";
+    const LAMBDA: &'static str = "0xb3c4d79d41a917585bfc41088d8daaa78b17ea66b99c90dd";
+    const BASIS: [[(bool, u128); 2]; 2] = [
+        [(false, 0x89d3256894d213e3), (true, 0x6f4d8248eeb859fc8211bbeb7d4f1128)],
+        [(false, 0x6f4d8248eeb859fd0be4e1541221250b), (false, 0x89d3256894d213e3)],
+    ];
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::curve::CurveCfg;
+    use icicle_core::glv::tests::*;
+    use icicle_core::impl_glv_tests;
+
+    impl_glv_tests!(CurveCfg);
+}
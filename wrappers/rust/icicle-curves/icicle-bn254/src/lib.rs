@@ -1,8 +1,12 @@
 pub mod curve;
 pub mod ecntt;
+pub mod glv;
+pub mod hash_to_curve;
 
 pub mod msm;
 pub mod ntt;
+#[cfg(feature = "g2")]
+pub mod pairing;
 pub mod polynomials;
 pub mod poseidon;
 pub mod poseidon2;
@@ -23,10 +23,11 @@ pub(crate) mod tests {
     use crate::curve::CurveCfg;
     #[cfg(feature = "g2")]
     use crate::curve::G2CurveCfg;
-    use icicle_core::impl_msm_tests;
     use icicle_core::msm::tests::*;
+    use icicle_core::{impl_msm_host_tests, impl_msm_tests};
 
     impl_msm_tests!(CurveCfg);
+    impl_msm_host_tests!(CurveCfg);
     #[cfg(feature = "g2")]
     mod g2 {
         use super::*;
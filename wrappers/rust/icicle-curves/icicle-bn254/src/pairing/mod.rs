@@ -0,0 +1,38 @@
+use crate::curve::{BaseField, CurveCfg, G2CurveCfg};
+use icicle_core::g2::Fp2;
+use icicle_core::pairing::{Pairing, PairingFamily, TowerConfig, TwistType};
+use icicle_core::traits::FieldImpl;
+
+/// Host optimal ate pairing of BN254.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Bn254;
+
+impl TowerConfig for Bn254 {
+    type Fp = BaseField;
+
+    fn fp6_non_residue() -> Fp2<BaseField> {
+        Fp2::new(BaseField::from_u32(9), BaseField::one())
+    }
+}
+
+impl Pairing for Bn254 {
+    type G1 = CurveCfg;
+    type G2 = G2CurveCfg;
+
+    const FAMILY: PairingFamily = PairingFamily::Bn;
+    const TWIST: TwistType = TwistType::D;
+    const X: u64 = 4965661367192848881;
+    const X_IS_NEGATIVE: bool = false;
+
+    #[cfg(feature = "arkworks")]
+    type ArkEquivalent = ark_bn254::Bn254;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::Bn254;
+    use icicle_core::impl_pairing_tests;
+    use icicle_core::pairing::tests::*;
+
+    impl_pairing_tests!(Bn254);
+}
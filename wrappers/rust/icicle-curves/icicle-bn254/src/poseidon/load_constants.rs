@@ -0,0 +1,4107 @@
+// Constants loaded by `Poseidon::load`, parsed from icicle/include/poseidon/constants/bn254_poseidon.h so the tests
+// don't depend on the location of the C++ sources
+
+pub(crate) struct LoadConstants {
+    pub(crate) arity: usize,
+    pub(crate) full_rounds_half: u32,
+    pub(crate) partial_rounds: u32,
+    pub(crate) round_constants: &'static [&'static str],
+    pub(crate) mds_matrix: &'static [&'static str],
+    pub(crate) non_sparse_matrix: &'static [&'static str],
+    pub(crate) sparse_matrices: &'static [&'static str],
+}
+
+pub(crate) const LOAD_CONSTANTS: [LoadConstants; 4] = [
+    LoadConstants {
+        arity: 2,
+        full_rounds_half: 4,
+        partial_rounds: 56,
+        round_constants: &[
+            "0x2c4c51fd1bb9567c27e99f5712b49e0574178b41b6f0a476cddc41d242cf2b43",
+            "0x1c5f8d18acb9c61ec6fcbfcda5356f1b3fdee7dc22c99a5b73a2750e5b054104",
+            "0x2d3c1988b4541e4c045595b8d574e98a7c2820314a82e67a4e380f1c4541ba90",
+            "0x222ae678ca46bef574be65c50320836a172c9f31e1c7eb104e991df686e0cb98",
+            "0x083efdd3feab27059d1f4dc127897d11ea40280f182c3fe3165ab8df21fbffe1",
+            "0x231850c8029145014b3079fb17341c5e65fa35a1b41584bf106eadece487554a",
+            "0x20865b0c465ccc9bf9be0c54ca12db973eb566d63f84261a6d758feb28f91b45",
+            "0x137e46c68484b89d4be5c6815c12836a1c7332d87035603383536f78054acf43",
+            "0x2de25a76cc60d541da340b0de89becb1baccdc4a5286f4bea4ec610c99f079da",
+            "0x07a779e38977a97a008d820a90bd2a35b4da7cefa5aae08dafe2dd0ff6369537",
+            "0x11aeb0d8b001c647351351e0e5597da4a86ac8f2f5b5f0bb14d6cf3e53e4eac4",
+            "0x041dc298daf2fe425e5e2d500500e786c66d2a00f5ef7f8e509531bf27c539eb",
+            "0x1a06e31d1b504cbfc1d0bab976fe7ab25d50f0ebc8909cc70a006b3658bffc31",
+            "0x1a7eed12dfa5ae088a94f1808448bb446a506e85cc8f7d73ec8f6011f59a97d4",
+            "0x106790180529bb12f592b08a8eb42b3d22c24cf55aafcc6b865730f6525b8fa1",
+            "0x285890410d0bb14d07a89ce8cd77dfa30abe5a3abc3050c596048b9f3e569735",
+            "0x1d9287594f7f9d44ff19f2aebfead5ba970268e0db36b7c29b63ad548c2fe74c",
+            "0x14f944cd043f212d0128879e7afe53d074753c509e6d803be7744301505104d3",
+            "0x246dae34b1b71fadfbd5424d05a7c23094219e41bb2184135b977078d9c71553",
+            "0x250310e145b55cbaea078e69d1efef80fc913eaf89720e323b792b33a12490a3",
+            "0x2ce991a5d68b97419e7273d48ac0945464643e5ae42183fbc050b01302777f21",
+            "0x02957c4f1b98cbc0c6f8d757762aeb7150b6f856355c1e7027b5bc14c66d00f2",
+            "0x0e603506ca94477fb46e7a3c63c34731754c25f7633ab9daf8391d3cbb6faf0b",
+            "0x2eafa6b105b801eac769d6cfd0e5c03c0e1556c8198bdde9eb25e39b5a94cf5b",
+            "0x095cbb5ea286540513b27bbae1a1e1eb204f9aca0bc1b339478f8af07dd43d5c",
+            "0x2941f87a4467526a893aeb2fbbdec45a3452946e17fbc5720eb1d5aa90807d74",
+            "0x0a4e9fe89b6d152ad7526c5a9c18bb4e82ac22553cf9c0dd01b8ecc9b298faee",
+            "0x2d608d6390626bf3324a8de5269fc8704c42c441116212ce9257d166368f40e3",
+            "0x077eae33f0181427a37d1cfcaaddb18780c58e50d92b13ec9bccf408274b78a1",
+            "0x0c838a2c1586821393c49aa5537739e92f3699ffbdcfd72a3efcdf3cde996055",
+            "0x1ed10f07bce3dfd089e944f49b6f0f630a8ccc732ffc51d937a457c72cb77421",
+            "0x0b27927b128fe1e4c8b65731f78f03f18b43b8f28817129af318dfe256efb8a2",
+            "0x26eda4db71d2d753a2d6cc873daba123588571fd09d9bab461fe14211b18cc37",
+            "0x1657bbd805763f23593e1647cb6234ac473d7e4216506f0e099f9d5d7176bab9",
+            "0x1a5de4b364eb3643c558fe9a241f935e5b7c3579f4821fd96380eadd285cc7d7",
+            "0x20570a7ffc4d5df7df07cf6743ec8a1443e43e62fe63d3a79cd6d4e0f7110756",
+            "0x10cf36cfe048a35f42cbd27ef8c060966f6b07a44145e91ad571a8a6d279e3b2",
+            "0x0ecfb83d6341d0f69d849e8facccfdf0d21878b1ed026a6488d132c3e14c1657",
+            "0x10e09ae2c76eba144311b5262eaef431fd9e7c2f501275ced70ab9b203fb852d",
+            "0x0d224e169e006d99b92799e9c37003415d2306495a5c8eea2d35bba7b7b7fda7",
+            "0x14f22b83447f576a98ae596ac2853c64b8b326cd1617ca53f965557ea842ce4d",
+            "0x01e14ab691854df3538ef18fd48f7713be0fb1d7069f196cef35517c5abed9de",
+            "0x0197e98fb9c763ba4500f938c25814f8f9d14352d592d489d6f5988ae0ed1edd",
+            "0x0f3f8ce936cd52bad13f70bc77c31aae6d891df2cca563f13644d1f9df1b8ddd",
+            "0x0611ce615fad8fd11d88202b1409e553f02787297f1754e6b1c2e6dacd6e7e5f",
+            "0x2b72d1acef4a92f7cad5b21b826459d55c7c0e7e8caf14206d72824547495cdd",
+            "0x2e7666241f28f94f78e2a309abbffd7754e1a299e5fca461731c311102a74c25",
+            "0x2d4e7357c117aaae59df9b79a39679c80a4a11f4c330782fe4c976a9966f1952",
+            "0x107c07f213bab5d6f1becf764696b6b3de2a986f51fb800b81b700ac75f5c85f",
+            "0x0010d73f63c5caddd7ff63fd37d6ff64638a48eb8f85930177f472373a3071c4",
+            "0x02412953c0d75b2dcc435d8c2ab0c79511b346416f4ed961c5fded91d84b1c27",
+            "0x1a6092357d352b054b51ba910b52c39243a7b2c1ff7c5b71bc964de19f4f3606",
+            "0x03f790e890b63704b56b10a73a31bc80c94386afdd6c1521066eb3f1805adfa5",
+            "0x24d2dcb39514e832a7a067ad84971a9c561576f60adf9b77ceb20361a8e2efdc",
+            "0x2f88b63afb7eb5d98d8ab3954fb5e24d1678440c81b7ff475c8a1570cc8adf2f",
+            "0x049aa957a2de3f6e2d043d313849351baf73eea62c56f0fb706a0c153aff1cb8",
+            "0x2837f2f75b47c2aa7435fa4a07f528f237a95e83c8fd9bf6cb8e8bffb944ca3a",
+            "0x2d9386f664e58b071c79faadb20d50fd010f51fd1800be6e748cc0458c1d08a2",
+            "0x1be3f4f7da6bd97146376eff94c2030b08e3d92267fa857712c3692256d8be36",
+            "0x28f30abcd7322abd0be7963954b89da585d4bc7e26762e4306086c8b89225eac",
+            "0x09c0ce4de52183744e72f3d6c102bb5f6cb6a28c116e7044a6e6ff811c385029",
+            "0x1d6753195a893b4684f3b9002d90fa143319497574480269fd74134fdec05df7",
+            "0x2329363c125172de426ec59cefa3bb30b0a2ed2b35b11359c265ea8f61cfca3c",
+            "0x2956c815c98d92047169a751e5d22b2dbb6ee581b6da2f9587cc3a7f66418955",
+            "0x1586c00127af72bc57ea4c0246751c2278dfa42c1eb76155f5a7e49fe45e8756",
+            "0x29797421d9fce01c3eaf5b28b775fa02c7ee0a575bcf96b0c20ca91107096132",
+            "0x0567fa6d33e0d001266d3a48369cb20f6a62a26c41eec6ac5258ac6b280bbeb3",
+            "0x0144963bc8e59c8082a0ec498c15afb4399caba17596f4230a10c99d6cdd2cf9",
+            "0x1f995a31c8d9a7bcc20325e25753e486353b985110b9dc1a42b2960b0fafbae8",
+            "0x2bc1d1e9e350992f39ed778bba455751eb4bdaa9aad30b6b33efd5e63c46cd10",
+            "0x0ce424ff6171410777bf277ea6751469fde4a2c0774ff185011fa27eb9481e40",
+            "0x0655eb9ad73bbf7526441481622bbf119fa76bbb99cbcce53d2876937da7c4cb",
+            "0x0bba823ffd44c793246e3a2dcb0aa7e21713b88fadc3b4100d82065fa8fd04db",
+            "0x249224952a2db675468c49c4b3280509435e5aaef9de9c5a2cb30bebde3fd7db",
+            "0x069fbb419d519dae6b9d212531ea3d8d2ce4499abf385ff52e05aa822aa307ce",
+            "0x082b85bc750c648be3940f3bd214a30b1932425ca7200f0b85f0ba7a5bc1004f",
+            "0x0de0cb70c9ab7d2174cfc02def0ce01011c6392967d7e723e29b7cdd026f527a",
+            "0x22743d00ac30bdecb9c6493c362a61b6d0b19f67fa69c15a4cbe5563ccf23b1c",
+            "0x0a9348c26a0c85580df68199f024300019f087be0872f673623faf8ad3b9b364",
+            "0x2fb7cb57e92b9082f1dd2f0cc29b20e33b1201828c5e2dc896281f6157e8cf22",
+        ],
+        mds_matrix: &[
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x244b3ad628e5381f4a3c3448e1210245de26ee365b4b146cf2e9782ef4000001",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x244b3ad628e5381f4a3c3448e1210245de26ee365b4b146cf2e9782ef4000001",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+        ],
+        non_sparse_matrix: &[
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x2563f051558bc46fedb3ea1c6b120695f0abb7c46ffc0e09a3b696695cd4a714",
+            "0x0743cb55cc780439132a77f78794ccd136f6aef143f7696a09224c6c67cd4dbd",
+            "0x244b3ad628e5381f4a3c3448e1210245de26ee365b4b146cf2e9782ef4000001",
+            "0x2c28a8c76edb4244375bef1d9d0876befcc2388549d78e6637ce2aeb644eaae1",
+            "0x28ab9bc6a95377f76ad0aeb60eba3c8fce1413e01030587f99c63fd30f481244",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x28ab9bc6a95377f76ad0aeb60eba3c8fce1413e01030587f99c63fd30f481244",
+            "0x03f1e26236e587acda9109b6a2234f452b4012b20986e27de58be02e25ecd080",
+        ],
+        sparse_matrices: &[
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1560b9223a42f39db92305ba9f556eae464c7d0a6f049397a7bd890cecf24456",
+            "0x1512ac176d9ff25561e9e7bb5a238f1e4bf65cad816257685090c4f33a37b7ce",
+            "0x1abb62ca4c14b671601d0fa5ef8caede54af9dea1bc359143ecd6c52cf1bb49b",
+            "0x1bbb53d50eb838bcdf6a1676ddd85c6f4f4fe1be7a543766d750c18437a77821",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x07c9a81bd11f83c76003526f376c58a7e7852874566f2c161de8b3b3a8068a2b",
+            "0x1b500feb0c2b15f5512b2da68fb25f0c588f54de28646184e9214cfcaf0bc420",
+            "0x045120e3e483e0346dfe4b23130b60a8c835e84d287b6bca19ba90e6a81a71ff",
+            "0x13b6e73772ecac5aebcfa6edb56de10034e54588545f26bd0244c60aa420193a",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x163d82b0e5d9c256226f6b10f8de834b3965f4bada3193ed2154be5d397233ca",
+            "0x2fd4a673097413e9071cad1a58ff029462d865978b9990d398f299abfc3fb925",
+            "0x186ee829fa260db04c68f591f93b8dfa6005d03c896ecac6ca8c93e7304375fe",
+            "0x2cf93064a9b4d0f723c8150cfbe48b8bcca39368614c372c1e65764cd9e75197",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0ace88991f8182b6ac986323d5e3c268eff275de5872a96ffbc2eab2e9439826",
+            "0x2d1af1c91dd8529696992582dbd6aab383a438a583105f45e445fd79653a9cfd",
+            "0x22b5b80f09c8e656f897fdd7ba7299932331c4aabe707d2a534aad309fae0923",
+            "0x0829f73741f663cc62b86bc8c172b7a6924c24da2d6bafb9d3945608e85d85c8",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x18324a15ced5607d59501564cb9a443dc97508d543847e34065cd60f100ec121",
+            "0x2d1d5cb259a98953beb59423b8029a96aec2d8e7bb28d4f2f219b119c79fd45c",
+            "0x20e81f82f61ce855da6cbdb35c7c3af921acaac06c63f68d57bcfc2379a6ae2f",
+            "0x2af3c4f8c9de7b9cbb098d5c9efd6cef423ad99051af3c0dd9f82edeef4c3297",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x19434cc941297f9004ee5a39b6b93efa0bdf52cb005b49aef7753d0417aea7e3",
+            "0x001ba74172d5b83c7c89e537efb539306ee12ba2d50c8f24a14a579741194ab7",
+            "0x005230b7c03a003a3ab0d9c2fbcc3231661ba907a2e63c55dbc83b0d3cb0f96e",
+            "0x2a33879273c90ad1571dc1022b233e0b02118191748bb04b60aa901ec446e9c6",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x105cac78a1844c85f3f2fbb6eab6c45859f7e7a1732e8da8dc366db40351d02a",
+            "0x17d025f7de08c5bdb8a50f0c3e4273627d8510873149c832866d6de4747c26a8",
+            "0x02d4d0bc472526e0d7025911ba9f37eeadfdb3937921d5cf7f83e33e4a439da2",
+            "0x11e2af9a861012c6a7dbfec5a10aa6fc0a57153370ba30110f3a4d730ca181d2",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x175f31e7bb0a54951e1187d2647ce07cdefb31b5616d055a9d576083ec9c8569",
+            "0x2affb345e637048e2ae4db1f18c7748857f010cd0f5c89f9a8941a0ac80d15ef",
+            "0x2c538e23de1e7a36708226d38391d1d088bc92bf0850ce7d32ed7ab79b0e57e8",
+            "0x29391dc33bd68a13438bf0f6f774e132e362f9038f92bf2f2c9715ec83847b84",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1a5bbf64fe44ca4208b9327714698e0760e9d26d431897b41cbabf2ef687e29b",
+            "0x300f65a9589e498ae5198b30cf6013df32d6780cd9b117b730c911b66e4cb7d9",
+            "0x2fc61fcc49590ceb7f6bb7d31b3af856f87185a5344df3d83547b0c033687ce7",
+            "0x0f0ff4e357ebe6b85c175bea8851f9063646c8920c3b2340487fcf7da4a5798a",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0bab2c12fe6237e73b625ab377a958816fd94ff371e90d79720398783327f6a1",
+            "0x2efa00b4ac7d9025429ab75e7a84e1d3969caee804202cb5b1abc55e519b8315",
+            "0x105526e2ed63c01969755a32a9a1ad4c930bd0a57d2b49bc4ab4a15a15ba1ec8",
+            "0x05ba8219db2c39520114d0a91c7ef1b6e2b5dac478b6651eddde018fd72d2edb",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1f2208cb28ad023577e9d771a8bcdda140e49a54a18cdd0f1ea16589ffd51c82",
+            "0x0e6aa92aa6fd8c67af73193b0a326a4b149005031bf85f2d347715e3e1847ee1",
+            "0x2b29e4a4561326e51076b8f230dc7d14ec087d48587681363eff981ef3cd34d7",
+            "0x2e330a25f5d91eb8cb64d8da258e467f23d64d8a1e7585c2416f77f75dccdfae",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1d996214b8c03b1cfc8f671a53085bcc994b267da684dbc9819e93709e9e9251",
+            "0x107740f4b125efe2b7c6b4ca54b938c389385f1029ea78a4a5b879af2954ec36",
+            "0x0342b25c091d2bfd874b0bdbc887ecf8d848c6e43be4b77d3994ebf5fa39aa98",
+            "0x0d1c30c6c6be2d5d8d43514813677eb4553e779c37155ea5ae47767ba756d6b5",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0be3e7f0517ce09d4c5cbfeee318192d96135a3a4178adf817efa88a614777d5",
+            "0x13f5a1fb5ab2748b93188c81da561c44256538736018f7eb576ff6771c268f77",
+            "0x0b672fd8f63adedaa5a7ea7dd869376a370d8f1252e229ac74cee285db4fd293",
+            "0x05e127f0a8cec95131c594294917b1bc02d797436a4ce7ba008544bcd62d6946",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1aaca71ccdcc38f377a85c037053c11ab5ab3bd7e2eca78f41d7b533e68c2fe1",
+            "0x2d28da99687c3b8ddfe82503370e64281b4921d07e13bcb1bcdbdb2f8cff2260",
+            "0x2b95f9f494a98a8620f8fb361c75a4a1dd6fff0d78582d99760589f782dfb3e0",
+            "0x066fe372e5f23fa9c8b4a9f4099d0edb9ef3c6b5aef14668bb3f120fbd38e465",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x09a2a7069a7e821ae4296941db46427d4855a4408f9db974db51c97760468f4e",
+            "0x008705b2dc661a587a25ed4dbbc9a1a6fb15c4a60b76559e545c1514504800b3",
+            "0x092a1b960683573210d8ba54562d50e4439abe7f650361f3d9e1a5af2c9f1e20",
+            "0x123ca879a1a0a286d683b50d08eb6c2d51b9edf5aa2c7fbadf3c409b287f4648",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x20a9c2f8f463592e8c0e3e163f4e6034271c07f01664721ed3d97a8a056f721d",
+            "0x2fc4c8e37d6b5075cea1e9a56b79089dd636a0e168ee1659038a5db5f9fdaa46",
+            "0x2ddf4a5a757ec416fec505668bfc040fb79c7b134d32524445d5a0794779879e",
+            "0x099e5bfb60b90cb2be9e657043c825595aef11c0803032b56ea9ec40b2c27869",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x2ec15aa44ad7e7e36c868c80170b24eb5fed0c96f82edd0f71d0a9b6289e6ef0",
+            "0x1fdae92328157fc1dae4a9777f15c138c1954ba89953254ca6e8ce5a980c2540",
+            "0x2af199f4ac8560ae9c75bac21258092c67f5d56c431ff68a61d63e3b9dec9476",
+            "0x0119361be60ddd5eed05ff9d4491dd494b2eb5d7b518f324288adb4e05bd4855",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0b6f22b0203c85cb033bab29e67d3b4bec8090b5764699db83639a5cde43d821",
+            "0x27fee96e3d8c8e5357a1e9bf7861299554e6b01db59608f8e9fc030c072854c4",
+            "0x2edaac0b1e8e09ceb391b1a8be6fc50f3d4f29936a742e40b2ce20ebf5130ca4",
+            "0x118c6ffa2852d7b44a15b5a603d1c796270528c54473b0c8a5b3757eca416b95",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x1c4f3c27d12ed8f1d8fa3a68c2c360cd5610a8463bf14d4b3a4b819c8da55b7d",
+            "0x18e6f3e86ac8455b1e1f448816a02f61bbcf043c5f38c32098b61058511d426b",
+            "0x062714a232eaa378ede7389930fd01e1b58ede1619cc6949cf38c14487baa2b4",
+            "0x02bf6b6326be5c2a42f992d833677a0fb4d9a6f74fb553fe226c17f90ec58894",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0b6ce1f6cbac66f4257e33070ce837cd87301909753fe6eea75fa4d5183f8c4d",
+            "0x096d8a2a92e8a5b0390b82e632f77a87bc6aba8ff5eb9926f062d4a422d0805b",
+            "0x2e895a3938e314bb22b69d3b37f5be2dbbe21dd5ffbcbbc0d068a219f1305d1e",
+            "0x1d76c461ce6dc2852213a96cfde2190a21b0f8ff7b3b06fe585287766358fa15",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x18d37fd0a2cdb157bebdf16e5442f08a3acb80394a84676ca2b02ed2e195dc1f",
+            "0x02194fe96e85ee287dd1b8055596372d550feea09b64d829e1310f6e4c718526",
+            "0x0b848dcf13e7db090e2951265194a57866b0e42754af7d652726b01ee70453b1",
+            "0x17705153a444218758eb24a4ccbc8e49bca817d0d1c76a01f9a86092e1e9ca41",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x28cd68f1ad74eb47f57b4658869ee0811dd16bc1c6556920d5b7844b2e1fd349",
+            "0x2f11844de51e15d18afe3ce4b0edfdd11ae434da3e9d2baa589c3196c0e6256d",
+            "0x062f137725926af47ea910d142a6fda3b6736b0f838c26fb602836556d42ef5d",
+            "0x039daa2808ea481212ffe576314b799e29c2f4e8cc2fd41c8c7585a160967082",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x017cd7c58551c6e910f7bf099a181a8fa90d2579a72f60eed858d084504edb29",
+            "0x10fb23e647a16ca0b7a637e108336e76ff7e11b875be1fccc1513add65262ea8",
+            "0x0f05f5caf268b3f16ec3b030e2f728d1edcf05f5ab3d555b88b0dea407a802b9",
+            "0x301c49b5df41d6b24808881a66dbc2abdb0e6cfb5da1c700c5a49afae32e65b7",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x29d7564bebd2f5c01bda45285d1f8914b0d23bbddab4fb461b6522a4a52fbc25",
+            "0x06215f74b089c23497f16fd97357a6b5b142934616e728ede91842cc70066825",
+            "0x04ef3e29e4452071f291e6ca9bd0d24e8ac09dda583f0c8b8d612ef176127a8f",
+            "0x10783d882e0efd4bd9dd10ff7b1b49a480dd8789bbed86b25ea77480e9d9e3a9",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x11748c1c2dda7d47351478e4550856febf13208a881cd45ef36010531ff98c52",
+            "0x26f5548bbf01689dc7b15482bc64ed9025f895d83f1e044802440eb639a14086",
+            "0x2abdc7fa38c6d0b2b82d0961e3ba19ee54a8cdd3909a127214a13d3bd0416c20",
+            "0x1107f6f7c9a4aec365dbfba8fd39e236fb15dc5a2bbc28ca3aa0c7d7575390ce",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x16e37ac41841b31187b70e2fdb793f7d6a76d4e424118d78edd247344ffffc27",
+            "0x13ff1dba1b980d01acafdb7c911020e7842cf6b4f38a9424516e02574cccd15e",
+            "0x0399f887bd393385be8f029b656c538b3585d6b961692216df74954a1b733d2f",
+            "0x0a2aca2f3ed2858c060472338853e3e9f2b714ac8e18ac87829e89f71097d8e9",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0e5cdb2ae335a1bc8c3b8e94888a0ce5b0aeb6ef0cd577319b852f5feffffffe",
+            "0x2a63a1d9344b65280ca9bccb251475131dba722c39811e4db49c96cb8666666a",
+            "0x0d4e6498c889123a5ea098172a037b4066352592ab23128330488859c0771609",
+            "0x0f51d08e12230c17f77633e048060ca234eda350603e99a276c4da0820f154a9",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x004250b7ef84d570d72936928796379e7e1e0928af8a0ee8c0b235c230000000",
+            "0x2667fe7f942c55b2c83b7c5d9cfc4993717044b05bcd63f97c38726a2b333334",
+            "0x00aadc8313ec8826c749036d0f132a681de0e001769f769e078653433dc40bc3",
+            "0x019c08a5ffcb302021b850174dfbbcd6cdddfa93e4ea5fd378dbaba9ebdf42e4",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00002791220a45bfde7eebae2c8c2dbceb0943a46671eb090786637a24000000",
+            "0x135b23a7099e75f5382c65a31512cb80f3c259e571b89273efec6ba90999999a",
+            "0x18197c3c939a61b90fb61221287fa2d1eb097d20007bfe7a79a985c7a0b36619",
+            "0x177a17a30e038f011df8aae9de8e60385b075ef7a747d3b278a2e237d4f43275",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000179b8816ee9881d5072e746970cc6b958f16cb50fa60b33799ac000000",
+            "0x09ada92e2d50335b4157b9a9d3cb008e744f63f74b1715517c76edb572cccccd",
+            "0x24dbf2abb9268952d21c15bc7c850a19a142df074cadfd17080df7137dd265a7",
+            "0x209e252fc290f8856018bde541dc491722066ab82fad29917145a260dd37ea99",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000e15dd23f00c4fc252f089b3baedd17f269dcbbad07ceed1a5000000",
+            "0x1d08fbde766929ba5b500a6f7618ca3de8228d76e04601a0c297991442666667",
+            "0x1ae1a7cefa24d1a38104df080e5ddedd42c4cf752ee90db9121c5222b01107ef",
+            "0x2bb8c81bba595be2c27ea5a422298e78cba2eec5c036592b822587291c738b95",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000008676edfff3deb752fd68b591f10a5fecbe206f68a971e83000000",
+            "0x26b6a528b41dbbb238b489b8c45742327b82693b77536c7f62d811a7e2b33334",
+            "0x0c596b2c3008559df7c1732f9b62e53c08f9a9a81a7a1381d69136acd531142c",
+            "0x014b4fb2993e0900b3ddded3b0d21d03406f251bf2eab606d2bc30de83c4e42f",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000503a6355e4f26e4bb39feea5c4c1d045af23032ab16e4400000",
+            "0x135b52945a13d3b7dbffd67be0c595b2f5208b081b3e4f3cdce43d4d6e99999a",
+            "0x0f6bb89ce137c9f9db794f4df8f792fd2c428df25452ceff646bfbe04eff003a",
+            "0x0732f0a6ca5708c4a873a7d65e73bd0e722300ebbff2d8e0e49d576be38e125b",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000002fde3296da49b632bbf8eb3bef1298f5b90544f435ac00000",
+            "0x09ada94a2d09ecd198820da6ace71ca45957b22463429845cfc33c2a1b2ccccd",
+            "0x05c46dd180ca8e1f6a4500706bff4ab368cf75a3782e34562de8723080c11a08",
+            "0x1b88a4f4d1b9b78361be5471ebffe174c1f1afc60adae0e69ec8378952abe21e",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000000000001c8f72b9fd259edd5fec26e1a95c35d5f5d11b1b0d00000",
+            "0x1d08fbde871dc67f6c78984f5378a28adc0b1da9dd744f7e8731283b1c266667",
+            "0x2ed03ce79a445472d16dff2c6c9ec06525af5a82aba6d7974794b3aa86c6d167",
+            "0x1124222effc83c5415c0a1cc06d7ecd267cd7571fe14915d5a41cd3322cb3a82",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000110a5d4202cc5ed960388f3630f771a487a3832300000",
+            "0x26b6a528b427b3549372279b0f4171841cc4ef216ed29acc722bbe344ceb3334",
+            "0x23fc71dd8f98f39f2247bc4aad79d67eccae785731b9cf9d0a3207be69153d65",
+            "0x056a9f6f85276f29b122fdab4deca5a12b84cd4b81f65e7c5b69f85490f5d1ec",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000a2acc45ce46226d6641951633797c475447f640000",
+            "0x135b52945a13d9aa49b9b4bb44709ea3cf93345af9a6a079099c07fe2729999a",
+            "0x10ef90f0f9df49c3993e599067f6faeaf16e663a0cde19fd032836f1d948f6fe",
+            "0x0206c377e029a95c67bc1a9f27ad4537098f830a00d0fc399040e3047e8e47f6",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000000000000000000610f4942c49cd7518cf504f3794bd536baac0000",
+            "0x09ada94a2d09ecd524dcdabda6c96387b9d9c64c9acc0ce0121443f59ea2cccd",
+            "0x09bd04de4e5b26edc69a6e7b3c3d02656ef36352b39da00991629b285696a296",
+            "0x02d2d2991b0e9ec6c3c5e41fa4cb0c60417b4c103429aadd1d7dd57066343a0b",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000039e9185606334ddc99a18465cf476226b50000",
+            "0x1d08fbde871dc67f6e96903a4d6f5304830dc4f2b6360c21b165fc9f38d26667",
+            "0x2ef39e64aaa3bc637d0a494b89b697fdaf53925890fd404aeecef1de7b12b45b",
+            "0x1571e4eafd0be12bf4e58e04f7f9e1bbd764ce0951ff39b331b1afbbd3d7c3e4",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000228d5ec29c63fa0ca822a29b5305c6030000",
+            "0x26b6a528b427b35493736af8679a841c931be2fbcc9997b97f5eb4bb70dab334",
+            "0x1c20c3ab26ae12b1b586fa4a6f7abccedda563b22b0741baab41348bd2281ea3",
+            "0x2cb5e878ec684cd074b1003a2e2ea0a5cf8826d9ffdbf3b2ae3ccb21c30ce227",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000000000000000000000000149d9395122a47abe7ced4fcea89584000",
+            "0x135b52945a13d9aa49b9b57c33cd5673366402f4021014e840b11e953ed6999a",
+            "0x1d1475887048709eb5ee4ec607860a2fbcd9e3ed29dde1bf596aa6a023a406f8",
+            "0x25169f46388d757ec304f3ca5da3497f320ca74c42e19a57dd4354f87bc57c40",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000000000000000000000000000c4cdb9eef9e407e9605da822f8ac000",
+            "0x09ada94a2d09ecd524dcdabe19e6ab45c64095e100597ef4f25d14a6e0a12ccd",
+            "0x0a983a1d2e2af63f1a1dd6054a142a830d921d33c6566c8a6b894c643754fa15",
+            "0x2da025958107fbd536b163b49d6f3607fc55d3d61c41ba12f489a518b9d8974e",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000756c2df1fda0a197815e41e69d000",
+            "0x1d08fbde871dc67f6e96903a4db401d17e7cd720ffd42c72b7c0278212ae2667",
+            "0x13f6c13a7b7b0960f951fde960497374849207c7e10698002cf7cf108413b967",
+            "0x1deecb85209766fde6409249956d62da43e644384c7324badb47a0c17d7fcacf",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000000000000460f5c1daf58bdd2e2371323000",
+            "0x26b6a528b427b35493736af8679aad17535cb4a21a95ca5542cac4e551a46b34",
+            "0x118e130b592b2508be3dc7a774356b424f24523622bf5e9e96e971406b09fed6",
+            "0x0b9cb01c17bb852374aaeccd338fc82a40e329ebdaf420a3ab53320b819d8d01",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000029cd1ad7037a75ea21dd8a400",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce6b10f4315774b88758193a99a",
+            "0x2be0a2a6e9cc10c7fad6516e548a62de385ab6a02a27994ddb8925c3cfc98398",
+            "0x0df6a3864a4cb0564e97ae0985cc93057710584aa2eb8c191885b5bac8e3c180",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000000000000000018f0cc3896bcc7f7a7fac00",
+            "0x09ada94a2d09ecd524dcdabe19e6ab45d4d72e74e34bcef3aa1c4c71b3ad02cd",
+            "0x1da8f76db6cd55307648b70c15f3b4a5efb16e837ebc044732b7a183aedbcb7a",
+            "0x0ebfb43926f7a4248e80a6fe0d7a5a07819a2b7de6dc09cb57451b6dece487c6",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000ee17ad0a35fcd91cc500",
+            "0x1d08fbde871dc67f6e96903a4db401d17e858b5eaf6e2928b16c7649436af267",
+            "0x0f86099e1a507897272b314d1bbf49c6fef64a27dc60bf27dfd2efd57f13b12e",
+            "0x047a5f98256a3ab4af4c633d90cc62311ec156ca0408ff44174a643c80a8bd08",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000008e0eb941affc868300",
+            "0x26b6a528b427b35493736af8679aad17535cb9d39494596554635b982e9412b4",
+            "0x0ef8b34e6212dbde61cae89f81a435cc12de778d931e4c9e31bff4862f6d83a2",
+            "0x2e240044d4480533d3df806e8a02ab7d31686ee6971741da40ea196d97f2bc51",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000000054c2265380ab0c40",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06836da13edcee9e9a",
+            "0x162649b85f4a0b7a5acbb1d94b88338e8227a77072429c792deb2b3da3e21407",
+            "0x0edb8cec9280461358aff234fa814626acd34f4ed64605c5af8391ad9b1b6429",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000000000000000000000000032922aea49fac0",
+            "0x09ada94a2d09ecd524dcdabe19e6ab45d4d72e74e525168373be038d8e26372d",
+            "0x2c2905f1de8af363d98fbc350421d64a13183f947c14cf64cd6b981b0e7deb39",
+            "0x0c281228c23673c5800fb4723e2052155754f4be3b0cadf9245f284f0f822ab1",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000000000001e2c4f9db2d0",
+            "0x1d08fbde871dc67f6e96903a4db401d17e858b5eaf6f438a5bedd5f601735027",
+            "0x187b44d18483b81b0160156dca32062f5ada38ee06d34d842a9e670667c25e73",
+            "0x2056879a19a0038778005def063c8b2f8fe4cff9addad9c28045a72d50d711fb",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000000000000000000000000000001200b2c230",
+            "0x26b6a528b427b35493736af8679aad17535cb9d394945a0dcfe7f79432cf53ec",
+            "0x23487958230bd60286efa0c458c56e79b1e0cab3f543dbc0005f4439843da928",
+            "0x07c407fffb71a9185c1a0106e93c896961a7c4f80f2f81dcdde6938656443880",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x000000000000000000000000000000000000000000000000000000000abdc5e4",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4b9a9202a",
+            "0x1a2508750d4813fc7cbdc83c052679d2bac3ef95df7a54c78338e730476496bf",
+            "0x0fcaf6642b1498525475e74befbaa506f5a9248d7bf442091790a75b6092dba1",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000000000000000000668ac",
+            "0x09ada94a2d09ecd524dcdabe19e6ab45d4d72e74e525168373f9fdea632b9963",
+            "0x22933f915e5b956e43f16df2cdcc9e4567571164235d20ef82a268ceba41d41e",
+            "0x25f368f1a9599123da6a2453f2558fa1a4427b63e945877209f5cad6800c89ee",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x00000000000000000000000000000000000000000000000000000000000003d5",
+            "0x1d08fbde871dc67f6e96903a4db401d17e858b5eaf6f438a5bedf9bf29999513",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1c66c18bf4ede32e6db2c4f3f3a055148a7d8ce64500a51d2e3d9b683257c57d",
+            "0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001",
+            "0x0000000000000000000000000000000000000000000000000000000000000003",
+            "0x0e847def438ee33fb74b481d26da00e8bf42c5af57b7a1c52df6fcdf94cccccb",
+            "0x244b3ad628e5381f4a3c3448e1210245de26ee365b4b146cf2e9782ef4000001",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+        ],
+    },
+    LoadConstants {
+        arity: 4,
+        full_rounds_half: 4,
+        partial_rounds: 56,
+        round_constants: &[
+            "0x169e0b2aacfa56f4fd84ea67de23ed61558806a0b86ab411a28baa592a9bc8be",
+            "0x24c4554f0c2a3255e1f27b4c7997366522f222c2469c36eec41b375637fe56e1",
+            "0x1ee762d4fa1d3d2b4d39154b36a508af77a824e7cc05a1e922480a530c723356",
+            "0x07156e951beb076f80c43235ae5bda7314d6b61e53c9a2132fc9d26cbf1e3ab5",
+            "0x2b7ecda7629b337dbbe9f7992d441d568485c9ca616305d90acb57233068a16b",
+            "0x156636f270bf86b33f8bac3b4ce391c0658a7972c72bd28455499bd63a81b48b",
+            "0x2aa9ea99e269adce25eb7009b1003163c40fcd5f80f0d00ec5a25cbc67f3275f",
+            "0x1a0e26e882d0d9265c600dc5202a795d71b4a82373c745c7afd5ebe58402db81",
+            "0x17b4114bfadc9848dbc767eb1df1a7ab11b4089b25ab4435befcb09f2c665305",
+            "0x16de0e3b05550289309ae155bd22411a2b8a2ff1510e561be7e48ff2271b3c6e",
+            "0x26633df5b35f76f44e25ad2484c6e3f2a0de2cc513bc1ee39064e130bb6f7156",
+            "0x11fe4f9d240bbdc0e266b03d7769f1623299245c9bcaa1696a1c95bb13eece8b",
+            "0x18ecb173cdab487693e3a866d5b9eea63bf6dc5fad117693999b555253b3d039",
+            "0x2895fca8ca7ac1e6435317ecd0a56be46c9f3a93158b7ff1098d9491b4d3aae3",
+            "0x266c1936d93c56095da04e4a631366acf778915ce44352c27ad2ddd047d3330d",
+            "0x2fb2d1f46c5b7cb8404a3518e7068395714f5f4057266a3b7cda823e7faf98ce",
+            "0x2425275f8b8d26b64ed9e5d9ce6dde5470470c8b838baf51f4e67b3d24f95d3d",
+            "0x2ffa201fe303987651baad9c7b25d46b5f0b3ba32fd0c743ecf754a463fdf7ce",
+            "0x2dc540e5ca7150e24fde2d95bbc14a5e1db4221ada0c2186f2a2b7d532a019de",
+            "0x0ed4a50cb3220099a25a09e9fb1e56a311ca1b488000c068e0aefccdc0a664a1",
+            "0x2677dc28cce91460e3ed75585a40b1650da7dfb399fc0b8345c2b61f3c4e4872",
+            "0x1eb5c002bfb9c97cb095593a5f02f569c779271fc30986e5a15f9ee2ef1b1b15",
+            "0x1fcbdb525f84f6666b5d6fb36233b81aceefdc9ca8533832dc92a05f21a4fa8a",
+            "0x2a04f582c03901e201cf023075a1c2f420c2e28419b4d66bd89778d13b1a8369",
+            "0x057ae6a083d15577084d78a7f8bbc0ec6d436ea4610d79c5517ce600211af680",
+            "0x16e698e61ad9a42e63eea87f8c78cfe295650096419b8a03b5b7ac4fb1ff75dc",
+            "0x2d09fee83c2b19a93a4d3720efa90d85b12c2ced876ce12edd430fd4976896b0",
+            "0x2f22d5e9362c742d17689d769f6558a8e3afedb63c75774e9e8240b7bfc2aa78",
+            "0x1b89f1d45c654f6217b796e87bc6d31be1d0aeea985448dd5fadc16e1bc76ea8",
+            "0x1173fdd4c6a2f6ae8740b5c43a34105e16129d8ec5ffa994981f0b49fb619250",
+            "0x253b7dfba9684cc17725dafa7363c04739c0b821dc95b77b934d580d98dd57dd",
+            "0x2a4bbbf95ff0234358c86d958878e73ef766393f460aa72f6a86e98798e2d139",
+            "0x19e1cee3d682a731f783391068c4d91c077cbb3351a72e8dd5ce16d95aa74616",
+            "0x076471212a4126b7c8a9bdb05f97e3afa82a000bd29c096497b0ba9224ba6652",
+            "0x2e30ddd63a5dd27d5a88d46f37e4363ce4569f491943138fb5b5b29f68b71f05",
+            "0x23dc009bab202eddae53bd2611a6458c8bf68d3d8bae40777a0252161754946f",
+            "0x2560686ecb43be4bfdcd4390dd8cb23e418d2d8c0eb2560f9d319733c6745e42",
+            "0x0ec61983953e149ce953332ed92c7c2c2a3d25dac612902bd8898132c9c548ef",
+            "0x2f8da5c734b43036e7b74637d73d7d4553c96b9b02acba3196154838eb89bfd2",
+            "0x09c51799afd698544bdc390fc55aca08fd5bb39b9ddf5c19cbe26739d08009ea",
+            "0x0d891c5c307e9b33d3b089b7cba8fd6c17a42ebe4f2ad7835151a0c98ddc8ad7",
+            "0x29d65d3d60526b77f979016280979cbbb3591fcda5c96c27caf4b0b5e6b63f0c",
+            "0x0843bf1f00c9af84ea5d8c78a4c3fa16ea8bac2565c3e5992f893e3f946bb010",
+            "0x24b4502f2c1df7575175de2745bfed0c4d525fa0b572a6d8f907d7262e41df3b",
+            "0x254b940db70d6ad182e1b9181c80f3456dfe4000ec160f141ad9c3913a29d9bf",
+            "0x01f22d358d2d10227b70767dfbe851c98182fb869bd0b5f0998af60a944c24af",
+            "0x12681774a08c9b076757193e53d4efb9f0beba0bcaced19f344a3b296c9c26a3",
+            "0x00b71d308ab25fb329bb9e7741de49c4a2f041664400b8119f0f0b330ab9800b",
+            "0x22cc2838fd89d88861ef3922b3efd5bc0b8d339ec83784ecdf86b35e1772e428",
+            "0x232e25d433d44ccf89c4a824e4ffeb8802211287bd903d7b6237fdcc12fa1a8a",
+            "0x1112d7be46646516ee8046018f7c88e7711609145a2573207e1038cc15859f5e",
+            "0x2e9390db50989e3ed3dc154e6df089e5075b6e328092fb908dc62dae7a40f60a",
+            "0x2c24eaab86572587ac8b812f5f1767a51197e2821e5e31a7c8b57aa00e075425",
+            "0x07a062585ab60422331f06a78dcb86654d0f11f5fbc8740dc409fe198a11741e",
+            "0x29b06ff2f10190383f0a6b1dd4c67f5b3c575e2950d0a68f6edfcca28594055a",
+            "0x0387218564be26bca6deb5d1cee00e5b568ba6b2e9c748c43b2514233415e144",
+            "0x19af649eeade9788104305e1ffe61a91e7285cf6a96f3f74df771dade5ad35f6",
+            "0x110578cc2be2c66a64ae5e940d37c8268cce09ef37f5e0442d5e63b1945a8c40",
+            "0x147f530b640523410ea5ae046b7476588b31327ba4ffb67898d594c530e26e8d",
+            "0x2ecca2e386921124947f66976fd6712dfb2c8dad5b9a85a21f9bba1398f2ee79",
+            "0x191047e6965667f0b3af3d30cbbbeb676b7ad1d4f30cc1865c60731357dca73a",
+            "0x007747851602e25074171e2ac107b05d1f07932e73122662b521158a080819b7",
+            "0x22c95f719bd39e7baa91609126833579f85456763924b7b953578c3deeb72295",
+            "0x2307cea0013c6cd9526595fc8e8390868f5f5cedf67387eef925ca35b1651b4d",
+            "0x0be119e8dce36fb7f2d40746e50e947479e33b77490ce498a365aa599016a9e4",
+            "0x15a816cebd75b239de7e68583fb04104bf0b716f2e9c2141e5d87e29bc0767ed",
+            "0x1f209a86ffb49d13a73d0067679af67c81e9edc15795510d8414dcb73a5c26f7",
+            "0x20997b992fad592ba4fa9645546f3d1c490373dbb1d10817f91df3e02947e733",
+            "0x082f1e1697e2c2a14e527820ef646d603be852cc4d0f8dcfa9b7e4c0f4905a80",
+            "0x0c3fd7be3df76a27612c1dcac42b5c898037149c0d324c1a6acef310a7b57f8c",
+            "0x186316dffdc832976d96f5812d6a32cb8bec9b23eb0805dd9f075a07ab1d8799",
+            "0x1bd75e47817dc4f5624a49db1faf2eb0325cdab98695c816f1ce7a221ae33189",
+            "0x1a782ad1dba398aa05b524d0a27e7e81c42ef82b843a8d72b4203a947296c9a0",
+            "0x149ae5e89fc3ade5c51434248f47590a939131f47f6a70eb89d8189c8582f03f",
+            "0x00d1b17cb5ac25cb59ef93668897a78644f2d5284bbdc08a3fdfdca83b3d46be",
+            "0x1fa82059e32f8d03947c21b4801b637e32547d46d232a6a601553f3e64f405d2",
+            "0x1e6e0a8ebdd086a4c92abb751d3caa58a0f185271cdd8b7c88f960c99c9f86dd",
+            "0x0dafb5af53559f9bc3fe1fc3eaff57bc6494e83e6fd8e00506ebd87653aee732",
+            "0x1f4acbc4c22027fd8e8ce41858564caba347efbd08f90b2e057451f30bc31d54",
+            "0x02b232cfee0a9c3dc42ab9fc7d0855fb755cb8d0d6b18cf6f64e7bae8a752af1",
+            "0x1f0ca0b1b09dddf368c0a239b05b2ec6ce419c490801c6f03af0116d959a7233",
+            "0x0036ed3846e3272f7e6a31c605acfed1fb72f5c83b0d498acb17610a86833577",
+            "0x16352d565d30eb7d883ca5d91f97ccd0272703a5b681c613151c3a171fdd9079",
+            "0x2c4da9fda9f5db936fad890679e206dc5303647a19a8c0c89d6574b77d040e13",
+            "0x17c9235b71cd6fe7aa98d569de7b45bef55aaeef7571432b375cacfb7a245b96",
+            "0x08c33c60d76d6485fd0f3c450fab4797db323aafd1f094e8c1d4c162d28be3d1",
+            "0x1ebd50a2a77f125d29f54f292c9a3e5ac6e513b9192f225e79602abcb2775b19",
+            "0x0954c4bf0fc598828647ff84e86d8fed880aaa908a7e2e6fc7b68a489a194bde",
+            "0x12dfe12df74b9c5ebb7941e16a65a4673c1f2b25d4f8d75d7c17be4139f223cb",
+            "0x19f4abfd169bd29f386a35a5846488f788f2a96ca70cea668624c96f818ccd4a",
+            "0x21c51529573fc2ffbf85f9dd034347dcac314c71fb52d1213ae5dd51be347777",
+            "0x1e232f4fcafe03b469c4d752666f482614ede68a40e36a9264e4cc461cf91e24",
+            "0x2b7c69dfe972d131cf47c5b72f91bd20b98218c8f99e1bac62cd3f0f5f3e89ed",
+            "0x18e78de6dd0d639794fd6e179c3399f355d6a68c79b21ca1adbc4ca348c32f18",
+            "0x0ffe9a0873ed70538d470e25c6d78cbc9b65365b8c6e30aaebba1ab396ef1c28",
+            "0x060360aaf54b42a7031aca2c081011155fc20297a3931034798e3abadf29ea5a",
+        ],
+        mds_matrix: &[
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+        ],
+        non_sparse_matrix: &[
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x12186864dc80dbbecb24b0eeeb783bbdfce3cb31259124f3f4214c296fd6ef8a",
+            "0x2f0443344b1132c8173e67140fd760b5fd139cc1a91e93f923d24ea2541116c7",
+            "0x27a4fc8e49bd57bc1a2800074155f0ad8bce8b4d80f34258f9a139d2f0626cdd",
+            "0x0c672f6deeffd332d124741b4fe0c99dc4c03698d889fb5633b30309923a280a",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x265e128a01840404106e5c8496bae285f46ad1c8f91af115d608f44e73c8a0f6",
+            "0x0eeb20f669912c4997b46c56e31a309623264eae71a59b9963f15d8933df1e3d",
+            "0x03c90d4a27f69bd6ca59a63ca2e060000906d64acae71e78decae1d0d7c59b3e",
+            "0x15f3e44c50709b38d64eefef9ad01a320210560957a41515a17a416952293fc3",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+            "0x0eeb20f669912c4997b46c56e31a309623264eae71a59b9963f15d8933df1e3d",
+            "0x18e6fef152fa43f6ae345a4545fb7caba7087f915fb19342436fc869c3599221",
+            "0x178a4f8025d720c7b389a22b81cd373bc697451b94ab84ed25661fc4d2908556",
+            "0x21e530a3f52f73fe993c012783961c8d48f90057b1a0abb39fd031ae1d732e92",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x03c90d4a27f69bd6ca59a63ca2e060000906d64acae71e78decae1d0d7c59b3e",
+            "0x178a4f8025d720c7b389a22b81cd373bc697451b94ab84ed25661fc4d2908556",
+            "0x2678de77a36d789a6620e36d016b63950875245200a5182af154dfcf7dddf55d",
+            "0x2e4169e5ce35c05ad9896d3ecd25e8e9d425a3323e33c79d9b423f7eefca9c34",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x15f3e44c50709b38d64eefef9ad01a320210560957a41515a17a416952293fc3",
+            "0x21e530a3f52f73fe993c012783961c8d48f90057b1a0abb39fd031ae1d732e92",
+            "0x2e4169e5ce35c05ad9896d3ecd25e8e9d425a3323e33c79d9b423f7eefca9c34",
+            "0x2d1bf7c392411bb212b4c99097fe5a620775d5e7219b1114f8f4a0fdf8ebec78",
+        ],
+        sparse_matrices: &[
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x228e796ed5c7f947963965a7b0d413340010c6523a2d5bc190335ca0bb1f5a26",
+            "0x066cc0ecdb7c37b247de663da73739e5c43f4dbfa289f1963bebd5ed73388a55",
+            "0x10db5b0b14dc9f3127e9720ddffa2c8e4b80e948e9681ae736283987bf90ac28",
+            "0x10767f9194e01c79a179667edfc89ea9e3313faaf8f5c5313fe15d8178f7c590",
+            "0x009cd02f5f7d8a9e8e74cf5bb404bca10360009fc488278509f3f1f09ab999e8",
+            "0x0643d1513fad6d996ded3fe3c247ba2bbd2d5bc728c0aeb7099c350994cb06d8",
+            "0x0dfd57aa55faa9ca17bab49846b7aac429474eeaa52d577f4694117185487b72",
+            "0x210903207847a036385d4cc719f83ca192df728017c73b3f7638036c203a9773",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x12cd8f32c5010c4f30ac17f2b2cddb8042d416483f5695fb1135efaacd5ab25d",
+            "0x288cdf621c8fefde436ad83411f957671188f2b4cbb5cb82b4a0260c48fb0607",
+            "0x06f24f2a47042377e918f6cb0db1667c4d41d30b68f35c29118d15664e7187ff",
+            "0x178a7317acda240bcb5b68eca33e2ba10c5584617f6d7a7cd9ee36ea5a92c5fe",
+            "0x2beff2558204204bdbd59fdf4861e84fed37f7d1a973029a74cc3a511960538e",
+            "0x2f17c31190c9ce656379aca43257e030b04c417cb3dda5dfafc8cf725b976f20",
+            "0x1096f97549828bb838daef7881414bbee02d972a7db7ed3efc66b926bf762afd",
+            "0x013fad1b89036b767238e6520b442a1465a7fe7c3511dfd40f48360127e69312",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x28e531e35bb9af32b9c9bd0233cd20fde96988c4c69e074fcf336ee69da5e64a",
+            "0x169b7def1d86f2be27caeccda72e6238fd1aa04110144ba91d7458c444787d8c",
+            "0x1043dd9db3495dbbbe053f1294b6f91cbb4c4992dfda59882453c1d91da2758d",
+            "0x01a528cd21441ee1a4a54a88e8312c0d758144bb44d9865272efce253628964d",
+            "0x17f4ac3320a68a9d9a63739ff0b749c9f5d0cea76e503e19a17bb7965e1a85a9",
+            "0x1f780676c2c705c377571b4be0b28f5a43a9b6c3c6aeb50be482825b0648815f",
+            "0x1d7a3c7d21dc6d9a048161bf7ee1bee462fad4dae915d991476d60100875710e",
+            "0x22740ce497b8e2b281940a5b0d4ffc135a225db09b872152c61b1201bfa9e748",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x1e751c21922e7a7a34892fcd81ea1b5b921c0c99cbb943ca765d294173519844",
+            "0x1ca26ab1edc1f6d4701e82bef92b1b6c6a602dbd50a0571f65b8043ee6ed1338",
+            "0x0255b31529c743256f0c78e8f3db3dcacd6788387d174145aefd867fb51b2bc5",
+            "0x06dd8517d1756f0b880dc05fcb44c962dcf39c9455c968a3812b3b00d01b4958",
+            "0x1f24704f2a2097fcbee15095907c8b4e64039c6c6e123d6507a98c95e1459c7b",
+            "0x1d6904226e3fff03c958437b896eba6f17c7d30b4a30aa8be8030ec70d478d47",
+            "0x1fce1bc50a60c4b86b3513421d26acb3a0cee7a26345e460f13d45bad584cf0b",
+            "0x1063b36cb9d7c19c9d333e9a83aaced3fec7c6c51ed433136c01c9c98f99477c",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x092efe7058e58dc95c85256436ac60c4afa5cc5b7c187e5ef14f7b81f269c182",
+            "0x05fd29c93a6cf07be9a1247b2bbddb0b7ee575a78b7733c9d215485b92b1d1cc",
+            "0x036a305c7b2d85167df1184b8d0ddd76b10ec70fc6a2494bda4db067019b6ec6",
+            "0x00c279b2887f425a67a8ff433fbe35099763763daa8b3efdef4ebee914567f8f",
+            "0x2580d3de93af4358ce1a8962c7e7687115e3424a5c335a52cce218cf9c893b91",
+            "0x108c3f9a9ea4dc0716d250ca8e014f7f07891e74dad5fb8ef3c3db52ccec722a",
+            "0x164d63a3a35097e952f13e6f8bf888d6d8020101eca241615fa14bf3a0210236",
+            "0x07ad570de508df15c6f58e494d49aaa709f3b69b890a8cc0cb46d5cb3ba7d502",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0f1cf949f12c66dde163953a354aa728cf04ef63b4d7c257c601c6493455ced5",
+            "0x0e77f0eca96357d36ddd0a0be9384b8c0293c5ee22a98fe5d92f7d33d55aa587",
+            "0x024f8da0b9df4c95582b422204ff77fabc67f682298a05c12c18420629a8c944",
+            "0x218f35d4a34774b912a132bd52776ef906187043c2c0fa45dbd220cb072520bc",
+            "0x0605634c125288eed185311c5d355c75a102e0c15474ea3925aadd2a6bd61081",
+            "0x14ace366154ca370f735d40eba92d8e161f0719d6d714331d36658ce1431e2d9",
+            "0x1d7f87752c59a8b46b0d4236f852b489ce137926dbbe1099aec5fa648d183eb0",
+            "0x0748478710e0f473c43a5e46a26390d17f58f0ed8ce3c1c75f11d10f5332bf6c",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2398fd0deb6188256eaa1bb69343b2b3b443e5ce4203f209abcd6b608daab41b",
+            "0x14eadba1fa241fa2052fd444d49d8815f495ded75aed298c6f56e77b6db5b8f1",
+            "0x014b29445ea552d0f79c48f5a8d0b66246e1213523eb20a8d15dfe4d07675d45",
+            "0x2d0c1fdede30e0c983d8d0cb34fcaf7690781263f4491a082b77917df5b5b429",
+            "0x2c4223c8e72c2d3a0ebcc9ec0c9ded3b57a63d791d229f95f1b70f382f9e431d",
+            "0x249d0cb4b5af1e3aa5fc1db82737d16c4129b72173d791a7bf7e5e40b2838769",
+            "0x2aab42814eda15d104b26d3494eb13cd2100ca2a8b07d1336bd820cca17e520b",
+            "0x12d58156ad18d3556639fa803273d959438afcfda01cbd880e5ac2804e0acdf5",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x08ae441285e4d3ae67a112219ffd0222c9299c164bd9b75991f4300c24475f69",
+            "0x0632273ddfe2743b4f05b965f13281da2dc572f0f79b0718076447ea89d417e7",
+            "0x0893095095ddd89669101b02054df18a977435497156f071fee357ce9c43c13b",
+            "0x1c5ce402b3d24a75c25f909e02829e59d2334d3e33f16c28dc7b5f4c96f9bba6",
+            "0x0d599e532ac89dcc677b5e38810c50d66d01c5a6819f4ceb26cfaad8cb5c00f7",
+            "0x120ed75dfeaf40fb3dbf3f34da3743a54be5f2e8e704ac46e222b1251d26b0d1",
+            "0x184c0ce6788755d79f5a9a3e688b8497d432adb6e84beafc50a425c7553d665b",
+            "0x0fce114210ec3d998ab175a9ee1fdf0db5fc59774c99768a04e426cdff379e37",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x13fea34302022ae4aedc08b6619d2bc6d63ae96cf73ead3417469ea94102df75",
+            "0x250afc46b2ad7b51e79a03d468a0bdb976cb6ff902262af07375976bd9198162",
+            "0x300910ba19602bcb45d583b699510a518eefc783deb27777aaf859d9a966a7dd",
+            "0x04dfed26e1efc0c7fd003d88a30333b63150b204340ad8ccbef2ae4f99d03778",
+            "0x091475b6052b33550ed1c1069ec0b429ce0515f0d5a71a51e26eb2baf74b18f9",
+            "0x0c8441f4f2305c0444c2f098cf5dc0771e90d1797b9ab46265ba0812d88fc3a6",
+            "0x13fae5349d461eae45603ed559ea2350155acd38428efe4bc06dfb1cf3421fa5",
+            "0x268433735af51cead0fe27408f756c3f179a901db519278b5c711dac122f9458",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x26155f9963b80d97a600392e9979215e5a9b10c27596b25bca242e192760ad60",
+            "0x123a865aeba752a9bb38c503ed8bb8108fe1fa57dc009d61561533958ca57e96",
+            "0x2dfda635efeb32a51c3699136248cb8e2a48b5d7faf0b9808c1665a67cbd3af2",
+            "0x24399e7bf7e66ba090ddf3bbf7926b0a30625f4a73316e51a58d2af98f5ab32c",
+            "0x15b6aa180d14224ea15d767228d5ca925ec3e8e56650a8af4a3cf1a425a8c013",
+            "0x1669bbce693458b574ed2983aae421a0445cdc3e46e4745e5007d230aa93f8c3",
+            "0x0ec1694e8ea609003720ce08a05f940a5c28a48726b3ae18a13ad356b652361a",
+            "0x0d419947362f8a56c3ede6b62fcba92ad05b0ab35be88688739dc654dc2893c3",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x02e4efaa712065f0871ba1844b7f03bb174f799200880c242f8f2a024050d8ee",
+            "0x04e6e85033c5a3c8d79b01385c7a4a0790d3f70520aa024e832dfdef0d89de1f",
+            "0x19154d5e88afe0601281b881cc8f2924a91fe5af2f1128208ed902531ef66135",
+            "0x0484005e424b8068f5da1898eb25213c68f8722e1e3adb3b87fefb13bf43ceb8",
+            "0x135c1c5b49ac834552627b270ab16bce6edc71e1e0b6acfac90a344142bd01dc",
+            "0x1f862ede07a6f7a970d8f443269118d2529bc5ecc362bf8b666761fee7db6941",
+            "0x12f865c0cb28db0dde7ca1e587f7fd72ac7f54135397d081c388c67a042ede8a",
+            "0x30266c3433fdd1a9eaf208a08cfd7b526720b1b4add9fd54d1d787c4ba35f1fa",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2b7f2aa8c58e932feba07bef9496e73cb05e810a912084c4fa053c0b33ddb867",
+            "0x07a993dac535f190d9dfd945637b8ba6824c1cec461029c0fb0406a16c886372",
+            "0x04c9c4a1de97353757d15ea5b3dc64e835ab68efa91ca4227c58b8576db1a7ab",
+            "0x182307b3e7ded3a8e63bc3481bfa7a245615238619a1d6b056a005ba5c0c87e3",
+            "0x18c614b24e674517a94e5be378793b3c9144d75a815a407b5b6b3c5df704e300",
+            "0x058823baf00f3a6590519ed2e9ed4c686fa78a32929038d0c94e04290b493ead",
+            "0x1cdb1986a0b7277263ed8dbc5fef881138117db6601d7fc389ef5d8c09a1b054",
+            "0x1adcfebaab5d1980a64d5c113cb1c82d3cb43300574f637d7115a024350de9c0",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2c5656502fb84899c90daf81d217e4f2bc82406053b0434d9b407bddca06bb36",
+            "0x22066c29407c96c06330d0339d81967e442583965595ddaea7d0b84176deadd5",
+            "0x1ef24c54c010c8f8971cc783f2f5cb5b19900f8868b5bd0cf2429be9b3c52c97",
+            "0x1c7819b4ffaae41f495a4d858a1e9ab93f3435c8971651bbfe50f84f7ad3d8a3",
+            "0x03121a51e52ed2c763f339348d3388176ea9bfbe30159147cfa65e2bf337d457",
+            "0x2e761ef796f88be491503c47a5b46c8aefc77316de5d48e6c2311e0bb2fb9860",
+            "0x24f86920d41520ec7cc101fa8bca15c1750d58b4c59613b749750280feab7f33",
+            "0x185e55c077b9b80e2621572ef1aa3ffd99b64171b825bcbdc8458aabc3d9d7ac",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0fbd4d1f7ba982095a4a2d40b05daf49e0cc392d73044ba716b402df35168497",
+            "0x07b2a451043f5799065fc3af575e840380c8e0f35b79d7810ded5966679266f1",
+            "0x0c3822a0e0770068e4718ffd7c2cacceec7d2caed6193bcf8306fe4a9f373ba5",
+            "0x1c60254b859b5771e49389310aeb68185e916435cd01090d3e53abdd170f3e65",
+            "0x16240e77bfb1be0b60dcbc45b21209f1f718e1115c31996fcef5cd414f8f1764",
+            "0x298f205703c08827f53bcc3b064c82d7f9f9492a0ff8c80517f602768f8d9ffb",
+            "0x24402a9aa7a25c3f8101c6fc1c2c818e895a0b5ca1b3e45f69c24dc92b1fdcd2",
+            "0x27ed717c76f29c2dc6de116796e7d68500e3d4a2684ee7c7632b26a4ece1c303",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0db94ac5e1c8c0b656cfb86fd3a8b8fcf9e7bb25dc3622cc8aa9d025eb507ae6",
+            "0x033d41eb0bf32a4c4e8a3bdddeaca83474b524c6e81afe645a4aecd584c38ef4",
+            "0x280a18e7a4b3c7d7dd034d9b6d88bc87cbff19bd304e45d4fbdb7ea38d8219b2",
+            "0x1357fc67e34b0f170fc6fb3cc3688544432be81c9cf07498cd7e188ff2dec3ce",
+            "0x1218ed89673de74811290bf60c642ea1a7b50505b8088ec4f89e03c53377e4c6",
+            "0x070726be069a8e22a239ac1783221b03b600768c3c0c00f10a0a6766ed34af3f",
+            "0x1a257ce6c8fb037edf15db5c9b6b3cbe4fdb83883c6b7a2357cb6436967c5dc7",
+            "0x19cb10d65f03c3076fde5409366c028d015dd9c3d98142d5e2a34f8c6cf3e9fb",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0636be392e6c707258ae26879daa68baeb3af1cecd645f31bc39c54d3e1b91bc",
+            "0x2ffe487d216d9fe7895b92ec1804b41bfffdbd89009ecf380b53ad5457395b71",
+            "0x2f6d5f6d236dede1c75a8dc91c286e8d8f5eb41ea37b6d707948c64db4db7890",
+            "0x0e4f0040b0cb30a1fac80fcc29d29823aa17b306d13d59c60b4bb931206e48dd",
+            "0x1abcf299722361c6aa49638b901bc633ff927b9ea777ffc4e2a40e9e5da5cb02",
+            "0x1eac5d4bab7557497bf73d6c2b8922b5d08bd1a8de35e19e086b68f1d63515d6",
+            "0x02862a42becbea7f32a86969577e4bd545a770d32f15a027863240f943955aa2",
+            "0x226b625c0b6b035ff4570abde8a2f09a3ba45700561453541706cccf456a09a8",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x08137c4761172962927d027b95f2afb24429201c2d327e8c7f1dc4541aa67f31",
+            "0x06ec2f454287d0fe151a764dc66656526b7ad6324e1505eb41731f7bd0c06b1d",
+            "0x20b89cfd94c4ba532f1a5ba19b39127ba500a668c433e556eb8c06e1710bd2f8",
+            "0x1741f6c6dfa177ecb8eb55a06714fe9fa11256174069dd3f2c0ff66e19d04660",
+            "0x2542590fb41dd71ed0f6d4129df3b23c968cb17790153311cfaeeec9e1f038eb",
+            "0x2431ca61786c736dce5ff7e82e03232b931a87c598a5502f87e05c4b4fdb671a",
+            "0x186aca01602a39daa40b4802f3a492e0d2dca719cad80643fda80871f928893c",
+            "0x0ea7d24d17f01ef9078976718ca6f8e0c07cd42264023d41920dfc31e95c59bf",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x05d9557d8d789d076efe7ada9dfa06fe2dfcf9e146fed368dec44ee0a75913c7",
+            "0x24366359d626aee236be34ca12326bb7155e9a9a28b8add4f3d72db6ecaa86d0",
+            "0x028b063a033fbbe21e7f64ddb8e18dd3c214c9898e4f429460714f2bb02cec96",
+            "0x0779bf131b6912332cc8abb5df4c07398466212d6ebfc0f327e0ac8f98387d53",
+            "0x001b3045a3f7d3c7c28defb0a26a9bbbd89ea5aee24d7aaa4695e55c7e3174a8",
+            "0x0b0c507d5eb67ddfda8c561db9e72150788a53a5de62e4a2065c6584c7903904",
+            "0x0934d042ce18b5cbfacbfb0d8e99b9eb67315ec62f6d50eae0089de60193d08a",
+            "0x2178406f1b42c926374734310d49f7f7da05321530869bafc26c3a17e97cf5cf",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x124c54a8b1b6e960ddfee6cbf2d0b47b4852e8c3c70d38be5eed87408d09544d",
+            "0x033b7d247f926e0203c246a946463af2428d8fa3a41c0b47d5a4dcfcd77fb198",
+            "0x1c9cc7cce50b6dce57e8fe26948b821f1f2114fe6f6fbe8fab3b7438e8b2ff89",
+            "0x068951fdb24211c4c429490d85e7c0a033abc3ad15b7a531b5576e5edcc114bc",
+            "0x0559a6a95dbff901b23b5734a4260b7c70986d29f4828caea2a7eb8fcb9f53d4",
+            "0x0d442e4d726a504b7ce6545c295ffff35697041fe10832808af944a29df88807",
+            "0x2dec341164fc6bdf48c8294bdf78648c883028804f21b101d4e0c302344f8c16",
+            "0x2dd4888bae80db359791a6a57cbee3384de1157ddbfee1400d304361750b2267",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x106027f38f8fdb2b92675db5dca92a9d05bac796a11cc9d5cd7acbc09fba37df",
+            "0x20ff9a7b1b8a40da870f69908e88998bf7a41ce07262dbcc5316946929a0a42f",
+            "0x121d07c1da893104e33958fdc9ee4474225f761225958fc0e8333b3762b7feb0",
+            "0x112a4a32fd8826bb36c25cab1f27dfe82c91acb3e857501702561bd6d79736fc",
+            "0x1d4dccbb1615c82bcfa3151f58755b090e708b02500b1dae902dc54e8e635588",
+            "0x08b10f0014df2b35bd2427faedc9519e9db9d2168cf396a134b6eaad227c892f",
+            "0x19161e123e66018ca39f88d520247c970700f1f7fe14a858b5f5732e1849d304",
+            "0x061f5099145ceab529ff0d30548967ae8aa731b07d364eee16bdba7a73295aba",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2ad1c2616287e866e02d1ddb1cb8342c7b3b1d0c633fbb92fa011b3d79fda03c",
+            "0x071e28f88e899583f8bf16bd94211c48420590ef8c4fb707b14110b3b6f8a891",
+            "0x0825c7f2ea46c5e83da8d1f69d35d178dbeeae76d853ad4994b2c791dc0cf196",
+            "0x009ec6d92ae8f9170394330238f5783e983a2f1093312ccb77c77c707c6fde27",
+            "0x2f1b0298b694daa837c342e21f21abaedcaf3bdc1f778fd697bc3691a2798e33",
+            "0x1f12dd315d3f2f3df0fe95cc6963ac088d5c87c59a06f1d513ab367a627b128f",
+            "0x1478e638eb7eb48b7e494cf7e22a004920d5968c2e6ec3a7eca860b5305747d1",
+            "0x03cf7bbf2976bff09a13cf378395bd72ae8bd527fb42c953384d3ca2bc3ae732",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x1a464f4309d40079b4763d98c26a5704e1e3825764c2894feaf6b97b487656ed",
+            "0x06de2777cec8aa2aa0c2cf3233bd4007cf6533ae71952603d98ea3b8bc58d51e",
+            "0x2698ada0e457feefddc45937336e14f75208e16c0d6d8cd405c15512ee7e1f91",
+            "0x084e7813a1aef7733e640e00600d9a2d2dd9bfe94c2eb5180af8bb88485da28e",
+            "0x0fca5e7115b84f7e03cc3ab6a78b20fa7db146cffb8bb2f4d09d4d2009d4111d",
+            "0x097848f62d6d212dc9c528ffbb55f17e5e7dd778c229f34d2a7102ed16b8edf9",
+            "0x17964b277ac23bbcfb33363a17a90141120ace4814e15d3f82d842b7e2a27feb",
+            "0x140d8bf7b7159cfd49ffd0b3441044715ac1cf80611f35c83ac79c57369d8507",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0f392528773d0cbd5bfc07659c4bf4fc4b8aebf9069d915d2737b0fec308b862",
+            "0x2294c63cf3506de12fc7b0a5792d2f3c1b95a5bfc60aab608c37ddac616b54ad",
+            "0x1490a3f424e41a08590119bdb6343114535c36ed0362c0bbeb4706e995ad2df7",
+            "0x2ee0130287f071a93b7a46820dcc812e671ad0b8d5c2d893894aa97586680a16",
+            "0x29a444ed99537cf5932a8af3bff45fc281cb324fa5c059724e7af445da4157c5",
+            "0x0e1361acddb844d095b75752aae31dd8912278f83766e50588725302282e9e4b",
+            "0x04d5033334726e8688c6d1d5203ffc7f3b45e29930e92dfd9c54916001a20008",
+            "0x16a51006083a9c9cf36bf58e94fc73f766c4c8396434a45b56bd981f0e011cab",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x300e016f1924c94509d33e3fb15b4dba3684f86dbc97c88e2c91c470caee0903",
+            "0x1cf38ef5e03ce828f0d3f21d92ff4bcc339df4fbfcd753fa03f685c0652756d8",
+            "0x10024d208619c64988bffab879d42593ad8261db68f994b120de9b4d81241d94",
+            "0x255e3f879ef2c6a4fc4ef8c5d9b4ca0d921ec81317dbb4ce7c9296bd8e387c67",
+            "0x050ead095e156b47366882123356b2ea602b0958cfa20ed437f48547465ed109",
+            "0x1dccd25cc9062613d6117cd7198160f37b14ea54765038ec7d7fe9b9752e682f",
+            "0x1eac3c48f2d650dd96c607fba8b0aaf4f14ad0887f27bf41fdd161a1dc0eab33",
+            "0x298ea00f8ef4735a6a96383fdd5b06411d3310e6b00cda555f26bd8aa4a03d85",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x15c2aa082cd4ed63e1201095c6c4130240b39d75719a1ab4cdfc8906d07db04a",
+            "0x279417fbc5a48bdc3c754b858d1dbf08d542bc1dac02e39b913614965306cca2",
+            "0x105907ae8b6ba08af26892fd4ff5c4edcf59a5678ab357f0281beae373b8bb76",
+            "0x1106cf5d2eef1a6d6447ed76a95e07d5ed34f4f462469308e955e95e09667e74",
+            "0x0d14b19f92ab90619db0ec92ec8368125345725a5b537feddec838261e714072",
+            "0x04fa4ee49aa26b4b2e056aef63d301e225b12c58d14af871e3e788611238db6d",
+            "0x0665820b3cc471048ea2cb66f7391c257b4cf942675e2849aaf5c07f26f8f6e8",
+            "0x10d422023a22ab655a1ee3c2fffbf065698d2a80b775ed0ce7552495a0ed8928",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x1372c3549d1d33d058fde2131c370bb997936783349a18aaf2c163594710c498",
+            "0x20b64ad3f2e6521f2df0c80e2dff4edacdb42ba64791ea0671ee397ba239f665",
+            "0x1cbc36843bd8ff5de126c1c9d61cb7625475568770bef280a51b569b1b49fc01",
+            "0x0dcde086aba31f9dcc54be73dfb5287c4c0252b06e865595984bf06cc58b40dd",
+            "0x24157b26cb8d7f1c72603823b666412f4a0460c5ad19e383efac85d55fae0be4",
+            "0x0381e3599f263222073a0dd02cb9b1b9118ccad537a41591349cc278fede58a5",
+            "0x0824a6f4dde494228bd6d12cd80b86ac5d819a2b0bf91e5da18c99ecc05c20a3",
+            "0x22e9252fa2ef60934fec1a9e8f22d69668172f068ca72872593d7b58b1f58d18",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x11387f5a9f16527442368026796419fd86e17cb0e0a315960db3bff5100e1c41",
+            "0x00a9958cb8f579860aabe9f75d356b389097f0646a104ac23903bfb92a2639f7",
+            "0x20316f7812cb2a7fa465cc516f2393c5181d53bd60847e883d59c0f466f2d05c",
+            "0x2c7464abef9357f40f61de639a183ff21d3f20cb71b4703b7aaecc468313f96e",
+            "0x22513888dee8adceebeda2f4003c16741794bbf0dd56c74e24fc900c36ff95fc",
+            "0x1d75acb708f3d3b09a557e57aff4e53a1c91063167c3c146fa57ac341322599d",
+            "0x1dbcdbfac09573d88d1632721bb20b27bb69bb87a6d65bcdfa16276cc829f7b3",
+            "0x14894053d6b47a32c5db65707c0586f489639ddd076e001b6692962163a74c1e",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x062af998521b8f92140b960ea9a1471c9dd891310d4f23e9d09dd0b0d9218931",
+            "0x1b319fa1fe95d05f8bcd141fc7aa0f6614e69983de911bdbc53ecfcd5c4872a4",
+            "0x17145b830ddad805b417279a1a29c62b87d337c223c71dbc68bc3554b6dacd3d",
+            "0x224a9d08ed114b8e49229644d34e5fabb9c0e2a42ee7348fbbb281d57fbbd5f1",
+            "0x0dabc7a6180532aa93bb907bb10557e6f9bac4bb9a4c0bb00dacb1e744e04d63",
+            "0x113c41ee0fc37a36f7ce9483ba7b85e5e271035e7dbca645d0460005bc33b020",
+            "0x172e55fed9d34a3095df6ebbb75ee579f7d139c4853049debc88d140bd1ea185",
+            "0x13052a9c32e245e368b28432048b5370d938be2c4f86ca05db7ab23bb9c26eeb",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x28d861368f1a38125c4789b4b8cedb91c28d005f6182471e63d0263f1ad4fad4",
+            "0x26696dfe45fd65ad1e38a8a79508259a3e5c1640e83e3246da9fbc9b22a041a9",
+            "0x0bdafd5434354eb006dcb37aada85fb9e825252d60516f75ceeac57c339444c1",
+            "0x03b80b4580236eab428081985080e7149370586f82ede13033d1ee5b15050719",
+            "0x19c2f98f3bbd2f795c8b041ebd8dc7b76416f3e1e7e9c292c29770562e11dc45",
+            "0x2753ca956c7c83186236b5a9e8907b55fb1b07ac88a2b44e481fd2136547403c",
+            "0x00fd5f4eb24ae21ea1157b10a30a5e5eaaf5d4ccec2f13115365638777a4b73e",
+            "0x1475d493ba2520c646bd5440be95a38c4ff00496edb8f1a7bda970dcf20417ef",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x12f3640ed31b4cadd55d7fe5ef225d6371a131f24ae2eef2fee69fd7848f380c",
+            "0x300bea0a743e1cfdc6ac6a55926ff26505d4dd7de86c5f66f47b10becfe2e371",
+            "0x2dc48ea90ee379abaf6f9a962b9a37c8dd17597f5954e64c7f660195c838d202",
+            "0x2a4499195aea084936632d4cd95026e6281241e89c33f8251f023519d56f0ec6",
+            "0x0dd839042b6a192c9d029572c50bbdb2dc0d56f409fe4fbc84d781ba28a3d8a9",
+            "0x138c83bb6f5726b116b1536f53d02c0076eefb0a2a8845ed3abf010f9cf9b5b1",
+            "0x1ec8a6cd165878a5858c7cec0569656b3076cf6b127c8d7d9b9786d6c2952a22",
+            "0x164ef96d38c3c64f1037a42e58dc5c90e2b24d94fdc7d98de52d7ed7af2bada7",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0bab4019e8afd4963599599fa690cdad81a69a6f0e42d0f7a5d425c6ca2855d5",
+            "0x22a705831c15aec4427b0f8fe423c1633aa516f21d060d6f2a4321d3a3478fa4",
+            "0x0006e33681f433c283886e833bbb39f67838f437406a93a4b72a569da6f4c386",
+            "0x2b0d2e61ec8fc8e14434523596028bb77159d43a010505c8522ed1150982ef0a",
+            "0x0b165983bbfe75f4861d9dd768b9481c8995b8a70f7cb05ef0d04c72706bc7b1",
+            "0x0ba3ea6d1625eee6e462a198ecab26d93ade12e5817971443463e8266ff59db6",
+            "0x128d0ba98c2f487412c629620f35f83aadc84a062484ec6995468554386b5e91",
+            "0x11294a2a8ba3c9e630a991ac302715a46d61c304aafb0514740b8a12f3294892",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x152321d245ade873ecb928633e66cb2776f8bc90627a8fcdc1edd4a8073440fa",
+            "0x2fe6f322a2751f4c1a91d8eb7d8538bd48c8fd5a3e16f5cf47b8a9e9ab668312",
+            "0x1214406239bf46d16a782aaef04e6991bdc8fc8561370624cb0da32dc2abe529",
+            "0x0c81084fd5d1e3e4c4402c16dbed8a14e733f3f77a3aaf46aaff86c95de4715f",
+            "0x05cb8950752a4f2d2e44f06738040bba7318c782f3336ab4ff075ce55c1354c7",
+            "0x16a20ecc2516e333c7b466cce76ea6317fe6b4c194bd12c375357e5d01e80021",
+            "0x14a9f55132fc2fb3f47906ac54c276113c148d9d8040d5faff2bb9d757833937",
+            "0x0b356d329d00dbb6c08be48cde5f397ad3e4d06c6a70e8b3380406c13e225105",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2c074bcd9185cd8295f2e721c290bcd4c04845e5100caa2eb4845f1468a2b143",
+            "0x244d808a33d72bda74d3cedb76cb922bced0ca091c269d9ff88e04a0cbc0fe81",
+            "0x25cefac758f9816c3072663bca8ec18a76685ccbcec486a778775ac8e405149d",
+            "0x27ce15eab5a16aee7c7b68d558b028d6a97a275bdd20873c8042a315910a2903",
+            "0x06d075a5a8861abb18609575b6322ec5d80dcd2b740cd382f792146f3915db56",
+            "0x145c74073d4df0118e8e58bf56ac40c6ad1fb4c0d6f1979638049d9bad1bd73e",
+            "0x046de10b57e10e4e479d9a33b0648df9ba43372e43d8d7298123ab89b67b240e",
+            "0x29455c291f9ef9fe47651a0fc772c1d33c83c77878020fff7e71d27a973c8f0a",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x1f5179e906f2e4734d11a809d82aea1cc238fb19014d5eefbe578cb657908a4c",
+            "0x070cf30712ace86a6618261f6f130aa96527084a472598ef78da54d94d8f8b1a",
+            "0x1e99c2dd444f2ea39320baeef8ded78d43a3ec330f559a357312376dd1dc1538",
+            "0x0093b3cb260b166f58e272c5fde1eef4b195669110fef277b20444911b278de6",
+            "0x191186c31e877b74cf77c0957da85eac9c62fb5db8458ab1e66d8238e8dfa566",
+            "0x24b14d833c50c0933a2c17adbcef143fd6e0247a262b9eb273f477bf16e92d35",
+            "0x265265d20c8c9efa4190ae540dde6c0efd19164181e85405f312e2f469f8a21d",
+            "0x0f20f868343422545b7605bc38b50a6673b57c810cc9934aa25b66f4947dc197",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x234666b0f0c56192190bb7a8f58636728f6b5eaca1549b38b02393210610eb07",
+            "0x24e5090d4c5de283d54eff7d892c0041c62cd521bb6a4a2df145e941a3998d60",
+            "0x07a6780fa1fba592ab65bfebb1b50443c787e263844c66a8f367c5a50c1c8187",
+            "0x27e44de9e90c7f83702f957b78a53c2da5b970952b79769745178327901854a2",
+            "0x2635a308bd51c76aac2242ff2e662e2990aff4196991f1a89c60c13a32e697f4",
+            "0x23ba67adc9952d5e4bea601f83349b279043637c65c7d37485cedf3fd8a8270a",
+            "0x02f32ad42116e08daf7681e8c7ce14cc15c13b5816601a2e3832f4dd2c51a165",
+            "0x042374e08155033c9129174bd2980080e1662cfc0330cf11dcd3f3f9611973b1",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x222780d94d184afdccfcbd364a61eb80fd0665132f6dadcce6f152f2541b5019",
+            "0x2af31fddf7ff10ab7a23feb458c2b5f633616fe893346575d9785ecd1950bd57",
+            "0x27a326aad7cff3ed86e80b9c6466ae33a48a01228580440c0096255384341bcb",
+            "0x09d94d45904f674ea28183a9790bcb2985fb2e6ff8b8928094fee5df2d82a70a",
+            "0x2922c83b1b5786d9b48c5b470e567bc9e7ad6b061862252079f415bfffb1b606",
+            "0x20a3fe22028f02a2a5c8a120b776810b21075dfb8d0f9bf81895adb745a78a50",
+            "0x28e438702163df6a326b22c2d289d69f7eb61c12efaead5e1f8768766174b5da",
+            "0x1c2a068e680c0014a29e2730c94e25d991a6ed825c4e7789a5315a30efeeed6c",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x25825f2aaefe9ec96b7dfba09fcb8c92337b06875fecac43acaaa9735739f103",
+            "0x12f28e380e3f460dc17a2045aa1313833db1fd5276d800370bb7f7c20de34aae",
+            "0x2c304c9ba9df84d14747e4a92681148b3e0771665d17e20040bedeeeda862d0f",
+            "0x1c6592125969e3930881930690a9b622f50f6d7987b38575f07787119468c8da",
+            "0x109ed8fb82b11a2803360f36513b5a0e7dfd6ddc250f114f0cd575ab680ca10a",
+            "0x1ac9c6b0fa82c9ecdb4f114f3fade72d8000f6ae89d89a3b031813667ded9b33",
+            "0x19fafbe5d93dd50ace6df4245c6c0964b54a9e499c129288b9cdb08ac2f0b5c6",
+            "0x23df39feb2a56f1335f65ee50222d658fce5edb14620f95285e3545588d4418d",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0aacf7a61bc928c634121b0db2b1f1cb27efb5ed45db76c53909a9c3a9b300b1",
+            "0x0176d4e44fa16e96504d076a666400525627659d8c877587f639248c42ee112a",
+            "0x12c06873363ab8c66ce9b8a15c046b491c730e1585cfc0d9c57a8f32c1ec8992",
+            "0x1f898b2a3741b072384d6db9d222a1b418678347a3644711b3d960ab0b54f6c8",
+            "0x072e48e6cf091f18e70569551b599d0c155f96815bc6ee555fc3a5a2d2d69943",
+            "0x03eecdac8d27f3ac3325037ac52ae644e7eb7ab411be811855412e8b65344825",
+            "0x05ff642c5ba7061ec6f4f9f9cf22ef29c1e6088003675bbc015290586cd60623",
+            "0x0e5c939c08037fa89c369f0e4aafebb9c066403acf84ca85729666656f3a064f",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0599e42a2038df547e64960cde9d57a5aa7ee4dff9e94c783b64961407c8d6b4",
+            "0x23c683cb6e0cae7b8af5a33de44547de55691ac1ddf8a77afa1a58a35fbe0a11",
+            "0x25ed3693c4d4d8d4ece05c0de0aabf8658f38db9e4b0424d36b900d437908dec",
+            "0x1d202f12e693ef48e1084f8f8ee7fb4f5ae3a9158795f7faab78aa91a53e98eb",
+            "0x014d1b95b66013d7cdea467ae6f8a538f8cc1e1a19861d83062834d7b84a3d25",
+            "0x1a3aa0cd425576851133a3b422acaedd4fa49fa41688a440be2cad974ddf3e7a",
+            "0x14c07689582e0e2a1375221e60191ac21582136711dc74f0131035fe48c20d44",
+            "0x25887897d31728d92da6034726fa159b0d5e475d066247588392a110e7e1a4e1",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0f2ee5211a298f8f8d619954f06ce3d1d0990ecfc106daeb919c7145a28d82a6",
+            "0x08d179420fd34db49e2c60d3b0a01bb803941755c76b5e15046fe2383da7055e",
+            "0x19b138b360b387ee6a1e1cdc6a0baaae7a0f3e096d50328316beaaabd2265801",
+            "0x020fd28b246ef8ff91993dfa2b88cb4dfec916a4396a09e440e36a8293b34488",
+            "0x289668067e60cf8737b2e9de50cdfa01b2344c266fa58679054206583d027d11",
+            "0x19d12e0d4d6db2dad3c0aeaab96fce65cbb83f7255a11cb41481008f89d3f5ff",
+            "0x1aa44466aa0827b307f33ee913a6f4874eb61b4fe43483b1fd912afba4f20808",
+            "0x2998ba9117a42225a997284f836c044dafe11a14f8b8923ae01f33e2cc2f7f8c",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x0cbefb54b637a61eab00a59e01a63a5ea4375840bc0250b54e12fbfe6fa04be4",
+            "0x2c14383284b893726aefbe7993fd64b7d0239a61b3a20f061cc1a3c8e765c28c",
+            "0x0b3c3b899fa9155bdc68bf6bc7486af52d13d88501027bcfa45b8b9913361523",
+            "0x24c60e3847b95e9738d7b1c2f0ea15ae91f7061036693d11d119460f2e28e3bd",
+            "0x0caf47f271afeb08b707b41fb979be2f850eec6224d39b7640aa87f7a407ec64",
+            "0x00a3ca430a396874552b87674fbc08f3542c237c0d4c66a76e14d5fa76e40da7",
+            "0x01a245003ed0bcec785ed1cd93cdf93c7e89cac1be0ae8b67fd804bf6c1bbfbd",
+            "0x00803c9fe16f1a7056b0c565745d28302b3b97b284afb34e02730e897255bb14",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x117ace203b21113cceb8b1d9242dacd2b7a4bd6cdeb8c9a9b2e28b8710fb068b",
+            "0x16e266a44ecb22ede24399d324ffe359441b6bf60a801e6993d00ab2b5dcc4a9",
+            "0x0c094a5c915c082c5bc96e17f44581961e10ece8713732e56a5fdfef8b99b571",
+            "0x2f253ebb9a3a7ebcfa2ae33b981e5f3b70d4a50c8a6d2bc3cbbe881e77b96dc8",
+            "0x0c2b628ffa94657d70ff38d14ca51f4d5d644284f2a10144a255fba6d03fefb4",
+            "0x2ffffc98fc61c4bf99beefb636b4bc0cdb0277ccf9cec3cea9c61efe2b5b290d",
+            "0x1e87395449a2623cc27df48385eb8e13bd60c5cc235ca80f0dbf6797b57aaad6",
+            "0x10e2d082d72a1e3524817076beb99d34f1b85445b97bd73cb1f8af8fbe86daf2",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x29ec5d12899ea6b5d007e47d09c4c2b745d886c3fd89b04d318b2043c45b4199",
+            "0x14dc6b41d931098379911673c11866445f1a261f4cd76ca2fa0ce81d8be9b84a",
+            "0x10576098af40aac1bebc684b5dc3c463e19a7215c86f56cc9cf857dcd352a076",
+            "0x1b332e9d3dca95d55eda334258bf86d077f7327567391eb184479d4f381ebe00",
+            "0x2e3d4b5e1c6541d9d248a7c0af289b11a53a8cfac35844e1ffbef403d8c99759",
+            "0x1106161ea89df967c67c0d37ed6d3b2ad743917b7211eccce068035a576f4c7d",
+            "0x01e52df30cc1e95ff6e796b6ea670642582fd3eca5b06f535a493d8e5824e7b9",
+            "0x021a9f237d20fe0b710d3459ece98e73228668ed9adc733e6e927cf0d84cc543",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x2e0be65cf49ffc1d069e5e31919bab8f3db081062c485bc6ba48fdfbe6a862cd",
+            "0x2e2fe5b008f30b602c0f4dd73f0339c73629c22faa85a67afe284f8b6609a53d",
+            "0x08d5a457053c022952801a0fefc521fefed11f7638d12b1cb6e4e3102d609855",
+            "0x23f601b3ee94335c91abbdbcd340fbc3d5540b3c9fa05fc751dac51754a23908",
+            "0x1f18b3210726c49773ddd69b334682c633a38ccc8c9f83ce4d04108bf95a9cdb",
+            "0x2c3f6352e8cfe5babbbf5dcc8584cc4d9c67dd443e45ccd9a16ae17eefdeed12",
+            "0x09f4a16d88e9513b966b1bb6c8a4737c78cafe0b02e22c91f2e30fa1dc19cbb7",
+            "0x1362c32152b8c1eafdead2fc833f2e6f8ac4d645172ad177568841a5a83651fc",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x1168c455513a401c8003ea65263627d251adb9f59387eb8383c0b6496f2aaaab",
+            "0x24123c53ed1dab02d7ec775194010fa0183adf6f76e8e1255c78831ce624924a",
+            "0x0e44a107745cb3d3c5fb3ad2052518e88c0f8ce280e9144838c197f3b8fb6db7",
+            "0x19f3021367960f2c0e8fa60f00aff97f257c4d17e071d13623988d68da65965a",
+            "0x088069dd4cb1a58bb1a5aa962cfbdca0003e39cb015eee4cbcfcd6936de114f2",
+            "0x2abc6cee990de8f2140085fea319fc1e3963eb94bf582d445ffd572d620ae08b",
+            "0x10ffcd3d97ee5fe6149cc3da5a92ab67915a9d67636dfcf69c77ba2384b9acb0",
+            "0x11c61b3c1c4a6a79a6941e0389fe47451b195fe2c049afc030a2e10be2bbeb14",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7f6b316d2ad4c2169a366ad4bc7590b410a34a50d7d516a571096aaaab",
+            "0x1ba708319bad8be005d47372f6d54ce8fae7b743f20462cb04dd26f853cdb6dc",
+            "0x2290ca67dec28b1e7159eb90b008f7d01e1ac284455abee94319055c4c692493",
+            "0x0f5ccba9159a72947fda7b3abbf221c5b11a2976eee61816bc3e6c63abc6baec",
+            "0x286a35dbc2fdd1a2dadda7c4822847050638f1b63b40b316b9a8dcb59712437e",
+            "0x1eee269cab2e2e8c8ed876e6d5aa922684f3a90304347efe50e3291dc6f2d170",
+            "0x1ac5ac4180c237d6c0418825dc95b81e79de16bfe1e0aa06e30a33192ca19fa2",
+            "0x138d7f45daad7e1c3332edd7260ddd3918c4e16485472f61d0757f815a394e31",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065eb4d13d96d4457b3d4cee2f614707e47b849fe33064167b2aaab",
+            "0x2290ca520e90f9b21c7699c378d9e653e99ba80f77cae96544372386e6992493",
+            "0x1ba70841a5412e80384f173ff60dfc1fecc91241a7c2d1248ced362748ffb6dc",
+            "0x241a11e3ed14a832f27d67e306554bc77fdcb156588a34774c24f63038910411",
+            "0x21e42fe15a0673b23e9c7eedbad83bba0ef809c15762d7d34433124bd0095e2f",
+            "0x02e77c6b0a864b643e10ad4b7cf3561b8d7adbe2b3111f9d4c5acd80bfeba9f6",
+            "0x2e13ed2523f39c9a00620b70271e001f8ffddd0070c501962ec22010c659961c",
+            "0x0966cf6e9289a5bb33822262dc79166699a2a6961d603f4378f44ffde3e53a82",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de83c1ce8ecfa77a57bc9e8fc4eae8a9a7631cbe55176aaab",
+            "0x14bd46313bf0b25b054c3243373ea65df3227a6070167cf13d83b7a40de84925",
+            "0x0dd38420d2a076e759fb5f91e28adda543862f349a11dd928b7217db57d8db6e",
+            "0x087309a247d3d6e2b63369d88fc2fe1c23338c8c303054cbe49094887a28cd35",
+            "0x0685bc9b92acd3d517131647c138112ab7350a2c8056db23c93c1fd3fca5b307",
+            "0x27ba37493c9c2db0ba728e45444250eb0778acc847e928aefcef9c9b45f05777",
+            "0x0ee2628822a723bb8c3f01d953d1d0210a07953e4d2ca87b23be4f292177806e",
+            "0x25c3107c2d8ceb3ed77737f16a1e6bb14e4002c8863ceb08b8566ea0a0dd3821",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7e36d506bf05eead16c7761f2a7958c50c2f72aab",
+            "0x2290ca520e9129425f14c4130348818dd9dfbbd4c0217df91978acef01d62493",
+            "0x1ba70841a540edceb2770345f0c0eeb8a706711ef99342b27d532c245b35d6dc",
+            "0x0f5ccbb2b12412566325ac79f9a849c0a97aa26430192199b06f917ac617baec",
+            "0x169244c1a4843587384e4a19a1c797e5b8af3335e17e3f95b070a64af0144f11",
+            "0x0cd7c56425896513df49120a1dd42eec60ee57da5a21bf46a8c3e1f1a7dd1f07",
+            "0x0c687a14a41f246973ec948b78c6b9387eb9172d6d7d327d2c7e149a75559534",
+            "0x18d6a3b1cb5d98d66d7d844a02e781143980f1fee6eb86f4dce0c257bf6d9103",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808073ef1c3527a5fdd121016ace7e176e256aab",
+            "0x1ba70841a540edceb2770343b7b79b5c74c9a333d794eedbc20821da4b1816dc",
+            "0x00000000000000000000000000000697a3e5e76078080b7012288ddc55522000",
+            "0x1d304fd383c4893dbc612e1cd0255f17fc6c7cd513923f58de4139444d962e5a",
+            "0x0673fdfdad18c8f7d94bb6efdc75c7b32249b97d2f54bf34275aa38dfe737733",
+            "0x00b68e93ed480182dd7385a604436e643ebfb4ffe2e72228b0537e3336472dd2",
+            "0x1a6b694e763b9e684e7ebdccb424c91dfcb8b7cbac52c7e0c2f5d08fd20de441",
+            "0x11a52aa42a11d9466ff4efc70216a72865da69822fbb9196661122d5a1520bd3",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b814b3902f5d120fe930e7e4e02d32ab",
+            "0x2290ca520e9129425f14c414a5a588428a5fd85317de067c13b26eb6be827493",
+            "0x0000000000000000000000000000000000138d2e26629b2e06783962bbcff200",
+            "0x0f5ccbb2b12412566325ac7af44991e4af44d1cc6c0b3e7ebed2af3915eb8aec",
+            "0x24683f201b6f5e682eb761d298c02999ba13b7b30cee481a4c39a06f9f2529ea",
+            "0x0ee285bacdd331d4ccd3be5d1261977d5275b657bc1739ad66a78c7679dbfec3",
+            "0x017b66e8d86668c382bf44668f69ae9f1a9b4d8a915e5724f65411ab2b498ca9",
+            "0x070de37efb0c9c3c0ef19c9687f3334713afa6e04a1069ff4c125362d42ae1a1",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b8114d6d87fd1daca975cad6862b36ab",
+            "0x2290ca520e9129425f14c414a5a588428a6e38339a074c33f49b2a76c4f31a93",
+            "0x297a8c6277e164b60bb284e59393704fd951103e592d8776943768b99d74344a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce79363729e06f22f10cacd08f48",
+            "0x118022073672c641a1c58b1b72871367d4f32ba0acdf164d80fe7419e8027ede",
+            "0x1ea033ca0cae9f726895b9b90a239f3f9a3b6973145364e0f9f7f17e4ef5f30e",
+            "0x0297e83fa9383184eaee702937ba03c353827bb06ca437c92f682e2aed4ca8f0",
+            "0x01cbb61b1ed9078d0088f919c93c33b4060ddf3f73f6823efe9ac53cd55323e0",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b8114d6d7de87af8fc3e2a2a1475df2b",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a88342899e767986bb8eab7",
+            "0x0dd38420d2a076e7593b81a1dbdbd01a9dc5b014b51069fa0c66e5e5fc32d98e",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc14751d304bf8470ef22c8",
+            "0x2839c93d9c95d5693129d13990c9176566aa1db17290252b8c7b03954dc3f1bb",
+            "0x2e5f9ff46c0962848d4c66e1a2d6634f2305d76b63f9a95dede97b70ee56d106",
+            "0x294a96b2b2aa95e050702937677b9b55c7ae128f26dec2c5d2281f1746da4fc6",
+            "0x1a5e221519e353ee2ba96b1666361113a03a320119dab09eacd5330bf7774796",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b8114d6d7de87adb16a0ffdc8cb6956b",
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e07e9794c7ee61",
+            "0x0dd38420d2a076e7593b81a1dbdbd01a9dc5b014b510694e13671fa08f90d58e",
+            "0x01894791de839b6f09ea2ad9186dc1ca1187be3b30900bb3577c23175bea3096",
+            "0x263648648a870f6d60b0bc8ec1a343e50ddbc3975ac521c24d5775942cad96b5",
+            "0x2cb9ddffc57e7b91d2a14391f656ce336d9591cf67dbfa7448853ebd8f98ebc5",
+            "0x170d4bf51f06fa872053f9111d6cb5d8fafd57a38817dd16fdc1805324708ab2",
+            "0x0f8c1c7580af7f8af1a8417d313abfc19935a2690605b7768e7f76596a59dd3c",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b8114d6d7de87adb16a0a7315107b0f3",
+            "0x2290ca520e9129425f14c414a5a588428a6e3833c4a90743307cd3fbf0390563",
+            "0x0000000000000000000000000000000000000000000000000000000005e81f52",
+            "0x1d304fd383c4893dbc612e1cd02561ff4d131e649ab0de4f7e4746eb81f907aa",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2e46a7391af30ebe2bd26a1e1fdc375aa2ad50d40bc928991959da53f3cd7707",
+            "0x2e4f24fc8bae55e170fb4091f0633d93122ecac0f5da3b780695dc71e488fa05",
+            "0x2e6a8d506526cd5f764c8a0317efada2e1bcc161ac35194920d112f43766bb84",
+            "0x135b52945a13d9aa49b9b57c33cd568ba9ae5ce9ca4a2d06e7f3fbd4c6666667",
+            "0x10216f7ba065e00de81ac1e7808072c9b8114d6d7de87adb16a0a73150000007",
+            "0x0dd38420d2a076e7593b81a1dbdbd01a9dc5b014b510694e13652197fb6db6cc",
+            "0x2290ca520e9129425f14c414a5a588428a6e3833c4a90743307cd3fbf4924935",
+            "0x04fe289a132bb928e0390b418f64b5d0b8f92a405dd42606dc564c218c92491f",
+            "0x285396b510feb022c442e4c2c1411ef84c2b4191bac53323b891a1fb48000001",
+            "0x06e9c21069503b73ac9dc0d0edede80d4ee2d80a5a8834a709b290cbfdb6db6e",
+            "0x2a57c4a4850b6c2481463cffb1512d51832d6b3f6a82427f1b65b6e172000001",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+        ],
+    },
+    LoadConstants {
+        arity: 8,
+        full_rounds_half: 4,
+        partial_rounds: 57,
+        round_constants: &[
+            "0x20b8e1be8ea94ea6d605717a3c3cca8a0c58c744bc5fb8b6c80d65f2e2e3039c",
+            "0x21b877d25aed648de30ab32cc340394e560ff72bd4d74c93213f503fe631cfeb",
+            "0x09905305368399435ff4067410a79606ba5675d2cd073bc25d308988a6f498d9",
+            "0x1878085d07a58a7716960d488f845628131c9ece9e8c75653daaff953eda1356",
+            "0x2633012c14625cb65f6cd48f06a3a905a01040aaa466dafafa2f42cb1b71720c",
+            "0x1fd85c04bcac8137002cd1ea0818e57160d623e61dc0a10492c1f0a1a5a67c96",
+            "0x2bb695e7cbc75a3cfa836765d5a262817928c3a4a503267bc76804b48c536a67",
+            "0x022e58c5236b531a74e56f5e25ba4f7e1b68e1d9ab31d73b4d3d4defa837f591",
+            "0x24bc3e823ae7574d70af0c3f646a6761159a7649ca4b2766c5c1795d56d03758",
+            "0x2c2a98726c89feeb19f09ad2e34dbfcfbae58f7bb099b58ced2bdbfe1f277f50",
+            "0x054d72ad69f584b134693f74375deb87301ddfe97da24b983601a3627378b38d",
+            "0x148e52fa3ed36bbf895e7b1f558cfbee7df9f85c08989456b916c3205fd0bec7",
+            "0x28946a12ebfcab1c13b7f9b92d02e2aad70197abae68eac06e901a9664445d13",
+            "0x20cdc562b488a3db27556451de21be3c5c0c9abec74d81f7f382282c78ad245e",
+            "0x1ccbf7f3fe18aefa6720f6d3fd33311d37004d8c41f1b0604802c8dd02ed133d",
+            "0x13573cd2d43b10454b52c197c2532c1a559aecc7147c55327686a2d16df33ddc",
+            "0x295a379755d54cdbe5bc86c15eed569d7ce45ca8dc23e1cb189d56d8d096e4f1",
+            "0x29224ec163f857fda18e6a062faccac2793d0f6bd9828379a166bd8e54be20b5",
+            "0x1cb50779ac4be4856163fd21ea507ec4d9977a7c3995678b93fecbddde3a4f17",
+            "0x2eb8aa50268b1d0e53e3e75707943bd80e104aa39ab2ef5ec6afb06edfc1d6d9",
+            "0x204d9d79a8831f5c28b7aec205ff26b809a206808e3ed0231c9adbe192102291",
+            "0x24f7190c57b9b65806c4dfea8da6f7fea0db9269ceba59ccf92930f9e167b174",
+            "0x30042b05f4e25986c37606bbcaea64d56911980152554ee82bcdb01330f8672c",
+            "0x0c99abc199af6abe500686eedd08ebdda10d4b7ccd5bed62709b43b157277bcd",
+            "0x0898ecea1e54f51d6d71eec158df2c41546b7e2e5409c792ddc6068e2e5a23ad",
+            "0x0c423c47e6703231d2439114d89791766b156e4fbb41ea7d1faddfb2a2073210",
+            "0x1b7540afbd1da44c0bc4e2c94f8f680aa85b2762f99cc73aa30f5b2d82cebdbc",
+            "0x15b05e0f51c395ee66d09dfc6d4c0bad36cd64f82d2f3d41200d362f45c705d3",
+            "0x191313e2e41158de91c11a0f6597478cdf8efda7cea80b03faa6ecf3cd287803",
+            "0x06836443d14b7b74e39cd1c330a8422b270546db60bde3237b4f508ef66ce91e",
+            "0x0ca442e5b002fff8f30ca2a669e2dfe2a0b0450525e6633b86e46ce1345e29ba",
+            "0x1bab750126d47d5fa1e39d6c9bf2f16745b1bb1ba86de7e7c342d4968ec370e3",
+            "0x16dd26f6c48253241086049dcc0feeeb0123deb30cea63210b09a7da83dcf7e5",
+            "0x05e282149e6d7b1efb54b3928309efc31cde5913e86c65b0dcc3267130c76a79",
+            "0x17bf71fa2d0bae8b98d91e40488702e9d2fc1d656a363c0a95a4ff2204079ee9",
+            "0x09a109ddfda914ede1f0866b60b42c16b1892a3e724b56b9b76f7f30504b4651",
+            "0x0613b221b679cbd6b5f4c46687523f6b2664be491c7ab55b8fea4eed0be99fa4",
+            "0x08a92b5abc4b01f894eef43770f929353d51fb11a7bb5e906631c648a5070916",
+            "0x1606623d3959bb3189df97fb6c18d20712d045c6c85f2e357aca396b9ce3bf8b",
+            "0x00f3b576ec60625855d5ef8ac112c42204d4fb786977b25783b15cc5d3313e3e",
+            "0x111162b8340af3e07db6f02274ed233f6a875e342144583de1c3c2f4df7cec21",
+            "0x2c9e6d772f0f4b3773a8d925eeaeeb00beb70f7cf9d225383c51a46ba43a46a7",
+            "0x09144d2718d0c7e9da6046a90f17b7bf94bc755c462b3adebdfe5bcf8f0b8711",
+            "0x12498bc01bc9cbfa9cfbf4b9e93ce0cbca0e76d0cc5d4fdbfa867a9c261e0112",
+            "0x01477a051aff1f622a2c3bde09f4005e8ad6cedfa4d5f739ac89afac6c4c7f1b",
+            "0x23aec4cb15330e9287341afb878767abd7b98723c6792eee83e46641e66cd825",
+            "0x0e06aa35b524f71c14eda8702e8f24524e4ed8d6a0652739de20d8c512c8ddd5",
+            "0x027f58b7f5ecc27a93d1ba377c3c99897a7d990bc7cfa1c9fc2e32a26e70cee4",
+            "0x260eedf805c640919308ec2993158a1a1ff29c24423da77ceb03d70cda5844d1",
+            "0x15358f2238320306b4e721e93c9e04bab89f1e392c04459ddb48e1a9a171225f",
+            "0x13b2274ef4e7a951054201e351e130d1b7368c5f2bc600529e11468ab2554afc",
+            "0x12eb6564cdaf625b016fcb68d32c827c8a4da08f05cde6ad147be2ba5cdd9ff4",
+            "0x0305c55175c9107a0701e0f52c90a8ade831e45a5fcbbf3d8b37677d3bb4eb2e",
+            "0x2d202db7cc9abf073aad30bb17bd0e1888d5fc02a20e6afd86702adbfa1c2a86",
+            "0x036956f74979e5d5e30ca8ca35a6b83979bdf15c0e045458805b2d334e8a4ba8",
+            "0x1631b3ab2aafe2c54185cfd5627aa832b919c9464426526d8dfc23410e0f7c12",
+            "0x1ee69249446e0c2d044c4d53edf4f754155e7bd5e9be844da117bde8b7e12290",
+            "0x2205b0b086f9dd2c228b58f30337f09cf2643ce41ef856826f50a3905751f62e",
+            "0x1fb6642dcc21970d32239dfc6fc78d3d3b6fb4e7c629c7c8385999d2b54ba2bc",
+            "0x2bb603bc3d5797e1264700ae44b2e0c3a00e385e3604a828b4707f18d819c939",
+            "0x04c61177faa8b763c526b187903826080b07c3d3505a7ef564b92dc20d6bc3e3",
+            "0x0355e9838a297af160cf58090cbcc8aac7a69b31e5dbeddf6eee37ab7bdd356a",
+            "0x2c479861c301c6ffc1007c46e188e52ccbe6fc99376749881eeea44973c48777",
+            "0x24cfb50d70e0963c388a7da86a136f22cb775df784315020eacf1abaa4b18a4b",
+            "0x23c964fc5637c6045761e24a07f8965bdcb6f3ca07bd90e6b40a715b30a9f28f",
+            "0x0f25a426f4376246e98c884f975e5a28e71c019542e464f7ea8816ef7b347549",
+            "0x15f28238a1298126c55d01e15eef6e90fb0bd85b42e09f3523cd1d84c8219212",
+            "0x04096445cfca3e7e1f526e752b5e021125ad06fb5bba4f5aa5b0f1491177fbb8",
+            "0x2da81d45ae30030d4a61ea62e411eb648d443e68283634bce821258e344a75e3",
+            "0x10ea89e9013791e685e941e3664028aa081b97e34b3cfd88a8d16d587261e595",
+            "0x19ddc8b3002e7ef184deee62d0c50a0845595ce06e00ea74c05f496aaa3a180d",
+            "0x0978253bfc35a718ca8fc624af4315cb2ac6bbd2e374003c0a964316886bb519",
+            "0x25f8866de1d1a820cb5fa91952cda4ea2746e0b31f726bc8c68e24c1fb806622",
+            "0x00ddb74a57d06dd2e6125011833c1a693d765f76b962b3c26eed7395d3bbacab",
+            "0x2400fce6e43fd836deee23f1cfdefa8eead2cd71dfa49507c52f1d15e5d7849c",
+            "0x287537a94db2fe3a9c728c74c98bec9da87a2ce68c63ecc1539a1e5d96d143b3",
+            "0x0cce0dc8791de08303eb4142c00683ae8e5e0ba9f95e7ccab49733e3069b73c9",
+            "0x2f200a2b05883d9b8cb43ca50b954c99c3fd4d11a718de2c3033d0691d828733",
+            "0x19ec720ce29fc3fe4630a052b1b842f943c1723d3fac1c2fe2e4989ad1a5991e",
+            "0x0fe218c572dcfa4e724158215fbefafd7238024a394b2e56f6c85a851fd1c1f0",
+            "0x0844f6bdf94cca55f76fddc6723ac681ee69b6b52a1154ddfbca62c0336d1565",
+            "0x2b32dc8e30a601a4d6612218fb2b179c4c5dc352a62a5109e8136ca01d90ddd8",
+            "0x2b66206a42c7bbaa24e2276a462f397e995746057741f22252f0ea639493af1c",
+            "0x2d12e75fdca55e67eaefc36b383c936b278499fe71b539159e1116779fa5ce81",
+            "0x2c7041acf3981034619c42816144326803304cebd7f655ab7ae58336e755925f",
+            "0x303af98870c1801abfd9f422079b76d3bd3a7eb85db8cfd6ac5d7f39a4c2cead",
+            "0x274aa68c66e616458fd6406ff0bc74d4ea756a94c795226e6d26d7c8c4263b36",
+            "0x021d5ed98d12e4d4c960d23c44adea8c79aa35d07c019c92b2c5ae077e755ebd",
+            "0x16e8f8f51adf629775d6411f781ff60ebb600b07e40a651d584d029231f8e8b6",
+            "0x24e2c618bfe1d79ba20387a2449a3012415436d10c1026d88734562c751a316b",
+            "0x1b7557cf862985070b355ea08aef3201b24b37c745ef69e004746a5dd095795f",
+            "0x19539f71b0c697c5fa05d3d704d81c555b2906f9aaa982be0c27a988f4372d26",
+            "0x2efe3a0a9872ae86e5bf03486680e3c35664f896d3f8c62683ff0bfdc65cec97",
+            "0x21fb81bedaf93ecf66bc755a6b635271dfb7110054d1247d0b3e40e6ea9ded2c",
+            "0x222d9bbc455c3888e72f44f5ce0025dcc3f9cf071aebc85694e64a2fabe74098",
+            "0x1b4cbb2f7cca0824c7494f8f2098ca458a8fcbfde5ce1fd496343a0fdcd7938c",
+            "0x0fa124e223e0f67d751e7f14b7fbc2f6f2ddd11e5298ec1e9c5df9eeac247d11",
+            "0x17fd5be6b58144c960ce29ee9574dc2c6b6c021af34e98606303ec992d80d984",
+            "0x2b735545a294f30f834ab94ce0f4e6e2a07df6404a172ab43fa39268c7ba5f85",
+            "0x29bafccad746a39447821ee04d16c9886921acac5fe7b976b825787c34982f26",
+            "0x1117fd4fd3ab1e4bcc6e7070dc8a381cb00189192eb9b0721a94bea8cfe67bc7",
+            "0x2b92ed13cb9a212a66c1ab4b5a5823f06e1ed3d53bfd8f3cb3e3a6bb3edaf11d",
+            "0x056970d1125cb61cd0fcbbd27d3891306c8788fb86dbf0cd8f8420055606c031",
+            "0x1ab98209df222b2502316aa788ec5fc1ad8c9d1511eec0e34e90d173de6c60ba",
+            "0x2419141a81f682c46bb7ec016314d7c8e6609bfb4775d56c2829ab5032cbfedf",
+            "0x05e4abc979f8adf7701611862af29680dacdb199e10d74aef11b34f14c83d7d9",
+            "0x2653915e4d69ca5d07be66f7e3a38687cbc97a9bdd6664301a3946458ef511c9",
+            "0x1f68ecbfd4171706fedffc43d9d0399ea80624c762fb6b2cdcc8e543d956ce37",
+            "0x0f24b5778b27fd1a13c9b1fe2e620ad3fb4799fea78c45418cc659c8a885948c",
+            "0x186fc0a2297e70df577e339832c143faea0355684c0d7a679d84981b986e506d",
+            "0x0514e43a06e82467654b508860666cdad58b827296b109f473de1b4049033826",
+            "0x0a7379afc745dc6c5aef96b20d784e9cb5b7726549c1f9d82f5c6a5a274be322",
+            "0x10c0e7a565852b98ae1bcdb1d78441f8460973d7113a98448bec650db3562707",
+            "0x0d6002ccac936e7cc51680ae8594046315b1b00c1302a9519471ddbc3cd391bf",
+            "0x1f45f1c9d5c9237bf4ed7218a8c5e74cd72e7299a5ad2660aac8be12a950fa89",
+            "0x1e68e0b87a0ae54ab4e15f846bf072b1d6e076a2d11450a213f97d779950f1ea",
+            "0x15b051c755108d193873bdeb5981f41b3aa7a9aa2884647f739fd364a3429a81",
+            "0x0993447d8e7cb954ad2edc36247f5fcd6f753eae8faf51327ce39ed226d75568",
+            "0x0913ea00e37b9b11b9c9727ba9b6bb97f5cb3ba0651695be4c7f06b3ea7926af",
+            "0x0d92ae7bf9a80aa31a5c2aa6235e0fec875ff5b912f971d0df162be46906bdd7",
+            "0x19655a6c26793cfc74d243f478658e1229a305bd04b98c1553097ca837fe3bb9",
+            "0x25ef85db420d41af2070b05d46a4f44c638e45851d1e3ba13e1c271cb9e8cada",
+            "0x17be78731a26c41b99bf0d44f537f145d3ef4061d1e6757aeeb0f30f238d2b12",
+            "0x18c2b5218b83f410cd8950731e81a1610b287db30038b21747d1c812076148ea",
+            "0x3049d41d97fa0ac8725f1a9f4a76f69a54c78bbe2d0a027e8d8c5af2d1e23442",
+            "0x05231cdc323490276aeaecb4bec37cbabf32b0aa7e05d4a9d70ad9a2f47a8583",
+            "0x049ea3f69bc388267eef2653994c9d90c4d5575e2df652d216ec1ba020697fce",
+            "0x0816cc82c430047c8ca9b31ff7378abbc3dc0536141101018952791962cf7c87",
+            "0x2095525de12a2779ef7402a9a33e39dcdd9293db3c94873d2ee1c3b0992b61cb",
+        ],
+        mds_matrix: &[
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+        ],
+        non_sparse_matrix: &[
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x07df0dec27622c264397bb1bb11af95d879e2f83c4d13a1892bf95f16720798d",
+            "0x2801aa6e88c5cf81c0f83504c3a40e42cc24d395f61bbdb188cf0d963d4cc73a",
+            "0x2743cab7eff0b1cd16b929bb346232494de56ebe2d6110fdded1493180866ef4",
+            "0x1ef8f6f7533d4f7a9dc3e5d7b9f97ae90e622f3b3fd2d20123cd68e7d2470233",
+            "0x181c6da321405d9b4ea64a126f822f148a978c2dc37f3356f938e8d3cea51225",
+            "0x20fdd185e5919df046e1aa2cb424beaa21b94b087dc04afb0d7e5c26532708bb",
+            "0x019207a8c8363ca137082ac618d5e9bfef58043d7c5a3681a0a7287dc999eadd",
+            "0x22f2a2146df0b851f14faf77f743663a2d8b75c6728be7f624b67db09f5bc1a6",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x03f0d42ee1dacc7a28108ece66bd6e696b84d237236cfc9e97f07a7f6a3cdbcd",
+            "0x2e99e5d6bc56dbd5a880e901e539bcab8201c792b5d5003b8fea74fe1b80cec9",
+            "0x1b0b032aeaab803179880728816e5ce151b0917d22335ff5649437237e8e93b6",
+            "0x233e6d669c5dc918a88531010930661172607b7a5da72c0cba46cd419bca51b7",
+            "0x20e0032fcba7932885dfcb6b4aab265c852734fb032c8ec1c07ad2c9a21b36dc",
+            "0x0c3a60e8c6d8ffa98f7fe25f6d5608ed4562efbb76d2f3679ef482e3efe9f71c",
+            "0x29ba48ca5a635fd719560d3b6e164635b6b8f7b2a024f32307774584375c6ec1",
+            "0x0f545a73a5978293ccb9b3ecc045b3036a2a11d0a4f042aa04a26fb0a16590b2",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2e99e5d6bc56dbd5a880e901e539bcab8201c792b5d5003b8fea74fe1b80cec9",
+            "0x09f51e5c9854aadde5fe3ad75abb367486ae9aec42946dcf62bdc2aaf7d61d14",
+            "0x07dad9c91dab4b29549ad8bca71c27e63be8c7bb427a9e3f8f79e0b3a45fd148",
+            "0x1a992b808655c98394f9f2eb3d479b114796b4f14b1c0c692dd40e825947d143",
+            "0x171bb227304a4b7eec913373b8edfb91738ce0fcdc423c0e415b031fdbcdf36b",
+            "0x051a4fb3dd7deed06cf920297f82471d9aaa407800860f21dfd6a0fa6b0e2464",
+            "0x258851a236290766c5cf3c5ebeb3145add481a46b283d2b39807d1811be4947d",
+            "0x1ca0e619a9da5191d07312f1cc991ff1e906dfe29f3bba9bed26437d784ef92e",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1b0b032aeaab803179880728816e5ce151b0917d22335ff5649437237e8e93b6",
+            "0x07dad9c91dab4b29549ad8bca71c27e63be8c7bb427a9e3f8f79e0b3a45fd148",
+            "0x2469a02d4f65c4c3acd563b78194840b7526e1b93f5a7a6a08779374a2b114df",
+            "0x0067ad5430840193ddf9e2bf54eb37911c402c61bf24fd4bd4c363949730342b",
+            "0x16da5c47675a71b01cba8bd68b24e2dd84ba9f2456cab7b98262160f4951215b",
+            "0x19eaec3a6b47bdd7ecb10bd698a90c7583e5f8ffda5f952d008406b007d451d4",
+            "0x09e4e4995955fd568e9f7645be6280a3dfe9d839e51168ceefe4e50efa22f7d6",
+            "0x21c34de7ea31ff6dc8681401c59838a104da1fcb7df4b97df435bfe11de5d4bd",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x233e6d669c5dc918a88531010930661172607b7a5da72c0cba46cd419bca51b7",
+            "0x1a992b808655c98394f9f2eb3d479b114796b4f14b1c0c692dd40e825947d143",
+            "0x0067ad5430840193ddf9e2bf54eb37911c402c61bf24fd4bd4c363949730342b",
+            "0x048a270c7e5aae79af3a3c0a05ad3b6bb891adedd96e7b4161c7f4f658d5199f",
+            "0x2f76344900c149f43f22fd2c969bb80e2bc4f1f2697a90354075e7108fd96f90",
+            "0x00f3d0148aa5eb42d6404b532d70a5f340743572f1ea72e54001f74656c55ae3",
+            "0x2d98d6870b51f29368ddeb54086a125b432419e303c5ede72cec293129da6285",
+            "0x0d6bcc773d874797135d91e810a64076caa6c76b86720345d3d59818c7a371c2",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x20e0032fcba7932885dfcb6b4aab265c852734fb032c8ec1c07ad2c9a21b36dc",
+            "0x171bb227304a4b7eec913373b8edfb91738ce0fcdc423c0e415b031fdbcdf36b",
+            "0x16da5c47675a71b01cba8bd68b24e2dd84ba9f2456cab7b98262160f4951215b",
+            "0x2f76344900c149f43f22fd2c969bb80e2bc4f1f2697a90354075e7108fd96f90",
+            "0x1e29ebeafde358d49044e2e12f3975b78b7f2dc897ffce3bd36764725d0cba87",
+            "0x20854c2be0d50611a4c87a35188e79ecbe2344a30b17b818478c51a336089a11",
+            "0x25384357646b04bb58f9b7a3558d80e29d4fa1476a29dd1e66d5d4d9846048f7",
+            "0x2ed44c46f3de3588522d4edee9b9dfff0285e30febc8a592d9a4936c5e720e6f",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x0c3a60e8c6d8ffa98f7fe25f6d5608ed4562efbb76d2f3679ef482e3efe9f71c",
+            "0x051a4fb3dd7deed06cf920297f82471d9aaa407800860f21dfd6a0fa6b0e2464",
+            "0x19eaec3a6b47bdd7ecb10bd698a90c7583e5f8ffda5f952d008406b007d451d4",
+            "0x00f3d0148aa5eb42d6404b532d70a5f340743572f1ea72e54001f74656c55ae3",
+            "0x20854c2be0d50611a4c87a35188e79ecbe2344a30b17b818478c51a336089a11",
+            "0x2ff3e8334fe8378745105b32bb0ad9de907a0f267593f6eec555e0dd7aac6f8c",
+            "0x0b2ec1a7aabd9c6eb9e5efbd6880287705ab358fc4f6a558f4458b016fbcd9e5",
+            "0x191cd68cad8e4e344427d5ae9d7cae188a02f48a8ea9b808efb46cb25aef02f1",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x29ba48ca5a635fd719560d3b6e164635b6b8f7b2a024f32307774584375c6ec1",
+            "0x258851a236290766c5cf3c5ebeb3145add481a46b283d2b39807d1811be4947d",
+            "0x09e4e4995955fd568e9f7645be6280a3dfe9d839e51168ceefe4e50efa22f7d6",
+            "0x2d98d6870b51f29368ddeb54086a125b432419e303c5ede72cec293129da6285",
+            "0x25384357646b04bb58f9b7a3558d80e29d4fa1476a29dd1e66d5d4d9846048f7",
+            "0x0b2ec1a7aabd9c6eb9e5efbd6880287705ab358fc4f6a558f4458b016fbcd9e5",
+            "0x159ccee1d6efa6075eeed6ccf7919dea68258066839c6e153729f97ed479f910",
+            "0x254a1a9ee36056948e46c0a8dfd5e177b8cb7fbf88600bba77ec462ec235af89",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x0f545a73a5978293ccb9b3ecc045b3036a2a11d0a4f042aa04a26fb0a16590b2",
+            "0x1ca0e619a9da5191d07312f1cc991ff1e906dfe29f3bba9bed26437d784ef92e",
+            "0x21c34de7ea31ff6dc8681401c59838a104da1fcb7df4b97df435bfe11de5d4bd",
+            "0x0d6bcc773d874797135d91e810a64076caa6c76b86720345d3d59818c7a371c2",
+            "0x2ed44c46f3de3588522d4edee9b9dfff0285e30febc8a592d9a4936c5e720e6f",
+            "0x191cd68cad8e4e344427d5ae9d7cae188a02f48a8ea9b808efb46cb25aef02f1",
+            "0x254a1a9ee36056948e46c0a8dfd5e177b8cb7fbf88600bba77ec462ec235af89",
+            "0x03a59c878a72b01e1ff42b79f53868b662876f06ab0d374591ae851745de6653",
+        ],
+        sparse_matrices: &[
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x24dd31a8df0f9dec970023fcbb5f21eca30be4fbaee9875f479287da81ee28d4",
+            "0x0729ddffb8b60a5008612f43bcb3dc32a07584c37cbf187f8424d33f7d747b36",
+            "0x10920628412c06df74c25fa35966c7dfd0921e71c0ccc2a6c7acaefda47e84eb",
+            "0x291401fdc8d5f58440bc6aa1a3203d2c2466b32d187bca796d1b52ae965d0ede",
+            "0x15b5dbd7ffe653f0f6d071dba8f96dc034b607b2d35fd45404b16dd0544a8cc5",
+            "0x04c112cc4e7597a828b6a3d78aafc399e6d21f37123b6f05eef478781816cd3e",
+            "0x0a8c0c43ff4be09d2cefb4723a8b7f91e68e125793150d97389ab9ebcc11a348",
+            "0x12c291169e73a517673a891fb59655a585f7742426ccdc7402e8f35529d3af34",
+            "0x254b63e6eac109aa09933fd521e3c53940bec87b24a9b87c98b2e49489827597",
+            "0x081de19199882b74a5cca5a9b0449c3682e10dd9cc092d24e461936cccb9545d",
+            "0x01b35c3af3609d87fde0e013ccc72d107d45bf6bb269fb5b6145aa8c043ad100",
+            "0x0c6bd8a65460dd2a36308a43d215015da5c968401d407adfe709e1e30214f527",
+            "0x021c0a0bf61f44766dd61c40cf40a5e1de94dba3e4a72487be7de71f727aba77",
+            "0x2d53432d9491352df1f56286ae60cee01ef9b3c1778fb2b47489c220e1349df9",
+            "0x1968f9ebf1dc6fe5262df78fc8f4e5d66eacf8a992d41f0fc5b5aaa3b8652d34",
+            "0x0d808c9426b277f652a361ea86b9f020c52e259319a04713726e6b10c3dbf52c",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x26a60ecb3c8070d953474f430db5e02b5227d452f9a29e620c21760d6814bc23",
+            "0x2a8d5aa71cc29e5ba1317c835d933f57c377744c6ae426e55cd67f07059f6711",
+            "0x116a8d8b31ef8c31d70097e3beaf3a2eafe873fa69e34966946cdb5146e25262",
+            "0x1bb8768b1fe12cc72787a45c165979fc6675f122d41c56f5748bc54966b2bb3c",
+            "0x16575ac278d932e0bdec04f915adc5d52dc5751a26cd8eac6b60ec1728998268",
+            "0x12171771f358dbd5bebbf5cb4af474241db0ef8fce871b9bf9301a9311a7bd1f",
+            "0x193aa77865c3e13ac3f1c45beb28c8a71c33f4f69672d65ac0586f7cdd692c4e",
+            "0x1d79b4847f120969ccaa30114270c96bf6c2cf622234137c37f26434bce15f41",
+            "0x03a8b11e7028bbca1be5713c47a5788c70285f08279be87070f22c1aa05f34f6",
+            "0x0d345c11c4988fd6690b1f69cead8d9bed7c15be18b8ef959ff165480937c498",
+            "0x0807ee2b57307cb9c7dd6300aadb31be63d3faa4f964af6f82d51335005143ed",
+            "0x142d7a5f888725461174f6ef98f65032eae1b75a2769d3f59ba6128c82539583",
+            "0x0de9baa9f5016c38e9835d64cd5a96d61edf4ba6da03d4fb0826c292db6a7694",
+            "0x1f3268e8340b991f994e008fc6446c24686c556fa86350001d912d8e54cccfd6",
+            "0x14e867f7c373816d76149d9e35975991ef6befdde7c1d3139ec8c2c75d09aeca",
+            "0x0f7081a902deff0868215e5e17f3caa58e55a90d923c8cb88f02cafcf33c22e5",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2310061cbd43f8df93e4b25a27be1a483234ffec4b454c32d91657e67ead121b",
+            "0x27efe1e70bf35807011bde05d0a37ee90f624e934d9b73ee954f6c2b8ce699b8",
+            "0x0fe151c2fb5b8b953be9d19dba1368856639c4a986f125d300bdd5ed297eaa6d",
+            "0x13f5476a7c8db9ec3fcff3f1978fbfe1e4a9e4e2023da77b9b1b6122876b2e1b",
+            "0x11dc94e39579d66076f526a3b31ef42252df9097b1e68627c752862d9fd68a22",
+            "0x0c5e7362d929aa27bee0e57c71582ad465859cf17036a26c16ac32e7eb039b5b",
+            "0x258bf39237b0949c2a27ffc6801fef55dd9607f3464042177e4cce58fd29f875",
+            "0x0f103c00fca413dded42dc43bad4d28665d401e1dece26a65b74c78da0f709e5",
+            "0x2535277dcc7b74a05add2cf1f4c12c310a7e1b203e5a709487fa35f31915b622",
+            "0x0a1ef7397b9de600579d4f527be05af690bfb1da70ba6976a610ede2b2b1a5a3",
+            "0x1de0c265e16e2809be1d4a73f933dd7690c8d0f3e4ca830f22460de256f20bfa",
+            "0x09bd16881e417327cd1addeb478d48fef558b08d9d3819f506f9431fdf8f2019",
+            "0x158e7d44faa3bf24bb5d36cacf0f43b44b8e12649e66e329cf272281ad1051b5",
+            "0x1c95ade8b999731d15a4c3fd6828b8c8baf6671597401127591f86b02231b31e",
+            "0x1ec57a49515323c50072881a1c342625ef1ba94848407b52b92a41759c082f64",
+            "0x2930ba57d0e666e4eace9611dd5de5495944d7bb1f87a4997d4f3a4a94a06904",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x224336f2c6900657e2bbe5786cc5b64646f9328ca290800f5544f6a0d7a42882",
+            "0x253023827587091cbf3a586b688ac1a741d02eecd879c34aced8c53256e20385",
+            "0x09d66f59c394f25c5e22654b028751da17297e76af7e46c17dc0549b96c25625",
+            "0x0e38299e964372d6205c6e71902dd14a8dde8a0cfc5d59fdbad83ed31c448e1e",
+            "0x264cb1729402f46c16e78716dc760f464d187d3ffaacbafec5428f7ad92a8cf8",
+            "0x247bc8c7767a683680c9885956552a6175bf7c46f2460d267288653dbc021c0d",
+            "0x044e0cb071ee8f6022ca40c206cbe42fec93b15bb6c192841778f8c3ac168304",
+            "0x2589255c5b118c4ec6a2251d74429fa552b37061e6455e87bbe3b58781b10b82",
+            "0x1e74918f49d952470aa6856ee41dcb9afd269785fdc4691e2508d9de9322ecce",
+            "0x2cad6ff8c7f43b11566aec801d9bd8e214485b4d8c759e93ac834232d9af02d4",
+            "0x0dc4481011e1440c4efc2139e40bf4dae7e5695dfc87a4cdebfde70a032dda4f",
+            "0x03c7833de74ba9213e791a9b9df4ce75da6a2f67ebb252bc38b554d47b1fe1d4",
+            "0x26a0568931965b9a44f213920bc07b369e3ddced63b5eba0a75e9a9135755d4f",
+            "0x082fa20630c1003b00c1394a1fa4eaa84d7036706cfcf86c63e7fc43d56fc988",
+            "0x0bd2ca4e86f0479d1072c1fb02678d3a982cc3c23be4e30c040be6db24b45593",
+            "0x27941becc434346c3f17314373d9fe9e89183269e4a3a660c63a2f1b5f52c45d",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2122a67f8e0b68020d6a31bd4c7daef2d4fe4c7eb5f221e8b362f8663633c810",
+            "0x1a13e5b1ae963590ea4941e1e6b90b749622ea01ebc21d0f577a3d8d693f8a6b",
+            "0x1823d1aed07cab0f183d1d5b1a188211aaaf323ef97bc9469e98af5f1579f9b4",
+            "0x080665aa48bc36ec8220d6fe1e21798347c8ab522b8ad9743fe39d59525341dd",
+            "0x26d6a6ae6c75b8a9ad88266fd463a4b6cb9274df7b8b2535d137814f05ae8ac7",
+            "0x216bd12ea0c8162680572e29c1452d8f8055269178765d792c43f3fd9b81839e",
+            "0x18d8b02a2143862a0a4d460b41c4f857cceef89b888b81213e761dcf62d62a3e",
+            "0x0ed3dde7ee7cf7aa506b3b792a47ad41eacb30e60c799f296a70a8422931c767",
+            "0x274fdc5c8c424a35a12a3d054a7eb020740deb3ad0726a79024dc3f382f8a869",
+            "0x103ddc2bd24e37f16c35c76766c2f25384a1f9867037829b013deb00f76613e9",
+            "0x19861421d127cf2e6bd5e1fa8eccb41b12f5a362470d4bf43b90fe39541968bb",
+            "0x1553a36ae3e0f2ef1d666e8153a0c9a91d8e1eab37b6bfd6f7156142c4d7fd0c",
+            "0x2c286c9cf0dd9125833acb2e22931563b0e588adf2db3c6193ee3dd2963044fd",
+            "0x1f19b4ec8783b1a25d9188694ce1b3a8f4fbeb952e78ed3959dda1aaa5d89f56",
+            "0x2ceccd31ef0b1345991b9b45d8a3b26a346e8baece22ff3eb1aac63590517d33",
+            "0x19082e1cf2beb3d60fa48b03df21ba90690fa2b617cd2eb0ec99132f2ed55025",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0707b5d107c024382ed9bfd6fc5902c2ebb19f9e659d77ae3075f01a041439aa",
+            "0x1fc917918647ce01e1c01aa91fc0203f2bfaf32b98dacf29dca37b88d2d52837",
+            "0x12963cad8938aedb2caf8d9a38aad7c746f9d633c04ec7f0008496245999a933",
+            "0x06544cd5d2a44e61cf539ccefbaa54c7b6e7490cb111511d76f4e289a8c36bbb",
+            "0x0a451ac7e0c2da714aeef2080d72e55fd124311264ed4694c467081053d7a85b",
+            "0x0895855ff4698c06ddfce16ac526f0eee4547efeb6ef7a7375cce27f74dfa5d4",
+            "0x00314dcf50fbc9f0859d114320397ea10cccfd4726e8619fc25274fb5e5260c3",
+            "0x0122b35de79b3ba924080c08b70b7239f05dc2c4a7ec2b27b5249b2a647a3291",
+            "0x10a22c994730906fea8c6ba4418c2f857b5ffd4a27f3f6bcd205a32cd6d767d0",
+            "0x245489b4fbe388ac05293818ff83fa40917b7fbe09f2cd46ebef91486a44f3b2",
+            "0x087a19ef31153a2f27b9aefb951b0983d40317fcddd013e3e6f0ad22247fcc6f",
+            "0x267223060e6ba2e051478734268829be85c6684a2606f75556c0488b619488e8",
+            "0x1d8d96de8f3726804d39197baf43d7c7bf4f5272912ee5d75c4c5edc38ca68af",
+            "0x2d283d05e805c0e2b674574e3005800c22b07dd08f65fe8f87af62cf80eeff70",
+            "0x2376308dd0c8efc263d741a2f0446ba676a723b7ece55207cea59ffce9152f87",
+            "0x199e42eff3b75f8cb925d462ec99ff838be318929c4a5240089add17ad4cc015",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x090a57266645822f5e4bd0b5aecb52253ab745e647bf31038e700fd9843d830f",
+            "0x0668902b57cbde5fb27862eefd909b2ea3ae38379c6d279ecba7db88f3576dc1",
+            "0x07ea1ca4c46385992d9353c9103387dbf7a0bbf8c68881dc13997f0c91e772f2",
+            "0x104c56d1be526a5fa3576ce71228bd4c3f4ef2000c1b87c2fddd35df18b3a38f",
+            "0x1786bab04ca6d1ea2e564504bf7048d03e73a62518c5b18918f52ba14e9617c3",
+            "0x1a63848ce755b5a681429320b266033104939a5298d86588c96c6eee560ab654",
+            "0x1e10df7cc0c120585f778f8ab303f40a38073a313e236c688d3abd0b8872c434",
+            "0x291577f430f75ba53f5c065660cc1d7ee4e92b2e653c837edee1c1cb51161925",
+            "0x18c0540a34cd000219adc1f0e1b82257afb3baa9a4a43886a3a25c19339887fd",
+            "0x0b4bf118c990795b2fdc82938299fe9c9bb2cc79fd1c5c3bed7143674fa79e5f",
+            "0x2c67596f925757c4442f9569c474b997a6d81e4051a4a51dd2f31dc6153f6cdc",
+            "0x0fc8a57265cff9127ebe9899508be4f2546c088ffcd22ed01067eece36089262",
+            "0x170338d031d32707f32a34e7efc97da6a06e1c5f1e1cde564c9d3c969a393c92",
+            "0x137e6e811829c423aa1ae20bbdf77908e7c113c31ad712a66bf90ea0f4d65be9",
+            "0x14701626064a3206cb884e069f9e3a10f3912833697d04617f472f814ef46a26",
+            "0x187f3d0878ca592de65692ec8fac78b219974a7ae2721f95603db67d98e89e97",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x10e9d0ed68f03949ec42145fb412e6587efbc8be5a362e6db651a59d8318acca",
+            "0x1f23c6236c3974a74968e48993b47c1d0bef825d124506adda8db49f21581900",
+            "0x09c0ed056048d75538e9303e6bfab02f73e35774ee74111d99572ea92b94b1bf",
+            "0x265a737b0a259f71717aa2495d727c2ac58d5effdf5cc97d7cc9a7bcd96b7074",
+            "0x0e13f0d0cb402e9eb4daba7d483e9683be9aac2f7be28df35b110b6dc3ae266a",
+            "0x1f4e87b13bc3491436f81a50a3fb0d1d6855f597b25154fca0dc8c6a49483495",
+            "0x208aa2f9c0aa37f05cb4ce61bda63e4c55b1c0bea590a5ca3c7386e68f335ab0",
+            "0x09522012895c323c3396811acddf6766e274f1c7c097bd58ea9ca3dd44430a65",
+            "0x0c2758810d7e9218f5883df86f9a9b7002d66810bab750b376b80b91df936cd4",
+            "0x2536074081d7aa131f56b8025aad7878551e5b3c35cfdaade6f47ed74f8db6e2",
+            "0x21acb365eb5338242ac46f547601b0347f3c291a9f0af3206a25322d8820aaf3",
+            "0x260d3d40201ba549bf51620d37ae1877f6559b13d45d647c3c149181bb0ed1b1",
+            "0x069b397c71fc190cd9174bbab0c5b8cac8c5ffe7b91d5bfbfc2201b5d339ebca",
+            "0x263bbfb1590bf061a5df303af6f16bf36a08c6fcb6ad381e452423a4f8a76b75",
+            "0x233b83186349a00d46d3ce3ca13dee0b1876dce3e80b0ec46c45fa7d060a8d34",
+            "0x1b664531b81f7ff72ebc19de728c7c3040c4b866d3d39faeb509e15a3d11a780",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2a2f011af4b119a7ae655b1c1604934d87a169f1005926c0803853e301c8bb87",
+            "0x00b16d98ded857e6512ca7203c198ecab28b93a7bb2cbefbc42f8b25be1126eb",
+            "0x2288537309e1445349d492bc152d429b674572f565fd940ff9bfc4e3d264d29d",
+            "0x0283fbc0360fb3a328d8f9d48a9b33a7e0cfc23b21fe987c2e6ad182b0491b3d",
+            "0x291224c22b780e5afa2df88ead971b0fc09cfc214a3ed084a81f43ad6b06cb81",
+            "0x20537b832e13cbf362de57ae5bf7bfaa7b76f96feef6fa2c10a5f3d354423620",
+            "0x167f7e1c492869202e05c056f5907c6f9288313318833abf99ada4c093a14acd",
+            "0x22cc52ec027123ccb312d52d948cd37d5257c722ba814602f58700096cdc574a",
+            "0x06b8d80e447414850184b282f9339cf4113fdabc3a30f12993585e3536f75506",
+            "0x1312c9899020e135bf1751c64c6985620373a3b72177206d302737eec258839b",
+            "0x062c0c34a8ddacbe6f9d8763f515f75a532d9bb9a547d34d8e812d12e211f5fa",
+            "0x1809907f5d0c94943dfaf6f5d5ecf587f7abf1fce19c9421aea32c4a02aa905e",
+            "0x216929f19166d97fe35224d77d82fd61378e985f712813d9e841f69e0f3f078c",
+            "0x01f235aeb141e60e9dab81e7cc8ca375c146a7b23f03fd985a06bc3217cd6bd6",
+            "0x30056d69a632f25ace93603693aefe2f474b8e4d059e87d1f5ac56aae39c11c8",
+            "0x1f3180c1024fec49a48d3667828813cca463a347158d13650fea068f05f9984d",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0f3ede91fabe03c8facbffa54abe11b392649f8d9f58edea9faf4f060ac7f001",
+            "0x062171cc2932d891cafb582229edb16b16fe3377a35d0e281ebba1243ff06a7c",
+            "0x204bd1c39503faf3f4ef34985fc80525d873c45e0002f9b22205b43f1142f219",
+            "0x08fa4b8f517152960ee129bdba8d2ffb1e660b1f31b52c8419b6efb022faa9b7",
+            "0x0e725109e72dbcd7c4046003c5e1a57322728d9732c10035c3cf972241214f3a",
+            "0x27cc83c5aa5bd4a7f12277ce5624f2a693a18b82da2a4dc80e79290ba2b7db20",
+            "0x1e14c388368d8b538cc370f9a3e3bea7685fd025c147232caae46ab4228bbf83",
+            "0x13f0921f963c9e27ba04436021569c1ba8e0f0291f8a7237f766035ba35c0e70",
+            "0x0fa999f6a64d838ae6cef0e62b939e8755fe604346eb0a92ffbb0f11dc85d244",
+            "0x2fb4a4aafce038378850b570a97d6e00415a0e048add5f247dff0de7c808452d",
+            "0x2748afec8eb5cf3676d7fc5d3995026302040f33caf2d3a7bdb62d3c95dc55bb",
+            "0x063fb68b1d25a5b3f2c507ea90911407e8628b9e8665458093dee7742d5bf025",
+            "0x2c1f894552d0f108e89dc8b4abfd4329e0259f8420b133f9a930929308f846f5",
+            "0x072e238359e77828314c8da9d7042e8410ae5d8ba88bc670d753aae285f0cc25",
+            "0x1270ce91ac67173cd6a83360f41918f7efa03b3824a6cde69a5556469b50dc87",
+            "0x1714ee64a382ed74653bbf23a23648976d4f10743c0335a5de94a9ff1b27c388",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x18357b24d33288e954bde161de2da4154cf9f14687cab70142efc768abf63552",
+            "0x16e1b005c2754bbd18fab5215eb143a4603a3683975962a0bccc8fdaa4f4deeb",
+            "0x2f3987284b432d54f140380cb19e81e29cc88bf9f243b9302e25d1755cc0c698",
+            "0x08fc570eb6f7e7542cb30123cf10fec59a11510f5a5cd7f1cfcc7f7fb3f44705",
+            "0x103cffc826478c3c3867ad3a03c12a977d09001684d4f1fdbc3abd23717f0046",
+            "0x1b934050c057883e5b94c92518c752b532613ac6dac69ebe1df289ff3b828166",
+            "0x185845d54d25dc7e93dc0e9f6e5f855dc63096f63395ca98983ce20a4e877166",
+            "0x0387413e9dd940fcbcf3e4829d4c5abe82b35e35107cfffbffa74c8d15b2cca1",
+            "0x25c43c5a96550a8fdea903ebab75d85fc224f956c36459ffc98d5a52cd741431",
+            "0x082a2bd9d91cb8e6d6cc931d721a38e02b32cef8f9c31b2943c4c4e88086db56",
+            "0x03236904bd45095d7c32d357a3d80362cfe40d1b1afa1fcb66a762e2a133bc5e",
+            "0x2e7b6ad39c1597fd8280b0d06cecbbaba7d8ecd3e70c6eef50fea413fbd4ba5f",
+            "0x1b048b49051b104bd90a43ab91b28fc9530e6450be01f4c6fb5077ff1d0bb36f",
+            "0x03ee61b9fed9683cec461643e40b1e5bdf23d56aab567fbdab29c748baa9734e",
+            "0x1067417d12d25cf37f923e8f46734b3bf3b1f2adecce50153b63b3439ceb435f",
+            "0x073970adf83ead16f85ac1e8405f262efbc8f01f2b5a826d4d78209ebddd4768",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2e733d77d8701cbc0536236265959724f9951df991364989513a76a5590ec31c",
+            "0x0aef062ba643df73fce5b8dd1ea353e66bef5862fe34d5b0078b520700bbd277",
+            "0x1fbef2eb809e7597a024bbeea469cd33b17e4623c9c7f1a32c805b1a001a3058",
+            "0x0fa4f756bb45a84aff9a971a83b6506a68355ffceb7f6b5bbdfb1c8135b6f3e0",
+            "0x1840daac4a5e649a11716ada20aeee4b9547375f942ae8226192d96f6347faf2",
+            "0x2a6e3144c7e488fc9929e0d9fe6819fe12e574f9c69c2f8cc1caa8ab8e263fed",
+            "0x16c1be8a5855ed74a5fe2cee133f7c0b8d4ad599d5944ace3f0d613497c224e1",
+            "0x11561d2a3b80ada0d0408e73770ab02ead443aad4c238c23b89f18e87fdaaae5",
+            "0x0a1fbe0e702f2745b76a5da2157a79b84948d18d0ef2b4799b49d14bbf8a4ee2",
+            "0x0980b0f6b0d1f505c91be1abdee0a635ee44161e26a9d3878bd6c61687a381e8",
+            "0x18670352d9bc2cb2cd000b3948db2e4df04a144242ef72a501dd560f1e612c65",
+            "0x1881559b5611eb1f695d07923a59206919ed85bb01e2efda0f56100b5d0bcb0a",
+            "0x22da49239921725300d9f40e4de56e89441b6982edaa5b6d390e37e371877a28",
+            "0x1e4de69441806dccd38318bd09bee4368d6263082b9c08715d1a8bf6435a5edd",
+            "0x29806f2fb95f3d4c905bdbf2951c9681f7dd1f19a9e648f58d0f76c7662f2c5d",
+            "0x1ecb42b8f606ceb31a86ce041e8b4207d0eb38081d000fee555bdc1a27ec099e",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2ed23a142e809d42fd34aeb252f26fd723d363955d9b2b72f67335c53566c491",
+            "0x284ce843a2c076102b30b17bbe3aff7c1c83fa261008dfeec4bce2269c14f792",
+            "0x166648f783f4367563ff73c47095c905bcdbbfef2d65e8a04aae9aaf27c77910",
+            "0x20b0a4f6db894302876f541ba87373d02683d676a309908aab96c427df879b8d",
+            "0x1e3487b5dc69fd780e7ef08476e822cc6de957e8fe7ad313fb18ee4c907c56ea",
+            "0x1f43ba38e58dd2953162be334be29ff12d268bb0857d896cfc95f88ca2d559d3",
+            "0x2ff049b621924dea8942cce6ffce67ef84b7d4896bbbe3bc3a688dbf44e35648",
+            "0x1c5a626131b4c244a97f8e95c10ceea619283b05f8d97b7328e1259abd45a782",
+            "0x2125ba3844fc15f2f76ac9f9654371c7a698d2e3af23c81df190877bf8c04e26",
+            "0x2fe6fc6fc032016f2f04547288f7e3d704f78a886a2ab3cbd23821dbe11f8a74",
+            "0x188173a82a50dae3fa7440625cc6fdfb729a2b7ee9fe9299a0be0e86a7b74dbe",
+            "0x287c6151e70734f3b1598c022d769ff3ed1a0140d57ba597fe694359fa1d554d",
+            "0x175e5be5d5cd929c1c0f3b1f45531b969da6cc423047d9188b145d313ebe8b39",
+            "0x097af4c574b5ce6600e08a7b4608801cbf4901470a1df148693eff8b1869f293",
+            "0x010809c4053e89af2e11b0e80b60593184bf0e9bd593d42273068ab57f715012",
+            "0x249f5eddd6699f971209fb6f7f655e853b38e418ca7d9a923c71131ddf7b841d",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x094a44677ee6b1fbcf569eec1515f71dbeaa18e3caa958f8fa7d0891181430d2",
+            "0x19604f2dd7d875612cd6eefa4056740fff142d28ca1c11c53f44189f6e07840e",
+            "0x1853d872c5f413ea12a801777410026bf43bec6a6e73707455030c5ce004c4a5",
+            "0x2d8574be8586fe7df31d097f3192caa2ea70d38bbc6fd9d60e82a62ed2701162",
+            "0x231e375b14fc18a9d1bd9e41ad505efa98678c5c7b0cb0187bd77569e11f04ce",
+            "0x26c6cbf1c31f843b84fb52986dc480814d8140ca1fc651b5d1d3fb987614e532",
+            "0x2c8623e3c0573e1fd8c3e6cf7e55eb3bdf58889c15c0cadcb619fa2ddb576540",
+            "0x28c70cb1dc0149bd17f8a0d010c72ee873c341b8d62d57b6003ff914907fa1bf",
+            "0x12c291f0aa3f4824aebac874f4de8c811e4cd2ae7e9e90cd234b475a1da7124c",
+            "0x255d7a5c3e42f35a6ed3ceb4c673e94f4790bc14729ed06b671a6c96c3b26420",
+            "0x2e83a7115cbcaac00c4e63e5288c70b33d06b680af5a782d3236d8ff4967c47a",
+            "0x1cdf8d600037bdb862029ab753fea6e7b83df2da867546739a1c2be7b3f15872",
+            "0x181526aa93877de3a99a7e8625fb2878f86f5b88e41b657656cb977c6ae6aaca",
+            "0x0fbfecc4227a985b9701c9bcc5101ee85e616db502c72ab7aee70bb8bea2f39a",
+            "0x0786d9e86b9293c96faef36fce50ed8220d0eb01001fd390fc4b9c41526bc39c",
+            "0x04c5f32931b803fd5e426931f1b785666e89c4a2d1233f1cdf9767b8351fcc6e",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x17a91050f3b1a19fce1202fa75ac8ad701f8bb4ad99c955012cc9bc5fb88c87d",
+            "0x2cab510e43a0536f867deedd17710f2ffa7fc804395e2966f899537516eac3f8",
+            "0x1a9fa8c47ccdc675ddbb282bdebad2f3ddfd9b60835a9d390a41611887671e8b",
+            "0x1f352afc58c737484b1fbfc2ddd5621fb9e932d2e9978f91408549a5eb70cf50",
+            "0x0dea449712536515754e4ef2fab43b65318c95cf68bcf08e02cf2724bae792e4",
+            "0x1893a2d81b3619ca6d8c7d7f8081049c8361b71bcd559c8e63766c51dcf7983c",
+            "0x16ca7f0490ce369c72055615e7f8b8c552c60d2948f7a2feec4cdd47bfb32b86",
+            "0x2d7544b66172bda31657b16dba596e33877d95a52346a19c0785c7b7cba24bb6",
+            "0x02babb63f0da3ebc37f5131f90f65c06bc0658eb35ce12db6b46a3c51938e038",
+            "0x1bcf893a59d3a9d6555dbb9623e55903a3ad062f40d14a3a8a06759edf452803",
+            "0x1d01e2e9e6aed6edd3b7a4fc58754924ad6fdf330b1db4392fcbae04e195c53c",
+            "0x0eff1e6b2e8b463cfe0a4e23f0b3c9d1ea2b0698ba90c01e70b94000b4e76591",
+            "0x0ed734fab064342521faf6c61764fc461dcdd9d556f812f263d61edf0d39988c",
+            "0x10c1d9d227fe2babea42f41a6f67b44accb453d97f5d680d1aaff07e143314c0",
+            "0x09196157e635c207186f9b8535f31150a512b5f33b47fd185a3e53264ce05a14",
+            "0x23cfc2919792df4081537d387a999be8f385072db08c1916ed5394605ec5fb13",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2858a7d1cc96613e3ac7490a831a43b095dd8340800ac35933d2809e5e4fd3a2",
+            "0x0d6dc394ceb7da458199cfb012193cb26c740ea61bb3bfc650cf50e8e0b5cd5e",
+            "0x06942e07d02b9c582a5d7f8ad84890ca852b291b7c64c83ff5469708b8caabbd",
+            "0x0178c2c02cfb9f9cddf6efc490ce6bf692841d3324f2e50319eab16fa7cf7a68",
+            "0x03c1b5252f65654c4d563d6c3cfc2644ae170fab4a4c7e66a1eaed12a3ca71f9",
+            "0x10e54e52f7de0c82733a23189070dc384ee2795184b8f2411a5950790323c0ef",
+            "0x0aeb748b6f3f47f0c5c47bbbb2bee61024879207d74b5a8a5c81efad69e7788d",
+            "0x1cab9f9eee67f508f95d844273849ba87396fd2e0d30ea4b554429928ff801d0",
+            "0x22de86b3a3e52bd557acd87f564f22e856dd38864ad13e0224052c7124393fc1",
+            "0x1adf33fea4ef234a17f48c0652c853bd1fb03c15a11ba8c7929d467ddb00eaf2",
+            "0x0531eea40d2bbba344a9171f6169a110858239379d8dee50532b65c31f8aedf8",
+            "0x2af91a6ec848d4e577225a092fb25a1e34476026f96da650f6a7f28ee8cad603",
+            "0x1cc3052599bf781f38569867ef126d416a5cd4169252247d18882f8950e37c95",
+            "0x23880c439cbf2af65254c7af8496881729a75eb2a49a354fc7adb52634bd3391",
+            "0x1e564088be82b8006557da4d9a919e369dfe2c78f642359054906898550e8f2e",
+            "0x20e9a1c40dfd90e5bfba5e6e4e107468902401a7c799917e77b474301ea710ec",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0aeefd2f86b0380be27239dd0947478b711a6ade12f993299caf7478278f9936",
+            "0x17c318e64ec715f666c3c9be91f80b94af4efdc51bcce0e33a0d56b6d3734ee0",
+            "0x293ff85e4c1d6135d495fe45b4af6c146013cf6ea16e7df7916a57b504edce20",
+            "0x0841c5cf555bf6c28da1fa230a4e83932b1449e9a12b74becde8dd4145158ae1",
+            "0x2eb7ffe773d977fd36769c20ff584c604fb3033c5c705819c4b4dcd3b33cb1e5",
+            "0x0fd3e94be004000714f2307e27c56ced93b932ad094cd2603d8c685b663f87b5",
+            "0x1a66cdf55d5e8a14fe1a9656c632e91b88813c15679145ffb0cc9ea02eb61eb9",
+            "0x0568de0cd4e4e729b41695568079663a264307d44776cc27f917bf851adf9bc4",
+            "0x27fdaa7f806fb7c83a948aa153621d82bee48f6fd2d797375d1713df34ec9f44",
+            "0x20e668a64f67bfe525000fe273e5c12ce0fc0f39b424d48d40a74849357a25a3",
+            "0x13137570088a5f6454a302562647adc537786ea7fbb4fa46ef5c5f6cbe934208",
+            "0x24f6676534750a8ed24ab9cc4ea3a03891e4dabf5b76219db35f605c25714e68",
+            "0x0572073bc80931a1a3758f8aeacd0a0c1051ffc88acd2efc62d2e220f435bfa5",
+            "0x0dcf6b1c5228ff814161e22f9ff87dc078cba99df13e2404f7e9718f19569768",
+            "0x101c31d1d4de0b97e33f98e8fce16a271e9afd4742b227049d8b87c4cabd7692",
+            "0x2655604c8499bd4a485615ef58d59f79d4278a760e2791d2956c5733c1ef50de",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x17e4fe84f810f646dd3c60e363e7dd657947801a5421a5d9df234322d59fd508",
+            "0x14ebb67b95ac803c94f3aaf47a61357664a6a52e0d2a4a2b39c12091ad3b774f",
+            "0x1acdba9e8147a95e1e97bd23ac782d00500d25d34576cecd1754149a1e3599f7",
+            "0x09feeb930fa1cbbcc77fd70a634a1f9eacdaf437ed0308b2df7282f9da22037b",
+            "0x03cf2240754a583ccf76e0a82687b533d496326856c33f78d435c62d57440fda",
+            "0x2c42ed804091fd65aebf8dbd73c473dcacb9f24a71626eb78d4aeba71dbb64c1",
+            "0x2a23e9b9175f038167cb311042b108e860ee05705fc9d4bfed937ea0f9b261ba",
+            "0x10ce59e3f5a40a21e566dca3de96528dffaa00cd625bc4d0e8a5c889c4b013ec",
+            "0x0fea42d0096ff224b890a07736d6c9a09f1d0878029ffee09e784de0ca742815",
+            "0x0183a34a5b112aba2e0b8cbef06ccc83f2252c3b5ee6120d2e01598beb6b3f77",
+            "0x28c2f0b08254a0d554a998319d0814f2df2e8cfad366f81836fb6e02ad2f1dac",
+            "0x305a6c3d68bb8ee032fb120758ca2b83c179c768989bc99fbb84c55bbe8ca5d6",
+            "0x1d6e00916789f344abdde494f6991e0ffba4c62ff84fd1ddebde8325482ef1d6",
+            "0x1818aa56b1eb3e6a05cccb1fbd0fb95ce839f1e0a22342b3e87adb8e315472f0",
+            "0x30238c675169112b5ab3f4e8cd11697f267dcf6afbd463d16fd9bf4182bcca22",
+            "0x12a4dc15a642bc6029258c7f826ddd9f1e434e0c9dfc9a5115b3bf26868b2b1b",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0fe7f4e2696197d018815b6ca6707811229cfbc34bfb6e5b237f29155d277ffd",
+            "0x026d7b0b42d3eef2de24a2d9195a88cd21e220e0f363e4a2c97f2e81bff7669b",
+            "0x1bba31bc84118b627d71888cd25127a2981f4ec43f45bf8ef862361a750cdedb",
+            "0x143d0f64a0d59d0294d46d88e595ca063dbaffda09f5e4a8fcf1c2eaa0d01012",
+            "0x07f3a56a89b2466c2dbec92d17d4d39dbb5e2899cd9d0c598fb88426a047d8d2",
+            "0x0f4bef258a5f5ca4e641d4d41ccf30c12585585099daac2966afddd5a2dcb224",
+            "0x24c29258e563e8e26934b03e140418c15e98ae4c544dd15b9824cf88105dc931",
+            "0x1c308e30c2923b345f8ef5cdcfd70e45d7de9c38f94218a14d2f4c0c7f06342b",
+            "0x21db80190cca5398639a7f1a2d517c0002722faf6b40fd8c3ed2cdde1ee752a6",
+            "0x301bea3da968dcd170d9b97111408b9c72da0090fb7013784be9249eda709b6c",
+            "0x25a70e3ab831d189e08379736a04bcbd77d7a714291c1e54abcce7ff48909171",
+            "0x1282f72904f170b2b9d27acadd428b4d0480471a0d865290e021eab983cdb12c",
+            "0x0ea00985c1bf013365d18b756fd032434ea6f689f6d88a70cdb2647f4b85da05",
+            "0x2704cb21decd33850b4688de070a1b7af5ea0403625cf6812bf4e472cf69c3cd",
+            "0x2b7b5d04447cc999de05f180c7402804af6d7773dd64dfcd7ede9d20f8ac0e95",
+            "0x0d2a2bcaa3a977c71b4d0ea974d4ac03d919e02b88fffae89a4ec2fb7247bd9a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x047000e5a5ed5de170a68f3d69ce9c12a9575a962baca4a558d7095b2b600ff7",
+            "0x0d253dfe02a90ca59a47232c864d7fdb67d89739745a3546b2a19ae5c8efa4d5",
+            "0x2a1322067810b75da1f8005ff2f219126c27263eaccd1ec7e959be3853f27908",
+            "0x16f884019017ced1d179764a9e67ee99371f87fd6e9b12e2ce9496967c1c221f",
+            "0x20956fc7f5180c072925aed40d98694160d89cff016eb910d86dfff91a5f1cbe",
+            "0x2dc8eb8bbc263d0c6b9cd037937b18cc1c9066bd63df32ca13909722b1745e3b",
+            "0x0faf482a0b106373e403e8edc4b2fc2052a1e9660cb25bd3676f693714f7e681",
+            "0x22389cf20e83e6b9ca8b28356296547dfb3b0569cafda64a828a21d55eb1f180",
+            "0x2e63b0a2679d38e467b543ae1c956af9e0658499980237d061a2aa2225d5a79a",
+            "0x1682965d36b2f3ea63c7926e31dc32e6f97bd2ed669fa2ec397c4252bc22d055",
+            "0x21906d7801ebf170a6ad2a2f3ca216af9da311ebfc7865f8b6e96cacdb4af3ea",
+            "0x2ae9cbb6e04913152913f9cf81afc651cc6a68e5dd0b57d40773136f0203eb6d",
+            "0x0c270d01322eb025b5ecbb781e7dbdf7d172e91bdd40bdab8eac533ae35d5b84",
+            "0x24e8f5ff7b87c526c122051522f419199c265cc64d16c2182dd911e0f8c95d6c",
+            "0x1182ffd1d115fa206cb1a9e425339bf0b0d79e879c284f618841571c811feea3",
+            "0x2519de7b9d3af97f06952ccad3fb14b04b1c3bfcc54ce96bd6865e0d55f2c0cf",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x17e710e450377023bda3a97450877e2d23c5474da292857a52fbfb81ceab62c8",
+            "0x17f387c5b1b60b38142e4d8168664f98e3488a7676e103a3502cf21268d757f8",
+            "0x293b609465e0af38742ab063803c634b70a372192665e22c2fff8268ca983f1b",
+            "0x0a0389b07e619ac526ae8b046210a51a60e8271fd1bd38acacdaa8ae0ad3dfb6",
+            "0x2e180e809077dba061cd1e9157e210e5a7be38d04271e2cb0f15602ec8d9869a",
+            "0x2d4375d36609dcb54f160e1e78608a08bb364938a6da6bc6a9a10896098596f3",
+            "0x2f378e311589ced1198e8617f930166e270943e0f285330810d7922cdf832fb7",
+            "0x04f68b892f4560c7905af61af4751e7513cdac96bd203b358f06d6c4d30f1f73",
+            "0x1a05748216ef4cc91ffc9709e3779ae16336a360ccb3036c934914e588031f2e",
+            "0x092211a931efd3ebab4a8668d0095147e3db82c10d1b6587d388d702c9da483b",
+            "0x28c1eca8f1f0ce323e3969999b7e2c08ed77952405e30b443c60e9276005cd13",
+            "0x2cfd3a968bc76fcce348483c02f7088649eeda045e5bf22d537221fbb5ab4ed6",
+            "0x0d303237258e009b6568832d993f908f4b28ab486dafe390758818aaa29faef0",
+            "0x1389cf7e7c51097b44b399d1c0bf9d78fc1b07f67d59595bd43b660760aac340",
+            "0x2d77c1d9b391c628b2c7210cb730a3a32af90890a3ef8e21899a02895715a9c4",
+            "0x065efca9b112aba4b98547a683d31b875f5f3f61d361065c2ebb33df9d1081d0",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0b2b7ada382e4aeb3b4bccc94435993f9b4872e8393de3e4f39c5d70b26d48cf",
+            "0x2f13017a983c23f86b11fcdf486a0a26c33a2aef09398972d95427b2c805ae89",
+            "0x0c4e3f3f707c18a005666793ff58d198a6eca7191b06e985d9b0b46b89ea8002",
+            "0x120b3c9c73c14690df27aea799f4938b478b7282fe9abc83dd163b1c70f650fd",
+            "0x2e8eb4791b71643ce71a6eb3d4b0fa283f525005d3a4a61cbc78aa2f66ba6ef7",
+            "0x0aff1b5437b6d71222469e8b75f02a6011abace2ee61f661c964bd307bb432ff",
+            "0x09125ed95ad962e468c3bb9ca8ca9fd21b0bec60702df1077e603c8c820f677f",
+            "0x195a790f1dee261761bcee1618fe7f54c8ee02ee9683093e56c2f44c7f187b76",
+            "0x213c5219ef217b84392eaa62c6fe841764ce2283b96b68b1e99e3aea99496217",
+            "0x303a3703922b03f63dd00bd88ae9c1d409a1ecd7740d70ff63eb2cdb90ecb6d1",
+            "0x213161b6d9224b56bb5433afb581b11d609642b05e82479e3967b2d832ae3557",
+            "0x16e4066bcf62f261b9f0675a5851383c3853fbe8965e0d8d0dc8cea1cde1e8ef",
+            "0x19d3480ef1010acf663faa712759dcfcff8558dfac4d9ca65cc6f83c8e44ee7e",
+            "0x0aea12df3ccaf53eb869053dfaf0b74786f37a73054041491231b4bedd207921",
+            "0x0bf797359fad73d9d3aca2a353ad3bcdc247b4ab1cf579e6c12c0db3d9572bb8",
+            "0x228a5f50abfe0223c1200ca111ac6500c392acfd65e7afaa9d249f15dbbf21b3",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0bf26bdc4f3bb5c4716a8154ca74daeeee3531b5887528e1291e9d7ee1853761",
+            "0x2a5c80f4399c1972cd60de46737ec637613eb628933a963747489ff0f82e2bcb",
+            "0x1f075f8bcfd24b08e0c60d9cc479e745618fadcddb634f2b03c2e62381852104",
+            "0x0b498b4aa35d0facad9473364c6647fdf7edff2f8ce22183b46cc42a13e293a6",
+            "0x2757bee4da6d6356d1923880ee7411293cbc6651b4aa60e1aa933d30b6132a90",
+            "0x0c33d9de1b526a7c98ea7a8ceba96ef1aca436773f5a48087060113727e164db",
+            "0x1d3daed14dd20a0c9bab99440de05a0607fedcd62321fc858614afab724984a4",
+            "0x0cfa34116cb8ee42b6b26b55ea0fd4250ac40d3283e130974ea05cbbf02b253e",
+            "0x1fab2b306e22b6e05c37bf6b2f9bf9a39293e69e859eca44d2d8b9f591cff6e0",
+            "0x260709f38741b78d88e3ad9767fb48ab5295536dd1aa16d4ef1cec768e14c0e5",
+            "0x210040bdd4f592099d55abf39f9fdf6dc16dd1fb9b18eae9f080a221f7e42461",
+            "0x069c1a5274d1f7cba4005e806f0cdac52cd3e196dd511fbd4eaeef1fdbe91d00",
+            "0x21db7def872b180c0a0cf3da06407901de0a757281dda8458c0c465c7a1f3d26",
+            "0x26dfd32936c305360377de0f509b46cb589b0978f7a398746debd27ebbf85238",
+            "0x0758418bf2550770837afbf3dee85ee812a9cbc69e6751ca4a72041c96b6a478",
+            "0x1bd99633b6d9fc85784c4c8370440d369168b03aeace3b8d0e935a657b31410b",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x17b09f408daaa44895a4575291b3750e5b613db61046963da1dbe31f7b017836",
+            "0x1438316fade7de113e845661487b384bd012d748b67bda68b0b1c6c324ebbd7b",
+            "0x0f105aca1e84b4fc932d383c48801d8e67154cbe135e904230e1b26a0e8632b5",
+            "0x089b2e53f7bb60459a36887d2f03f7b9ad326aa57906e30b7e994068dc7e7891",
+            "0x09a8c10b4bac3b6b21555b8a3c22f00720ce57c9d123cb95055a526e2e487be4",
+            "0x125e34075abcfe8a13c98b3b96d408dbd0082f5c486886e87d1a475878474e82",
+            "0x238ac68c89e6c4801487bbc107a41b9d971b32feb9ef6bd2d3f5a8a59975d1ac",
+            "0x170a61a61fdd7fb4e3cf490d77d1a9b66e533e679a39ae6874402364cbb7c0b4",
+            "0x2bcc5fa12f925b517690931bcc08e317859e0486656d8ffcb4a11dddc92e7f2e",
+            "0x247a667c9adce727704e2a0a2ccc546d9c4f0abbd100a67b65063e7221b25b0b",
+            "0x245073933cdd274e34a67a831bb76222005a73ad89b4dfa4cf48e1e8dd904f16",
+            "0x2f777d71331aab3b14d6e15c5fb36a823d3c9e9f080d0c6dcd778c7cf55393ae",
+            "0x19f7dc79cdecdb7ca9ae35583be03671f1d46c63679099697ea403157b7bb484",
+            "0x10cb7c1f308dc516a6ccd22e60eb526bf0df03b11c1b2ae67b9d65173f6f87e6",
+            "0x195eee52e08931ba050de79c1545915ec043697a93bc98ac551e6fcb6ca42e3a",
+            "0x01eaa53865c92ba93d78c5a022059d929dc78784ff68b370a9111954bb5688ef",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1cd80916beaf8c57878859c4cec46282c52f5958bac4e68aface254969c9b99d",
+            "0x1fcb9f47b1346d712d7c68de64b866821f174a7817afad128daebda7ada440cb",
+            "0x0d788f3cdd65e7b52078d945ba12556c879f486cf88727499bf52c78ef9d2738",
+            "0x09dae718210ec86174af7d7fc5e72f30e67262e30297e3a988715310f5ba108d",
+            "0x151643964b0aedc13a50210589188dd2e59fe5f58ef1f31069098b1433175f8a",
+            "0x0a6ada0476ab51b322b5bd460de748879e12bb1b11efb7e6aed668ce4e72df03",
+            "0x0f180baca8be8d5dcecec1dff1e4d2e55ff270ccb17fd93c9f45e2da8ef38918",
+            "0x11c9903091cba2f89bcc0031d4c0fe029f2fc7602e953eece64e24941d9af7ad",
+            "0x29f8a88224576ad8b0809aa790cec4ed1521229f3f005da8a648117fb6be2100",
+            "0x0b195578d1bd9026051c95ad3dffa01b9ce406f03ab04b3fff45d59af0c29d6f",
+            "0x156731559b9f7990f100c8f2ee4f976ec64ffa5b3332df4fa5a9801f191c4fee",
+            "0x1fae266546801b34c6f8831b2eb49d7806f46b9ffaaf8d7c1de4d1edf3548633",
+            "0x238bf45a93287e2b47db943940d2bc6dd68ebf39a5b3641cab66988d92759f0c",
+            "0x0de147f553ecd493a104347ab57f91cc19c6b185b77b580408b4b6b6b5f06f01",
+            "0x179474d9e69dde986036bf51552e7b16245c0236143f090374c08978490ba08b",
+            "0x2f3e6c3088efbdf34c7db33cca7bb6453c7def655f06ae709a7f59d777017cf3",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x14f7381844afdf0aebb07510868ef92d196dae5f08d9ad2c48bfb3b5836a7dcd",
+            "0x13c68f6c974bc35ac05a8d086e0a8f36e1c50a6f8f7cf8cca085fb93d1c83593",
+            "0x080d1b623cdf189b1073e5ef653dc27d7ae085b21b8be23c9289b6bf78c83c84",
+            "0x135232b75596e1a2662dab0cb65556d9c942264451c821b4d20da04f66b1310e",
+            "0x0488c811f8f23e2e35be0eb79ecd471399b51991797848ec80735d6735dc55b8",
+            "0x1850fac5d0dfc6c84d3b8720057d68462bd1501a27e96de9c80e962597b861db",
+            "0x223b61a047bb19ef29a60e29c97d34799c46abf7ad1d9b5d164e3916e0eb80d1",
+            "0x2eccf40b7098a305f6ca4c4ddde8c0e52a31d3ab59fcec3a1c7ec22247eead03",
+            "0x134b779f0e29be042b6fc21beb7bbb254458f744f1f17b7976211032ff508f34",
+            "0x0980c41722790b957419f809f37cb9e75735f689c1ffbc94413d057b24480eb7",
+            "0x1094cbda9c22893b14c791e8ee49e9b3e31264a8f2a27ee666f9da5873d88094",
+            "0x101a70114f3c21f1b6ac45780d471cf422d4bc117e6b38aeaeb0bad1e6cdfd74",
+            "0x25f81f616447a3a744deed4bdc28ff947c86e211b88c79421341e8e24cb982d3",
+            "0x05bb936b0f2c680ee91396bbf1480b59889ca0c9ac71ce16014ecad86fd3113d",
+            "0x0c356afe00a5ff0fcc8026f5d0024b02e8f4edea725bb511c7b1d76f70f2a3e2",
+            "0x11f99ed325f273b1d329ddb85a957d42133ae33ef3f0aa62c53f3afcb06dae58",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2d1f5b2c4f9c2e30d27af66f6e1fe823e6042bb58fedf15712461bf7e30fd3f2",
+            "0x29b37d4be30dfa6f50fbf25c9561ed4ef6983da63edfcae3d43ff8dc623f4b1e",
+            "0x11e8035cc06f7cd0cf3dd32e4873c2f8953e0eadc89394c1656e41eb6e407c20",
+            "0x0a8e8b4fa69f436d213d2a3fe2e44840c10b4e6364ee8c6b9207df50c19e4077",
+            "0x27544edeee86e8a45a331b88c642a557f30bc8b2f02b203c4b30ef230794b509",
+            "0x0913a10797cec94af63e47b38bc0e01d661d82e8c4d68bb583003d8ed93cf7cd",
+            "0x127b2e243afb93d53b369c6671df7e1b2c41362e9f8d453da562a09a39664b0a",
+            "0x1eb550521867c5089f3874de3173bf8a037d9c762f8b3ca7e4f24b10549036ba",
+            "0x2fc98026500f53ec4811c088f8f15d3ea50957f123727960077d8f61903522a7",
+            "0x1efd6204f4bee670484c468bc37ac487efb797fcbe22c29a8455bc778272ac62",
+            "0x19148549a0ff9d6baaf7da6a61d1d3c8f13674a08a4d55b81e238146eeffe676",
+            "0x14b6a45f03720cba6eb711732670d263d4f078b108d282f6bfda764ed2671235",
+            "0x1382a9b9460f7a9794e8867cc1fd08373c7010b8703cb0b3a1bfd740f2748323",
+            "0x2b29cd4cbb7f922e2bc7a12e16d0ed3e0f2cb431ef75ff227acc2cc3f956729e",
+            "0x1248d9c6c9aef55f1d2d3910b5f0794b91a44543372cc38bf7f290f529e8609f",
+            "0x08921d4e7ab462eacfc56b932382915a3375ca6d7d2f8974306d17832af67d4f",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2befee6216ceaf1d31e8b7dab936865f29f11d7ee6bb01d1cdde87b0b9ed684b",
+            "0x2cc03320924b3f8f550a27c5d2cc2fdab74eb926f0265707d71422b48da30108",
+            "0x2791bc1c29a8d68771c25a6f12f02698805bc0ee4b4bf34856cbac777a8be1ab",
+            "0x24afd7ecd23082dddbeef731352af67e382054642009e1d7eed8d8201bbee6ab",
+            "0x16ef0a896d47e10cc9878b7cda66ca3db5823179bb174a9fa8a57c21769dd2eb",
+            "0x1600168d6943c7a9de812b37e77c64f0ed1904beafd6b82fd08470554dce5129",
+            "0x0ebb4afd4e276a150ef5934981919fb3d68ed6a2c39d82a3d47faee16844bec1",
+            "0x02d48048525aeae311d85117685aac47a786b0462e5666903c4be636a4b049cf",
+            "0x1032ef2211e704be7c8d0dbad47b44e76514a6142e732d75f44ee8f5f00c85e6",
+            "0x238320ea66ec2ad1d04240b8cc3c0dee37ec004222cd660901a0dbf7d7ace75e",
+            "0x110bafd1bcfbae5704eb8f58c9c7c8bf3b1b7198e785ec39d807f1d7986cb2b3",
+            "0x0f45ff8e2697f6da601db49f76d26ee62f4561fee9c20014f6e001a7c6fedeb1",
+            "0x160ef8ab2de6cdbd8001f66b65fc0f24ee86dbe5ceb82f87e3a1a5702692126f",
+            "0x04a95683e1abf5832ca91092327965e9e0bb08bdb5e93464a7c472dcff3e5507",
+            "0x2570208637c5ad53343d805298cd430f130ebf6a0df77241942d09975f31a623",
+            "0x3013aaf866bdb4d28f2a3939df3c0378aa15dff50d362fc564b76b617071ab41",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x089a0605043d76c0f94c494aa657d1ece4a8374a1b32328247eb7d09a6d2f724",
+            "0x1685519b1a4ddda68647b9042b7c6f6a063dc0fa70f8d9e6dfe5ea4a610f85e2",
+            "0x224e720413d1f2cdc51a85e52ca30e123e36ba92a5859a48b994f5a950777182",
+            "0x01ab16995af3af8fc94b9f05ec858538fc534e53cee359ff9bd3db173921c46a",
+            "0x1c1808dff673b14f51c82d29b695b2c020bdae7fa9fe630e4abe4fa67db87ca4",
+            "0x2382dfd770822a9fc9d555f5c5d81eb1601506f0f148764231aba32bf91d2de5",
+            "0x0809b0ba19d812c5e19be980326012598e227526d7454a93a1a701edeaef4db0",
+            "0x1195bb87e082661801634b22f96c6b3ac9b09d9a276ff0cb7a314f1d3bda5365",
+            "0x2e862041221b1619fd2df3d4913890c4a0b5a59abb315433b7acfcb6c2656d30",
+            "0x2f755c9e501a4c79f315e7b630bbc7716e8addfe31d6c977a785a77ad4eee234",
+            "0x2a6064799572693fa11bfd2a651bcab9d9b43f42a9e2e983010f95198b82ed12",
+            "0x20495c4348122546846a2b78d10a4614159bb4549c78059b176664b612280ab5",
+            "0x15bec405e4fe7646c9d7bd49cf377d98d680e4c4067d075a2988190a63548e5c",
+            "0x250f52166a1369ebf0eb1af9942b0d948d545904799bb9c8b7ad2abc81ea320a",
+            "0x1bfe89b7fbfede05412244360e4f3326bacaa2185bd4c6b317f024bed5f6fb52",
+            "0x19fe9092cd18756817f7556e665688f298ea1beca153ec6352ab94271f793999",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x10ce4a6d4269a17dfe92510ec93fb6658ff39f9a8f0e585531f73f7cf752532f",
+            "0x016052e02614a0aa887015d35724cedd2a504c518d57333fe5383e9551e027ed",
+            "0x2be3cbb3d77a5bd51f1b13145dfb965a752872fe3106bdd3bae64d209c9ea4b5",
+            "0x25a9a2f4b92c0892b49d2679bb9e1435cadf99273d230578990d6aa745b96561",
+            "0x1baf3ed657154cd6f669b5f8eedbfb83f6e195c81771d99f22ce037eed4c208f",
+            "0x3014d7afb9f54611648236f9fd8b500aa75f63297f4bcb861fd3873f5eab5ac0",
+            "0x121170c6d1149774e21df95b400719d14c750d28138afbf863154f8792724f3c",
+            "0x2574067c6d21ce31ada88d944515d049f39c276a514d02bd2499ba155091c92a",
+            "0x23e9ded4b421e4593d8fac1b0ae3fd7356b44ca4e260761a7684d6f007b29d19",
+            "0x0f35d721f6b21476d6b3ff849c475e11ce64b95e232ab7e2a7438b634de73001",
+            "0x10c65fa83ed86ccae69d46bedb5ada3e19ebc7bf9bb472632353ecabb23100d6",
+            "0x2992f0fe42d4564e6e6bfd35afd66c2c3a27a9a0f633d9cc1c1d427bb963be52",
+            "0x07e6be0645c3abd3f029bad9dbe7245f6314dfd995bc3c6b077149b622641ce7",
+            "0x132d7fadb9055e24a4d83b5147ac8d511974174002cf523973b516134bdf571d",
+            "0x03f65d97b7f0552461e914199771d0c5d328522e8c7c4e864a46642711fc9714",
+            "0x1161f27448e3aa4db296dc8cac6fd849b70551947c7991723ec34d5a0ff831d4",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2c1fb2617d0c9fe4284e29e0381bae45a6db3ed4f71cd1bb3b26488a1b9e51dd",
+            "0x2101957afe861f05b258391bc6df3c1fa5625535a20c0768f688a443efd094e5",
+            "0x2371a0848c76037bc404927441a0df354b290811512a4ec8220d52b595f67909",
+            "0x18c0822092e2ace78e325294ae8e45d16f9816ad27bcf5c1b4c18eb958cce277",
+            "0x0180e64b39561c2e7f42a478661d0e029968fdd3172daf58a08f019234ad079f",
+            "0x2de09d447261d18c347afda109f4dee4b36fdb22bcb6b1a25d066ac7e0a04c92",
+            "0x2ed91a31bef7cd81ded3e9cfa40039ba6c8ce523edf02718911ba2b7ebd43f43",
+            "0x0589191c005fd95d28e0b866ac38d4938edf10b6f28af8de0d6ee1215c5d6752",
+            "0x022717e4d8f99f8c4ffad4201735063878cd69633a0548ba222a60cbf5305541",
+            "0x0c3ef6b41d37892b5f897557d3af6f8d2a1f036070830bc03f8b349dc17ef227",
+            "0x189fa9829da743b067a8cbb723a022275066dee4d6b4eb3ad28fdbab7af4a64a",
+            "0x071d4aaba8bf501caa9f501b501ca69848e40f91ece1a6bd26d6523ee3d9362d",
+            "0x1af3ccda95f6aa62ea79f9a019e089994d1a8bdc3887a1d71338c70b75863520",
+            "0x0bd652d1ea4dfb47378122e3be566c975a71c24e20fe157291cdb9fe2b91e946",
+            "0x041f663bdbe47fb65f51ea336dfc085d4736e624a0290c97cf98f0281af61d0c",
+            "0x1aac52d205ec6b064d8b0d5343e52b0ee3f72f98e633d622f54cd3aeb1ee13f8",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1e265e4bd3edca15ac279879c4d03cd1e5ab383ac9c0dfce03932258266d0002",
+            "0x1144f03d428484523089f743913f55cef56ba65f1fdf6722c2beb790973777ac",
+            "0x0073aa396e27ba9591fcf0eea23dffaaee54885c098a5de8e66d2c40183f524f",
+            "0x28d66d2104c6afad051e78f29acb2b54a8d4904b2007e6f54c555ac999eef95f",
+            "0x1de390f86d83aa344cc4bdd5bddb215c63232d97d7321926463a3409c8df2f60",
+            "0x16f25c943027e6fab0a6e968c30671759c14ddc0068cbfe83615169a17c22538",
+            "0x12de14e9339ae76e8179b07db32866849f6e095af8e9351b9cd5ca7719b0bdf0",
+            "0x0e412d472c122d14f3dc1e7113e203a1cc90ddcf90b502328e3ce6406effa39c",
+            "0x0ea05adc8a495a8e28db663cc7230788bc0ccd88fc8b2e27492ba528e3580092",
+            "0x15426895192de8dde6fe74afa1f4ff18ecdb01756510519b6996735d366b1fea",
+            "0x2f0dff0a97333111fc8ee472289fa282af7b4c63ced0b938ff06996e7bb2d956",
+            "0x0026508b02acf1011171175441aebf7e8ba75b2dc50b6553d7787001ee7a22fa",
+            "0x25653ec1f14f395d7e487242b02d0b3d41a37e1b74a2da8bec6d20a9134a459a",
+            "0x08ff4c0a40ec8acfb193fea58ca9facf3eeca7ccea29158e0d48253e8ed5322c",
+            "0x22c9aea15ad0604069a1b37d6f3b3163683deb5b768361451c79c1fcbd6075db",
+            "0x040eaf74686002e54cc687c89da03b7a9de2d017513d3a060c367726bb29c945",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x14e65c2b53f107a7343b923750c6fd777e7be0b1b3e912911eb9244f9e365a96",
+            "0x153107b5a395488f97b3780aef9f939f929b04f4c18a63018da9951da3ea9b35",
+            "0x2699398a2969d027eaedc4bf7ba3c7f6fce9a4db6c73e7ebf12b19c386a5e0f1",
+            "0x031d9dd2c3de454c75566cfa7ea85e53aa2a64b98072c90d2fd00c7a95193773",
+            "0x17a951ba66772649e1bc62b73854155cfc544fe1435e44d76f3517b4145e0386",
+            "0x010ecec4f6c1a73846f8fbd38a95525436e6830af1a4078a8eee416ef6553a92",
+            "0x0a77cfd505a06c86385fd5de21cbbf64ec4f46e6de1cca27642f3e9122d35fd7",
+            "0x0ef56ea2f041be614967e0ddefa5db7da3e8a1867f2e6f646670e5eeaafe5931",
+            "0x01ccef86739cb0bf12aa05b6d5d144849174e8ab4155f0eeca39192bcb16f37d",
+            "0x1e27cd25ff5502754f1d1f009bf27b174facf34390501d0d9ad59495ffd17d91",
+            "0x2c61ede53e03a7ca305ba356121e9144af22f453c3dc53dc830339a4a967b5d3",
+            "0x0c093e2397b83ba51d5ab8ebea292826894484d441668eed5ec0bd7af54a71a1",
+            "0x03ad275656c3aa89b3ba10f5ad741cf9833aa55e04913aed725bdc8322ff3b99",
+            "0x04a609be49b399fa2d55c57dc5db8238542a1c3aafa9e89da2bde434543213e7",
+            "0x1ecfdcbb98fb85ea74536ce81d50011099264ed5cbaf63c971e7cefb31efcca5",
+            "0x03b4d08bbc785a10286b45484858f8393d5c9dfd40c1988853da27d40d1069b2",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x167fe67a8fac9eacbd2cc8036fd3bc006cc05c06dafe0577b6a061e17d428b2e",
+            "0x2e4a97de6089719807d390814f33b02abee4949a6bce4e4637cfc57044a9938e",
+            "0x179a7cccc493d2811216c19a76c83e4210bded6f83f9d84deb7e78e9a8624887",
+            "0x0ba76c3a60b492674e66e8adf4f7bdc9e23ccfa45c93af75406274d82fd4500f",
+            "0x001c4a56697b09ae07c2a45789564b907efbfeb9918eb4c4a441f5b55addbec5",
+            "0x20f7ba140daaf02d6ec3723e3357108e6ac46bb167bff2edb837cb6df8c91e04",
+            "0x18a2b56415506fa33dbc701a1eac5ad18a4aed5441e14b2cb84493cd0546d426",
+            "0x1357dfaaecd28e3034ca49ccf402d08d511ae4f5573e7800eedd86a4f50b1096",
+            "0x1cd4b13232b8822dd41ff70883346b7c9aec3b4f7800856076b26a2c0edfa13e",
+            "0x174e6082a0beccf554c113d3d9e067eaca6ed9aca79c94d29ed0d8b60aa35c41",
+            "0x254d88c45e80e07e5282a5462362bfcce1838f4bebc76b421d69397678f3604e",
+            "0x1df36c5a5c367b0fb16e75c449318b870f81801d4a30440ac40c52151a533f2b",
+            "0x1e152d976741d79b7bf3422ed40d94087ec83f0a8b55f186c27836725f6651ae",
+            "0x0c2040b07ce29d7c6fcfcad258e588561198d7388f9de8a5e7c841c759dceff1",
+            "0x0a0b3720bdad9e0ae529709b98d15ac93f9528d6d611388e93706b8126dd06f8",
+            "0x1a3c5ca792df20014001161d6be74a615fb6e23d7c1c1123ab86c31ba0f7cd8a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x002f875b35be421f678dbdc8010541c1c1974a2c5456fcee563b682f48ec8365",
+            "0x0291d004307a40e55ec0883c74594731f828fc9083a0c1fd9e16548eb8c0ffb3",
+            "0x263d8c33d6ff59f50df08e4ac66444e1e5ccafea46a2dc25625df3acfb558133",
+            "0x0cc79e03138db3dedc5d0f1eb493546f57dd61ff43b25a93e8b70b2406f08fff",
+            "0x0e7e756603205605309eafac32036c59143f45e8ad3b9114b360d6b62cc400b3",
+            "0x13c2af0a2a01e09786fad13042dd002ae614705a59ddf82b08fbcaed279db411",
+            "0x0fa42330c7bf8fabf99f9e41bec129441560a8086c5fa890e1b9d43867190784",
+            "0x1679aa41bc384152ed54b5da82682824937f9f593bb2bd0c4d43273d304b3068",
+            "0x2bfc309a7a118de2d5780bd1ef11743ee830de87ecb2f36d65222bfe1303a268",
+            "0x2b0d42e144fb09d5bac855143d8030d8876f1287d880a449e2b45425a4024b34",
+            "0x12c81a1a7ca3ea0ade4fffe2101a358c80a7948fb4bdece56e5121a11b33412a",
+            "0x011fd75c5092e4aaf20219845135200a77690b512c86f8b688aacaee4bf3ccfc",
+            "0x26515a8166635717a7085c4097be8eb767bf96de48ba0f062682d003f5ad03fc",
+            "0x23146de03f5c05ba7df1eff715f9b70b5edd1bda5bb43a2042808134926dc5ad",
+            "0x05f78111e3c200fde6ef1d093911a417f3b0155382c3b787755229e7b400cb82",
+            "0x1c5438ab77e0413bba8b186187773ebbd53c15ed90afbcb350aa198fd41fa4ff",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1a0947eea3b15fa340d15db4cb7840fbc1c980d27a71fbdd843a25b6be64d660",
+            "0x116301be64f9288927194efd0ff5060d931f750f78fc41de04bd07eb8abe4d84",
+            "0x11cf91fd196ffe67d59887959976c63a1f2f3dc10dc1dde2aa0ab71d57ae94a0",
+            "0x2efdd12c27a24263771690c982fa974c206d6b8c4fcafd62b8849b4e65bfb32c",
+            "0x1834653149647e186e9a45fb2eb68bfbf1447bce0647c39770792b42d7887b53",
+            "0x04fbc23905dfb87b3aed73dc00a4e4786a8a7c9755f326639266ec3bb892191c",
+            "0x2e60d115ae6061084a5e032d9ef67344dc28dca93129be1235aeb3dc0dacdb1f",
+            "0x064dd3a457b6f186936103a5b338e4e718ee8c92272c113c999db4673f203bc7",
+            "0x1b8aea0306bd7f4547a9b6e75f3fd6293844c84ce487885dd7bbd5e2bcf55f57",
+            "0x0f7a996e41df041ae587e642d48a887f246e874bfc021cc6da3ccc6bb2c2a7c8",
+            "0x0c135fd4214ae534c4cec45aa9083be99ed957e93538a20a99f3868dd17560db",
+            "0x1915aadf609396ceca1001819b6a16f6a0f1bf051df94c026b3986bff517c6ad",
+            "0x3052f61af90b4c87b2f2d7605557848c656e558d21807e89c7e3ef8135453a2b",
+            "0x16ee07ec18b3178ee485b2c1ae4b4c21ae9940af6dcf972f5a9fe85a74528251",
+            "0x143eb9ea575b7f9327e7e4c956c70d0b06fcfe0136aff2a424035d98427359eb",
+            "0x2012248b2620e2213d3ce8c7242bc69f7501378e8c664a97a1ea1bb9a3359098",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0bfe98b8fc31cc8083c1f38f3bb2c54488472dffd7edf82fef906f993cb15626",
+            "0x2dd97f3e9cc1bc0eec85a1fa9ff65d9cda7a7307e699bb8357aa1c729a13666f",
+            "0x060cf42ae78c8f5b24f11e83e616bb026f6a9a6bf2099b48ea1f3ecb1f5f607d",
+            "0x0213320aaa18bc6b19684a677cdc2e7a0eaa9fd097681f8e45e906da64476641",
+            "0x29af57cf2b697c1b78e08ea1aa8cd298dfbae4ee7203f9ad69371b65942d9513",
+            "0x0bd387f5017188e830c1cb4d1764af6e89348abc94cfd5143aa02929afcebeee",
+            "0x0641b40359b703aa6b13e1690cba693b574d53f082b53dfd6722216d2331a422",
+            "0x1369521ce1524da1cf4295b6f4a4c80dd931e9eaf6137fb16989d410f8a05d75",
+            "0x26346a2885f109b8de58cf0a1d23e1f590b9e59b2d7b9f1544f0205420fdbb33",
+            "0x01ac4fd0ce31514c8f1b0e0c300efe4a39c6e255ac0a9ed198e5c0323251a6a0",
+            "0x0a6edef53a1b3d979a0fcf03648df2914c228c0560370f5a92d5d39a089b5ac1",
+            "0x124afcdc653eab75ddfbc52927b5e5176ff06bc2be8f2dda7ccc88b3e37dc92f",
+            "0x29800f44ad41b4e774281f84a016e536a69e98944c4a7ad66f336033a5539892",
+            "0x1fdd7b53a5ec9bfab433769738510ef0cb5175c62b7c1ec378ecbe22121b7238",
+            "0x02dca903cf5ec0ffc8621cbd34acae4bc37178998630830644ae937a653734d2",
+            "0x277e32b56fb93936ae6a12275a8e8d9fc4597cb462fa33610ed3385aa376d63a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x2208a3fba2b2605b9155c54dc25365e388d1170240126c512700b0c5bd207150",
+            "0x2b97d900b95e518fc1af99ccdc1e1defe269407c29aef0a1d4ba42f2e92c05ee",
+            "0x224779891e3ba529c7aa5f50980cbe91e9bb22ca77efffba37fce471e7ec65b2",
+            "0x0fcc3167e4311172a727ee3c86022e0265b3583d3ba2ff976e9ad3f3566abcf5",
+            "0x063023161dc22ca61cacc3b4f2e9e381a03b0ef8e9bc616089e2253e8241c0cf",
+            "0x2292bf68f183706a8a2b43d180d45b9ce3b13d3e82bf66a682cbd2e55becaf31",
+            "0x12bbb646cc4a71ee15efce0004b42e605861522266f7f8f29fdc4454676e8381",
+            "0x2a9e7129a88ba7059923ccd3a6dbf0bfc889d25b41a1ced6ec6eee503c0dbc52",
+            "0x0ec2baa96400f2c140c45a577ea67016d44fe326f208b07c5499151dda81d3e4",
+            "0x289c49da1087916bd80ae9233533f0af6abc584f13323cabba4dd5224a76e833",
+            "0x00d5104eeb2311d08a6f83958ae61f7e54ae8354ea7a60a82ee52225ce93d06e",
+            "0x2e3c11d3191e3a2ff600565fd5ee49784f4593de77ae0de69a6aed1b390fd5a0",
+            "0x22730d582ef0c8de48004fd9b2e354758a46d33c0bd9787108ddb5be443e1748",
+            "0x2b50e859c7eee798cbe4eced70127ac408a957cc5f555e0b3947f6253b7c4988",
+            "0x1928588fc98fd7a753ccc7f012c58948aaad8de77c2cebe821d752401e251c0a",
+            "0x055ab7027209b614657bae1b5eea7c5c76170b0e27f5ffd7719427d24ee0ddc0",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1f71b7e3dc715036eb8947680a67cb72e7ae2af8e58ae8a8cead78b52a4a7fcf",
+            "0x212bc14bd5572b92f78d695135fc5a2162eef6d015832313e59953e187bd2e5c",
+            "0x292d402347a32d39ab28cb5f782c7b422d5f44d9b3e2fe3d8615ca2ad4bb6581",
+            "0x1a6635ff120988acaf9edf97114e3755968e4d8965b104c2458133e50f868cb0",
+            "0x23fd5efede5ca629769661a6d313c5ccf510b9b605da01afd77dccefb144bd61",
+            "0x0c4dd6c32175af53a323fc128812ddaa2db03f80f00f3fe5ef19debea9f03704",
+            "0x03e66ab15edc19cdbe890c536ebb52ba538d622a4916b1d62c066d203239636d",
+            "0x16b8d13d20bec370e0f27bbdca7eb1f5504831c0eeda09e1cae4e3f5717c2ff3",
+            "0x29bdd32b57bf1e7108b78eb9d14beceaae889fe1e06a8f43a532514bbacbc1dc",
+            "0x2523ae6ac41cd9a4cd6ddcf55dc6ad295373870e2d2cbc00ee27f1c8bde0e990",
+            "0x12f24d1ef2b29ac589a3f49fafb611db262ecafe361f1b1eae70e46264e2a256",
+            "0x15abbbc65fc27a168634a4e9c33cb4fa357711c8261b23c19f5e292892da4d61",
+            "0x0768b6cd8e3a0b39c3f357c688515e9b4f59c3851b5a91e6b5bacc6050d93e88",
+            "0x2a55463bdb9840c4c54efc09732a6a39f7c5773cd6e5fcc9b9abda728f0f2280",
+            "0x2ec0fcbd9dcdb64de86f2048d2695fc173a75c687e6d6c1c6648dbdb33599a5d",
+            "0x29b75dfc2fe3c1ae26a06a67425314c1fce5c44b8bc93ffa57955566de98b83c",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1a8c1d25c43deafcf0b727f4952cb9f66cc8e3333f91575c6ad10c2a54a6af8b",
+            "0x1a89e984646fc0201558dc88be36c3677b3b8756f8ca2fa9f915bf96dd380c7e",
+            "0x1c1901810a377a1b25f92651998ef9b88cac465917f24c2c4c1ce32dd4785bca",
+            "0x2b200cc5d507a600097d71292cd0c189662491031a8a51836bcac5bcc497c3d3",
+            "0x1542074df23b88172464686878e54959ff7ce5b22e4af9cb11a154676813cdbe",
+            "0x2ff122c67782134004cfa732920452b165ff9f6b9dde79c62d6d807ff14d216d",
+            "0x2645552b6f866e5ca869b23dcb93a72e912b59a2b832905c9efd8ad53734ccb7",
+            "0x0551a2dda1fcb98f90576b80b4c150a411d0c1c96bedba7e9339215e40ecfe7d",
+            "0x28dc0ec30c4551f836dd51bd8d528b419f7880d2c37d98bd13f844275ee1dcab",
+            "0x1ba5864780e1b751d782ffee8de027ae6f008cffd32ae7071006b06d2907dd47",
+            "0x0c960bcd6b0a829a93d6d361a0b3c2b5d119ef2c5f1da33be1e2f81b9487395b",
+            "0x27c8d01833a284c52afaebdd5006463424508c787f0608f13288e060dafba192",
+            "0x108e75078ce100b7f2b6be662e6f589f433bd8ecc28a02b2af0ecf4316191ade",
+            "0x2eaf096e565552bd4a01716fd7c849280b1fe0bbc62bcd8c96eef210a2442f14",
+            "0x10fbb75fd6bf417a625e3663d4ec40b2dfdb9fc33f4f3779b25fa5b597cf2f5f",
+            "0x24ff88fe593cf2cc0ff289a19c4da2b15e0ba5798a69884b89a5fe52c43931a1",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x13d6ef0ef83ee05b5c884f566d7ee302d2bf5eee3e78c56405a1bc3c864a7ff8",
+            "0x0543431141190a1db1567709598699090a67c1c5e9f899f757055e51d8b772f0",
+            "0x0f0ccb75a23e7e2bb337d4fda1b6e511c0b349bc3e53605293c8f677ffd23144",
+            "0x170e19a4a9571081ecdfe2f2e04a075960a489ac3106a8c53800332a666c9849",
+            "0x0f9f68adf4a6e14da3c23d297805a4466c5906c68946e4cc4b949f09c5640777",
+            "0x112b509c191d4752ce4935d3f8394f5915e3da6fe6a17e837012a2f66f2d0f0e",
+            "0x0a823cc402da461b6018a9594b28401c8e0a82f40f1893595282d2422d9ecbb0",
+            "0x22109150204d672462760a69f8b9b6a6399083b5af70890f1d184499b3e1ae09",
+            "0x235a98f3e4a0ceceea47f88ca8dac1b469d6c05e1239923d7a3425a37061bf52",
+            "0x015b3cd4f7f48a93493f5edd825425256571ca1bfd9089eb88c22c31273a1f18",
+            "0x25a75bfb009af66b8b06beb8d0b799e90454500c4c1d6762d8bd4cada87e486d",
+            "0x02a7da783e5e65a2a7829bdf3c81f6831fa8d2859f6793e89509065485b2dcdd",
+            "0x051a447914a9b1c8d12f87ac04e8f9477078845a75cfe54073bb7084d4b03740",
+            "0x1c03b2c3b6b464469e03cfeeb601e0c1cfba9c0abb25807e92a7c862c77eca1e",
+            "0x1b99bfd7e13a53ed420ae14dbdfcb5061d3d7e58127f4b96d48390619e700b53",
+            "0x28b521dd5181c0e7da97094f024fc846ce70570bfa8ca077e66cf648f364f442",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0a5609abc5687752f91009ddf77ea237eb739567c47874d91e0e80a31611df10",
+            "0x0f3c8a4728f9e980295b799d8f386612e4b96907c718e860a061f52ad88e5013",
+            "0x2b5725223b4ffcfbe09dffa66cc65fd4134a91f271d313d5efc37b8dfe3cf3d6",
+            "0x2af412a619b556a7a0dc4083d5abeedf49c03d0e79ca3ca886191597eafae2b4",
+            "0x0b205b3c20977bcecb28ddec8309b3b581009272f4e6f204b92afad6fb2f519b",
+            "0x164d4d2bb5888fe00eec931d140b86eaa1a37c95136ffb66a677aff5280196b5",
+            "0x1813ffda9d5f1a26940f30fa2356fd16a0dfce2f26522a01ecac0ea52da27be0",
+            "0x2d54960cce9ee799647b77849bd8fd52c46fd2c6dc57972a413d18c5538a565b",
+            "0x29319fad1949653d8a846563b04acd14fbc678caeb5395f542d4d017c77be083",
+            "0x043b4eed92d1d1a7cd6d7c4d6dffa88c0c7120ef5d90cf7e08e9894687f3d911",
+            "0x25821f595f37f7af962148dfde15adeb934f210c420c17208454c8d8be814047",
+            "0x059e0b31096024b19bb61018fdab0d9968d13543b27e2a8bbbe73233bd5dcb37",
+            "0x06f6ae0c62bdd6cb558cbe5eefdf257c22f287953c8e6ee9407fa736ef3191e1",
+            "0x2629996ee50689c5b2cd9e285d79cb0b49fdd0faeef3f338feff4e3f4f3ce3f5",
+            "0x0e4da553526374ca76c0154b374be9513920439f03943a529e554abf446a2e39",
+            "0x0770cdd4c75ea82197662bf05b7e40383aaa6d57775e24d87af4633ec38a800b",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x24cbf789e6b504ff862721dcb8b0131cdee2c833629a0ac0bb97345d1ee8cc0d",
+            "0x1bb44326a94e09fcf2b08f8d87c3a34556383f1ba27876ad229582c0c1a486a9",
+            "0x037d45231de1df3b616ba9e0ef07791836c9308ba5ca5088cc7cbb99a9e97759",
+            "0x114eab0d47fcf0612800da2643430d2280f3f89d57a3150aa774c5f1c3ad1720",
+            "0x20f606a895e568be945f24da25e7b4309dcaddf90b6e19d2fc4dd9a0d1e49cc0",
+            "0x27996446ae2d6fbbae99a11a2f45ef7b86ba44eb55cf76680c56b67a9d218628",
+            "0x14fe59246cd80f4817b2a14941bf5e855b468ab6673ee80cce28db5ff22b275c",
+            "0x013c694c7af51f9481b31591a87252db1ea149ec182a80d1a7780e301daa4348",
+            "0x293b1a6b183f8663322075f3619bd3a98068c10adbd82d048320f153847c608a",
+            "0x136dc715feed85bbccafd16f69cf497154dcac9e31bd4de2bd7396910475c97b",
+            "0x2c5fe8f7d35bf5a29f1ccccb5e054c911b100ccb04d845c52425460a513d15f7",
+            "0x2818f3682b9ee5e3c060756fcf00968b033e08ff14e0924c8e1cd36658cfd839",
+            "0x1f909f5a88e621210df8a1da5b18722a7008bed7df7b2be9934c516e391e9294",
+            "0x1aead2b8c6c3ab674205bc8cd223a9380cf2b3d02521454f8739615c1c8d594e",
+            "0x104dadda272e59d0b3b0e0dcb5a91e09ccfe39b6cfed4bc27628e40cec9ed2bc",
+            "0x08cc1d4b98fff3e73d0fbae5ea524411087c095e6a0a4ffb217274c14d0d905a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x13869e1f931d9e3cd6a5ec30759086a2f2703e55a2690304453bbfbb527e95d0",
+            "0x1476a32dab3dd270c27bd8df497bc043c740ca09735a70be69e467204e14300d",
+            "0x1d01fc854d91daff921d3f875366ad0905c229af2f9b95490352b757e4c2e4c7",
+            "0x1944ecc9370e1019b34b9fae00c29b81945d5632386a849909ce10aee14a2172",
+            "0x15124d37dd153ea3d6aba3eb04e479f0f188dca0f961fc03befcb8486feafba0",
+            "0x276750bff09b4f8295e90f651a50fdbc53675e9055ed7e8b9c636a837eef42b5",
+            "0x1e57758bc459bdad0573c1cf64356c69fbccb52699a687364a46fb43efc48a75",
+            "0x01bc81e07b87066bc97de117dd7cd2d57031d2b34650749a92ee2192d8d71b0e",
+            "0x0ca00e30b3d27e2784da8cc8ae33e8beeb12e36f987e182432bd68424acdab03",
+            "0x10e3c8368f018e06c879447c7ad56a1ef3c27650792970d991125cfaf4f788d2",
+            "0x10f2591f2dbf2a4d0dfa488ea3e8c825f136a95747955e0d995f0519df1d24a2",
+            "0x0491c65361a6dbcf71e8cd2b9f455f64a997126b24bbaf1b54d16ef786f34a9e",
+            "0x2f797260be9a31c5821e83ddfe667bb2d39b619000aecd1ec876e5e91b56612a",
+            "0x2ee7c65fd0e30f94977581a882cd0f2068da3a2ce0934784a6892f77aac65d6d",
+            "0x143e6ce2fc47ef2f8ae260e060fe3c26f4d8e931209038de4a738314789aebe1",
+            "0x19b396061c4bbff797cfbb51b7ffa35568b9b010ad09d979fa3beaa0b0c2c58a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x03d62f8d9f3c0c7c0be73e77521ef0fb4410b99c8853e815a6d17883e723576a",
+            "0x09a0026202ba6f7d6679b59f8e8c633c5fedf920705fed189f6d968e5451642f",
+            "0x09d7d7bd8da2ce8ddf30ff136e5f22260ea31e2a2314eddafe16b78bf65e0e52",
+            "0x00ad2e7d9e2f5255d09ddd66aef0b04dc34230213ae5557325d8060ace065f13",
+            "0x068c2b6d5819e2befb7a952b6cec891ef8a5fae51e786560ea40f9aa692478a9",
+            "0x2957a86994743ec84409a73b351d6266831189b2dd34efc88026bda159295d70",
+            "0x0d20846c0fcefdf740d307aa52caca1c0602e01ec9bd271b576eb5011856b11a",
+            "0x1773821410cd19a5c7e680f54433ed3d86ec9cb1c6b71c77dab3ad2e61a010b8",
+            "0x2d9a4cc5629923790956b4f229f640bed0d7d1a88d868c18465ff2608b4ff55f",
+            "0x0abe193c2855f86255f9fd92695172ebc17a261e202417a8984ee1e4923fdfb9",
+            "0x244b47f8530d4fbd6c509e219dc03d42210473be0890acc544262af4e95401a4",
+            "0x25673567759bb55cb49d3719812c5322f9f78ae6ae601d61241adf90c85224cd",
+            "0x2bd159b7e7d2923a94d365017e0bd645fee8f91300967396c96134354789e1ec",
+            "0x2269170439797823b5988a0e55c203b476ea7f17ce8a72d820bae775db07f444",
+            "0x04cdc3847d11b98f3a9fa60355546f42772783d827fe699bec49d10cfdefd028",
+            "0x04eaf02b180a1fdf5546f15ab9bd4b26f9e9e7fe9450542cdd914ae8bed16ad9",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0cb9eb8dea3904bbf5b3a2e4a18138a2f69a0a718d390e26bedf9fae68d9880d",
+            "0x1c83ae210ed5a5d0631d47a24ae7a9193cc33112a83373723b0c71e5db2d75ed",
+            "0x1cbaa94993f9d597f239f9e0ef4f2fab44978fc883bce647fc7439c54db40df0",
+            "0x0bce29568ce57eab945295f86dec09249e4514826b4a56e708391aa5e9ee5cda",
+            "0x1a1a31268dff2fb261c854e182b8eeb737f57bc45ddcaa71187c355054cef392",
+            "0x2e62ae444736e1390f41271d3211b01e79e27f856de54ac8c9c218ae44cf11fa",
+            "0x226b525974aedfb5816243eac6094edd64acd39f7ac1f9602bfda498aae25b66",
+            "0x138af51143cd675330ec36b73688d94b3ac1b45b8e823efa193fa0f756b58ee9",
+            "0x1232673649db779c2690ea66dde1171c411f0a71c4710db047da6b848028cf9c",
+            "0x1ce95dc19a314c9a337fbf6ed8b9364a1501cee4b9f72834bbd74d211162b6de",
+            "0x18b91c2ea1798ffedb17f3c67aa5876d9ca352281ebaf2938827b640d99efb02",
+            "0x2547d1553670c44faafbcd825fb6c42ee7d93671fbcd026481c693c4f3aa9dce",
+            "0x0b6a59a8e7ff6a09fbca204527a892841d36b27207d6f0649ea1b84cbab23856",
+            "0x2492bb9fc7c88c57ba57e1aff3f9db31df66db1d1cb9fe52d77f39309797b3a9",
+            "0x000489dece0e0607489ef1b904a947778539655bef88eaad61e780c71698d9c1",
+            "0x105c91eec4590a3b2a2b93a14b2e5d8afd07c55df86ffcfbffc48e0bb3028e48",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0dde3dedde5fa5db52f90c9b1f6e416c19fb76123678a18d0f63b56e777bc1e3",
+            "0x14b6eab64e36fd9d5903a245e29d9109b8e73cee0c3234a989ffd4b375c7046e",
+            "0x1333e70a62b6be95495261f8dd51f7bef1a3667b5916b796dbee123874dfc030",
+            "0x023d4478e47dea82ebdeb04c352dbb70d3e14c54548409487280149230f0e871",
+            "0x284dc6740a76919c88e97a352bf8df96eef3523c4c0a1125f29c36857948f751",
+            "0x1636483972c274afc73bd9708f3012d4076813f8ca81775edef39f576256ede5",
+            "0x123ba9b7a352d848b19e03f470a7fda3d0f330695c1ae7568edf11683ec396b2",
+            "0x022acc131a57b0e7412c61a9ebc450889947cecf50021a5d8ba27a246fcb460e",
+            "0x1fe4741a50a326767a4ec1b001d7b65995ce8721e1672cddb8331eec5113010c",
+            "0x0987a55b72887d298ae1015aab99a47a39c28221a68e3d3c06c39de23ae1fcb6",
+            "0x15d18d7b7c8ac41e05af1f0da0b4ddf955eff118d59b09b612066d24ed826d2b",
+            "0x07c0ca8aaa05eb1308350bc57ad0a4a45b52cb20542cd8c3abe7c9b625a877b2",
+            "0x18794a33cbbe20d57eaf317bec766232837fd2d75bafc91229aa77071aeb2dfd",
+            "0x1c38c4d85d589c3f2a0e30202867894cd02ea323982df08bd15e28e0c95a8efe",
+            "0x2d60c28f7e43c1c7acaa3cb940b2f2d2e7c6e9764ac76b7ac7bbcd680e8ae315",
+            "0x015eb3714d9cf90aae64133e700f8a7ed051bd3b15d639a0ca45b3b86b707709",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x25cc404a3f66e9f7e3f0673ff39fd02929397b2a3b724f66ebbbd23c86ea0a3e",
+            "0x0a63cf1f390291ced40717dcf75c4da3eb62b7ddb31e7f304112221f46f957b6",
+            "0x28a82316bc3e35a606e771b00b4421e660314907ed31b273cf4cb257b8287349",
+            "0x11d25b041bc4b40304dfae364d1c6518cb52300bc9c4dc8c09b01800a72ab5c5",
+            "0x2c5817707fb3c84b2da8d8ed73cd9d89c5a86e45ed3a6ac20c7e2af1997a0154",
+            "0x11ab828ba47268445827f8ec24401791be6ccf73aa1a632bd8ba79faf81f6090",
+            "0x02782e229af9436a6435a8382c477a08bb27e8b31ae72cbd4cd4f6052aef463a",
+            "0x0a14163985a10825b69e4aeb8e9bad326173debe98e6fd879ed484851c1f4bdf",
+            "0x0d014710cf7627e9a355a730e98f2f98cb2561651464d831039991b5616c15f9",
+            "0x268a35390128026719527f7aecff479be112a7a83ee7f222f9a073a398fffce1",
+            "0x2060a04377dd6e17225e857ce05d22f4a0a5d26047b5cec1e9e9713081025149",
+            "0x29ec3eff518487119191c849cf4d5ff2b4ba7481cae4cb0b5b8f2f6e8cc1f57d",
+            "0x1c2db95b6fd184a7a2fbf5471553b7028e06582329a9171841fb58ce4da86ff5",
+            "0x0009c804c2660a470efe35f462d4d65b8819acc1d43aab04458805bb5bb4ec25",
+            "0x2aff29f06a10fa8c0ba9c91d2353301e6c4d3b412d8e209c8e7120e82dd597e9",
+            "0x2031e8ac8dbde032266cab393cd773887c0693b5196594883a33052f78bcc095",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x16d6ea3959f89671d09c530e4461a41ea49fb03c7c227914d690a1e69c4606df",
+            "0x095f0e014d894cba2805e3c6b742c84e7a3e2129f4f8b0aa131f7c702a1c3b1d",
+            "0x07a32f1079bf18c4d4145ba66cff3488e120d484849693864838be58583a9bda",
+            "0x0e49876dca2df253755aae7488fc256bb47a1f95dfd438413128428a9d827ab9",
+            "0x17c1fdda30f984c26247ba51ec815df0a0d5e82792ae9efdaeec4e52683145b0",
+            "0x181065bdc408bc9d8b8f6be6df522a218365d6302906aef346dff751cf79b2d6",
+            "0x23779844a92729bd6ab584bf59829575cb98ca00be1c85e9ba6135c615c7902a",
+            "0x02f4465201353a89f0cf23fe99163c67f9fac96a0ad785abc4f8959efff7f6bf",
+            "0x217853092a3c8494ebe4cfec37b5367a9828c61e72d8b8b0e6edc2e7473640f0",
+            "0x193602fe63dafc0fec53ca58157bd9a3eaae4e4db15cda06e8d9a835e37dc11d",
+            "0x30252526bc13e6524fd473f97c239035a6cdb800152ef4a75f89a71622516043",
+            "0x0d75f262f07708e32fce5c530aca76a67075b80cb3275372bf59a1d960b6ac50",
+            "0x1cf83c1f34650310ba295ef38b6f583ebbe758d1060ba116e5b6464b28df8506",
+            "0x2836c0e72981e4ecc8d67b892940a36f406a801d78658bcc300d5489bb804f63",
+            "0x09e44667bfb8a72fec9385d072b1d164f06a260eb9cdee1b611d897171840fb4",
+            "0x2db08dccb8e4afcf03dd12f599d3a4fa5761e4a868206b13e26f79b7126ec684",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x0027ac9ca2fafcb935d05415efc2a948c9c06b5760d17a36a29325fbaab57a34",
+            "0x1cd600833e5dce58cb6144d7974819e9de91429f09f7e103dd7fc77bde199fed",
+            "0x0d3cdd9b7d6188dde7a61cba638ff4e5ea3b74b8218e6c893081796bf1ec6efb",
+            "0x224d149b0446d9af818d3aaa097cdbba0b02b391f4f15733cacc15a5386fa1e0",
+            "0x086a6ba9b6eedcbab512e5e14059092d4dbe321b54417b4936b0e6b5ef79c66e",
+            "0x2639d9eb2e23efcaf49998b5d6901032f1ba1fc327d38883e232349d05f5b5fa",
+            "0x1209b888f8d38c96ae10d223579d063e7ecb537f4f399e671e85a07d73da0117",
+            "0x076108a8d7403038e9cca22ec44a2a820da279b47965c12f0f91433beaef7a6a",
+            "0x0d273a87ddaf8aaa7fc0ad05bcbbbbcdee314f1b0b01aba213c716eca0193446",
+            "0x021f0cc992adcc879ddc8df210c1960f94319f542741dd585892b34d5f60d2b8",
+            "0x2ce352bccb3addfe3c3a6e80ac4fd9bf24f47b71fe47910115993281b1cd04b3",
+            "0x18ffb03c74072be8507467044d43d5f32bc6956853bf6289eb713e3aaf2d2b97",
+            "0x05cff0af2e8988ea37878451a09017b8caffbcd3be5cf2a8e9a28e2c0787d3af",
+            "0x0d4f607df818816273f955efbc6c4b378ed517ac0159d836c2d832478a98fa35",
+            "0x16c916e03db3485ff2c5cffc9e5d20bb7a319211ceba8c0e53ce06bb186c9d83",
+            "0x24ac386ccdc9382b5bebdbbf7150829e1d9d878d07320677bc74db13c3825c60",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x1ceea52255a20f8985202fec6e991a6e410bf45b69996778d40c3c6fb4d8a32e",
+            "0x181543db69123841fab76c5b1dd0d2a0d38ad5c8637b3c86aa3fda2b2bb3a428",
+            "0x081660ccec6f58772413e8a90ea325ae7e0228796ab67ec82a8f5f60d1806699",
+            "0x24308e600e67429185bb4902b0b0b53e927569eddf3100093056edc2e02ee5d6",
+            "0x0571bdc2cb97cf7e2e7d52ab0d981ea016b912ce63b3a5037b1408acc4b4fb95",
+            "0x1b0b758dcb2d0cba6db2e78922c1054daaf190b574474aafa5781d2d612a0d2a",
+            "0x1e0927d1dc49ddfd39038453cc36d970fc23c80d0eb32b2457b7def440c3b8ac",
+            "0x1b59ae41084b40dbe99a9bfb7f2b93a95e4d404a9a02da4556620ca4b50ca9bf",
+            "0x1644e0f387fb14689a521588a0acccdf5485541f56e7dfbd67012fabcbeb8b45",
+            "0x0fdebe710158120c6041a52bcdb20a3957c34cc96d093a7ec267d3b61d593e32",
+            "0x0f30bb79a1b84dc8b633f41ce1a77326293e40cd0945b5ed4b6467f33e2bcede",
+            "0x077e533f74f63373aadddcdadbac6b3972849fb7b576d1415b6912f1c6abad07",
+            "0x139498d539bd415d744e1783ab32308d8e3246cfd4e8fd782a196d6fc7c78e82",
+            "0x291a9fb3c18933f32071353713d7635ce03811f89bdf560a325419c910d55556",
+            "0x180ca583b032fd1075b1e712d3814b85beb560dab7cec0994c6d48f78d78becc",
+            "0x0247af3d0596439dcd5c6cb10dbf889d22c402f7b320b5b4fdcb19d240a4e91a",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x09d0c53eaef1a91feb1e83e5d5d48940a18af66d17f9fc14c8750222eb660000",
+            "0x1d726ec981d7314d98b76c4bee2ed80532560074f0ea4940d2fa305e08c57460",
+            "0x2847c94881ca15ec41da3f3d04412ded90a413fae133b54266a56c750d90fff6",
+            "0x2a04b9419aa5a7eaa87867447153960ec25f3096ef46a2af958472e02860408f",
+            "0x2eead5444a491d17ed9c6343ce7906a8302b86f4d0bf052a717bafb81275b113",
+            "0x054a54cf1f8bf87e301aa6512326b7ca7ef05ed9a8f6477b889fed4016640024",
+            "0x1ecb85a672519ef4b9ad718247015e9185c1836007e2b5e98d956d5e0fa06752",
+            "0x11ba34287f0ebb340cda6d03947fab28a1ba01bcbef578a890c3c4c25f782073",
+            "0x1a02292a79fe6e43b21b6dd2e63bdbd381791b5b06c0ea2cadee5715104a05c0",
+            "0x21369c5ba9d6634771023b7e38cefbe3c166f7cdfc17c0001d27e4fb7411a541",
+            "0x05e4628cce24548f3b746a07170e3a3a1702ed294473104f12f375f2d3a3f1a4",
+            "0x29a9653519e9958ac9c2c77344d8cf2a3b018c9adfec4e5bdf079446d38d8384",
+            "0x301a1b43af6d6ff2e08dcf4c1bb36768c05acbad4e2e87405c92caf1bc2edbda",
+            "0x1d50d550daef709ccc2d40a4090e4d56db54b05ec45e8f5e8e8e6128920e1e0c",
+            "0x0d33b5af6ab866b6483c6a4f3d2d85731fe982506054b138bb13538cf6d62458",
+            "0x216cd8723374d81a4f6ed312c0394ebe6ffe5a6c6c7873d59b946b3bfcb7f175",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x00000000000002a719b0060376cb18250af60b0924b662f45eb310b6a1948000",
+            "0x0d32a1080ededf0c2d7257783ea2f192405802934d578f416476cda07969e2e9",
+            "0x046635ad5a4ae20c547db6de62e224127b9a1a3f95e9d68f078866b6fb370ba3",
+            "0x0979aeb073b261037b4f8644bfe79a98180cd98d87ece07cb35ceec76f13b424",
+            "0x0b2ad706d183b3ef4132e244697edfceff26e19ebf193d658bc26bdfc78f6c4f",
+            "0x218085147482fbf3ddfa4aa29c837437942cdcbc1abaefccbb8d669517d384ed",
+            "0x129cbbb607d8e222feb0ac5af3e74b38416fe8c94f5690bd21e1ed228f78c9d9",
+            "0x1b26e7ac2f954c4823e60241c50d9a3618b2dddca011047e0035ecdfb164b4ac",
+            "0x2d948c7fd4ea95fa7035ff65964e5021d09ec916c92f61da56bd7ba052a012e2",
+            "0x2593f7a18f3355fa4bf9f279831cfd7e6b47c0aead2e73c7a1a8e8d6dc4a97f8",
+            "0x2aa41195f7d58b41c4a0cf831612fdd4737dfad8042444f5770471fee11cce4d",
+            "0x149e6df90b544048ac92846c2f79d421e50f7b713653790f4bfc5d48c434f9d8",
+            "0x273a610b477718acedf37508de68202fc28d3ae8e5ed16e7511017652f216d4d",
+            "0x2c69fa84a3a88248b9fb567b215e6fc0a8f151efa978dde3ef441fb2a9c54088",
+            "0x265479577597c08c0a898c8b2e2ebda6fd35ed5d9760d79b79cb1fb54c4219d8",
+            "0x18bcc30f3795b84643142b8c4c62ec6a35f2d4023599d7b30db3909ad10f20fb",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x00000000000000000000000000b7888c17437a76e2915c75987c6bd0aba65800",
+            "0x2797e3182c9cf767f3e49620ed76b4b8e5491a1ce29e5e6fb8ddcb9bac8938bb",
+            "0x2797e3182c9cf767f3e496211be1ffb587405cd2cf7c6382d9f8fbb06fbc4cbb",
+            "0x1b692710bc6cab47f79e408c9763fb4611afa13c6f3f5df062110bb6317c111f",
+            "0x129cbbb607d80286333290f7fdf7a8244409e82a7ffb7998a1b052e6431f51d9",
+            "0x2539776c0fb0050c666521ee5aea755bacf89cfe940284df2aa1cfd8206d23b2",
+            "0x2cab5c1b46066c7547ac8f1edb5c812d2e965b9c60b05caac3e5e984ecef423c",
+            "0x1f4ff68691b6b2edd19d5a48de4316b88d34598af7839962dde81136ac136a5b",
+            "0x2dd664432f6b047bffcc18b69b32dc87bf69e26adef1b89bc08b8c5ed5b26192",
+            "0x15731d4e5c33612c2405607536349554cd1a626ddf75c108c0b257d8854dad6b",
+            "0x14fe72a2f35f88aba6c6bc7e95d8414c36b69af3da266b3f0a5ebcb2f34a582e",
+            "0x041e81025437192b4a4b55c27f892832609591e193edf7ef971c25d49d94ed30",
+            "0x04803835451611762e3bac10325c2bc42fc159aa9b58cf0cbe26c1aa200c428a",
+            "0x2d3844dd5b4f253ed9791e363c3377af33651b509c7570478e7619c7bb158342",
+            "0x23819973d07a63ba42824629c9019e4c47c0909cb5cd22db9905e5ac1787d927",
+            "0x0a615af0ba1360ea8e385549e8ece8ac06b9f51aa2eab6b8a94d6ab83e7d76ae",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x00000000000000000000000000000000000000319a15c360ff89a229a6480200",
+            "0x2331ad6ad252a30711aebe562fa3e32c7a5449a506ab2589bddbee20381e6e18",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a3044cc63e841e1712a7f0c18c08c",
+            "0x13f74261861643df1656d35d33a45bf44f3e7d0234dddf0e9dbf8677a2463915",
+            "0x1655ae0da303363aa3d6478f4f76c65261532ff49d693f6075e08c4cf91d729e",
+            "0x1dc792bcd9599da3851db4bf149e5dc32c6e940b2cfa28581ec4f786f7235128",
+            "0x218085147484d157f5c16b56f732297b91fc9d73a6a656629607d5b30441c9ed",
+            "0x1344e67a32490ba60ad6fc7ba3ee006f3ade36771455ffa84307e0327731e898",
+            "0x004395c7a49d0141a086a5998beb37141ffdeaf4a2f6b69299154c88a460fffb",
+            "0x217c6f61e3345013847ec0ba152cb5e285ae4957316cadea0ff7031768fc6318",
+            "0x2737aed2c1cd888432ec589e77b243df1f0a7fca127f2d6ea1b622548a13afca",
+            "0x049163293f0769e9736dbe6049504befc58f2aa9de9dc375b56c044ce4a33668",
+            "0x2f4a69e3c47c16ca30aefc4803c21adc59c62a4a828e2397358820d78e4a4cc7",
+            "0x1bfb59585f46e524cfc594e484d305a08a5de3d9c07db22209532795059c7227",
+            "0x195b378297157928a95a92bc6945170ddbe5aacc1f326a4e32177259617dad52",
+            "0x0baa97b8636b8c9fbe9b0efe864238c0dd9664bc78945269ea15fee00c761009",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x000000000000000000000000000000000000000000000000000d6e9ef2e61560",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef00d12d9db377bfcc",
+            "0x1ecb77bd78084ea62f78e68b69af66c6eb09c25caa8d47a24545163803a99405",
+            "0x14fb276224c4f4e1c0b2052988b564f80dd9a35e148d447f66df8aa95faae8a2",
+            "0x218085147484d157f5c16b56f732297b91fc8d1e7ba7c415d721ad6ced0fe40d",
+            "0x129cbbb607d80286333290f76ce2fa99fbc531f47d96179a4bec35106b421219",
+            "0x1a0ea0653e2e69ef1479fe27320a920ac6e0df897c9eedd815456eb2743e6047",
+            "0x02689ccf46492174c15adf8f747dc00de75bc737bbec4550344dd476eab89647",
+            "0x24900dc2e3090cec525ec89545676a96079eaf80b6fde345ef05bf3c93333334",
+            "0x019c48f2364fe86556b4328d999bf8d7a17184ab107d877d2c11827172a22c96",
+            "0x2a79bdb41dc348efc94557a80e1810c8b3009f2d348ec8db1bb915281138ea87",
+            "0x1a513afb93fcd7799d0c938cc8a3d44a3845c6fdb5d9d8c296de312d77c61d9c",
+            "0x2280c4a806c373d29ee92e518ff976966e0d104a2a55838cdab00dd6774cb49d",
+            "0x0a8a913daab4af407755741fcfe6327b327c2ce277d1dc9d91312f9bb2a6b139",
+            "0x2b74fa63864fb20bca26ea74b469b28131c6372055aad58cdcbc6903418ee10d",
+            "0x10b091f3f23d07ecededa4ed385756365ae1c5af5052c81919f45cb762d3aec8",
+            "0x2b03d3f456650025159cafbeac013219ead8ce794fc1479d91ac688380000001",
+            "0x26b6a528b427b35493736af8679aad17535cb9d394945a0dcfe7f7a98cccccdc",
+            "0x2f83108368bc5c1657df1a8df383a5e271f1ccc97c2a6e85509caff2fe41298e",
+            "0x2331ad6ad252a30711aebe562fa3e32c7a544bd79e5851de02d2e125c5d17580",
+            "0x24e2d5c1302074b62d9c115568159cdd62ac0a90c108dfa6391d04965ba2e671",
+            "0x129cbbb607d80286333290f76ce2fa99fbc531f47d96179a552f85d6700002e1",
+            "0x06b34dd0e41a9044cac048aafe3d3b7f1d32829fb254c0d128e825f109999765",
+            "0x11de24d7b59c2b621cab6c72a5f89ea84ddc06ff30e2022dc26b0fd819999a8c",
+            "0x25fda950ebde8fb18ca4f4b3c4a826acc12797a9429a45428cbfdf7dfcb4b489",
+            "0x21dfd0839da2bcea0104fd995aa7577468f122992201cecc15eaf8b45b333334",
+            "0x1198d6b56929518388d75f2b17d1f1963d2a25ebcf2c28ef01697092e2e8ba2f",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+        ],
+    },
+    LoadConstants {
+        arity: 11,
+        full_rounds_half: 4,
+        partial_rounds: 57,
+        round_constants: &[
+            "0x1dea192bf2c37b6e94c156da653d1faf6e461545cc154e38ed92d79e2dcbcaba",
+            "0x1ff4bb0af82ae66f4d535f1a0a1a9e3f2afc579545dc840f60f33d0470b436b2",
+            "0x0c708f9ae2f2ccee7fd34f2dc657762b85d717b6525e0b5c653b1fccd120455d",
+            "0x25f5e0ddef591486865a3e1a6087e90644e4cdd37c48fd18ac3bceb52a33dbb9",
+            "0x2862fd91a31460f0fb411e34a6abe4e1cdf08b90efa2043392b889e6d9a4ee14",
+            "0x122df58fef77650437cf8552136dd677fde6e9cf0c1d0916fb91217754f8e3f3",
+            "0x18c13b0279a1f78879d93379f9638acc4cadd251bb738de3dc77f9e137aefb8a",
+            "0x2b299b0d81ed3ae814c9e50b981a8a37560bba18b0c7c982fc95ca0a7d430933",
+            "0x043525bc7cef55159956217569e33348fbd44efc94d80720da1af7a320a728dd",
+            "0x12105c0375c1c404635d63900a57d937f62f2941baef305133740579701c3f6c",
+            "0x1062aad9a7aa010e29a8b88b9af11b1723e1ca9b41ec9c18859e68ce515bf1ea",
+            "0x2189b4933b08550e21575ec9890392a2fc6ca01fce5274719995de22e2e53439",
+            "0x26f6f66f88b44877d02648592f37fbf1a8d2ffd2bfd02d8e1b901f3e47e5847d",
+            "0x2510c8b047ffbda6de795a8de944fbfcf21957b97d34ee181ce78175afd8b0b8",
+            "0x16b1c39acc2379f401557ccedf6f18d9ae573895b4a0707ca48f27e5ecc9a793",
+            "0x1f27c6b14e8a20df304ca652ff04363705c551676028b33e59127627a7efa3ab",
+            "0x269e02d8c1537646181e37ba15ae59255653edc4579ff5b6e0de481ffd4696ec",
+            "0x2dadf8fde6ad19320f8042ff1a11497ee4a4189138d89bb029fdc0537fc99e45",
+            "0x27e99a70261584b49c2194e441df09a24069155b3f916cdf293900c57bbd7a94",
+            "0x1ea94cfdcc359d8f5e56512d0b0b3b345a063ff05dbf501665f8ad76d3ed893e",
+            "0x183457258cbcd8b292ece2c6ea8d82b21f3459902452b7df63675e4c02ddcbc3",
+            "0x015ff4b505ef6fdd3fa7fe811c82a1bc7e93559483337029ed21d868f68e2a89",
+            "0x0fb0d36ef52c1d3c6e2e04762afd7680e315e3716d521264b085799a1771a496",
+            "0x24b5605a533990e8e27f9dfcc0f1b6c295c76ca80ee122e42402a3e69a0ed3be",
+            "0x2b39f3bb0936adb1f4e13292e199ad6684f0af91fe4d1c03ec929d631ece5ed2",
+            "0x0e74617952aaa98df55e1d960cc382f45794a126f21e10c9efc8da4ea6c98ee0",
+            "0x0bb1c5b1552debeb974265321b07186888b6b8923eb2e681a60fabd8da2a0a8e",
+            "0x24339795620563a13e76018e98f3846e67abb8b800e73666453ac2a7deea0d29",
+            "0x032510e05a84e7ebdd52b628fa94e5f6deabe787d465c790655cd2c3d7b39400",
+            "0x043366e561c92595c7fdae5c7a7075beda6bf8cc1e1d1a84275f0f41f32cefdc",
+            "0x02f5e615e7702cee047ead33c9126abf5c783b1c6278e05618c553c7cd090641",
+            "0x158dd54e678175689d95445f6e7cf7d76973bb5341512bffb4f79ec9ffd46244",
+            "0x1bbf7a01095c2269770c22a7a60182177e89d96893f3875879b3b1960639c9a6",
+            "0x110e7439c9d38c18895867e63988fc1b0dec749537ee139e193dbafcab684633",
+            "0x0e2c0ba3358bc5bcfe62dc0eba022ca7f6d441e2c6202d16de1553c77d732e96",
+            "0x0b4424314a3b291328ebf35aeae9622eafc01d5900698ce1e4e8fb9001a80ff5",
+            "0x12d5f49bb6b348e81ce0923503b9f5701bee5b9897eca6b1c884250ded58a906",
+            "0x050151363aaa47278dda48f0324545b44cafb3641966edc9871d960864372455",
+            "0x0754791e4c616551fc5f66d6e01f31293c8cad0c494c69723236dea7250364fc",
+            "0x2a9ddb641c3fc6d5f68c05b18223492499726344149cbc8c72a9293a83562923",
+            "0x0fc71a19c8d4da6233b4eb3ba6406a128855e1bd87a70900abac72af02de737e",
+            "0x18b41255dbc0e7d72e2fa1e36cf252dc81a032ebb2c0e1e45c87ad366ae9efdd",
+            "0x0674033e8aa168d4b0504b9c4df894c54c80dc6e6c4430a48003287db036cd20",
+            "0x17e219f13702f748b7c08f102c5378bd2018e9a77ee927e1c4dd32173ad4d55f",
+            "0x1e8239f09dc709c4505d96beb49a4ad1c7a080b0d310277a63edce0489563b00",
+            "0x17038bf5a3f3ce9b2209411ae844e12f5530420af7f6aed73f263577628af7d7",
+            "0x1caf01c6d9ebd73bd9a6ac214b2c1670c689cb3c1c753403a234feffd003648d",
+            "0x15be76aabd0eee6e3f784b61c9da568dbb491f0143d00f5c8210e071622ef95a",
+            "0x219ae54f3b83ff32e30fa5b8d5d55c75d91eecdc22d3dbed6ef56e1d1885baa3",
+            "0x1cd1a2e376ddd90237e609974bf8ae19a18f7d5db66f56803177e1c17f0d100e",
+            "0x28c82d3d6b2a33feaeacaf6c178f808736e625f885b870daffea943a2af23a36",
+            "0x0d14e8f78792da2b1fda0efdaeb5e1eedc79848ec3b56cafcc17e6bfbdc7fa37",
+            "0x22730f02b8ef8ac6ac07a06163c20c2327fac40d04a46ffdd9ab3716a93490fd",
+            "0x0c90f5de4103475484fedb0dcb501bcb35a1ce94b688c1fe088a66090fe05aed",
+            "0x0a9ac948b69eb4327ad26475fdf25912d07dda8ea73911c8251feaeafdda4bad",
+            "0x2e72f7d63c7f4111b0541a718601cb6f343298b5b622a7507efe4e328dcbcb71",
+            "0x16b602e37ffbb3c4bdea7af2cbd7ce689eda7ca1e733543ca04ea1159d6d25a9",
+            "0x1d704449eb94ed3c2b9a8eed646d50b60b1a0c1661033dbbbabfb2fb9db82312",
+            "0x1da6f3dbcd217c1fb083a37f614aca0307532f74fece7d6997a3513c2f373fda",
+            "0x20782b08b83c7d207143940ff1a6fc359d772b917bc22975f1cc9aef2cf558eb",
+            "0x2354b3e12839610680efa72736f99655de446c0acdf817fc1dc5f64483ba5a20",
+            "0x0d9f69227ec350bdccbef4dba76d736803b1b54b932e051c6c927ab0c39f680f",
+            "0x28ba1c653d6023b03940372c18ace681342589d78b1297880ec50328b3b8040c",
+            "0x1064c8aa2488116e6a6e151dbc55230ca630612c9b02f2d5361b194f3aa77ecb",
+            "0x07b41f3120a078ff56052da760d4382df6101103e3347ac61dc2195ba675c548",
+            "0x008b52a043e416f188d99e6ae5487d9bc9cab67ac3e0526eb4dbd8b067c0f848",
+            "0x25adbd468aec24dd3511a183255ae6a470ed0c743407915aa2e792188e7328f6",
+            "0x106aedd640105f5d2bbb25d0acb154138e29a49b009519c0676774afb171bf8f",
+            "0x0b1441f0b0b4b5e87cb7f3b44c74a0df1e588a097bcdb4abf90f7eb8c30aec8d",
+            "0x1a7ed1c0404523b928fb9f2b62e8db16e75bd8e47999e3fbf5c23877e65a1261",
+            "0x167ab5d83c3463a8d84466278d39cf1c100e3496bce867b4273ccdf32eb36e42",
+            "0x1e6021ada5617597e9e1f8bc1cc81d9ed07554c9e3266a921cbbddd5aceb40a3",
+            "0x196a8ff9a71d42e3267a04aff54d2d66799c61265054b98f472c16523cc6b85e",
+            "0x1e27ef2a280995c027424558dfa6bf14a84aaf34c508fb12e5f8670f85f60a9d",
+            "0x29f38add5eb9d66564277350e0e930667e4ac9967de54f2c7e194a40b9e1bba8",
+            "0x0c50260073d2498627365c8b4b5de83fd61ba0f580cef7685398b1218946f4ac",
+            "0x03cece607c598956696b7ba72f8f66f3e8ad2c50fb4c13f3dd80e8de8980a593",
+            "0x1bd2889a3c1f95d9177be63aa0d05ba6a481ee01314553785a9855e4728e545f",
+            "0x246b534f41c063222fe9ea875f58d50e19b5ee4fbbfe1728be44a0e675775848",
+            "0x2d285af7b6f4f83746689704d2ca7e46bbc6c4d48dc70b4781bb1d82553de753",
+            "0x20e528be7c62644343cde4a3155cefe77f1badc466fed6165d5cdf1e57df3185",
+            "0x0c780bada5b0170c75fa78df9c4d7c59dac1b5807a624e4bfc13f89eca8a6c45",
+            "0x299e5d5e8b07e6bd73690b8e7fa789db98cd963084c6ed726031a8057420bc7f",
+            "0x171b041c6e0248317ee1c283f1e42846d23dfa8e6c37808acbdcb178be83b750",
+            "0x1826db39c29b392e6007e1f6d0d19855a6d129a3a485a658e845f0c9ac5f39e7",
+            "0x0f32bc920afddc2288600456e2bc3471c6ef00503b9c903273c20f03dfe35c8a",
+            "0x2ba85235bd7b3e1cbef9052394dbf6227489b544405bdf892ddde7606811e66a",
+            "0x03cbf6d8616e3da4327d0e8f5122ba0d565a13c11439a6a56e9ce2d8f86af9c0",
+            "0x22e06d398ad7af4a222fdace12af41ff6cbe15c200278e3b7c877e94a02a1d9d",
+            "0x2855bde54df65769dfb8d12fcd816395108f0f38652430d3022abc55a5634827",
+            "0x0841aa22ec7bc913a5ca61122429088a4c35006e05721d6d4827b045c51aa2de",
+            "0x16d57a05f00d6abb6212bdc31b4b4c65754add1f8d1ca33f8b2fb39b240c4b59",
+            "0x0a2f3a82d82c3e772b418dbd6eb85163d43bf2032fdb88d5c57ced16c07854da",
+            "0x1dde33cbfe2e2de345f8758627f0458034c23b9d825d7465f4a5c8b7a560316a",
+            "0x17f6b21625cb8b8f25e42a07471bb0d7419604c98c91514b5b2ab40b97c841ce",
+            "0x112a1e35758903cecfac3dedee670a29b677a5835dd5050a9c8cbd398f6ba763",
+            "0x1cc7be35cfe8551223bb496dc0a8003948f379919fc42198768b47aac5f2d572",
+            "0x0d61c895e529de9ea5b650d1a0f1bc03f1628c4dd0b3c5e69aeb0b31421df7de",
+            "0x00bdaf47d8f37ef45f73a3c3a282b70918eb381698959fbb04fdd924a4cf5a26",
+            "0x0d9bd8188efe8a664bc20928964e886b0ed9835298fb364b159c4424111a8d20",
+            "0x22c5dac1c62e1d5c58bd3db26460a3358c60dfc80df36e8a469afcb74e1384b7",
+            "0x073eb4a72f84742d0cf2a206a2ac62e04666370c37d56a85627ac12f1c910248",
+            "0x18d15484b46a51fc1f9b7410078556f25b04d397f23b0202f35e399611718c00",
+            "0x196c7249874ff71b727cbd21c2f322691226f23b8905e08ae9af5ca67ab076cb",
+            "0x16c7f5d60732dc593cf35575640d7d00b6f6441d512be213f1666ef4910549b7",
+            "0x07f7443c32142290fb0ed5e121215b2b10d33d7b21208561bc6e17a354df864c",
+            "0x092d96e7e24cc9f76622c0bce0db590124fb94333c94b703b87cf3164aa94655",
+            "0x23c6b5d890224fce0d1d012f732734eafdbf92e1a543b8f624f36abc36b89812",
+            "0x19224282424e89c35ed19993a380457dbacddfc540aba050e88015ccd3d6a39f",
+            "0x22203302a8637c6cc893c085522956f3e577c273ea66a4e8fb7cf790e88e2d47",
+            "0x1dc56e75b95f6e3e856959623d186287c55e07c7b81e0a8ad884a8e805b4c9de",
+            "0x1ab63eae4c08302f0c83ee43eb1055a76a5c1d006a9930c9f78644e1b58967f7",
+            "0x17f1c164a042c5540a9957fd5408bfb8942228a2ae83b6987afa3ec1b486e63e",
+            "0x293d2992dea89928860c07a9fbb4c785848746e2c6bd7a924358c4dd8d4eff06",
+            "0x1cfd28d50c0410ed1e333386791945e09d99144c04956718f628b4ceb90c138e",
+            "0x097fa45a2da80fa1337e9d19621e9cb35f901564a3ca16e0337d8e96acc1d766",
+            "0x2ba76ad4191a4b4a4a2a3bc7a5f0aa7bbc190e595548b075f5e99570d0896973",
+            "0x1ba01eb43ccf791a7dc9c6835a64b463ff33970cf05dc2f4ace26d9789e52115",
+            "0x1d6b0baa459ed17504ce34b2cc7595648a22de8d943c888c375651f9bd43f351",
+            "0x0ef91365570263c4f9a25f2c6160ec2ce486975fae920cf62c557ba92257778c",
+            "0x25b25a2834e6ab459c8182b794ecda249180236dc81e3e5d8d1ac1c468951272",
+            "0x18fde3a68daf2cf4bfee0b9f1f686390bf371c101f63c72a43706018778e1e6c",
+            "0x14d332f43e88ce178db348d278904b6845aa970cc97337deb042e4f50345b3e5",
+            "0x02001c4831b23239d247b292af95bed65d9b578eb2ec5bfe794b2d930cff481b",
+            "0x07ad7e66682b55699680f131ba866156e20058e503f13314f6ad2d4e8ceef3d9",
+            "0x0ad2db519599cd45c7a3a1b0fd800523fbc2d9e09b0d22e1e50739737d949481",
+            "0x01478b7678f0f8b88e90bf1c8bfa02e89bb21919ab4ccdff109fe65ed17d4063",
+            "0x2ae7f6743c479e916b8a6b220e210311c09a205f9a5f99b89cf6d71afe7aaa9a",
+            "0x20d651a68cab2c2ac9b097e88582f630a53c0fd514d63b74949320f648c6a33e",
+            "0x12fd01fffe0fd7d6129e4dcd978adff3b8d97cdb01375a4bb16e46bbc62d2d8b",
+            "0x0cc444a38e3416a9296ca4672b777ddd25652f19b621e53372be232b33deec14",
+            "0x14aa3c7c1195f753067b8f7b3fa5f9920ad43b294ce0ad40155da20de21cf672",
+            "0x01da6132381b3b69d542d34a91daf5c4b5a7255311ba0fe95059a8e8d35fc7d7",
+            "0x19845b3fdedcf90bd794da7309880547c1bfe8ec5a65dc2b4fe1071a21769cb4",
+            "0x16cc7a54891ab9a96732da732c32be9298bb11e5215cd09ea848ee372c1c717b",
+            "0x09adc6117657c184f89906a62f0e996a4d642add982e59a7b548014da251c15e",
+            "0x2616bb1f2f5cafea68f83a979c56acb3b5c134190902842b7b9596faae3154ac",
+            "0x1e8d12b8f4df987fce47e8fe3df4a8a2d72a32b19ad7d93b5f99267314bd396b",
+            "0x2e3d4498631a6c7d09ff650ac698308087ae13260b04c0bcadcd971188d506d7",
+            "0x20772330e378e90b3a3a77d7f9dbf95a1066a522f9e46d19048a49ce6d02b5e4",
+            "0x2bd55ec9e1f3e2b15b56f6e69bad9682aadb91d43d4ac2fd7e8ad55d50ebf0c6",
+            "0x17b104a20a3ca3dc22d8b076173aebf7ad33b945757b656c22866808acbad031",
+            "0x0236830955ce2f855390cef4c045902efeed573619ed11d7c3b79d1f7dc442ad",
+            "0x011cb66c60e65f39baa0d6577c520ee6c4743f9e2702c469d8a1d1a22afe281b",
+            "0x0f9c53b4e73918e3a76a5b4d161eee74516c23cc5d14549f44d6b07e6ff5a2dd",
+            "0x1138431d3c980c826a6a3438ca403ecdf2d17e50f6204cb97d5598cfeda501e3",
+            "0x2630f40a0747718ca54dbbde94c2d49e711ec78a4ea2bf3ff91eb454c06c545c",
+            "0x149788c85ac09ca01854e1febcb501031b8fec49958926ab803be1772acdcd2b",
+            "0x2ccdba83b79c2720bfb16add87ad758939a12d67c1047ea94beecad50f27dbd7",
+            "0x2396b4c8c8f624b5057a4599656af3a74ef4377a96f9155fbff54d3224734fc0",
+            "0x02fc326be271f5450c9e6b21b0595dd217c0d2eb80c4689e546b2aa762a9087e",
+            "0x11fea5ecfbfdbf586d9896732168dbbf60355c2f459358432be43cbfcc5c62fd",
+            "0x012dc3a7ab3f307f3265fdf3641a0ce3639792cbbc2a72fd70309d86a423e99c",
+        ],
+        mds_matrix: &[
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x1b6c0a522a46c9b13dc7166ff40d8ff085b703a0893e6a7473444f6d6e666667",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x1b6c0a522a46c9b13dc7166ff40d8ff085b703a0893e6a7473444f6d6e666667",
+            "0x063e7dbc3e16eb6038d08d1fcea555b9708ad3a6414137d0adeb9b8ef5ad6b5b",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x1b6c0a522a46c9b13dc7166ff40d8ff085b703a0893e6a7473444f6d6e666667",
+            "0x063e7dbc3e16eb6038d08d1fcea555b9708ad3a6414137d0adeb9b8ef5ad6b5b",
+            "0x2ee12bff4a2813286a8dc388cd754d9a3ef2490635eba50cb9c2e5e750800001",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x1b6c0a522a46c9b13dc7166ff40d8ff085b703a0893e6a7473444f6d6e666667",
+            "0x063e7dbc3e16eb6038d08d1fcea555b9708ad3a6414137d0adeb9b8ef5ad6b5b",
+            "0x2ee12bff4a2813286a8dc388cd754d9a3ef2490635eba50cb9c2e5e750800001",
+            "0x26207bde63d985f25327f8dd5e46e0c58485fcd440dfae05d86473e8eba2e8bb",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x2e6020836d24e427fb4ced7991714a03f131be9ac9fc6135e10de0adc6000001",
+            "0x2a95b5acc62baba9d5655c113ec3be66754c660256a32fdbfe4bf6d41ae147af",
+            "0x2e87d547139c064f7ffe6a6a90377280f56cfce339f73b01d4f6ce9818000001",
+            "0x1e780b77bd32356f99f9a7270f2b837d06599240987e3d64f1da1f5d25555556",
+            "0x19ec97bd8aecdef1c74f930f7c3c2631e7d2aa26d37ec572645d9efcf76db6dc",
+            "0x2eb91f9b0e64e142c39cf3dc59baad97bce2ab4ecdcd872b270f221340000001",
+            "0x1b6c0a522a46c9b13dc7166ff40d8ff085b703a0893e6a7473444f6d6e666667",
+            "0x063e7dbc3e16eb6038d08d1fcea555b9708ad3a6414137d0adeb9b8ef5ad6b5b",
+            "0x2ee12bff4a2813286a8dc388cd754d9a3ef2490635eba50cb9c2e5e750800001",
+            "0x26207bde63d985f25327f8dd5e46e0c58485fcd440dfae05d86473e8eba2e8bb",
+            "0x20bc532f985646b2cf8198920c4ff802c85f595e3439ae07ea27e9e4134b4b4c",
+        ],
+        non_sparse_matrix: &[
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x10551c5c866a188e0251b98f5c5399996af8bb50c3be1941826e1c2aae175dc8",
+            "0x0157a0a3c0b930738fec3b2957d07731fb41d6cf9c86707e4e0dc5bf860a79f0",
+            "0x0826dcd197c5d19cb436b84543b1ed7fc1af98e23b89e02e55038cfaa760c9b9",
+            "0x194c1c87970c7f3ec6f7550f799ec3132496d6cf820f298e8a90ee0daffa3f9e",
+            "0x0af4aee2b7a3ff4aee7ffbb1bf3d7693951309581e35a1ab11c3df8c15cae650",
+            "0x2c8adf6fd8dae8354abb19a8edcc9f1b2a86bd95356c12fc398c6eff3bd5028f",
+            "0x20f07a1691050e9ee291c00191e47bb30ff9a72aac7c1060eaa5238caf9e1db4",
+            "0x051f7d606889b760bb486e85dbcaf19f7fc3e41a939efc4bde543dc9a1896beb",
+            "0x0320a12e997c96baa6f523eb551872f759bc95757820cef0590791934dffa8fb",
+            "0x04bc453bc82e752e0cf04afe21b64c2e5e3218db67ace5dc58294c5c362f2cd7",
+            "0x20fe9da8bb464558aac883eaaf5bd8a9b7d15a0e65cce168aaafd3cb5aff8093",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x15877b60ad28ffa5f24f3f099640abed517052822b4b93eb86444ffc565a8b87",
+            "0x2ff0b2a4e3e8a19d03669b0f458c1fc21c07bfaca6eb948a8dadccd35d11ee68",
+            "0x09b7b0bb7c45db81a002f46ce34860fc7f19d815b870b976bac9706ea1748c8a",
+            "0x0acb758c62863aaa14f23bbf42770b9095959d3eac757c11b068841f1994d41f",
+            "0x0cf90d3c768d43d7740c5237ddc9f05e64f72a1e978d3eb26760a6f815d1997e",
+            "0x24d9ddef02653729e48c28556e150abd360334c5edfbadfbaf91969298da9dd4",
+            "0x115c8428b685e339d56cc6ba21ca80c52b071664a5ff229b1e704561a6a208f2",
+            "0x20f032e1fff9c029fb12a92610c3269f616cda00f9d4db517fa64767b8fec078",
+            "0x1e6173b00b296a514ef7dbd8fc646db9b003244d3ad30a8c851bc7591307ce4c",
+            "0x1f0deec5a6767e1cc5dbe3cfb857dd3ad715f4fba48cf61d3cac32b081b8417a",
+            "0x246f72ea6c4e37f3c6ef10b0633fc7628fa1eac598a9beea9756bd703b047ca6",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x2ff0b2a4e3e8a19d03669b0f458c1fc21c07bfaca6eb948a8dadccd35d11ee68",
+            "0x08e280d68ad45641025880f7d9e25810b9af3cf2180837552cbdc822cd124b0c",
+            "0x24be8f1cfe56c06004ebe2dc926817a17b02deb1cd53b22a7058e2f83781daa0",
+            "0x0e56c622ca51bc6f5d3ab1bb10d94246a4649ec7cb455f90767b03612d3726ec",
+            "0x1b5c97107f1a924ca0ba943f0d7739f759965d2d0369b2db05fa200628a33640",
+            "0x2aac92e316cd10c31909f1321533d58b19a6ea6c6b779f39677eb0e624029a63",
+            "0x0cc88d2d66b203438f926c5011515b92e0c37da3cfaaf19c31d0798eed1c8b40",
+            "0x15a60ea468ede81fdb6a8520baa1e938b1cce8c73f284ecae8a5f6a8cf90ed04",
+            "0x1842b29ed1b903897d19f46fc05b2be6f8e30b983c500b523b433143d73b735c",
+            "0x036db5ff31b75498479449414a306d96e843cb8d7873c6eda08a2d61ea1fa358",
+            "0x2401e917744816f77da78d5e9adc7be0423bd654f84b841889110954e8aa01ae",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x09b7b0bb7c45db81a002f46ce34860fc7f19d815b870b976bac9706ea1748c8a",
+            "0x24be8f1cfe56c06004ebe2dc926817a17b02deb1cd53b22a7058e2f83781daa0",
+            "0x15fc6f311b82b35f4fc5843ac196dc15e4f9b433268c2db02a4d72269db3d09d",
+            "0x024dbaad9c65735f671d226e73dab8d3853b2396b852a2989a3667249bb7eb71",
+            "0x17ac0ebce9179a96f2d28a6075307d226e1305a0dc2d22fc8b8a79ea7e7f90b5",
+            "0x117619fe8d42259cf18ee96b99d4cd3c72d87141af62a1f21627cbd9db85faa9",
+            "0x0ed307ee0b8e0fdf8900cf699d2aa88f06db765a52784f2693bb5d4ef289fa66",
+            "0x1f0c50bfe599747f3e148d1adb29695dccdedb9e71ff26e67096f0a2c439fc48",
+            "0x0b6902310fd3a1540d9d95fb0889f4ff6227384d59c093593ae0bb923034fa45",
+            "0x135f740ae8035f76807fb840f5e54915f42a128171c0b9428f5643853601058e",
+            "0x119950bd70d1fc17efabd543a1785e86f15919ce9420f5061495c8daed35f227",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x0acb758c62863aaa14f23bbf42770b9095959d3eac757c11b068841f1994d41f",
+            "0x0e56c622ca51bc6f5d3ab1bb10d94246a4649ec7cb455f90767b03612d3726ec",
+            "0x024dbaad9c65735f671d226e73dab8d3853b2396b852a2989a3667249bb7eb71",
+            "0x29cae09908e2e0e38a6149f46d7653d8b90b1a261c212a01f1325cda6710a63f",
+            "0x1236ad82ba634cc805193a7c05fe34b1e5769eeef6bcc94db86cf8264f26e5a9",
+            "0x0c59cbb74d523911e971f1280feb451f53cb93bcd00dfb731b7796ad311719ab",
+            "0x018f50ccd13e3f33a58727250ff064b9f4e37a526b98b3f449dbd7e15e113f5b",
+            "0x26e73a759698f78c835ccdf1b137cd5494e1ae81bb141d8cb8cc82ac3904c58f",
+            "0x21e7368df0ad7b90c36240671e5d25ace45041a47e8e2d567325eaf7fac81804",
+            "0x1c6d5d213e876009361ddbadbc8384f254182b9b6010c05b10839037704479df",
+            "0x213f97990177785af98e530bb12f033e37e0c76eb8a03055024c7062065ddf9f",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x0cf90d3c768d43d7740c5237ddc9f05e64f72a1e978d3eb26760a6f815d1997e",
+            "0x1b5c97107f1a924ca0ba943f0d7739f759965d2d0369b2db05fa200628a33640",
+            "0x17ac0ebce9179a96f2d28a6075307d226e1305a0dc2d22fc8b8a79ea7e7f90b5",
+            "0x1236ad82ba634cc805193a7c05fe34b1e5769eeef6bcc94db86cf8264f26e5a9",
+            "0x18ae9430ca3260044a622437939caa29970fd752e08ba1eeca82ba1db15e5e43",
+            "0x13678861d1420b5f92cb41785014fc4643f019a6c17ce5d8d10b98b6d1877164",
+            "0x185db99b1f0ef2d4003eeacb7436c22d173b8b6ad25634eaad09eb441d6d1979",
+            "0x08e0045c45593eae3b21fe70a683d83c8c78a9b05d7dd5b55ce1d9516a4d00e8",
+            "0x0458cd3f3f39f3eaed13fb10fcc44b697316b67486757e98456e00451840b217",
+            "0x123da574a7f5fb60cd414945b94e787abc93e64ed7a433a2e19135686ffa155c",
+            "0x0e7c13299cc5dc5ea898248f0494c033b0f124fbfa0f7598f981a76490335c2f",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x24d9ddef02653729e48c28556e150abd360334c5edfbadfbaf91969298da9dd4",
+            "0x2aac92e316cd10c31909f1321533d58b19a6ea6c6b779f39677eb0e624029a63",
+            "0x117619fe8d42259cf18ee96b99d4cd3c72d87141af62a1f21627cbd9db85faa9",
+            "0x0c59cbb74d523911e971f1280feb451f53cb93bcd00dfb731b7796ad311719ab",
+            "0x13678861d1420b5f92cb41785014fc4643f019a6c17ce5d8d10b98b6d1877164",
+            "0x13fa3395a7ab859559f02da9f4eda0a769691efe97912c3fe1fbb175fe5ce926",
+            "0x15e6c52575a939f57c44df280714e21dacc61d317d8fd1b644e9b2f03cd64022",
+            "0x2f6f503d25790b8d7ada7584ecb78b4af39d619efb00d318ec58b3b6c1f4153e",
+            "0x2edc903baa3bba6d2a86fc3866fda10637e081e4b2282d66d988c1413d5713d0",
+            "0x216ccbf7a4448ba314902ae46a629f8e59f30fdfa12ee67ba7615e9816e9ca5f",
+            "0x20f26794c5dd9e2c0523c58248dd3620bd4e9518842b434683154b15c8659b09",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x115c8428b685e339d56cc6ba21ca80c52b071664a5ff229b1e704561a6a208f2",
+            "0x0cc88d2d66b203438f926c5011515b92e0c37da3cfaaf19c31d0798eed1c8b40",
+            "0x0ed307ee0b8e0fdf8900cf699d2aa88f06db765a52784f2693bb5d4ef289fa66",
+            "0x018f50ccd13e3f33a58727250ff064b9f4e37a526b98b3f449dbd7e15e113f5b",
+            "0x185db99b1f0ef2d4003eeacb7436c22d173b8b6ad25634eaad09eb441d6d1979",
+            "0x15e6c52575a939f57c44df280714e21dacc61d317d8fd1b644e9b2f03cd64022",
+            "0x1f5a3ccdd62e363dd2fe8d3ed6d47f94b0d01dadf9a7ede4f7ec6394a6bb6d8c",
+            "0x08d3cfeb8628a46d7fed5a8fa081664948f8ab0d1c8a0ebfc76980f58fffdd93",
+            "0x0a8651019825945d21a6a9bebcd36939b8a2eab0f3beec8c2b9015d7408df08b",
+            "0x098bf54e999e06eb609d471888e3191f7f75fc069d5b3bb65473662eb861f894",
+            "0x0b82212f1f88076153b1db3dab23997e3a9450e30b3151bb1bdbc32a60c68fbb",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x20f032e1fff9c029fb12a92610c3269f616cda00f9d4db517fa64767b8fec078",
+            "0x15a60ea468ede81fdb6a8520baa1e938b1cce8c73f284ecae8a5f6a8cf90ed04",
+            "0x1f0c50bfe599747f3e148d1adb29695dccdedb9e71ff26e67096f0a2c439fc48",
+            "0x26e73a759698f78c835ccdf1b137cd5494e1ae81bb141d8cb8cc82ac3904c58f",
+            "0x08e0045c45593eae3b21fe70a683d83c8c78a9b05d7dd5b55ce1d9516a4d00e8",
+            "0x2f6f503d25790b8d7ada7584ecb78b4af39d619efb00d318ec58b3b6c1f4153e",
+            "0x08d3cfeb8628a46d7fed5a8fa081664948f8ab0d1c8a0ebfc76980f58fffdd93",
+            "0x2b6a3ddc3efeb1044e0339accebc1122edeb64e3694a5324c71c8a25181ff748",
+            "0x18fadfa7d5d58e5a524cac5d5f5e559acd094f42f994e79fb31de3d7e34d7579",
+            "0x07c3f5c827ff1318566c0f9cc535b19539202f0e2f179ed9947bd287c6a11eaa",
+            "0x0038337d89ffd21836f55f1d97d4aeff4a0e2b5966715fc22fa4b0dd2405582e",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x1e6173b00b296a514ef7dbd8fc646db9b003244d3ad30a8c851bc7591307ce4c",
+            "0x1842b29ed1b903897d19f46fc05b2be6f8e30b983c500b523b433143d73b735c",
+            "0x0b6902310fd3a1540d9d95fb0889f4ff6227384d59c093593ae0bb923034fa45",
+            "0x21e7368df0ad7b90c36240671e5d25ace45041a47e8e2d567325eaf7fac81804",
+            "0x0458cd3f3f39f3eaed13fb10fcc44b697316b67486757e98456e00451840b217",
+            "0x2edc903baa3bba6d2a86fc3866fda10637e081e4b2282d66d988c1413d5713d0",
+            "0x0a8651019825945d21a6a9bebcd36939b8a2eab0f3beec8c2b9015d7408df08b",
+            "0x18fadfa7d5d58e5a524cac5d5f5e559acd094f42f994e79fb31de3d7e34d7579",
+            "0x1fae21434d399ca35d1f5eb81f895889c01f20d83a36376e4453279afe56097a",
+            "0x1d13fa4a404aa3e0c8012d851a5d8021942a22cc9ed55197c297086dff2e4a0a",
+            "0x2a8d8b143ab856584af8f33e879c57048a527468d97b3136e21bdbbd521f0f33",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x1f0deec5a6767e1cc5dbe3cfb857dd3ad715f4fba48cf61d3cac32b081b8417a",
+            "0x036db5ff31b75498479449414a306d96e843cb8d7873c6eda08a2d61ea1fa358",
+            "0x135f740ae8035f76807fb840f5e54915f42a128171c0b9428f5643853601058e",
+            "0x1c6d5d213e876009361ddbadbc8384f254182b9b6010c05b10839037704479df",
+            "0x123da574a7f5fb60cd414945b94e787abc93e64ed7a433a2e19135686ffa155c",
+            "0x216ccbf7a4448ba314902ae46a629f8e59f30fdfa12ee67ba7615e9816e9ca5f",
+            "0x098bf54e999e06eb609d471888e3191f7f75fc069d5b3bb65473662eb861f894",
+            "0x07c3f5c827ff1318566c0f9cc535b19539202f0e2f179ed9947bd287c6a11eaa",
+            "0x1d13fa4a404aa3e0c8012d851a5d8021942a22cc9ed55197c297086dff2e4a0a",
+            "0x05eb41133ac6ea407003746a3081a8b257eb7b73a6c69eea5d0f7447ac141f34",
+            "0x1626deaeb54350eff5dad6903900d3dad06f84f68062b4df7ed72b2793ccd0e6",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+            "0x246f72ea6c4e37f3c6ef10b0633fc7628fa1eac598a9beea9756bd703b047ca6",
+            "0x2401e917744816f77da78d5e9adc7be0423bd654f84b841889110954e8aa01ae",
+            "0x119950bd70d1fc17efabd543a1785e86f15919ce9420f5061495c8daed35f227",
+            "0x213f97990177785af98e530bb12f033e37e0c76eb8a03055024c7062065ddf9f",
+            "0x0e7c13299cc5dc5ea898248f0494c033b0f124fbfa0f7598f981a76490335c2f",
+            "0x20f26794c5dd9e2c0523c58248dd3620bd4e9518842b434683154b15c8659b09",
+            "0x0b82212f1f88076153b1db3dab23997e3a9450e30b3151bb1bdbc32a60c68fbb",
+            "0x0038337d89ffd21836f55f1d97d4aeff4a0e2b5966715fc22fa4b0dd2405582e",
+            "0x2a8d8b143ab856584af8f33e879c57048a527468d97b3136e21bdbbd521f0f33",
+            "0x1626deaeb54350eff5dad6903900d3dad06f84f68062b4df7ed72b2793ccd0e6",
+            "0x21f7ab6d988e7650bb15dfe6c4571c6755ecd4386ca23c25d6bba7d4b5dea928",
+        ],
+        sparse_matrices: &[
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x20663e8fd8fb9bb8f4870398ea9a1d7bb3ce2fb1acb895bab371aeb090bac120",
+            "0x09098022fd7e97f337a56983741254d804ca9b323c968cc1534289f4a4b22ff3",
+            "0x08132918c9864d3e469ce1b1f81a9224b0934ece7165dd28d2cce1cac1d3c495",
+            "0x04eb4e4f09c49c6e9cb54242fbf9e5da5f27a474b8288685033e4b276c1f50ab",
+            "0x0c7ad5f26d70f4931edcfcc4925f48f833eb34470dd9169b0b23354b4b95029a",
+            "0x155197bce4cff2a0fbb02a29bbd8a8a10a00ec5173f3aa2e50aa012fc7e0fdeb",
+            "0x0f081156a2283ecab9e22642e4fe7ac612c759b8c83023f796865fa66cbea0ea",
+            "0x1d0a4eb61c1ddfbb437a8e7d8201d0dd4d70779cbebc38dc6f5a0dbf5f4c49ba",
+            "0x03bf23f1f88c58750f8906edf66f6d011029cfa2c4f97019aec206b88d2c4435",
+            "0x0518a5af9be96c3e193b1761bca4a537c2af2e30d38200d28143493ade0be4f1",
+            "0x07b0c6878c1d51d79d458fdc45bb49d9e765e502e4f84f1854b3ea7e77b1fe65",
+            "0x1b80f6e10de3e48dbcc287db4b8a3a2182af3aee3490995be3f7e9e8359bc9c3",
+            "0x0731f716af79ebe73c8f9a1cb51d56da0f76cd3befdc09cd976ed3faf41b2e2a",
+            "0x1859c3f66f2c19fe2c44486fe29c20193f4a4850aeb2842a4f66f9e85738a01f",
+            "0x224a537bf960000711b0e861b407e8453b300e89dd41688cdc9bafdff5635b36",
+            "0x2e0397155167e44698a728a55dd26b8c024e772abeaad3ae4c3566c4498fcb4d",
+            "0x24d868b6562f336f7c85af8b0f84df8489e2d7f60b5fd889ca1d208a2a76a4b5",
+            "0x29cfbd0eff971db5971c0a65150902b512e2b25609f5ef326037569695c81f98",
+            "0x0e64755592dd295efa0dfb32366fa30b7af9e8b5c1d2fcef9bb2f0244fdf359e",
+            "0x080ea10a7d16aab739e3e79226288f1c4b46a3c16a844730523e7d8591ae4f23",
+            "0x02061e5b898a09adcc026955d58706eb0cfe5e25a640e46d0fb36997d85f4c58",
+            "0x2c0752e247ad6ea3e4babeb3c4799373ba7448353075fce0b6302cde524c9206",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0715aba0027a636c2b40d3e07490728d0b1d6b446c3d681862511a65b641e953",
+            "0x1ce3463536a6307a699773d2666585980dcbb26a81d41597337f938e4674880e",
+            "0x167bf74536dae94ed0893ab2ecc3d582fc24b88e2475d3e7ba918344a04ab549",
+            "0x067a1e31bb7c1f9d2f96e080135606f2485d7d8c755a9e9d99557bcd7aa3e859",
+            "0x29d638ae79ffc673ac2d599eca727f16e8a88fca45dc05f05b56d3e5da25a5da",
+            "0x0aba780a73fc79bae93508f2d74110dbd5d5cf0f137aceda1fc0c1ef6205b103",
+            "0x1817fce7c15f3df3e4664528c7b14b50cf3f0b8d45c1291a1e29f4e90575a892",
+            "0x0690a3ce91f494e07892970e410262a79e658fdac3a406321de09f513fa8d764",
+            "0x1e68efafc3612b0548ed20f5029969bda81ff31632405bd9ba838e6be5e1acb0",
+            "0x14f623f2790bd3341496c617c86e0e795ca37d0a710d8c2bf9bd859e02c1970e",
+            "0x11033f141af9eccf68e1520fbd02e8833260a107641f87c58bf50cebae990329",
+            "0x264b5366fbe0292c61b84a2a9e1d67df8cffdfa4f01f0fa4aed6ee02dd7ff527",
+            "0x25b20ae98c8516ab20283489f78d3d4a96d055dfc59aa69530c6c49ccec2cddf",
+            "0x0828befc30860ec3e8a08a52f013a65fe4af3552d7d3d6e205a721e5e1bb6387",
+            "0x1bad33d14e3e000b0c5bd6f6a5c6c70b4f493d65ca9dae5c7c7bc6b34b91ff79",
+            "0x1f712ea51dcd581a59e13dbb6cbdfb3eedef30a1eba1983084e7e955fca0cb9e",
+            "0x1efee34ca25cd5cdb290c58c3f68f8bc118fc64bd7397f2bbae547e620342d26",
+            "0x04dc621d35289dde06e2fdffcbafe36a0498f596edb76b513ce5cd29c481a30b",
+            "0x07afb640563014c6fa951c31d2a8b1420e2b56926df2c520025791b57e5a1d6a",
+            "0x27656e25db560c94726bf81566700ac82ff4ee56a7363716de96d2c6ccb8fb44",
+            "0x137771f61ef112dfd8ab2327073ebbcaa71157e8daa54977054284c51501c604",
+            "0x01de85e15782af218fbeb49e13971b9e162781d41e67ebb8663034645cdc36fc",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0c6675e05520a8d315cef5d144251986c9ebaa0cef871c25c775f57b03c4a544",
+            "0x129abc6dccd938cf87a866138b593b5d04a33c8bf0c3607315a2760b289e7a90",
+            "0x210561bf31a490051cff34d8ded72b66769aa0648462e08daf46174c93b969a6",
+            "0x175f4a3d6f7aab230787a9f6d3e766036e909d375e58e080478cabb75717f41a",
+            "0x1b859eac1a0ab6bfcbbebb4e5a8f1c886f338134b5189ef1b2fd309460c7d7c8",
+            "0x054a82ca962947d86d1b21ec36b1f00c14e03d0e141be95cc5cdc3e12048eba6",
+            "0x04242ab7f73b7897c1de903408d1741f298a95810442a4a07a92d77a7fc5b4f1",
+            "0x1e8ac6d33d84bbd2af205433173977d8e1c7378c5fab01f55c128e6ca99295cd",
+            "0x00a3853ae76f0451f5df214faf4d00c7dd371e5aaac1e24d8ac230e21e18f02f",
+            "0x1e22bd1bf353d09305541f72229c99b865e531b7d7c90cd58514d43f4604ff64",
+            "0x0d41a40a5f1b8af495c5fe14168aa878af153400f49a885adef9c794a7d5a812",
+            "0x1d56e9496639ea65d9f12a51a62e13cbffbf81da4e24aea7561b6f86aff5a0fa",
+            "0x1cf69b96cde51cd73726931f240e0df36b6a2eb75d6107616f93cfb41e892f78",
+            "0x035fb6a10fb65bdcf6da4f177572bbf1a19e0587b7d380eb752157f925478aa3",
+            "0x1837ac54d837b509b877417988ef251047df352cf0bc266373f7830966b60fe4",
+            "0x03cc54053136bb7dcbecc0afdc87ea815168719ef6fa43970a59f73e8485e1b7",
+            "0x026745b9a2f23933382efd0a276a95027351e803a71bbd73b35fde41e6b501ab",
+            "0x1c52ad8547d0079b51880354009ca2b8bc9a6a05c1428d8232040174851d0470",
+            "0x1daccec3b27832bef3c7e5e510009b64ce2bb7b531eab6e5ecfc55062d858172",
+            "0x29af23855712a8144a766073ed0dcec26d1b42526fc34ab86cd9078a679960ec",
+            "0x0200ab8be789551a65ec89f4c661bf92c629de029daa5f86eaefc52c0d4029f8",
+            "0x264a27b2fdaa8b1cfe4ae90cb227e2e08138a961344c9b84e771ee743adaa513",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1a8fa58a72cc502c220f9a5d5a8a3460ea5a0720a317b9ef8c16fa3fcfbeb9e4",
+            "0x04e3f82048f0baf862e20be1931f6497583563c3ba7343a89bcb43d839b01cb2",
+            "0x13897721243fa6efb05538fc54a1b690c968aaced0ae6e3bd5499013f00843a3",
+            "0x20a3ac1cbadbe05f599a5fa4590b2df3de59febc84a95233f43adfa19e2b0975",
+            "0x2eba7c0de342755000a64780d0a1699ff0b44ea210a710e480faa68d1d01056e",
+            "0x01138d57475675673d203091fcb0c21f48efb9a05a040632139fcbf26e262e87",
+            "0x13f4b892b8443a957d5584383f6ea921614a4f0a55ab7da6914fa4bdf1ede656",
+            "0x089d9959cbe672f61178bc932a2efebb37966c4dd659cfb4138c7c5d2b1739b5",
+            "0x1f92824c9b9767d8f1273be1ccc94f7de8129d9112a640881919ec9f92ca6834",
+            "0x2f52fb1fa4b0ac48c24a03c3a714b71f0a3396c24ae717bfd9f351376017ad28",
+            "0x156648014ec881089d782fc270faf44a386ddac30f40aff7b4884111e74b4186",
+            "0x1eb68de5807face2b60ed092c9f510d2d901f9a82233c5e27b01f28044ad7ec0",
+            "0x10fe276d3bd4acca3695b30d872769bcc43c25f4e69bac70c7b697a7450e3b5e",
+            "0x2b972f2a0728cadff6c976444bb2d0b514277aedfed644d686a9740561bd9d75",
+            "0x15e4147db85f396f61fda75dc7659ae8495ae01a57715711d2180bbf0d3460c7",
+            "0x09d97d723e6fca8316f0854e914a390a7095b40f23a97baf616ac56d7c027d5a",
+            "0x0829008413360cca1e9d69dace9c0d2fbaa39bd92427a5fcc5da61a3d4116bcb",
+            "0x06ea800a1d4ab57c037db3977b15d3e2e1fa66d3ac9df0f0f3b641d98cbf262f",
+            "0x16e9cde5ce16aba63626f2e91eb2e70391a442a75f2b735dbd782a0ec3383aec",
+            "0x194ab0461c875bd7c29f05f7232921f7cd8cb2b6026032b9236994a728f084b9",
+            "0x246f692c3e06130b2ba609b371fb303bfd0c336a5a9e0216bc6fd0618540c3da",
+            "0x23f9f992209317730a52b9bc59b1fb12f3f0a95385c43249ca41513ae8f1bbf6",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1dd72a398197fa23b46fe207069355a99afb76cbaad4d19bc200b45b1c273fce",
+            "0x237b7b5c2f0193a5778ff094376ab54ac130f28cdd7f7e870acc3f25b88acae9",
+            "0x2493225a9dfb69db5c34c48bbc6419f2ca30826fb24bd364c1175029008c7746",
+            "0x027cd82570babe2c631733805e467b26593fece3d2dda86eef88d6e6704e2699",
+            "0x04b9778e816be6faf5af92497c21d4350f661960e831bb19abcb6768a89c0d3d",
+            "0x0e713bd9261f051208c65b0489834a7a67f9cb58f5caa62bb7002c1c686bbd0b",
+            "0x2531f3d8120b7feb61904b83557c210cb61c026dd1167b8d493499a79c32180d",
+            "0x18353883d47abfb6bb67b6442cccd7fb22231b2680f38017c580e7d759e765e9",
+            "0x2e0231aafe79e87b805c88eeb233dcb4ec26e3680aa6112e59bc6a2274cdd8f5",
+            "0x1dc3a75f6938f79089331afa3ac61902334e5df054079bb60417eab0e400c2a8",
+            "0x1a108892daeb6cef4210472419d62418447edaf4300aaf14fc062e4e614df379",
+            "0x0a26f87e7e17af015499c2d2bef65d66893f63a26703e538173cce9552cc7955",
+            "0x01cecf1b161a0cf2ff69a7e187a9a3eb98902350048d56f4798ac49974c7015f",
+            "0x15bd4b3cb07131d004c70ef28d0e753d26c2b75014fdc48d3b82e52195ec1a0e",
+            "0x0edeaafb86d5d2b51f448d29a698a10c5e768839ef995210207447d129d1f3f8",
+            "0x213ddf16c8fb5719d19ac4f035cdbd9ad5b4a8fa270620fe57bf1c7dd286903c",
+            "0x0af53b3e4740417811f1ec48a621df96635dec314ae8ef89fc43d1e5f8d2b6e8",
+            "0x2ca54f4e41c09bc1921c506ea7d3c78de88a7dd7462ec86b0442181d798e8c41",
+            "0x2b8127b54c952a84843c3ab0c810e6eb0076facb800c497310a350a88b342c4c",
+            "0x1bc03af25ab490dbe7020ff3672d72295a8c247d088a2c1e2b9b7d37778a9478",
+            "0x2889b7f848ecb29ef7a52c3940c5405c315d551dae2f2023a25045b7213b416d",
+            "0x009e4c4edbbcb0c4bb52d4aa5ba87bfbe927bcf02838ee1f3fa946c62408775a",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2be94bc905b687bf19f0e889232744cb838281852cfed6e1477a889d4f99d500",
+            "0x1f9e7a0bade7627009111404985bafae043ef013c08a4f02178cbea94cf24cbe",
+            "0x2abb645b80faa2e7c5e7d9882871b4c34d396180b958f449f80f06b4e54b6746",
+            "0x1b9e0f56aaa0cc7ea99c1c522c17a2a77447d2a1fc805f9dd04f56ff7fb78a11",
+            "0x077ea1e02abdbe727855124ad935cc7e269bd6578d37f779c1cfcd6abfa504ee",
+            "0x03a6ca8ecf13ec172649db3474c4922730e95cd0e42924858f173e43efe18520",
+            "0x22e4f30b917ca6d6af160d5276ef40619912b5d90d6fc355c88c2aec105699cd",
+            "0x02420e666a50fd20e4fd8c28924a5db8b8fe88b0f6d7b88dc98ced7114844de8",
+            "0x10a160c2be644d891737ccb455be112a7a189d0d03d994d651c488f81a2e7278",
+            "0x19df9112a49c14c23abe310d2e50aa6cdabc85a0cbbbc66d850d4672da31de06",
+            "0x057ef070cf16374031565313516b011acf7be52a2b55128ec2471100314e8993",
+            "0x23435a69c8abdad541638c876dbe53a4b6e217675c4971ee836a41ef178ea319",
+            "0x10e1f220f8e16ab7cc7ea0b1ebbd292280b287e361b23c795abde08fb62893db",
+            "0x3056aa44d9eaf2efcef0ba8695abdcd5e22fe1910822eb5acdb9e5b1955d303f",
+            "0x229a0f46be8aeeca2c9b71636e2436dac957788ed3ee46c89fe99697ed128377",
+            "0x2cd9be21aaa1ae6cdf876bb425d89121ed4930f8e0ea87655c556c6f4a8febf9",
+            "0x09807e54d7ab93ea81cae99186b73d70e9a11c2f29a53a4abd49b4d193dadedc",
+            "0x0a3019887c9add4d8fe52c62480b2c3fce92ac84a2c25b18b553269285303e17",
+            "0x0ca3e241ab6aa293ade44a77063604cc91e8c995e09aa7613b76721cb13260f4",
+            "0x2428984c14d31f81a2ff75e8eadce87cdd9c933cc00030bc51387ebe9aaa7d70",
+            "0x1c5ac76eb2d5a6881395e4c4cfeb354c52061b286ba902e79a6868f75ff9a7f6",
+            "0x276846b56dfce90c8c4ea2bb4be045a7313f7b2e7c5991850e73b2362a804965",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x236e23e4c1d31ffafbffbb4f2223047d5a4fc7477b5e00974f6d852570086a28",
+            "0x0c5cf404b9e44859584f7e97de3029ea74a83cdd2d0e6bf80b5c2731e1f7949a",
+            "0x0524bf565d0021255a0ee1a9e2ca78ba25e94df3d473ea69efb6ce138a95e6db",
+            "0x14cd0106d71d4c51fca4b994e11b2aadc317f7b04608d91a859975a645844fa3",
+            "0x01611d62e61d5f0be9098a22af4d93198b5a0355cea6180e977ba2f0b59287a6",
+            "0x0765fd6fe2575b142da06d25f4a7ee4a0947c33ee0d28c28f5e65f092de22972",
+            "0x2776ac421558d222f7fcb703dde3ec0d462ffc91089181e04e3b3a2062fd3e54",
+            "0x003ca939417611eaa8f17683453795deaf0c1f8e874d2475dbc64282830fc5b4",
+            "0x19afce1c5de68b6101a6824fe73f159b03d4014e185311d93e28682e59623d30",
+            "0x23c7f567f85e65c162bb864e3b2742e2c7932fee8311e2ce831963cf5840da4b",
+            "0x2bd1b87c687161a4a8b750ed81f2046e88065f16fda596f4d4513c103182ac73",
+            "0x08ada9c54262a5c71c091f32e4593dc1dfdf1881bce6bb788baf1db8d193642d",
+            "0x1037fdcac6ba9e4fbb33a315abc296d89bb06bb3e8a1b6fcf05d4af2e8774f73",
+            "0x1ce73ddb60eeba4019be5b327cbc27f9ceeef9c1241fe7e10fd1ad6d4fb4fd60",
+            "0x05eeba46adba84c862b1316a473b6383aab0189cb45ac48976ad567e4b469fe7",
+            "0x1eb8e706bab3f15da30084ddb5d8e51f72933cb6024b71b0385c9a7101f316cd",
+            "0x27df72364d39e334465efbe9c4217fb7c2be8878d067466e8880ca3fc173776b",
+            "0x12673bcb29882d1aa8e619e018016290e32860ece9d3149450b3b37b7cff8c8e",
+            "0x04e71dafaea94821db0c955924414f2611a84fb501d2769340a731f0700cd704",
+            "0x130acbcd6708e79038de3999ff6d5c63c3eeaa877243e2d62739ddf09d647a54",
+            "0x18d9d4ffff7b532991d3e05f9a0ea322dff6c5bb7776749c3e6c462b6d142688",
+            "0x28a1b5aa7a0bdc35749c0b69db34d35ef07cf229ddd7fca24a751fe6f2a2accc",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0543773251d8047ea173b390ae8082cc1d508a8047487c3ab14cb3a6f636905d",
+            "0x2a285541b3bc51417d7b9fc204be3b3e1274afd8486a2d3f463f9c5a4b0eaed2",
+            "0x1752d2adfa007484a1b4961f1471ba3d5e00689a8037e01647accbe8bac7b2e7",
+            "0x0c3f411e17d75485648fcfc313f73f8cd9b0acbb9754184396949ab330b335b9",
+            "0x0860fff559e6214a76d64ec5c4472123f11d0778ac16a491f04ea598e23468b2",
+            "0x0de8168378b23e5eb18009e3b96cb6ad32df4e38a38a88f58298ca81c1b6bc48",
+            "0x24e4d4c493604f924997bceb9ac60e6f787a78a0cd3e79c3df97b2704233d114",
+            "0x16a17a830551a991e0f8dff1d855111e52ecd9606d9a6a7d7c926dea574dca99",
+            "0x1b1a6c88c65882f6010a6c363d93028f5fcb6da085bfc2d78abf54cf92b69d3c",
+            "0x27b861089ba59e4ca03862ac79281ed1631390df1b3b5f7f55778091b489f030",
+            "0x299054062a6966ac1689a5118faa49d269f5fa2b7c26a4cd9f6f716a85726b84",
+            "0x2ad7188520469aff83152cbcd9c08c981063b2e6c7a1e1644719cc6073805ef6",
+            "0x10001f437f2509003d0e6a6f70572224024626c47bc997f54de30686702291e1",
+            "0x2189a36dab43b67e6a8cd83c8f534610a3bcb037de0bd828d607dd58d10e34e2",
+            "0x04861a1a7b83fe1aa86dc823e77f167e31368a05847333987a5bbe34b68360ac",
+            "0x26004f8536ddb9c6a0e57cdbf3272fdaec3d3f6d659099d34284d50ff83c2303",
+            "0x18f2e216c3bd9e2b2efa37ab5e6b1ef6a49b55f31ef10c01a94750c4b09d5679",
+            "0x105261b8aa1ea580d6562a2a930e9ce95c7c12038c78645187616c7962930fb4",
+            "0x085fedd412b15ea5abed5e5a3689d90ae6a374835daa0640ca7c16d4225d5af5",
+            "0x234849244308e31cd3e04e29c149695b29d4c73217aeb86a6988f4ca78f131ad",
+            "0x1412b5f92ded3d4202cdb7c0908bf67c320960ec30a3c1e0027929d76f6e4915",
+            "0x0a0e7b186c8e71bbefb78d49004502728beab604476f3ad40342a73f306190f9",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1d0980ab588b308af7be465f0d7b4ac3bd683dc641c2f0ee56561ffb626ea597",
+            "0x0d947a4f3bee3f77930bb72cecff63db9226d696d226208d4977dfc933e8cc2b",
+            "0x265c9ad649b9079fbc966cbba60b93bbd67ad1e83ea2ec09763a594e458a4904",
+            "0x027aae0bafc42e9f67d3214af356ae9d6fc154e8b1a98543d62403e55196fa46",
+            "0x29e3e5779d91e9ad9aaacc6d7ff4269e99642900c87cad3ac00daea34dc34414",
+            "0x0dc10ef34c51cf939b2dfca86ee2240e2893d451dcde68312758f6e77d1ce6f5",
+            "0x0fe1560a67eccfd6811eb4a065bc6973a32a5205917e2a71eb5818e920171165",
+            "0x204261eaae3d7458284deb71f0755cfb7eb36d259a1c7b8a5dc5391fcd923e5c",
+            "0x0a5a1a47dabcadc12eaae9ecec9c873ee7a2aec21fd060a656b48c5ef1486134",
+            "0x177912dc9a9f4e99d142c4b5b2e426f164ebb0ddf38a06864b74f9b883685268",
+            "0x1571a4be78ce33e6cb28aa916e5b02f29e36255a1a1eecd56b642cc016fce063",
+            "0x239e785f4bcf18a5f2127852cb2be7352fa718e074218ebf569dd118113bb46d",
+            "0x1ceb66b40ae4dd839b5c5f8f62c2efa077838300a292ff43010f31e4133dc1a0",
+            "0x1223366d6de22ff0c8549d4db1e191a2e4c867771d0ed79de64601142a8fcb2b",
+            "0x195971d84c17410871fcc62cd40a0785c945132db3237a2dc1b73dac50476134",
+            "0x1c0fe233799689ccef8d4598cd85f56ffc9961edf90a7d6e3db2ae446f57554a",
+            "0x25d102f692b44c6a496ca3fca753a9b9ef5bde6844b846574bc427dffc4a26e6",
+            "0x09833bebc25de043834c17aec40409f88e1008bd4c8fa9748ebea67b2259b276",
+            "0x1630e075c9e738f665c4558d9a2c76d9d69fcad07662de5609d17822bd3c38ef",
+            "0x000a89ca04c9f2c94d25f69f72420d1bc9f8dd0fa0e24c40972a61166a30476d",
+            "0x09e7f64cb7afba919f34ed02f75873421ce5d5f6ff0da261a100d38b82ef35d3",
+            "0x2a8f1b9a626512f4b2de309d34b7f82b7917f1c5947febd933299478b07a37c7",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x16e1518fde087b54717eb2c0d66071137ce9372edcd19e4b9ef697a9c116ddc2",
+            "0x02ffc54258c7c80c0fa904e921415078d242fc4f8866ff65611ac45f40f16667",
+            "0x08abb04d7571d616a3e310b2111c81b0c3b1cb9ceb46b8df4375c4add9d826a5",
+            "0x0ad5e2a2a4f13a7670bec22ce051648699fffa1c02e6f14d54900c3e28aa1cb0",
+            "0x13c7d333aade5dc9e45f164cbeba81853e571fa8cc3c4e3a75b2cecae6de66ae",
+            "0x0a6389f912e183042f8948649c585d685be7204a923c94ce528c6ffb7de8e625",
+            "0x023bf44bf973cf8033bdbfbe6f342a62f616c12ea2d66a143d542995f7e89fc5",
+            "0x21569e671ccc8d267617832d83a974ba0feabc11051980f77a6def7708a11621",
+            "0x18b5ff1bb3d2949b672718cfb22f5cc7c69bed68019b9f5de1717dd012adb467",
+            "0x0cb6638d9ed530cfdf16042826ea16bee5dfc872fa0ab39931949df3da924172",
+            "0x1ce303aa9950bc10f3ef29a6d10f3867f63744b88cc1e7c1717b6e435a5d8667",
+            "0x09a1acb8c1a10727e9e4a10e411ac09f74e60effb514dce9831b4e4ed50954d9",
+            "0x226e37af2c4e4374e3036307e5066a7b275856052c3016d38bbaaacb957f9942",
+            "0x10741559b9b5e2683a0669164d296960d81b25499af6747013e5dd40e72c75e6",
+            "0x25545b75e1377b057152308df69921069f3ea987ee58c53745ddd1111c07becc",
+            "0x12bcaf2548497a9a8cd2ffe483b63c3fd3b1589995f93838805dab49e0da7a7f",
+            "0x2ac13d22aeb4ba3417124a4cfb366c9ee6a1e16105c095ab24b1658350ddf001",
+            "0x0a2f8af8e85ae6755d9396a9f8602fcf68efff892227ab98ceb927837826ed9b",
+            "0x2ca2b0652e1e4a62de3b66c7f080def9f76d83159bb1208769897438858329ef",
+            "0x16bc646af5faacac0f13c961e77fd6fe063e267f83b490c990aed311f3f19239",
+            "0x152f2d075dac5dbbbf4bbfd360a9427add59eed9171b0d0eba2c6b8fd00a0977",
+            "0x217f8bf3cc73775913d6d9177f01d3b3eb4cd9f1570dda896ebb4e0f3c6db762",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2389d9818a47026da5d99fa82b47512a1367400cd7c0a84896dde7cf37b58c5e",
+            "0x03265609560baadfff783fcca5bc043d1efa4416d5e19fe6964cf1301648cb89",
+            "0x1f9b8e1c1480ad9eb92441bab97882e86dde1904ff7226aa6895a53c3ed3428f",
+            "0x0ef6c5b7c90b1a3e0e565d9802a065b01dd6cc7e5dca67d3b43d7aa220f1dc61",
+            "0x2785d9dcac926dd42f6e439621310f86948dbaa88e824f4fbbb8cd4226b47d24",
+            "0x26b227df49c6b132841d5fc5885ce6e6b27e2b0d6c1c5a1edfd10815e760b884",
+            "0x2b3e475bf8af29d1d8d7d796d55ead552be843fff5b01b43885c3c2d89a72977",
+            "0x13ff694170659747916554993fcb4767bc02ce6e9866b997b5c85188079f8e81",
+            "0x0f52dd85b186575d9c9e32ba122b0a3cd0591c1a15f97dd47730220a225ec700",
+            "0x20d1c1de1c9e50be2e92b7b788d1d95a30412b90cc0cced97aebfe9b9aea4e6a",
+            "0x0b08467ad56262b38868daf47a261babbf70ba9aa4213010f3eefad0c7b181c6",
+            "0x01128804ef22a54a28195a378afa34f77431746b54dc502308c4f26b8f917a63",
+            "0x0b1c2fc0a84907c4f7b53ca9b4922661897dce165d7ae906d4c95012c336978b",
+            "0x0d5ff72b3e244062443dbb5d3e1e7cae87aeb8d9614e170b387f058c738821b4",
+            "0x29c83d8370fd4db5e491336b7fab6e2c5187ce54d124327d9e1d812c41c566ab",
+            "0x0be7b8e8fd5e48468eec2df31224ed9c1685a94a0270ab822b80fd93f2a6be22",
+            "0x24a2fe02662819f2089800e9e1a4867d05fc09f81ebfb61b053920cd45b3f45e",
+            "0x1a0ca70fb90ce93c4db7cbe44334489f9764b378e88b392165ea42ed1118ced7",
+            "0x1015d0e096413c62f38869bec50479a58bcd333bf73a9e37508f0b084445cb87",
+            "0x08957b88f3f2fb4289e40ee1a8c9e8d95c837e99712514ef980734081983602d",
+            "0x26993594c69ea0fde9bb74982dcee49531aeec335ff0e8a8911b2680db8f5827",
+            "0x1a3bffe1399a05729e635f33618bfac2e1fd06beef59af3b0e69be28a32f9b32",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x127e2f372fff0325edca5445ddc2c41b5258d705b1d8da5016517d604b2e4caa",
+            "0x0cb99d3f0d92c3b1b14523c20e086379e42706084a443d2e97a4a31518370a23",
+            "0x0e15a1b6f834e5d907a5ff31ef34b097154d72338cc63baa86d41ba56ee64d7e",
+            "0x2fe8760a328db31c9187fb67756f21571cffe02743beb0db9f5427437ea5dbc7",
+            "0x0ac0d922b717b9ea5498a75d1635101b37e23a31bce455957c62fb385829dada",
+            "0x178bc06020e213279d857eb656f0bac971720bbc28d24c0166b66b4f6571328a",
+            "0x0fdd8f669d988e8637e1b7bcb47a464a5e57cfe4aad59e83f43971ab3c0aa39a",
+            "0x254cad3ec3a30b0427a68401d142f76e7eb9eb1e3c1ba6fe1e56d0a84a55dccb",
+            "0x2ec57fe4278ebc3a62810fba5a69c79da89ee01499e52c02b461305e3f29da6f",
+            "0x1d857f0b7171b7286c8142d25599dadaeafdad1c74223fd2075c312dd9e8863e",
+            "0x2f76401e9c84ba2949a5fc0ffacfaeae70fbb9a0352eaa8aff604b91bf81f0ce",
+            "0x0bfe83d14267928536670110705dddbd9fb64fd43b7a2063027e4f905183c0f3",
+            "0x07ae92cf0cb04d9def334313b4c7a1dd83b9eedccc6126c260158d341ca2463d",
+            "0x00950a2c0ef739df38fc90cfdae4bc4cdb59c4b004c5d57cddeeb84cd431e024",
+            "0x27e6e8cd40a1e98d4529398f92d0cf8f3653e175fc277f1dd51c870651034ca3",
+            "0x1789376db2b0c063b89305eb9a8529986acb4eee6438cb16e3187a37b65c5acf",
+            "0x0553e8f4bddbd9875a41e07d1dd49b87ca96af372aaf2f3029fcf8172bfd2850",
+            "0x17dd9091efe5786ca8c705219f40972d94c104522de209286f2a6a0341e4484b",
+            "0x09598606e0c4cb4cff8683eb3cf5af3aaa6c584cb089cba9b6498f6eaf044be1",
+            "0x15d75d2690ec745188f5f43e1d5a19673d184c803ab98c84eb82e1f36c28b97f",
+            "0x02678de1b9659003dd518f0497985d82e4e2d278108e52593521784ae0e1a618",
+            "0x1826ece183daf2971f9e8a8f3e0ebf73c622ab288ff9a0095e754cbce0c354d6",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x00f50a7c14c8c983fd0e4bcd1eea9dd2994dfd1a94dcd0118a00de69594e0109",
+            "0x1588d2d9a45ca7d02e1c15425390ca7550b7a92764344fcb8966b7f2b7b3acdf",
+            "0x030876bb3c2c26d4d7a9844a3b3506dbdd690813aa41144f4b7d2b2753b4e45c",
+            "0x28a61f5ee73630a9ca84907470b6cb38e4d9ed870f76f447b52d649b428d4421",
+            "0x0c69a9eb741e83aaa6e467b2af92be9bf073cd5bdca97d1052b235b47ba308a4",
+            "0x244a4d419026f31b708520b928fd68052e9d54713d5574e6ffe3306f3bb3b154",
+            "0x1e66fd2db0ebcc64cab673615ed159efcf080748e95ae32bb376c27f83d833ac",
+            "0x217fae185c282b7e2f0a0f6dfa7ffb11a0f4330c5bbfc7fd7006da02f6c866ae",
+            "0x13193f20f32f6478e1f3fd72725d7cb935cb2e60efbe54b1dba51cc0f909a9ab",
+            "0x2fb24b62dcd174159a9a6a08960dbd929c3f330072738b0f9f46fe1547bd4678",
+            "0x2fb2d8644218896830c4acf71c1d09ed9fb4bfd642d568934adceb62f5006f04",
+            "0x2bfe7c8a907eec86ecfdfbddc8bfe7fe0a4e268ac0e308b4ab01cb3a3ed114da",
+            "0x2eacef1fdaba20f14d93fbf1f5b1ce117ad5fb377bbe2d95042393447e1a1229",
+            "0x1cd92abfa18cd1a38a30f8d629e972835e299176c6877e964a1325fddcb144a0",
+            "0x1983921d83f5efc388ab1b682264663dd61a3c95f8f9251ea6e48904dcc05910",
+            "0x28cbdc2d4dc0313e02a05ef8d7b55f669c9dc98f09cb898ea1cd26745cbbe519",
+            "0x022d45ed9712e4796cac21590919f52125d4d3dac6e3f93e378599b9028bfa0a",
+            "0x1fffc28bd89b425105a7052b311318cfaded57e17040d3663a4b47fd938fa5a9",
+            "0x1e671fe3a1bac9d3901d168aeacfe325fe6fc48ab84c858b547b73d72f56fd95",
+            "0x1957eefd326ddd5baa90aeabe3eb421eaa2608249e654a907f21798264521b04",
+            "0x291f640f09351f1f42e6434a1a4225505aee70db87f3fc85a534acb89b8760f8",
+            "0x187e703b151e6c743a110b5beac381e1140783853f62e3a1b1d2772dd6a5eada",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2fa7c0767301ae74b747dc1bb83ddbda526bca0a3d523768ca9aca97144efb82",
+            "0x132b813c25e81e06362770555c3f8c94b7820026843bcedcc3046fe3b46d4cbb",
+            "0x2bc4e35a03577827c0c1e29246ac3f7d52d729818c7e7b06f353d498ca61ef34",
+            "0x1cb49445b936b6f2b46bf46762038be4ab20e114d7d5242956636c8a006f4693",
+            "0x290a0509f5446ef675d7cc0e2c4ea4bacd8a5169e1d78687cc49fc1fe7392aa7",
+            "0x26332c62dd7c0210d5ec59777ce40b5337d7b226ceb541fa364edc4362e1459a",
+            "0x14623ff687194aab0705ee1e92ff8ebe4932a9c79ca77321f75b215e1ca10fb5",
+            "0x1fdc1c9d1a9fab7d56f24d0a290c6ab4f27b2291f95099758ef199b641286439",
+            "0x0497f3d644abb493d1e50329846a3e123680fd1ec4aa098df2ea83f55ec1f1d5",
+            "0x27052620971f7b17c766d3939f35ed42dd1b24804fded04c25538aaf6f342619",
+            "0x28321ba07eb42f82e24909f75faec969feb77f96ddd9a77f9069084ae6a4d54c",
+            "0x00699498ca7c6d4dae0122bc11cf79de770d443422493005fbafa1f706d01716",
+            "0x2fb76ddce8e0e63903313d650fd14952e358fe3c1e10998c3d34ffd8f8be3953",
+            "0x261f7c7e1f229702d3deb06d30a32955c6cf3769a4a81421ce94de769fa279e1",
+            "0x2fb79ac48d400414901e83eaedb799893058e5ceb36c457797a1347606915929",
+            "0x2a35d63f952e9e020ae7074060787342fecc9002cf42ab233eb3738b39a13f96",
+            "0x0ff391a029bb590e08ac2f4921816c45313f91aa42a1ca211f0e64b0de3e5fad",
+            "0x1bf7f06cceb90da6fe62cf77459642bc6fef0a6d499103075f009bbd5717b708",
+            "0x22255f1467da268ec9b035c8a4cc3855ecffe2e4f405b125a51bd9761d5bbb43",
+            "0x1023ca5e8b9bf659f91d81def4d803a394d770c0f6d03ff5fc3ae05a512440a7",
+            "0x2f0826e9a68854d5094125291826f4bf53c3856b679d72a455b8eba5e0bc9ea4",
+            "0x2cd5ec9b7ef2a15027f70d813135859d599af68e1966c7bef98d4e4d96461f64",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x06b69f453ce3b1d123a933c7641011d41755f712b6f84f65ae9bef40867c488b",
+            "0x08fd3705888695f0e38fe15afa6591553c402e6218e963c28872bf76b3cd73e4",
+            "0x19803a9b45f61186d0ac99ab7f16421f30242102b8f3d107f65a7c80844ee4ee",
+            "0x0d947b113ccd18a8b2f8cdcd7e4d44de623d59f7c069b1b42189ed2e0e799249",
+            "0x05541c8d9fbe2089e08b5402e38a86119f6aec50e219e727321cd662124aeecc",
+            "0x0a3b33eaf00b5f057d9d920d764f3263a72c80cd36f6c2bc966bd7a32ea97252",
+            "0x00e675acd2b15db70473b9cf27997cbaa7b064e50b6452921b52df33a48d3099",
+            "0x131c0ec4f0d1128280c5d5e9030a5164645055430a0f72e3c508e2951d01c37c",
+            "0x2b61b347e3bc57e8ccc122827c6ee266cb57f8afa7867f3eec1f7a754d88e160",
+            "0x2fbc8a165c1475eac34453d41cedb0acc70c00e932c6258583e7f403d9a8bc31",
+            "0x01acae6958ad4619cf56b3485ee66d609caa19ce9aa87c839b35fbf6c12b976d",
+            "0x0d1a10ef8cbd965f66cc89905fac795e028a7ebe54e6772ec564c8493b755446",
+            "0x14d0a27ff0e618ec98108bba813cfc09ea6da392edf75b2e0dcbcd6a8bf7e0da",
+            "0x07cce04915e006714efe799b253e3c39de4d100d6b7ab6690113c75d40c2992a",
+            "0x0a4f0d632a35d1d6818a12604b97784eaed8a2dd77718d0a324cc0dcc85b2dc0",
+            "0x0e05a5efd9305472f8191078c2b001721e1a091eeb231925dd20620fbaae22fe",
+            "0x024224b369039afb2b7f8b81eb3e476c2a0fc76ea8bef464c89673708723c42c",
+            "0x28b0fbe2461f2c47a4afa0d0f6d08f288b21b20f90f2ef669be29c31e91905c6",
+            "0x1cfd79928ad5129f51b6f72b01c90d663334d605f0f6e8e02d9613204ee2c3dd",
+            "0x28d8fc2cab7a6eb49dfff94e1920d3d54f601412c7feaa156db89b239be70e99",
+            "0x0bdebf0b900e6f4e0c099314eb74294e97f62715c84ba0c59f21787631f0fa36",
+            "0x182b9caa5161feb63ee2ccda8d0f65ff1d0f806593b6a398fe303645ad0b5ce1",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x115619185d9d09ed63bcd186ed525b312f17572a7a837160e5d4496bfc154fb9",
+            "0x1c840bce1e6a69e8042b37bc5440dbc1b62db0a765b2abb9a78ffb6b9943c0c9",
+            "0x215637f318f6cb2e03563a36d50044b913d29c96784163b2179808925394e198",
+            "0x01876f1c6ce7707339d42156589ae5697bc4009ad462e7ab36afd4ac5a5cdcff",
+            "0x156cc44e4f1333cb7bd93abedf75a2572379d465d9f75847220f7df7df5f14dc",
+            "0x1e5089e7d99b552bfa3d9ea99c85d7dc6eb0f46df73b56c26adfb8eb7c1a151d",
+            "0x1c5f66e28bf85bf0fd5ccfac8480b967fa1f92c2e0a2f0e74e21cfda24814fe7",
+            "0x10d9a418157911aab1f521af9c5cf7a4c7e625bf4f430d299a7802eb71b7b5cd",
+            "0x0d35ef7faa533cbf105863228d313b244c33f9e7525fb7c44914f6882a793467",
+            "0x013ff7b3868eba19e52873fb87249ddf6c29147768b5f86d4f573c1096dfbeaa",
+            "0x1c7e1678cc2e293b4024b26649a55d34c8e8cb3786f32675e7e840fb99a1f7b4",
+            "0x05b6181d48646a1d2332f4d7034747072ca7fd5529f223c2b6a009af56efd134",
+            "0x03df46a9fca40930fdf522cf5d1659f6de1a75e26ee8cffa620bdce661c7cc4f",
+            "0x2c4a04eeb2c7043fc4dbb0fe66c7dc6f188fd89832e19f5bd2546e76d6ac0c34",
+            "0x0303afaa7c1eba836a924f96f5e68bcd18e1ea12363fa1f6f16ca8a541c54d33",
+            "0x2bb174f316b112fcce004f6576442ddfeec879ab7f1954f56aa9268e9d865056",
+            "0x05fc25b3cb8e3a2e3a76c96837b946766de7b22d3cda79784844e0e044de1d7c",
+            "0x17e42c5ff0a77e16687ccddc25b337daca309e86e041f5f128c54a52519fd624",
+            "0x05dd5caa3b33856f5521e04795adb71c365e5f7e22ac0fa34074f70063973bf2",
+            "0x131828d87d9a9dfa8bb63b59c598d3206e92656fe205fa62666b685f472515c4",
+            "0x18dd8d084684e3ea5ccda26d42982ebb849d362b99e619fb4ee417446e489b3c",
+            "0x28110745f4c668f0d86c942b86070b45bed9c32cf3ed4b9a95fcc263dc0246f0",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0010eb7e9598abbb2cbcb4dc5a4f1353292e56e355d7c8082a1f6402790a8b93",
+            "0x27afbf4acf733c6a73e812e8729240d34e58da8365235b77fd0ec9f13a989927",
+            "0x11922ad6bc4901125a998af3cac946559ba63db414a0d43cb8d22f31740a2480",
+            "0x1aad2a19efe383865b45983daa53dea6a654b5675ccbbead653afc10c7c70e20",
+            "0x2284c0af9a71de069c8cd7776ec6eb98c39ce1b166f5f91ff9cb20124c7b4c22",
+            "0x012c95853efdfbf4a55f51e5578405118ae362d150f8eeaf5a3710295ea8a9ed",
+            "0x1f6367069b72f3088dc86bb5404f5b858ba24feb59b97085f1d8e90e790b5334",
+            "0x24428aeebf572d0843c62ed13cac9b1caf2d67bbfa4a6bdabfb807a4b8c526f5",
+            "0x01599d9b0456a0324b719ca6b96101455d1c0e8617a35952aa67fdfd7fda4989",
+            "0x0788265dc1771b330514ae88e16a84c48a07e329afd5b454ba52acbfd9798948",
+            "0x0cfad0d95242591f44a507d8fb0ef72a75baaedb6f8d48e61e764fdd783bd334",
+            "0x00d44b6be670da904b6b0195337293871ed5e2bdf5c142495440604756c52733",
+            "0x23de85b8c4a1f40596bb58a18b28e879c7df053ee3a6358cf6af5b2d972f0434",
+            "0x0884c83931a7c8ca8172cc28412ba9264230d55cd5d8d2956397dd4c3e260ab2",
+            "0x0b89801f8762d04cbef1975e05e161d3dccbaad8eeeaaa829d3e3279a0a02915",
+            "0x111a4e8d251aea21fdd28746222342f4be40d13318a0a2d459f62c4bb3f1ba71",
+            "0x206a499d2d7d6c2a02f8f0aaff4ee4681d95a431fff286f06747a79577a72e0f",
+            "0x256f4642c122737bfa40262b37dad6bf08b8691ae7ce7ecfc710d46b1afae9e0",
+            "0x01b9696c7bf2e8b9b5dc56d7fa7d649b19770a7cacf9460374267672bcb9f4f2",
+            "0x21705cb336f4b615fc2c4c09a16b2d909a544ddbc1f0d359dc8d01a1710128fe",
+            "0x29a3b30e4260b1de23a6e8a8d92dcec21edc089df206b1a54882aac8501dc2ae",
+            "0x1eb3bb943e3d43362a3fe170aa3221fa8b2b07bf1589d327ef9bb505e127ea05",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0a35dcf56608bdad5df89135851c10d12b84449b9d9e55b98d2771dbfc61c605",
+            "0x23a4952b88b8f0094f46858653cecf4644ddb364a0e88ff4ae68e48110598c53",
+            "0x20497bd1f6e39f5ede691471a9d1818c5f88b1d34fd91717e57c96d04822b531",
+            "0x0a9d65db1b6acf8f0468b14eb0f0e09485cf655705cb090d4239621448cfaf19",
+            "0x059f93bbfa9d98d583e312a97c92052c09a767aa2608538a3d09d50fe298b9a9",
+            "0x0df44c3bde512d5de7796aeb137ddd0e384bd01f06d21b4a6aa7f5a986b68c00",
+            "0x126f3f9c82461a049741749f9d148403d45924f38e9bf98cef0fcca4b0f5eeeb",
+            "0x29ce6fb28bf967fbe2f90559c814cb7e5dfa437677f28ca78dd4d5d876a0dc1e",
+            "0x2bc967a2db5ce539d5d90168c344e9a8d07a562aabf365c873b3462096abba0a",
+            "0x18e3e8c0e108db0159bec97fda8c1dc2ea3a6fd13aa48ca8bb5c952f9c0a4a45",
+            "0x2a3fa2ccd44ce73ef4b224b8ceaf05ba7719e2b4b464e9044090ac48e170b0c2",
+            "0x16109445c583a54adc3376377ad759041105d900cbc1b707b085209430e91fa8",
+            "0x1ee0133efa9ba7464bd0c2a35872bbf042427fe629726709485f97889a54500c",
+            "0x03627147582b3bdec1518706a4f3d92feb40b044bf9d411d686a6acf5fcfc488",
+            "0x2d83ed7ad6a49de1dd0a44eaf4b73fa583a1c0f8ec4c6ab250c6946b9a34be6e",
+            "0x071d69888ce57f567cea20d80a6ab21d3af0e9c0464212eca4802ad96d5836e8",
+            "0x1cf0f6190c56b040d6ce636625b896154a997c83120e6d9e304d2856c94164c9",
+            "0x1597bd7c93887303b994a29a75db9cd1bf21c1363635bd0438ed94b9a226843b",
+            "0x286afe317e24bc018af7fda5fe5a7976547cf5daa9c66d0b331e18a6580286f0",
+            "0x11f61549b2a79352ecb17d1d9e89deef5dca0fd796e084f42abcd16f9a8b1fba",
+            "0x1a7532458707293a9020ecf46ec112d67e682095d08519d74c879ca310d02265",
+            "0x18ad412e1849d02c1de0a0ba2f1a4dd2e007bce63338325a926fd1534be41f56",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x18b3293e7e89b6dc4ef042b36299c9e240ad9dba514f640fcc654ac692cb5327",
+            "0x25efe71aedf211c64a8656e272fc688b9ade8a07c49885d1b6dce4d92550bcf7",
+            "0x29663fca1c969b407e13dfd76564dcbdd8d31e592eb570637b002fb1eba18cc3",
+            "0x2d754d9fb41493e3f168cf704978cd32b7e4c429757ddd56daca7dbcc2b0bd49",
+            "0x149e26e92816518d7b8fa7c891fd482beaefa70593d880c88c3ddb8200d90555",
+            "0x1b852e4ec88816eee233cccff265461ef4d4b032f656d6f4243485a9f9842174",
+            "0x0f80f9ed32506595994d4bd80aa2ca644d65fb46c3dc7aac3f7c968921bdf4d7",
+            "0x0e8ac50b893ec2da1ee9df37a391400ff4d11a4aa5d2a50f2ad67dd9e1bacee1",
+            "0x02f5ca75a15a8a1059f789bcc88e02c08d92f5b66c28cd9fcaee4edc81e49c32",
+            "0x20763832624a75261e30c0aad896cf085b8ddaf126ffcd74cafcf5e68c27d792",
+            "0x14b277aed911de952f5028e6158b0f649977659d6b4f4e141a6ba48bbebfe471",
+            "0x155c1f13b36b41765cf29e7fbec9ecf1a5216ed78033eaded360735041f738c1",
+            "0x09e4c868b431f4ba41879f3abda81a240930dd4360faccef53dd63eda6c7a1ea",
+            "0x18db02fe33b5a024b0cd7d00825ff6dc506dbd7f594ca8b094d248e51d8f5674",
+            "0x2c356a0b9d63e6ccba0bae47d2724034f3730520b1c0b620548f634a451a74c7",
+            "0x005a06e60b06fdbb9394fdabc584cc9f941311b1393ddff84978fe1480161099",
+            "0x218cc4523b8013d8729214b7da032487c3e3523960296b519070c8b811c60021",
+            "0x0b2e1d27baa95f47dfa597e8cdd957532e86c921b3ba7588fa1eb6020e966b9e",
+            "0x20fcb19652c2ed4a1d52fdb9592c58aefc32050e5afda2c7c6a6ba3344f80551",
+            "0x1ac24921f78e8c7e7734feaabfbb64084605217749d1d0010dd777caf5a2b4eb",
+            "0x23d64363d809f69ac2dc87bf2d7c83b4e366175f3c60fc784748307b65cc85ef",
+            "0x0c60798cb198452424be9e493e29ce6b6123a8fd2ea82b8244b7b60bce1bf8d8",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x12bfa7625be9b197fed46275df137595335586d2d20e78c8eafcb8035e181442",
+            "0x1d03046707cc750493155cb55640d4b5492c0306173b030c52359a5ba26794a3",
+            "0x03bfc9f29dd263eab3a0db09405b7a4522e30efb3c68627b9e6303a03f2a727d",
+            "0x0256794490672028aa8f6921fa43b4a27a5c6f6538d5fa549842fac571a03683",
+            "0x09a442585deb9af220ab7ae996c8d529fcf15a6e4dd557f9fa25c40e910a195d",
+            "0x01394646086f1bc33834dcec482f6f64110c4b310dd07056ef42d27d9bfda2aa",
+            "0x0b1a0c715f8c12c237cf21a88cf8a4118120880c6547d531a868fcaedb4245e3",
+            "0x1eaaade7e08c6c41c0713093868d81ecae18d65493fcf9e93ebc7d96ec6fe69b",
+            "0x04cdddf92b724d49ead4afac4ca51b832485dfa7999f1356ef05eb2b817c6133",
+            "0x0f2a8113c6c50084567b55f77daa0bbfcd0ab6dfb7af0d840fab67650fb5dc1d",
+            "0x26b7585bb95774bed60f3232267caf1723a832a6c08e6fc7eef134c153659239",
+            "0x0e3a2f9dd0d872dcb8789b11b2384841a97ef342f80cabc98e73f941b9f65559",
+            "0x253967aca2441e0441ae016cbb7868beb43283a04daf35bb8b59ada8db2bd1d3",
+            "0x2cc8667692478aa6030f73a2efd3e3658352c4375a5691d6b72ba8d5bdea4b6c",
+            "0x2f39bb826da0c84ffc4f342edab1b88ec610759d224cac9de4e901a7b07d5be8",
+            "0x19594ee48fe2d460e295fcd457e7ea1d046ddc22049bedd9470a64adc3deadf7",
+            "0x0e151d1793cbdd17901528d08c465266fde525ac22f36030ee927d3b30b2df50",
+            "0x24027186afb28638130f1222dfc01dd10d02c0a9e4acafe93fe4e6b23423d8f1",
+            "0x0039bf63f9edf16b1c2e9da0943e7ec2777b7cab2849649d930a05745df26618",
+            "0x0c610463419995c31a885703dde82e80956063be3dd1c06218cef953be937b46",
+            "0x1f7e7a755dc7810d77c8dd0ca9ba5fe4229a80a33516dea31ba01b229bcab925",
+            "0x197e0703f3916bf09a52f5e8d95108212107bbdfca04b84c26e8113930509952",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x151520287d3bfd5f4a143235919a7fc742691888c1901d6bb27d6672bcdd51a2",
+            "0x16f8dd41ebfb9e15ae667d51effb547e5c25db26a5ad6a7e7c5071f9b502689a",
+            "0x0ab033a5e5eb6956cf5e0ad48ce1bfeec16cb1c0e05f16c9b5ed01e8bb6726df",
+            "0x2562c16ff1f13548f2893d115e9223da5325b897082c49a9d2e096c5073e6ba5",
+            "0x1054d25a6099bb601d141430fc00aa5ee9bdba364bbce438cee4976cb88969fd",
+            "0x08974dfb42b85d3b2f1fd9b95eb1ef68e208e5b8e464225f58d452766417604e",
+            "0x1f42d3a707d5ec79a40fdae3310ee52c044e45eabf68628d8039c26703ed1f44",
+            "0x21fbb22404200591bfa52adec3f9e01a30dac9bc913770b603688c7a4cf375c8",
+            "0x1652a9211b4e879324ed25c25242a6f5921ebad81f51912976c35de7a4a67d0a",
+            "0x06f9a28d16ac4354599537849791ee3324f5a71e237b7dcbef88f4fd6e14968e",
+            "0x2e3524cc041bb9c8f0265a3ad517646b2e8fa700ba193a048c327d2e0a8f8463",
+            "0x12da82eeca8dfe13f85ebeaf47613dbacc595716dba82f479a37d28879da3f0a",
+            "0x1abc4c8fb1aeea04bbaf68a8871fd0efcac95fc75b9bdfe332c06fabe7ddfd29",
+            "0x11a50b7d7e4dc853d7facefb3a5f531f8e33b37141007575c34db328e4b0e72c",
+            "0x1d64ad02a8981a2b586d8c860ea87bdce5b063cafc6792c89cf2e58f91172b40",
+            "0x170e5d2244dbf79e0c64ad1143942d695b271e876a1941ddec8ea386f610f1b3",
+            "0x0671287bf82ccff7df10ee5e08fb54bff0beb07a4ed46f7f728985d3a06e19c1",
+            "0x2352c3190bbc3f07fe1479582e536f1878a554bd56863df09096050ddde8b513",
+            "0x044f0ce1fcb4bb95cbe977553db616f534ac343e87fb9f8d0c0976146bce154b",
+            "0x1ffec7033c1ca0a059085f1f6a27e704362d2e6da0cae4274175e8781becdf11",
+            "0x2d756ffcee668c953752f46a267badd47fa1b14213874d2dcf07f80094efd20a",
+            "0x29d761440e3448eab67607f0e0ed5e5426585b74ab2f51e3c45a00b3a842d9b0",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x02f4334b990bce845c42cce03d5894e4f93fa7e0b891fdb1ab80510d6344c9bf",
+            "0x1fb372aca4af07b28468256d1bf796b904cd7d1a174b422ca262cbfc5646ec57",
+            "0x075d774412011ce6c6bf3277675550d9a3dad7bad4550fdba3c8fe0277c6d2eb",
+            "0x138c5787fadcb36b3e4b3d6d578f626cc6cc574047091f45c1e6540636e611c6",
+            "0x1497d05fc88f4dbe141798bd7c9fa84421431043ab4616843e4c0f6ac176a135",
+            "0x1ab969b28cf0a278ccb8b77f534a0f0fae8c896a6f948e469c5b875ec439b7c5",
+            "0x04e16525ba101a0217ff72f75f370ea2779141d44c915b9eb8f65237b64a8ef8",
+            "0x1035ce983063f442820bd42dc70bba3882184dbb11fbc493e7193ad704c8f0c1",
+            "0x2e7721443933cc97f95f12f3881f1dcc027bc92e2e417d61a8e013a69a1271ea",
+            "0x06ef0f9f1d45a44e2be14333d645920ae2836084809f09cf1b5fc29f649717f2",
+            "0x29b688b82ba3d684fe786710d77bdc3c9f9f9ae2681bda6e8f6bd1a771230bc7",
+            "0x2111eb83eb44cf783650c9864875877277dfc6ec0fcb1aab71028fe77d2b19ef",
+            "0x0c16767947a202e5c5620195a942356737a3acaa65385866aa1623610e384f83",
+            "0x20cb364b6628e0447a1dadf34fbd051d4b469254a352330170df7a50ef6f8d74",
+            "0x2be038543594355204db5cc361201d6800c34f119d78acc4c7539013199eb778",
+            "0x26eb33ec20f4f7e334b1519f76028b65e25062479d8e783d61d16662cfdb196b",
+            "0x1a45e57854fdbe9a615864dd21328c7ac5b7038914752818765871dfd55e2247",
+            "0x10a00cdf49ff1bd63dd901f192d0b84d0e04a7489844cbb4455fa3b91d911d45",
+            "0x2f8528289e9f3a310a37a5228466b4878b8d2924c1f4273e637e08344b4cac84",
+            "0x213ba977d25b095e40140957531478c4577a8d7526829c3ec45d069c6b585f3b",
+            "0x0b8ce13d3c1ee4c0da1f5b517ace737df88e87fce5efbee31d8acdc2b80fd2a2",
+            "0x0d1ec33fd7fba11cc9e26fe9543d5bbe16aca4206bae983835685654920a9e51",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x14e1f06b20fd6f4e634e6ed97a54ab818b20db53d91a9d07729c291833bf1828",
+            "0x08941ebcbecf2e4b29e18ae11175a8e42e753a915cddda4c311279cfb8c83b0d",
+            "0x1a1f252c2b0ec4e1658db51867f78dc40e5d38726730a6b3f3fa29a44e30ffbf",
+            "0x2fd4461c31efbc5ba2a4139e29fd1fb6ec295a6a5083cb6adf127f0c7c562e6d",
+            "0x013318f55f00019e2358a036b37eb5f34ec5a86166d97fa4d1bb3bf83e103b98",
+            "0x25f4693bb4da01673939e295eb97ca3821f27d9e41f303045144ddbd769f72cf",
+            "0x02c4d265b28087ef61dbf72ad3e19fe7a6af89ca676180f26cf4f234269bc7ec",
+            "0x13a70b5c39ebf57270047060c14d3c5512d242ac777ad1146f55ef2cb5decf21",
+            "0x163ec7a19c285a3e21821af92e586ba6604251930d29c336cbaea95dae318fbe",
+            "0x14ed1ceaa437c9f425069f69e9154edf2ecddfade17aa64077a25d1e57890562",
+            "0x26e3f55637ea555eeb24545ba3190c083aa4b09a4bc0a877f7952a3c8fbfdc73",
+            "0x275c2db07c9276146fe6190551c09106727a5d6b6d79b93a8cd71bb7361c26c9",
+            "0x295329cc77cdac1509d68299dcd3fbaf7e8a12f04dc060a1c43b2b2bbb877dd0",
+            "0x023aab77061e9ac19aec655c7620402b2622bd0f8f4c4915a02fd98d6e040117",
+            "0x24f11c39c90d139792460f47ca53488b1196ede662ae74da818a3e6c1ca3d797",
+            "0x0e059d4a351ca1905e07b5267beb2fe640bbea18c517fe28307bb342551875dc",
+            "0x2f75655db0a646bcbdc9b97fd9f90fe8e11a360b9e4021cde8256e5f8d336c3e",
+            "0x0fb5f24e3b9803f5880477e3cf764778d47910a1e9a892619d1d777cdd5fe8ff",
+            "0x273b41377dac71c702401466cf77193597a1a39a1977a1796af767a1192120f4",
+            "0x17399d77c00ddf8896dabc11982d5451dde66fbabf6059cdac66ad687e56f84f",
+            "0x13015c8cad5bae71decab9faa691c89b03bd889371d9f0341e9a3007a53d7e6b",
+            "0x014d26309c853287afb56b98db449dbe3157367f034641ee1f8b42cc86818504",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1fa6d47a73d02d0b1d915c333f3cf27d2737d14179a332f22580a1947a5a845d",
+            "0x00a7bdd6ea5f826412a9e3085529eeb3d2f6adc353223982fd8021f397de98e3",
+            "0x08b499dbdf8f970c3c8fc076ecce56dd5cbb6d3d05de7a2a0c501aa57a75efb7",
+            "0x11fbc7b170423e813b8d26f961c99146f732cc145ed047cb6bea88f9b42e7e1d",
+            "0x00b1d0da219ff7b651946de0079bcc3b2e1ef568c6d16349db3b7afdfbecc4fb",
+            "0x277be9b66a43b64615b8558e9d8f0669f643d997a365f321f7f7fb9f6e5a1104",
+            "0x18493e4cfa07dc395a45512d3324d2d08058fb69c6bcdbf367f3659649c0e800",
+            "0x1e4e85306e4a452779c994ce00f22f4d6f21d83d7a2fc95429a8e9293a1c5bad",
+            "0x276ea21eb92e7c03d60e7e77bfab80114b74f42bc0ede888f0f75b64387b7883",
+            "0x1cd5164b89018abb18e32822f90f00c17ac4c79647ab4c4a54726742318b23fb",
+            "0x2f3b8b12cf24a3a994b347d9bb15ce09d660fcdd0ac54e020eb75a3ea3b4c962",
+            "0x0948300dc9aec4a799cb6c8ad0d34e9d458c47ae1df70ac89b3f3509b0aad4cf",
+            "0x1e1d1b3332b89f62714474dc7af5f9c8aae5eb5e4589a4f840abbeb751674d1f",
+            "0x0f10a3834eabbb62549be200ce5ca0faaa41eba545e54fed80bb7245e02705fd",
+            "0x241276321fab8e1929038f229f67887e9d4292b7cc97186e2dfc0edfea386198",
+            "0x1d8d39822be81b8a2c3a210af75c6b574032f1d40acafdc2bb3d88fa4d6656e8",
+            "0x2d719d563f0f6d88383528353deed979fe4eec3a99bfb1d4dfaa0081fd856f07",
+            "0x192e4db2f73f1a7b2eb556b7f49828e73c6986b269025d094e62c9911219d35d",
+            "0x033344ea358381b6aae109cbc3b8460970a1c6f69841eb161c8cd69d1db74d7e",
+            "0x2a9b6b55fbde298585d303e16d19c7cc74af5ef40dc4709230078781e1488739",
+            "0x00d33046190958695ae3d1072e2f42ba8bbf6a7eef5bd89932918b1b83927ecc",
+            "0x26d9956f5c913d2586dd328eddee9d5485bc0a419c89e9b71318cafbf746e2d4",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2a8bcc7669d4866ab9a7828de5d3ec87ee34b2c37898ff1f8bd53821356f1767",
+            "0x0521c1f8ad711289adfddefe7946aabeb0957559d4adf7e462209c57d5c2a285",
+            "0x1bf8383246aa61a4615ea0aa8358f166a2e328e4db51a9388c28029edf615af1",
+            "0x2ee86083121851076ee35b8bfd8b7946aa65e56e0aeb2e40c5dc55def78232e8",
+            "0x125450baf2f0e696bdf503cf53d3290fb3bd3f3e43a367e7cda7c170ea42bfe2",
+            "0x2ea54d109b139628d79b5468a43d8fc90f44117f1aff28ae9367fa106c0f5f62",
+            "0x1c0131cbcae116951a9a574612b982d142cd1710fc317bae8f0df46b1be2787a",
+            "0x0a3c6fc8a73f1b6e1baf35ceb5abd553fd6256f8b08fdbf9276a78b9336b624c",
+            "0x17d29e078ae317fd76d9f3e8c66983b9b6d5dffb99257d0fe921e0ca8d67c9fa",
+            "0x1e54de07f1b9caba278c35921d3d31f588f4455fa1fcccb3627fc47fb9b94d2e",
+            "0x1cc69dc5245054d2aaa3bf313b54217651374217b7a9b88155fda8669de0cefd",
+            "0x2a4108a3f4a2d4b47a03b871653b98df8e283566211c66ca32a4164d3aac156b",
+            "0x26ac24d47e0b092294f3d7c81011d326600310a79558fc87206bee111d033c74",
+            "0x0eafad6a8e02d31be12feb4a2463b0058317b1f833323f2de80c646359dfd74c",
+            "0x2af2c954dd0074b64e165ec13547b3336316e147bd4e75c9b352c9f8b15a8492",
+            "0x2c79f3b7c13dabe04d51cb52f171cd3fc567bb1a70b910dffff8516ddc08c3b8",
+            "0x13699710e0f34d9f2f1d3fecc8e03424adc0d7a1913725b49d53248bf28d55cd",
+            "0x100ae49e886d8ecc02ab2e2c593cf2bd20be686c7904f7fc6e11b9a6616c121c",
+            "0x2d3e7549afd9f1b65e09b306c5d504b3d2bc86e52a5982d97aa26c73e1e6cb32",
+            "0x21a7d2ef6ff6f818657ac1d11279ceb788f24eb6de8d5c260eb3702a21fe0c60",
+            "0x204f37422f608663377c0fce9b3962cdddbb55d263d2be757070f5b4e4401df6",
+            "0x2f8a016bcb9d16bed2717dbdb1411acad3e7c5b5517213411b03f3843419d5f0",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x03a25d700e1993a10f36a9befa846dceba0d11e7e82e24c02f0c7d4007552b33",
+            "0x25e01128e8b58085df3cbdbc21e75c5fcf25fd6f5906ebf692871c1dffa58f2d",
+            "0x1f647b169a031caabe3a931d0124255318d2ca0652358661668e9a09971ad585",
+            "0x30245408cf37a80de467ef2195850e976c04c92ba21a284a9f27637a3cab8f05",
+            "0x1bcdc506053197d401ff85d8667090c53020b5ed468e7fe1207b5c0a04d830de",
+            "0x02d6fa09ac2fe82a298c0b3a9a88f08562e75d0a6867b678446c3bcf47c636e3",
+            "0x12692bec02b03020716155251654454f8bbbdb262aea08a0d2ee2aabf626b94a",
+            "0x0fbbf2ae77273f2bde01e1877a86009f2df041f6d99820bb5c637837800973a5",
+            "0x073f42ca3f26f392ed092efbe32bbb9115b7dbd80624093976db6017ec0f398d",
+            "0x0834d2a628c5568c18c7ec00f0895efa03400dbf4b94ba3188a65519c89b5467",
+            "0x22561f5360ec007a4a22ebef8f66a694451eadaa249eb0687466b9c1b0f8295f",
+            "0x14305a36b20277d39a4f023b27228c8b27001d138780ad5111a96c787a0ffac0",
+            "0x267b37f1eceae52da5fbccd925445ea7e9a082349c04111d02cf3a93f6f4016c",
+            "0x11b6c2e113c01d8c7872131bf722bbb24f768d939aa4d712b5a228ff548df262",
+            "0x03fc4b7ec2555146d2ff4998a29c0da871fb47b56e307569b7e2f88fa1fd6527",
+            "0x218c4868a8a02d375fcbea9593fab44ce549f8b85b015d6d2e04f4aff2cb86fb",
+            "0x28bc4835a4b72455f5159cdc7b68548d4aa4c731bbd31ad6572035dcb3bfdf53",
+            "0x017bf892b7be2bb513d8b057112076164c0e22be972284c6516ed815e163b139",
+            "0x16ea06b0e930c151efc5b13f908386c71f25b85f03ea7900b9f06c0aec72bdc4",
+            "0x144041e7b9c0c9e152caea73f7a0dfaaf81b348e9ff8f387482b94e92bca7196",
+            "0x0d0ea977e5faefd21bec1adad91d1d65f98dc0a68c5e41591427c2e537748b15",
+            "0x0e8f04f6e6f3df0761a2e5f9351bfe4e6f471dbadd2580a370c3d2bea7482726",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x18cf02e9c495f6564969a5db43fdcef7502733a60e491e491963c138856905d8",
+            "0x2281b96bcb803be69714be8db6e630396c8c39533c325fc9fc0717027216e737",
+            "0x1de6436d77330c145303738be1ad8ad03e50dc8f41b6c3da2fc292a633973a85",
+            "0x27afc2195ac7e24fe8b40c0671564fc1db428277cbf0ef7a43bf986c11cc291b",
+            "0x1c6c6822e276cc0dade37144e35e32ee4222b4e80fce2cb76de0ad46e9339a94",
+            "0x10182f5712fc63046cb11a9b53949da30eaaa128fab719ca2e2becd6b286c14c",
+            "0x2d3969aa72c6a61591ede0c4b056e075d15cb864ae8c17a7ee8f6071e136e9c9",
+            "0x05f14099f7c942237eb57231c9dcb93d9c45185c05fb7bb8d7bf04c14ae276b2",
+            "0x0d8f3a0e061c1b8f3d5a4a8d23c9bc16a51c9fde93d8b94a0a9c01bd52cc0ace",
+            "0x1873e917f7346194fed5cf9294541b9c32e5ce86f25468dfda2c999bb3bea581",
+            "0x1ceb7b618d0a3d4f8200f725765c7bec87a92546a01a4da2e655056a8c33f83f",
+            "0x2a9be73216d2bbfb5d65f0244e438b2d732b8cc559600ed1318641b4ffd0ae69",
+            "0x26949efb4732121aab81b7bc55d9d531fa84d809e62e5e562f5a336ff88980db",
+            "0x0382e76544c646892cb65613b89bfa35425bb9085fe5e3bde955825a4037b744",
+            "0x097e2d35355d7c34aab79e933ffbc268bc26a1e5f60e9e38c9b4813eb699ae0a",
+            "0x27d1cd0d8a816ebdbf4af82ebdf9f90448e864944a350d30270945a93157d7e4",
+            "0x085ab82d7a0f59173f984ef64d51e22a8b4f103ab6971cac88b641a54848c677",
+            "0x1eb4964c8f296bc74bb904c6576bd98ed3dadd9be880246a5a105fe31ee1bb1e",
+            "0x09aab465f7c7592024943119d3731e543247d2058c9d727707430ce4804ac6f7",
+            "0x00f25981b5382d2145bcd8d27f3436d3df75e47a32aaac34c2948d708a2617b6",
+            "0x1033d710eee2ec65d20360d84dc4222d1631e78011d22d0de3487b2a8d302bf8",
+            "0x21206338a9a5e8c3689b0c7a57d70173523691e9e0631ee3032e82f4b58f9f63",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2e479f2efcc6fc4211e263ef6abdc7671096f47b694b5dbb492154a9d88003a9",
+            "0x28584408c721582fc2c3529f2ee5f45810826563c1fb536968d2fae48065bb2e",
+            "0x1a22ed8312786d8b2952267d75985c8438a9e542c280e421072ce8a5359cc03b",
+            "0x1bf5ab785d9e4edb4e3f370c46e784aab1b986439b967cf22011c4f6d9a3e43f",
+            "0x23159f9b645ce18d3d30a4716e5ceb33ce96ceb2f992d93fdbfbec862842c8f0",
+            "0x2a69923166b268c70e1ceab6981244050db01bdded39393f0f04a0400cd7b633",
+            "0x0d1959b878301b18e83154b082355ef9c62532961a4d07fee6cb1abbe69f43fd",
+            "0x26f85037ad02f7485aa0ef2dfa434ca1e91e808e853a11529f67ed2a4cacc710",
+            "0x1ad12f7aa6162c274a80ef1702c26298511ef4f3d874f0449c26c48bef59ce6b",
+            "0x1cccabaa0e338b5dc4aee1d4392a0a11961a2998cd5065a47bdead5ec6652d67",
+            "0x244c6dfce4845b62dda2ba75c3ad4560bb94008020594fac25d9771566c3d2db",
+            "0x2c6f0a058c35f169da73b61c0da4ae008cf4e326f8f3d32e6450f96a3861adf6",
+            "0x1c06aa455b6e655c77778e8511acd567acb0441c46c5fb8e18f7e1d64b3478c6",
+            "0x24c803fa2d0bcd5c97c1877b9654315d0e68454f4816510c78c270e1bc08194a",
+            "0x1fae6cad050ef9b791c39b5a389e28b742f1eab685a8ada807390f94e1685fc4",
+            "0x1b1aaec1b6596864146b6fe156937a5139bc5ca7923b8f95e69dc47efe0b1b40",
+            "0x1576a7ca7575bf4423833ee03a6c6d47ef4a5f1b2d3b8bb0499447af74aee609",
+            "0x109e1b3efa6a4e858e2795cbda4a22a6bca5a8c4d638e79f9e27c1495352ff0f",
+            "0x0c1d0b822acbf45c6abc6be8c8a58b7babd7b00fcc07a61d16287748b7a48bf2",
+            "0x02740bef881b556f4ad57c6addea0ab512207054e2c37e4c407a4b7ccc90179b",
+            "0x2d99868faf8e5921f22dc4cea9c7081e92d824c858054c6d488d5702f16fbf3c",
+            "0x0c1c186616446959d1576d7135eeb6140739b9a5e5222c5c28e50f020545a929",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x257aae94f2c0d8dc3c33f98d1a6eafb18c52a2c3af876bc24a8f599aabb5931d",
+            "0x2e1aff9f3403d1e9335890d123b5763d93616b516ff591806c0527f0ca423053",
+            "0x290b935e4ed1697f8779ff0e621cc75a712d09c323cfe3fbb128fb4d61be4cb6",
+            "0x1d14c8cfb94ba740c3439770e4ca3e941fd2c669f64e8ea44b3911f6367d53bb",
+            "0x200cb3a569796aae29acb8f8fd5ca34c66af59ccfe04b465dc4ad191e15d1865",
+            "0x0b40a99c4ed4b5c819e1bd08f31f1cab3fda556b2394505ad4830ad8658bfda1",
+            "0x102b6d778ac968cc4ff427bdc21f70a2e439b30c43de3c31d4206277514df397",
+            "0x2ecc8f520c6ef956b9eaa69b747b3de594bd984a7bf7eaee7bb66c52f68ec773",
+            "0x00432aa1311fdec6ebba1cdbcc0084f5dd47bff6f662af8d3ee18d0b408367a8",
+            "0x15b16211d44420776555c11bd9bb486ca658cf2835c1fd94028cb851224dceeb",
+            "0x0911475c994e82a114e774dbdd5dd35137e3051beb9837f248121a033f05f491",
+            "0x1e38c97edd7bc03cc7ae111020e1204bc7732a5739c0d57115be7ac1350a1139",
+            "0x102e45918514ace5a0d6c372c0690ebcb0d791c21296ceeb47b05398ffaa22a0",
+            "0x299cb5db7d8be4af45d1756943de77a33b1112faef11aa74f8c184487f4aa81b",
+            "0x2597d42b238310d8c847add6fb592e4f3d31c04cd5f605f181887fc95fc805ac",
+            "0x0bce6da8700d616f829264fb30fd5f5be2aec0e601ce7ca8ea9323daa86cc041",
+            "0x2e443521ba899737d02e5868b6320951250db529a12c0a77491a527427405c75",
+            "0x10475a0a28d089e49ec25b0ecb4c211a9531726dea79710f38426dac4da6fdac",
+            "0x29c251ff018807f427fe7db7353d23fe768ef6e01f486526b354ebf4881fbbbe",
+            "0x0966fa2970c1966d014b6aeb373989840d47e694fcf68a57d07523e75f3f54f3",
+            "0x1a4d04b3e4894e81f69d0975919712cfbea52e065b3043e3cdbf03b82177d25c",
+            "0x1b59f625b29903412b5ff6b2bd7d0f59745e68b30b363c5be00fad752ba7ed0e",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2a1d6d079a0a062261b146cda735b6575bbc0b92b5232a6d57c97a8a20ad7197",
+            "0x20f47e78e4ed5a5f5cc20682985941fad43b18df346ac2b26ccd1f7f73cb257d",
+            "0x058f756cf98817fcdc723c5b54db81227dcb8f9e5da010cfb90b24d736378e59",
+            "0x0d5b72d1c62131f1e2a732918b04ebcfbb26cf1b702e6ac4a814a592a2d71ea9",
+            "0x0ee06e04086a78b573cbc4bb5fc442d51a0de03d0a9db18fe5e55c4c9c7f50df",
+            "0x088b7191ce135e48323bcb5ec322b2ef6d1b1ad61df99e5cf120dc4826610deb",
+            "0x0cd976124b39cb79b7f0162c3b3f4a06bf045ecca9f6fba3a9bfe9cc7d7dace3",
+            "0x1a440fd75961fb4b50e6c0b1ccc7adb77290811755a8b5cd23f73866fcfae85d",
+            "0x2bb63f1d006ba0bd6818ffac3e66be3affe1dd1cb12b2bd8668ee54cc3744ad5",
+            "0x2e62af54a6f3395b0c0aab5de7652b4a01ab3b671df6b36a95135c2e65595872",
+            "0x1067c535ead363c36ee145464cd1761b09cbfbef3294d4a2f0a5a5a9c2840c81",
+            "0x22e7ef08bebdbce59f7e437aedd271b80c1da4804b7b2a8181db17136827f1e6",
+            "0x198ff70c2571e8416f47a1d7dca12398835540cc9839d71dba1a6446700a050d",
+            "0x1b4e09a70802fcf52c719e8fb5ac9be0df0c954188aebd37081930c00dae001c",
+            "0x10d41ef53273c8d1eaf88978da083eb2b9e5d01b62b1ea32f5fabd1f391e330d",
+            "0x08056c79d047bfa8c87c5aba3f66d48149f59166d44283922d00992432e47d5f",
+            "0x255542b954dd8142aa1c408c89513c191abde8bb5e604e2b29e1d61a6e1b8164",
+            "0x2890259cd9ca21a37c78a796f56f6db5116505b313561eeff0c6838170842c31",
+            "0x1b6d5f3769fb8bfac561940c1ae4eec550dfc1a58cfa533889baf8bf028844ad",
+            "0x2e2f830da98325d8eee924ce01ca0cccdc50b302046b4566f70e361d3464c3ce",
+            "0x0733240e901dd8673c822f249c2d754e1dbdb834532831da8abac39beef3f74e",
+            "0x28f7fadcbfe799f4178121f4f6cec3fef562f90086a426d8b82540514bf9366e",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1d16415b969590d0898944d52aa5fe3f82cfba4439f14a61dbca9ad0e2172723",
+            "0x2ad588fc59ebbf4f58b01df19ebae9185f110b7032f1343dc986cd4f107ef2d6",
+            "0x05ba889d42622a5cf97c927d61367a42fcda5027c70dc79d68e7bf063202c667",
+            "0x0fdf6524b3f75db40c58ec9d4281f41b6c754f781b207796d8642308b4ffd992",
+            "0x0928889020e575ce5859b7ac93ea258231869eae58c3f4a914ed88bfec02505e",
+            "0x107e0a78b7dccfc5f1514f4fa8555cc664fe448137185ad7a9d2b50ca3979f8c",
+            "0x2002a206ee45bef09d0535c33d9646787ec4047a48b0212ecd24797001873985",
+            "0x10033f46e037876af177fe9c87fa608b156916af798c42dffdf3dd2c3fa966db",
+            "0x2bff8f2df05cfd302a6e190ba40bfada55d4cc80ec4ce8613a5a7418318e2333",
+            "0x303a6ee4d587c3fdd8964f1bbbd0ff21067f158b0074cfc51f5c8e3bd9adb5fa",
+            "0x22291c456a4e2c764b603cb99ab1dfb81a8dec41d16c3668379cac2c683a9183",
+            "0x0a9a986fe0b8b867667120c64731bb216b10f94df380de829253442acbedaad4",
+            "0x2db47e6db080cc06e0ebf83c08aa0f455679e1f2b5d7bb41fb3c014d88895d91",
+            "0x288e8abfb84f3818cdc3c277f11483aeb5a858e6715f5796e6e233ad51c67c47",
+            "0x184f19a0535099c01b692faf13ea56f2f481dd2a9935038b15a6f17562016546",
+            "0x08100f13c8aed41363c947749c315c9ae10c2a49d055f29a7bb032a7d054698b",
+            "0x2a62099e1ca31b8a1013abb65327fcc167a407d31b344a25df2b7fac8ee46b29",
+            "0x23456a440d14da7e8c66db0f13f484f62e4a37362ba35bb2d87c7ae16821963c",
+            "0x18973522fc68cabcc959df281abc7737da3c3e0719702ad8a160810ab93767d9",
+            "0x1bf27168d84c919a0026700dd9b298b23bd9674ac1affa454bf20c95a7932aef",
+            "0x01c5b308ddbd70279ca5753c0c68cc3637243cf5bca633588b3eb489d1d448d4",
+            "0x0b9b3671e62f34ef891fa773b74f2f17f9d3f517d1f85659d0e521c10908befa",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1483f16b75f5218e18e2284ce9797527515761712a2b529e58f02ab5cc593d44",
+            "0x15566a034a10242b73083e982905c1c0e8acb4fa6da5b62cc464d009954eba42",
+            "0x1afdaeea12ed6de0665a0cfd0222f5bb4d04bd99060e4e63de37f2720a0ffede",
+            "0x1c8a6403b82126c654d9dc3c5a19c8d332ee76178e99ce675f8f7e1c39deb869",
+            "0x04ef8c587d817e1d4bc69230a00001eac2975c50b4734a01ebf4d27dc4b8688d",
+            "0x0df7127e0156d97ea996271af1aa146f78e71dd9e1fe4fd821e14633a446f6ff",
+            "0x1bcf569410d36c2f7e35e279e5ac723cf2795a3aaaf3717ed67617670519f395",
+            "0x2ed4bfd8c96b66413121508df8e69e67d304dbc7b0303c990782470888909b11",
+            "0x2e179a91eee10715fa639a6a480af8688237e700ea99c0bf1af985d1521dc2b9",
+            "0x1dea555a1f51ab0f14cf84332ff53ebf27eccc4e940f361ea64ece47be1339f0",
+            "0x2edaab05b16855576882e6412e551df6ec26dbc09da274b21e3edc5bb5c35277",
+            "0x074531a5e0563c63a69e25312fd2545f183f2933348ac8a16aa7c60c30738132",
+            "0x03b4f96e77b9cf78a3efad302d5edd187f1caceac91383b040d832fbf63050a5",
+            "0x17cde39e9129f30dc860533aeaeb4590d31ddebd54ceb827f04bedaa208b8151",
+            "0x2d7938ab8a70cd848585dc4526154693091f4b65faddf30b97658f1a641413b4",
+            "0x300a6c8ae5e7416d26a51fd0b39febf2764152f2d0520e338688f52cc61a6eae",
+            "0x1367e79c5078712185e4aaeaa94bbdaa55da30180efca9fb7203313c5c2cb60f",
+            "0x2b71e505deb1ede31b2a577f2eace4d7aa75778ae6c7da7cee5978bd39247d0c",
+            "0x058283398c75d500196be0f2124797df169696fdead4c70353533e8dd004b3ab",
+            "0x151376e38f27831793a770592c406ce55dab2a6b7d87d6cfe17508330168c563",
+            "0x1e70f0f0bc52c739091026fed1af1dffff7576cc60802ec43aa8935659a6779e",
+            "0x00cc7dcea639ee30a2479a0a49d18fc028c5e17eb287c8a802a0c0fcf019257d",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0c493fd3501772c96977bf897ba7cda6baa42149ce893b4837981652c0f76f0c",
+            "0x02dc897909fee975fd5533f438d246c2a8344a9d21c9b7c90ad58d6702d4edf9",
+            "0x1e7c029be213babfeeac3cd2ecff32d0e137a87f79bd309e2e353293228d9ebb",
+            "0x1afd6bbeea904741a60f8d8ce613adfca3130f07cd556d5ff241b37c253ede30",
+            "0x19859b68902b7163e3f2906873292cae0ff2098e872c2e8147d6954525bd4c33",
+            "0x0af07af7a902e05be32dc767629ea62fd82a590a77945ef1e6ef57e4dc2a13f0",
+            "0x0c4d6a98b8f155cd165b9e68bf716a610131180d01e3dfadbf7e6e2e6def0916",
+            "0x1069a53091de93a4eb31133e5a96d7a5285cff5e97537c28611ab0bec1aca1b5",
+            "0x2735ab312a920a48a4dc39bd2cf73c5fa7482c1f1897eba45fbc7f56a41fb635",
+            "0x0e0ee5f2d791f812ad867c9135cd18f509dfca9984ecbf17cf5110d5c04a38cd",
+            "0x1bafc2b2777b4009e89bddf190c7fd2f9ef10a72c58e879f830f076f7a1665a9",
+            "0x1dc94be9a18ff2645cba25dcd00f2b16734f7890f9b40b072944395bed1aef97",
+            "0x25deef74ddc9b279f46a3c340d582b324e1b28259f041299f5f542845c9b4abf",
+            "0x248f6728d80eed8466a1aa2b2d661b56743f2a2148358e51c144a04d13150f1d",
+            "0x0b3df30c3dd1b66596831fef6255789d11b967d692db3e6579777c0c28c96cf5",
+            "0x24361115e0604b4c83962f485550dbff1107882c2108205e9035b03542047b1a",
+            "0x035163680cc343263aea7d91cce55c59fecd1ff2d6c40053e3a5c5814a2d2c29",
+            "0x027f065cc8993d1c1834ba2b3fe635f1d65e354b62b510e3f96429b285d37cc0",
+            "0x214cd556fd50f1b340de7acea76ab2e6aca88878e910483ff8c9adc25fee3390",
+            "0x0e43b81a7cea99945d414c705869549caeb8faf2a9a11937aeb86b81f93c8451",
+            "0x12d50f77f7675d8a027ad1389482986bff1cc79651e1c17bf65325dfc40d70aa",
+            "0x2e8ec1002d396f75e4530167d868dfad2bef51baaad6b8fcbfc9bb1789055623",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0746e92afbc3a67df1a8944a772a9a97636cb3eb6dc805e28771b6790f5e5b36",
+            "0x2ade3f9dc0102b9f9368cd4a8e00da0f6e1859ef053ad5953d250c17c630ab9a",
+            "0x165f0a44eebbd423f042d1798944352125979a55799e8cfb13c2b66a33943e72",
+            "0x0162b91fbce6e8cf14b4e97f5f02e6616fc2282826d341dfc2a8a01e17b06387",
+            "0x05de8127e37036bdb2ec06fc0ff48a32f8887ba995bc84474134b15a069d02f4",
+            "0x24710c9ccc910f0ed876f27d4fb9fa551454acd1487d5183e18100660816eeb1",
+            "0x17309881d600b2a8c81cdcf407ab02dd919cbc5ad55ff4364ae091c03d847e66",
+            "0x256434da5b6a0392fc1a2377f153efcc623d8c98e65e40d2babd17e6859ae1cb",
+            "0x10173c279528a160ae10109c8f4ff9a993d2bd9b3762e09eb684d3a390fe2ea7",
+            "0x25bcdaacf046f55c34648540910a53872b80d480c871eede0a3b34ea6dc1d1df",
+            "0x25d5adc955a43dde24b5d9cf39de4c0ff6beb5165b46eed417d1c5b687ac35bd",
+            "0x24b2a9007c878f9bfde64b025f0a6308cf2d46a7ee831db33451ef20dc9f194f",
+            "0x2feb8327368efbbb7b483805b2214b6110127cf8c160f1b5f1eac4258ac05ffb",
+            "0x149b84d72a8bf2d968f58fc90de042d7fd03bb8c76eb91a0303ef18e903b3186",
+            "0x10d3567fa659d1de2c076638c9e2b755519c05248277662d1a5f2fa51d9eb474",
+            "0x0edc6021bea39783d6073612fb60acf3fa7c009c46be8ece9ef0501e84872782",
+            "0x16231316ff952e22be2c07e1fe8572bcb10562a1f5c2f0f812f0329b2415e03e",
+            "0x23da74d18e3eb55233c75d012df245b164cdb78cb843aaf1d4cfd691501d6617",
+            "0x0b8cd95088d925f7f27d1621b0aa30c2d31148b135bb99c9b65fcaa82cee7b0b",
+            "0x04e93e6963e306c93612fc035defbd235577c9c86746ea73955d83fc83a9c450",
+            "0x0e4f3815e1ebaaa00fee625118df8e991c173826452edd9744e185ea6724362c",
+            "0x067321f24cccc92976e4c55be334e9a4c0848e65c967581eb296a9403ce575ca",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x17a22d0cd4f00629c8bc6105faae4b14a324f3768012c86916865b4496bf7000",
+            "0x0a57ba0d5cde83fe36c409de00cf8a39caffcdb051fc891d1d8c31a95b3c45f9",
+            "0x1f84702ee514daa64fd6cab541f3f8951622865d32b0ab70188f051d02fd84c6",
+            "0x011878d1914ede076f64a72927341e7dec515e4a2afa717571fb97efeb8dd812",
+            "0x0911d7d2b977d11038aeb739e1e378ca617c1a56fc17a1dd144812ff66830666",
+            "0x2267600e83b00a394f76e36896f200e26c7d8cc6e66d31c7004706c99677c26a",
+            "0x070684516ffc119a9604211acc1ff575018a1bdd1f6dbe4da2b043202fba43d2",
+            "0x0046f5899181663f580d394985290f9e826de76d7d898f0da2d698a2ef8911c6",
+            "0x17f08b33d6d63426130edd56bdeafdb08e5bb7dbd15ff47364bf526afcd5c53d",
+            "0x0d4c52fd61090696f8e7942dfd196d3bf1507c6871b49896fa22dd0d52c4a560",
+            "0x14dfa8aa60b751e113f490b7d760ec5283afc08bb7af22f7ced70645c261b610",
+            "0x278cc4fc81fc35cd4b481c5bd9615143efd27ab55c7d2fc9361577fdd51b55d8",
+            "0x1521c5462064912a29836571efabc68579d5f24ccf9407db619942b3ff0bc43d",
+            "0x177c6c9d6ff5ba82188bf0bd030e3ec015134d9709d8ba28d1c6ae00add2a1e6",
+            "0x0e19c999439543be706d1d947adc665a356bf833a4f6a9079bff750d0ace31d3",
+            "0x1930f87dcdbe271d6f4d4bd4f6a1411dd96d863cf0eda0635681bea95d4d4780",
+            "0x129dc4ee48260b85a0ec58f3727aa24af1b3164a7fe797daf1e0ab4dd9dcec5d",
+            "0x27f0559b3db76ef5f01cf3cdd876b9f5c5c88c8e653bba54a40539522bcf123d",
+            "0x2d4f8e44115bf290b273e16a15a934b9da1c79654da6cf7e674ef19f16ce617d",
+            "0x2e5bff278ac86a5202177338b0cbe11ce3410963b4ab9ce402535bab9d05280e",
+            "0x08db683bd7ba22740ecbad71c3c88ab10283b6378cc7a99a70aff359de6052f1",
+            "0x0d97482032e4bfb70dfc59874755c39f8df0b4838c7a5a9439a95fb7d25b731c",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2bd1e16dcc725dc78e335041d5f71044bd854f775896960a0457b579c07649d5",
+            "0x1219d07864c70e6a6a3a984938bb39f3ae2e1e968bd3baa8ec08353045ef4045",
+            "0x2db0f051efe3f634a0d5fbea6acf57b1d5bfd3744d025e7fb29ddb5800cf245e",
+            "0x1695536eff02aa633b472d98bbd7d58b189ed35cb79b3291a6a63bf033d2ab5a",
+            "0x206a94a240c1da5e420e14d33c1bc2bdd4b058ca7440636611ccece06cfc4378",
+            "0x22a1b044ff48a239e4e59c57e3a252aa72d7f0110cdac214d26ed0c84b0c1958",
+            "0x15e00e696d3da0299043e29b2c86de96a2011a2abc59b8677ef1165371efd849",
+            "0x2893d3ccfcc2898ad123ea4c349682b837e3bf04c5e3906a0cd2c748c87ae026",
+            "0x02d8f1a5614e7d60f843ea43bb60e8553e820a82363d37ecfd538bd34ede456f",
+            "0x303f2976b5e84a6adbb78eec47b262f950734889a77a8d1fe5faca0b3b82e3c0",
+            "0x02e4fdbb868f385698c648c28919db92dedd963e73f2b875f0f6d39d941b6e57",
+            "0x23dd079e0e8169028a1ffed7ba6043ba3fe5ca83bcff5f26c390cce33875bf2f",
+            "0x2863a98dcbf66d21bedce27c50e0af0c1cc1d16c3a68d5f3b2bb582c3e15e9df",
+            "0x203dbd529b485c4bde0da48836415d18fd65203536793dac019750efcdb2d328",
+            "0x0dfdbc864e21c0f5cb229dc0269693bfbd9221cf0266f0886e4e82815168afa5",
+            "0x0626af7eb2537f97860eb3903826aa3a1cb73cb9fca86220df00f3d381d5d688",
+            "0x0274c76e5ba9203fc8818880b2bfee782af72bd74036a6908bcfc3f814fb9a47",
+            "0x16ccb57dc66271d92355c3086f39b88d39730522f4fe96f57b7cfcb46e51b34e",
+            "0x04146d780ac7035f6e895a9454636bf414a89cd19d89212a6f9457dbd813373e",
+            "0x1930bfe81781e84d5de1ab29531a20ec6b66e908298857c8d58845eb57502d0d",
+            "0x2b690dcd4103b637f8796ffb73cd391553b0e247c7e4d2a9e6cc34af09557955",
+            "0x04849a8b5e07c3c4e8a005efb318b2afcf81d5d983b9d52039dc7bee8232ac99",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x057c6cfb57c3f22637c754cfc55111fb00227dd1ba6965d3e36f5ce2460fe716",
+            "0x1751853ce3bbbb41cd2387c69f8fb2d4538449a5b5ca6cb40b2e88491ffd4ea3",
+            "0x05fd3971965e35a568ebfc050082e4d420dc64d41db39c0f0f592b42bac10b67",
+            "0x19fc58d8ce40418dd606ef883503aeeacfc1dad18b05af60cd5448c282bc91dc",
+            "0x06a181130d567cc4f1064080f4ea0cbcae2bf91a9d963c6dc591879f6fb9d17b",
+            "0x0e6b304dbf2f04b3f9abdb9d5a798b62a49d2b8a6f9ad1392518d2a85710ae9c",
+            "0x00b9e1ca76d5bded88d01816e9576ed2ef4960cbf5350e0ccdf3a93d48b9783e",
+            "0x1872e922a6c9ac05ab0cf245575a12c6555a3664662fb4625e7fa8020111c343",
+            "0x0d88274a82e599950f501134736a7604f395d488fb76ace84d867ff1aed5785e",
+            "0x2ae204f6c7e0d1352e28bcd9f1d84e5f99b39df756de970fd44edab5193d9874",
+            "0x27594c2a26513164449139f5f46d77e86b7402534eff9ef98ba8df1021eabb7e",
+            "0x2896d83406f1349cf7d4ebac9e9cd07cadab2e94156eb8cc8f94914967742863",
+            "0x038c5c41f224efffb2b8221941aa134ca2801eebb62ae1bc13ad8cfd53bd2131",
+            "0x2d639312ddf5f0233a849be4ad6ea71e0486a53bbe9f1e3fa2069e716123c2bc",
+            "0x2fbc254ceb687ae37e363ec8e45940dae10dfee6d6ae48bc69e8b49b61e663dd",
+            "0x1b6d4e935635364d0d3485036c5a14d0569d939735dcbc982ef4dccdc8cc4596",
+            "0x1ffead77970102f787a6fa10671aec7b48157a41384eeeda642cbf6ac7450dfd",
+            "0x18d79e58d7358ba8f7dfdf5a1e1e0e424dade988db189f4740fe4930566372f9",
+            "0x17f4f70e97ee3ce619a00aa731df4d4421d273874305c0e184a2510893119303",
+            "0x2542061de0a8958fa2e2490a61a2738746d739f8cb45129d661d42c1039b2989",
+            "0x1b73c0b03dfb64d29b97e10b747f295163eb54e4282118df6aa03e7a7f1d9aa5",
+            "0x3004ed3842d173fdbbf26466bcd58bc535b20177a44fd2bf2ea6933fcce66e6d",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2acc50077fb3c138c47401644d10f7cfb50b47e4d21c0cfa845137199cbae4a1",
+            "0x26290b127a83b7452392bda9ff472157724f597ffe5f51c12eda70ab582e152d",
+            "0x1a1d530b7a6b54e2474fb63f829f9c56fe517055c50dd09747fe731ad3bfc3db",
+            "0x1a88f62677412b7afccd7e969b35a0c7a2da949fb5373c6b841c5eb221222210",
+            "0x03dbee624f412ac9f31922ffcfb2ff5a4677ff17cc6669d5b63046470a720c20",
+            "0x11532274696b84aad28de56d46941d99274a4a048f8c1500ff730df529342d42",
+            "0x0ec20080769031283f5a4184105f218f195e46c1bc66e3e4b9f4e66d5b718f0d",
+            "0x16e07903bf6341b740f8f364075ff4f818cb419ace852332d2d7620c96263a81",
+            "0x284a2f0d0dab466575902f7c93115576ced4ea3d8e284a5aedfbb434c6f3576d",
+            "0x14e8b6692e739bf4cd8f567222d4baec44ea04a003015db701d9743d6629bfb4",
+            "0x10422311ba88e525d7968e6224897784f01582a5c950142e19c3af28c1e442d0",
+            "0x03954da4bb0b4c065ed4ba37a34e9cc0b328e02fcd766229fdf928ee181205ba",
+            "0x2b3473944c70c648f070197fd760060c9d31230d49518771b4029c8250a4139d",
+            "0x2ac2ffc58d021330180594b7d1ef29c73cbcc12661383f659b35f49642990f77",
+            "0x2ba520c75acda71e0f486d40aaa1201cd56d640190dcd06a8954f88910cbec2c",
+            "0x156f94c923e11200cb83af5a69eb3dabe0169007c3df4a185c72c45aff830a48",
+            "0x223709fc7f25c7b1cfba1a2e1a72c7088d018fd9872609a4ef762794f964844c",
+            "0x130d6322278a98353194131844bd868879a984aaef4d47dbc97aae0f39e11a79",
+            "0x2aadde2d3d95d0af6f8672a268339b9140cadff4ce91ec156f43124dcd09e7e4",
+            "0x23ec93525e6340640912428d05bd693e564fbebdc83a18f1940adbac14bee506",
+            "0x2fa8a63818ac6f10f9392bee02776997601d9187f668df7c63b62dafdf375740",
+            "0x1a32b11e4a14a895becded29b9496c0e802c90fbc885a8fa273da0a1e93f1f77",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x242acd73ebdfd8f1b16511af809588bd449fd50d24b77e3a18081930be5afe19",
+            "0x16809212aba7098d7eae66a9378a3fb9bdd0b627131d839e3497f00934861148",
+            "0x25072a94bcdb21b0a357aef25744d00f13979b152fd94164434a46b2924b9d92",
+            "0x0ff7b889aaa91dfbb939929d2e34b1337f6a6ff9ed80adba59bf99dceff9a8f0",
+            "0x02f9fa29eb60372995e48a2efc0c01ab0eb41b92f37ab93d9457f79f4570ba05",
+            "0x0e39ad5beb177b5b9a10b033e4e2b4ecdd6d86464155e547ab50dac1299c9251",
+            "0x08ef1de40b4e595ecd10a7dec27c5d883b746ed725676435831a9a646b7807e7",
+            "0x0c976aa405117447e3b518a6b1e2bcc6f34c72bc6e6b630a2ac47debffb6b69d",
+            "0x26319be809af4b9cdcb30bc3802d2d05a4d7d80511d12da06d879648a83e96a6",
+            "0x197a244825685b754cce127929b0dac8407c19191d92df7e887187d2ab4e593d",
+            "0x257f7018924eba49090c8f5c45dbdee3992de81004925b8ed4807caa00e4b3bd",
+            "0x14e2e6ffee0904533e7fbb1a95410b01f3803c6f937232df9c76e7b3f7971bd3",
+            "0x19baea497d502338d1ee3dcca0712407bd0c2e0566d36c470f03c5ade9c34b8e",
+            "0x0c793a59553774ab97c01ab229b55987fe2765786b9e9c5c8cdadd97dc144c49",
+            "0x1ba5c105eb8c893ca4e0132f5c49bd9acb3bbe5b508b716e499ab4ae976cccf8",
+            "0x03ec3478499e184924d2a6650df9faf01f5de2bd18bb1db1e8ca27ac38a74777",
+            "0x2019501b15e4fe0140e9bba3426fa4c48231984be6004428f527f17a58db379a",
+            "0x0fd860380903073cc588373c9882cd822c00cb08567ebc39f11bf1cfdb449c03",
+            "0x17450ee9f2606e0acf64003002d31a8ea48a0baa804c09438df46163b391e9db",
+            "0x1fc3e59cbe616f508f963a36e79d1c8c1b4d58506303683f01861c23d5861e9a",
+            "0x106e94b814fa60beaa646d5818be593e6796af806c1b8cdd8eb49bee8a0a3d34",
+            "0x173b0aa508d2f70eecbb665f218cf8d356975630592f3acdb68062d21d6a9c35",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1586cad60129fbb500cd57ee46ed8d9531fb8ffeed59cad7a8fd38911ff0b92d",
+            "0x265042129bed6e2bb63a161bd02a6738753bc89ba66e204a236c68b754ce0a79",
+            "0x22bf569381351118a8079993f2f7b73ea13dee0b2ff9cf22f12f08306098e3f6",
+            "0x1f8c5e3aebd505dae0415cc1b06dae5b97cf15ed573f7084fd412b26a89f0f02",
+            "0x03376992b78af5a4fab7aa857d7949119c75553c8e637a990e60303f7808a3ac",
+            "0x25745f2ed383a1af1d9fc7f49db1bef01deba8e88413efe1c2956beb67e96bb1",
+            "0x0f059ef9ee065d2d043c4035ed93aefe5e3af8df6170483705aed9db8aadd567",
+            "0x2eebd99010ea2385b352592e9fd463b5c0b6a583a1f73a3cb270d6bef40811b3",
+            "0x28ae83efd79c9a8109abd73b92393e80ba619538eec91643e4be66d6d1ba0485",
+            "0x11369a76085851ba7dbc3d4bf2818d7e230792d569cb32b508bb382c6b5886fc",
+            "0x2494603030882e6731018f9211009d28ba48b7767e970c383d365a9815f61e9d",
+            "0x2014a20d21247c79d74b24b3f07d8a41985d40463fc398a9c4ab9492acc5d77b",
+            "0x05bee2d5dd72a45e02a1006e9222f1665d6ab33844c792e47ac0969580235045",
+            "0x24521b7b89e363274baa2104df581343cbb467d856daa9ccc6396bd724d76227",
+            "0x210893e476dbc7ed5e802c9119b49aef7c8aa3a370ab3ed75ff44f2a8f1bae0c",
+            "0x1f61e5f7cd48aa2aa5fc7e3fa3a70442dd9d078b3d000394b18d4101b779c048",
+            "0x0b468a9c9c539cb2775c8bf610e894aef28f7a2b99a75404e7ce0afac8288e58",
+            "0x1c65cb03abbe809e3d23c46a22f805cd8e06a3fc55be88e86e1bdf7d2116f805",
+            "0x1a5fbb5e69e73f7ac0dab3c355fb77adb025607a5cb2f93f04a35519736174b7",
+            "0x020629aa517122dc6e99e0be2ffcbb8e4f00c2947039b42804325765a3e416f8",
+            "0x2bf191f585a6ad894365dfc63742c3efd89f9e206cfe178f22b6ad46d3b40a67",
+            "0x2a0ef0cf8f5092ca4503792eeb27fed2669473a1c24289524f5be6d5c80eeecf",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2ef75bb98675070c205dae75c1c21a5226b4b4c2e8b7cbb14c770b5be04a3757",
+            "0x00c76a97294fc550c257ea039587afe291e8ba932bf610e2be713d3e9e4c3379",
+            "0x1bd076fd9012ad2a904b205216a8d4d423575fa83608167f9a0685eef0e82250",
+            "0x1118cd345dc12db9628d70a96a9498e24862f1dd4c1d012946f45f0e939a914f",
+            "0x2e2e2293ea31564725987a802f04014e9d56040b7b9c545e0bae02a73f7fe187",
+            "0x261a6595a15fb371b574988cf6a161755bffb9ec13316decc622a9e5de08dd89",
+            "0x0614565fb99495c1820856ecfaeb48bcc63d754e946995d2774cab817238ccd0",
+            "0x135dd3f30e85af657bda31a7d026d04693719a765b03fe48d9faa1822e33ab9e",
+            "0x1064e55a765c64ffe3f4974d297d2705bde592169e55f38f3779024d4da3a5ef",
+            "0x2be8c18cba947a3e98a2d257c91e7310e4a3d10a2f1328eb1d5520f06e1aa2fa",
+            "0x0aad56d694292c4bf5636bf574bad3f7f471fbcbaf35692e72b67d9543e21b6f",
+            "0x012f37262e73b729e642011d1a0d95db0c1cceffa894ebf98c761b091b5cb310",
+            "0x14ccdfa0f3fa6f31fed745c676c566f4fff6b9d79738810090b55fcec6dafcf4",
+            "0x1d231a2b5bf841ad376f920b6cbc4c4d248498b8ebf2066113a3a9cc187c7633",
+            "0x2bbc4148d99e78ad543858eddbc5525ad398bc983845618f70c35e206da191f9",
+            "0x0b9a654058020380838ef7cd90d5c61a281a31d74c9c1f2b286337816f464a5e",
+            "0x1a3db5cb286f7f049204d33ac3ace3948e800f43000e8a88bdf317afa25d46dc",
+            "0x0036c3d42661d60907a57feaa6c78ea3cc3bf010e5cfaf3ba19c5d4209d6d5bd",
+            "0x14733aa50329900b458a0e96f91fea396556c9014da1bb1bd080ac836036de26",
+            "0x0dc6be63e44df3f619fbad126e85ae17f1cbac7ccc3431d774faa045a89c949f",
+            "0x0bed33c7905203cfad6a78b69001270309c3e27d412893429e9df2a96c6d4727",
+            "0x214a13d610119cf37460c17e7201c1520b42d88130a1c9d3379490cbb5badff4",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2862c51a61a193eb4bee21ef6d86e93b95eb1b7785988b1b2fd298a07ccc69e5",
+            "0x2c3f4d11764c8d2c728ed19e39aecc203e2ed7a2b0869929c97a87c0f9aaae34",
+            "0x0d2a180e3677ea122535489a70c39899b24b563dfa5e0773e51061e37a1cd52b",
+            "0x1f1394f3e718edc738419a6f67feb70de0f334a3ba5cd320aa0d83377369b332",
+            "0x1a3295e7bcc16e75811024e1dc011645080a27988cd12b1da8c0d7d3741706bb",
+            "0x1c45e2493d4e08cca16052d8ececba208834e96d13a999ca61b0e9b9f827cb06",
+            "0x0f72b1145f1cecab41ecdcb34482fc00644ea37e5aa4854fa405a5067e6cec67",
+            "0x26fe8c7074fb323c6f8c92e940ebcfc32de028ca717a3bd874ad4dca8fc55585",
+            "0x102d30083bf7c6f1c2bea8bf0d36c75d3ede125c1075e5d11ee9cca3fe47a49c",
+            "0x1ea3cac38de4f3d49f1e522737b754d5ecc0d6a140874a7cd61bae3208e088ea",
+            "0x21018bad35e0e1bb5f97fb21021bf4748910df72ff08c652367b0b89fe67f588",
+            "0x0787481b56edb70640632aae99170ddd11ccf70554dcc8b5c84c219fb00c0612",
+            "0x1ba78a17e02df2a49efbf61a620732408a8480b6e256e4aae35e094aa035f4aa",
+            "0x251fd2d4c03fe5ddbc475e6178103bc7dac33a37dc6a25cc68b85fa6ec2cb46b",
+            "0x16817297aa59acb0cfaf6dde1a56df02bcadb8a89b67942d1a6b844a4c4f0a76",
+            "0x12b7e68df5dcc3ae92eca8d5a4fac2548e994dfecdcf3eb1a4f034f1591fc969",
+            "0x2ce6d2fb31bbf2834dc616622b2789b59f334a32b29b37aef7976dedba2dd5bb",
+            "0x0d110e3287800aed0bbca6570dc79933b969cc72cf967cbb7c01f88a228c590d",
+            "0x23c0d774c5d525d91c5b38f8bb8424cf43f56b08841ccff38b7b90b580bca475",
+            "0x03a7176a96cf7a5b44523b6d225f25c2726dd711694249f87dbac692288ebc76",
+            "0x162d2c6e82cb01b9a8f0838d04e159b835bfbb5d16169cd96116fe0ef1b6814e",
+            "0x0dc03556cab1dc235773445cfb7334a3551abbd647c28cb1fd2365a2dfbc2fb0",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x14bf700d8f3991f049a7337ec4699d2beddb52d2999b6c24ff70a359a1d56ffa",
+            "0x1e59bfdc9d1e0e99073233bb8bce273024ecd3393885d1fbd109447a102926f2",
+            "0x0d9eebb1864bd63d26d9328fabdfcc26a34bc7daf8a6543a89e9453254dbeac2",
+            "0x19af11e75d91777eed83a6809557fa65ee38578d4be6096ad096b51a4160a913",
+            "0x146aeb31b0c3d217cea55f6f3de93a7b968899d76fdaa6533fc4825bdd535f4a",
+            "0x0e3b532a0b2f4dfc3eff7af0743a47b9739822814b90127590262fb7bdb99b81",
+            "0x077a7faa72b8af0b238b54806d6a2ca2e3f9de07e5dcad1d6b195c7d222cb99f",
+            "0x1f47327c1f9e372721902381720e430c22bef37e5b1a23734330e4d03e43d30a",
+            "0x0adc1f8b993a6b5e34b149dac5ba0fd64f23514c8d763cf4dbbaea52b7eef739",
+            "0x2ac1dc7b58323a98e18f2f4f1ba7a5ca33a88e839d8d727e6385d907c641bd7a",
+            "0x0ff9bc8453cd9e7232cd603cd28460aabcccad3ad5549961ac83dedab58a3b03",
+            "0x144f62a411bc76a0d3036e97616966a33b171848f9492459593e217b1bbf72c4",
+            "0x072a376ad12b4006328e5031661a085b7f897525b4afd37bb1560cf141e81921",
+            "0x1b3118aa9b4933450a076221a7596f08f053c393a42d76424f98c27e7de291b1",
+            "0x2a3e789f5f04d27bdcd02a889c52955352629126c579a99dcaddab911f73c9df",
+            "0x19d6d4522ad055164c6c6bfcad4912575e18d67604b96a7622eb86080a7da1cf",
+            "0x11f4ac947e0c70bc6e53b0c853300986a42eaee3a2ba889a28431fdf615e9371",
+            "0x24b0a46bf03729b4f2509aff82ddedeb81e5540bf2196f8bcb35fb8f45826a51",
+            "0x2495bf4688de163191ab1a66dad657ff7965ec83a9458df6f30f1ff224b5245b",
+            "0x09c951ab6cd387a60826465654b12c66e5bf562ec812a6b69a1f5c7841cc495e",
+            "0x0d3926710ded9c92df189f72a13d80dfb7f52e2cd8225b087bc76c814877ce9a",
+            "0x24e8a49083f672d9657772f771c3e3719ba99bf53b2d846b19b082f433a03fc1",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x024d7ecbefa3d2c94dd91462bc9d013703481febf6e14a7bbee6ba6dc752226a",
+            "0x2cb549c1961a4dd6e13033ad2b2440d8278b6c20d18efaa36f27b353850a17d7",
+            "0x12820b0c0c8d75e6d2b3ac4acca37a08bd4bca4ee331f710aaff95d97c294426",
+            "0x2abc961cda29668dcd6f773615592a421a2a2227f7b8473749e1823c36008adb",
+            "0x013c0e6d2e7b1353165b314cae07a78eb35fa3491de7d1b9c07f4414990e1432",
+            "0x1c818572933ec8a9be01fccedf060cb2ff7c85015d5e9703af28daaa9b846d05",
+            "0x1edd01d0232333b62617edd9fc98922740a092508be4ffab6fd6a79f7abf797c",
+            "0x0d8fa94da8350f9ec57a76e249480536b9a7509e6f14133e205f3789c91c8cf2",
+            "0x0693ab8707c0d98f8b1d48cc2cad9980b412a18c9a1a58d060d056cef636275f",
+            "0x145dc50a4c6981918adf719f86101805e36ce28bd35b9e00ba82696adce4dac8",
+            "0x1deb89af5218cec25e89147adb2eea43b636925d1dafc93a8ab54a892476f481",
+            "0x15f7e83a6507b1bd946248fbadf5ca88adbad86d99176ca6358fdcc4b2da504d",
+            "0x23ae5b9089ffd263e412fda081476ecc71b0d919b87491853e453be6780d3540",
+            "0x2d78ac0ae8af111e05b824a61d666d81e872f0c9045ffbda2873eb4bbc39b140",
+            "0x16b3b144297473c7e36ec269feef9704e7bfb135378bc26e766f0387c09ad2e0",
+            "0x2c18e215088f7edb06b5edeb1f0b9c23425974ebf65e5ecc27fca641377f17c8",
+            "0x08996f1ebcaa0631967d810872d0951768e63f1cf41ac1c76242d1f1f8b73843",
+            "0x116de2a83fb324b98be900d773bf09c1342b53926c245455a3ba898bef08b9eb",
+            "0x0aa204a7dede1589fb2268c5b67ef95418dcf2cf880e786c7a49897aae28a5f8",
+            "0x261868de279a277e4a4b7447e04a76df7043ea37c898004749ee3a5b49f20f1a",
+            "0x055a386d8f459f1c9bfe6c18b4c1c3cb7a7419bb1f7fe7b1bac4918b29adee5f",
+            "0x12044473268a54bdded58990ba9506e57de7d3b10ff6eb3112eefc84b4c3c626",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x0a0907aee29f316a6efec2f59baffaf8ebcb3cb10082ea24a5a4769e5e98a151",
+            "0x1cc34fd4b2e6a46d7c39f3c1d744be1cab3830135fa46cd27a48e793274848e7",
+            "0x025d76217321fe31a4bba3448e04fe3a794c12c7213685a82e9ec1e9c9a84f8a",
+            "0x1cb15f2c926755e86713b2974f0b9f60a0a987e6b69276495c1d3b83c92bfa5e",
+            "0x1e05dd13b8a30a2f837bd134066ce7e07394b06e871078f41234e71fd65eae3e",
+            "0x21c07239ceb24dc3bd465c4561a7a75d3bf9ff7ea728dd219f6ce2c2ab8b1460",
+            "0x10582206a1fc4bd9132d830dda8dac4164f0d4e8117c2fa301b1c26bca08c1f6",
+            "0x0d25fee64074284e2929e81d095b69f5f0a5ccafbe56454b1bf5bc2e50417088",
+            "0x1312e6db9d61c5728832636dbf8160ef6d1ff087c2efa007d78e3870659bc25d",
+            "0x06269773b9d6ca737c876e66c1874821eea1aa995f7b8f5e9aea6cec3d7cc362",
+            "0x0901b7898e1b9d8cb5e9354efd791d0c0009a2344479d856145bc4e104ec1e45",
+            "0x15101d6e36c0e4645fb4a3d423887d1a639d8b6dbd826aff0ed1a2c990b2b941",
+            "0x298b41e0a9f26656a06d8304ffb6abb91b2557a7762218a57eedd9113bc44fcd",
+            "0x0cffe07761a0cf433e73c228e7002e6729a022bb9f4903e12271815f10bbddae",
+            "0x2aaa6168b645c1d77083b0470ac5a9dd978fdbce392f81e30861cc260c4b2acf",
+            "0x0e2ae62243fa51e8c5bbb5db655af65d1c9c7d90a1e7b52d48a3f5a5be640f29",
+            "0x2be7eb694239a066083d926d96828ebaed569330ebe303f413db37a253bd3644",
+            "0x11f9e95c6017db3aa727b357a35cdce373fcaf7f3dcd044c5c27ea6551160113",
+            "0x179a8f205dda67aefd131a60d8770e62378d1b1a0e292d4a62298e5d3f00c7ad",
+            "0x000ec063be0c1df3d76de91e268277fd76b9ce41c3913b026a78e635f524f5ff",
+            "0x1f03fe762bd349fba3cd3448ff6fb4cead898111bfb90f9cd5cc202385835ea0",
+            "0x18902de6d685baaf6c255d3fb0405e405ba8875a9b6fe39da1ddc419f74fe065",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1428fdaf7b2e8401bf5f65f4385651a9c9bbf7e62cfa8d7efcc686bb97e60be1",
+            "0x154ca3d2fb0638cb2a1d39db2a3fcdb087b34bbb4de72aeec978e2b4c3763dba",
+            "0x116a91c74fed8b200f82754205fe3f661ca18f98b55633ec7e478d12f7555938",
+            "0x1ff1792c90200d5543de9d4997c939dda4fb8e56b9ad79922876e380d6b3517a",
+            "0x10a93e5e8b6a1d73106b17e243a1d0767db67e1d6709998c9a2b1d953585591b",
+            "0x05fc69b7a4a4af27be06356aa5f4cdcae40357bbb8ad72418db1a70569087566",
+            "0x1b68209345c9a954712bc6cb4b1db4f83400a2820b687556c8e77e5129b06cb5",
+            "0x11211ac80cb9ce4b2c34a495bd769b94e130ca8b09458d9608d626f5853d4679",
+            "0x1aa61afde250b5a3ba04f40c449d28768c2b70cedd80dd6cf495e61ed9d50cc4",
+            "0x04b057bb913807426d5d4dd097ec6d35beb7a8f56ad0dd52f126306984efc919",
+            "0x20bc45ce10449fa454e08657a738230ee4c9bb2ce8b7cb317bb60c41cc09a47e",
+            "0x107797287190ee215b5f685bdadc1c33d106a09b3050a510abd550097eed0031",
+            "0x147d0e48bfd58a8fd4e97c3175ef68797d51f3e64efbd809abba6ddcad7830a4",
+            "0x245e0f7d2d1246e9302adb642589a1ed49a89a4c12208a3546e7a96b006f703d",
+            "0x28c4af956928de9b6fce406807df07947c48452b7f42e72d355d77311fc0fb72",
+            "0x2da45aa7c5ae1a75c505f6d767082a09e32b19424fca133087072d42255c58f6",
+            "0x16fb67a204ab4fbfcb24ae5ce0903f2d60b3637a9aa24fe7125a01b2d370d437",
+            "0x0b10e8c405a344331f926716bfe84be0b4f35970f953e3f8ea25d603eb21d8a8",
+            "0x098aa34ac37e81a293f8f90adcce7092fc8f2325c5b9c56dfcb0761f3e52ee04",
+            "0x2583fa54a5bfa934f32853c4af365a2d3b50623dee7a8ef0f9a32453989862bb",
+            "0x1c257850443589f1fa9e9fb84069bf8768abe533dbede12cda1d977322165a86",
+            "0x1ed8914f7cabaa916d1105405c369f8f174f621c7351d7c172adbcdabcda7e23",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x23808acd3e78e747bc62a9efb6223cc2351ebcf6e50a48bb3f7d274c189c0863",
+            "0x1560df75e01ce173dbdd99a7867cf0ba696fac423014805685f74cdc35383883",
+            "0x0c4dbd7e9dedb4c115811ea83792a42ac9f0b910be11558e7e47af3b62ef8aab",
+            "0x2dc5a82abc8f3fbf0df6f6f4b591fe90bcd42f72edd34034bcef153d1ecf4d5e",
+            "0x1c7b5c3a1bb87e51b8dbeb125ec3bbdec71d94d85190c12982e9b91b6d1621c3",
+            "0x0d4797cdbde26eab55650429472ac73755f2dacb06b10fcfd63c61538fbb1168",
+            "0x2b180560f9dd57e4cfa679b2d5a6b9808834a045eba5fc123d6becf6aac0edbb",
+            "0x2129d9f0f0b2b5be6e24cea58e55c6746255207cdfd6340216299e67aa702d25",
+            "0x0ab56cbe8a98be2c87001714f4589baa92b330857a4635f4673a8730dfe39169",
+            "0x2be4e747f71da1d7e8ba7790740db6f62526431f2e54759b7c0954c41663f124",
+            "0x285eb867fd68a71d83e5f4ff65ccf1917f259acb88a04eadb830f511b4bb31cc",
+            "0x206d37ab47518133a5e23a776d6698bacf5f25552fb5f31d50f5d43c58423f25",
+            "0x1f5790645a6c5994bed85ec673e6ff8175e7bbd2c743f733470cbc9234aa2fb7",
+            "0x19ffd2a77e31e2a9eeac8db6048e113236cc470ccc77bbad4f284050cc7938e6",
+            "0x0cb83b5cfb8c8f07703366e99af60124f853ffed217edb1f228c01d4e58fe351",
+            "0x08e0e5f504b7ef4f10b589b3b41d22e9cfe90bf1064c69f3541c60d1e27e1a05",
+            "0x15a0cb064457f4d34d98e3c49e78468f34b45bca71be524ad6c00a1d637404d2",
+            "0x04a3b0b9a616fe4e3a6578d3ca39889893b247e9ad9dac5306309084d1de9620",
+            "0x2b13f3cfbaa7d51f27ecc75b782cc700bb866ebfe92f6ebd5f671b50ba6514b7",
+            "0x2ea652bd71b859cd2f5a8e916260509768660f28e546f70c68f5dda2ca35f356",
+            "0x2353073d114228319c5154bb6fc86a982aba505e9c7adf83bc80fdc78713b4cf",
+            "0x2e382fb2f7451e07cc94d8039c00938719978121883a971d8287677303f548d1",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x02582815dc5c0df6a92b3a83bd3d58ee4d9adb98142d6b78d88da8b1b3a6dcc1",
+            "0x07c9d26dc72883c406c61c3a20c8fe324bdde15db65015dcb1186351d89a4685",
+            "0x0fe567d837a1f82a81456d10fb35811e2c102563af19bdff50785c4c1b40f951",
+            "0x2073cccd39a6566c8668290314c45795a166fb64cc5e16ce622445b6c6e9f106",
+            "0x0206e5228870fc00d6a6ce06179373e59626dccc71c37caedfcef7991f426976",
+            "0x21be110aaa145962794c6bf003fd4922ebdac6a95eef63dd4af78f41dd433c8d",
+            "0x1df2a2eee15e1a41eddfcb9bc3033815be9b51b768316a7b22b41f3da1d2232c",
+            "0x035840c5b302d42cb84842771a633ead151b1297190847785fd18931708e647a",
+            "0x294acd885d11dbfb1a6f440b8b47c62a4bf67b22d7e714dc658b9ae710214a49",
+            "0x0ff134507a4275188568057f5fd011299b55e44b65d9f4a234243aef945902bb",
+            "0x170e903106a0286e492f8c6389c8457fc08f729d58ccc8771246a0b33b2165e2",
+            "0x07b2d735e371cb716db23147b0facfee315de8efe308957ec38dbefca7e57f58",
+            "0x2fed9622726ec88e21f8554d8bf7fbd683ded0f012864653990990485483bd05",
+            "0x10da646994170640aa02435844992aa5b14b8d1232a97cb8a922ddfc76bac6e8",
+            "0x1d353b4be1f04efb682e3c156ab28ea51a4d3e0cd645dc46a2e8c0924442e603",
+            "0x007a24ca9465abb9b277b6faa1828aa436affaa9c2e8c18f1db02aad30c5da06",
+            "0x2c6c93d110edd2e4c58c594d4e497d9037bf32868cfa56ad26e3fd928a8c677d",
+            "0x1cc6807b9c98341041809268bcd95a102c13178c15d1eaa9b52ee4140dbb18c3",
+            "0x2699f66c18be7939d4451e7338bc123c273b51e2c82edd68efea832972701a15",
+            "0x00922c6d71ef51489e05e8af44d332333bcf05b5411fcdae4648441f52c27d21",
+            "0x0c711c323278839997dec1f58fd21a4aa4c9646a2538738347bbd29bb1dc4e35",
+            "0x07c75213b792976c0748bdea8562daa31dbde286fe8d277bc08f2958200c8008",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1201c9c6387520829d1ad17164523c22d58ac51244c63853e5a4a08778e9ba20",
+            "0x26b76f3be19d2e2fcab40cae7bafe28fbeaf2f64421f99aec952ee60ea342766",
+            "0x192aaa69c5ddaf3cbeb886823fca8192f437c7c69cb6a8654c1d40b5905a3cdc",
+            "0x0753b9d07c62d0effd5aad06821f2de4cc325413a5de69aa1950ce03f6b5e553",
+            "0x004d5eb98f843cecf2478c8b9baff7da84a162c2b5aed6df6bdf2cde56df345a",
+            "0x028a6926b3b39f49d0cdd8fc91dd69514dc0e5bf2152a4c8587cd485715f5688",
+            "0x074e137693d33ad20242862423ee27326337f51088631bcbe9e41b1022e91c80",
+            "0x2c92711a576470900ae305611fcbbc582fcd9d35e5bd1bbebd8cf22369cb41c4",
+            "0x265d3405f4607704e06a6448456abe1ad01e65d088dffde52ee80f22d60b55ad",
+            "0x251f52ad0018955eba6ec28e09a4459dbbbf5935dd36563ef6629aa79c9445c1",
+            "0x1eb2809c9abbd44cecc81bcfafc6e572379a6f71684e33a9a39b6f74f75e7bf8",
+            "0x04cde9fcfe898b91df745bf6f2e68a9e9a6e5ce221c9a89bbb2a41f34c96b3f1",
+            "0x2b60a841ec1abd01e34a6515e96b12d437330abf461a839d6578b2527f8894b2",
+            "0x214e5a38989228dcd9b5b9f2ee4f34781750b3f17235c12694adbee88604722f",
+            "0x2538142e81da7a1d337b2bd9f2bc7e89d7de995cf9223072c0e527c060c162c9",
+            "0x1739afa43d1430e230e0018d5a5f09a8f641a537c1cc131cfa8b3ad5469f09c9",
+            "0x1976f86bb0b70d3ca51594b5074e4ab8e010be1f4dc531d2155d3d9c03a5b339",
+            "0x159fbdd98f7c8581f3a7280400e358a4f83700253f7c7dfe6109313edea20f33",
+            "0x11e79f384c86634fb80c59e4b67d522e08c0a711e24767ecebd1073d82a33a84",
+            "0x0b0ae46a1e060812af44c7e56c754e6abedb37601d4e866369aadc9abac2de64",
+            "0x133a9ea4149378c6c0e2316d2bcf5d218dad254cc7c2e5b205075e58738e5ed0",
+            "0x028e11e5602718727462b39d6216e4533239be2fd4c63d3544adc22c1115f576",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x02ee77c372d63d89aacebb5864d4be3e7e6c8ea89e678c0246d286d1e54824c5",
+            "0x196489f3f99cb09d70fb484ba0470bca6f760e123ec2f5d52d529b8cc9c7c267",
+            "0x07266d15e9c0f6f2b4649c2aa8494e68e6e602bcddfd1eeab8df5199fb7dc692",
+            "0x09e07ea037e2357e2442b974a71e0da5f1efba7a5f30f8407bbfd43bdde2dc36",
+            "0x07413e28f0f1b9edd22c5be75b7915cd41a209c2d134cb30f70eb58f292578d7",
+            "0x06b9a499e1d52495c4e3b96db7af0952e2d2b370ea504c7e11be344609e75971",
+            "0x2c53a4cf237ac73a5ae607dd6a1981ea801c219661b6681a18d215a64a623ca4",
+            "0x20a32d6fe28ff25061598b4d2cccefcc573b5eeef2dca3608e12621c36680782",
+            "0x1650049f9e80d0818b4e5b0e62e2cfeb17590e6bb7009e5d1826f3c52b807f9d",
+            "0x13ddb9725978687027ef3548e114991a493c296d678c7415f65ee99188c14ecb",
+            "0x2153686c1e4c45ac07e2707178c2dd5dbe22c96003c9d7627c3506c4bc014f05",
+            "0x1e00991def05eb9c9e6e27ac8e27784e928fb30abde6d33b7b93527549b626eb",
+            "0x2b8034c695137f736e3c00897971448129709a0170070c0a9f9d0f2b2c9968c5",
+            "0x0150a79682394c2f31ec7240156da5b9a58895e91ebbcb2cd022301f59b0d3b9",
+            "0x2f575ace0e7779d2762ae8b3fa38b98988ee03a746aae4ddedce17fad02d3d02",
+            "0x0ca68649605a78642afd05d264b6ae34c583613fc025ab3e44778a96b844a372",
+            "0x132beedeb14726ebcd94d04dba067d12fb1ca9c96d97a0e050da262cf4a31664",
+            "0x0c67aeedfd5fe5200da7f68af12361b8c3662a95e0ff725a26ca383495a8ba5f",
+            "0x005060783719f4af7a889a7cb6cfaa2de5e82cdf3fa1949f0c967c90736f50a1",
+            "0x194e3d3ca44ee06c4b93beb933f38a12d18ad816f7783f51151a66af576c4d97",
+            "0x1cac9f4513986817fed6c9c76fd02256da4b153a4695cf8973bae4cd0e9fcc8e",
+            "0x0926ae63833a5cd86f972c031a708bfa4e96081a85b98d7d2fdbb29de16002f2",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2e4a74622cf74059ebecf120489b3c7122b685848aa9715323e18103ca370930",
+            "0x093286867f13217fc245cb1558eec22b06832977e56e276f744b5f509ab9c6ce",
+            "0x153d489b0929b9c756243e9a675be505b84579412155b43458ff96261099e767",
+            "0x07a7a5c8c894c918e95323e239f3dccd2b46f1c67ab79ffb076109e6427acdb0",
+            "0x0b06c2834cf87c73b36e6a9d590b3867fa2b19c3512d70866a6d0d69b296dd92",
+            "0x2bffb956b0c8977cd30542f7c2196e19ca00cee5ac12e75c5fcada71e9687c84",
+            "0x178f96101c3578f5bc9e401c2381858a3ceb925ab33edef026be8dfa403f848d",
+            "0x151e459d6946846ec209d5a13e44965cff9df7563fb24c5e90a117a9e5993c80",
+            "0x028efe138e078e52f6107bf0bbdfc9c0813dc19ba7533f68f2339127b630c248",
+            "0x1c7d88b164ddf05087b929be31c0b433399687f8fe0d622b300ca343df1a193d",
+            "0x162a584e7f3d7c62b218045cdcf7a285466b49b54749df6c1bb2ad9dda0e2d9d",
+            "0x0b4fd4a5c3fdde8cc78312f2f4c35386654707d6beab2fcf4fa155a8d4ab6f52",
+            "0x3044444976826d30c4342d2372a214040bffebb8c8637922aba88adbf0f29872",
+            "0x2dc2815fe2f505f72fb0f91db73f49a3231b20cbcc91179db8198ce2a1b5e384",
+            "0x2dc552782be622ac23a17dd67c363ba0f17aed2d9c1505c0ba6e56079896f856",
+            "0x206252450e00428ae2373e62f9b519d7f2849fffafd7cef54ea08466afcd4312",
+            "0x1f56e256dd24b2f96de4f8811faad221a19542b59d562828508335604568e356",
+            "0x0097725024cd0fdf1e4462e3cd599f6b52ae7d45807ce4861ae3c393eb3521fe",
+            "0x00fef2f5bbc7fc922cc58f4d1b4aff53b4d89e9562135d4e43be36a16d5c325a",
+            "0x2adf1809485edf94fad0042cdc16dedf02c13d0d5868b31d48e014e8ad049b66",
+            "0x15743d0837a685c382377436b5a18edbf3262ee996b95cbdcb50dc462d5aee4b",
+            "0x2726d15aa7ce8dd742b922406e4e31ab85561a56125392cf6b94a7a25184a405",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x2eb813efd438c3f8d1290acbe7f76164a3bda93ae16e05cf72f55df19a3f92f7",
+            "0x1236881ce5c82acdef0c0e65f158dd717c0b13898b67a5e711785fc111460d29",
+            "0x0830d8457789f2764aa05125345eb71e90b5728c275a1601c59d92d0739182ed",
+            "0x2294be757ad321827dd68df6fe8e1dfaedd29797ccf815711b8798d67f60afd0",
+            "0x0831b8f946c7af61edb5d32397ccf13fb196aad95f2ec0c5a3d9c9afdf5a4d7c",
+            "0x1ddcc75df16412c8a435d97c4bd5c203cd5cb731566ff77a5f624b01f90b9e61",
+            "0x163485c383a69a41dbd77fdd968bbc229d79882461c6c26e866530e332d2a224",
+            "0x1cbae61a20a17ebc92419224a5f3c81c61a6f69be0391acaeb93cfa2bada6280",
+            "0x1512b3749c9b9780294838c594e1cb1dcf9242ede2fb46c674949bf9e32bf369",
+            "0x1e4d654deef6f450e2f33885773e9fceb653a53cb1988aeb300a851115c3e577",
+            "0x28e3b40eb789e123b50d33f15afaf8323522fc6d2ce1df9ab3f689aef8f7e6f4",
+            "0x14761fdebf8676547f0837a07190aee32a660c2b964916a7df588d033825b0d8",
+            "0x284474d7601c19c9a9340c355f1adbdbd9c72b132c8abddb0cb146de30a0edf9",
+            "0x06a0619132a1bae0af408ce7fc2d93cf0f333fdf36a6105f99cd3156c7dec1f9",
+            "0x22a09c64b2506edec41b976b5e8b54b56d3e1afa1115b73f5949323f106489c0",
+            "0x00b81b2f5f1b20b061abe19962c16c44bd1cdf9a170e738fedd6cd448ac1b999",
+            "0x11fbdd6b69fbd8f3f8d8effd676067c03050f134491d98e09ba4cd1eaab425f7",
+            "0x1472583434cd12d1ab5a8f26c651995aa5e9aad4c93811cf80a761593496e79e",
+            "0x26fe27abeb374fa039a72156affc168c480b562355f80fa842691eac67627e22",
+            "0x27c26994f7a87591b4c4740f9fa33aab782c9e7f1e0e01dcf1e2b08df5a0f9e3",
+            "0x0ac33603443a7a1fe0c2580cbc372e216809556df8c2ae0d3b7be2c31288351f",
+            "0x1575ad76d37466ab0b81383d134e9446946a40625139f1d2a85c86c9771d0b33",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x1f37a7127bdf68cd01c6809d0ec0b24f448d8bd6601965219c9df085df3e76a9",
+            "0x1bc4c1d73f18bb2083d64deaec9e85e3d27df4c4357b65d2ef299da1ac546521",
+            "0x2baedb87c7ad9d2aa5d2abf01f357850061f262d7f9d69bb9e83960bfcbc390e",
+            "0x0c2160b4e90f60d12328dfc9f297493bc36a13841f02ea901cebb5666b0bddca",
+            "0x2b6ed94f0df0f14591d4150809adc76df50ce1f999c37f24188ea171cb45f9bf",
+            "0x1c83e59e75ddf830d71c8f270d3f63a1b8211dd9f154099f9b840dde86b87d9a",
+            "0x11c5eeefc0290a75c955364e67386be19b7afb46ef66d6229403177d2b088230",
+            "0x0564217adb44c01f464aaad83f76ce7fd193c0ec9d331f1ad686bbc2485a1b22",
+            "0x0aaadb2231bd734663625a497525b09c60f52a463346a95d94737775ab93f059",
+            "0x1d57d8d79c077082d0d21e0c9cd743fbdc885ae178edaba4f247c4a4e00fe99f",
+            "0x19f7032acf4feb04fce8095b354e050e9de58eb49052210993621640938257c4",
+            "0x061fb4e79466c0584654ec5d5fad70701207e4fab1c60e33e64c35ec404d0be4",
+            "0x278b245b1b5d0ee10e100bd5097e8663775e5ce5aea77f5862c35011f57d7312",
+            "0x06eee801eedd6e55c53272e3e2dbcad6ff749a14a925314c81cc246e05a0f5c1",
+            "0x2c8e3e73321c73aacab1b0125e705a684da96ffdf7bda81b9fb89e68ad92ff9d",
+            "0x217df8eb2a56418dff02f6c0baeb0779c9df0fd8be014fca77d25403e6d89bcd",
+            "0x0e6f0deaab5d53cda5d32ded230286f78f92e3b77912e1ba314eb72bd69ce5bc",
+            "0x2b09fb7f53359860062474f746c317d1b726b31e249019bfa762ca5f2ae5afac",
+            "0x2db15811382230f46cf3479cc03b56e4fbb22fa2638b29aa7b72ac109e62325e",
+            "0x12306893ad4bf25d6376d75123f39bdd5d5e2c5fb4b1966ba5a61d54595981f2",
+            "0x19e4367f0a66a597c791617c88142f8ecfbc83ad7fbefa1036d9e29c044b5b5c",
+            "0x0409c70f1ffdb865a111d875882f73ae475b6d153135f38644d314b292134ab6",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x03b8f2579b5a2b8420b5d87e9f285f80d56b32c683342e211a46f9ffa00bbec5",
+            "0x26d1df4873a3c70af9c43149c0e7a2851cc32a18e3f0c08ec0480bdb55734b2b",
+            "0x06e9c210808b4ab1d644ebf4971e4c4f7c04d42560e3a8d289e456430c8ebdb7",
+            "0x30644e727d335ba2dd7b7465cd03e9038eb5241dd4150e96ddc5c6c5fda61901",
+            "0x111457ed63b09ab68d4c45d94e5964768b8bc4297107209b052f67b5cdfc55a6",
+            "0x25016928ab8da6a8902b20b411a8734748d7d30f57a903ae1659ba9dbd3f743d",
+            "0x169f6ffa6b27634d9aad4f32e76f5586e875f36f14d791d6e12164cc25fe041d",
+            "0x17127fbf4f9e3bedf0b8e5cf9bf7228d4243cf04eaa53c67a8c9a2bab0d1325e",
+            "0x020884605ee1183f62f5bbf7e5e1a0ec9491f571b1324228715cbbfbc7a41e63",
+            "0x1764af9a38f1759684ff384862f08a5d7d787f50a46ebff8ed21f9c5995555b1",
+            "0x2a697c6840ef363e40ea7c5d80596278e94314004a6185828faf116bd5d5e6e3",
+            "0x1f48e1710648dd9493a38efa5ca75195ffe8aa638a823643cc5edcf0b90f9950",
+            "0x0cbaddffa56a21ad2bad692aa0656fc62bd96005a6caa2718b9b6215b6314cb1",
+            "0x176593302d286c0eedfe2d8fb1f7fd7176204a839a1a9e6c5b56791322cb41fa",
+            "0x1a3f2458408628bc57e6f76d6080883641182de0db5973a494123498509ffaa5",
+            "0x1d7520bda6adbc32680e34508fdd16ef1c6320cc0ec24f5d28217d1d8769ca76",
+            "0x0fa7f14269ab2eb23c5b13728adbd299a6b41311842bfc1cd2ec11f546194c1e",
+            "0x20da800900fd6ae2e7cf66ef56aeee3db5101063c1e5ff5cf2329f78154c4586",
+            "0x07ad8923e98ae0d68636369cf95a7f0ed7d6d7019905b61aa225c1210bf4c7b9",
+            "0x13eec4d5eb8f7e48c6ada8e3a6ab8fc52928373627cb2872ae966098e8020c17",
+            "0x0b632d84cc172c7b5ce31b80a484334ac3fb8ea22badc8902b0849277362d378",
+            "0x1ae447afb11f5be73d72bb2fcba6549314ae1ed6f4b31961a42ecd22da95d34d",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x218085147484d157f5c16b56f732aab11cf7ceb35f48f02de6b2b9d89229a6ed",
+            "0x1dc792bcd9599da3851db4bf1495c861366401852d2de18681e12305db9c8b28",
+            "0x0dd38420d2a076e7593b81a1dc1bb877092bc5cd1d826495eefe622b7e81616e",
+            "0x06e9c21069503b73ac9dc0d0ecdad3405cbb141b2001ef22d4cfc328859ae477",
+            "0x088a2bf627bd769df35975b6ce8725f88af5da08a01d244de0e8c1b78f549dd3",
+            "0x30644e72e131a029b85045b67bff09612719b5f8ff7fbbb1d4243fbf2f7aea81",
+            "0x0ed508fc5ff839cef7806e2290f137c130de298be7e89c7aebc4614e71a2e361",
+            "0x1f76511f1925cff514c2da1ab21e04c1de515eed8b16c47781e8c9b0016fdb26",
+            "0x2a66269db7d517073a744cee61b8bfb5f3cabc7a2241df3ae107c816cfe2ab49",
+            "0x1fcdfba04eb6124ed2cf6bfa43fb93dc835783f7180f2a062f42c43d56a06f96",
+            "0x29cb8b4eb74811514ec3b2a0a3e35c3b86b98c82f5f7f1b45fc8fbd26016d6e6",
+            "0x1a408e6431a119700ec9232bd6dd1e6240d20fc96eab06571c1dcab7ed3a6511",
+            "0x2719114f3ee5e4cd50f0699235d9632ae7864e183604109e5e6ebbe751cd036a",
+            "0x0b501f4ad0e60b2ed0c1aa66c6ddeb713595735e73161f0415ce7bc82fe4db47",
+            "0x1e95d9fa2d438f8b4e472f782ffbd4b413f34da038242221c257836de57c3880",
+            "0x193f6f4604bb3f8d08c6dfb584bcce31383a28c4285a88530febdcc1443832b7",
+            "0x18e8eae327fc905f1441a462e8a01dbeacb4d420b8e57298678d4b5f3e589292",
+            "0x1ede6b19613916027a0201d7e278c21e64be4bc7c47baf20f6eab7e752f2e122",
+            "0x07c58a32ab460e89a6b44c6e2937783a96d9ccc61bd8d9404ec23d41166d9da9",
+            "0x1e504b99788fff40f84fdbd1cd7118a24ad8540601ba2b410c691e3de21f4687",
+            "0x1dcfef33c130a840eb6d5618f1c19fcb1e7efe1fbea9d38339a292df4ee18734",
+            "0x1d7f3016e4e5b615cdd13c956303fe66f19e1675ce14b22371d9879d5ad6a0d3",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x000000000000000000000000000000000000000000000164055c9d842b4e07c0",
+            "0x275a01e743c8b4e1390bd36a36acb2c1e86dba138d2e9deacf932072259a829e",
+            "0x00000000000000000000000000000000000000000000affab75581845666db40",
+            "0x14bd46313bf0b25b05d94272c9c9b827eca8881f0f95a8afb34d240766b7ba39",
+            "0x2d8b9520d3f2789fbc87c9243da6e9c116c77135274d49ed522c48db185c7710",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeaac2e9968ea6e94a32a166",
+            "0x00bfc4faa52b91244fbb7f190461e7368b52702d819019d8e03d7ba7fb967217",
+            "0x11610d1d5e59274a6cfdeb113278f424a0455db955a856c59d0b4e468b21696c",
+            "0x123142103d8fc4df905bc5842149a60812f6a451242c1380421f16019c8f428a",
+            "0x224f0a712664f77f108b56acfbea5d8eecf2de8a90f0537df0d47be41d3b5cf7",
+            "0x07020e8a7c556f3bda561b55d92d82f6a4e9792d0f090683731bd59342253f99",
+            "0x2eaf8a0aba13287704afe75b8efd9afdf9a83b2ba9f214cdc8dfbc826a000001",
+            "0x1bf981ec84e09d585ef4d8b4a459147daa0094bb7d2637eb49a781d41efd1305",
+            "0x156bc11218e6429693287bf4e031f21e78ab669c4646033f36f59bf900ecfb9d",
+            "0x2bfc778c00c09633ab3ae65feb75919c027046fa47f921c0da9b9d7c1b9c6761",
+            "0x17bddcc8c7ac8e2fd0cc88d4a7d7dfe846daaa62c047a5b2dd528cb725ebb2a6",
+            "0x037bf0c51d905395611e9beec0348bbdcfc0333d2625dd6157c2d03b6399bf8a",
+            "0x2fd31bf03d67eceb3816da02f6e3507453fc7d5ecd0657c7af45369f4c93f3de",
+            "0x25225bf48bac09ab3300344163ac861a3cf4285eae2654af3c818d324bfefb2a",
+            "0x2dfa711792547ce04fb53033e2d80032a94b8348c53ea6f2c8ddbacc9ce4ae7f",
+            "0x0bd505c8dcb6a8947a7db0e9897eab9484dc1f0365a4a295f9e01b16d695f57b",
+            "0x23e5c20b48c62c99157edcdb0ff9d212e625718bd80e623a20d2fb48d45c0aea",
+            "0x2c5bf293f91828263e49953ca1613baaba2f94ed1a3f51da7e39cbc79c000001",
+            "0x218085147484d157f5c16b56f732297b91fc8d1e7ba7c415cc88bdb530000015",
+            "0x1d3f701e0c5371ae507408603d64ae5fb035e0c957a2b7603cb8649a1db6dab9",
+            "0x297a8c6277e164b60bb284e59393704fd951103e1f313bea3a2f64c7f2492842",
+            "0x297a8c6277e164b60bb284e59393704fd951103e1f313bea3a2f64c7f2491825",
+            "0x2ab2dbcec6b35115c0bf4c91f9cc7b25055afa21d4d0ccbc6912ab8288789523",
+            "0x13ed113e5cba14c5e27b67ffdaf9064479f74187412e3d68fdd5833cea5a2c2d",
+            "0x1f03415582d878df4b525aa54f08643887ee8a8f2400bfd19a76a7afa67d6df1",
+            "0x1725ad0dbc8f04e20621a41ea104687bd10cf0b1546105e4ca79f3e1b5c53133",
+            "0x2254851939bdfba4ac6c1fcaf4b9f6733a127b58aa97b56b7847fc3ac121ef24",
+            "0x2fc56968b01c27e70fd97d5151fd06806b9f24ef92176a4146f98e397fa90510",
+            "0x148cad3d15e64acaaa688d68f34a8255409b45d9a697ce4e41bb81e485ccf431",
+            "0x2cab5c1b46066c7547ac8f1e9eed8ca4c2a6117dfa350572660ba79c40000001",
+            "0x0374e10834a81db9d64ee06876f6f406a7716c052d441a5384d94865fedb6db7",
+            "0x0673c631735bf338c33de7296699c783e33a1ef898c36457a2a6a946eccccccd",
+            "0x2d5e098bb31e86271ccb415b196942d755b0a9c3f21dd9882fa3d63ab1000001",
+            "0x111457ec4f7aed3be6b2eb6d971e97a8688aca73eeb9eb7e906dde3436969697",
+            "0x2db411339bcb502766f67aba96c1453b89865b60e4bd5c176ac72f0bb8000001",
+            "0x028c0420fe60ed7b757d7ceea880121fd9b1e3cdeb758ca9546a3558a0d79436",
+            "0x10efe841ced15e7500827eccad53abba3478914c9100e7660af57c5a2d99999a",
+            "0x126f5ad66e2b493476fa022d25251578d25ceac646c08c6819dc2ccaa4924925",
+            "0x20fe9294252d78d6a093d270cca9a4f9b2af071a2472ccc022a5b31369745d18",
+            "0x12ef9921d28dda7fa12a8a95541138d68a2a928baa10e943f92be5a931642c86",
+        ],
+    },
+];
@@ -13,11 +13,36 @@ use core::mem::MaybeUninit;
 
 impl_poseidon!("bn254", bn254, ScalarField, ScalarCfg);
 
+#[cfg(test)]
+mod load_constants;
+
 #[cfg(test)]
 pub(crate) mod tests {
+    use super::load_constants::LOAD_CONSTANTS;
     use crate::curve::ScalarField;
     use icicle_core::impl_poseidon_tests;
-    use icicle_core::poseidon::tests::*;
+    use icicle_core::poseidon::{constants::PoseidonConstants, tests::*};
+    use icicle_core::traits::FieldImpl;
 
     impl_poseidon_tests!(ScalarField);
+
+    fn parse(values: &[&str]) -> Vec<ScalarField> {
+        values
+            .iter()
+            .map(|value| ScalarField::from_hex(value))
+            .collect()
+    }
+
+    #[test]
+    fn test_poseidon_constants_match_load() {
+        for loaded in LOAD_CONSTANTS.iter() {
+            let constants = PoseidonConstants::<ScalarField>::generate(loaded.arity, 5, 128).unwrap();
+            assert_eq!(constants.full_rounds_half, loaded.full_rounds_half);
+            assert_eq!(constants.partial_rounds, loaded.partial_rounds);
+            assert_eq!(constants.round_constants, parse(loaded.round_constants));
+            assert_eq!(constants.mds_matrix, parse(loaded.mds_matrix));
+            assert_eq!(constants.non_sparse_matrix, parse(loaded.non_sparse_matrix));
+            assert_eq!(constants.sparse_matrices, parse(loaded.sparse_matrices));
+        }
+    }
 }
@@ -33,4 +33,12 @@ pub(crate) mod tests {
         let poseidon = init_poseidon::<ScalarField>(3, MdsType::Default, DiffusionStrategy::Default);
         check_poseidon_kats(3, &kats, &poseidon);
     }
+
+    mod transcript {
+        use crate::curve::ScalarField;
+        use icicle_core::impl_transcript_tests;
+        use icicle_core::transcript::tests::*;
+
+        impl_transcript_tests!(ScalarField);
+    }
 }
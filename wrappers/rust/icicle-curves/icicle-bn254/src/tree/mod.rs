@@ -18,7 +18,7 @@ pub(crate) mod tests {
         ntt::FieldImpl,
         poseidon::Poseidon,
         poseidon2::{DiffusionStrategy, MdsType, Poseidon2},
-        tree::tests::check_build_field_merkle_tree,
+        tree::tests::{check_build_field_merkle_tree, check_tree_builder_config},
     };
     use icicle_cuda_runtime::device_context;
 
@@ -41,4 +41,9 @@ pub(crate) mod tests {
 
         check_build_field_merkle_tree::<_, _, Bn254TreeBuilder>(28, 2, &sponge, &sponge, ScalarField::zero());
     }
+
+    #[test]
+    fn tree_builder_config_test() {
+        check_tree_builder_config();
+    }
 }
@@ -5,7 +5,7 @@ use ark_bw6_761::{g1::Config as ArkG1Config, Fq};
 use icicle_bls12_377::curve::BaseField as bls12_377BaseField;
 use icicle_core::curve::{Affine, Curve, Projective};
 use icicle_core::field::Field;
-use icicle_core::traits::FieldConfig;
+use icicle_core::traits::{FieldConfig, PrimeFieldConfig};
 use icicle_core::{impl_curve, impl_field};
 use icicle_cuda_runtime::device_context::DeviceContext;
 use icicle_cuda_runtime::error::CudaError;
@@ -14,6 +14,15 @@ pub(crate) const BASE_LIMBS: usize = 24;
 
 impl_field!(BASE_LIMBS, BaseField, BaseCfg, Fq);
 pub type ScalarField = bls12_377BaseField;
+
+impl PrimeFieldConfig for BaseCfg {
+    const MODULUS: &'static [u32] = &[
+        0x0000008b, 0xf49d0000, 0x70000082, 0xe6913e68, 0xeaf0a437, 0x160cf8ae, 0x5667a8f8, 0x98a116c2, 0x73ebff2e,
+        0x71dcd3dc, 0x12f9fd90, 0x8689c8ed, 0x25b42304, 0x03cebaff, 0xe584e919, 0x707ba638, 0x8087be41, 0x528275ef,
+        0x81d14688, 0xb926186a, 0x04faff3e, 0xd187c940, 0xfb83ce0a, 0x0122e824,
+    ];
+}
+
 impl_curve!(
     "bw6_761",
     bw6_761,
@@ -44,9 +53,10 @@ mod tests {
     use icicle_core::curve::Curve;
     use icicle_core::tests::*;
     use icicle_core::traits::FieldImpl;
-    use icicle_core::{impl_curve_tests, impl_field_tests};
+    use icicle_core::{impl_curve_tests, impl_field_arithmetic_tests, impl_field_tests};
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
     impl_curve_tests!(BASE_LIMBS, CurveCfg);
     #[cfg(feature = "g2")]
     mod g2 {
@@ -2,7 +2,7 @@
 use ark_grumpkin_test::{Fq, Fr, GrumpkinConfig as ArkG1Config};
 use icicle_core::curve::{Affine, Curve, Projective};
 use icicle_core::field::{Field, MontgomeryConvertibleField};
-use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom};
+use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom, PrimeFieldConfig};
 use icicle_core::{impl_curve, impl_field, impl_scalar_field};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::DeviceContext;
@@ -14,6 +14,19 @@ pub(crate) const BASE_LIMBS: usize = 8;
 
 impl_scalar_field!("grumpkin", grumpkin_sf, SCALAR_LIMBS, ScalarField, ScalarCfg, Fr);
 impl_field!(BASE_LIMBS, BaseField, BaseCfg, Fq);
+
+impl PrimeFieldConfig for ScalarCfg {
+    const MODULUS: &'static [u32] = &[
+        0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+    ];
+}
+
+impl PrimeFieldConfig for BaseCfg {
+    const MODULUS: &'static [u32] = &[
+        0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+    ];
+}
+
 impl_curve!(
     "grumpkin",
     grumpkin,
@@ -31,10 +44,12 @@ mod tests {
     use super::{CurveCfg, BASE_LIMBS};
     use icicle_core::curve::Curve;
     use icicle_core::impl_curve_tests;
+    use icicle_core::impl_field_arithmetic_tests;
     use icicle_core::impl_field_tests;
     use icicle_core::tests::*;
     use icicle_core::traits::FieldImpl;
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
     impl_curve_tests!(BASE_LIMBS, CurveCfg);
 }
@@ -0,0 +1,21 @@
+use icicle_core::glv::GlvCurve;
+
+use crate::curve::CurveCfg;
+
+impl GlvCurve for CurveCfg {
+    const BETA: &'static str = "0x30644e72e131a029048b6e193fd84104cc37a73fec2bc5e9b8ca0b2d36636f23";
+    const LAMBDA: &'static str = "0x30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48";
+    const BASIS: [[(bool, u128); 2]; 2] = [
+        [(false, 0x6f4d8248eeb859fc8211bbeb7d4f1129), (true, 0x89d3256894d213e2)],
+        [(false, 0x89d3256894d213e2), (false, 0x6f4d8248eeb859fd0be4e1541221250b)],
+    ];
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::curve::CurveCfg;
+    use icicle_core::glv::tests::*;
+    use icicle_core::impl_glv_tests;
+
+    impl_glv_tests!(CurveCfg);
+}
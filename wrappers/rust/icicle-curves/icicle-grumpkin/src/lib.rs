@@ -1,4 +1,5 @@
 pub mod curve;
+pub mod glv;
 pub mod msm;
 pub mod poseidon;
 pub mod tree;
@@ -16,10 +16,11 @@ impl_msm!("grumpkin", grumpkin, CurveCfg);
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use icicle_core::impl_msm_tests;
     use icicle_core::msm::tests::*;
+    use icicle_core::{impl_msm_host_tests, impl_msm_tests};
 
     use crate::curve::CurveCfg;
 
     impl_msm_tests!(CurveCfg);
+    impl_msm_host_tests!(CurveCfg);
 }
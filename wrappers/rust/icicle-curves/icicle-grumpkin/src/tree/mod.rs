@@ -12,7 +12,11 @@ impl_field_tree_builder!("grumpkin", grumpkin_tb, ScalarField, ScalarCfg, Grumpk
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use icicle_core::{ntt::FieldImpl, poseidon::Poseidon, tree::tests::check_build_field_merkle_tree};
+    use icicle_core::{
+        ntt::FieldImpl,
+        poseidon::Poseidon,
+        tree::tests::{check_build_field_merkle_tree, check_tree_builder_config},
+    };
     use icicle_cuda_runtime::device_context;
 
     use crate::curve::ScalarField;
@@ -26,4 +30,9 @@ pub(crate) mod tests {
 
         check_build_field_merkle_tree::<_, _, GrumpkinTreeBuilder>(25, 2, &sponge, &sponge, ScalarField::zero());
     }
+
+    #[test]
+    fn tree_builder_config_test() {
+        check_tree_builder_config();
+    }
 }
@@ -1,5 +1,5 @@
 use icicle_core::field::{Field, MontgomeryConvertibleField};
-use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom};
+use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom, PrimeFieldConfig};
 use icicle_core::{impl_field, impl_scalar_field};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::DeviceContext;
@@ -19,13 +19,18 @@ impl_scalar_field!(
     Fr
 );
 
+impl PrimeFieldConfig for ScalarCfg {
+    const MODULUS: &'static [u32] = &[0x78000001];
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ExtensionField, ScalarField};
-    use icicle_core::impl_field_tests;
     use icicle_core::tests::*;
+    use icicle_core::{impl_field_arithmetic_tests, impl_field_tests};
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
     mod extension {
         use super::*;
 
@@ -114,10 +114,12 @@ pub(crate) mod tests {
                 .collect();
             let matrix_p3 = RowMajorMatrix::new(scalars_p3, nrows);
 
-            let mut ntt_cfg: NTTConfig<'_, ScalarField> = NTTConfig::default();
             // Next two lines signalize that we want to compute `nrows` FFTs in column-ordered fashion
-            ntt_cfg.batch_size = nrows as i32;
-            ntt_cfg.columns_batch = true;
+            let ntt_cfg: NTTConfig<'_, ScalarField> = NTTConfig::builder()
+                .batch_size(nrows as i32)
+                .columns_batch(true)
+                .build()
+                .unwrap();
             ntt_inplace(HostSlice::from_mut_slice(&mut scalars[..]), NTTDir::kForward, &ntt_cfg).unwrap();
 
             let result_p3 = Radix2Dit::default().dft_batch(matrix_p3);
@@ -17,7 +17,7 @@ impl_poseidon2!("babybear", babybear, ScalarField, ScalarCfg);
 pub(crate) mod tests {
     use crate::field::ScalarField;
     use icicle_core::impl_poseidon2_tests;
-    use icicle_core::poseidon2::{tests::*, DiffusionStrategy, MdsType, Poseidon2};
+    use icicle_core::poseidon2::{host::HostPoseidon2, tests::*, DiffusionStrategy, MdsType, Poseidon2};
     use icicle_core::traits::FieldImpl;
     use icicle_cuda_runtime::device_context::DeviceContext;
 
@@ -268,7 +268,7 @@ pub(crate) mod tests {
         (&mut round_constants[rounds_p + rounds_f / 2 * WIDTH..])
             .copy_from_slice(&external_constants_flattened[rounds_f / 2 * WIDTH..]);
 
-        let mut internal_matrix_diag = vec![
+        let internal_matrix_diag = vec![
             ScalarField::from_u32(0x78000001 - 2),
             ScalarField::from_u32(1),
             ScalarField::from_u32(1 << 1),
@@ -293,8 +293,8 @@ pub(crate) mod tests {
             ALPHA as u32,
             rounds_p as u32,
             rounds_f as u32,
-            &mut round_constants,
-            &mut internal_matrix_diag,
+            &round_constants,
+            &internal_matrix_diag,
             MdsType::Plonky,
             DiffusionStrategy::Montgomery,
             &ctx,
@@ -614,7 +614,7 @@ pub(crate) mod tests {
         (&mut round_constants[rounds_p + rounds_f / 2 * WIDTH..])
             .copy_from_slice(&external_constants_flattened[rounds_f / 2 * WIDTH..]);
 
-        let mut internal_matrix_diag = vec![
+        let internal_matrix_diag = vec![
             ScalarField::from_u32(0x78000001 - 2),
             ScalarField::from_u32(1),
             ScalarField::from_u32(1 << 1),
@@ -640,14 +640,33 @@ pub(crate) mod tests {
             ScalarField::from_u32(1 << 22),
             ScalarField::from_u32(1 << 23),
         ];
+
+        let host_poseidon = HostPoseidon2::new(
+            WIDTH,
+            24,
+            ALPHA as u32,
+            rounds_p as u32,
+            rounds_f as u32,
+            &round_constants,
+            &internal_matrix_diag,
+            MdsType::Plonky,
+            DiffusionStrategy::Montgomery,
+        )
+        .unwrap();
+        let mut state: Vec<ScalarField> = (0..WIDTH as u32)
+            .map(ScalarField::from_u32)
+            .collect();
+        host_poseidon.permute(&mut state);
+        assert_eq!(state, kats);
+
         let poseidon = Poseidon2::new(
             WIDTH,
             24,
             ALPHA as u32,
             rounds_p as u32,
             rounds_f as u32,
-            &mut round_constants,
-            &mut internal_matrix_diag,
+            &round_constants,
+            &internal_matrix_diag,
             MdsType::Plonky,
             DiffusionStrategy::Montgomery,
             &ctx,
@@ -80,10 +80,12 @@ pub(crate) mod tests {
 
         let (commit, _data) = mmcs.commit(leaves);
 
-        let mut config = TreeBuilderConfig::default();
-        config.arity = ARITY as u32;
-        config.keep_rows = HEIGHT as u32 + 1;
-        config.digest_elements = DIGEST_ELEMENTS as u32;
+        let config = TreeBuilderConfig::builder()
+            .arity(ARITY as u32)
+            .keep_rows(HEIGHT as u32 + 1)
+            .digest_elements(DIGEST_ELEMENTS as u32)
+            .build()
+            .unwrap();
         let digests_len = merkle_tree_digests_len(HEIGHT as u32, ARITY as u32, DIGEST_ELEMENTS as u32);
         let mut digests = vec![ScalarField::zero(); digests_len];
         // let mut digests = vec![ScalarField::zero(); COLS];
@@ -17,7 +17,10 @@ pub(crate) mod tests {
     use icicle_core::{
         ntt::FieldImpl,
         poseidon2::{DiffusionStrategy, MdsType, Poseidon2},
-        tree::{tests::check_build_field_merkle_tree, FieldTreeBuilder, TreeBuilderConfig},
+        tree::{
+            tests::{check_build_field_merkle_tree, check_tree_builder_config},
+            FieldTreeBuilder, TreeBuilderConfig,
+        },
     };
     use icicle_cuda_runtime::device_context;
     use icicle_cuda_runtime::memory::HostSlice;
@@ -39,6 +42,11 @@ pub(crate) mod tests {
         check_build_field_merkle_tree::<_, _, BabyBearTreeBuilder>(25, 2, &sponge, &sponge, ScalarField::zero());
     }
 
+    #[test]
+    fn tree_builder_config_test() {
+        check_tree_builder_config();
+    }
+
     type PlonkyPoseidon2T16 = PlonkyPoseidon2<BabyBear, Poseidon2ExternalMatrixGeneral, DiffusionMatrixBabyBear, 16, 7>;
 
     #[test]
@@ -73,10 +81,12 @@ pub(crate) mod tests {
 
         let (commit, _data) = mmcs.commit(leaves);
 
-        let mut config = TreeBuilderConfig::default();
-        config.arity = ARITY as u32;
-        config.keep_rows = 1;
-        config.digest_elements = COLS as u32;
+        let config = TreeBuilderConfig::builder()
+            .arity(ARITY as u32)
+            .keep_rows(1)
+            .digest_elements(COLS as u32)
+            .build()
+            .unwrap();
         let input_block_len = COLS;
         // let digests_len = merkle_tree_digests_len(2 as u32, ARITY as u32, COLS as u32);
         // let mut digests = vec![ScalarField::zero(); digests_len];
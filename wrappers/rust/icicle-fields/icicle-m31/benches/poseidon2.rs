@@ -0,0 +1,5 @@
+use icicle_m31::field::ScalarField;
+
+use icicle_core::impl_poseidon2_bench;
+
+impl_poseidon2_bench!("m31", ScalarField);
@@ -1,2 +1,4 @@
 pub mod field;
+pub mod poseidon2;
+pub mod tree;
 pub mod vec_ops;
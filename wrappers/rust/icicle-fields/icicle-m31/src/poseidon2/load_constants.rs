@@ -0,0 +1,193 @@
+// Constants loaded by `Poseidon2::load`, parsed from icicle/include/poseidon2/constants/m31_poseidon2.h so the tests
+// don't depend on the location of the C++ sources
+
+pub(crate) struct LoadConstants {
+    pub(crate) width: usize,
+    pub(crate) alpha: u32,
+    pub(crate) internal_rounds: u32,
+    pub(crate) internal_matrix_diag: &'static [u32],
+    pub(crate) round_constants: &'static [u32],
+}
+
+pub(crate) const LOAD_CONSTANTS: [LoadConstants; 8] = [
+    LoadConstants {
+        width: 2,
+        alpha: 5,
+        internal_rounds: 25,
+        internal_matrix_diag: &[0x00000001, 0x00000002],
+        round_constants: &[
+            0x4c7e5ab4, 0x5981a113, 0x776aae0f, 0x23f3b647, 0x333f99a3, 0x78e2b909, 0x72457fd4, 0x67fee18a, 0x749c1e5d,
+            0x20153da5, 0x288a369c, 0x39bb481a, 0x584d9033, 0x4fb38689, 0x2769dac8, 0x1350c5c0, 0x31bde689, 0x4d399c82,
+            0x3151e4ba, 0x56c67c21, 0x12aeae36, 0x7e7b0890, 0x28fc522c, 0x74766e76, 0x57d58330, 0x3414ea35, 0x38e5c441,
+            0x1e369712, 0x15b6d9f3, 0x4808490a, 0x0ad3f306, 0x70dd4d9e, 0x47f5a80f, 0x670f0a7d, 0x069515f5, 0x33e1c76b,
+            0x1388a4eb, 0x374f2db2, 0x0648f79a, 0x3418c169, 0x60735181, 0x71035d19, 0x561879af, 0x649d6193, 0x26fdb0a3,
+            0x0a50811d, 0x1e91c913, 0x293bcdd9, 0x01dcdb10,
+        ],
+    },
+    LoadConstants {
+        width: 3,
+        alpha: 5,
+        internal_rounds: 19,
+        internal_matrix_diag: &[0x00000001, 0x00000001, 0x00000002],
+        round_constants: &[
+            0x6630420a, 0x6e77b6b5, 0x085badf3, 0x06d17b3c, 0x2d2ca8bb, 0x3e585d13, 0x7f96d29c, 0x02c60c89, 0x3d73c75d,
+            0x44685392, 0x7545b9b7, 0x14abb4e4, 0x18bc8537, 0x204f93c1, 0x4081b364, 0x5cbe9f9b, 0x7f9f90a1, 0x3cbf7bc2,
+            0x268a6eab, 0x6f243411, 0x2c7554f8, 0x5fab2c61, 0x111376ae, 0x2cfe0ebe, 0x36b2e729, 0x29909ad3, 0x28b820d6,
+            0x54323e5d, 0x786e4d2e, 0x324f30d3, 0x65f93d17, 0x4f8c73b2, 0x23d9fe34, 0x17909bd1, 0x74864a54, 0x7120da84,
+            0x4c238376, 0x28d0791e, 0x154f4ab1, 0x27cd497e, 0x32d48f16, 0x005e68b6, 0x686a839b, 0x3c24c8fb, 0x530722d5,
+            0x23fe799a, 0x235dc05f, 0x1912a500, 0x5bb5c2b0, 0x5742d7d7, 0x37477606, 0x1d600471, 0x427a8b2c, 0x7d89eccd,
+            0x378e1019,
+        ],
+    },
+    LoadConstants {
+        width: 4,
+        alpha: 5,
+        internal_rounds: 22,
+        internal_matrix_diag: &[0x0f15107c, 0x4628fa64, 0x254112b8, 0x08a004cb],
+        round_constants: &[
+            0x16af2c22, 0x7804cd6b, 0x246e234a, 0x5dd3cd12, 0x321bfb5c, 0x5950358b, 0x7264a446, 0x78a0325e, 0x56c502c1,
+            0x459d26df, 0x73a880e9, 0x41ec4bfe, 0x5543742f, 0x72f68275, 0x19ed08b1, 0x147cda79, 0x637ee4ca, 0x0ad181b6,
+            0x06cba3e5, 0x6d19581a, 0x6e0ce518, 0x67ecfcba, 0x1338163a, 0x1b7388ed, 0x33965b46, 0x4e8fa553, 0x305411f4,
+            0x58754ce7, 0x4380543d, 0x4f3f78ec, 0x7e2bafa9, 0x60e96f27, 0x30158e74, 0x64dbacc0, 0x3516eedd, 0x200643cc,
+            0x714c3435, 0x35a7ae39, 0x22a8d380, 0x17b1c7b5, 0x14588589, 0x6325321f, 0x18a12d4f, 0x1cd62c68, 0x1ef5bafc,
+            0x083f3012, 0x6e334dc0, 0x590d574d, 0x14213a07, 0x74c3e1c4, 0x4b7fc4bd, 0x3a29253d, 0x014b8230, 0x13228033,
+        ],
+    },
+    LoadConstants {
+        width: 8,
+        alpha: 5,
+        internal_rounds: 13,
+        internal_matrix_diag: &[
+            0x03128b56, 0x4d7d3afe, 0x350942aa, 0x2ea8c3ed, 0x5ce80b48, 0x765afd80, 0x198f06fb, 0x7c1b2a16,
+        ],
+        round_constants: &[
+            0x12910754, 0x36ed49be, 0x07c97497, 0x40d71fed, 0x2dee313c, 0x26de4470, 0x5d7a5ae2, 0x1f1e33ff, 0x13c00c61,
+            0x0cc4e3f6, 0x54c32ba0, 0x4c77e54a, 0x7951cd04, 0x4c05faa2, 0x6e479da0, 0x38117730, 0x7374c4ce, 0x50281aea,
+            0x48232e55, 0x36539d1e, 0x5e5a4ac1, 0x77ea4408, 0x1cefa067, 0x09ce05ef, 0x26d24c81, 0x4c873b15, 0x77d2ea65,
+            0x56b28bb3, 0x2b2b9f8c, 0x4a57e3f9, 0x32c445a7, 0x782f41c7, 0x6c205891, 0x67503f5e, 0x756c593f, 0x0462479a,
+            0x4271c9ca, 0x4d1af3ce, 0x180e9999, 0x025328db, 0x398b8c99, 0x03f4755c, 0x68c74b5f, 0x5f548c81, 0x737a627f,
+            0x6177ee3d, 0x487567c7, 0x067f94f4, 0x2cd1087d, 0x304d9054, 0x490e2bab, 0x5c659e18, 0x7c47cce3, 0x61a387b5,
+            0x3857def0, 0x2624467f, 0x7ef53cc8, 0x1d2ad358, 0x796caff2, 0x10eac4fd, 0x7f4b54a5, 0x35086d00, 0x41b5005f,
+            0x49ab9f3e, 0x7d0cf6fe, 0x13904839, 0x67dc28cd, 0x28322a3b, 0x291d6b95, 0x4b5e6304, 0x5e4b793f, 0x1c3de5d4,
+            0x52cd150e, 0x7852055b, 0x3f71122b, 0x408ba008, 0x76d7ded9,
+        ],
+    },
+    LoadConstants {
+        width: 12,
+        alpha: 5,
+        internal_rounds: 12,
+        internal_matrix_diag: &[
+            0x4aa20d66, 0x3767088c, 0x5e82bd8a, 0x2833018e, 0x5942300f, 0x461b8a6c, 0x3c643b85, 0x4c08c05c, 0x569f76ce,
+            0x4728dbb1, 0x758d80eb, 0x33fa823c,
+        ],
+        round_constants: &[
+            0x68b6c0e0, 0x4ec257b5, 0x50d9f348, 0x2234cb53, 0x04e4b9ee, 0x305981fe, 0x3352aef9, 0x1c3c33a2, 0x7aacf58a,
+            0x06cba897, 0x7af46fc0, 0x51e5eb08, 0x094c6e20, 0x61460e39, 0x693211b7, 0x18cab2e2, 0x05ab3d0e, 0x61c0b778,
+            0x7ff68d71, 0x7eedb35c, 0x061ba0c6, 0x26634770, 0x3f0102a4, 0x4aaee298, 0x38e7a9e8, 0x06f98c0f, 0x75516ec1,
+            0x692857c5, 0x1d912c5c, 0x056d84ff, 0x2f2d7061, 0x2da16acf, 0x092aa6f8, 0x1ae7576c, 0x3ba868b1, 0x17199a1b,
+            0x3cad0e76, 0x42f237e3, 0x7d0453bc, 0x088dc5ca, 0x07288cf9, 0x5f1b1828, 0x4428bef1, 0x29cedc7d, 0x49e66341,
+            0x2072a1d1, 0x6803953e, 0x53bc35d3, 0x2b3dd9bd, 0x7aef95e6, 0x0e5b46d0, 0x7d822e91, 0x74a3ffcd, 0x2d1340e1,
+            0x059f279a, 0x54907d93, 0x3b46dbce, 0x77aaf554, 0x6cf5ea09, 0x5f1deed7, 0x3c462ae5, 0x14bdbb1f, 0x369dd818,
+            0x0d82f8a6, 0x1bff0de0, 0x5de6e94a, 0x3d48d65a, 0x06cf6d84, 0x7b48cc6a, 0x447b57d3, 0x76d578c5, 0x5be6b1f7,
+            0x539e1fa4, 0x3bd278c3, 0x09bc1c1c, 0x54b09520, 0x6dd578fb, 0x7817c3a1, 0x121990ef, 0x48460fa9, 0x10f7a489,
+            0x2dc1eb30, 0x6baed45f, 0x1f88aceb, 0x776749f5, 0x29f0e0a0, 0x39af070f, 0x3ba0ada6, 0x1c9d037c, 0x67ec538e,
+            0x47269542, 0x31903718, 0x3f7c997c, 0x1edaeedc, 0x6e6588c8, 0x13dc160e, 0x7a6589b3, 0x0ba42ae7, 0x405c7385,
+            0x3fa52e7f, 0x20cb72d1, 0x65935cca, 0x6cb5cea0, 0x37fcdd04, 0x56bb831d, 0x17e6dd1f, 0x2a948729, 0x68ae5b61,
+        ],
+    },
+    LoadConstants {
+        width: 16,
+        alpha: 5,
+        internal_rounds: 14,
+        internal_matrix_diag: &[
+            0x07b80ac4, 0x6bd9cb33, 0x48ee3f9f, 0x4f63dd19, 0x18c546b3, 0x5af89e8b, 0x4ff23de8, 0x4f78aaf6, 0x53bdc6d4,
+            0x5c59823e, 0x2a471c72, 0x4c975e79, 0x58dc64d4, 0x06e9315d, 0x2cf32286, 0x2fb6755d,
+        ],
+        round_constants: &[
+            0x768bab52, 0x70e0ab7d, 0x3d266c8a, 0x6da42045, 0x600fef22, 0x41dace6b, 0x64f9bdd4, 0x5d42d4fe, 0x76b1516d,
+            0x6fc9a717, 0x70ac4fb6, 0x00194ef6, 0x22b644e2, 0x1f7916d5, 0x47581be2, 0x2710a123, 0x6284e867, 0x018d3afe,
+            0x5df99ef3, 0x4c1e467b, 0x566f6abc, 0x2994e427, 0x538a6d42, 0x5d7bf2cf, 0x7fda2dab, 0x0fd854c4, 0x46922fca,
+            0x3d7763a1, 0x19fd05ca, 0x0a4bbb43, 0x15075851, 0x3d903d76, 0x2d290ff7, 0x40809fa0, 0x59dac6ec, 0x127927a2,
+            0x6bbf0ea0, 0x0294140f, 0x24742976, 0x6e84c081, 0x22484f4a, 0x354cae59, 0x0453ffe1, 0x3f47a3cc, 0x0088204e,
+            0x6066e109, 0x3b7c4b80, 0x6b55665d, 0x3bc4b897, 0x735bf378, 0x508daf42, 0x1884fc2b, 0x7214f24c, 0x7498be0a,
+            0x1a60e640, 0x3303f928, 0x29b46376, 0x5c96bb68, 0x65d097a5, 0x1d358e9f, 0x4a9a9017, 0x4724cf76, 0x347af70f,
+            0x1e77e59a, 0x7f7ec4bf, 0x0421926f, 0x5198e669, 0x34db3148, 0x4368bafd, 0x66685c7f, 0x78d3249a, 0x60187881,
+            0x76dad67a, 0x0690b437, 0x1ea95311, 0x40e5369a, 0x38f103fc, 0x1d226a21, 0x57090613, 0x1fa42108, 0x17bbef50,
+            0x1ff7e11c, 0x047b24ca, 0x4e140275, 0x4fa086f5, 0x079b309c, 0x1159bd47, 0x6d37e4e5, 0x075d8dce, 0x12121ca0,
+            0x7f6a7c40, 0x68e182ba, 0x5493201b, 0x0444a80e, 0x0064f4c6, 0x6467abe6, 0x66975762, 0x2af68f9b, 0x345b33be,
+            0x1b70d47f, 0x053db717, 0x381189cb, 0x43b915f8, 0x20df3694, 0x0f459d26, 0x77a0e97b, 0x2f73e739, 0x1876c2f9,
+            0x65a0e29a, 0x4cabefbe, 0x5abd1268, 0x4d34a760, 0x12771799, 0x69a0c9ac, 0x39091e55, 0x7f611cd0, 0x3af055da,
+            0x7ac0bbdf, 0x6e0f3a24, 0x41e3b6f7, 0x49b3756d, 0x568bc538, 0x20c079d8, 0x1701c72c, 0x7670dc6c, 0x5a439035,
+            0x7c93e00e, 0x561fbb4d, 0x1178907b, 0x02737406, 0x32fb24f1, 0x6323b60a, 0x6ab12418, 0x42c99cea, 0x155a0b97,
+            0x53d1c6aa, 0x2bd20347, 0x279b3d73, 0x4f5f3c70, 0x0245af6c, 0x238359d3, 0x49966a59,
+        ],
+    },
+    LoadConstants {
+        width: 20,
+        alpha: 5,
+        internal_rounds: 18,
+        internal_matrix_diag: &[
+            0x01dae7ff, 0x7ff6f82a, 0x34a931ac, 0x5615364b, 0x7a21d142, 0x5c54896c, 0x2d0b6848, 0x03bcadb3, 0x4a99a480,
+            0x3d1d966f, 0x1f312fcf, 0x2fd5a425, 0x5077536e, 0x6e6534be, 0x281e1387, 0x23d4b042, 0x17196fb7, 0x5e92eacd,
+            0x28a23c08, 0x2e8a998c,
+        ],
+        round_constants: &[
+            0x30cd4497, 0x67cf7d92, 0x113ca786, 0x28f511f7, 0x4c850311, 0x3dc07be8, 0x0c8214b9, 0x18090d97, 0x684f35fb,
+            0x7f4e478e, 0x11eeba68, 0x567d0ca7, 0x6d3fbebc, 0x47028460, 0x09a8d433, 0x46bc5d5c, 0x110805b2, 0x50dddc82,
+            0x001ee4fa, 0x55ed4719, 0x4708bdce, 0x1a530b2b, 0x6d372400, 0x32101d42, 0x02b0d7d0, 0x3064f512, 0x670f3c64,
+            0x35735e31, 0x5a2b6144, 0x210d6ba6, 0x1274e66a, 0x212e3e09, 0x57e62ff0, 0x6b631874, 0x6b577a1b, 0x0d17fdaa,
+            0x24ebe81b, 0x34f6c9fe, 0x591d5a55, 0x5f45a942, 0x5e454f3f, 0x23b75633, 0x55076170, 0x3caa9482, 0x6a2cbf00,
+            0x7224801b, 0x74bb2d64, 0x2e6d0990, 0x78317bf5, 0x23eab921, 0x663603a8, 0x03e8e97f, 0x04db5d3d, 0x64c1e827,
+            0x37c478fe, 0x35efc805, 0x555aaef9, 0x6563ad0c, 0x14c9aeac, 0x16d6e172, 0x424fcbe9, 0x30380855, 0x74d4e5bf,
+            0x1275ba83, 0x56f32a2d, 0x3cf97f89, 0x440c5711, 0x48687e8a, 0x496600f8, 0x0b0e429f, 0x154b1e26, 0x379bb8ce,
+            0x277683e5, 0x4403e094, 0x12033240, 0x3bd006d7, 0x4ee6ee1b, 0x62c6764c, 0x7230552f, 0x4f341de7, 0x3c8f8c49,
+            0x6fc6bee2, 0x0a3ef74d, 0x23710107, 0x24650c82, 0x12baebd4, 0x46c2f3ce, 0x57816fd9, 0x443fe51c, 0x2a7805ab,
+            0x3f508ef1, 0x150b836c, 0x1c014ffa, 0x1c0786be, 0x10645190, 0x607b9c0a, 0x48961505, 0x6f20b7bb, 0x5b5aea23,
+            0x1fe5355c, 0x27ef98aa, 0x043e120b, 0x647d5ccd, 0x455c81b2, 0x6ac27d3f, 0x279f6126, 0x76a15758, 0x58c1b0a6,
+            0x4af83901, 0x45641d4f, 0x1dfe8c33, 0x03720c88, 0x3482d40d, 0x770d8960, 0x6108b730, 0x063fc084, 0x096e2a34,
+            0x130514a0, 0x42bc3eff, 0x43775848, 0x702960e4, 0x6cf19c79, 0x4c3030dd, 0x6dd27932, 0x64350de9, 0x480e8311,
+            0x6a22632c, 0x2814bcf6, 0x117197e3, 0x4844d419, 0x76e181a6, 0x6155d7b4, 0x42dbed26, 0x78c7db7c, 0x62e9c27b,
+            0x105b4f11, 0x51d45858, 0x33fa0298, 0x26a08228, 0x5f14581f, 0x3bd89737, 0x2325968f, 0x418512ba, 0x7e416920,
+            0x2f47442f, 0x0e680af5, 0x0c424cba, 0x6d3885ae, 0x0a9c8cbe, 0x2485f2a9, 0x1f99357f, 0x0f58d531, 0x32d6f6b1,
+            0x2c26876d, 0x2de3d38e, 0x5a7b6cc9, 0x1caec7f0, 0x4aa1f575, 0x77af0ccd, 0x19d3be5c, 0x0aa619d8, 0x04cfea78,
+            0x0db3ab76, 0x2ddffaa3, 0x52246767, 0x78632df8, 0x7bd54641, 0x50d7f018, 0x318ceb9f, 0x2e69a506, 0x4bd9d1fa,
+            0x2a0b579a, 0x561238c2, 0x2877bb9a, 0x06ed2c79, 0x229998ba, 0x737f0185, 0x609aad31,
+        ],
+    },
+    LoadConstants {
+        width: 24,
+        alpha: 5,
+        internal_rounds: 22,
+        internal_matrix_diag: &[
+            0x0cd7ecea, 0x074a071e, 0x66aaaefe, 0x1c334b65, 0x4d094cb0, 0x2f8579a9, 0x27094719, 0x710093cd, 0x53d435ac,
+            0x7306a10f, 0x57568905, 0x4967d8df, 0x7f74627e, 0x06821459, 0x478d7524, 0x1eacc228, 0x651f1140, 0x42f397ef,
+            0x24e49ad6, 0x5ca356ff, 0x42f300e9, 0x2fd01084, 0x28a86483, 0x104402b4,
+        ],
+        round_constants: &[
+            0x1feaba61, 0x53224454, 0x6bceb9e2, 0x5019f9b4, 0x48726592, 0x2b22d0a8, 0x6151bbf9, 0x2f474b21, 0x2eb5f337,
+            0x3b645d87, 0x0942cef0, 0x65228c52, 0x78ffb30f, 0x4d2837c8, 0x0e17ac4f, 0x05546686, 0x046c06cc, 0x0b51c3b6,
+            0x568db763, 0x38b334e4, 0x57f5acf0, 0x19d32611, 0x77d02f4b, 0x6c82e9b8, 0x7148c1b6, 0x08067c75, 0x46d1e8c9,
+            0x30973b07, 0x20614f3b, 0x5c3ff851, 0x30503329, 0x4972e7cc, 0x02d1d8bc, 0x09d5bfa6, 0x097104c0, 0x7ba49a34,
+            0x4a07c2fc, 0x24c1ee69, 0x28a6ab41, 0x5d9108a0, 0x3a7851c7, 0x1dd495f9, 0x12b49ff4, 0x7bad5760, 0x5fed64c2,
+            0x66f5c96c, 0x7eafbd02, 0x39b3593b, 0x4a653b49, 0x75091dc1, 0x56e488e0, 0x1704a355, 0x745e4ff3, 0x392ef16e,
+            0x31e33fdf, 0x02c28c66, 0x36c3083a, 0x3104d1fa, 0x5b03cda3, 0x6641e1af, 0x37754b56, 0x396f5af9, 0x1a1a461a,
+            0x688e26f2, 0x6f829784, 0x1bb91d69, 0x5b788016, 0x704aa5c5, 0x0181869c, 0x41211e56, 0x0ce803a0, 0x23bff3a0,
+            0x17fb7064, 0x47317220, 0x76914b53, 0x219c1905, 0x16655528, 0x4df35544, 0x60808465, 0x3350f833, 0x03bccdc7,
+            0x0a87180a, 0x017a99f5, 0x6e945726, 0x15445504, 0x780533b1, 0x3b91bf38, 0x3fc77eb1, 0x4b4d960e, 0x3cd93d2e,
+            0x0ea4e976, 0x1d5306cc, 0x3a7ac284, 0x0ec22934, 0x4d979713, 0x51a41c65, 0x22776a11, 0x5fa34268, 0x1415528d,
+            0x563fbd14, 0x34f45244, 0x120ea1b6, 0x261368a5, 0x27665ec1, 0x36be2805, 0x345c4784, 0x17efdcc1, 0x393e6530,
+            0x6da0b4b8, 0x31e5ded3, 0x675b27ac, 0x0ae88c30, 0x577841cc, 0x5fe06dec, 0x56b0691a, 0x7242de1f, 0x3c377529,
+            0x339b7523, 0x1c662299, 0x057c955a, 0x7ab6c0f2, 0x25a6ad0a, 0x75850b58, 0x48fd3793, 0x0b4366b1, 0x0fdd0d49,
+            0x7db419f9, 0x49b9cc0f, 0x48949716, 0x29c35890, 0x76445485, 0x1c27d30c, 0x10aa7a3b, 0x30f34fb6, 0x6fe06435,
+            0x02135ecd, 0x6caaba96, 0x3eb290d0, 0x22fd8d3b, 0x768b1525, 0x5be95814, 0x523d7fe9, 0x55e94cec, 0x47c42e1f,
+            0x1aa53b5e, 0x2fd1fe7e, 0x59230e91, 0x7472da66, 0x6443f2df, 0x2d9de19d, 0x6f7f6a84, 0x77800430, 0x0f014bc8,
+            0x7bf3d095, 0x26afd318, 0x582561f7, 0x5ee3198c, 0x6acc0000, 0x2f315e26, 0x27cac040, 0x2595081e, 0x5963b7da,
+            0x7e073565, 0x6cf3f5f1, 0x09f8a3a4, 0x0da8ccfe, 0x60be2365, 0x7ed742f5, 0x668b8031, 0x4bb03494, 0x59019333,
+            0x700e2878, 0x1cc45856, 0x1d1617f7, 0x7b988da6, 0x4eb4936c, 0x78c9f87e, 0x63ce3e94, 0x7178341b, 0x45bc2f86,
+            0x05b775bc, 0x704b0244, 0x29eed278, 0x47f43032, 0x2127b2e5, 0x1997903f, 0x24b3ce03, 0x0c32298c, 0x7d2b6f3a,
+            0x17fcaa81, 0x72f37fef, 0x3028e7a9, 0x5edd4d96, 0x1f96583b, 0x4cd6918a, 0x14880f0e, 0x69170359, 0x173cbd33,
+            0x0969e7f4, 0x6e7f23ab, 0x6182ea87, 0x4dcb1f5c, 0x585fa113, 0x729cb3b6, 0x01b3a27a, 0x1ba173e7, 0x4b33bcea,
+            0x63d93bbb, 0x6b3fbf99, 0x6f17e9d1, 0x0c3dd8ba, 0x0bc1f9a8, 0x64d3f370, 0x465a6a18,
+        ],
+    },
+];
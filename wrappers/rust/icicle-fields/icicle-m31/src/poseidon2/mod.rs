@@ -0,0 +1,194 @@
+use crate::field::{ScalarCfg, ScalarField};
+
+use icicle_core::error::IcicleResult;
+use icicle_core::hash::HashConfig;
+use icicle_core::impl_poseidon2;
+use icicle_core::poseidon2::{DiffusionStrategy, MdsType, Poseidon2Handle, Poseidon2Impl};
+use icicle_core::traits::IcicleResultWrap;
+use icicle_cuda_runtime::device_context::DeviceContext;
+use icicle_cuda_runtime::error::CudaError;
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use core::mem::MaybeUninit;
+
+impl_poseidon2!("m31", m31, ScalarField, ScalarCfg);
+
+#[cfg(test)]
+mod load_constants;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::load_constants::{LoadConstants, LOAD_CONSTANTS};
+    use crate::field::{ScalarCfg, ScalarField};
+    use icicle_core::hash::SpongeHash;
+    use icicle_core::impl_poseidon2_tests;
+    use icicle_core::poseidon2::{
+        constants::Poseidon2Constants, host::HostPoseidon2, tests::*, DiffusionStrategy, MdsType,
+    };
+    use icicle_core::traits::{FieldImpl, GenerateRandom};
+    use icicle_cuda_runtime::memory::HostSlice;
+
+    impl_poseidon2_tests!(ScalarField);
+
+    const EXTERNAL_ROUNDS: usize = 8;
+
+    fn load_constants(width: usize) -> &'static LoadConstants {
+        LOAD_CONSTANTS
+            .iter()
+            .find(|constants| constants.width == width)
+            .unwrap()
+    }
+
+    /// Host sponge with the constants of `Poseidon2::load(width, rate, MdsType::Default, DiffusionStrategy::Default)`.
+    pub(crate) fn load_host_poseidon2(width: usize, rate: usize) -> HostPoseidon2<ScalarField> {
+        let constants = load_constants(width);
+
+        let internal_rounds = constants.internal_rounds;
+        let internal_matrix_diag = field_elements(constants.internal_matrix_diag);
+        let mut round_constants = field_elements(constants.round_constants);
+        // Narrow widths ship more constants than the kernels consume, only the leading ones are used
+        round_constants.truncate(EXTERNAL_ROUNDS * width + internal_rounds as usize);
+
+        HostPoseidon2::new(
+            width,
+            rate,
+            constants.alpha,
+            internal_rounds,
+            EXTERNAL_ROUNDS as u32,
+            &round_constants,
+            &internal_matrix_diag,
+            MdsType::Default,
+            DiffusionStrategy::Default,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_poseidon2_constants_match_load() {
+        for width in [2, 3, 4, 8, 12, 16, 20, 24] {
+            let loaded = load_constants(width);
+            let constants = Poseidon2Constants::<ScalarField>::generate(width, loaded.alpha, 128).unwrap();
+
+            assert_eq!(constants.internal_rounds, loaded.internal_rounds);
+            assert_eq!(constants.round_constants, field_elements(loaded.round_constants));
+            assert_eq!(
+                constants.internal_matrix_diag,
+                field_elements(loaded.internal_matrix_diag)
+            );
+        }
+    }
+
+    fn field_elements(values: &[u32]) -> Vec<ScalarField> {
+        values
+            .iter()
+            .map(|&x| ScalarField::from_u32(x))
+            .collect()
+    }
+
+    fn check_host_kats(width: usize, kats: &[ScalarField]) {
+        let mut state: Vec<ScalarField> = (0..width as u32)
+            .map(ScalarField::from_u32)
+            .collect();
+        load_host_poseidon2(width, width).permute(&mut state);
+        assert_eq!(state, kats);
+    }
+
+    #[test]
+    fn test_poseidon2_kats_t16() {
+        const WIDTH: usize = 16;
+        let kats = field_elements(&[
+            0x505d9689, 0x3b64c904, 0x79e2fd81, 0x4ba8015f, 0x24b6d2f5, 0x23845add, 0x521f4314, 0x69dfb019, 0x2aaae419,
+            0x6cb4502c, 0x6f7fa65a, 0x75feff24, 0x128d6587, 0x515877e4, 0x037f4dd7, 0x134b427f,
+        ]);
+
+        check_host_kats(WIDTH, &kats);
+
+        let poseidon = init_poseidon::<ScalarField>(WIDTH, MdsType::Default, DiffusionStrategy::Default);
+        check_poseidon_kats(WIDTH, &kats, &poseidon);
+    }
+
+    #[test]
+    fn test_poseidon2_kats_t24() {
+        const WIDTH: usize = 24;
+        let kats = field_elements(&[
+            0x30760a99, 0x38fdd606, 0x784386d8, 0x3605db49, 0x71d446dd, 0x5d83fa40, 0x3ea7eb5c, 0x722b6b82, 0x06d7645c,
+            0x6507e47f, 0x035be329, 0x3b1563d6, 0x4c7f1bce, 0x6a463721, 0x054f919b, 0x3c42aaa4, 0x043c42d8, 0x526b6bc9,
+            0x6766263e, 0x0b00751d, 0x479788f5, 0x7754570b, 0x50564d9d, 0x3d309a9e,
+        ]);
+
+        check_host_kats(WIDTH, &kats);
+
+        let poseidon = init_poseidon::<ScalarField>(WIDTH, MdsType::Default, DiffusionStrategy::Default);
+        check_poseidon_kats(WIDTH, &kats, &poseidon);
+    }
+
+    #[test]
+    fn test_poseidon2_host_permutation() {
+        let number_of_states = 1 << 8;
+        for width in [2, 3, 4, 8, 12, 16, 20, 24] {
+            let host_poseidon = load_host_poseidon2(width, width);
+            let poseidon = init_poseidon::<ScalarField>(width, MdsType::Default, DiffusionStrategy::Default);
+
+            let inputs = ScalarCfg::generate_random(number_of_states * width);
+            let mut outputs = vec![ScalarField::zero(); number_of_states * width];
+            let mut host_outputs = vec![ScalarField::zero(); number_of_states * width];
+
+            let cfg = poseidon.default_config();
+            poseidon
+                .hash_many(
+                    HostSlice::from_slice(&inputs),
+                    HostSlice::from_mut_slice(&mut outputs),
+                    number_of_states,
+                    width,
+                    width,
+                    &cfg,
+                )
+                .unwrap();
+            host_poseidon
+                .hash_many(
+                    HostSlice::from_slice(&inputs),
+                    HostSlice::from_mut_slice(&mut host_outputs),
+                    number_of_states,
+                    width,
+                    width,
+                    &cfg,
+                )
+                .unwrap();
+
+            assert_eq!(outputs, host_outputs);
+        }
+    }
+
+    mod transcript {
+        use crate::field::{ExtensionField, ScalarField};
+        use icicle_core::impl_transcript_tests;
+        use icicle_core::poseidon2::{constants::Poseidon2Constants, host::HostPoseidon2};
+        use icicle_core::traits::FieldImpl;
+        use icicle_core::transcript::{tests::*, SpongeTranscript, Transcript};
+
+        impl_transcript_tests!(ScalarField);
+
+        #[test]
+        fn test_sponge_transcript_vectors() {
+            // The constants of `Poseidon2::load` for width 16
+            let constants = Poseidon2Constants::<ScalarField>::generate(16, 5, 128).unwrap();
+            let poseidon = HostPoseidon2::from_constants(&constants, 16).unwrap();
+            let mut transcript = SpongeTranscript::new(&poseidon, 8, b"icicle-m31").unwrap();
+
+            transcript
+                .append_bytes(b"commitment", &[0xab; 32])
+                .unwrap();
+            transcript
+                .append_scalar(b"evaluation", &ScalarField::from_u32(1234567))
+                .unwrap();
+            let alpha = transcript
+                .challenge_scalar(b"alpha")
+                .unwrap();
+            let beta = transcript
+                .challenge_ext::<ExtensionField>(b"beta")
+                .unwrap();
+            assert_eq!(alpha, ScalarField::from_hex("0x38f5eab1"));
+            assert_eq!(beta, ExtensionField::from_hex("0x3008db924acde8927154fe9375864411"));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use icicle_core::error::IcicleResult;
+use icicle_core::hash::SpongeHash;
+use icicle_core::traits::IcicleResultWrap;
+use icicle_core::tree::{mmcs::FieldMmcs, TreeBuilderConfig};
+use icicle_core::{impl_mmcs, Matrix};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+use std::ffi::c_void;
+
+use crate::field::ScalarField;
+
+impl_mmcs!("m31", m31_mmcs, ScalarField, ScalarCfg, M31Mmcs);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::ffi::c_void;
+
+    use icicle_core::{
+        ntt::FieldImpl,
+        poseidon2::{DiffusionStrategy, MdsType, Poseidon2},
+        tree::{
+            merkle_tree_digests_len,
+            mmcs::{mmcs_commit_host, MatrixDims},
+            tests::{check_mmcs_multi_proof, host_merkle_digests},
+            TreeBuilderConfig,
+        },
+        Matrix,
+    };
+    use icicle_cuda_runtime::device_context::DeviceContext;
+    use icicle_cuda_runtime::memory::HostSlice;
+
+    use crate::{
+        field::ScalarField,
+        poseidon2::tests::load_host_poseidon2,
+        tree::mmcs::{FieldMmcs, M31Mmcs},
+    };
+
+    #[test]
+    fn test_poseidon2_mmcs_host() {
+        const WIDTH: usize = 16;
+        const RATE: usize = 8;
+        const ARITY: usize = 2;
+        const HEIGHT: usize = 10;
+        const ROWS: usize = 1 << HEIGHT;
+        const COLS: usize = 8;
+        const DIGEST_ELEMENTS: usize = 8;
+
+        let ctx = DeviceContext::default();
+        let poseidon = Poseidon2::load(WIDTH, RATE, MdsType::Default, DiffusionStrategy::Default, &ctx).unwrap();
+        let host_sponge = load_host_poseidon2(WIDTH, RATE);
+        let host_compression = load_host_poseidon2(WIDTH, WIDTH);
+
+        let input: Vec<ScalarField> = (0..(ROWS * COLS) as u32)
+            .map(ScalarField::from_u32)
+            .collect();
+
+        let config = TreeBuilderConfig::builder()
+            .arity(ARITY as u32)
+            .keep_rows(HEIGHT as u32 + 1)
+            .digest_elements(DIGEST_ELEMENTS as u32)
+            .build()
+            .unwrap();
+        let digests_len = merkle_tree_digests_len(HEIGHT as u32, ARITY as u32, DIGEST_ELEMENTS as u32);
+        let mut digests = vec![ScalarField::zero(); digests_len];
+
+        let leaves = vec![Matrix {
+            values: input.as_ptr() as *const c_void,
+            width: COLS,
+            height: ROWS,
+        }];
+        let digests_slice = HostSlice::from_mut_slice(&mut digests);
+
+        M31Mmcs::mmcs_commit(leaves, digests_slice, &poseidon, &poseidon, &config).unwrap();
+
+        // With a single matrix whose rows fit into the rate, MMCS degenerates into a plain Merkle tree
+        let host_digests = host_merkle_digests(&input, COLS, DIGEST_ELEMENTS, ARITY, &host_sponge, &host_compression);
+        assert_eq!(
+            digests_slice[digests_len - DIGEST_ELEMENTS..].as_slice(),
+            &host_digests[digests_len - DIGEST_ELEMENTS..]
+        );
+    }
+
+    #[test]
+    fn test_mmcs_multi_proof_host() {
+        // The sponges of the device test, the rows of injected matrices are absorbed at the rate of the compression
+        let hasher = load_host_poseidon2(16, 8);
+        check_mmcs_multi_proof(&hasher, &hasher, 8);
+    }
+
+    #[test]
+    fn test_mmcs_multi_proof() {
+        const DIGEST_ELEMENTS: usize = 8;
+        const HEIGHT: usize = 6;
+
+        let ctx = DeviceContext::default();
+        let poseidon = Poseidon2::load(16, 8, MdsType::Default, DiffusionStrategy::Default, &ctx).unwrap();
+        let host_poseidon = load_host_poseidon2(16, 8);
+
+        // One matrix per height, the device sorts matrices of equal heights in no particular order
+        let dims = [(10, 1 << HEIGHT), (3, 20), (4, 5), (7, 1)].map(|(width, height)| MatrixDims { width, height });
+        let values: Vec<Vec<ScalarField>> = dims
+            .iter()
+            .map(|dims| {
+                (0..(dims.width * dims.height) as u32)
+                    .map(|i| ScalarField::from_u32(i * 3 + 1))
+                    .collect()
+            })
+            .collect();
+        let matrices = || -> Vec<Matrix> {
+            values
+                .iter()
+                .zip(dims)
+                .map(|(values, dims)| Matrix {
+                    values: values.as_ptr() as *const c_void,
+                    width: dims.width,
+                    height: dims.height,
+                })
+                .collect()
+        };
+
+        let config = TreeBuilderConfig::builder()
+            .digest_elements(DIGEST_ELEMENTS as u32)
+            .build()
+            .unwrap();
+        let mut digests = vec![ScalarField::zero(); merkle_tree_digests_len(HEIGHT as u32, 2, DIGEST_ELEMENTS as u32)];
+        M31Mmcs::mmcs_commit(
+            matrices(),
+            HostSlice::from_mut_slice(&mut digests),
+            &poseidon,
+            &poseidon,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            digests,
+            mmcs_commit_host(&matrices(), DIGEST_ELEMENTS, &host_poseidon, &host_poseidon).unwrap()
+        );
+
+        let root = &digests[digests.len() - DIGEST_ELEMENTS..];
+        let proof =
+            <M31Mmcs as FieldMmcs<ScalarField, Poseidon2<ScalarField>, Poseidon2<ScalarField>>>::mmcs_open_multi(
+                &matrices(),
+                &digests,
+                &config,
+                &[3, 17, 18, 63],
+            )
+            .unwrap();
+        assert!(
+            <M31Mmcs as FieldMmcs<ScalarField, Poseidon2<ScalarField>, Poseidon2<ScalarField>>>::mmcs_verify_multi(
+                &proof,
+                root,
+                &dims,
+                &host_poseidon,
+                &host_poseidon
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use icicle_core::error::IcicleResult;
+use icicle_core::hash::SpongeHash;
+use icicle_core::impl_field_tree_builder;
+use icicle_core::traits::IcicleResultWrap;
+use icicle_core::tree::{FieldTreeBuilder, TreeBuilderConfig};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+use std::ffi::c_void;
+
+use crate::field::ScalarField;
+
+pub mod mmcs;
+
+impl_field_tree_builder!("m31", m31_tb, ScalarField, ScalarCfg, M31TreeBuilder);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use icicle_core::{
+        ntt::FieldImpl,
+        poseidon2::{DiffusionStrategy, MdsType, Poseidon2},
+        tree::{
+            tests::{
+                check_build_field_merkle_tree, check_incremental_merkle_tree, check_multi_proof,
+                check_sparse_merkle_tree, check_tree_builder_config, host_merkle_digests,
+            },
+            FieldTreeBuilder, TreeBuilderConfig,
+        },
+    };
+    use icicle_cuda_runtime::device_context;
+    use icicle_cuda_runtime::memory::HostSlice;
+
+    use crate::{field::ScalarField, poseidon2::tests::load_host_poseidon2, tree::M31TreeBuilder};
+
+    #[test]
+    fn poseidon2_merkle_tree_test() {
+        let ctx = device_context::DeviceContext::default();
+        let sponge = Poseidon2::load(2, 2, MdsType::Default, DiffusionStrategy::Default, &ctx).unwrap();
+
+        check_build_field_merkle_tree::<_, _, M31TreeBuilder>(25, 2, &sponge, &sponge, ScalarField::zero());
+    }
+
+    #[test]
+    fn tree_builder_config_test() {
+        check_tree_builder_config();
+    }
+
+    #[test]
+    fn test_poseidon2_tree_host() {
+        const WIDTH: usize = 16;
+        const ARITY: usize = 2;
+        const HEIGHT: usize = 10;
+        const ROWS: usize = 1 << HEIGHT;
+        const COLS: usize = 8;
+
+        let ctx = device_context::DeviceContext::default();
+        let poseidon = Poseidon2::load(WIDTH, COLS, MdsType::Default, DiffusionStrategy::Default, &ctx).unwrap();
+        let host_sponge = load_host_poseidon2(WIDTH, COLS);
+        let host_compression = load_host_poseidon2(WIDTH, WIDTH);
+
+        let input: Vec<ScalarField> = (0..(ROWS * COLS) as u32)
+            .map(ScalarField::from_u32)
+            .collect();
+
+        let config = TreeBuilderConfig::builder()
+            .arity(ARITY as u32)
+            .keep_rows(1)
+            .digest_elements(COLS as u32)
+            .build()
+            .unwrap();
+        let mut digests = vec![ScalarField::zero(); COLS];
+
+        M31TreeBuilder::build_merkle_tree(
+            HostSlice::from_slice(&input),
+            HostSlice::from_mut_slice(&mut digests),
+            HEIGHT,
+            COLS,
+            &poseidon,
+            &poseidon,
+            &config,
+        )
+        .unwrap();
+
+        let host_digests = host_merkle_digests(&input, COLS, COLS, ARITY, &host_sponge, &host_compression);
+        assert_eq!(digests, host_digests[host_digests.len() - COLS..]);
+    }
+
+    #[test]
+    fn test_sparse_merkle_tree_host() {
+        let host_compression = load_host_poseidon2(16, 16);
+        check_sparse_merkle_tree(&host_compression);
+    }
+
+    #[test]
+    fn test_incremental_merkle_tree_host() {
+        let host_sponge = load_host_poseidon2(16, 8);
+        let host_compression = load_host_poseidon2(16, 16);
+
+        let config = TreeBuilderConfig::builder()
+            .digest_elements(8)
+            .build()
+            .unwrap();
+        check_incremental_merkle_tree(6, 8, &host_sponge, &host_compression, &config);
+
+        let config = TreeBuilderConfig::builder()
+            .arity(4)
+            .digest_elements(4)
+            .build()
+            .unwrap();
+        check_incremental_merkle_tree(3, 5, &host_sponge, &host_compression, &config);
+    }
+
+    #[test]
+    fn test_multi_proof_host() {
+        let host_sponge = load_host_poseidon2(16, 8);
+        let host_compression = load_host_poseidon2(16, 16);
+
+        let config = TreeBuilderConfig::builder()
+            .digest_elements(8)
+            .build()
+            .unwrap();
+        check_multi_proof(6, 8, &host_sponge, &host_compression, &config);
+
+        let config = TreeBuilderConfig::builder()
+            .arity(4)
+            .digest_elements(4)
+            .build()
+            .unwrap();
+        check_multi_proof(3, 5, &host_sponge, &host_compression, &config);
+    }
+}
@@ -1,5 +1,5 @@
 use icicle_core::field::{Field, MontgomeryConvertibleField};
-use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom};
+use icicle_core::traits::{FieldConfig, FieldImpl, GenerateRandom, PrimeFieldConfig};
 use icicle_core::{impl_field, impl_scalar_field};
 use icicle_cuda_runtime::device::check_device;
 use icicle_cuda_runtime::device_context::DeviceContext;
@@ -9,11 +9,19 @@ use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice};
 pub(crate) const SCALAR_LIMBS: usize = 8;
 
 impl_scalar_field!("stark252", stark252, SCALAR_LIMBS, ScalarField, ScalarCfg, Fr);
+
+impl PrimeFieldConfig for ScalarCfg {
+    const MODULUS: &'static [u32] = &[
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000011, 0x08000000,
+    ];
+}
+
 #[cfg(test)]
 mod tests {
     use super::ScalarField;
-    use icicle_core::impl_field_tests;
     use icicle_core::tests::*;
+    use icicle_core::{impl_field_arithmetic_tests, impl_field_tests};
 
     impl_field_tests!(ScalarField);
+    impl_field_arithmetic_tests!(ScalarField);
 }
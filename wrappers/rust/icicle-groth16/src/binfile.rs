@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use icicle_core::{
+    curve::{Affine, Curve},
+    error::{IcicleError, IcicleErrorCode, IcicleResult},
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+fn io_error<T>(result: std::io::Result<T>) -> IcicleResult<T> {
+    result.map_err(|_| IcicleError::from_code_and_reason(IcicleErrorCode::UndefinedError, "Circuit file I/O failed"))
+}
+
+/// Sections of a file in the iden3 binary container shared by `.r1cs`, `.wtns` and `.zkey`: a 4 bytes magic, a
+/// version and a number of sections, each section being its type, its length on 8 bytes and its data.
+pub(crate) struct BinFile {
+    sections: BTreeMap<u32, Vec<u8>>,
+}
+
+impl BinFile {
+    pub(crate) fn read(mut reader: impl Read, magic: &[u8; 4], max_version: u32) -> IcicleResult<Self> {
+        let mut header_bytes = [0u8; 12];
+        io_error(reader.read_exact(&mut header_bytes))?;
+        let mut header = SectionReader { bytes: &header_bytes };
+        let file_magic = header.bytes(4)?;
+        let version = header.u32()?;
+        if file_magic != magic || version == 0 || version > max_version {
+            return Err(IcicleError::invalid_argument(
+                "Unsupported circuit file type or version",
+            ));
+        }
+
+        let mut sections = BTreeMap::new();
+        for _ in 0..header.u32()? {
+            let mut section_header_bytes = [0u8; 12];
+            io_error(reader.read_exact(&mut section_header_bytes))?;
+            let mut section_header = SectionReader {
+                bytes: &section_header_bytes,
+            };
+            let section = section_header.u32()?;
+            let len = section_header.u64()?;
+            let mut data = Vec::new();
+            io_error(
+                (&mut reader)
+                    .take(len)
+                    .read_to_end(&mut data),
+            )?;
+            if data.len() as u64 != len {
+                return Err(IcicleError::invalid_argument("Truncated circuit file"));
+            }
+            sections.insert(section, data);
+        }
+        Ok(Self { sections })
+    }
+
+    pub(crate) fn section(&self, section: u32) -> IcicleResult<SectionReader<'_>> {
+        self.sections
+            .get(&section)
+            .map(|bytes| SectionReader { bytes })
+            .ok_or(IcicleError::invalid_argument("Missing section in circuit file"))
+    }
+}
+
+/// Reads the data of a section front to back.
+pub(crate) struct SectionReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SectionReader<'a> {
+    pub(crate) fn bytes(&mut self, len: usize) -> IcicleResult<&'a [u8]> {
+        if len
+            > self
+                .bytes
+                .len()
+        {
+            return Err(IcicleError::invalid_argument("Truncated section in circuit file"));
+        }
+        let (bytes, rest) = self
+            .bytes
+            .split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    pub(crate) fn u32(&mut self) -> IcicleResult<u32> {
+        Ok(u32::from_le_bytes(
+            self.bytes(4)?
+                .try_into()
+                .unwrap(),
+        ))
+    }
+
+    pub(crate) fn u64(&mut self) -> IcicleResult<u64> {
+        Ok(u64::from_le_bytes(
+            self.bytes(8)?
+                .try_into()
+                .unwrap(),
+        ))
+    }
+
+    /// Reads a field size and modulus and checks they are the ones of `F`.
+    pub(crate) fn modulus<F: FieldArithmetic>(&mut self) -> IcicleResult<usize> {
+        let n8 = self.u32()? as usize;
+        let modulus = self.bytes(n8)?;
+        let mut expected = F::modulus_bytes_le();
+        expected.resize(n8, 0);
+        if n8
+            != F::zero()
+                .to_bytes_le()
+                .len()
+            || expected != modulus
+        {
+            return Err(IcicleError::invalid_argument("Circuit file is for another field"));
+        }
+        Ok(n8)
+    }
+
+    /// Reads a field element stored in normal form.
+    pub(crate) fn field<F: FieldImpl>(&mut self, n8: usize) -> IcicleResult<F> {
+        Ok(F::from_bytes_le(self.bytes(n8)?))
+    }
+
+    /// Reads `count` affine points whose coordinates are made of `n8` byte elements of `Fq` in Montgomery form, the
+    /// point at infinity being all zeros.
+    pub(crate) fn points<C: Curve, Fq: FieldArithmetic>(
+        &mut self,
+        n8: usize,
+        count: usize,
+    ) -> IcicleResult<Vec<Affine<C>>> {
+        let coordinate_len = C::BaseField::zero()
+            .to_bytes_le()
+            .len();
+        let bytes = self.bytes(2 * coordinate_len * count)?;
+        let mut coordinate = vec![0u8; coordinate_len];
+        let mut decode = |bytes: &[u8]| {
+            for (element, montgomery) in coordinate
+                .chunks_mut(n8)
+                .zip(bytes.chunks(n8))
+            {
+                element.copy_from_slice(
+                    &Fq::from_bytes_le(montgomery)
+                        .from_montgomery()
+                        .to_bytes_le(),
+                );
+            }
+            C::BaseField::from_bytes_le(&coordinate)
+        };
+        Ok(bytes
+            .chunks(2 * coordinate_len)
+            .map(|point| Affine {
+                x: decode(&point[..coordinate_len]),
+                y: decode(&point[coordinate_len..]),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,39 @@
+use icicle_core::{curve::Curve, msm::MSM};
+
+/// Pairing friendly curve a Groth16 proof is computed on: the two groups, sharing the scalar field the circuit is
+/// defined over, with the MSMs of both.
+pub trait Groth16Curve: Clone + std::fmt::Debug {
+    type G1: Curve + MSM<Self::G1>;
+    type G2: Curve<ScalarField = <Self::G1 as Curve>::ScalarField> + MSM<Self::G2>;
+
+    /// Name of the curve in snarkjs proofs.
+    const SNARKJS_NAME: &'static str;
+}
+
+pub type ScalarField<G> = <<G as Groth16Curve>::G1 as Curve>::ScalarField;
+/// The prime field the coordinates of both groups are made of.
+pub type BaseField<G> = <<G as Groth16Curve>::G1 as Curve>::BaseField;
+
+#[cfg(feature = "g2")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bn254;
+
+#[cfg(feature = "g2")]
+impl Groth16Curve for Bn254 {
+    type G1 = icicle_bn254::curve::CurveCfg;
+    type G2 = icicle_bn254::curve::G2CurveCfg;
+
+    const SNARKJS_NAME: &'static str = "bn128";
+}
+
+#[cfg(feature = "g2")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bls12_381;
+
+#[cfg(feature = "g2")]
+impl Groth16Curve for Bls12_381 {
+    type G1 = icicle_bls12_381::curve::CurveCfg;
+    type G2 = icicle_bls12_381::curve::G2CurveCfg;
+
+    const SNARKJS_NAME: &'static str = "bls12381";
+}
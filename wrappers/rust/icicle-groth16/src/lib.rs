@@ -0,0 +1,12 @@
+mod binfile;
+pub mod curve;
+pub mod proof;
+pub mod prover;
+pub mod r1cs;
+#[cfg(all(feature = "arkworks", feature = "g2"))]
+pub mod verifier;
+pub mod witness;
+pub mod zkey;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,116 @@
+use icicle_core::{
+    curve::{Affine, Curve},
+    traits::FieldImpl,
+};
+
+use crate::curve::Groth16Curve;
+
+/// Groth16 proof: `A` and `C` in the first group, `B` in the second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Proof<G: Groth16Curve> {
+    pub a: Affine<G::G1>,
+    pub b: Affine<G::G2>,
+    pub c: Affine<G::G1>,
+}
+
+// Decimal representation of a little-endian integer
+fn to_decimal(bytes_le: &[u8]) -> String {
+    let mut limbs: Vec<u32> = bytes_le
+        .chunks(4)
+        .rev()
+        .map(|chunk| {
+            chunk
+                .iter()
+                .rev()
+                .fold(0, |acc, byte| acc << 8 | *byte as u32)
+        })
+        .collect();
+    let mut digits = Vec::new();
+    while limbs
+        .iter()
+        .any(|limb| *limb != 0)
+    {
+        // Divides by 10 from the most significant limb, keeping the remainder as the next digit
+        let mut remainder = 0u64;
+        for limb in limbs.iter_mut() {
+            let value = remainder << 32 | *limb as u64;
+            *limb = (value / 10) as u32;
+            remainder = value % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+// Coordinates of a point as the decimal strings of their prime field elements, in projective form like snarkjs
+fn point_to_decimals<C: Curve>(point: &Affine<C>, degree: usize) -> Vec<Vec<String>> {
+    let elements = |bytes: Vec<u8>| {
+        let n8 = bytes.len() / degree;
+        bytes
+            .chunks(n8)
+            .map(to_decimal)
+            .collect::<Vec<_>>()
+    };
+    let one = |first: &str| {
+        let mut element = vec![String::from("0"); degree];
+        element[0] = String::from(first);
+        element
+    };
+    if *point == Affine::zero() {
+        vec![one("0"), one("1"), one("0")]
+    } else {
+        vec![
+            elements(
+                point
+                    .x
+                    .to_bytes_le(),
+            ),
+            elements(
+                point
+                    .y
+                    .to_bytes_le(),
+            ),
+            one("1"),
+        ]
+    }
+}
+
+fn json_strings(strings: &[String]) -> String {
+    let quoted: Vec<_> = strings
+        .iter()
+        .map(|string| format!("\"{}\"", string))
+        .collect();
+    format!("[{}]", quoted.join(","))
+}
+
+impl<G: Groth16Curve> Proof<G> {
+    /// The proof in the `proof.json` format of snarkjs, coordinates as decimal strings.
+    pub fn to_snarkjs_json(&self) -> String {
+        let g1 = |point| json_strings(&point_to_decimals(point, 1).concat());
+        let b: Vec<_> = point_to_decimals(&self.b, 2)
+            .iter()
+            .map(|element| json_strings(element))
+            .collect();
+        format!(
+            "{{\"pi_a\":{},\"pi_b\":[{}],\"pi_c\":{},\"protocol\":\"groth16\",\"curve\":\"{}\"}}",
+            g1(&self.a),
+            b.join(","),
+            g1(&self.c),
+            G::SNARKJS_NAME
+        )
+    }
+}
+
+/// The public signals in the `public.json` format of snarkjs.
+pub fn public_signals_json<F: FieldImpl>(signals: &[F]) -> String {
+    json_strings(
+        &signals
+            .iter()
+            .map(|signal| to_decimal(&signal.to_bytes_le()))
+            .collect::<Vec<_>>(),
+    )
+}
@@ -0,0 +1,151 @@
+use icicle_core::{
+    curve::{Affine, Curve, Projective},
+    error::{IcicleError, IcicleResult},
+    msm::{msm, MSMConfig, MSM},
+    ntt::{get_root_of_unity, ntt_inplace, NTTConfig, NTTDir, NTT},
+    traits::{FieldArithmetic, FieldImpl, GenerateRandom},
+};
+use icicle_cuda_runtime::memory::HostSlice;
+
+use crate::{
+    curve::{Groth16Curve, ScalarField},
+    proof::Proof,
+    zkey::{Matrix, ZKey},
+};
+
+// `Σ s_i P_i` in one MSM, the point at infinity for no scalars
+fn linear_combination<C: Curve + MSM<C>>(scalars: &[C::ScalarField], points: &[Affine<C>]) -> IcicleResult<Affine<C>> {
+    if scalars.is_empty() {
+        return Ok(Affine::zero());
+    }
+    let mut result = [Projective::<C>::zero()];
+    msm(
+        HostSlice::from_slice(scalars),
+        HostSlice::from_slice(points),
+        &MSMConfig::default(),
+        HostSlice::from_mut_slice(&mut result),
+    )?;
+    Ok(result[0].into())
+}
+
+// `Σ w_i P_i` plus the few extra terms of a proof element
+fn proof_element<C: Curve + MSM<C>>(
+    witness: &[C::ScalarField],
+    points: &[Affine<C>],
+    extra_terms: &[(C::ScalarField, Affine<C>)],
+) -> IcicleResult<Affine<C>> {
+    let (mut scalars, mut extra_points): (Vec<_>, Vec<_>) = extra_terms
+        .iter()
+        .copied()
+        .unzip();
+    scalars.push(C::ScalarField::one());
+    extra_points.push(linear_combination(witness, points)?);
+    linear_combination(&scalars, &extra_points)
+}
+
+/// Evaluations of `<A_j, w>`, `<B_j, w>` and their product `<C_j, w>` over the constraints `j` of the zkey, one after
+/// the other, so `3 * domain_size` values.
+pub fn constraint_evaluations<G: Groth16Curve>(
+    zkey: &ZKey<G>,
+    witness: &[ScalarField<G>],
+) -> IcicleResult<Vec<ScalarField<G>>>
+where
+    ScalarField<G>: FieldArithmetic,
+{
+    if witness.len() != zkey.n_vars {
+        return Err(IcicleError::invalid_argument(
+            "Witness doesn't have one value per zkey signal",
+        ));
+    }
+    let size = zkey.domain_size;
+    let mut evals = vec![ScalarField::<G>::zero(); 3 * size];
+    for coefficient in &zkey.coefficients {
+        let offset = match coefficient.matrix {
+            Matrix::A => 0,
+            Matrix::B => size,
+        };
+        let eval = &mut evals[offset + coefficient.constraint];
+        *eval = *eval + coefficient.value * witness[coefficient.signal];
+    }
+    for j in 0..size {
+        evals[2 * size + j] = evals[j] * evals[size + j];
+    }
+    Ok(evals)
+}
+
+/// Computes the scalars of the `h` points of the zkey: the evaluations of `A * B - C` on the coset of the domain by
+/// the root of unity of order `2 * domain_size`. The division by the vanishing polynomial is part of the `h` points.
+///
+/// The three polynomials are interpolated with one batched inverse NTT and evaluated on the coset with one batched
+/// coset NTT, so the NTT domain of the scalar field must be initialized for `domain_size`. Its roots of unity must be
+/// the ones the zkey was set up with, which is the case for the standard roots snarkjs uses.
+pub fn compute_h<G: Groth16Curve>(zkey: &ZKey<G>, witness: &[ScalarField<G>]) -> IcicleResult<Vec<ScalarField<G>>>
+where
+    ScalarField<G>: FieldArithmetic,
+    <ScalarField<G> as FieldImpl>::Config: NTT<ScalarField<G>, ScalarField<G>>,
+{
+    let size = zkey.domain_size;
+    let mut evals = constraint_evaluations(zkey, witness)?;
+    let cfg = NTTConfig::<ScalarField<G>>::builder()
+        .batch_size(3)
+        .build()?;
+    ntt_inplace(HostSlice::from_mut_slice(&mut evals), NTTDir::kInverse, &cfg)?;
+    let cfg = NTTConfig::<ScalarField<G>>::builder()
+        .batch_size(3)
+        .coset_gen(get_root_of_unity::<ScalarField<G>>(2 * size as u64))
+        .build()?;
+    ntt_inplace(HostSlice::from_mut_slice(&mut evals), NTTDir::kForward, &cfg)?;
+
+    let (a, rest) = evals.split_at(size);
+    let (b, c) = rest.split_at(size);
+    Ok(a.iter()
+        .zip(b)
+        .zip(c)
+        .map(|((a, b), c)| *a * *b - *c)
+        .collect())
+}
+
+/// Proves knowledge of `witness` for the circuit of the zkey, with the blinding scalars `r` and `s`.
+///
+/// The witness is the full assignment of the signals, starting with the constant one and the public signals. `r` and
+/// `s` must be uniformly random and kept secret for the proof to be zero-knowledge, see [prove]. Each element of the
+/// proof is one MSM over the signals: `A` and `B` over their points in both groups and `C` over the private signals
+/// and the `h` points.
+pub fn prove_with_randomness<G: Groth16Curve>(
+    zkey: &ZKey<G>,
+    witness: &[ScalarField<G>],
+    r: &ScalarField<G>,
+    s: &ScalarField<G>,
+) -> IcicleResult<Proof<G>>
+where
+    ScalarField<G>: FieldArithmetic,
+    <ScalarField<G> as FieldImpl>::Config: NTT<ScalarField<G>, ScalarField<G>>,
+{
+    let h = compute_h(zkey, witness)?;
+    let one = ScalarField::<G>::one();
+
+    let a = proof_element(witness, &zkey.a, &[(one, zkey.alpha_g1), (*r, zkey.delta_g1)])?;
+    let b_g1 = proof_element(witness, &zkey.b_g1, &[(one, zkey.beta_g1), (*s, zkey.delta_g1)])?;
+    let b = proof_element(witness, &zkey.b_g2, &[(one, zkey.beta_g2), (*s, zkey.delta_g2)])?;
+    let c = proof_element(
+        &witness[zkey.n_public + 1..],
+        &zkey.c,
+        &[
+            (one, linear_combination(&h, &zkey.h)?),
+            (*s, a),
+            (*r, b_g1),
+            (-(*r * *s), zkey.delta_g1),
+        ],
+    )?;
+    Ok(Proof { a, b, c })
+}
+
+/// Proves knowledge of `witness` with blinding scalars drawn by the field's random generator.
+pub fn prove<G: Groth16Curve>(zkey: &ZKey<G>, witness: &[ScalarField<G>]) -> IcicleResult<Proof<G>>
+where
+    ScalarField<G>: FieldArithmetic,
+    <ScalarField<G> as FieldImpl>::Config: NTT<ScalarField<G>, ScalarField<G>> + GenerateRandom<ScalarField<G>>,
+{
+    let randomness = <ScalarField<G> as FieldImpl>::Config::generate_random(2);
+    prove_with_randomness(zkey, witness, &randomness[0], &randomness[1])
+}
@@ -0,0 +1,100 @@
+use std::io::Read;
+
+use icicle_core::{
+    error::{IcicleError, IcicleResult},
+    traits::FieldArithmetic,
+};
+
+use crate::binfile::BinFile;
+
+const HEADER_SECTION: u32 = 1;
+const CONSTRAINTS_SECTION: u32 = 2;
+
+/// Linear combination of wires, as pairs of a wire index and its coefficient.
+pub type LinearCombination<F> = Vec<(usize, F)>;
+
+/// Constraint `<a, w> * <b, w> = <c, w>` on the witness `w`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct R1csConstraint<F> {
+    pub a: LinearCombination<F>,
+    pub b: LinearCombination<F>,
+    pub c: LinearCombination<F>,
+}
+
+/// Rank-1 constraint system of a circom `.r1cs` file.
+///
+/// Wire 0 is the constant one, followed by the public outputs, the public inputs and the private inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct R1cs<F> {
+    pub n_wires: usize,
+    pub n_public_outputs: usize,
+    pub n_public_inputs: usize,
+    pub n_private_inputs: usize,
+    pub constraints: Vec<R1csConstraint<F>>,
+}
+
+impl<F: FieldArithmetic> R1cs<F> {
+    pub fn read(reader: impl Read) -> IcicleResult<Self> {
+        let file = BinFile::read(reader, b"r1cs", 1)?;
+        let mut header = file.section(HEADER_SECTION)?;
+        let n8 = header.modulus::<F>()?;
+        let n_wires = header.u32()? as usize;
+        let n_public_outputs = header.u32()? as usize;
+        let n_public_inputs = header.u32()? as usize;
+        let n_private_inputs = header.u32()? as usize;
+        let _n_labels = header.u64()?;
+        let n_constraints = header.u32()? as usize;
+
+        let mut section = file.section(CONSTRAINTS_SECTION)?;
+        let mut linear_combination = || -> IcicleResult<LinearCombination<F>> {
+            let n_terms = section.u32()?;
+            (0..n_terms)
+                .map(|_| {
+                    let wire = section.u32()? as usize;
+                    if wire >= n_wires {
+                        return Err(IcicleError::invalid_argument(
+                            "R1CS constraint on a wire that doesn't exist",
+                        ));
+                    }
+                    Ok((wire, section.field(n8)?))
+                })
+                .collect()
+        };
+        let constraints = (0..n_constraints)
+            .map(|_| {
+                Ok(R1csConstraint {
+                    a: linear_combination()?,
+                    b: linear_combination()?,
+                    c: linear_combination()?,
+                })
+            })
+            .collect::<IcicleResult<_>>()?;
+
+        Ok(Self {
+            n_wires,
+            n_public_outputs,
+            n_public_inputs,
+            n_private_inputs,
+            constraints,
+        })
+    }
+
+    /// Number of public signals, outputs then inputs, following the constant one in the witness.
+    pub fn n_public(&self) -> usize {
+        self.n_public_outputs + self.n_public_inputs
+    }
+
+    /// Whether the witness has a value for each wire and satisfies every constraint.
+    pub fn is_satisfied(&self, witness: &[F]) -> bool {
+        let eval = |combination: &LinearCombination<F>| {
+            combination
+                .iter()
+                .fold(F::zero(), |acc, (wire, coeff)| acc + *coeff * witness[*wire])
+        };
+        witness.len() == self.n_wires
+            && self
+                .constraints
+                .iter()
+                .all(|constraint| eval(&constraint.a) * eval(&constraint.b) == eval(&constraint.c))
+    }
+}
@@ -0,0 +1,472 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{Field, UniformRand, Zero};
+use ark_groth16::{prepare_verifying_key, Groth16, VerifyingKey};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::test_rng;
+use icicle_core::{
+    curve::{Affine, Curve},
+    ntt::{get_root_of_unity, initialize_domain, NTT},
+    traits::{FieldArithmetic, FieldImpl},
+};
+use icicle_cuda_runtime::device_context::DeviceContext;
+use serial_test::serial;
+
+use crate::{
+    curve::{BaseField, Bls12_381, Bn254, ScalarField},
+    proof::{public_signals_json, Proof},
+    prover::{constraint_evaluations, prove},
+    r1cs::R1cs,
+    verifier::{ark_proof, ark_verifying_key, verify, ArkGroth16Curve},
+    witness::read_witness,
+    zkey::{Matrix, ZKey},
+};
+
+type ArkScalar<G> = <<G as ArkGroth16Curve>::Engine as Pairing>::ScalarField;
+type LinearCombination = Vec<(u32, u32)>;
+
+// Signals [1, out, x, y, t] with `out` public, constrained by `x * y = t` and `(t + x) * 1 = out`
+const N_VARS: usize = 5;
+const N_PUBLIC: usize = 1;
+const DOMAIN_SIZE: usize = 4;
+
+fn constraints() -> Vec<[LinearCombination; 3]> {
+    vec![
+        [vec![(2, 1)], vec![(3, 1)], vec![(4, 1)]],
+        [vec![(4, 1), (2, 1)], vec![(0, 1)], vec![(1, 1)]],
+    ]
+}
+
+fn witness<F: FieldImpl>() -> Vec<F> {
+    [1, 18, 3, 5, 15]
+        .into_iter()
+        .map(F::from_u32)
+        .collect()
+}
+
+fn bin_file(magic: &[u8; 4], version: u32, sections: Vec<(u32, Vec<u8>)>) -> Vec<u8> {
+    let mut bytes = magic.to_vec();
+    bytes.extend(version.to_le_bytes());
+    bytes.extend((sections.len() as u32).to_le_bytes());
+    for (section, data) in sections {
+        bytes.extend(section.to_le_bytes());
+        bytes.extend((data.len() as u64).to_le_bytes());
+        bytes.extend(data);
+    }
+    bytes
+}
+
+fn modulus<F: FieldArithmetic>() -> Vec<u8> {
+    let modulus = F::modulus_bytes_le();
+    let mut bytes = (modulus.len() as u32)
+        .to_le_bytes()
+        .to_vec();
+    bytes.extend(modulus);
+    bytes
+}
+
+// Coordinates of the point as `Fq` elements in Montgomery form
+fn montgomery_point<C: Curve, Fq: FieldArithmetic>(point: &Affine<C>) -> Vec<u8> {
+    let n8 = Fq::zero()
+        .to_bytes_le()
+        .len();
+    point
+        .to_bytes_le()
+        .chunks(n8)
+        .flat_map(|element| {
+            Fq::from_bytes_le(element)
+                .to_montgomery()
+                .to_bytes_le()
+        })
+        .collect()
+}
+
+fn r1cs_file<F: FieldArithmetic>() -> Vec<u8> {
+    let mut header = modulus::<F>();
+    for value in [N_VARS as u32, 1, 0, 2] {
+        header.extend(value.to_le_bytes());
+    }
+    header.extend((N_VARS as u64).to_le_bytes());
+    header.extend((constraints().len() as u32).to_le_bytes());
+
+    let mut data = Vec::new();
+    for combination in constraints()
+        .iter()
+        .flatten()
+    {
+        data.extend((combination.len() as u32).to_le_bytes());
+        for (wire, coeff) in combination {
+            data.extend(wire.to_le_bytes());
+            data.extend(F::from_u32(*coeff).to_bytes_le());
+        }
+    }
+    bin_file(b"r1cs", 1, vec![(1, header), (2, data)])
+}
+
+fn wtns_file<F: FieldArithmetic>(witness: &[F]) -> Vec<u8> {
+    let mut header = modulus::<F>();
+    header.extend((witness.len() as u32).to_le_bytes());
+    let data = witness
+        .iter()
+        .flat_map(|value| value.to_bytes_le())
+        .collect();
+    bin_file(b"wtns", 2, vec![(1, header), (2, data)])
+}
+
+// Setup of the circuit the way snarkjs builds a zkey, from random toxic waste, with the verifying key
+// computed from the toxic waste rather than read back from the zkey
+fn zkey_file<G: ArkGroth16Curve>() -> (Vec<u8>, VerifyingKey<G::Engine>)
+where
+    ScalarField<G>: FieldArithmetic,
+    BaseField<G>: FieldArithmetic,
+{
+    let mut rng = test_rng();
+    let [tau, alpha, beta, gamma, delta] = [(); 5].map(|_| ArkScalar::<G>::rand(&mut rng));
+    let g1 =
+        |scalar: ArkScalar<G>| G::g1_from_ark((<G::Engine as Pairing>::G1Affine::generator() * scalar).into_affine());
+    let g2 =
+        |scalar: ArkScalar<G>| G::g2_from_ark((<G::Engine as Pairing>::G2Affine::generator() * scalar).into_affine());
+    let g1_bytes = |scalar| montgomery_point::<G::G1, BaseField<G>>(&g1(scalar));
+    let g2_bytes = |scalar| montgomery_point::<G::G2, BaseField<G>>(&g2(scalar));
+
+    // A and B entries of the constraints, plus one A entry per public signal after them
+    let mut coefficients = Vec::new();
+    for (j, [a, b, _]) in constraints()
+        .iter()
+        .enumerate()
+    {
+        for (matrix, combination) in [(0, a), (1, b)] {
+            for (signal, value) in combination {
+                coefficients.push((matrix, j as u32, *signal, *value));
+            }
+        }
+    }
+    for signal in 0..=N_PUBLIC as u32 {
+        coefficients.push((0, constraints().len() as u32 + signal, signal, 1));
+    }
+
+    let domain = Radix2EvaluationDomain::<ArkScalar<G>>::new(DOMAIN_SIZE).unwrap();
+    let lagrange = domain.evaluate_all_lagrange_coefficients(tau);
+    let mut polys = [[ArkScalar::<G>::zero(); 3]; N_VARS];
+    for (matrix, constraint, signal, value) in &coefficients {
+        polys[*signal as usize][*matrix] += lagrange[*constraint as usize] * ArkScalar::<G>::from(*value);
+    }
+    for (j, [_, _, c]) in constraints()
+        .iter()
+        .enumerate()
+    {
+        for (signal, value) in c {
+            polys[*signal as usize][2] += lagrange[j] * ArkScalar::<G>::from(*value);
+        }
+    }
+    let combined = |[a, b, c]: [ArkScalar<G>; 3]| beta * a + alpha * b + c;
+
+    let mut header = modulus::<BaseField<G>>();
+    header.extend(modulus::<ScalarField<G>>());
+    for value in [N_VARS, N_PUBLIC, DOMAIN_SIZE] {
+        header.extend((value as u32).to_le_bytes());
+    }
+    header.extend(g1_bytes(alpha));
+    header.extend(g1_bytes(beta));
+    header.extend(g2_bytes(beta));
+    header.extend(g2_bytes(gamma));
+    header.extend(g1_bytes(delta));
+    header.extend(g2_bytes(delta));
+
+    let mut coefficients_data = (coefficients.len() as u32)
+        .to_le_bytes()
+        .to_vec();
+    for (matrix, constraint, signal, value) in coefficients {
+        for field in [matrix as u32, constraint, signal] {
+            coefficients_data.extend(field.to_le_bytes());
+        }
+        coefficients_data.extend(
+            ScalarField::<G>::from_u32(value)
+                .to_montgomery()
+                .to_montgomery()
+                .to_bytes_le(),
+        );
+    }
+
+    let gamma_inv = gamma
+        .inverse()
+        .unwrap();
+    let delta_inv = delta
+        .inverse()
+        .unwrap();
+    let vk = VerifyingKey {
+        alpha_g1: G::g1_to_ark(&g1(alpha)),
+        beta_g2: G::g2_to_ark(&g2(beta)),
+        gamma_g2: G::g2_to_ark(&g2(gamma)),
+        delta_g2: G::g2_to_ark(&g2(delta)),
+        gamma_abc_g1: polys[..=N_PUBLIC]
+            .iter()
+            .map(|poly| G::g1_to_ark(&g1(combined(*poly) * gamma_inv)))
+            .collect(),
+    };
+    let double_lagrange = Radix2EvaluationDomain::<ArkScalar<G>>::new(2 * DOMAIN_SIZE)
+        .unwrap()
+        .evaluate_all_lagrange_coefficients(tau);
+    let sections = vec![
+        (
+            1,
+            1u32.to_le_bytes()
+                .to_vec(),
+        ),
+        (2, header),
+        (
+            3,
+            polys[..=N_PUBLIC]
+                .iter()
+                .flat_map(|poly| g1_bytes(combined(*poly) * gamma_inv))
+                .collect(),
+        ),
+        (4, coefficients_data),
+        (
+            5,
+            polys
+                .iter()
+                .flat_map(|poly| g1_bytes(poly[0]))
+                .collect(),
+        ),
+        (
+            6,
+            polys
+                .iter()
+                .flat_map(|poly| g1_bytes(poly[1]))
+                .collect(),
+        ),
+        (
+            7,
+            polys
+                .iter()
+                .flat_map(|poly| g2_bytes(poly[1]))
+                .collect(),
+        ),
+        (
+            8,
+            polys[N_PUBLIC + 1..]
+                .iter()
+                .flat_map(|poly| g1_bytes(combined(*poly) * delta_inv))
+                .collect(),
+        ),
+        (
+            9,
+            (0..DOMAIN_SIZE)
+                .flat_map(|i| g1_bytes(double_lagrange[2 * i + 1] * delta_inv))
+                .collect(),
+        ),
+    ];
+    (bin_file(b"zkey", 1, sections), vk)
+}
+
+// Verification against the verifying key of the setup, independent of the zkey reader
+fn verify_with_setup<G: ArkGroth16Curve>(
+    vk: &VerifyingKey<G::Engine>,
+    proof: &Proof<G>,
+    public_signals: &[ScalarField<G>],
+) -> bool {
+    let public_signals: Vec<_> = public_signals
+        .iter()
+        .map(G::scalar_to_ark)
+        .collect();
+    Groth16::<G::Engine>::verify_proof(&prepare_verifying_key(vk), &ark_proof(proof), &public_signals).unwrap()
+}
+
+// `Σ s_i P_i` computed with arkworks
+fn ark_msm<G: ArkGroth16Curve>(scalars: &[ArkScalar<G>], points: &[Affine<G::G1>]) -> <G::Engine as Pairing>::G1 {
+    scalars
+        .iter()
+        .zip(points)
+        .map(|(scalar, point)| G::g1_to_ark(point) * scalar)
+        .sum()
+}
+
+fn ark_msm_g2<G: ArkGroth16Curve>(scalars: &[ArkScalar<G>], points: &[Affine<G::G2>]) -> <G::Engine as Pairing>::G2 {
+    scalars
+        .iter()
+        .zip(points)
+        .map(|(scalar, point)| G::g2_to_ark(point) * scalar)
+        .sum()
+}
+
+// The prover's pipeline with arkworks FFTs and MSMs, to check the zkey layout without a device
+fn ark_prove<G: ArkGroth16Curve>(zkey: &ZKey<G>, witness: &[ScalarField<G>]) -> Proof<G>
+where
+    ScalarField<G>: FieldArithmetic,
+{
+    let mut rng = test_rng();
+    let (r, s) = (ArkScalar::<G>::rand(&mut rng), ArkScalar::<G>::rand(&mut rng));
+    let evals: Vec<_> = constraint_evaluations(zkey, witness)
+        .unwrap()
+        .iter()
+        .map(G::scalar_to_ark)
+        .collect();
+    let domain = Radix2EvaluationDomain::<ArkScalar<G>>::new(zkey.domain_size).unwrap();
+    let double_domain = Radix2EvaluationDomain::<ArkScalar<G>>::new(2 * zkey.domain_size).unwrap();
+    let coset = domain
+        .get_coset(double_domain.group_gen())
+        .unwrap();
+    let [a, b, c] = [0, 1, 2].map(|i| coset.fft(&domain.ifft(&evals[i * zkey.domain_size..][..zkey.domain_size])));
+    let h: Vec<_> = (0..zkey.domain_size)
+        .map(|i| a[i] * b[i] - c[i])
+        .collect();
+
+    let w: Vec<_> = witness
+        .iter()
+        .map(G::scalar_to_ark)
+        .collect();
+    let alpha = G::g1_to_ark(&zkey.alpha_g1);
+    let delta = G::g1_to_ark(&zkey.delta_g1);
+    let proof_a = ark_msm::<G>(&w, &zkey.a) + alpha + delta * r;
+    let proof_b1 = ark_msm::<G>(&w, &zkey.b_g1) + G::g1_to_ark(&zkey.beta_g1) + delta * s;
+    let proof_b = ark_msm_g2::<G>(&w, &zkey.b_g2) + G::g2_to_ark(&zkey.beta_g2) + G::g2_to_ark(&zkey.delta_g2) * s;
+    let proof_c =
+        ark_msm::<G>(&w[zkey.n_public + 1..], &zkey.c) + ark_msm::<G>(&h, &zkey.h) + proof_a * s + proof_b1 * r
+            - delta * (r * s);
+    Proof {
+        a: G::g1_from_ark(proof_a.into_affine()),
+        b: G::g2_from_ark(proof_b.into_affine()),
+        c: G::g1_from_ark(proof_c.into_affine()),
+    }
+}
+
+fn check_circuit_files<G: ArkGroth16Curve>()
+where
+    ScalarField<G>: FieldArithmetic,
+    BaseField<G>: FieldArithmetic,
+{
+    let witness = witness::<ScalarField<G>>();
+    let r1cs = R1cs::<ScalarField<G>>::read(&r1cs_file::<ScalarField<G>>()[..]).unwrap();
+    assert_eq!(r1cs.n_wires, N_VARS);
+    assert_eq!(r1cs.n_public(), N_PUBLIC);
+    assert_eq!(
+        r1cs.constraints
+            .len(),
+        2
+    );
+    assert!(r1cs.is_satisfied(&witness));
+    let mut wrong_witness = witness.clone();
+    wrong_witness[4] = ScalarField::<G>::from_u32(16);
+    assert!(!r1cs.is_satisfied(&wrong_witness));
+
+    assert_eq!(read_witness(&wtns_file(&witness)[..]), Ok(witness.clone()));
+    assert!(read_witness::<ScalarField<G>>(&wtns_file(&witness[1..])[..]).is_err());
+    // Files for another field are rejected
+    assert!(R1cs::<BaseField<G>>::read(&r1cs_file::<ScalarField<G>>()[..]).is_err());
+
+    let (zkey_bytes, vk) = zkey_file::<G>();
+    let zkey = ZKey::<G>::read(&zkey_bytes[..]).unwrap();
+    assert_eq!(ark_verifying_key(&zkey), vk);
+    assert_eq!(
+        (zkey.n_vars, zkey.n_public, zkey.domain_size),
+        (N_VARS, N_PUBLIC, DOMAIN_SIZE)
+    );
+    assert_eq!(
+        zkey.coefficients
+            .len(),
+        7
+    );
+    assert_eq!(zkey.coefficients[0].matrix, Matrix::A);
+    assert_eq!(zkey.coefficients[0].value, ScalarField::<G>::one());
+    assert_eq!(
+        zkey.c
+            .len(),
+        N_VARS - N_PUBLIC - 1
+    );
+
+    // On the domain, the evaluations of C are the ones of the R1CS
+    let evals = constraint_evaluations(&zkey, &witness).unwrap();
+    assert_eq!(evals[2 * DOMAIN_SIZE], witness[4]);
+    assert_eq!(evals[2 * DOMAIN_SIZE + 1], witness[1]);
+
+    let public_signals = &witness[1..=N_PUBLIC];
+    let proof = ark_prove(&zkey, &witness);
+    assert_eq!(verify(&zkey, &proof, public_signals), Ok(true));
+    assert!(verify_with_setup(&vk, &proof, public_signals));
+    assert_eq!(verify(&zkey, &proof, &[ScalarField::<G>::from_u32(19)]), Ok(false));
+    assert!(verify(&zkey, &proof, &[]).is_err());
+    // A witness that doesn't satisfy the constraints gives a proof that doesn't verify
+    let forged = ark_prove(&zkey, &wrong_witness);
+    assert_eq!(verify(&zkey, &forged, public_signals), Ok(false));
+}
+
+fn check_snarkjs_json<G: ArkGroth16Curve>() {
+    let g1 = G::g1_from_ark(<G::Engine as Pairing>::G1Affine::generator());
+    let proof = Proof::<G> {
+        a: g1,
+        b: Affine::zero(),
+        c: g1,
+    };
+    let json = proof.to_snarkjs_json();
+    let generator = G::g1_to_ark(&g1);
+    let pi_a = format!(
+        "\"pi_a\":[\"{}\",\"{}\",\"1\"]",
+        generator
+            .x()
+            .unwrap(),
+        generator
+            .y()
+            .unwrap()
+    );
+    assert!(json.contains(&pi_a));
+    assert!(json.contains("\"pi_b\":[[\"0\",\"0\"],[\"1\",\"0\"],[\"0\",\"0\"]]"));
+    assert!(json.ends_with(&format!("\"protocol\":\"groth16\",\"curve\":\"{}\"}}", G::SNARKJS_NAME)));
+
+    let scalar = ArkScalar::<G>::rand(&mut test_rng());
+    assert_eq!(
+        public_signals_json(&[ScalarField::<G>::from_u32(18), G::scalar_from_ark(scalar)]),
+        format!("[\"18\",\"{}\"]", scalar)
+    );
+    assert_eq!(public_signals_json(&[ScalarField::<G>::zero()]), "[\"0\"]");
+}
+
+fn check_groth16<G: ArkGroth16Curve>()
+where
+    ScalarField<G>: FieldArithmetic,
+    BaseField<G>: FieldArithmetic,
+    <ScalarField<G> as FieldImpl>::Config: NTT<ScalarField<G>, ScalarField<G>>,
+    <ScalarField<G> as FieldImpl>::Config: icicle_core::traits::GenerateRandom<ScalarField<G>>,
+{
+    let ctx = DeviceContext::default();
+    initialize_domain(get_root_of_unity::<ScalarField<G>>(DOMAIN_SIZE as u64), &ctx, false).unwrap();
+
+    let (zkey_bytes, vk) = zkey_file::<G>();
+    let zkey = ZKey::<G>::read(&zkey_bytes[..]).unwrap();
+    let witness = witness::<ScalarField<G>>();
+    let proof = prove(&zkey, &witness).unwrap();
+    assert_eq!(verify(&zkey, &proof, &witness[1..=N_PUBLIC]), Ok(true));
+    assert!(verify_with_setup(&vk, &proof, &witness[1..=N_PUBLIC]));
+    assert_ne!(
+        prove(&zkey, &witness)
+            .unwrap()
+            .a,
+        proof.a
+    );
+}
+
+#[test]
+fn test_circuit_files_bn254() {
+    check_circuit_files::<Bn254>();
+}
+
+#[test]
+fn test_circuit_files_bls12_381() {
+    check_circuit_files::<Bls12_381>();
+}
+
+#[test]
+fn test_snarkjs_json_bn254() {
+    check_snarkjs_json::<Bn254>();
+}
+
+#[test]
+#[serial]
+fn test_groth16_bn254() {
+    check_groth16::<Bn254>();
+}
+
+#[test]
+#[serial]
+fn test_groth16_bls12_381() {
+    check_groth16::<Bls12_381>();
+}
@@ -0,0 +1,107 @@
+use ark_ec::pairing::Pairing;
+use ark_groth16::{prepare_verifying_key, Groth16};
+use icicle_core::{
+    curve::Affine,
+    error::{IcicleError, IcicleResult},
+    traits::ArkConvertible,
+};
+
+use crate::{
+    curve::{Bls12_381, Bn254, Groth16Curve, ScalarField},
+    proof::Proof,
+    zkey::ZKey,
+};
+
+/// Curves whose Groth16 proofs can be checked on the host with arkworks.
+pub trait ArkGroth16Curve: Groth16Curve {
+    type Engine: Pairing;
+
+    fn g1_to_ark(point: &Affine<Self::G1>) -> <Self::Engine as Pairing>::G1Affine;
+    fn g1_from_ark(point: <Self::Engine as Pairing>::G1Affine) -> Affine<Self::G1>;
+    fn g2_to_ark(point: &Affine<Self::G2>) -> <Self::Engine as Pairing>::G2Affine;
+    fn g2_from_ark(point: <Self::Engine as Pairing>::G2Affine) -> Affine<Self::G2>;
+    fn scalar_to_ark(scalar: &ScalarField<Self>) -> <Self::Engine as Pairing>::ScalarField;
+    fn scalar_from_ark(scalar: <Self::Engine as Pairing>::ScalarField) -> ScalarField<Self>;
+}
+
+macro_rules! impl_ark_groth16_curve {
+    ($curve:ty, $engine:ty) => {
+        impl ArkGroth16Curve for $curve {
+            type Engine = $engine;
+
+            fn g1_to_ark(point: &Affine<Self::G1>) -> <Self::Engine as Pairing>::G1Affine {
+                point.to_ark()
+            }
+
+            fn g1_from_ark(point: <Self::Engine as Pairing>::G1Affine) -> Affine<Self::G1> {
+                Affine::from_ark(point)
+            }
+
+            fn g2_to_ark(point: &Affine<Self::G2>) -> <Self::Engine as Pairing>::G2Affine {
+                point.to_ark()
+            }
+
+            fn g2_from_ark(point: <Self::Engine as Pairing>::G2Affine) -> Affine<Self::G2> {
+                Affine::from_ark(point)
+            }
+
+            fn scalar_to_ark(scalar: &ScalarField<Self>) -> <Self::Engine as Pairing>::ScalarField {
+                scalar.to_ark()
+            }
+
+            fn scalar_from_ark(scalar: <Self::Engine as Pairing>::ScalarField) -> ScalarField<Self> {
+                ScalarField::<Self>::from_ark(scalar)
+            }
+        }
+    };
+}
+
+impl_ark_groth16_curve!(Bn254, ark_bn254::Bn254);
+impl_ark_groth16_curve!(Bls12_381, ark_bls12_381::Bls12_381);
+
+/// The proof as an arkworks Groth16 proof.
+pub fn ark_proof<G: ArkGroth16Curve>(proof: &Proof<G>) -> ark_groth16::Proof<G::Engine> {
+    ark_groth16::Proof {
+        a: G::g1_to_ark(&proof.a),
+        b: G::g2_to_ark(&proof.b),
+        c: G::g1_to_ark(&proof.c),
+    }
+}
+
+/// The verifying key of the zkey, as an arkworks Groth16 verifying key.
+pub fn ark_verifying_key<G: ArkGroth16Curve>(zkey: &ZKey<G>) -> ark_groth16::VerifyingKey<G::Engine> {
+    ark_groth16::VerifyingKey {
+        alpha_g1: G::g1_to_ark(&zkey.alpha_g1),
+        beta_g2: G::g2_to_ark(&zkey.beta_g2),
+        gamma_g2: G::g2_to_ark(&zkey.gamma_g2),
+        delta_g2: G::g2_to_ark(&zkey.delta_g2),
+        gamma_abc_g1: zkey
+            .ic
+            .iter()
+            .map(G::g1_to_ark)
+            .collect(),
+    }
+}
+
+/// Verifies a proof for the public signals, the witness values following the constant one.
+pub fn verify<G: ArkGroth16Curve>(
+    zkey: &ZKey<G>,
+    proof: &Proof<G>,
+    public_signals: &[ScalarField<G>],
+) -> IcicleResult<bool> {
+    if public_signals.len() != zkey.n_public {
+        return Err(IcicleError::invalid_argument(
+            "Wrong number of public signals for the zkey",
+        ));
+    }
+    let public_signals: Vec<_> = public_signals
+        .iter()
+        .map(G::scalar_to_ark)
+        .collect();
+    Groth16::<G::Engine>::verify_proof(
+        &prepare_verifying_key(&ark_verifying_key(zkey)),
+        &ark_proof(proof),
+        &public_signals,
+    )
+    .map_err(|_| IcicleError::invalid_argument("Malformed Groth16 proof"))
+}
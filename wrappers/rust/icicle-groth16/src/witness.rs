@@ -0,0 +1,30 @@
+use std::io::Read;
+
+use icicle_core::{
+    error::{IcicleError, IcicleResult},
+    traits::FieldArithmetic,
+};
+
+use crate::binfile::BinFile;
+
+const HEADER_SECTION: u32 = 1;
+const WITNESS_SECTION: u32 = 2;
+
+/// Reads the witness of a `.wtns` file, as computed by the witness generator of a circom circuit.
+pub fn read_witness<F: FieldArithmetic>(reader: impl Read) -> IcicleResult<Vec<F>> {
+    let file = BinFile::read(reader, b"wtns", 2)?;
+    let mut header = file.section(HEADER_SECTION)?;
+    let n8 = header.modulus::<F>()?;
+    let n_witness = header.u32()? as usize;
+
+    let mut section = file.section(WITNESS_SECTION)?;
+    let witness = (0..n_witness)
+        .map(|_| section.field(n8))
+        .collect::<IcicleResult<Vec<F>>>()?;
+    if witness.first() != Some(&F::one()) {
+        return Err(IcicleError::invalid_argument(
+            "Witness doesn't start with the constant one",
+        ));
+    }
+    Ok(witness)
+}
@@ -0,0 +1,165 @@
+use std::io::Read;
+
+use icicle_core::{
+    curve::Affine,
+    error::{IcicleError, IcicleResult},
+    traits::FieldArithmetic,
+};
+
+use crate::{
+    binfile::{BinFile, SectionReader},
+    curve::{BaseField, Groth16Curve, ScalarField},
+};
+
+const HEADER_SECTION: u32 = 1;
+const GROTH16_HEADER_SECTION: u32 = 2;
+const IC_SECTION: u32 = 3;
+const COEFFICIENTS_SECTION: u32 = 4;
+const A_SECTION: u32 = 5;
+const B_G1_SECTION: u32 = 6;
+const B_G2_SECTION: u32 = 7;
+const C_SECTION: u32 = 8;
+const H_SECTION: u32 = 9;
+
+const GROTH16_PROTOCOL: u32 = 1;
+
+fn g1<G: Groth16Curve>(section: &mut SectionReader, n8q: usize, count: usize) -> IcicleResult<Vec<Affine<G::G1>>>
+where
+    BaseField<G>: FieldArithmetic,
+{
+    section.points::<G::G1, BaseField<G>>(n8q, count)
+}
+
+fn g2<G: Groth16Curve>(section: &mut SectionReader, n8q: usize, count: usize) -> IcicleResult<Vec<Affine<G::G2>>>
+where
+    BaseField<G>: FieldArithmetic,
+{
+    section.points::<G::G2, BaseField<G>>(n8q, count)
+}
+
+/// Matrix of the constraint system a [ZKeyCoefficient] belongs to. The zkey only keeps `A` and `B`, the evaluations
+/// of `C` on the domain being their products for a satisfying witness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Matrix {
+    A,
+    B,
+}
+
+/// Non-zero entry of the `A` or `B` matrix: the weight of a signal in a constraint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZKeyCoefficient<F> {
+    pub matrix: Matrix,
+    pub constraint: usize,
+    pub signal: usize,
+    pub value: F,
+}
+
+/// Groth16 proving key of a snarkjs `.zkey` file, points and scalars converted out of Montgomery form.
+///
+/// Signal 0 is the constant one, followed by the `n_public` public signals. The constraints are padded to
+/// `domain_size` and include one `A` entry per public signal, which the setup adds so that they are bound to the proof.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZKey<G: Groth16Curve> {
+    pub n_vars: usize,
+    pub n_public: usize,
+    pub domain_size: usize,
+    pub alpha_g1: Affine<G::G1>,
+    pub beta_g1: Affine<G::G1>,
+    pub beta_g2: Affine<G::G2>,
+    pub gamma_g2: Affine<G::G2>,
+    pub delta_g1: Affine<G::G1>,
+    pub delta_g2: Affine<G::G2>,
+    /// Points of the public signals, for the verifier.
+    pub ic: Vec<Affine<G::G1>>,
+    pub coefficients: Vec<ZKeyCoefficient<ScalarField<G>>>,
+    /// `[A_i(τ)]`, one point per signal.
+    pub a: Vec<Affine<G::G1>>,
+    /// `[B_i(τ)]` in the first group, one point per signal.
+    pub b_g1: Vec<Affine<G::G1>>,
+    /// `[B_i(τ)]` in the second group, one point per signal.
+    pub b_g2: Vec<Affine<G::G2>>,
+    /// Points of the private signals, the ones after the public signals.
+    pub c: Vec<Affine<G::G1>>,
+    /// `[L_{2i+1}(τ) / δ]` for the Lagrange basis of the domain of size `2 * domain_size`.
+    pub h: Vec<Affine<G::G1>>,
+}
+
+impl<G: Groth16Curve> ZKey<G>
+where
+    ScalarField<G>: FieldArithmetic,
+    BaseField<G>: FieldArithmetic,
+{
+    pub fn read(reader: impl Read) -> IcicleResult<Self> {
+        let file = BinFile::read(reader, b"zkey", 1)?;
+        if file
+            .section(HEADER_SECTION)?
+            .u32()?
+            != GROTH16_PROTOCOL
+        {
+            return Err(IcicleError::invalid_argument("Zkey is not for Groth16"));
+        }
+
+        let mut header = file.section(GROTH16_HEADER_SECTION)?;
+        let n8q = header.modulus::<BaseField<G>>()?;
+        let n8r = header.modulus::<ScalarField<G>>()?;
+        let n_vars = header.u32()? as usize;
+        let n_public = header.u32()? as usize;
+        let domain_size = header.u32()? as usize;
+        if n_public >= n_vars || !domain_size.is_power_of_two() {
+            return Err(IcicleError::invalid_argument("Malformed zkey header"));
+        }
+        let alpha_g1 = g1::<G>(&mut header, n8q, 1)?[0];
+        let beta_g1 = g1::<G>(&mut header, n8q, 1)?[0];
+        let beta_g2 = g2::<G>(&mut header, n8q, 1)?[0];
+        let gamma_g2 = g2::<G>(&mut header, n8q, 1)?[0];
+        let delta_g1 = g1::<G>(&mut header, n8q, 1)?[0];
+        let delta_g2 = g2::<G>(&mut header, n8q, 1)?[0];
+
+        let mut section = file.section(COEFFICIENTS_SECTION)?;
+        let n_coefficients = section.u32()?;
+        let coefficients = (0..n_coefficients)
+            .map(|_| {
+                let matrix = match section.u32()? {
+                    0 => Matrix::A,
+                    1 => Matrix::B,
+                    _ => return Err(IcicleError::invalid_argument("Zkey coefficient of an unknown matrix")),
+                };
+                let constraint = section.u32()? as usize;
+                let signal = section.u32()? as usize;
+                if constraint >= domain_size || signal >= n_vars {
+                    return Err(IcicleError::invalid_argument("Zkey coefficient out of range"));
+                }
+                // snarkjs stores the Montgomery form of `value * R`
+                let value = section
+                    .field::<ScalarField<G>>(n8r)?
+                    .from_montgomery()
+                    .from_montgomery();
+                Ok(ZKeyCoefficient {
+                    matrix,
+                    constraint,
+                    signal,
+                    value,
+                })
+            })
+            .collect::<IcicleResult<_>>()?;
+
+        Ok(Self {
+            n_vars,
+            n_public,
+            domain_size,
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g1,
+            delta_g2,
+            ic: g1::<G>(&mut file.section(IC_SECTION)?, n8q, n_public + 1)?,
+            coefficients,
+            a: g1::<G>(&mut file.section(A_SECTION)?, n8q, n_vars)?,
+            b_g1: g1::<G>(&mut file.section(B_G1_SECTION)?, n8q, n_vars)?,
+            b_g2: g2::<G>(&mut file.section(B_G2_SECTION)?, n8q, n_vars)?,
+            c: g1::<G>(&mut file.section(C_SECTION)?, n8q, n_vars - n_public - 1)?,
+            h: g1::<G>(&mut file.section(H_SECTION)?, n8q, domain_size)?,
+        })
+    }
+}
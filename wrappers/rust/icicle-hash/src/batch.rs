@@ -0,0 +1,114 @@
+use icicle_core::{
+    error::{IcicleError, IcicleResult},
+    tree::{merkle_tree_digests_len, TreeBuilderConfig},
+};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+fn host_only_error() -> IcicleError {
+    IcicleError::invalid_argument("Host hashes only accept inputs and outputs in host memory")
+}
+
+/// Hashes `number_of_blocks` consecutive blocks of `input_block_size` bytes on the host, writing `digest_len` bytes
+/// per block, the layout of the device batch hashes.
+pub(crate) fn hash_blocks(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    digest_len: usize,
+    hash: impl Fn(&[u8], &mut [u8]),
+) -> IcicleResult<()> {
+    if input.is_on_device() || output.is_on_device() {
+        return Err(host_only_error());
+    }
+    let (input_block_size, number_of_blocks) = (input_block_size as usize, number_of_blocks as usize);
+    if input.len() < input_block_size * number_of_blocks || output.len() < digest_len * number_of_blocks {
+        return Err(IcicleError::invalid_argument(
+            "Hash inputs or outputs are shorter than the number of blocks",
+        ));
+    }
+
+    let input = unsafe { std::slice::from_raw_parts(input.as_ptr(), input_block_size * number_of_blocks) };
+    let output = unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr(), digest_len * number_of_blocks) };
+    for i in 0..number_of_blocks {
+        hash(
+            &input[i * input_block_size..(i + 1) * input_block_size],
+            &mut output[i * digest_len..(i + 1) * digest_len],
+        );
+    }
+    Ok(())
+}
+
+/// Builds a Merkle tree of byte hashes on the host with the layout of the device tree builders.
+///
+/// Each of the `arity^height` leaves of `input_block_len` bytes is hashed, then each node hashes the concatenated
+/// digests of its children. A digest is made of the first `config.digest_elements()` little-endian 64-bit words of the
+/// hash. Rows are written from the bottom up to the root; with `config.keep_rows()` set only that many top rows are.
+pub(crate) fn build_merkle_tree(
+    leaves: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    digests: &mut (impl HostOrDeviceSlice<u64> + ?Sized),
+    height: usize,
+    input_block_len: usize,
+    config: &TreeBuilderConfig,
+    hash_len: usize,
+    hash: impl Fn(&[u8], &mut [u8]),
+) -> IcicleResult<()> {
+    if leaves.is_on_device() || digests.is_on_device() {
+        return Err(host_only_error());
+    }
+    let arity = config.arity() as usize;
+    let digest_elements = config.digest_elements() as usize;
+    if arity < 2 || digest_elements == 0 || digest_elements * 8 > hash_len {
+        return Err(IcicleError::invalid_argument(
+            "Merkle tree arity must be at least 2 and digests must fit in the hash",
+        ));
+    }
+    let kept_rows = match config.keep_rows() as usize {
+        0 => height + 1,
+        keep_rows => keep_rows.min(height + 1),
+    };
+    let number_of_leaves = arity.pow(height as u32);
+    let digests_len = merkle_tree_digests_len(kept_rows as u32 - 1, arity as u32, digest_elements as u32);
+    if leaves.len() < number_of_leaves * input_block_len || digests.len() < digests_len {
+        return Err(IcicleError::invalid_argument(
+            "Merkle tree leaves or digests are shorter than the tree",
+        ));
+    }
+
+    let leaves = unsafe { std::slice::from_raw_parts(leaves.as_ptr(), number_of_leaves * input_block_len) };
+    let digests = unsafe { std::slice::from_raw_parts_mut(digests.as_mut_ptr(), digests_len) };
+
+    let digest_bytes = digest_elements * 8;
+    let node_digest = |input: &[u8]| {
+        let mut digest = vec![0u8; hash_len];
+        hash(input, &mut digest);
+        digest.truncate(digest_bytes);
+        digest
+    };
+
+    let mut row: Vec<u8> = (0..number_of_leaves)
+        .flat_map(|i| node_digest(&leaves[i * input_block_len..(i + 1) * input_block_len]))
+        .collect();
+    let mut offset = 0;
+    for depth in (0..=height).rev() {
+        if depth < kept_rows {
+            for (digest, word) in digests[offset..]
+                .iter_mut()
+                .zip(row.chunks(8))
+            {
+                *digest = u64::from_le_bytes(
+                    word.try_into()
+                        .unwrap(),
+                );
+            }
+            offset += row.len() / 8;
+        }
+        if depth > 0 {
+            row = row
+                .chunks(arity * digest_bytes)
+                .flat_map(&node_digest)
+                .collect();
+        }
+    }
+    Ok(())
+}
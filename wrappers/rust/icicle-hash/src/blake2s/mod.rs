@@ -0,0 +1,183 @@
+use icicle_core::{error::IcicleResult, hash::HashConfig, tree::TreeBuilderConfig};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::batch;
+
+pub mod tests;
+
+const DIGEST_LEN: usize = 32;
+const BLOCK_LEN: usize = 64;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a]
+        .wrapping_add(v[b])
+        .wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a]
+        .wrapping_add(v[b])
+        .wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+fn compress(h: &mut [u32; 8], block: &[u8; BLOCK_LEN], counter: u64, last: bool) {
+    let mut m = [0u32; 16];
+    for (i, word) in block
+        .chunks(4)
+        .enumerate()
+    {
+        m[i] = u32::from_le_bytes(
+            word.try_into()
+                .unwrap(),
+        );
+    }
+
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= counter as u32;
+    v[13] ^= (counter >> 32) as u32;
+    if last {
+        v[14] = !v[14];
+    }
+    for s in SIGMA {
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// Streaming BLAKE2s with 32-byte digests and no key.
+#[derive(Debug, Clone)]
+pub struct Blake2sHasher {
+    h: [u32; 8],
+    buffer: [u8; BLOCK_LEN],
+    buffer_len: usize,
+    // Bytes compressed so far
+    counter: u64,
+}
+
+impl Blake2sHasher {
+    pub fn new() -> Self {
+        let mut h = IV;
+        // Parameter block: digest length, no key, fanout and depth of 1
+        h[0] ^= 0x01010000 ^ DIGEST_LEN as u32;
+        Self {
+            h,
+            buffer: [0; BLOCK_LEN],
+            buffer_len: 0,
+            counter: 0,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            // The last block is compressed differently, so a full buffer waits for more data
+            if self.buffer_len == BLOCK_LEN {
+                self.counter += BLOCK_LEN as u64;
+                compress(&mut self.h, &self.buffer, self.counter, false);
+                self.buffer_len = 0;
+            }
+            self.buffer[self.buffer_len] = *byte;
+            self.buffer_len += 1;
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; DIGEST_LEN] {
+        self.counter += self.buffer_len as u64;
+        self.buffer[self.buffer_len..].fill(0);
+        compress(&mut self.h, &self.buffer, self.counter, true);
+
+        let mut digest = [0u8; DIGEST_LEN];
+        for (bytes, word) in digest
+            .chunks_mut(4)
+            .zip(self.h)
+        {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+
+    /// Hashes a whole message at once.
+    pub fn digest(data: &[u8]) -> [u8; DIGEST_LEN] {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+impl Default for Blake2sHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes `number_of_blocks` blocks of `input_block_size` bytes into 32-byte digests.
+///
+/// BLAKE2s has no device kernels yet: the hashes are computed on the host and device memory is rejected. The config
+/// is ignored.
+pub fn blake2s(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    batch::hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        DIGEST_LEN,
+        |block, digest| digest.copy_from_slice(&Blake2sHasher::digest(block)),
+    )
+}
+
+/// Builds a BLAKE2s Merkle tree on the host, each node hashing the digests of its children.
+///
+/// A digest is made of `config.digest_elements()` 64-bit words of the hash, set it to 4 to keep whole hashes.
+pub fn build_blake2s_merkle_tree(
+    leaves: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    digests: &mut (impl HostOrDeviceSlice<u64> + ?Sized),
+    height: usize,
+    input_block_len: usize,
+    config: &TreeBuilderConfig,
+) -> IcicleResult<()> {
+    batch::build_merkle_tree(
+        leaves,
+        digests,
+        height,
+        input_block_len,
+        config,
+        DIGEST_LEN,
+        |input, digest| digest.copy_from_slice(&Blake2sHasher::digest(input)),
+    )
+}
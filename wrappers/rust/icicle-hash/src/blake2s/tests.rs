@@ -0,0 +1,120 @@
+#[cfg(test)]
+pub(crate) mod tests {
+    use icicle_core::{
+        hash::HashConfig,
+        tree::{merkle_tree_digests_len, TreeBuilderConfig},
+    };
+    use icicle_cuda_runtime::memory::HostSlice;
+
+    use crate::blake2s::{blake2s, build_blake2s_merkle_tree, Blake2sHasher};
+
+    fn hex_digest(digest: &[u8]) -> String {
+        digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    #[test]
+    fn blake2s_vectors() {
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"),
+            (
+                b"abc",
+                "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "6f4df5116a6f332edab1d9e10ee87df6557beab6259d7663f3bcd5722c13f189",
+            ),
+        ];
+        for (message, expected) in vectors {
+            assert_eq!(hex_digest(&Blake2sHasher::digest(message)), expected);
+        }
+        assert_eq!(
+            hex_digest(&Blake2sHasher::digest(&vec![b'a'; 1_000_000])),
+            "bec0c0e6cde5b67acb73b81f79a67a4079ae1c60dac9d2661af18e9f8b50dfa5"
+        );
+    }
+
+    #[test]
+    fn blake2s_streaming() {
+        let message: Vec<u8> = (0..1000u32)
+            .map(|i| i as u8)
+            .collect();
+        let expected = Blake2sHasher::digest(&message);
+        for chunk_len in [1, 63, 64, 65, 128, 333] {
+            let mut hasher = Blake2sHasher::new();
+            for chunk in message.chunks(chunk_len) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn blake2s_hash_many() {
+        let input_block_len = 64;
+        let number_of_hashes = 16;
+        let input: Vec<u8> = (0..input_block_len * number_of_hashes)
+            .map(|i| i as u8)
+            .collect();
+        let mut output = vec![0u8; 32 * number_of_hashes];
+
+        blake2s(
+            HostSlice::from_slice(&input),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut output),
+            &HashConfig::default(),
+        )
+        .unwrap();
+
+        for (block, digest) in input
+            .chunks(input_block_len)
+            .zip(output.chunks(32))
+        {
+            assert_eq!(digest, Blake2sHasher::digest(block));
+        }
+    }
+
+    #[test]
+    fn blake2s_merkle_tree() {
+        let config = TreeBuilderConfig::builder()
+            .arity(4)
+            .digest_elements(4)
+            .build()
+            .unwrap();
+        let height = 2;
+        let input_block_len = 32;
+        let leaves: Vec<u8> = (0..(1 << (2 * height)) * input_block_len)
+            .map(|i| i as u8)
+            .collect();
+        let mut digests = vec![0u64; merkle_tree_digests_len(height as u32, 4, 4)];
+
+        build_blake2s_merkle_tree(
+            HostSlice::from_slice(&leaves),
+            HostSlice::from_mut_slice(&mut digests),
+            height,
+            input_block_len,
+            &config,
+        )
+        .unwrap();
+
+        let mut row: Vec<u8> = leaves
+            .chunks(input_block_len)
+            .flat_map(Blake2sHasher::digest)
+            .collect();
+        for _ in 0..height {
+            row = row
+                .chunks(4 * 32)
+                .flat_map(Blake2sHasher::digest)
+                .collect();
+        }
+        let root: Vec<u8> = digests[digests.len() - 4..]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        assert_eq!(root, row);
+    }
+}
@@ -0,0 +1,330 @@
+use icicle_core::{error::IcicleResult, hash::HashConfig, tree::TreeBuilderConfig};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::batch;
+
+pub mod tests;
+
+const DIGEST_LEN: usize = 32;
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+// Message word permutation applied between rounds
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    v[a] = v[a]
+        .wrapping_add(v[b])
+        .wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a]
+        .wrapping_add(v[b])
+        .wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+fn compress(cv: &[u32; 8], block: &[u32; 16], counter: u64, block_len: u32, flags: u32) -> [u32; 16] {
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(cv);
+    v[8..12].copy_from_slice(&IV[..4]);
+    v[12] = counter as u32;
+    v[13] = (counter >> 32) as u32;
+    v[14] = block_len;
+    v[15] = flags;
+
+    let mut m = *block;
+    for round in 0..7 {
+        g(&mut v, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut v, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut v, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut v, 3, 7, 11, 15, m[6], m[7]);
+        g(&mut v, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut v, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut v, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut v, 3, 4, 9, 14, m[14], m[15]);
+        if round < 6 {
+            m = MSG_PERMUTATION.map(|i| m[i]);
+        }
+    }
+    for i in 0..8 {
+        v[i] ^= v[i + 8];
+        v[i + 8] ^= cv[i];
+    }
+    v
+}
+
+fn chaining_value(words: &[u32; 16]) -> [u32; 8] {
+    words[..8]
+        .try_into()
+        .unwrap()
+}
+
+fn block_words(block: &[u8; BLOCK_LEN]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    for (word, bytes) in words
+        .iter_mut()
+        .zip(block.chunks(4))
+    {
+        *word = u32::from_le_bytes(
+            bytes
+                .try_into()
+                .unwrap(),
+        );
+    }
+    words
+}
+
+// Inputs of the last compression of a node, which is redone with the root flag when the node is the root
+#[derive(Debug, Clone, Copy)]
+struct Output {
+    cv: [u32; 8],
+    block: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl Output {
+    fn chaining_value(&self) -> [u32; 8] {
+        chaining_value(&compress(
+            &self.cv,
+            &self.block,
+            self.counter,
+            self.block_len,
+            self.flags,
+        ))
+    }
+
+    fn root_digest(&self) -> [u8; DIGEST_LEN] {
+        let words = compress(&self.cv, &self.block, 0, self.block_len, self.flags | ROOT);
+        let mut digest = [0u8; DIGEST_LEN];
+        for (bytes, word) in digest
+            .chunks_mut(4)
+            .zip(words)
+        {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+
+    fn parent(left: [u32; 8], right: [u32; 8]) -> Self {
+        let mut block = [0u32; 16];
+        block[..8].copy_from_slice(&left);
+        block[8..].copy_from_slice(&right);
+        Self {
+            cv: IV,
+            block,
+            counter: 0,
+            block_len: BLOCK_LEN as u32,
+            flags: PARENT,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ChunkState {
+    cv: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+}
+
+impl ChunkState {
+    fn new(chunk_counter: u64) -> Self {
+        Self {
+            cv: IV,
+            chunk_counter,
+            block: [0; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.blocks_compressed * BLOCK_LEN + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            // The last block of a chunk is compressed differently, so a full block waits for more data
+            if self.block_len == BLOCK_LEN {
+                let words = compress(
+                    &self.cv,
+                    &block_words(&self.block),
+                    self.chunk_counter,
+                    BLOCK_LEN as u32,
+                    self.start_flag(),
+                );
+                self.cv = chaining_value(&words);
+                self.blocks_compressed += 1;
+                self.block_len = 0;
+            }
+            self.block[self.block_len] = *byte;
+            self.block_len += 1;
+        }
+    }
+
+    fn output(&self) -> Output {
+        let mut block = self.block;
+        block[self.block_len..].fill(0);
+        Output {
+            cv: self.cv,
+            block: block_words(&block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+/// Streaming BLAKE3 in its default hash mode, with 32-byte digests.
+#[derive(Debug, Clone)]
+pub struct Blake3Hasher {
+    chunk_state: ChunkState,
+    // Chaining values of the complete subtrees on the left of the current chunk, largest first
+    cv_stack: Vec<[u32; 8]>,
+}
+
+impl Blake3Hasher {
+    pub fn new() -> Self {
+        Self {
+            chunk_state: ChunkState::new(0),
+            cv_stack: Vec::new(),
+        }
+    }
+
+    // Merges the chunk with the subtrees it completes, one per trailing zero bit of the number of chunks
+    fn push_chunk_cv(&mut self, mut cv: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            let left = self
+                .cv_stack
+                .pop()
+                .unwrap();
+            cv = Output::parent(left, cv).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack
+            .push(cv);
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // A full chunk is only closed once more data comes, the last chunk being the root when it is alone
+            if self
+                .chunk_state
+                .len()
+                == CHUNK_LEN
+            {
+                let cv = self
+                    .chunk_state
+                    .output()
+                    .chaining_value();
+                let total_chunks = self
+                    .chunk_state
+                    .chunk_counter
+                    + 1;
+                self.push_chunk_cv(cv, total_chunks);
+                self.chunk_state = ChunkState::new(total_chunks);
+            }
+            let take = (CHUNK_LEN
+                - self
+                    .chunk_state
+                    .len())
+            .min(data.len());
+            self.chunk_state
+                .update(&data[..take]);
+            data = &data[take..];
+        }
+    }
+
+    pub fn finalize(self) -> [u8; DIGEST_LEN] {
+        let mut output = self
+            .chunk_state
+            .output();
+        for left in self
+            .cv_stack
+            .iter()
+            .rev()
+        {
+            output = Output::parent(*left, output.chaining_value());
+        }
+        output.root_digest()
+    }
+
+    /// Hashes a whole message at once.
+    pub fn digest(data: &[u8]) -> [u8; DIGEST_LEN] {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+impl Default for Blake3Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes `number_of_blocks` blocks of `input_block_size` bytes into 32-byte digests.
+///
+/// BLAKE3 has no device kernels yet: the hashes are computed on the host and device memory is rejected. The config is
+/// ignored.
+pub fn blake3(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    batch::hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        DIGEST_LEN,
+        |block, digest| digest.copy_from_slice(&Blake3Hasher::digest(block)),
+    )
+}
+
+/// Builds a BLAKE3 Merkle tree on the host, each node hashing the digests of its children.
+///
+/// A digest is made of `config.digest_elements()` 64-bit words of the hash, set it to 4 to keep whole hashes.
+pub fn build_blake3_merkle_tree(
+    leaves: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    digests: &mut (impl HostOrDeviceSlice<u64> + ?Sized),
+    height: usize,
+    input_block_len: usize,
+    config: &TreeBuilderConfig,
+) -> IcicleResult<()> {
+    batch::build_merkle_tree(
+        leaves,
+        digests,
+        height,
+        input_block_len,
+        config,
+        DIGEST_LEN,
+        |input, digest| digest.copy_from_slice(&Blake3Hasher::digest(input)),
+    )
+}
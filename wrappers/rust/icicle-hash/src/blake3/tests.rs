@@ -0,0 +1,145 @@
+#[cfg(test)]
+pub(crate) mod tests {
+    use icicle_core::{
+        hash::HashConfig,
+        tree::{merkle_tree_digests_len, TreeBuilderConfig},
+    };
+    use icicle_cuda_runtime::memory::HostSlice;
+
+    use crate::blake3::{blake3, build_blake3_merkle_tree, Blake3Hasher};
+
+    fn hex_digest(digest: &[u8]) -> String {
+        digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    // Inputs of the official test vectors
+    fn test_input(len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|i| (i % 251) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn blake3_vectors() {
+        // Official test vectors, truncated to 32 bytes
+        let vectors = [
+            (0, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+            (1, "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213"),
+            (1023, "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11"),
+            (1024, "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"),
+            (1025, "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"),
+            (2048, "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a"),
+            (2049, "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b6879522563030"),
+            (3072, "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd2"),
+            (3073, "7124b49501012f81cc7f11ca069ec9226cecb8a2c850cfe644e327d22d3e1cd3"),
+            (4096, "015094013f57a5277b59d8475c0501042c0b642e531b0a1c8f58d2163229e969"),
+            (4097, "9b4052b38f1c5fc8b1f9ff7ac7b27cd242487b3d890d15c96a1c25b8aa0fb995"),
+            (5120, "9cadc15fed8b5d854562b26a9536d9707cadeda9b143978f319ab34230535833"),
+            (8193, "bab6c09cb8ce8cf459261398d2e7aef35700bf488116ceb94a36d0f5f1b7bc3b"),
+            (
+                102400,
+                "bc3e3d41a1146b069abffad3c0d44860cf664390afce4d9661f7902e7943e085",
+            ),
+        ];
+        for (len, expected) in vectors {
+            assert_eq!(hex_digest(&Blake3Hasher::digest(&test_input(len))), expected);
+        }
+        assert_eq!(
+            hex_digest(&Blake3Hasher::digest(b"abc")),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn blake3_streaming() {
+        let message = test_input(5000);
+        let expected = Blake3Hasher::digest(&message);
+        for chunk_len in [1, 63, 64, 65, 1023, 1024, 1025, 3000] {
+            let mut hasher = Blake3Hasher::new();
+            for chunk in message.chunks(chunk_len) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn blake3_hash_many() {
+        let input_block_len = 1500;
+        let number_of_hashes = 8;
+        let input = test_input(input_block_len * number_of_hashes);
+        let mut output = vec![0u8; 32 * number_of_hashes];
+
+        blake3(
+            HostSlice::from_slice(&input),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut output),
+            &HashConfig::default(),
+        )
+        .unwrap();
+
+        for (block, digest) in input
+            .chunks(input_block_len)
+            .zip(output.chunks(32))
+        {
+            assert_eq!(digest, Blake3Hasher::digest(block));
+        }
+    }
+
+    #[test]
+    fn blake3_merkle_tree() {
+        let config = TreeBuilderConfig::builder()
+            .arity(2)
+            .digest_elements(4)
+            .build()
+            .unwrap();
+        let height = 4;
+        let input_block_len = 64;
+        let leaves = test_input((1 << height) * input_block_len);
+        let mut digests = vec![0u64; merkle_tree_digests_len(height as u32, 2, 4)];
+
+        build_blake3_merkle_tree(
+            HostSlice::from_slice(&leaves),
+            HostSlice::from_mut_slice(&mut digests),
+            height,
+            input_block_len,
+            &config,
+        )
+        .unwrap();
+
+        let mut row: Vec<u8> = leaves
+            .chunks(input_block_len)
+            .flat_map(Blake3Hasher::digest)
+            .collect();
+        for _ in 0..height {
+            row = row
+                .chunks(2 * 32)
+                .flat_map(Blake3Hasher::digest)
+                .collect();
+        }
+        let root: Vec<u8> = digests[digests.len() - 4..]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        assert_eq!(root, row);
+
+        // Digests longer than the hash are rejected
+        let config = TreeBuilderConfig::builder()
+            .arity(2)
+            .digest_elements(5)
+            .build()
+            .unwrap();
+        assert!(build_blake3_merkle_tree(
+            HostSlice::from_slice(&leaves),
+            HostSlice::from_mut_slice(&mut digests),
+            height,
+            input_block_len,
+            &config,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use icicle_core::{
+    error::{IcicleError, IcicleResult},
+    hash::HashConfig,
+};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::batch;
+
+const KECCAK_STATE_SIZE: usize = 25;
+
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+// Rotation offsets of the rho step, indexed by x + 5 * y
+const RHO: [u32; KECCAK_STATE_SIZE] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The Keccak-f[1600] permutation. Lane `(x, y)` of the state is `state[x + 5 * y]`.
+pub fn keccak_f1600(state: &mut [u64; KECCAK_STATE_SIZE]) {
+    for rc in RC {
+        // theta
+        let mut c = [0u64; 5];
+        for (x, c) in c
+            .iter_mut()
+            .enumerate()
+        {
+            *c = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho and pi: lane (x, y) moves to (y, 2x + 3y)
+        let mut b = [0u64; KECCAK_STATE_SIZE];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(RHO[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+
+        // iota
+        state[0] ^= rc;
+    }
+}
+
+/// Domain separation bits appended to the message before the `10*1` padding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeccakPadding {
+    /// The original Keccak padding, used by Ethereum and by the device kernels.
+    Keccak,
+    /// FIPS 202 SHA3 padding.
+    Sha3,
+}
+
+impl KeccakPadding {
+    fn first_byte(&self) -> u8 {
+        match self {
+            KeccakPadding::Keccak => 0x01,
+            KeccakPadding::Sha3 => 0x06,
+        }
+    }
+}
+
+/// Keccak sponge absorbing a message of any length in pieces.
+#[derive(Debug, Clone)]
+pub struct KeccakHasher {
+    state: [u64; KECCAK_STATE_SIZE],
+    rate: usize,
+    digest_len: usize,
+    padding: KeccakPadding,
+    // Bytes of the current block that are already absorbed
+    position: usize,
+}
+
+impl KeccakHasher {
+    /// Keccak with a capacity of twice the digest length, as in every standard instance.
+    pub fn new(digest_len: usize, padding: KeccakPadding) -> IcicleResult<Self> {
+        if digest_len == 0 || digest_len >= 100 || digest_len % 4 != 0 {
+            return Err(IcicleError::invalid_argument(
+                "Keccak digest length must be a positive multiple of 4 bytes below 100",
+            ));
+        }
+        Ok(Self {
+            state: [0; KECCAK_STATE_SIZE],
+            rate: 200 - 2 * digest_len,
+            digest_len,
+            padding,
+            position: 0,
+        })
+    }
+
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.xor_byte(self.position, *byte);
+            self.position += 1;
+            if self.position == self.rate {
+                keccak_f1600(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    /// Pads the message, applies the last permutation and writes the digest into `output`.
+    pub fn finalize_into(mut self, output: &mut [u8]) {
+        assert_eq!(
+            output.len(),
+            self.digest_len,
+            "output len must be equal to the digest len"
+        );
+
+        self.xor_byte(
+            self.position,
+            self.padding
+                .first_byte(),
+        );
+        self.xor_byte(self.rate - 1, 0x80);
+        keccak_f1600(&mut self.state);
+
+        for (i, byte) in output
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = (self.state[i / 8] >> (8 * (i % 8))) as u8;
+        }
+    }
+}
+
+macro_rules! impl_keccak_hasher {
+    ($name:ident, $digest_len:literal, $padding:expr, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone)]
+        pub struct $name(KeccakHasher);
+
+        impl $name {
+            pub fn new() -> Self {
+                Self(KeccakHasher::new($digest_len, $padding).unwrap())
+            }
+
+            pub fn update(&mut self, data: &[u8]) {
+                self.0
+                    .update(data);
+            }
+
+            pub fn finalize(self) -> [u8; $digest_len] {
+                let mut digest = [0u8; $digest_len];
+                self.0
+                    .finalize_into(&mut digest);
+                digest
+            }
+
+            /// Hashes a whole message at once.
+            pub fn digest(data: &[u8]) -> [u8; $digest_len] {
+                let mut hasher = Self::new();
+                hasher.update(data);
+                hasher.finalize()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_keccak_hasher!(
+    Keccak256Hasher,
+    32,
+    KeccakPadding::Keccak,
+    "Streaming Keccak-256 with the Ethereum padding."
+);
+impl_keccak_hasher!(
+    Keccak512Hasher,
+    64,
+    KeccakPadding::Keccak,
+    "Streaming Keccak-512 with the Ethereum padding."
+);
+impl_keccak_hasher!(Sha3_256Hasher, 32, KeccakPadding::Sha3, "Streaming SHA3-256.");
+impl_keccak_hasher!(Sha3_512Hasher, 64, KeccakPadding::Sha3, "Streaming SHA3-512.");
+
+fn hash_blocks(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    digest_len: usize,
+    padding: KeccakPadding,
+) -> IcicleResult<()> {
+    batch::hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        digest_len,
+        |block, digest| {
+            let mut hasher = KeccakHasher::new(digest_len, padding).unwrap();
+            hasher.update(block);
+            hasher.finalize_into(digest);
+        },
+    )
+}
+
+/// Host version of [keccak256](super::keccak256): hashes `number_of_blocks` blocks of `input_block_size` bytes into
+/// 32-byte digests. The config is ignored.
+pub fn keccak256(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        32,
+        KeccakPadding::Keccak,
+    )
+}
+
+/// Host version of [keccak512](super::keccak512): hashes `number_of_blocks` blocks of `input_block_size` bytes into
+/// 64-byte digests. The config is ignored.
+pub fn keccak512(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        64,
+        KeccakPadding::Keccak,
+    )
+}
+
+/// Same as [keccak256] with the SHA3 padding.
+pub fn sha3_256(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        32,
+        KeccakPadding::Sha3,
+    )
+}
+
+/// Same as [keccak512] with the SHA3 padding.
+pub fn sha3_512(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        64,
+        KeccakPadding::Sha3,
+    )
+}
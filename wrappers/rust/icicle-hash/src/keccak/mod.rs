@@ -6,7 +6,9 @@ use icicle_cuda_runtime::memory::HostOrDeviceSlice;
 use icicle_core::error::IcicleResult;
 use icicle_core::traits::IcicleResultWrap;
 
+pub mod host;
 pub mod tests;
+pub mod tree;
 
 extern "C" {
     pub(crate) fn keccak256_cuda(
@@ -49,9 +51,7 @@ pub fn keccak256(
     output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
     config: &HashConfig,
 ) -> IcicleResult<()> {
-    let mut local_cfg = config.clone();
-    local_cfg.are_inputs_on_device = input.is_on_device();
-    local_cfg.are_outputs_on_device = output.is_on_device();
+    let local_cfg = config.for_slices(input, output);
     unsafe {
         keccak256_cuda(
             input.as_ptr(),
@@ -71,9 +71,7 @@ pub fn keccak512(
     output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
     config: &HashConfig,
 ) -> IcicleResult<()> {
-    let mut local_cfg = config.clone();
-    local_cfg.are_inputs_on_device = input.is_on_device();
-    local_cfg.are_outputs_on_device = output.is_on_device();
+    let local_cfg = config.for_slices(input, output);
     unsafe {
         keccak512_cuda(
             input.as_ptr(),
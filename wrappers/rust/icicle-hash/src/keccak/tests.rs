@@ -1,12 +1,28 @@
 #[cfg(test)]
 pub(crate) mod tests {
+    use icicle_core::traits::FieldImpl;
     use icicle_core::{
         hash::HashConfig,
         tree::{merkle_tree_digests_len, TreeBuilderConfig},
     };
-    use icicle_cuda_runtime::memory::HostSlice;
+    use icicle_cuda_runtime::memory::{DeviceVec, HostSlice};
+    use icicle_m31::field::ScalarField;
 
-    use crate::keccak::{build_keccak256_merkle_tree, keccak256};
+    use crate::keccak::{
+        build_keccak256_merkle_tree,
+        host::{self, Keccak256Hasher, Keccak512Hasher, Sha3_256Hasher, Sha3_512Hasher},
+        keccak256,
+        tree::{KeccakMerkleTree, LeafEncoding},
+    };
+
+    fn hex_digest(digest: &[u8]) -> String {
+        digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    const MESSAGES: [&[u8]; 3] = [b"", b"abc", b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"];
 
     #[test]
     fn keccak_hash_test() {
@@ -32,8 +48,10 @@ pub(crate) mod tests {
 
     #[test]
     fn keccak_merkle_tree_test() {
-        let mut config = TreeBuilderConfig::default();
-        config.arity = 2;
+        let config = TreeBuilderConfig::builder()
+            .arity(2)
+            .build()
+            .unwrap();
         let height = 22;
         let input_block_len = 136;
         let leaves = vec![1u8; (1 << height) * input_block_len];
@@ -45,4 +63,241 @@ pub(crate) mod tests {
         build_keccak256_merkle_tree(leaves_slice, digests_slice, height, input_block_len, &config).unwrap();
         println!("Root: {:?}", digests_slice[0]);
     }
+
+    #[test]
+    fn keccak_host_vectors() {
+        let keccak256_digests = [
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            "45d3b367a6904e6e8d502ee04999a7c27647f91fa845d456525fd352ae3d7371",
+        ];
+        let keccak512_digests = [
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+            "18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96",
+            "6aa6d3669597df6d5a007b00d09c20795b5c4218234e1698a944757a488ecdc09965435d97ca32c3cfed7201ff30e070cd947f1fc12b9d9214c467d342bcba5d",
+        ];
+        // FIPS 202 examples
+        let sha3_256_digests = [
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+        ];
+        let sha3_512_digests = [
+            "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+            "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e",
+        ];
+
+        for (i, message) in MESSAGES
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(hex_digest(&Keccak256Hasher::digest(message)), keccak256_digests[i]);
+            assert_eq!(hex_digest(&Keccak512Hasher::digest(message)), keccak512_digests[i]);
+            assert_eq!(hex_digest(&Sha3_256Hasher::digest(message)), sha3_256_digests[i]);
+            assert_eq!(hex_digest(&Sha3_512Hasher::digest(message)), sha3_512_digests[i]);
+        }
+
+        // One million times 'a', absorbed in pieces that don't line up with the rate
+        let mut hasher = Sha3_256Hasher::new();
+        for _ in 0..1000 {
+            hasher.update(&[b'a'; 1000]);
+        }
+        assert_eq!(
+            hex_digest(&hasher.finalize()),
+            "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1"
+        );
+    }
+
+    #[test]
+    fn keccak_host_streaming() {
+        let message: Vec<u8> = (0..1000u32)
+            .map(|i| (i * 7) as u8)
+            .collect();
+        let expected = Keccak256Hasher::digest(&message);
+        for chunk_len in [1, 71, 135, 136, 137, 500] {
+            let mut hasher = Keccak256Hasher::new();
+            for chunk in message.chunks(chunk_len) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn keccak_host_hash_many() {
+        let config = HashConfig::default();
+        let input_block_len = 300;
+        let number_of_hashes = 16;
+
+        let preimages: Vec<u8> = (0..number_of_hashes * input_block_len)
+            .map(|i| i as u8)
+            .collect();
+        let mut digests = vec![0u8; number_of_hashes * 32];
+        let mut wide_digests = vec![0u8; number_of_hashes * 64];
+        host::keccak256(
+            HostSlice::from_slice(&preimages),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut digests),
+            &config,
+        )
+        .unwrap();
+        host::keccak512(
+            HostSlice::from_slice(&preimages),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut wide_digests),
+            &config,
+        )
+        .unwrap();
+
+        for (i, preimage) in preimages
+            .chunks(input_block_len)
+            .enumerate()
+        {
+            assert_eq!(digests[i * 32..(i + 1) * 32], Keccak256Hasher::digest(preimage));
+            assert_eq!(wide_digests[i * 64..(i + 1) * 64], Keccak512Hasher::digest(preimage));
+        }
+    }
+
+    #[test]
+    fn keccak_host_matches_device() {
+        let config = HashConfig::default();
+        let input_block_len = 200;
+        let number_of_hashes = 64;
+
+        let preimages: Vec<u8> = (0..number_of_hashes * input_block_len)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let mut digests = vec![0u8; number_of_hashes * 32];
+        let mut host_digests = vec![0u8; number_of_hashes * 32];
+        keccak256(
+            HostSlice::from_slice(&preimages),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut digests),
+            &config,
+        )
+        .unwrap();
+        host::keccak256(
+            HostSlice::from_slice(&preimages),
+            input_block_len as u32,
+            number_of_hashes as u32,
+            HostSlice::from_mut_slice(&mut host_digests),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(digests, host_digests);
+    }
+
+    #[test]
+    fn keccak_merkle_tree_of_field_elements() {
+        let leaves: Vec<ScalarField> = (1..=4)
+            .map(ScalarField::from_u32)
+            .collect();
+
+        // Root of an OpenZeppelin-style tree of keccak256(abi.encode(uint256(i))) with index ordered pairs
+        let tree = KeccakMerkleTree::new(HostSlice::from_slice(&leaves), 1, LeafEncoding::BigEndianWord).unwrap();
+        assert_eq!(
+            hex_digest(&tree.root()),
+            "1e8cc8511a4954df48a80e5f5b8da3419a99ba3e7697574234e10893022167fc"
+        );
+        assert_eq!(tree.height(), 2);
+
+        let tree = KeccakMerkleTree::new(HostSlice::from_slice(&leaves), 2, LeafEncoding::LittleEndian).unwrap();
+        assert_eq!(
+            hex_digest(&tree.root()),
+            "6254b68638d9aa95cfbee7e797ad9fb244ed53d0dc04b58393c143855766464b"
+        );
+
+        assert!(KeccakMerkleTree::new(HostSlice::from_slice(&leaves), 3, LeafEncoding::LittleEndian).is_err());
+        assert!(KeccakMerkleTree::new(HostSlice::from_slice(&leaves[..3]), 1, LeafEncoding::LittleEndian).is_err());
+    }
+
+    #[test]
+    fn keccak_merkle_tree_open_verify() {
+        let leaves: Vec<ScalarField> = (0..64)
+            .map(|i| ScalarField::from_u32(i * 7 + 1))
+            .collect();
+        let tree = KeccakMerkleTree::new(HostSlice::from_slice(&leaves), 4, LeafEncoding::BigEndian).unwrap();
+        assert_eq!(tree.number_of_leaves(), 16);
+
+        for index in 0..tree.number_of_leaves() {
+            let proof = tree
+                .open(index)
+                .unwrap();
+            assert_eq!(proof.leaf, leaves[4 * index..4 * index + 4]);
+            assert_eq!(
+                proof
+                    .siblings
+                    .len(),
+                4
+            );
+            assert!(tree.verify(&proof));
+            assert!(proof.verify(&tree.root(), LeafEncoding::BigEndian));
+            assert!(!proof.verify(&tree.root(), LeafEncoding::LittleEndian));
+
+            let mut wrong_leaf = proof.clone();
+            wrong_leaf.leaf[0] = ScalarField::from_u32(1000);
+            assert!(!tree.verify(&wrong_leaf));
+
+            let mut wrong_index = proof.clone();
+            wrong_index.index ^= 1;
+            assert!(!tree.verify(&wrong_index));
+            wrong_index.index = index + 16;
+            assert!(!tree.verify(&wrong_index));
+
+            let mut wrong_sibling = proof.clone();
+            wrong_sibling.siblings[3][0] ^= 1;
+            assert!(!tree.verify(&wrong_sibling));
+        }
+        assert!(tree
+            .open(16)
+            .is_err());
+    }
+
+    #[test]
+    fn keccak_merkle_tree_of_device_leaves() {
+        let leaves: Vec<ScalarField> = (0..64)
+            .map(|i| ScalarField::from_u32(i * 7 + 1))
+            .collect();
+        let mut device_leaves = DeviceVec::<ScalarField>::cuda_malloc(leaves.len()).unwrap();
+        device_leaves
+            .copy_from_host(HostSlice::from_slice(&leaves))
+            .unwrap();
+
+        let tree = KeccakMerkleTree::new(&device_leaves[..], 4, LeafEncoding::LittleEndian).unwrap();
+        let host_tree = KeccakMerkleTree::new(HostSlice::from_slice(&leaves), 4, LeafEncoding::LittleEndian).unwrap();
+        assert_eq!(tree.row(0), host_tree.row(0));
+        assert_eq!(tree.root(), host_tree.root());
+        assert_eq!(
+            tree.open(5)
+                .unwrap(),
+            host_tree
+                .open(5)
+                .unwrap()
+        );
+
+        assert!(KeccakMerkleTree::new(&device_leaves[..], 4, LeafEncoding::BigEndian).is_err());
+    }
+
+    #[test]
+    fn keccak_merkle_tree_of_unaligned_device_leaves() {
+        // 35 elements of 4 bytes span a full block of the rate, and every other leaf starts off an 8-byte boundary
+        let leaf_len = 35;
+        let leaves: Vec<ScalarField> = (0..8 * leaf_len as u32)
+            .map(|i| ScalarField::from_u32(i * 5 + 3))
+            .collect();
+        let mut device_leaves = DeviceVec::<ScalarField>::cuda_malloc(leaves.len()).unwrap();
+        device_leaves
+            .copy_from_host(HostSlice::from_slice(&leaves))
+            .unwrap();
+
+        let tree = KeccakMerkleTree::new(&device_leaves[..], leaf_len, LeafEncoding::LittleEndian).unwrap();
+        let host_tree =
+            KeccakMerkleTree::new(HostSlice::from_slice(&leaves), leaf_len, LeafEncoding::LittleEndian).unwrap();
+        assert_eq!(tree.row(0), host_tree.row(0));
+        assert_eq!(tree.root(), host_tree.root());
+    }
 }
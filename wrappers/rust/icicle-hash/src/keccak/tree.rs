@@ -0,0 +1,266 @@
+use icicle_core::{
+    error::{IcicleError, IcicleResult},
+    hash::HashConfig,
+    traits::FieldImpl,
+};
+use icicle_cuda_runtime::memory::{DeviceSlice, HostOrDeviceSlice, HostSlice};
+
+use super::{host::Keccak256Hasher, keccak256};
+
+const DIGEST_LEN: usize = 32;
+// Bytes absorbed per Keccak-256 permutation
+const KECCAK256_RATE: usize = 136;
+
+pub type KeccakDigest = [u8; DIGEST_LEN];
+
+/// Byte encoding of the field elements of a leaf before it is hashed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeafEncoding {
+    /// Elements as they are stored, least significant byte first.
+    LittleEndian,
+    /// Elements with the most significant byte first, like `abi.encodePacked` of integers of the field size.
+    BigEndian,
+    /// Elements left-padded to 32-byte big-endian words, like `abi.encode` of `uint256` values.
+    BigEndianWord,
+}
+
+impl LeafEncoding {
+    pub fn encode<F: FieldImpl>(&self, leaf: &[F]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for element in leaf {
+            let mut element_bytes = element.to_bytes_le();
+            match self {
+                LeafEncoding::LittleEndian => {}
+                LeafEncoding::BigEndian => element_bytes.reverse(),
+                LeafEncoding::BigEndianWord => {
+                    element_bytes.resize(
+                        element_bytes
+                            .len()
+                            .max(32),
+                        0,
+                    );
+                    element_bytes.reverse();
+                }
+            }
+            bytes.extend(element_bytes);
+        }
+        bytes
+    }
+}
+
+fn hash_pair(left: &KeccakDigest, right: &KeccakDigest) -> KeccakDigest {
+    let mut hasher = Keccak256Hasher::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize()
+}
+
+/// Binary Keccak-256 Merkle tree over leaves of field elements, built on the host.
+///
+/// A leaf is hashed as `keccak256(encoding(leaf))` and a node as `keccak256(left || right)`, the left child being the
+/// one with the even index. This is what the usual Solidity verifiers compute when they walk up with the leaf index.
+#[derive(Debug, Clone)]
+pub struct KeccakMerkleTree<F: FieldImpl> {
+    // Rows from the leaf hashes up to the root
+    rows: Vec<Vec<KeccakDigest>>,
+    leaves: Vec<F>,
+    leaf_len: usize,
+    encoding: LeafEncoding,
+}
+
+/// Opening of one leaf of a [KeccakMerkleTree].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeccakMerkleProof<F: FieldImpl> {
+    pub index: usize,
+    pub leaf: Vec<F>,
+    /// Siblings of the path from the leaf to the root, the leaf's sibling first.
+    pub siblings: Vec<KeccakDigest>,
+}
+
+impl<F: FieldImpl> KeccakMerkleTree<F> {
+    /// Commits to `leaves` split into consecutive leaves of `leaf_len` elements. The number of leaves must be a power
+    /// of two.
+    ///
+    /// Leaves in device memory are hashed on the device with [keccak256](super::keccak256), which reads them as they
+    /// are stored, so they must use [LeafEncoding::LittleEndian]. Leaves of at least 136 bytes, the Keccak-256 rate,
+    /// whose size isn't a multiple of 8 are hashed on the host, the device would read them at misaligned addresses.
+    /// The rows above the leaf hashes are built on the host:
+    /// [build_keccak256_merkle_tree](super::build_keccak256_merkle_tree) only hashes the first
+    /// `arity * digest_elements` bytes of the children of a node, not their concatenated digests. The leaves are still
+    /// copied to the host for [open](Self::open).
+    pub fn new(
+        leaves: &(impl HostOrDeviceSlice<F> + ?Sized),
+        leaf_len: usize,
+        encoding: LeafEncoding,
+    ) -> IcicleResult<Self> {
+        if leaf_len == 0 || leaves.len() % leaf_len != 0 || !(leaves.len() / leaf_len).is_power_of_two() {
+            return Err(IcicleError::invalid_argument(
+                "Keccak Merkle tree needs a power of two number of leaves of leaf_len elements",
+            ));
+        }
+
+        let number_of_leaves = leaves.len() / leaf_len;
+        let mut host_leaves = vec![F::zero(); leaves.len()];
+        if leaves.is_on_device() {
+            if encoding != LeafEncoding::LittleEndian {
+                return Err(IcicleError::invalid_argument(
+                    "Keccak Merkle trees of device leaves only support the little endian leaf encoding",
+                ));
+            }
+            let device_leaves =
+                unsafe { DeviceSlice::from_slice(std::slice::from_raw_parts(leaves.as_ptr(), leaves.len())) };
+            device_leaves
+                .copy_to_host(HostSlice::from_mut_slice(&mut host_leaves))
+                .map_err(IcicleError::from_cuda_error)?;
+        } else {
+            host_leaves.copy_from_slice(unsafe { std::slice::from_raw_parts(leaves.as_ptr(), leaves.len()) });
+        }
+
+        // The device absorbs full blocks of the rate as 8-byte words read in place, which are misaligned when a leaf
+        // spans a full block and its size isn't a multiple of 8
+        let leaf_bytes = leaf_len * std::mem::size_of::<F>();
+        let leaf_digests = if leaves.is_on_device() && (leaf_bytes < KECCAK256_RATE || leaf_bytes % 8 == 0) {
+            let device_bytes = unsafe {
+                DeviceSlice::from_slice(std::slice::from_raw_parts(
+                    leaves.as_ptr() as *const u8,
+                    leaves.len() * std::mem::size_of::<F>(),
+                ))
+            };
+            let mut digests = vec![0u8; number_of_leaves * DIGEST_LEN];
+            keccak256(
+                device_bytes,
+                leaf_bytes as u32,
+                number_of_leaves as u32,
+                HostSlice::from_mut_slice(&mut digests),
+                &HashConfig::default(),
+            )?;
+            digests
+                .chunks(DIGEST_LEN)
+                .map(|digest| {
+                    digest
+                        .try_into()
+                        .unwrap()
+                })
+                .collect()
+        } else {
+            host_leaves
+                .chunks(leaf_len)
+                .map(|leaf| Keccak256Hasher::digest(&encoding.encode(leaf)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut rows = vec![leaf_digests];
+        while rows[rows.len() - 1].len() > 1 {
+            let row = rows[rows.len() - 1]
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+            rows.push(row);
+        }
+
+        Ok(Self {
+            rows,
+            leaves: host_leaves,
+            leaf_len,
+            encoding,
+        })
+    }
+
+    pub fn root(&self) -> KeccakDigest {
+        self.rows[self
+            .rows
+            .len()
+            - 1][0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows
+            .len()
+            - 1
+    }
+
+    pub fn number_of_leaves(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    pub fn encoding(&self) -> LeafEncoding {
+        self.encoding
+    }
+
+    /// The hashes of one row of the tree, row 0 being the hashes of the leaves and row [height](Self::height) the
+    /// root.
+    pub fn row(&self, depth_from_leaves: usize) -> &[KeccakDigest] {
+        &self.rows[depth_from_leaves]
+    }
+
+    pub fn leaf(&self, index: usize) -> &[F] {
+        &self.leaves[index * self.leaf_len..(index + 1) * self.leaf_len]
+    }
+
+    pub fn open(&self, index: usize) -> IcicleResult<KeccakMerkleProof<F>> {
+        if index >= self.number_of_leaves() {
+            return Err(IcicleError::invalid_argument("Opened leaf index is out of the tree"));
+        }
+
+        let siblings = self.rows[..self.height()]
+            .iter()
+            .enumerate()
+            .map(|(depth, row)| row[(index >> depth) ^ 1])
+            .collect();
+        Ok(KeccakMerkleProof {
+            index,
+            leaf: self
+                .leaf(index)
+                .to_vec(),
+            siblings,
+        })
+    }
+
+    /// Checks an opening against the root of this tree.
+    pub fn verify(&self, proof: &KeccakMerkleProof<F>) -> bool {
+        proof
+            .leaf
+            .len()
+            == self.leaf_len
+            && proof
+                .siblings
+                .len()
+                == self.height()
+            && proof.verify(&self.root(), self.encoding)
+    }
+}
+
+impl<F: FieldImpl> KeccakMerkleProof<F> {
+    /// Recomputes the root from the leaf and its path, as an on-chain verifier does.
+    pub fn compute_root(&self, encoding: LeafEncoding) -> KeccakDigest {
+        let mut digest = Keccak256Hasher::digest(&encoding.encode(&self.leaf));
+        for (depth, sibling) in self
+            .siblings
+            .iter()
+            .enumerate()
+        {
+            let bit = self
+                .index
+                .checked_shr(depth as u32)
+                .unwrap_or(0)
+                & 1;
+            digest = if bit == 0 {
+                hash_pair(&digest, sibling)
+            } else {
+                hash_pair(sibling, &digest)
+            };
+        }
+        digest
+    }
+
+    pub fn verify(&self, root: &KeccakDigest, encoding: LeafEncoding) -> bool {
+        let path_len = self
+            .siblings
+            .len() as u32;
+        self.index
+            .checked_shr(path_len)
+            .unwrap_or(0)
+            == 0
+            && self.compute_root(encoding) == *root
+    }
+}
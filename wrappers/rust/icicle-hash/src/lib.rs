@@ -1 +1,6 @@
+pub(crate) mod batch;
+pub mod blake2s;
+pub mod blake3;
 pub mod keccak;
+pub mod sha256;
+pub mod transcript;
@@ -0,0 +1,183 @@
+use icicle_core::{error::IcicleResult, hash::HashConfig, hash_to_curve::XmdHash, tree::TreeBuilderConfig};
+use icicle_cuda_runtime::memory::HostOrDeviceSlice;
+
+use crate::batch;
+
+pub mod tests;
+
+const DIGEST_LEN: usize = 32;
+const BLOCK_LEN: usize = 64;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (i, word) in block
+        .chunks(4)
+        .enumerate()
+    {
+        w[i] = u32::from_be_bytes(
+            word.try_into()
+                .unwrap(),
+        );
+    }
+    for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let t1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(maj);
+        (h, g, f, e, d, c, b, a) = (g, f, e, d.wrapping_add(t1), c, b, a, t1.wrapping_add(t2));
+    }
+    for (s, v) in state
+        .iter_mut()
+        .zip([a, b, c, d, e, f, g, h])
+    {
+        *s = s.wrapping_add(v);
+    }
+}
+
+/// Streaming SHA-256.
+#[derive(Debug, Clone)]
+pub struct Sha256Hasher {
+    state: [u32; 8],
+    buffer: [u8; BLOCK_LEN],
+    buffer_len: usize,
+    // Message length in bytes
+    length: u64,
+}
+
+impl Sha256Hasher {
+    pub fn new() -> Self {
+        Self {
+            state: IV,
+            buffer: [0; BLOCK_LEN],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.length += data.len() as u64;
+        for byte in data {
+            self.buffer[self.buffer_len] = *byte;
+            self.buffer_len += 1;
+            if self.buffer_len == BLOCK_LEN {
+                compress(&mut self.state, &self.buffer);
+                self.buffer_len = 0;
+            }
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; DIGEST_LEN] {
+        let bit_length = self.length * 8;
+        // A one bit, zeros and the bit length on the last 8 bytes of a block
+        self.update(&[0x80]);
+        while self.buffer_len != BLOCK_LEN - 8 {
+            self.update(&[0]);
+        }
+        self.update(&bit_length.to_be_bytes());
+
+        let mut digest = [0u8; DIGEST_LEN];
+        for (bytes, word) in digest
+            .chunks_mut(4)
+            .zip(self.state)
+        {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// Hashes a whole message at once.
+    pub fn digest(data: &[u8]) -> [u8; DIGEST_LEN] {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+impl Default for Sha256Hasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SHA-256 as the hash of `expand_message_xmd`, for the `XMD:SHA-256` hash to curve suites.
+impl XmdHash for Sha256Hasher {
+    const OUTPUT_LEN: usize = DIGEST_LEN;
+    const BLOCK_LEN: usize = BLOCK_LEN;
+
+    fn hash(data: &[u8]) -> Vec<u8> {
+        Self::digest(data).to_vec()
+    }
+}
+
+/// Hashes `number_of_blocks` blocks of `input_block_size` bytes into 32-byte digests.
+///
+/// SHA-256 has no device kernels yet: the hashes are computed on the host and device memory is rejected. The config
+/// is ignored.
+pub fn sha256(
+    input: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    input_block_size: u32,
+    number_of_blocks: u32,
+    output: &mut (impl HostOrDeviceSlice<u8> + ?Sized),
+    _config: &HashConfig,
+) -> IcicleResult<()> {
+    batch::hash_blocks(
+        input,
+        input_block_size,
+        number_of_blocks,
+        output,
+        DIGEST_LEN,
+        |block, digest| digest.copy_from_slice(&Sha256Hasher::digest(block)),
+    )
+}
+
+/// Builds a SHA-256 Merkle tree on the host, each node hashing the digests of its children.
+///
+/// A digest is made of `config.digest_elements()` 64-bit words of the hash, set it to 4 to keep whole hashes.
+pub fn build_sha256_merkle_tree(
+    leaves: &(impl HostOrDeviceSlice<u8> + ?Sized),
+    digests: &mut (impl HostOrDeviceSlice<u64> + ?Sized),
+    height: usize,
+    input_block_len: usize,
+    config: &TreeBuilderConfig,
+) -> IcicleResult<()> {
+    batch::build_merkle_tree(
+        leaves,
+        digests,
+        height,
+        input_block_len,
+        config,
+        DIGEST_LEN,
+        |input, digest| digest.copy_from_slice(&Sha256Hasher::digest(input)),
+    )
+}
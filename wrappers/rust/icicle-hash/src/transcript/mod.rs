@@ -0,0 +1,98 @@
+use icicle_core::{
+    error::IcicleResult,
+    traits::FieldArithmetic,
+    transcript::{reduce_bytes_le, Transcript},
+};
+
+use crate::keccak::host::Keccak256Hasher;
+
+pub mod tests;
+
+const DIGEST_LEN: usize = 32;
+
+/// Byte-oriented Fiat-Shamir transcript over Keccak-256.
+///
+/// The state is a single digest. Appending a message hashes the state with the length-prefixed label and message,
+/// drawing a challenge hashes the state with the label and expands the new state with a block counter. Scalars are
+/// drawn from twice as many bytes as a digest and reduced modulo the field order.
+#[derive(Debug, Clone)]
+pub struct KeccakTranscript {
+    state: [u8; DIGEST_LEN],
+}
+
+impl KeccakTranscript {
+    pub fn new(domain_label: &[u8]) -> IcicleResult<Self> {
+        let mut preimage = Vec::new();
+        encode(&mut preimage, domain_label);
+        Ok(Self {
+            state: hash(&preimage)?,
+        })
+    }
+
+    /// The current digest, to cross-check the transcript with other implementations.
+    pub fn state(&self) -> &[u8; DIGEST_LEN] {
+        &self.state
+    }
+
+    /// Same as [Transcript::append_bytes], without having to name a field.
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) -> IcicleResult<()> {
+        self.update(label, Some(message))
+    }
+
+    pub fn challenge_bytes(&mut self, label: &[u8], output: &mut [u8]) -> IcicleResult<()> {
+        self.update(label, None)?;
+        for (counter, chunk) in output
+            .chunks_mut(DIGEST_LEN)
+            .enumerate()
+        {
+            let mut preimage = self
+                .state
+                .to_vec();
+            preimage.extend((counter as u32).to_le_bytes());
+            chunk.copy_from_slice(&hash(&preimage)?[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, label: &[u8], message: Option<&[u8]>) -> IcicleResult<()> {
+        let mut preimage = self
+            .state
+            .to_vec();
+        encode(&mut preimage, label);
+        if let Some(message) = message {
+            encode(&mut preimage, message);
+        }
+        self.state = hash(&preimage)?;
+        Ok(())
+    }
+}
+
+impl<F: FieldArithmetic> Transcript<F> for KeccakTranscript {
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) -> IcicleResult<()> {
+        self.append_message(label, bytes)
+    }
+
+    fn append_scalar(&mut self, label: &[u8], scalar: &F) -> IcicleResult<()> {
+        self.update(label, Some(&scalar.to_bytes_le()))
+    }
+
+    fn challenge_scalars(&mut self, label: &[u8], len: usize) -> IcicleResult<Vec<F>> {
+        let mut bytes = vec![0u8; 2 * DIGEST_LEN * len];
+        self.challenge_bytes(label, &mut bytes)?;
+        Ok(bytes
+            .chunks(2 * DIGEST_LEN)
+            .map(reduce_bytes_le)
+            .collect())
+    }
+}
+
+// Length as 8 little-endian bytes, then the bytes
+fn encode(preimage: &mut Vec<u8>, bytes: &[u8]) {
+    preimage.extend((bytes.len() as u64).to_le_bytes());
+    preimage.extend_from_slice(bytes);
+}
+
+// Hashed on the host, so that verifiers don't need a device
+fn hash(preimage: &[u8]) -> IcicleResult<[u8; DIGEST_LEN]> {
+    Ok(Keccak256Hasher::digest(preimage))
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+pub(crate) mod tests {
+    use icicle_core::traits::FieldImpl;
+    use icicle_core::transcript::Transcript;
+    use icicle_m31::field::{ExtensionField, ScalarField};
+
+    use crate::transcript::KeccakTranscript;
+
+    fn hex_digest(digest: &[u8]) -> String {
+        digest
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    #[test]
+    fn keccak_transcript_vectors() {
+        let mut transcript = KeccakTranscript::new(b"icicle-keccak").unwrap();
+        assert_eq!(
+            hex_digest(transcript.state()),
+            "2dd8e381b649409d89baab84ccc5a36d4e8b7281269c3464f9a8ae9629924d7f"
+        );
+
+        transcript
+            .append_message(b"commitment", &[0xab; 32])
+            .unwrap();
+        transcript
+            .append_scalar(b"evaluation", &ScalarField::from_u32(1234567))
+            .unwrap();
+        let alpha: ScalarField = transcript
+            .challenge_scalar(b"alpha")
+            .unwrap();
+        let beta = Transcript::<ScalarField>::challenge_ext::<ExtensionField>(&mut transcript, b"beta").unwrap();
+
+        assert_eq!(alpha, ScalarField::from_hex("0x305ec52c"));
+        assert_eq!(beta, ExtensionField::from_hex("0x0b6b2b5f42b27278006315851202a573"));
+        assert_eq!(
+            hex_digest(transcript.state()),
+            "f16e01bdb10bf7952011a9200efbb0aba6ded8f8e413e1f46709eabd601fe8d6"
+        );
+    }
+
+    #[test]
+    fn keccak_transcript_labels() {
+        let challenge = |label: &[u8], message: &[u8]| -> ScalarField {
+            let mut transcript = KeccakTranscript::new(b"icicle-keccak").unwrap();
+            transcript
+                .append_message(label, message)
+                .unwrap();
+            transcript
+                .challenge_scalar(b"challenge")
+                .unwrap()
+        };
+
+        assert_eq!(challenge(b"message", b"hello"), challenge(b"message", b"hello"));
+        assert_ne!(challenge(b"message", b"hello"), challenge(b"messag", b"ehello"));
+        assert_ne!(challenge(b"message", b"hello"), challenge(b"message", b"hello\0"));
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::{Read, Write};
+
+use ark_ec::{models::CurveConfig, short_weierstrass::Affine as ArkAffine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use icicle_core::{
+    curve::{Affine, Curve},
+    error::{IcicleError, IcicleErrorCode, IcicleResult},
+    traits::ArkConvertible,
+};
+
+/// Reads powers of tau serialized by arkworks as a `Vec` of affine points, such as the `powers_of_g` of an
+/// `ark-poly-commit` KZG setup.
+///
+/// With `validate` set, arkworks checks that every point is on the curve and in the prime order subgroup.
+pub fn read_ark_powers<C: Curve>(reader: impl Read, compressed: bool, validate: bool) -> IcicleResult<Vec<Affine<C>>>
+where
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as CurveConfig>::BaseField>,
+{
+    let compress = if compressed { Compress::Yes } else { Compress::No };
+    let validate = if validate { Validate::Yes } else { Validate::No };
+    let points = Vec::<ArkAffine<C::ArkSWConfig>>::deserialize_with_mode(reader, compress, validate)
+        .map_err(|_| IcicleError::invalid_argument("Malformed arkworks SRS"))?;
+    Ok(points
+        .into_iter()
+        .map(Affine::from_ark)
+        .collect())
+}
+
+/// Writes powers of tau in the layout [read_ark_powers] reads.
+pub fn write_ark_powers<C: Curve>(writer: impl Write, points: &[Affine<C>], compressed: bool) -> IcicleResult<()>
+where
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as CurveConfig>::BaseField>,
+{
+    let compress = if compressed { Compress::Yes } else { Compress::No };
+    points
+        .iter()
+        .map(|point| point.to_ark())
+        .collect::<Vec<_>>()
+        .serialize_with_mode(writer, compress)
+        .map_err(|_| IcicleError::from_code_and_reason(IcicleErrorCode::UndefinedError, "Failed to write arkworks SRS"))
+}
@@ -0,0 +1,159 @@
+use icicle_core::{
+    curve::{Affine, Curve, Projective},
+    error::{IcicleError, IcicleResult},
+    msm::{msm, MSMConfig, MSM},
+    polynomials::BivariatePolynomial,
+    traits::{FieldArithmetic, FieldImpl},
+};
+use icicle_cuda_runtime::memory::HostSlice;
+
+use crate::prover::linear_combination;
+
+fn check_sizes(x_size: usize, y_size: usize) -> IcicleResult<()> {
+    if x_size == 0 || y_size == 0 {
+        return Err(IcicleError::invalid_argument(
+            "Bivariate SRS needs at least one power of tau in each variable",
+        ));
+    }
+    Ok(())
+}
+
+/// Prover part of a bivariate KZG structured reference string: the points `[τ_x^i τ_y^j]G` for `i < x_size` and
+/// `j < y_size`, laid out like the coefficients of a [BivariatePolynomial], at `j * x_size + i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariateSrs<C: Curve> {
+    powers_of_tau: Vec<Affine<C>>,
+    x_size: usize,
+    y_size: usize,
+}
+
+impl<C: Curve> BivariateSrs<C> {
+    pub fn from_powers_of_tau(powers_of_tau: Vec<Affine<C>>, x_size: usize, y_size: usize) -> IcicleResult<Self> {
+        check_sizes(x_size, y_size)?;
+        if powers_of_tau.len() != x_size * y_size {
+            return Err(IcicleError::invalid_argument(
+                "Bivariate SRS needs x_size * y_size powers of tau",
+            ));
+        }
+        Ok(Self {
+            powers_of_tau,
+            x_size,
+            y_size,
+        })
+    }
+
+    /// Computes the powers of `tau_x` and `tau_y` times `generator` with a single batched MSM. Only meant for tests
+    /// and benchmarks, see [Srs::insecure_setup](crate::srs::Srs::insecure_setup).
+    pub fn insecure_setup(
+        generator: &Affine<C>,
+        tau_x: &C::ScalarField,
+        tau_y: &C::ScalarField,
+        x_size: usize,
+        y_size: usize,
+    ) -> IcicleResult<Self>
+    where
+        C: MSM<C>,
+        C::ScalarField: FieldArithmetic,
+    {
+        check_sizes(x_size, y_size)?;
+        let mut scalars = Vec::with_capacity(x_size * y_size);
+        let mut y_power = C::ScalarField::one();
+        for _ in 0..y_size {
+            let mut power = y_power;
+            for _ in 0..x_size {
+                scalars.push(power);
+                power = power * *tau_x;
+            }
+            y_power = y_power * *tau_y;
+        }
+        let mut points = vec![Projective::<C>::zero(); scalars.len()];
+        msm(
+            HostSlice::from_slice(&scalars),
+            HostSlice::from_slice(&[*generator]),
+            &MSMConfig::default(),
+            HostSlice::from_mut_slice(&mut points),
+        )?;
+        Self::from_powers_of_tau(
+            points
+                .into_iter()
+                .map(Affine::from)
+                .collect(),
+            x_size,
+            y_size,
+        )
+    }
+
+    pub fn powers_of_tau(&self) -> &[Affine<C>] {
+        &self.powers_of_tau
+    }
+
+    pub fn generator(&self) -> Affine<C> {
+        self.powers_of_tau[0]
+    }
+
+    pub fn x_size(&self) -> usize {
+        self.x_size
+    }
+
+    pub fn y_size(&self) -> usize {
+        self.y_size
+    }
+
+    // The powers of tau_y alone, for polynomials in Y
+    fn y_powers_of_tau(&self) -> Vec<Affine<C>> {
+        self.powers_of_tau
+            .iter()
+            .step_by(self.x_size)
+            .copied()
+            .collect()
+    }
+}
+
+/// Evaluation of a committed bivariate polynomial at `(x, y)`.
+///
+/// With `P(X, Y) - value = (X - x) Q_x(X, Y) + (Y - y) Q_y(Y)`, the proofs are the commitments to `Q_x` and `Q_y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariateKzgOpening<C: Curve> {
+    pub x: C::ScalarField,
+    pub y: C::ScalarField,
+    pub value: C::ScalarField,
+    pub proof_x: Affine<C>,
+    pub proof_y: Affine<C>,
+}
+
+/// Commits to a bivariate polynomial with the same number of coefficients in `X` as the SRS and at most as many in
+/// `Y`, in one MSM over its coefficients.
+pub fn commit_bivariate<C: Curve + MSM<C>>(
+    srs: &BivariateSrs<C>,
+    poly: &BivariatePolynomial<C::ScalarField>,
+) -> IcicleResult<Affine<C>> {
+    if poly.x_size() != srs.x_size || poly.y_size() > srs.y_size {
+        return Err(IcicleError::invalid_argument(
+            "Bivariate polynomial sizes don't fit the SRS",
+        ));
+    }
+    linear_combination(HostSlice::from_slice(poly.coeffs()), &srs.powers_of_tau)
+}
+
+/// Opens a bivariate polynomial at `(x, y)`, dividing by `X - x` and then the remainder in `Y` by `Y - y`.
+pub fn open_bivariate<C>(
+    srs: &BivariateSrs<C>,
+    poly: &BivariatePolynomial<C::ScalarField>,
+    x: &C::ScalarField,
+    y: &C::ScalarField,
+) -> IcicleResult<BivariateKzgOpening<C>>
+where
+    C: Curve + MSM<C>,
+    C::ScalarField: FieldArithmetic,
+{
+    let (quotient_x, remainder) = poly.divide_by_x(x);
+    // The remainder P(x, Y) is a polynomial in Y alone
+    let (quotient_y, value) = BivariatePolynomial::from_coeffs(remainder, 1, poly.y_size())?.divide_by_y(y);
+    Ok(BivariateKzgOpening {
+        x: *x,
+        y: *y,
+        value: value[0],
+        proof_x: commit_bivariate(srs, &quotient_x)?,
+        proof_y: linear_combination(HostSlice::from_slice(quotient_y.coeffs()), &srs.y_powers_of_tau())?,
+    })
+}
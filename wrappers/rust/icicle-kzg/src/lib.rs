@@ -0,0 +1,11 @@
+#[cfg(feature = "arkworks")]
+pub mod ark_srs;
+pub mod bivariate;
+pub mod prover;
+pub mod ptau;
+pub mod srs;
+#[cfg(feature = "arkworks")]
+pub mod verifier;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,203 @@
+use icicle_core::{
+    curve::{Affine, Curve, Projective},
+    error::{IcicleError, IcicleResult},
+    msm::{msm, MSMConfig, MSM},
+    ntt::{ntt, NTTConfig, NTTDir, NTT},
+    polynomials::UnivariatePolynomial,
+    traits::{FieldArithmetic, FieldImpl},
+    SNARKCurve,
+};
+use icicle_cuda_runtime::memory::{HostOrDeviceSlice, HostSlice};
+
+use crate::srs::Srs;
+
+/// Evaluation of a committed polynomial at one point, with the commitment to its quotient by `x - point`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KzgOpening<C: Curve> {
+    pub point: C::ScalarField,
+    pub value: C::ScalarField,
+    pub proof: Affine<C>,
+}
+
+/// Evaluations of several committed polynomials at the same point, proven by a single opening of their random linear
+/// combination.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KzgBatchOpening<C: Curve> {
+    pub point: C::ScalarField,
+    /// One value per polynomial, in the order they were opened.
+    pub values: Vec<C::ScalarField>,
+    pub proof: Affine<C>,
+}
+
+// Coefficients up to the degree, empty for the zero polynomial
+fn coefficients<P: UnivariatePolynomial>(poly: &P) -> Vec<P::Field> {
+    let degree = poly.degree();
+    if degree < 0 {
+        return Vec::new();
+    }
+    let mut coeffs = vec![P::Field::zero(); degree as usize + 1];
+    poly.copy_coeffs(0, HostSlice::from_mut_slice(&mut coeffs));
+    coeffs
+}
+
+// `Σ s_i P_i` for the first points, the point at infinity for no scalars
+pub(crate) fn linear_combination<C: Curve + MSM<C>>(
+    scalars: &(impl HostOrDeviceSlice<C::ScalarField> + ?Sized),
+    points: &[Affine<C>],
+) -> IcicleResult<Affine<C>> {
+    if scalars.is_empty() {
+        return Ok(Affine::zero());
+    }
+    let mut result = [Projective::<C>::zero()];
+    msm(
+        scalars,
+        HostSlice::from_slice(&points[..scalars.len()]),
+        &MSMConfig::default(),
+        HostSlice::from_mut_slice(&mut result),
+    )?;
+    Ok(result[0].into())
+}
+
+/// Commits to the polynomial of the given coefficients, lowest degree first: `Σ c_i [τ^i]G` in one MSM.
+pub fn commit_coefficients<C: Curve + MSM<C>>(
+    srs: &Srs<C>,
+    coeffs: &(impl HostOrDeviceSlice<C::ScalarField> + ?Sized),
+) -> IcicleResult<Affine<C>> {
+    if coeffs.len()
+        > srs
+            .powers_of_tau()
+            .len()
+    {
+        return Err(IcicleError::invalid_argument(
+            "Polynomial degree is larger than the SRS supports",
+        ));
+    }
+    linear_combination(coeffs, srs.powers_of_tau())
+}
+
+/// Commits to a polynomial of degree at most [max_degree](Srs::max_degree).
+pub fn commit<C, P>(srs: &Srs<C>, poly: &P) -> IcicleResult<Affine<C>>
+where
+    C: Curve + MSM<C>,
+    P: UnivariatePolynomial<Field = C::ScalarField>,
+{
+    commit_coefficients(srs, HostSlice::from_slice(&coefficients(poly)))
+}
+
+/// Commits to the polynomial taking the values `evals` on the roots of unity of order `evals.len()`, a power of two.
+///
+/// The evaluations are interpolated with an inverse NTT, so the NTT domain of the scalar field must be initialized
+/// for that size.
+pub fn commit_lagrange<C>(
+    srs: &Srs<C>,
+    evals: &(impl HostOrDeviceSlice<C::ScalarField> + ?Sized),
+) -> IcicleResult<Affine<C>>
+where
+    C: SNARKCurve,
+    <C::ScalarField as FieldImpl>::Config: NTT<C::ScalarField, C::ScalarField>,
+{
+    if !evals
+        .len()
+        .is_power_of_two()
+    {
+        return Err(IcicleError::invalid_argument(
+            "Lagrange commitments need a power of two number of evaluations",
+        ));
+    }
+    let mut coeffs = vec![C::ScalarField::zero(); evals.len()];
+    ntt(
+        evals,
+        NTTDir::kInverse,
+        &NTTConfig::<C::ScalarField>::default(),
+        HostSlice::from_mut_slice(&mut coeffs),
+    )?;
+    commit_coefficients(srs, HostSlice::from_slice(&coeffs))
+}
+
+/// Opens a polynomial at `point`.
+///
+/// The proof commits to the quotient of the polynomial by `x - point`, whose remainder is the value at `point`.
+pub fn open<C, P>(srs: &Srs<C>, poly: &P, point: &C::ScalarField) -> IcicleResult<KzgOpening<C>>
+where
+    C: Curve + MSM<C>,
+    C::ScalarField: FieldArithmetic,
+    P: UnivariatePolynomial<Field = C::ScalarField>,
+{
+    let divisor = P::from_coeffs(HostSlice::from_slice(&[-*point, C::ScalarField::one()]), 2);
+    let (quotient, _) = poly.divide(&divisor);
+    Ok(KzgOpening {
+        point: *point,
+        value: poly.eval(point),
+        proof: commit(srs, &quotient)?,
+    })
+}
+
+/// Opens several polynomials at the same `point` with one proof, for the combination `Σ challenge^i p_i`.
+///
+/// The challenge must be drawn after the commitments are fixed, for instance from a transcript they were absorbed in.
+pub fn batch_open<C, P>(
+    srs: &Srs<C>,
+    polys: &[P],
+    point: &C::ScalarField,
+    challenge: &C::ScalarField,
+) -> IcicleResult<KzgBatchOpening<C>>
+where
+    C: Curve + MSM<C>,
+    C::ScalarField: FieldArithmetic,
+    P: UnivariatePolynomial<Field = C::ScalarField>,
+{
+    if polys.is_empty() {
+        return Err(IcicleError::invalid_argument(
+            "Batch opening needs at least one polynomial",
+        ));
+    }
+
+    let mut combined = Vec::new();
+    let mut scale = C::ScalarField::one();
+    for poly in polys {
+        let coeffs = coefficients(poly);
+        if combined.len() < coeffs.len() {
+            combined.resize(coeffs.len(), C::ScalarField::zero());
+        }
+        for (sum, coeff) in combined
+            .iter_mut()
+            .zip(coeffs)
+        {
+            *sum = *sum + scale * coeff;
+        }
+        scale = scale * *challenge;
+    }
+    if combined.is_empty() {
+        combined.push(C::ScalarField::zero());
+    }
+
+    let combination = P::from_coeffs(HostSlice::from_slice(&combined), combined.len());
+    let opening = open(srs, &combination, point)?;
+    Ok(KzgBatchOpening {
+        point: *point,
+        values: polys
+            .iter()
+            .map(|poly| poly.eval(point))
+            .collect(),
+        proof: opening.proof,
+    })
+}
+
+/// Proves that a polynomial vanishes on the roots of unity of order `vanishing_degree`, by committing to its quotient
+/// by `x^vanishing_degree - 1`.
+///
+/// The quotient is computed on a coset with [div_by_vanishing](UnivariatePolynomial::div_by_vanishing), which assumes
+/// the division is exact: for a polynomial that doesn't vanish on the domain the proof is meaningless and won't
+/// verify.
+pub fn prove_vanishing<C, P>(srs: &Srs<C>, poly: &P, vanishing_degree: u64) -> IcicleResult<Affine<C>>
+where
+    C: Curve + MSM<C>,
+    P: UnivariatePolynomial<Field = C::ScalarField>,
+{
+    if vanishing_degree == 0 {
+        return Err(IcicleError::invalid_argument(
+            "Vanishing polynomial degree must be positive",
+        ));
+    }
+    commit(srs, &poly.div_by_vanishing(vanishing_degree))
+}
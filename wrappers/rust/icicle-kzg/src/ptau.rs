@@ -0,0 +1,479 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+#[cfg(feature = "arkworks")]
+use ark_ec::{models::CurveConfig, short_weierstrass::Affine as ArkAffine};
+#[cfg(feature = "arkworks")]
+use icicle_core::traits::ArkConvertible;
+use icicle_core::{
+    curve::{Affine, Curve},
+    error::{IcicleError, IcicleErrorCode, IcicleResult},
+    traits::{FieldArithmetic, FieldImpl},
+};
+
+const MAGIC: &[u8; 4] = b"ptau";
+const VERSION: u32 = 1;
+// Points are read and written this many at a time, so that huge sections don't need a second copy in memory
+const CHUNK_POINTS: usize = 1 << 16;
+
+/// Sections of a snarkjs `.ptau` file, as used by the Perpetual Powers of Tau ceremony.
+///
+/// A file of power `p` holds `2^(p+1) - 1` points in [TauG1](Self::TauG1) and `2^p` in the other powers sections.
+/// The Lagrange sections of a file prepared for phase 2 hold the bases for every domain size `2^0` to `2^p`, one
+/// after the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PtauSection {
+    Header = 1,
+    TauG1 = 2,
+    TauG2 = 3,
+    AlphaTauG1 = 4,
+    BetaTauG1 = 5,
+    BetaG2 = 6,
+    Contributions = 7,
+    LagrangeTauG1 = 12,
+    LagrangeTauG2 = 13,
+    LagrangeAlphaTauG1 = 14,
+    LagrangeBetaTauG1 = 15,
+}
+
+fn io_error<T>(result: std::io::Result<T>) -> IcicleResult<T> {
+    result.map_err(|_| IcicleError::from_code_and_reason(IcicleErrorCode::UndefinedError, "Ptau file I/O failed"))
+}
+
+fn invalid_file() -> IcicleError {
+    IcicleError::invalid_argument("Malformed ptau file")
+}
+
+fn read_u32(reader: &mut impl Read) -> IcicleResult<u32> {
+    let mut bytes = [0u8; 4];
+    io_error(reader.read_exact(&mut bytes))?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> IcicleResult<u64> {
+    let mut bytes = [0u8; 8];
+    io_error(reader.read_exact(&mut bytes))?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+// Number of `n8` byte prime field elements in a coordinate of the points of `C`
+fn coordinate_elements<C: Curve>(n8: usize) -> IcicleResult<usize> {
+    let coordinate_len = C::BaseField::zero()
+        .to_bytes_le()
+        .len();
+    if n8 == 0 || coordinate_len % n8 != 0 {
+        return Err(IcicleError::invalid_argument("Ptau field size doesn't match the curve"));
+    }
+    Ok(coordinate_len / n8)
+}
+
+// Checks that the modulus of the file is the one of `Fq`
+fn check_modulus<Fq: FieldArithmetic>(modulus: &[u8]) -> IcicleResult<()> {
+    let mut expected = Fq::modulus_bytes_le();
+    expected.resize(modulus.len(), 0);
+    if expected != modulus {
+        return Err(IcicleError::invalid_argument("Ptau file is for another curve"));
+    }
+    Ok(())
+}
+
+// Converts each `n8` byte element of a point between Montgomery and normal form
+fn convert_elements<Fq: FieldArithmetic>(bytes: &mut [u8], n8: usize, into_montgomery: bool) {
+    let element_len = Fq::zero()
+        .to_bytes_le()
+        .len();
+    for element in bytes.chunks_mut(n8) {
+        let value = Fq::from_bytes_le(&element[..element_len.min(n8)]);
+        let converted = if into_montgomery {
+            value.to_montgomery()
+        } else {
+            value.from_montgomery()
+        };
+        element[..element_len.min(n8)].copy_from_slice(&converted.to_bytes_le()[..element_len.min(n8)]);
+    }
+}
+
+/// Reads the sections of a `.ptau` file.
+///
+/// The reader only keeps the section table, points are read on demand into buffers of the caller, so a file with
+/// `2^28` points can be streamed to the device in chunks. A memory-mapped file can be read through a
+/// [Cursor](std::io::Cursor) over the mapped bytes.
+///
+/// Points are stored as little-endian affine coordinates in Montgomery form, the point at infinity being all zeros.
+/// They are converted to the normal form of the host types, or kept as they are with the `_montgomery` methods to
+/// be passed to `msm` with `are_points_montgomery_form` set.
+#[derive(Debug)]
+pub struct PtauReader<R> {
+    reader: R,
+    n8: usize,
+    modulus: Vec<u8>,
+    power: u32,
+    ceremony_power: u32,
+    // Offset and length in bytes of each section's data
+    sections: BTreeMap<u32, (u64, u64)>,
+}
+
+impl<R: Read + Seek> PtauReader<R> {
+    pub fn new(mut reader: R) -> IcicleResult<Self> {
+        let mut magic = [0u8; 4];
+        io_error(reader.read_exact(&mut magic))?;
+        if &magic != MAGIC || read_u32(&mut reader)? != VERSION {
+            return Err(invalid_file());
+        }
+
+        let number_of_sections = read_u32(&mut reader)?;
+        let mut sections = BTreeMap::new();
+        for _ in 0..number_of_sections {
+            let section = read_u32(&mut reader)?;
+            let len = read_u64(&mut reader)?;
+            let offset = io_error(reader.stream_position())?;
+            sections.insert(section, (offset, len));
+            io_error(reader.seek(SeekFrom::Current(len as i64)))?;
+        }
+
+        let &(offset, len) = sections
+            .get(&(PtauSection::Header as u32))
+            .ok_or_else(invalid_file)?;
+        io_error(reader.seek(SeekFrom::Start(offset)))?;
+        let n8 = read_u32(&mut reader)? as usize;
+        if len != n8 as u64 + 12 {
+            return Err(invalid_file());
+        }
+        let mut modulus = vec![0u8; n8];
+        io_error(reader.read_exact(&mut modulus))?;
+        let power = read_u32(&mut reader)?;
+        let ceremony_power = read_u32(&mut reader)?;
+
+        Ok(Self {
+            reader,
+            n8,
+            modulus,
+            power,
+            ceremony_power,
+            sections,
+        })
+    }
+
+    /// The file holds the powers for domains of size up to `2^power`.
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// The power of the ceremony the file was taken from, at least [power](Self::power).
+    pub fn ceremony_power(&self) -> u32 {
+        self.ceremony_power
+    }
+
+    /// Size in bytes of the base field elements.
+    pub fn n8(&self) -> usize {
+        self.n8
+    }
+
+    /// The base field modulus, little-endian on [n8](Self::n8) bytes.
+    pub fn modulus(&self) -> &[u8] {
+        &self.modulus
+    }
+
+    pub fn has_section(&self, section: PtauSection) -> bool {
+        self.sections
+            .contains_key(&(section as u32))
+    }
+
+    /// Number of points of `C` in a section.
+    pub fn number_of_points<C: Curve>(&self, section: PtauSection) -> IcicleResult<usize> {
+        let point_len = 2 * self.n8 * coordinate_elements::<C>(self.n8)?;
+        let &(_, len) = self
+            .sections
+            .get(&(section as u32))
+            .ok_or_else(invalid_file)?;
+        if len % point_len as u64 != 0 {
+            return Err(invalid_file());
+        }
+        Ok((len / point_len as u64) as usize)
+    }
+
+    /// Reads `points.len()` points from index `start` of a section, in normal form. `Fq` is the prime field of the
+    /// coordinates, the base field of `C` for G1 and the field it extends for G2.
+    pub fn read_points<C: Curve, Fq: FieldArithmetic>(
+        &mut self,
+        section: PtauSection,
+        start: usize,
+        points: &mut [Affine<C>],
+    ) -> IcicleResult<()> {
+        check_modulus::<Fq>(&self.modulus)?;
+        self.read(section, start, points, |bytes, n8| {
+            convert_elements::<Fq>(bytes, n8, false)
+        })
+    }
+
+    /// Reads points like [read_points](Self::read_points) but leaves them in Montgomery form.
+    pub fn read_points_montgomery<C: Curve>(
+        &mut self,
+        section: PtauSection,
+        start: usize,
+        points: &mut [Affine<C>],
+    ) -> IcicleResult<()> {
+        self.read(section, start, points, |_, _| {})
+    }
+
+    /// Reads a whole section of G1 points in normal form.
+    pub fn read_g1_section<C: Curve>(&mut self, section: PtauSection) -> IcicleResult<Vec<Affine<C>>>
+    where
+        C::BaseField: FieldArithmetic,
+    {
+        let mut points = vec![Affine::<C>::zero(); self.number_of_points::<C>(section)?];
+        self.read_points::<C, C::BaseField>(section, 0, &mut points)?;
+        Ok(points)
+    }
+
+    /// Reads a whole section of G2 points in normal form, `Fq` being the base field of G1.
+    pub fn read_g2_section<C: Curve, Fq: FieldArithmetic>(
+        &mut self,
+        section: PtauSection,
+    ) -> IcicleResult<Vec<Affine<C>>> {
+        let mut points = vec![Affine::<C>::zero(); self.number_of_points::<C>(section)?];
+        self.read_points::<C, Fq>(section, 0, &mut points)?;
+        Ok(points)
+    }
+
+    /// The Lagrange bases of `tau` times the G1 generator for the domain of size `2^log_size`, from a file prepared
+    /// for phase 2.
+    pub fn read_lagrange_g1<C: Curve>(&mut self, log_size: u32) -> IcicleResult<Vec<Affine<C>>>
+    where
+        C::BaseField: FieldArithmetic,
+    {
+        if log_size > self.power {
+            return Err(IcicleError::invalid_argument(
+                "Ptau file has no Lagrange bases of that size",
+            ));
+        }
+        // The bases of the smaller domains come first
+        let mut points = vec![Affine::<C>::zero(); 1 << log_size];
+        self.read_points::<C, C::BaseField>(PtauSection::LagrangeTauG1, (1 << log_size) - 1, &mut points)?;
+        Ok(points)
+    }
+
+    fn read<C: Curve>(
+        &mut self,
+        section: PtauSection,
+        start: usize,
+        points: &mut [Affine<C>],
+        convert: impl Fn(&mut [u8], usize),
+    ) -> IcicleResult<()> {
+        let coordinate_len = self.n8 * coordinate_elements::<C>(self.n8)?;
+        if start + points.len() > self.number_of_points::<C>(section)? {
+            return Err(IcicleError::invalid_argument(
+                "Reading past the end of the ptau section",
+            ));
+        }
+
+        let (offset, _) = self.sections[&(section as u32)];
+        io_error(
+            self.reader
+                .seek(SeekFrom::Start(offset + (start * 2 * coordinate_len) as u64)),
+        )?;
+        let mut buffer = vec![0u8; CHUNK_POINTS.min(points.len()) * 2 * coordinate_len];
+        for chunk in points.chunks_mut(CHUNK_POINTS) {
+            let bytes = &mut buffer[..chunk.len() * 2 * coordinate_len];
+            io_error(
+                self.reader
+                    .read_exact(bytes),
+            )?;
+            convert(bytes, self.n8);
+            for (point, point_bytes) in chunk
+                .iter_mut()
+                .zip(bytes.chunks(2 * coordinate_len))
+            {
+                let (x, y) = point_bytes.split_at(coordinate_len);
+                *point = Affine {
+                    x: C::BaseField::from_bytes_le(x),
+                    y: C::BaseField::from_bytes_le(y),
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a `.ptau` file section by section, in the layout [PtauReader] reads.
+///
+/// A section is opened with [begin_section](Self::begin_section), filled with any number of calls to the `write_`
+/// methods, so that big sections can be written in chunks, and closed with [end_section](Self::end_section).
+#[derive(Debug)]
+pub struct PtauWriter<W> {
+    writer: W,
+    n8: usize,
+    modulus: Vec<u8>,
+    number_of_sections: u32,
+    // Position of the length of the open section and of its data
+    open_section: Option<(u64, u64)>,
+}
+
+impl<W: Write + Seek> PtauWriter<W> {
+    /// Starts a file for the base field `Fq` of G1, with powers for domains of size up to `2^power`.
+    pub fn new<Fq: FieldArithmetic>(mut writer: W, power: u32, ceremony_power: u32) -> IcicleResult<Self> {
+        let modulus = Fq::modulus_bytes_le();
+        let n8 = modulus.len();
+        io_error(writer.write_all(MAGIC))?;
+        io_error(writer.write_all(&VERSION.to_le_bytes()))?;
+        // Patched when the file is finished
+        io_error(writer.write_all(&0u32.to_le_bytes()))?;
+
+        let mut ptau = Self {
+            writer,
+            n8,
+            modulus,
+            number_of_sections: 0,
+            open_section: None,
+        };
+        ptau.begin_section(PtauSection::Header)?;
+        let mut header = (n8 as u32)
+            .to_le_bytes()
+            .to_vec();
+        header.extend_from_slice(&ptau.modulus);
+        header.extend_from_slice(&power.to_le_bytes());
+        header.extend_from_slice(&ceremony_power.to_le_bytes());
+        ptau.write_raw(&header)?;
+        ptau.end_section()?;
+        Ok(ptau)
+    }
+
+    pub fn begin_section(&mut self, section: PtauSection) -> IcicleResult<()> {
+        if self
+            .open_section
+            .is_some()
+        {
+            return Err(IcicleError::invalid_argument("Previous ptau section isn't closed"));
+        }
+        io_error(
+            self.writer
+                .write_all(&(section as u32).to_le_bytes()),
+        )?;
+        let len_position = io_error(
+            self.writer
+                .stream_position(),
+        )?;
+        io_error(
+            self.writer
+                .write_all(&0u64.to_le_bytes()),
+        )?;
+        self.open_section = Some((len_position, len_position + 8));
+        self.number_of_sections += 1;
+        Ok(())
+    }
+
+    /// Appends points given in normal form, `Fq` being the prime field of their coordinates.
+    pub fn write_points<C: Curve, Fq: FieldArithmetic>(&mut self, points: &[Affine<C>]) -> IcicleResult<()> {
+        check_modulus::<Fq>(&self.modulus)?;
+        self.write(points, |bytes, n8| convert_elements::<Fq>(bytes, n8, true))
+    }
+
+    /// Appends points that are already in Montgomery form.
+    pub fn write_points_montgomery<C: Curve>(&mut self, points: &[Affine<C>]) -> IcicleResult<()> {
+        self.write(points, |_, _| {})
+    }
+
+    /// Appends bytes as they are, for sections that don't hold points.
+    pub fn write_raw(&mut self, bytes: &[u8]) -> IcicleResult<()> {
+        if self
+            .open_section
+            .is_none()
+        {
+            return Err(IcicleError::invalid_argument("No ptau section is open"));
+        }
+        io_error(
+            self.writer
+                .write_all(bytes),
+        )
+    }
+
+    pub fn end_section(&mut self) -> IcicleResult<()> {
+        let (len_position, start) = self
+            .open_section
+            .take()
+            .ok_or(IcicleError::invalid_argument("No ptau section is open"))?;
+        let end = io_error(
+            self.writer
+                .stream_position(),
+        )?;
+        io_error(
+            self.writer
+                .seek(SeekFrom::Start(len_position)),
+        )?;
+        io_error(
+            self.writer
+                .write_all(&(end - start).to_le_bytes()),
+        )?;
+        io_error(
+            self.writer
+                .seek(SeekFrom::Start(end)),
+        )?;
+        Ok(())
+    }
+
+    /// Writes the number of sections in the file header and returns the underlying writer.
+    pub fn finish(mut self) -> IcicleResult<W> {
+        if self
+            .open_section
+            .is_some()
+        {
+            self.end_section()?;
+        }
+        io_error(
+            self.writer
+                .seek(SeekFrom::Start(8)),
+        )?;
+        io_error(
+            self.writer
+                .write_all(
+                    &self
+                        .number_of_sections
+                        .to_le_bytes(),
+                ),
+        )?;
+        io_error(
+            self.writer
+                .seek(SeekFrom::End(0)),
+        )?;
+        Ok(self.writer)
+    }
+
+    fn write<C: Curve>(&mut self, points: &[Affine<C>], convert: impl Fn(&mut [u8], usize)) -> IcicleResult<()> {
+        let coordinate_len = self.n8 * coordinate_elements::<C>(self.n8)?;
+        let mut buffer = Vec::with_capacity(CHUNK_POINTS.min(points.len()) * 2 * coordinate_len);
+        for chunk in points.chunks(CHUNK_POINTS) {
+            buffer.clear();
+            for point in chunk {
+                for coordinate in [point.x, point.y] {
+                    let mut bytes = coordinate.to_bytes_le();
+                    bytes.resize(coordinate_len, 0);
+                    buffer.extend(bytes);
+                }
+            }
+            convert(&mut buffer, self.n8);
+            self.write_raw(&buffer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that points read from a file are on the curve and in the prime order subgroup.
+#[cfg(feature = "arkworks")]
+pub fn check_points<C: Curve>(points: &[Affine<C>]) -> IcicleResult<()>
+where
+    C::BaseField: ArkConvertible<ArkEquivalent = <C::ArkSWConfig as CurveConfig>::BaseField>,
+{
+    let valid = points
+        .iter()
+        .all(|point| {
+            let point: ArkAffine<C::ArkSWConfig> = point.to_ark();
+            point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()
+        });
+    if !valid {
+        return Err(IcicleError::invalid_argument(
+            "SRS point is not in the prime order subgroup",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use icicle_core::{
+    curve::{Affine, Curve, Projective},
+    error::{IcicleError, IcicleResult},
+    msm::{msm, MSMConfig, MSM},
+    traits::{FieldArithmetic, FieldImpl},
+};
+use icicle_cuda_runtime::memory::HostSlice;
+
+/// Prover part of a KZG structured reference string: the powers `[τ^i]G` of a secret `τ` in the group of the
+/// commitments, for `i` from 0 to the maximal degree of the committed polynomials.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Srs<C: Curve> {
+    powers_of_tau: Vec<Affine<C>>,
+}
+
+impl<C: Curve> Srs<C> {
+    /// Wraps the powers of a trusted setup, `[G, [τ]G, [τ^2]G, ...]`.
+    pub fn from_powers_of_tau(powers_of_tau: Vec<Affine<C>>) -> IcicleResult<Self> {
+        if powers_of_tau.is_empty() {
+            return Err(IcicleError::invalid_argument("SRS needs at least one power of tau"));
+        }
+        Ok(Self { powers_of_tau })
+    }
+
+    /// Computes `size` powers of `tau` times `generator` with a single batched MSM.
+    ///
+    /// Whoever knows `tau` can open commitments to anything, so this is only meant for tests and benchmarks.
+    pub fn insecure_setup(generator: &Affine<C>, tau: &C::ScalarField, size: usize) -> IcicleResult<Self>
+    where
+        C: MSM<C>,
+        C::ScalarField: FieldArithmetic,
+    {
+        if size == 0 {
+            return Err(IcicleError::invalid_argument("SRS needs at least one power of tau"));
+        }
+
+        let mut scalars = Vec::with_capacity(size);
+        let mut power = C::ScalarField::one();
+        for _ in 0..size {
+            scalars.push(power);
+            power = power * *tau;
+        }
+        // One MSM of size 1 per power, all of them sharing the generator
+        let mut points = vec![Projective::<C>::zero(); size];
+        msm(
+            HostSlice::from_slice(&scalars),
+            HostSlice::from_slice(&[*generator]),
+            &MSMConfig::default(),
+            HostSlice::from_mut_slice(&mut points),
+        )?;
+        Self::from_powers_of_tau(
+            points
+                .into_iter()
+                .map(Affine::from)
+                .collect(),
+        )
+    }
+
+    pub fn powers_of_tau(&self) -> &[Affine<C>] {
+        &self.powers_of_tau
+    }
+
+    /// The generator `G` the powers are taken of.
+    pub fn generator(&self) -> Affine<C> {
+        self.powers_of_tau[0]
+    }
+
+    /// Largest degree of the polynomials this SRS can commit to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_tau
+            .len()
+            - 1
+    }
+
+    /// Keeps the powers needed for polynomials of degree up to `max_degree`.
+    pub fn trim(&self, max_degree: usize) -> IcicleResult<Self> {
+        if max_degree > self.max_degree() {
+            return Err(IcicleError::invalid_argument(
+                "SRS is too small for the requested degree",
+            ));
+        }
+        Self::from_powers_of_tau(self.powers_of_tau[..=max_degree].to_vec())
+    }
+}
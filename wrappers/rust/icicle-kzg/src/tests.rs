@@ -0,0 +1,454 @@
+use std::ops::Mul;
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{Field, One, UniformRand, Zero};
+use ark_std::test_rng;
+use icicle_core::{
+    curve::Affine,
+    msm::MSM,
+    ntt::{get_root_of_unity, initialize_domain, NTTDomain, NTT},
+    polynomials::{BivariatePolynomial, UnivariatePolynomial},
+    traits::{ArkConvertible, FieldArithmetic, FieldImpl, GenerateRandom},
+    SNARKCurve,
+};
+use icicle_cuda_runtime::{device_context::DeviceContext, memory::HostSlice};
+use std::io::Cursor;
+
+use crate::{
+    ark_srs::{read_ark_powers, write_ark_powers},
+    bivariate::{commit_bivariate, open_bivariate, BivariateKzgOpening, BivariateSrs},
+    prover::{
+        batch_open, commit, commit_coefficients, commit_lagrange, open, prove_vanishing, KzgBatchOpening, KzgOpening,
+    },
+    ptau::{check_points, PtauReader, PtauSection, PtauWriter},
+    srs::Srs,
+    verifier::{BivariateVerifierKey, PairingCurve, VerifierKey},
+};
+
+type ArkScalar<C> = <<C as PairingCurve>::Engine as Pairing>::ScalarField;
+type ArkG1<C> = <<C as PairingCurve>::Engine as Pairing>::G1Affine;
+
+fn ark_commit<C: PairingCurve>(powers_of_tau: &[ArkG1<C>], coeffs: &[ArkScalar<C>]) -> Affine<C> {
+    let mut commitment = <C::Engine as Pairing>::G1::zero();
+    for (power, coeff) in powers_of_tau
+        .iter()
+        .zip(coeffs)
+    {
+        commitment += *power * coeff;
+    }
+    C::g1_from_ark(commitment.into_affine())
+}
+
+fn ark_eval<F: Field>(coeffs: &[F], point: F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * point + coeff)
+}
+
+// Quotient of the polynomial by x - point
+fn ark_divide_linear<F: Field>(coeffs: &[F], point: F) -> Vec<F> {
+    let mut quotient = vec![F::zero(); coeffs.len() - 1];
+    let mut carry = F::zero();
+    for i in (1..coeffs.len()).rev() {
+        carry = coeffs[i] + carry * point;
+        quotient[i - 1] = carry;
+    }
+    quotient
+}
+
+// Openings built with arkworks, to check the verifier without a device
+fn check_host_verification<C: PairingCurve>() {
+    let mut rng = test_rng();
+    let tau = ArkScalar::<C>::rand(&mut rng);
+    let generator = ArkG1::<C>::generator();
+    let mut power = ArkScalar::<C>::one();
+    let mut powers_of_tau = Vec::new();
+    for _ in 0..16 {
+        powers_of_tau.push((generator * power).into_affine());
+        power *= tau;
+    }
+    let vk = VerifierKey::<C>::insecure_setup(&C::g1_from_ark(generator), &C::scalar_from_ark(tau), 9).unwrap();
+
+    let coeffs: Vec<_> = (0..8)
+        .map(|_| ArkScalar::<C>::rand(&mut rng))
+        .collect();
+    let point = ArkScalar::<C>::rand(&mut rng);
+    let commitment = ark_commit(&powers_of_tau, &coeffs);
+    let mut opening = KzgOpening {
+        point: C::scalar_from_ark(point),
+        value: C::scalar_from_ark(ark_eval(&coeffs, point)),
+        proof: ark_commit(&powers_of_tau, &ark_divide_linear(&coeffs, point)),
+    };
+    assert!(vk.verify(&commitment, &opening));
+    opening.value = C::scalar_from_ark(ark_eval(&coeffs, point) + ArkScalar::<C>::one());
+    assert!(!vk.verify(&commitment, &opening));
+
+    let other: Vec<_> = (0..5)
+        .map(|_| ArkScalar::<C>::rand(&mut rng))
+        .collect();
+    let challenge = ArkScalar::<C>::rand(&mut rng);
+    let mut combined = coeffs.clone();
+    for (sum, coeff) in combined
+        .iter_mut()
+        .zip(&other)
+    {
+        *sum += challenge * coeff;
+    }
+    let commitments = [commitment, ark_commit(&powers_of_tau, &other)];
+    let mut batch = KzgBatchOpening {
+        point: C::scalar_from_ark(point),
+        values: vec![
+            C::scalar_from_ark(ark_eval(&coeffs, point)),
+            C::scalar_from_ark(ark_eval(&other, point)),
+        ],
+        proof: ark_commit(&powers_of_tau, &ark_divide_linear(&combined, point)),
+    };
+    let icicle_challenge = C::scalar_from_ark(challenge);
+    assert!(vk
+        .verify_batch(&commitments, &batch, &icicle_challenge)
+        .unwrap());
+    assert!(!vk
+        .verify_batch(
+            &commitments,
+            &batch,
+            &C::scalar_from_ark(challenge + ArkScalar::<C>::one())
+        )
+        .unwrap());
+    batch
+        .values
+        .pop();
+    assert!(vk
+        .verify_batch(&commitments, &batch, &icicle_challenge)
+        .is_err());
+
+    // p = q (x^4 - 1) vanishes on the 4th roots of unity
+    let quotient: Vec<_> = (0..4)
+        .map(|_| ArkScalar::<C>::rand(&mut rng))
+        .collect();
+    let mut vanishing = vec![ArkScalar::<C>::zero(); 8];
+    for (i, coeff) in quotient
+        .iter()
+        .enumerate()
+    {
+        vanishing[i] -= coeff;
+        vanishing[i + 4] += coeff;
+    }
+    let commitment = ark_commit(&powers_of_tau, &vanishing);
+    let proof = ark_commit(&powers_of_tau, &quotient);
+    assert!(vk
+        .verify_vanishing(&commitment, 4, &proof)
+        .unwrap());
+    assert!(!vk
+        .verify_vanishing(&commitment, 2, &proof)
+        .unwrap());
+    assert!(vk
+        .verify_vanishing(&commitment, 9, &proof)
+        .is_err());
+}
+
+fn check_kzg<C, P>()
+where
+    C: SNARKCurve + PairingCurve + MSM<C>,
+    C::ScalarField: FieldArithmetic,
+    <C::ScalarField as FieldImpl>::Config:
+        NTT<C::ScalarField, C::ScalarField> + NTTDomain<C::ScalarField> + GenerateRandom<C::ScalarField>,
+    P: UnivariatePolynomial<Field = C::ScalarField>,
+    for<'a> &'a P: Mul<&'a P, Output = P>,
+{
+    let ctx = DeviceContext::default();
+    initialize_domain(get_root_of_unity::<C::ScalarField>(1 << 10), &ctx, false).unwrap();
+
+    let size = 32;
+    let tau = <C::ScalarField as FieldImpl>::Config::generate_random(1)[0];
+    let generator = C::generate_random_affine_points(1)[0];
+    let srs = Srs::<C>::insecure_setup(&generator, &tau, 2 * size).unwrap();
+    let vk = VerifierKey::<C>::insecure_setup(&generator, &tau, size + 1).unwrap();
+    assert_eq!(srs.max_degree(), 2 * size - 1);
+    assert_eq!(srs.generator(), generator);
+
+    let coeffs = <C::ScalarField as FieldImpl>::Config::generate_random(size);
+    let poly = P::from_coeffs(HostSlice::from_slice(&coeffs), size);
+    let commitment = commit(&srs, &poly).unwrap();
+    assert_eq!(
+        commitment,
+        commit_coefficients(&srs, HostSlice::from_slice(&coeffs)).unwrap()
+    );
+    assert!(commit(
+        &srs.trim(size - 2)
+            .unwrap(),
+        &poly
+    )
+    .is_err());
+
+    let point = <C::ScalarField as FieldImpl>::Config::generate_random(1)[0];
+    let opening = open(&srs, &poly, &point).unwrap();
+    assert_eq!(opening.value, poly.eval(&point));
+    assert!(vk.verify(&commitment, &opening));
+
+    // The same polynomial given by its values on the roots of unity
+    let mut evals = vec![C::ScalarField::zero(); size];
+    poly.eval_on_rou_domain(size.trailing_zeros() as u64, HostSlice::from_mut_slice(&mut evals));
+    assert_eq!(
+        commitment,
+        commit_lagrange(&srs, HostSlice::from_slice(&evals)).unwrap()
+    );
+
+    let other_coeffs = <C::ScalarField as FieldImpl>::Config::generate_random(size / 2);
+    let other = P::from_coeffs(HostSlice::from_slice(&other_coeffs), size / 2);
+    let challenge = <C::ScalarField as FieldImpl>::Config::generate_random(1)[0];
+    let batch = batch_open(&srs, &[poly, other], &point, &challenge).unwrap();
+    let commitments = [
+        commitment,
+        commit_coefficients(&srs, HostSlice::from_slice(&other_coeffs)).unwrap(),
+    ];
+    assert!(vk
+        .verify_batch(&commitments, &batch, &challenge)
+        .unwrap());
+
+    let mut vanishing_coeffs = vec![C::ScalarField::zero(); size + 1];
+    vanishing_coeffs[0] = -C::ScalarField::one();
+    vanishing_coeffs[size] = C::ScalarField::one();
+    let vanishing = P::from_coeffs(HostSlice::from_slice(&vanishing_coeffs), size + 1);
+    let multiple = &P::from_coeffs(HostSlice::from_slice(&coeffs), size) * &vanishing;
+    let proof = prove_vanishing(&srs, &multiple, size as u64).unwrap();
+    assert!(vk
+        .verify_vanishing(&commit(&srs, &multiple).unwrap(), size as u64, &proof)
+        .unwrap());
+}
+
+// Bivariate openings with the quotients computed on the host and committed with arkworks
+fn check_bivariate_host_verification<C: PairingCurve>()
+where
+    C::ScalarField: FieldArithmetic,
+{
+    let mut rng = test_rng();
+    let (x_size, y_size) = (4, 8);
+    let (tau_x, tau_y) = (ArkScalar::<C>::rand(&mut rng), ArkScalar::<C>::rand(&mut rng));
+    let generator = ArkG1::<C>::generator();
+    let mut powers_of_tau = Vec::new();
+    let mut y_power = ArkScalar::<C>::one();
+    for _ in 0..y_size {
+        let mut power = y_power;
+        for _ in 0..x_size {
+            powers_of_tau.push((generator * power).into_affine());
+            power *= tau_x;
+        }
+        y_power *= tau_y;
+    }
+    let y_powers_of_tau: Vec<_> = powers_of_tau
+        .iter()
+        .step_by(x_size)
+        .copied()
+        .collect();
+    let to_ark = |coeffs: &[C::ScalarField]| -> Vec<ArkScalar<C>> {
+        coeffs
+            .iter()
+            .map(C::scalar_to_ark)
+            .collect()
+    };
+
+    let coeffs = (0..x_size * y_size)
+        .map(|_| C::scalar_from_ark(ArkScalar::<C>::rand(&mut rng)))
+        .collect();
+    let poly = BivariatePolynomial::from_coeffs(coeffs, x_size, y_size).unwrap();
+    let (x, y) = (
+        C::scalar_from_ark(ArkScalar::<C>::rand(&mut rng)),
+        C::scalar_from_ark(ArkScalar::<C>::rand(&mut rng)),
+    );
+    let (quotient_x, remainder) = poly.divide_by_x(&x);
+    let (quotient_y, _) = BivariatePolynomial::from_coeffs(remainder, 1, y_size)
+        .unwrap()
+        .divide_by_y(&y);
+
+    let commitment = ark_commit(&powers_of_tau, &to_ark(poly.coeffs()));
+    let mut opening = BivariateKzgOpening {
+        x,
+        y,
+        value: poly.eval(&x, &y),
+        proof_x: ark_commit(&powers_of_tau, &to_ark(quotient_x.coeffs())),
+        proof_y: ark_commit(&y_powers_of_tau, &to_ark(quotient_y.coeffs())),
+    };
+    let vk = BivariateVerifierKey::<C>::insecure_setup(
+        &C::g1_from_ark(generator),
+        &C::scalar_from_ark(tau_x),
+        &C::scalar_from_ark(tau_y),
+    );
+    assert!(vk.verify(&commitment, &opening));
+    opening.value = opening.value + C::ScalarField::one();
+    assert!(!vk.verify(&commitment, &opening));
+}
+
+fn check_bivariate_kzg<C>()
+where
+    C: PairingCurve + MSM<C>,
+    C::ScalarField: FieldArithmetic,
+    <C::ScalarField as FieldImpl>::Config: GenerateRandom<C::ScalarField>,
+{
+    let (x_size, y_size) = (16, 8);
+    let scalars = <C::ScalarField as FieldImpl>::Config::generate_random(4);
+    let (tau_x, tau_y, x, y) = (scalars[0], scalars[1], scalars[2], scalars[3]);
+    let generator = C::generate_random_affine_points(1)[0];
+    let srs = BivariateSrs::<C>::insecure_setup(&generator, &tau_x, &tau_y, x_size, y_size).unwrap();
+    let vk = BivariateVerifierKey::<C>::insecure_setup(&generator, &tau_x, &tau_y);
+
+    let poly = BivariatePolynomial::from_coeffs(
+        <C::ScalarField as FieldImpl>::Config::generate_random(x_size * y_size / 2),
+        x_size,
+        y_size / 2,
+    )
+    .unwrap();
+    let commitment = commit_bivariate(&srs, &poly).unwrap();
+    let opening = open_bivariate(&srs, &poly, &x, &y).unwrap();
+    assert_eq!(opening.value, poly.eval(&x, &y));
+    assert!(vk.verify(&commitment, &opening));
+
+    let too_wide = BivariatePolynomial::zero(2 * x_size, 1);
+    assert!(commit_bivariate(&srs, &too_wide).is_err());
+}
+
+// Writes and reads back a small ptau file and arkworks SRS, all on the host
+fn check_srs_files<C: PairingCurve>()
+where
+    C::BaseField:
+        FieldArithmetic + ArkConvertible<ArkEquivalent = <C::ArkSWConfig as ark_ec::models::CurveConfig>::BaseField>,
+{
+    let mut rng = test_rng();
+    let tau = ArkScalar::<C>::rand(&mut rng);
+    let generator = ArkG1::<C>::generator();
+    // Powers of tau and tau times alpha, the point at infinity included to check its encoding
+    let tau_g1: Vec<Affine<C>> = (0..3)
+        .map(|i| C::g1_from_ark((generator * tau.pow([i as u64])).into_affine()))
+        .collect();
+    let alpha_tau_g1 = vec![tau_g1[1], Affine::<C>::zero()];
+
+    let mut writer = PtauWriter::new::<C::BaseField>(Cursor::new(Vec::new()), 1, 28).unwrap();
+    writer
+        .begin_section(PtauSection::TauG1)
+        .unwrap();
+    writer
+        .write_points::<C, C::BaseField>(&tau_g1[..1])
+        .unwrap();
+    writer
+        .write_points::<C, C::BaseField>(&tau_g1[1..])
+        .unwrap();
+    writer
+        .end_section()
+        .unwrap();
+    writer
+        .begin_section(PtauSection::AlphaTauG1)
+        .unwrap();
+    writer
+        .write_points::<C, C::BaseField>(&alpha_tau_g1)
+        .unwrap();
+    let bytes = writer
+        .finish()
+        .unwrap()
+        .into_inner();
+
+    let mut reader = PtauReader::new(Cursor::new(&bytes[..])).unwrap();
+    assert_eq!(reader.power(), 1);
+    assert_eq!(reader.ceremony_power(), 28);
+    assert_eq!(reader.modulus(), C::BaseField::modulus_bytes_le());
+    assert!(!reader.has_section(PtauSection::TauG2));
+    assert_eq!(reader.read_g1_section::<C>(PtauSection::TauG1), Ok(tau_g1.clone()));
+    assert_eq!(reader.read_g1_section::<C>(PtauSection::AlphaTauG1), Ok(alpha_tau_g1));
+    check_points(&tau_g1).unwrap();
+
+    // Points are stored in Montgomery form and can be read without conversion
+    let mut montgomery = vec![Affine::<C>::zero(); 2];
+    reader
+        .read_points_montgomery(PtauSection::TauG1, 1, &mut montgomery)
+        .unwrap();
+    assert_eq!(
+        montgomery[0].x,
+        tau_g1[1]
+            .x
+            .to_montgomery()
+    );
+    assert_eq!(
+        montgomery[1].y,
+        tau_g1[2]
+            .y
+            .to_montgomery()
+    );
+    assert!(reader
+        .read_points_montgomery(PtauSection::TauG1, 2, &mut montgomery)
+        .is_err());
+
+    // A point off the curve is rejected
+    let mut off_curve = tau_g1[1];
+    off_curve.y = off_curve.y + C::BaseField::one();
+    assert!(check_points(&[tau_g1[0], off_curve]).is_err());
+
+    for compressed in [false, true] {
+        let mut ark_bytes = Vec::new();
+        write_ark_powers(&mut ark_bytes, &tau_g1, compressed).unwrap();
+        assert_eq!(
+            read_ark_powers::<C>(&ark_bytes[..], compressed, true),
+            Ok(tau_g1.clone())
+        );
+    }
+    assert!(PtauReader::new(Cursor::new(&bytes[4..])).is_err());
+}
+
+#[test]
+fn test_host_verification_bn254() {
+    check_host_verification::<icicle_bn254::curve::CurveCfg>();
+}
+
+#[test]
+fn test_host_verification_bls12_381() {
+    check_host_verification::<icicle_bls12_381::curve::CurveCfg>();
+}
+
+#[test]
+fn test_kzg_bn254() {
+    icicle_bn254::polynomials::DensePolynomial::init_cuda_backend();
+    check_kzg::<icicle_bn254::curve::CurveCfg, icicle_bn254::polynomials::DensePolynomial>();
+}
+
+#[test]
+fn test_kzg_bls12_381() {
+    icicle_bls12_381::polynomials::DensePolynomial::init_cuda_backend();
+    check_kzg::<icicle_bls12_381::curve::CurveCfg, icicle_bls12_381::polynomials::DensePolynomial>();
+}
+
+#[test]
+fn test_bivariate_host_verification_bn254() {
+    check_bivariate_host_verification::<icicle_bn254::curve::CurveCfg>();
+}
+
+#[test]
+fn test_bivariate_host_verification_bls12_381() {
+    check_bivariate_host_verification::<icicle_bls12_381::curve::CurveCfg>();
+}
+
+#[test]
+fn test_bivariate_kzg_bn254() {
+    check_bivariate_kzg::<icicle_bn254::curve::CurveCfg>();
+}
+
+#[test]
+fn test_srs_files_bn254() {
+    check_srs_files::<icicle_bn254::curve::CurveCfg>();
+}
+
+#[test]
+fn test_srs_files_bls12_381() {
+    check_srs_files::<icicle_bls12_381::curve::CurveCfg>();
+}
+
+#[test]
+fn test_ptau_wrong_curve() {
+    let writer = PtauWriter::new::<icicle_bn254::curve::BaseField>(Cursor::new(Vec::new()), 0, 0).unwrap();
+    let bytes = writer
+        .finish()
+        .unwrap()
+        .into_inner();
+    let mut reader = PtauReader::new(Cursor::new(&bytes[..])).unwrap();
+    let mut points = Vec::<Affine<icicle_bls12_381::curve::CurveCfg>>::new();
+    assert!(reader
+        .read_points::<_, icicle_bls12_381::curve::BaseField>(PtauSection::TauG1, 0, &mut points)
+        .is_err());
+}
@@ -0,0 +1,228 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{One, Zero};
+use icicle_core::{
+    curve::{Affine, Curve},
+    error::{IcicleError, IcicleResult},
+    traits::ArkConvertible,
+};
+
+use crate::{
+    bivariate::BivariateKzgOpening,
+    prover::{KzgBatchOpening, KzgOpening},
+};
+
+/// Curves whose KZG openings can be checked on the host with an arkworks pairing.
+pub trait PairingCurve: Curve {
+    type Engine: Pairing;
+
+    fn g1_to_ark(point: &Affine<Self>) -> <Self::Engine as Pairing>::G1Affine;
+    fn g1_from_ark(point: <Self::Engine as Pairing>::G1Affine) -> Affine<Self>;
+    fn scalar_to_ark(scalar: &Self::ScalarField) -> <Self::Engine as Pairing>::ScalarField;
+    fn scalar_from_ark(scalar: <Self::Engine as Pairing>::ScalarField) -> Self::ScalarField;
+}
+
+macro_rules! impl_pairing_curve {
+    ($curve:ty, $engine:ty) => {
+        impl PairingCurve for $curve {
+            type Engine = $engine;
+
+            fn g1_to_ark(point: &Affine<Self>) -> <Self::Engine as Pairing>::G1Affine {
+                point.to_ark()
+            }
+
+            fn g1_from_ark(point: <Self::Engine as Pairing>::G1Affine) -> Affine<Self> {
+                Affine::from_ark(point)
+            }
+
+            fn scalar_to_ark(scalar: &Self::ScalarField) -> <Self::Engine as Pairing>::ScalarField {
+                scalar.to_ark()
+            }
+
+            fn scalar_from_ark(scalar: <Self::Engine as Pairing>::ScalarField) -> Self::ScalarField {
+                Self::ScalarField::from_ark(scalar)
+            }
+        }
+    };
+}
+
+impl_pairing_curve!(icicle_bn254::curve::CurveCfg, ark_bn254::Bn254);
+impl_pairing_curve!(icicle_bls12_381::curve::CurveCfg, ark_bls12_381::Bls12_381);
+
+type G1<C> = <<C as PairingCurve>::Engine as Pairing>::G1Affine;
+type G2<C> = <<C as PairingCurve>::Engine as Pairing>::G2Affine;
+
+/// Verifier part of a KZG structured reference string: the generator of the first group and the powers `[τ^i]H` of
+/// the generator `H` of the second group.
+///
+/// Single and batch openings need `[τ]H`, a vanishing proof on a domain of size `n` needs `[τ^n]H`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifierKey<C: PairingCurve> {
+    g1: G1<C>,
+    g2_powers_of_tau: Vec<G2<C>>,
+}
+
+impl<C: PairingCurve> VerifierKey<C> {
+    /// `g1` is the generator of the prover's SRS and `g2_powers_of_tau` holds `[H, [τ]H, ...]`.
+    pub fn new(g1: &Affine<C>, g2_powers_of_tau: Vec<G2<C>>) -> IcicleResult<Self> {
+        if g2_powers_of_tau.len() < 2 {
+            return Err(IcicleError::invalid_argument(
+                "Verifier key needs at least H and [tau]H",
+            ));
+        }
+        Ok(Self {
+            g1: C::g1_to_ark(g1),
+            g2_powers_of_tau,
+        })
+    }
+
+    /// Computes `size` powers of `tau` times the standard generator of the second group, to pair with a prover SRS
+    /// of the same `tau`. Only meant for tests.
+    pub fn insecure_setup(g1: &Affine<C>, tau: &C::ScalarField, size: usize) -> IcicleResult<Self> {
+        let tau = C::scalar_to_ark(tau);
+        let generator = G2::<C>::generator();
+        let mut power = <C::Engine as Pairing>::ScalarField::one();
+        let mut powers = Vec::with_capacity(size);
+        for _ in 0..size {
+            powers.push(generator * power);
+            power *= tau;
+        }
+        Self::new(g1, <C::Engine as Pairing>::G2::normalize_batch(&powers))
+    }
+
+    pub fn g2_powers_of_tau(&self) -> &[G2<C>] {
+        &self.g2_powers_of_tau
+    }
+
+    /// Checks `e(C - [v]G + [z]π, H) = e(π, [τ]H)`, that is `p(x) - v = q(x)(x - z)` at `τ`.
+    pub fn verify(&self, commitment: &Affine<C>, opening: &KzgOpening<C>) -> bool {
+        self.check_opening(
+            C::g1_to_ark(commitment).into_group(),
+            C::scalar_to_ark(&opening.point),
+            C::scalar_to_ark(&opening.value),
+            C::g1_to_ark(&opening.proof),
+        )
+    }
+
+    /// Checks a batch opening against the commitments of the opened polynomials, in the same order, with the
+    /// challenge the prover combined them with.
+    pub fn verify_batch(
+        &self,
+        commitments: &[Affine<C>],
+        opening: &KzgBatchOpening<C>,
+        challenge: &C::ScalarField,
+    ) -> IcicleResult<bool> {
+        if commitments.len()
+            != opening
+                .values
+                .len()
+            || commitments.is_empty()
+        {
+            return Err(IcicleError::invalid_argument(
+                "There must be one opened value per commitment",
+            ));
+        }
+
+        let challenge = C::scalar_to_ark(challenge);
+        let mut scale = <C::Engine as Pairing>::ScalarField::one();
+        let mut commitment = <C::Engine as Pairing>::G1::zero();
+        let mut value = <C::Engine as Pairing>::ScalarField::zero();
+        for (point, opened) in commitments
+            .iter()
+            .zip(&opening.values)
+        {
+            commitment += C::g1_to_ark(point) * scale;
+            value += C::scalar_to_ark(opened) * scale;
+            scale *= challenge;
+        }
+        Ok(self.check_opening(
+            commitment,
+            C::scalar_to_ark(&opening.point),
+            value,
+            C::g1_to_ark(&opening.proof),
+        ))
+    }
+
+    /// Checks `e(C, H) = e(π, [τ^n]H - H)`, that is the committed polynomial is a multiple of `x^n - 1` for `n` the
+    /// vanishing degree.
+    pub fn verify_vanishing(
+        &self,
+        commitment: &Affine<C>,
+        vanishing_degree: u64,
+        proof: &Affine<C>,
+    ) -> IcicleResult<bool> {
+        let tau_n = self
+            .g2_powers_of_tau
+            .get(vanishing_degree as usize)
+            .ok_or(IcicleError::invalid_argument(
+                "Verifier key has too few powers of tau for the vanishing degree",
+            ))?;
+        let vanishing = (tau_n.into_group() - self.g2_powers_of_tau[0]).into_affine();
+        let pairing = C::Engine::multi_pairing(
+            [C::g1_to_ark(commitment).into_group(), -C::g1_to_ark(proof).into_group()],
+            [self.g2_powers_of_tau[0], vanishing],
+        );
+        Ok(pairing.is_zero())
+    }
+
+    fn check_opening(
+        &self,
+        commitment: <C::Engine as Pairing>::G1,
+        point: <C::Engine as Pairing>::ScalarField,
+        value: <C::Engine as Pairing>::ScalarField,
+        proof: G1<C>,
+    ) -> bool {
+        let lhs = commitment - self.g1 * value + proof * point;
+        C::Engine::multi_pairing(
+            [lhs, -proof.into_group()],
+            [self.g2_powers_of_tau[0], self.g2_powers_of_tau[1]],
+        )
+        .is_zero()
+    }
+}
+
+/// Verifier part of a bivariate KZG structured reference string: the generator of the first group, and `H`,
+/// `[τ_x]H` and `[τ_y]H` in the second group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariateVerifierKey<C: PairingCurve> {
+    g1: G1<C>,
+    g2: G2<C>,
+    tau_x_g2: G2<C>,
+    tau_y_g2: G2<C>,
+}
+
+impl<C: PairingCurve> BivariateVerifierKey<C> {
+    pub fn new(g1: &Affine<C>, g2: G2<C>, tau_x_g2: G2<C>, tau_y_g2: G2<C>) -> Self {
+        Self {
+            g1: C::g1_to_ark(g1),
+            g2,
+            tau_x_g2,
+            tau_y_g2,
+        }
+    }
+
+    /// Uses the standard generator of the second group, to pair with a prover SRS of the same `tau_x` and `tau_y`.
+    /// Only meant for tests.
+    pub fn insecure_setup(g1: &Affine<C>, tau_x: &C::ScalarField, tau_y: &C::ScalarField) -> Self {
+        let generator = G2::<C>::generator();
+        Self::new(
+            g1,
+            generator,
+            (generator * C::scalar_to_ark(tau_x)).into_affine(),
+            (generator * C::scalar_to_ark(tau_y)).into_affine(),
+        )
+    }
+
+    /// Checks `e(C - [v]G + [x]π_x + [y]π_y, H) = e(π_x, [τ_x]H) e(π_y, [τ_y]H)`, that is
+    /// `P(X, Y) - v = (X - x) Q_x(X, Y) + (Y - y) Q_y(Y)` at `(τ_x, τ_y)`.
+    pub fn verify(&self, commitment: &Affine<C>, opening: &BivariateKzgOpening<C>) -> bool {
+        let (proof_x, proof_y) = (C::g1_to_ark(&opening.proof_x), C::g1_to_ark(&opening.proof_y));
+        let lhs = C::g1_to_ark(commitment).into_group() - self.g1 * C::scalar_to_ark(&opening.value)
+            + proof_x * C::scalar_to_ark(&opening.x)
+            + proof_y * C::scalar_to_ark(&opening.y);
+        C::Engine::multi_pairing(
+            [lhs, -proof_x.into_group(), -proof_y.into_group()],
+            [self.g2, self.tau_x_g2, self.tau_y_g2],
+        )
+        .is_zero()
+    }
+}